@@ -0,0 +1 @@
+export const square = (x: number) => x ** 2;
@@ -0,0 +1,165 @@
+use std::path::PathBuf;
+
+use bpaf::Bpaf;
+
+const VERSION: &str = match option_env!("OXC_VERSION") {
+    Some(v) => v,
+    None => "dev",
+};
+
+#[derive(Debug, Clone, Bpaf)]
+#[bpaf(options, version(VERSION))]
+pub enum OxcCommand {
+    /// Strip TypeScript, compile JSX and lower syntax for a target
+    #[bpaf(command)]
+    Transform {
+        /// Lower syntax for the target, e.g. `es2018` or `chrome80,safari14`
+        #[bpaf(argument("TARGET"), fallback("esnext".to_string()), display_fallback)]
+        target: String,
+
+        #[bpaf(external)]
+        output_options: OutputOptions,
+
+        #[bpaf(external)]
+        input_options: InputOptions,
+    },
+
+    /// Compress, mangle and print files without whitespace
+    #[bpaf(command)]
+    Minify {
+        /// Do not rename variables
+        #[bpaf(long("no-mangle"), flag(false, true))]
+        mangle: bool,
+
+        /// Do not compress the code, only remove whitespace and rename variables
+        #[bpaf(long("no-compress"), flag(false, true))]
+        compress: bool,
+
+        #[bpaf(external)]
+        output_options: OutputOptions,
+
+        #[bpaf(external)]
+        input_options: InputOptions,
+    },
+
+    /// Format JavaScript, TypeScript and JSON files
+    #[bpaf(command)]
+    Format {
+        /// Format the files in place
+        #[bpaf(switch)]
+        write: bool,
+
+        /// Report the files which are not formatted, exits with 1 if there are any
+        #[bpaf(switch)]
+        check: bool,
+
+        #[bpaf(external)]
+        input_options: InputOptions,
+    },
+
+    /// Print the AST of a file
+    #[bpaf(command)]
+    Ast {
+        /// Print the ESTree compatible JSON instead of the Rust structures
+        #[bpaf(switch)]
+        estree: bool,
+
+        /// The file to parse
+        #[bpaf(positional("PATH"))]
+        path: PathBuf,
+    },
+}
+
+impl OxcCommand {
+    pub fn handle_threads(&self) {
+        let threads = match self {
+            Self::Transform { input_options, .. }
+            | Self::Minify { input_options, .. }
+            | Self::Format { input_options, .. } => input_options.threads,
+            Self::Ast { .. } => None,
+        };
+        if let Some(threads) = threads {
+            rayon::ThreadPoolBuilder::new().num_threads(threads).build_global().unwrap();
+        }
+    }
+}
+
+/// Output
+#[derive(Debug, Clone, Bpaf)]
+pub struct OutputOptions {
+    /// Write the files to DIR, keeping their paths relative to the inputs.
+    /// Without it, the single input file is printed to stdout.
+    #[bpaf(short('d'), long, argument("DIR"))]
+    pub out_dir: Option<PathBuf>,
+
+    /// Write a `.map` file next to each output file.
+    /// Inlined as a data URL when printing to stdout.
+    #[bpaf(switch)]
+    pub source_map: bool,
+}
+
+/// Input
+#[derive(Debug, Clone, Bpaf)]
+pub struct InputOptions {
+    /// Process the files again when they change
+    #[bpaf(short('w'), long, switch)]
+    pub watch: bool,
+
+    /// Number of threads to use. Set to 1 for using only 1 CPU core
+    #[bpaf(argument("INT"), hide_usage)]
+    pub threads: Option<usize>,
+
+    /// Files, directories or glob patterns, e.g. `src/**/*.ts`
+    #[bpaf(positional("PATH"), some("Expected at least one PATH"))]
+    pub paths: Vec<PathBuf>,
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::PathBuf;
+
+    use super::{OxcCommand, oxc_command};
+
+    fn parse(arg: &str) -> OxcCommand {
+        let args = arg.split(' ').map(std::string::ToString::to_string).collect::<Vec<_>>();
+        oxc_command().run_inner(args.as_slice()).unwrap()
+    }
+
+    #[test]
+    fn transform() {
+        let OxcCommand::Transform { target, output_options, input_options } =
+            parse("transform --target es2018 src/ -d dist/ --source-map")
+        else {
+            panic!("Expected the transform command");
+        };
+        assert_eq!(target, "es2018");
+        assert_eq!(output_options.out_dir, Some(PathBuf::from("dist/")));
+        assert!(output_options.source_map);
+        assert_eq!(input_options.paths, vec![PathBuf::from("src/")]);
+        assert!(!input_options.watch);
+
+        let OxcCommand::Transform { target, .. } = parse("transform -w a.ts") else {
+            panic!("Expected the transform command");
+        };
+        assert_eq!(target, "esnext");
+    }
+
+    #[test]
+    fn minify() {
+        let OxcCommand::Minify { mangle, compress, input_options, .. } =
+            parse("minify --no-mangle --threads 2 a.js b.js")
+        else {
+            panic!("Expected the minify command");
+        };
+        assert!(!mangle);
+        assert!(compress);
+        assert_eq!(input_options.threads, Some(2));
+        assert_eq!(input_options.paths.len(), 2);
+    }
+
+    #[test]
+    fn paths_are_required() {
+        assert!(oxc_command().run_inner(&["format"]).is_err());
+        assert!(oxc_command().run_inner(&["ast"]).is_err());
+    }
+}
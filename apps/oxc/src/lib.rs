@@ -0,0 +1,7 @@
+mod command;
+mod runner;
+mod walk;
+
+pub mod cli {
+    pub use crate::{command::*, runner::Runner};
+}
@@ -0,0 +1,20 @@
+use std::{io::BufWriter, process::ExitCode};
+
+use oxc_cli::cli::{Runner, oxc_command};
+
+fn main() -> ExitCode {
+    init_miette();
+
+    let command = oxc_command().run();
+    command.handle_threads();
+    // stdio is blocked by LineWriter, use a BufWriter to reduce syscalls.
+    // See `https://github.com/rust-lang/rust/issues/60673`.
+    let mut stdout = BufWriter::new(std::io::stdout());
+
+    Runner::new(command).run(&mut stdout)
+}
+
+// Initialize the data which relies on `is_atty` system calls so they don't block subsequent threads.
+fn init_miette() {
+    miette::set_hook(Box::new(|_| Box::new(miette::MietteHandlerOpts::new().build()))).unwrap();
+}
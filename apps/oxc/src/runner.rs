@@ -0,0 +1,489 @@
+use std::{
+    fs,
+    io::{self, Write},
+    path::{Component, Path, PathBuf},
+    process::ExitCode,
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use rayon::prelude::*;
+use rustc_hash::FxHashMap;
+
+use oxc_allocator::Allocator;
+use oxc_ast_visit::utf8_to_utf16::Utf8ToUtf16;
+use oxc_codegen::{Codegen, CodegenOptions};
+use oxc_diagnostics::{NamedSource, OxcDiagnostic};
+use oxc_formatter::{FileKind, FormatOptions, Formatter};
+use oxc_mangler::MangleOptions;
+use oxc_minifier::{CompressOptions, Minifier, MinifierOptions};
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_sourcemap::SourceMap;
+use oxc_span::{SourceType, VALID_EXTENSIONS};
+use oxc_transformer::{TransformOptions, Transformer};
+
+use crate::{
+    command::{InputOptions, OxcCommand},
+    walk::{Input, expand},
+};
+
+const MINIFY_EXTENSIONS: &[&str] = &["js", "mjs", "cjs"];
+const FORMAT_EXTENSIONS: &[&str] =
+    &["js", "mjs", "cjs", "jsx", "ts", "mts", "cts", "tsx", "json", "jsonc", "json5"];
+
+/// How often the inputs are checked for changes in watch mode.
+const WATCH_INTERVAL: Duration = Duration::from_millis(200);
+
+pub struct Runner {
+    command: OxcCommand,
+}
+
+/// What is done with each file.
+enum Task {
+    Transform(TransformOptions),
+    Minify(MinifierOptions),
+    Format { write: bool, check: bool },
+}
+
+/// The printed code of a file.
+struct Output {
+    code: String,
+    map: Option<SourceMap>,
+}
+
+impl Runner {
+    pub fn new(command: OxcCommand) -> Self {
+        Self { command }
+    }
+
+    pub fn run(self, stdout: &mut dyn Write) -> ExitCode {
+        let mut stderr = io::stderr();
+        let (task, input_options, output_options) = match self.command {
+            OxcCommand::Transform { target, output_options, input_options } => {
+                match TransformOptions::from_target(&target) {
+                    Ok(options) => (Task::Transform(options), input_options, Some(output_options)),
+                    Err(err) => {
+                        writeln!(stderr, "{err}").unwrap();
+                        return ExitCode::FAILURE;
+                    }
+                }
+            }
+            OxcCommand::Minify { mangle, compress, output_options, input_options } => {
+                let options = MinifierOptions {
+                    mangle: mangle.then(MangleOptions::default),
+                    compress: compress.then(CompressOptions::default),
+                    report: false,
+                };
+                (Task::Minify(options), input_options, Some(output_options))
+            }
+            OxcCommand::Format { write, check, input_options } => {
+                (Task::Format { write, check }, input_options, None)
+            }
+            OxcCommand::Ast { estree, path } => return print_ast(&path, estree, stdout),
+        };
+        let out_dir = output_options.as_ref().and_then(|options| options.out_dir.as_deref());
+        let source_map = output_options.as_ref().is_some_and(|options| options.source_map);
+        let runner = FileRunner { task, out_dir, source_map };
+        if input_options.watch {
+            runner.watch(&input_options, stdout)
+        } else {
+            runner.run(&input_options, stdout)
+        }
+    }
+}
+
+/// Runs a [Task] on the files of the inputs.
+struct FileRunner<'a> {
+    task: Task,
+    out_dir: Option<&'a Path>,
+    source_map: bool,
+}
+
+impl FileRunner<'_> {
+    fn run(&self, input_options: &InputOptions, stdout: &mut dyn Write) -> ExitCode {
+        match self.inputs(input_options) {
+            Ok(inputs) => self.process(&inputs, stdout),
+            Err(err) => {
+                let mut stderr = io::stderr();
+                writeln!(stderr, "{err}").unwrap();
+                ExitCode::FAILURE
+            }
+        }
+    }
+
+    /// Processes the inputs, then polls their modification times and processes the changed files.
+    fn watch(&self, input_options: &InputOptions, stdout: &mut dyn Write) -> ExitCode {
+        let mut stderr = io::stderr();
+        let mut modified = FxHashMap::<PathBuf, SystemTime>::default();
+        let mut first = true;
+        loop {
+            let inputs = match self.inputs(input_options) {
+                Ok(inputs) => inputs,
+                Err(err) if first => {
+                    writeln!(stderr, "{err}").unwrap();
+                    return ExitCode::FAILURE;
+                }
+                // A file was deleted while walking, try again.
+                Err(_) => vec![],
+            };
+            let changed = inputs
+                .into_iter()
+                .filter(|input| {
+                    let Ok(time) = fs::metadata(&input.path).and_then(|m| m.modified()) else {
+                        return false;
+                    };
+                    modified.insert(input.path.clone(), time) != Some(time)
+                })
+                .collect::<Vec<_>>();
+            if !changed.is_empty() {
+                self.process(&changed, stdout);
+                stdout.flush().unwrap();
+            }
+            if first {
+                writeln!(stderr, "Watching for changes...").unwrap();
+                first = false;
+            }
+            thread::sleep(WATCH_INTERVAL);
+        }
+    }
+
+    fn inputs(&self, input_options: &InputOptions) -> Result<Vec<Input>, String> {
+        let extensions = match self.task {
+            Task::Transform(_) => VALID_EXTENSIONS,
+            Task::Minify(_) => MINIFY_EXTENSIONS,
+            Task::Format { .. } => FORMAT_EXTENSIONS,
+        };
+        let mut inputs = expand(&input_options.paths, extensions, self.out_dir)?;
+        if !matches!(self.task, Task::Format { .. }) {
+            // Declaration files have no output.
+            inputs.retain(|input| {
+                !SourceType::from_path(&input.path).is_ok_and(SourceType::is_typescript_definition)
+            });
+        }
+        let to_stdout = match self.task {
+            Task::Transform(_) | Task::Minify(_) => self.out_dir.is_none(),
+            Task::Format { write, check } => !write && !check,
+        };
+        if to_stdout && inputs.len() > 1 {
+            let hint =
+                if matches!(self.task, Task::Format { .. }) { "--write" } else { "--out-dir" };
+            return Err(format!(
+                "{} files can not be printed to stdout, pass {hint}",
+                inputs.len()
+            ));
+        }
+        Ok(inputs)
+    }
+
+    /// Processes `inputs` in parallel, and writes the outputs in order.
+    fn process(&self, inputs: &[Input], stdout: &mut dyn Write) -> ExitCode {
+        let results = inputs
+            .par_iter()
+            .map(|input| {
+                let source_text = fs::read_to_string(&input.path).map_err(|err| {
+                    vec![OxcDiagnostic::error(format!(
+                        "Failed to read {}: {err}",
+                        input.path.display()
+                    ))]
+                })?;
+                let output = self.process_file(input, &source_text);
+                Ok((source_text, output))
+            })
+            .collect::<Vec<Result<_, Vec<OxcDiagnostic>>>>();
+
+        let mut stderr = io::stderr();
+        let mut failed = false;
+        let mut unformatted = 0;
+        for (input, result) in inputs.iter().zip(results) {
+            let (source_text, output) = match result {
+                Ok((source_text, Ok(output))) => (source_text, output),
+                Ok((source_text, Err(errors))) => {
+                    failed = true;
+                    let name = input.path.to_string_lossy().into_owned();
+                    for error in errors {
+                        let error =
+                            error.with_source_code(NamedSource::new(&name, source_text.clone()));
+                        writeln!(stderr, "{error:?}").unwrap();
+                    }
+                    continue;
+                }
+                Err(errors) => {
+                    failed = true;
+                    for error in errors {
+                        writeln!(stderr, "{error:?}").unwrap();
+                    }
+                    continue;
+                }
+            };
+            let written = match self.task {
+                Task::Format { check: true, .. } => {
+                    if output.code == source_text {
+                        Ok(())
+                    } else {
+                        unformatted += 1;
+                        writeln!(stdout, "{}", input.path.display())
+                    }
+                }
+                Task::Format { write: true, .. } => {
+                    if output.code == source_text {
+                        Ok(())
+                    } else {
+                        fs::write(&input.path, output.code)
+                    }
+                }
+                _ => self.write_output(input, output, stdout),
+            };
+            if let Err(err) = written {
+                failed = true;
+                writeln!(stderr, "Failed to write the output of {}: {err}", input.path.display())
+                    .unwrap();
+            }
+        }
+        if unformatted > 0 {
+            writeln!(stderr, "{unformatted} files are not formatted").unwrap();
+        }
+        if failed || unformatted > 0 { ExitCode::FAILURE } else { ExitCode::SUCCESS }
+    }
+
+    fn process_file(&self, input: &Input, source_text: &str) -> Result<Output, Vec<OxcDiagnostic>> {
+        let path = &input.path;
+        if let Task::Format { .. } = self.task {
+            let kind = FileKind::from_path(path)
+                .ok_or_else(|| vec![OxcDiagnostic::error("Unsupported file type")])?;
+            let dir = path.parent().unwrap_or_else(|| Path::new("."));
+            let options = FormatOptions::discover_prettier_config(dir)
+                .map_err(|err| vec![OxcDiagnostic::error(err)])?
+                .map(|(_, options)| options)
+                .unwrap_or_default();
+            let allocator = Allocator::default();
+            let code = Formatter::new(&allocator, options).format_source(source_text, kind)?;
+            return Ok(Output { code, map: None });
+        }
+
+        let source_type = SourceType::from_path(path)
+            .map_err(|err| vec![OxcDiagnostic::error(err.to_string())])?;
+        let allocator = Allocator::default();
+        let ret = Parser::new(&allocator, source_text, source_type).parse();
+        if !ret.errors.is_empty() {
+            return Err(ret.errors);
+        }
+        let mut program = ret.program;
+
+        let scoping = match &self.task {
+            Task::Transform(options) => {
+                let ret = SemanticBuilder::new().with_excess_capacity(2.0).build(&program);
+                let ret = Transformer::new(&allocator, path, options)
+                    .build_with_scoping(ret.semantic.into_scoping(), &mut program);
+                if !ret.errors.is_empty() {
+                    return Err(ret.errors);
+                }
+                None
+            }
+            Task::Minify(options) => {
+                Minifier::new(options.clone()).build(&allocator, &mut program).scoping
+            }
+            Task::Format { .. } => unreachable!(),
+        };
+
+        let source_map_path = self.source_map.then(|| match self.out_dir {
+            Some(out_dir) => {
+                let out_path = input.out_path(out_dir);
+                relative_path(path, out_path.parent().unwrap_or(out_dir))
+            }
+            None => path.clone(),
+        });
+        let options = CodegenOptions {
+            source_map_path,
+            ..if let Task::Minify(_) = self.task {
+                CodegenOptions::minify()
+            } else {
+                CodegenOptions::default()
+            }
+        };
+        let ret = Codegen::new().with_options(options).with_scoping(scoping).build(&program);
+        Ok(Output { code: ret.code, map: ret.map })
+    }
+
+    /// Writes the output of `input` to the output directory, or to `stdout`.
+    fn write_output(
+        &self,
+        input: &Input,
+        mut output: Output,
+        stdout: &mut dyn Write,
+    ) -> io::Result<()> {
+        let Some(out_dir) = self.out_dir else {
+            if !output.code.ends_with('\n') {
+                output.code.push('\n');
+            }
+            if let Some(map) = &output.map {
+                output.code.push_str("//# sourceMappingURL=");
+                output.code.push_str(&map.to_data_url());
+                output.code.push('\n');
+            }
+            return stdout.write_all(output.code.as_bytes());
+        };
+
+        let mut out_path = input.out_path(out_dir);
+        if let Task::Transform(_) = self.task {
+            out_path.set_extension(js_extension(&input.path));
+        }
+        if let Some(parent) = out_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        if let Some(map) = &output.map {
+            let mut map_path = out_path.clone().into_os_string();
+            map_path.push(".map");
+            let map_path = PathBuf::from(map_path);
+            fs::write(&map_path, map.to_json_string())?;
+            let map_name = map_path.file_name().unwrap_or_default().to_string_lossy();
+            output.code.push_str("//# sourceMappingURL=");
+            output.code.push_str(&map_name);
+            output.code.push('\n');
+        }
+        fs::write(out_path, output.code)
+    }
+}
+
+/// Prints the AST of the file at `path`, and the errors when it can not be parsed.
+fn print_ast(path: &Path, estree: bool, stdout: &mut dyn Write) -> ExitCode {
+    let mut stderr = io::stderr();
+    let source_text = match fs::read_to_string(path) {
+        Ok(source_text) => source_text,
+        Err(err) => {
+            writeln!(stderr, "Failed to read {}: {err}", path.display()).unwrap();
+            return ExitCode::FAILURE;
+        }
+    };
+    let source_type = match SourceType::from_path(path) {
+        Ok(source_type) => source_type,
+        Err(err) => {
+            writeln!(stderr, "{err}").unwrap();
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, &source_text, source_type).parse();
+    let mut program = ret.program;
+    if estree {
+        Utf8ToUtf16::new(&source_text).convert_program(&mut program);
+        let json = if source_type.is_javascript() {
+            program.to_pretty_estree_js_json(false)
+        } else {
+            program.to_pretty_estree_ts_json(false)
+        };
+        writeln!(stdout, "{json}").unwrap();
+    } else {
+        writeln!(stdout, "{program:#?}").unwrap();
+    }
+
+    if ret.errors.is_empty() {
+        return ExitCode::SUCCESS;
+    }
+    let name = path.to_string_lossy().into_owned();
+    for error in ret.errors {
+        let error = error.with_source_code(NamedSource::new(&name, source_text.clone()));
+        writeln!(stderr, "{error:?}").unwrap();
+    }
+    ExitCode::FAILURE
+}
+
+/// The extension of the JavaScript output of a TypeScript or JSX file.
+fn js_extension(path: &Path) -> &'static str {
+    match path.extension().and_then(|ext| ext.to_str()) {
+        Some("mjs" | "mts") => "mjs",
+        Some("cjs" | "cts") => "cjs",
+        _ => "js",
+    }
+}
+
+/// `path` relative to `dir`, e.g. `../src/index.ts` for `src/index.ts` and `dist`.
+fn relative_path(path: &Path, dir: &Path) -> PathBuf {
+    let (Ok(path), Ok(dir)) = (std::path::absolute(path), std::path::absolute(dir)) else {
+        return path.to_path_buf();
+    };
+    let common = path.components().zip(dir.components()).take_while(|(a, b)| a == b).count();
+    dir.components()
+        .skip(common)
+        .map(|_| Component::ParentDir)
+        .chain(path.components().skip(common))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use std::{fs, path::Path, process::ExitCode};
+
+    use super::{Runner, relative_path};
+    use crate::command::oxc_command;
+
+    fn run(args: &[&str]) -> (ExitCode, String) {
+        let command = oxc_command().run_inner(args).unwrap();
+        let mut stdout = vec![];
+        let code = Runner::new(command).run(&mut stdout);
+        (code, String::from_utf8(stdout).unwrap())
+    }
+
+    #[test]
+    fn transform() {
+        let dir = tempfile::tempdir().unwrap();
+        let out_dir = dir.path().to_str().unwrap();
+        let (code, stdout) = run(&[
+            "transform",
+            "--target",
+            "es2015",
+            "fixtures/src",
+            "-d",
+            out_dir,
+            "--source-map",
+        ]);
+        assert_eq!(code, ExitCode::SUCCESS);
+        assert!(stdout.is_empty());
+
+        let index = fs::read_to_string(dir.path().join("index.js")).unwrap();
+        assert!(!index.contains(": number"));
+        assert!(!index.contains("**"));
+        assert!(index.ends_with("//# sourceMappingURL=index.js.map\n"));
+        assert!(dir.path().join("index.js.map").exists());
+        assert!(dir.path().join("nested/util.js").exists());
+
+        let (code, stdout) = run(&["transform", "fixtures/src/index.ts"]);
+        assert_eq!(code, ExitCode::SUCCESS);
+        assert!(stdout.contains("export const square = (x) => x ** 2;"));
+
+        let (code, stdout) = run(&["transform", "fixtures/src"]);
+        assert_eq!(code, ExitCode::FAILURE);
+        assert!(stdout.is_empty());
+    }
+
+    #[test]
+    fn minify() {
+        let (code, stdout) = run(&["minify", "fixtures/src/nested/util.js"]);
+        assert_eq!(code, ExitCode::SUCCESS);
+        assert_eq!(stdout, "export function add(t,n){return t+n}\n");
+    }
+
+    #[test]
+    fn format() {
+        let (code, stdout) = run(&["format", "--check", "fixtures/src"]);
+        assert_eq!(code, ExitCode::FAILURE);
+        assert_eq!(stdout.trim_end(), Path::new("fixtures/src/nested/util.js").to_str().unwrap());
+    }
+
+    #[test]
+    fn ast() {
+        let (code, stdout) = run(&["ast", "--estree", "fixtures/src/nested/util.js"]);
+        assert_eq!(code, ExitCode::SUCCESS);
+        assert!(stdout.contains("\"type\": \"FunctionDeclaration\""));
+    }
+
+    #[test]
+    fn test_relative_path() {
+        assert_eq!(
+            relative_path(Path::new("src/a.ts"), Path::new("dist")),
+            Path::new("../src/a.ts")
+        );
+        assert_eq!(relative_path(Path::new("a/b.ts"), Path::new("a")), Path::new("b.ts"));
+    }
+}
@@ -0,0 +1,152 @@
+use std::path::{Component, Path, PathBuf};
+
+use globset::{Glob, GlobMatcher};
+
+/// A file to process.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Input {
+    pub path: PathBuf,
+    /// The directory which the output paths are relative to: the directory given on the
+    /// command line, the directory of a file, or the directory before the first glob character.
+    pub base: PathBuf,
+}
+
+impl Input {
+    /// The path of the output of this file in `out_dir`.
+    pub fn out_path(&self, out_dir: &Path) -> PathBuf {
+        let relative = self.path.strip_prefix(&self.base).unwrap_or(&self.path);
+        out_dir.join(relative)
+    }
+}
+
+/// Expands files, directories and glob patterns to the files with one of `extensions`.
+///
+/// Directories are walked with `.gitignore` files and hidden files respected,
+/// `exclude` (e.g. the output directory) is skipped.
+///
+/// # Errors
+///
+/// * A path does not exist, or a glob pattern is invalid
+pub fn expand(
+    paths: &[PathBuf],
+    extensions: &[&str],
+    exclude: Option<&Path>,
+) -> Result<Vec<Input>, String> {
+    let exclude = exclude.and_then(|dir| dir.canonicalize().ok());
+    let mut inputs = vec![];
+    for path in paths {
+        if path.is_file() {
+            let base = path.parent().map(Path::to_path_buf).unwrap_or_default();
+            inputs.push(Input { path: path.clone(), base });
+        } else if path.is_dir() {
+            walk(path, path, None, extensions, exclude.as_deref(), &mut inputs);
+        } else if is_glob(path) {
+            let pattern = path.to_string_lossy();
+            let matcher = Glob::new(&pattern)
+                .map_err(|err| format!("Invalid glob pattern {pattern}: {err}"))?
+                .compile_matcher();
+            let base = glob_base(path);
+            let dir = if base.as_os_str().is_empty() { Path::new(".") } else { base.as_path() };
+            walk(dir, &base, Some(&matcher), extensions, exclude.as_deref(), &mut inputs);
+        } else {
+            return Err(format!("{} does not exist", path.display()));
+        }
+    }
+    inputs.sort_unstable();
+    inputs.dedup_by(|a, b| a.path == b.path);
+    Ok(inputs)
+}
+
+/// Walks `dir`, recording the files relative to `base`.
+fn walk(
+    dir: &Path,
+    base: &Path,
+    matcher: Option<&GlobMatcher>,
+    extensions: &[&str],
+    exclude: Option<&Path>,
+    inputs: &mut Vec<Input>,
+) {
+    let exclude = exclude.map(Path::to_path_buf);
+    let walker = ignore::WalkBuilder::new(dir)
+        .filter_entry(move |entry| {
+            let Some(exclude) = &exclude else { return true };
+            !entry.file_type().is_some_and(|ty| ty.is_dir())
+                || entry.path().canonicalize().is_ok_and(|path| &path != exclude)
+        })
+        .build();
+    for entry in walker.flatten() {
+        if entry.file_type().is_none_or(|ty| ty.is_dir()) {
+            continue;
+        }
+        let Ok(relative) = entry.path().strip_prefix(dir) else { continue };
+        let path = base.join(relative);
+        if matcher.is_some_and(|matcher| !matcher.is_match(&path)) {
+            continue;
+        }
+        if has_extension(&path, extensions) {
+            inputs.push(Input { path, base: base.to_path_buf() });
+        }
+    }
+}
+
+fn has_extension(path: &Path, extensions: &[&str]) -> bool {
+    let Some(file_name) = path.file_name().and_then(|name| name.to_str()) else { return false };
+    extensions.iter().any(|ext| {
+        file_name.strip_suffix(ext).is_some_and(|stem| !stem.is_empty() && stem.ends_with('.'))
+    })
+}
+
+fn is_glob(path: &Path) -> bool {
+    path.to_string_lossy().contains(['*', '?', '[', '{'])
+}
+
+/// The directories of `pattern` before the first component with a glob character.
+fn glob_base(pattern: &Path) -> PathBuf {
+    pattern
+        .components()
+        .take_while(|component| match component {
+            Component::Normal(name) => !is_glob(Path::new(name)),
+            _ => true,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use std::path::{Path, PathBuf};
+
+    use super::{Input, expand, glob_base};
+
+    const EXTENSIONS: &[&str] = &["js", "ts"];
+
+    fn paths(inputs: &[Input]) -> Vec<&Path> {
+        inputs.iter().map(|input| input.path.strip_prefix("fixtures").unwrap()).collect()
+    }
+
+    #[test]
+    fn test_expand() {
+        let inputs = expand(&[PathBuf::from("fixtures/src")], EXTENSIONS, None).unwrap();
+        assert_eq!(paths(&inputs), [Path::new("src/index.ts"), Path::new("src/nested/util.js")]);
+        assert_eq!(inputs[1].out_path(Path::new("dist")), Path::new("dist/nested/util.js"));
+
+        let inputs = expand(&[PathBuf::from("fixtures/src/index.ts")], EXTENSIONS, None).unwrap();
+        assert_eq!(inputs[0].out_path(Path::new("dist")), Path::new("dist/index.ts"));
+
+        let inputs = expand(&[PathBuf::from("fixtures/src/**/*.js")], EXTENSIONS, None).unwrap();
+        assert_eq!(paths(&inputs), [Path::new("src/nested/util.js")]);
+        assert_eq!(inputs[0].out_path(Path::new("dist")), Path::new("dist/nested/util.js"));
+
+        let exclude = Path::new("fixtures/src/nested");
+        let inputs = expand(&[PathBuf::from("fixtures")], EXTENSIONS, Some(exclude)).unwrap();
+        assert_eq!(paths(&inputs), [Path::new("src/index.ts")]);
+
+        assert!(expand(&[PathBuf::from("fixtures/missing")], EXTENSIONS, None).is_err());
+    }
+
+    #[test]
+    fn test_glob_base() {
+        assert_eq!(glob_base(Path::new("src/**/*.ts")), Path::new("src"));
+        assert_eq!(glob_base(Path::new("*.ts")), Path::new(""));
+        assert_eq!(glob_base(Path::new("./a/b/{c,d}/*.js")), Path::new("./a/b"));
+    }
+}
@@ -0,0 +1 @@
+if (Boolean(Boolean(x))) {}
@@ -0,0 +1,5 @@
+import { A } from "~/a";
+
+export class B {
+  value: A;
+}
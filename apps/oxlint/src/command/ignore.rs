@@ -10,6 +10,9 @@ pub const NO_IGNORE_HELP: &[(&str, Style)] = &[
     (" flags", Style::Text),
 ];
 
+/// Files larger than this are not linted, see [`IgnoreOptions::max_file_size`].
+pub const DEFAULT_MAX_FILE_SIZE: u64 = 10 * 1024 * 1024;
+
 /// Ignore Files
 #[derive(Debug, Clone, Bpaf)]
 pub struct IgnoreOptions {
@@ -30,13 +33,23 @@ pub struct IgnoreOptions {
     /// Follow symbolic links. Oxlint ignores symbolic links by default.
     #[bpaf(switch, hide_usage)]
     pub symlinks: bool,
+
+    /// Skip files larger than BYTES, 10 MiB by default. Set to 0 to lint files of any size.
+    #[bpaf(argument("BYTES"), fallback(DEFAULT_MAX_FILE_SIZE), hide_usage)]
+    pub max_file_size: u64,
+
+    /// Lint minified and generated files, which are skipped by default:
+    /// files named like `*.min.js`, files starting with a `@generated` or `DO NOT EDIT` comment,
+    /// and files with very long lines.
+    #[bpaf(switch, hide_usage)]
+    pub no_ignore_generated: bool,
 }
 
 #[cfg(test)]
 mod ignore_options {
     use std::{ffi::OsString, path::PathBuf};
 
-    use super::{super::lint::lint_command, IgnoreOptions};
+    use super::{super::lint::lint_command, DEFAULT_MAX_FILE_SIZE, IgnoreOptions};
 
     fn get_ignore_options(arg: &str) -> IgnoreOptions {
         let args = arg.split(' ').map(std::string::ToString::to_string).collect::<Vec<_>>();
@@ -49,6 +62,8 @@ mod ignore_options {
         assert_eq!(options.ignore_path, OsString::from(".eslintignore"));
         assert!(!options.no_ignore);
         assert!(options.ignore_pattern.is_empty());
+        assert_eq!(options.max_file_size, DEFAULT_MAX_FILE_SIZE);
+        assert!(!options.no_ignore_generated);
     }
 
     #[test]
@@ -74,4 +89,11 @@ mod ignore_options {
         let options = get_ignore_options("--ignore-pattern ./test --ignore-pattern bar.js foo.js");
         assert_eq!(options.ignore_pattern, vec![String::from("./test"), String::from("bar.js")]);
     }
+
+    #[test]
+    fn generated_files() {
+        let options = get_ignore_options("--max-file-size 0 --no-ignore-generated foo.js");
+        assert_eq!(options.max_file_size, 0);
+        assert!(options.no_ignore_generated);
+    }
 }
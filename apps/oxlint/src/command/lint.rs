@@ -80,6 +80,14 @@ pub struct BasicOptions {
     #[bpaf(argument("./tsconfig.json"), hide_usage)]
     pub tsconfig: Option<PathBuf>,
 
+    /// Condition names for resolving `exports` and `imports` of `package.json` for import plugin, in order of priority, e.g. `--condition browser --condition import`. Defaults to `module` and `import`
+    #[bpaf(long("condition"), argument("NAME"), many, hide_usage)]
+    pub conditions: Vec<String>,
+
+    /// Root of a package of the workspace, whose files resolve imports with its own `tsconfig.json`, e.g. `--workspace-root packages/a --workspace-root packages/b`
+    #[bpaf(long("workspace-root"), argument("DIR"), many, hide_usage)]
+    pub workspace_roots: Vec<PathBuf>,
+
     /// Initialize oxlint configuration with default values
     #[bpaf(switch, hide_usage)]
     pub init: bool,
@@ -197,6 +205,16 @@ pub struct OutputOptions {
     /// `checkstyle`, `default`, `github`, `gitlab`, `json`, `junit`, `stylish`, `unix`
     #[bpaf(long, short, fallback(OutputFormat::Default), hide_usage)]
     pub format: OutputFormat,
+
+    /// Print the number of errors and warnings per rule and per directory at the end of the run.
+    /// Included as `stats` in the `json` format.
+    #[bpaf(switch, hide_usage)]
+    pub stats: bool,
+
+    /// Translate the diagnostics of the rules, e.g. `--locale=de`.
+    /// Defaults to the `OXC_LOCALE` environment variable, diagnostics without a translation are printed in English.
+    #[bpaf(argument("LOCALE"), hide_usage)]
+    pub locale: Option<String>,
 }
 
 /// Enable Plugins
@@ -377,6 +395,15 @@ pub enum ReportUnusedDirectives {
 pub struct InlineConfigOptions {
     #[bpaf(external)]
     pub report_unused_directives: ReportUnusedDirectives,
+
+    /// Report directive comments without a reason, like `// eslint-disable-line no-console`.
+    /// Add the reason after `--`, like `// eslint-disable-line no-console -- reason`.
+    #[bpaf(switch, hide_usage)]
+    pub require_directive_reasons: bool,
+
+    /// Report every directive comment which disables rules, with its reason and expiry date.
+    #[bpaf(switch, hide_usage)]
+    pub report_suppressions: bool,
 }
 
 #[cfg(test)]
@@ -548,6 +575,25 @@ mod lint_options {
         assert!(options.list_rules);
     }
 
+    #[test]
+    fn conditions() {
+        let options = get_lint_options("--condition browser --condition import");
+        assert_eq!(options.basic_options.conditions, ["browser", "import"]);
+        let options = get_lint_options(".");
+        assert!(options.basic_options.conditions.is_empty());
+    }
+
+    #[test]
+    fn workspace_roots() {
+        let options = get_lint_options("--workspace-root packages/a --workspace-root packages/b");
+        assert_eq!(
+            options.basic_options.workspace_roots,
+            [PathBuf::from("packages/a"), PathBuf::from("packages/b")]
+        );
+        let options = get_lint_options(".");
+        assert!(options.basic_options.workspace_roots.is_empty());
+    }
+
     #[test]
     fn disable_nested_config() {
         let options = get_lint_options("--disable-nested-config");
@@ -603,4 +649,15 @@ mod inline_config_options {
             ReportUnusedDirectives::WithSeverity(Some(AllowWarnDeny::Deny))
         );
     }
+
+    #[test]
+    fn suppressions() {
+        let options = get_lint_options(".");
+        assert!(!options.inline_config_options.require_directive_reasons);
+        assert!(!options.inline_config_options.report_suppressions);
+
+        let options = get_lint_options("--require-directive-reasons --report-suppressions");
+        assert!(options.inline_config_options.require_directive_reasons);
+        assert!(options.inline_config_options.report_suppressions);
+    }
 }
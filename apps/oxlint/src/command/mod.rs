@@ -6,7 +6,7 @@ use std::path::PathBuf;
 use bpaf::Bpaf;
 
 pub use self::{
-    ignore::IgnoreOptions,
+    ignore::{DEFAULT_MAX_FILE_SIZE, IgnoreOptions},
     lint::{LintCommand, OutputOptions, ReportUnusedDirectives, WarningOptions, lint_command},
 };
 
@@ -30,6 +30,11 @@ pub struct MiscOptions {
     /// When present, no linting is performed and only config-related options are valid.
     #[bpaf(switch, hide_usage)]
     pub print_config: bool,
+
+    /// Start a long-lived JSON-RPC service on stdin and stdout, which lints and fixes files on
+    /// request, without loading the configuration again for every file.
+    #[bpaf(switch, hide_usage)]
+    pub daemon: bool,
 }
 
 #[expect(clippy::ptr_arg)]
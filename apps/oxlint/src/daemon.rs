@@ -0,0 +1,407 @@
+//! `oxlint --daemon`, a long-lived service which lints and fixes files on request.
+//!
+//! For the integrations which don't use the language server, like git hooks, editor plugins and
+//! build watchers. The configuration, the rules and the cache of the import resolver are loaded
+//! once, instead of once per file.
+//!
+//! The daemon reads [JSON-RPC 2.0](https://www.jsonrpc.org/specification) requests from stdin,
+//! one per line, and writes one response per line to stdout:
+//!
+//! * `lint`, with the params `{ "path": string, "text"?: string }`: lint the file at `path`, or
+//!   `text` as the content of `path`. The result is `{ "diagnostics": [...] }`, the diagnostics
+//!   in the format of `--format json`.
+//! * `fix`, with the same params: apply the fixes of the `--fix` options, or the safe fixes if
+//!   none is passed. The result is `{ "output": string | null, "diagnostics": [...] }`, the fixed
+//!   code, or `null` if nothing was fixed, and the remaining diagnostics. The fixed code is
+//!   written to `path`, unless `text` is passed.
+//! * `invalidate`, with the params `{ "path"?: string }`: forget the cached `package.json` and
+//!   `tsconfig.json` files after `path` changed on disk. All the files are forgotten, not only
+//!   `path`.
+//! * `shutdown`: stop the daemon after the response. The daemon also stops at the end of stdin.
+
+use std::{
+    fs,
+    io::{BufRead, Write},
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex, mpsc},
+};
+
+use ignore::{gitignore::Gitignore, overrides::Override};
+use miette::JSONReportHandler;
+use oxc_allocator::{Allocator, AllocatorPool};
+use oxc_diagnostics::Error;
+use oxc_linter::{
+    FixKind, LINTABLE_EXTENSIONS, LintService, LintServiceOptions, Linter, ResolutionCache,
+    RuntimeFileSystem, read_to_arena_str,
+};
+use serde::Deserialize;
+use serde_json::{Value, json};
+
+use crate::cli::CliRunResult;
+
+/// JSON-RPC error codes
+const PARSE_ERROR: i64 = -32700;
+const INVALID_REQUEST: i64 = -32600;
+const METHOD_NOT_FOUND: i64 = -32601;
+const INVALID_PARAMS: i64 = -32602;
+const INTERNAL_ERROR: i64 = -32603;
+
+#[derive(Debug, Deserialize)]
+struct Request {
+    /// Absent for notifications, which get no response.
+    id: Option<Value>,
+    method: String,
+    #[serde(default)]
+    params: Value,
+}
+
+#[derive(Debug, Deserialize)]
+struct FileParams {
+    path: PathBuf,
+    text: Option<String>,
+}
+
+/// The error of a request, sent as the `error` of the response
+struct ResponseError {
+    code: i64,
+    message: String,
+}
+
+impl ResponseError {
+    fn new(code: i64, message: impl Into<String>) -> Self {
+        Self { code, message: message.into() }
+    }
+}
+
+/// The options of the [`LintService`]s created for each request
+pub struct DaemonOptions {
+    pub cwd: PathBuf,
+    pub cross_module: bool,
+    pub tsconfig: Option<PathBuf>,
+    pub workspace_roots: Vec<PathBuf>,
+    pub condition_names: Vec<String>,
+    pub skip_generated: bool,
+    /// The `--ignore-pattern`s and the `ignorePatterns` of the configuration file
+    pub ignore_overrides: Option<Override>,
+    /// The `--ignore-path` file
+    pub ignore_file: Option<Gitignore>,
+}
+
+pub struct Daemon {
+    /// Lints without fixing
+    linter: Linter,
+    /// Lints and fixes with the fix kind of the `fix` requests
+    fix_linter: Linter,
+    options: DaemonOptions,
+    /// Shared by the requests, until `invalidate`
+    resolution_cache: ResolutionCache,
+}
+
+/// Reads the file at `path` from disk, or uses the text of the request, and keeps the fixed code
+/// instead of writing it.
+struct DaemonFileSystem {
+    path: PathBuf,
+    text: Option<String>,
+    output: Arc<Mutex<Option<String>>>,
+}
+
+impl RuntimeFileSystem for DaemonFileSystem {
+    fn read_to_arena_str<'a>(
+        &self,
+        path: &Path,
+        allocator: &'a Allocator,
+    ) -> Result<&'a str, std::io::Error> {
+        match &self.text {
+            Some(text) if path == self.path => Ok(allocator.alloc_str(text)),
+            _ => read_to_arena_str(path, allocator),
+        }
+    }
+
+    fn write_file(&self, path: &Path, content: String) -> Result<(), std::io::Error> {
+        if path == self.path {
+            *self.output.lock().unwrap() = Some(content);
+        }
+        Ok(())
+    }
+}
+
+impl Daemon {
+    pub fn new(linter: Linter, fix_kind: FixKind, options: DaemonOptions) -> Self {
+        let fix_kind = if fix_kind.is_none() { FixKind::SafeFix } else { fix_kind };
+        Self {
+            fix_linter: linter.clone().with_fix(fix_kind),
+            linter: linter.with_fix(FixKind::None),
+            options,
+            resolution_cache: ResolutionCache::new(),
+        }
+    }
+
+    /// Answers the requests from `stdin` until `shutdown` or the end of `stdin`.
+    pub fn run(&self, stdin: &mut dyn BufRead, stdout: &mut dyn Write) -> CliRunResult {
+        let mut line = String::new();
+        loop {
+            line.clear();
+            match stdin.read_line(&mut line) {
+                Ok(0) | Err(_) => return CliRunResult::None,
+                Ok(_) => {}
+            }
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            let (response, shutdown) = self.handle_message(&line);
+            if let Some(response) = response {
+                if writeln!(stdout, "{response}").and_then(|()| stdout.flush()).is_err() {
+                    return CliRunResult::None;
+                }
+            }
+            if shutdown {
+                return CliRunResult::None;
+            }
+        }
+    }
+
+    /// Returns the response to `message`, if it is not a notification, and whether to shut down.
+    fn handle_message(&self, message: &str) -> (Option<Value>, bool) {
+        let value = match serde_json::from_str::<Value>(message) {
+            Ok(value) => value,
+            Err(err) => {
+                let error = ResponseError::new(PARSE_ERROR, err.to_string());
+                return (Some(Self::response(&Value::Null, Err(error))), false);
+            }
+        };
+        let request = match Request::deserialize(&value) {
+            Ok(request) => request,
+            Err(err) => {
+                let id = value.get("id").cloned().unwrap_or_default();
+                let error = ResponseError::new(INVALID_REQUEST, err.to_string());
+                return (Some(Self::response(&id, Err(error))), false);
+            }
+        };
+
+        let shutdown = request.method == "shutdown";
+        let result = self.handle_request(&request.method, request.params);
+        (request.id.map(|id| Self::response(&id, result)), shutdown)
+    }
+
+    fn handle_request(&self, method: &str, params: Value) -> Result<Value, ResponseError> {
+        match method {
+            "lint" => {
+                let params = Self::params::<FileParams>(params)?;
+                let (_, diagnostics) = self.lint(&self.linter, params.path, params.text)?;
+                Ok(json!({ "diagnostics": diagnostics }))
+            }
+            "fix" => {
+                let params = Self::params::<FileParams>(params)?;
+                let write = params.text.is_none();
+                let path = self.options.cwd.join(&params.path);
+                let (output, diagnostics) =
+                    self.lint(&self.fix_linter, params.path, params.text)?;
+                if write {
+                    if let Some(output) = &output {
+                        fs::write(&path, output).map_err(|err| {
+                            ResponseError::new(
+                                INTERNAL_ERROR,
+                                format!("Failed to write {}: {err}", path.display()),
+                            )
+                        })?;
+                    }
+                }
+                Ok(json!({ "output": output, "diagnostics": diagnostics }))
+            }
+            "invalidate" => {
+                // The cache of the resolver can't forget single paths, so the `path` param is
+                // accepted but all entries are forgotten.
+                self.resolution_cache.clear();
+                Ok(Value::Null)
+            }
+            "shutdown" => Ok(Value::Null),
+            _ => Err(ResponseError::new(METHOD_NOT_FOUND, format!("Unknown method `{method}`"))),
+        }
+    }
+
+    /// Lints `path`, or `text` as the content of `path`, with `linter`. Returns the fixed code, if
+    /// `linter` fixed it, and the diagnostics.
+    fn lint(
+        &self,
+        linter: &Linter,
+        path: PathBuf,
+        text: Option<String>,
+    ) -> Result<(Option<String>, Vec<Value>), ResponseError> {
+        let path = self.options.cwd.join(path);
+        if text.is_none() && !path.is_file() {
+            return Err(ResponseError::new(
+                INVALID_PARAMS,
+                format!("{} is not a file", path.display()),
+            ));
+        }
+        if !self.should_lint_path(&path) {
+            return Ok((None, vec![]));
+        }
+
+        let mut options =
+            LintServiceOptions::new(self.options.cwd.clone(), vec![Arc::from(path.as_os_str())])
+                .with_cross_module(self.options.cross_module)
+                .with_workspace_roots(self.options.workspace_roots.clone())
+                .with_skip_generated(self.options.skip_generated)
+                .with_resolution_cache(self.resolution_cache.clone());
+        if let Some(tsconfig) = &self.options.tsconfig {
+            options = options.with_tsconfig(tsconfig);
+        }
+        if !self.options.condition_names.is_empty() {
+            options = options.with_condition_names(self.options.condition_names.clone());
+        }
+
+        let output = Arc::new(Mutex::new(None));
+        let file_system = DaemonFileSystem { path, text, output: Arc::clone(&output) };
+        let (tx_error, rx_error) = mpsc::channel();
+        LintService::new(linter, AllocatorPool::new(1), options)
+            .with_file_system(Box::new(file_system))
+            .run(&tx_error);
+
+        let handler = JSONReportHandler::new();
+        let diagnostics = rx_error
+            .try_iter()
+            .flatten()
+            .flat_map(|(_, errors)| errors)
+            .map(|error| render_diagnostic(&handler, &error))
+            .collect();
+        let output = output.lock().unwrap().take();
+        Ok((output, diagnostics))
+    }
+
+    /// Whether `path` has a lintable extension and is not ignored.
+    fn should_lint_path(&self, path: &Path) -> bool {
+        let lintable = path
+            .extension()
+            .and_then(std::ffi::OsStr::to_str)
+            .is_some_and(|ext| LINTABLE_EXTENSIONS.contains(&ext));
+        lintable
+            && !self
+                .options
+                .ignore_overrides
+                .as_ref()
+                .is_some_and(|overrides| overrides.matched(path, false).is_ignore())
+            && !self
+                .options
+                .ignore_file
+                .as_ref()
+                .is_some_and(|ignore| ignore.matched(path, false).is_ignore())
+    }
+
+    fn params<T: for<'de> Deserialize<'de>>(params: Value) -> Result<T, ResponseError> {
+        serde_json::from_value(params)
+            .map_err(|err| ResponseError::new(INVALID_PARAMS, err.to_string()))
+    }
+
+    fn response(id: &Value, result: Result<Value, ResponseError>) -> Value {
+        match result {
+            Ok(result) => json!({ "jsonrpc": "2.0", "id": id, "result": result }),
+            Err(error) => json!({
+                "jsonrpc": "2.0",
+                "id": id,
+                "error": { "code": error.code, "message": error.message },
+            }),
+        }
+    }
+}
+
+/// A diagnostic in the format of `--format json`
+fn render_diagnostic(handler: &JSONReportHandler, error: &Error) -> Value {
+    let mut output = String::new();
+    handler.render_report(&mut output, error.as_ref()).unwrap();
+    serde_json::from_str(&output).unwrap_or(Value::String(output))
+}
+
+#[cfg(test)]
+mod test {
+    use std::io::Cursor;
+
+    use oxc_linter::{ConfigStore, ConfigStoreBuilder, FixKind, LintOptions, Linter};
+    use rustc_hash::FxHashMap;
+    use serde_json::{Value, json};
+
+    use super::{Daemon, DaemonOptions};
+
+    fn daemon() -> Daemon {
+        let config = ConfigStoreBuilder::default().build();
+        let linter =
+            Linter::new(LintOptions::default(), ConfigStore::new(config, FxHashMap::default()));
+        let cwd = std::env::current_dir().unwrap().join("fixtures/linter");
+        Daemon::new(
+            linter,
+            FixKind::None,
+            DaemonOptions {
+                cwd,
+                cross_module: false,
+                tsconfig: None,
+                workspace_roots: vec![],
+                condition_names: vec![],
+                skip_generated: false,
+                ignore_overrides: None,
+                ignore_file: None,
+            },
+        )
+    }
+
+    fn run(requests: &[Value]) -> Vec<Value> {
+        let input = requests.iter().map(Value::to_string).collect::<Vec<_>>().join("\n");
+        let mut output = vec![];
+        daemon().run(&mut Cursor::new(input), &mut output);
+        String::from_utf8(output)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn lint() {
+        let responses = run(&[
+            json!({ "jsonrpc": "2.0", "id": 1, "method": "lint", "params": { "path": "debugger.js" } }),
+            json!({ "jsonrpc": "2.0", "id": 2, "method": "lint", "params": { "path": "a.js", "text": "foo();" } }),
+        ]);
+        assert_eq!(responses.len(), 2);
+        assert_eq!(responses[0]["id"], 1);
+        let diagnostics = responses[0]["result"]["diagnostics"].as_array().unwrap();
+        assert_eq!(diagnostics.len(), 1);
+        assert_eq!(diagnostics[0]["code"], "eslint(no-debugger)");
+        assert_eq!(responses[1]["result"], json!({ "diagnostics": [] }));
+    }
+
+    #[test]
+    fn fix_text() {
+        let responses = run(&[json!({
+            "jsonrpc": "2.0",
+            "id": 1,
+            "method": "fix",
+            "params": { "path": "a.js", "text": "debugger;\nfoo();\n" },
+        })]);
+        assert_eq!(responses[0]["result"], json!({ "output": "\nfoo();\n", "diagnostics": [] }));
+    }
+
+    #[test]
+    fn notifications_and_errors() {
+        let responses = run(&[
+            json!({ "jsonrpc": "2.0", "method": "invalidate" }),
+            json!({ "jsonrpc": "2.0", "id": 1, "method": "format" }),
+            json!({ "jsonrpc": "2.0", "id": 2, "method": "lint", "params": {} }),
+            json!({ "jsonrpc": "2.0", "id": 3, "method": "lint", "params": { "path": "missing.js" } }),
+            json!({ "jsonrpc": "2.0", "id": 4, "method": "shutdown" }),
+            json!({ "jsonrpc": "2.0", "id": 5, "method": "invalidate" }),
+        ]);
+        let codes = responses
+            .iter()
+            .map(|response| (response["id"].clone(), response["error"]["code"].clone()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            codes,
+            vec![
+                (json!(1), json!(-32601)),
+                (json!(2), json!(-32602)),
+                (json!(3), json!(-32602)),
+                (json!(4), Value::Null),
+            ]
+        );
+    }
+}
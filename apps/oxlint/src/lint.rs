@@ -4,7 +4,7 @@ use std::{
     fs,
     io::{ErrorKind, Write},
     path::{Path, PathBuf, absolute},
-    sync::Arc,
+    sync::{Arc, atomic::Ordering},
     time::Instant,
 };
 
@@ -14,13 +14,14 @@ use oxc_allocator::AllocatorPool;
 use oxc_diagnostics::{DiagnosticService, GraphicalReportHandler, OxcDiagnostic};
 use oxc_linter::{
     AllowWarnDeny, Config, ConfigStore, ConfigStoreBuilder, InvalidFilterKind, LintFilter,
-    LintOptions, LintService, LintServiceOptions, Linter, Oxlintrc,
+    LintOptions, LintService, LintServiceOptions, Linter, Oxlintrc, message_catalog,
 };
 use rustc_hash::{FxHashMap, FxHashSet};
 use serde_json::Value;
 
 use crate::{
     cli::{CliRunResult, LintCommand, MiscOptions, ReportUnusedDirectives, Runner, WarningOptions},
+    daemon::{Daemon, DaemonOptions},
     output_formatter::{LintCommandInfo, OutputFormatter},
     walk::Walk,
 };
@@ -41,6 +42,9 @@ impl Runner for LintRunner {
     fn run(self, stdout: &mut dyn Write) -> CliRunResult {
         let format_str = self.options.output_options.format;
         let output_formatter = OutputFormatter::new(format_str);
+        let stats = self.options.output_options.stats;
+        let locale =
+            self.options.output_options.locale.clone().or_else(|| env::var("OXC_LOCALE").ok());
 
         if self.options.list_rules {
             if let Some(output) = output_formatter.all_rules() {
@@ -156,6 +160,8 @@ impl Runner for LintRunner {
                     number_of_rules: None,
                     threads_count: rayon::current_num_threads(),
                     start_time: now.elapsed(),
+                    stats: None,
+                    promotions: Vec::new(),
                 }) {
                     print_and_flush_stdout(stdout, &end);
                 }
@@ -166,6 +172,8 @@ impl Runner for LintRunner {
             paths.push(self.cwd.clone());
         }
 
+        let daemon_ignore_overrides =
+            if misc_options.daemon { override_builder.clone() } else { None };
         let walker = Walk::new(&paths, &ignore_options, override_builder);
         let paths = walker.paths();
         let number_of_files = paths.len();
@@ -250,9 +258,12 @@ impl Runner for LintRunner {
         } else {
             nested_configs.values().any(|config| config.plugins().has_import())
         };
-        let mut options =
-            LintServiceOptions::new(self.cwd, paths).with_cross_module(use_cross_module);
+        let mut options = LintServiceOptions::new(self.cwd, paths)
+            .with_cross_module(use_cross_module)
+            .with_workspace_roots(basic_options.workspace_roots.clone())
+            .with_skip_generated(!ignore_options.no_ignore_generated);
 
+        let promotions = config_builder.promotions().to_vec();
         let lint_config = config_builder.build();
 
         let report_unused_directives = match inline_config_options.report_unused_directives {
@@ -264,7 +275,10 @@ impl Runner for LintRunner {
         let linter =
             Linter::new(LintOptions::default(), ConfigStore::new(lint_config, nested_configs))
                 .with_fix(fix_options.fix_kind())
-                .with_report_unused_directives(report_unused_directives);
+                .with_report_unused_directives(report_unused_directives)
+                .with_require_directive_reasons(inline_config_options.require_directive_reasons)
+                .with_report_suppressions(inline_config_options.report_suppressions)
+                .with_message_catalog(locale.as_deref().and_then(message_catalog));
 
         let tsconfig = basic_options.tsconfig;
         if let Some(path) = tsconfig.as_ref() {
@@ -285,14 +299,37 @@ impl Runner for LintRunner {
             }
         }
 
+        if misc_options.daemon {
+            let daemon_options = DaemonOptions {
+                cwd: options.cwd().to_path_buf(),
+                cross_module: use_cross_module,
+                tsconfig,
+                workspace_roots: basic_options.workspace_roots,
+                condition_names: basic_options.conditions,
+                skip_generated: !ignore_options.no_ignore_generated,
+                ignore_overrides: daemon_ignore_overrides,
+                ignore_file: (!ignore_options.no_ignore)
+                    .then(|| Gitignore::new(&ignore_options.ignore_path).0),
+            };
+            let daemon = Daemon::new(linter, fix_options.fix_kind(), daemon_options);
+            return daemon.run(&mut std::io::stdin().lock(), stdout);
+        }
+
+        if !basic_options.conditions.is_empty() {
+            options = options.with_condition_names(basic_options.conditions);
+        }
+
         let mut diagnostic_service =
-            Self::get_diagnostic_service(&output_formatter, &warning_options, &misc_options);
+            Self::get_diagnostic_service(&output_formatter, &warning_options, &misc_options)
+                .with_stats(stats);
         let tx_error = diagnostic_service.sender().clone();
 
         let number_of_rules = linter.number_of_rules();
 
         let allocator_pool = AllocatorPool::new(rayon::current_num_threads());
 
+        let skipped_files = options.skipped_files();
+
         // Spawn linting in another thread so diagnostics can be printed immediately from diagnostic_service.run.
         rayon::spawn(move || {
             let mut lint_service = LintService::new(&linter, allocator_pool, options);
@@ -306,6 +343,11 @@ impl Runner for LintRunner {
             number_of_rules,
             threads_count: rayon::current_num_threads(),
             start_time: now.elapsed(),
+            stats: diagnostic_result.stats().cloned().map(|mut stats| {
+                stats.skipped_files = skipped_files.load(Ordering::Relaxed);
+                stats
+            }),
+            promotions,
         }) {
             print_and_flush_stdout(stdout, &end);
         }
@@ -857,6 +899,27 @@ mod test {
         fs::write(file, content_original).unwrap();
     }
 
+    #[test]
+    fn test_fix_passes() {
+        use std::fs;
+        let file = "fixtures/fix_passes/test.js";
+        let args = &["-A", "all", "-D", "no-extra-boolean-cast", "--fix", file];
+        let content_original = fs::read_to_string(file).unwrap();
+        #[expect(clippy::disallowed_methods)]
+        let content = content_original.replace("\r\n", "\n");
+        assert_eq!(&content, "if (Boolean(Boolean(x))) {}\n");
+
+        // The fix of the inner call overlaps the fix of the outer call, and is applied in the
+        // next pass.
+        Tester::new().test(args);
+        #[expect(clippy::disallowed_methods)]
+        let new_content = fs::read_to_string(file).unwrap().replace("\r\n", "\n");
+        assert_eq!(new_content, "if (x) {}\n");
+
+        // Write the file back.
+        fs::write(file, content_original).unwrap();
+    }
+
     #[test]
     fn test_print_config_ban_all_rules() {
         let args = &["-A", "all", "--print-config"];
@@ -877,6 +940,18 @@ mod test {
         Tester::new().test_and_snapshot(args);
     }
 
+    #[test]
+    fn test_print_config_promote() {
+        let args = &[
+            "-c",
+            "fixtures/print_config/promote/oxlintrc.json",
+            "-A",
+            "correctness",
+            "--print-config",
+        ];
+        Tester::new().test_and_snapshot(args);
+    }
+
     #[test]
     fn test_init_config() {
         assert!(!fs::exists(LintRunner::DEFAULT_OXLINTRC).unwrap());
@@ -1142,6 +1217,17 @@ mod test {
         Tester::new().with_cwd("fixtures/import-cycle".into()).test_and_snapshot(args);
     }
 
+    #[test]
+    fn test_workspace_roots() {
+        // `~/*` is a path alias of the `tsconfig.json` of `packages/a`
+        let args_1 =
+            &["--import-plugin", "-D", "import/no-cycle", "--workspace-root", "packages/a"];
+        let args_2 = &["--import-plugin", "-D", "import/no-cycle"];
+        Tester::new()
+            .with_cwd("fixtures/workspace_roots".into())
+            .test_and_snapshot_multiple(&[args_1, args_2]);
+    }
+
     #[test]
     fn test_rule_config_being_enabled_correctly() {
         let args = &["-c", ".oxlintrc.json"];
@@ -1,9 +1,9 @@
-use std::time::Duration;
+use std::{fmt::Write, time::Duration};
 
 use crate::output_formatter::InternalFormatter;
 use oxc_diagnostics::{
     Error, GraphicalReportHandler,
-    reporter::{DiagnosticReporter, DiagnosticResult},
+    reporter::{DiagnosticReporter, DiagnosticResult, DiagnosticStats, SeverityCounts},
 };
 use oxc_linter::table::RuleTable;
 
@@ -26,18 +26,24 @@ impl InternalFormatter for DefaultOutputFormatter {
     fn lint_command_info(&self, lint_command_info: &super::LintCommandInfo) -> Option<String> {
         let time = Self::get_execution_time(&lint_command_info.start_time);
         let s = if lint_command_info.number_of_files == 1 { "" } else { "s" };
+        let mut output = lint_command_info.stats.as_ref().map(render_stats).unwrap_or_default();
 
         if let Some(number_of_rules) = lint_command_info.number_of_rules {
-            Some(format!(
-                "Finished in {time} on {} file{s} with {} rules using {} threads.\n",
+            writeln!(
+                output,
+                "Finished in {time} on {} file{s} with {} rules using {} threads.",
                 lint_command_info.number_of_files, number_of_rules, lint_command_info.threads_count
-            ))
+            )
+            .unwrap();
         } else {
-            Some(format!(
-                "Finished in {time} on {} file{s} using {} threads.\n",
+            writeln!(
+                output,
+                "Finished in {time} on {} file{s} using {} threads.",
                 lint_command_info.number_of_files, lint_command_info.threads_count
-            ))
+            )
+            .unwrap();
         }
+        Some(output)
     }
 
     #[cfg(not(test))]
@@ -113,6 +119,33 @@ fn get_diagnostic_result_output(result: &DiagnosticResult) -> String {
     output
 }
 
+/// Tables of the counts per rule, most reported first, and per directory.
+fn render_stats(stats: &DiagnosticStats) -> String {
+    fn row(output: &mut String, counts: SeverityCounts, name: &str) {
+        writeln!(output, "{:>8}{:>10}  {name}", counts.errors, counts.warnings).unwrap();
+    }
+
+    let mut output = String::new();
+    let mut rules = stats.rules.iter().collect::<Vec<_>>();
+    rules.sort_by(|(a_name, a), (b_name, b)| b.total().cmp(&a.total()).then(a_name.cmp(b_name)));
+
+    output.push_str("\n  Errors  Warnings  Rule\n");
+    for (rule, counts) in rules {
+        row(&mut output, *counts, rule);
+    }
+    output.push_str("\n  Errors  Warnings  Directory\n");
+    for (directory, counts) in &stats.directories {
+        row(&mut output, *counts, directory);
+    }
+    row(&mut output, stats.total, "Total");
+    if stats.skipped_files > 0 {
+        writeln!(output, "\n  Skipped {} generated or minified files", stats.skipped_files)
+            .unwrap();
+    }
+    output.push('\n');
+    output
+}
+
 #[cfg(test)]
 mod test_implementation {
     use oxc_diagnostics::{
@@ -155,11 +188,13 @@ mod test_implementation {
 mod test {
     use std::time::Duration;
 
+    use std::path::Path;
+
     use crate::output_formatter::{
         InternalFormatter, LintCommandInfo,
         default::{DefaultOutputFormatter, GraphicalReporter},
     };
-    use oxc_diagnostics::reporter::{DiagnosticReporter, DiagnosticResult};
+    use oxc_diagnostics::reporter::{DiagnosticReporter, DiagnosticResult, DiagnosticStats};
 
     #[test]
     fn all_rules() {
@@ -177,6 +212,8 @@ mod test {
             number_of_rules: Some(10),
             threads_count: 12,
             start_time: Duration::new(1, 0),
+            stats: None,
+            promotions: Vec::new(),
         });
 
         assert!(result.is_some());
@@ -194,12 +231,52 @@ mod test {
             number_of_rules: None,
             threads_count: 12,
             start_time: Duration::new(1, 0),
+            stats: None,
+            promotions: Vec::new(),
         });
 
         assert!(result.is_some());
         assert_eq!(result.unwrap(), "Finished in 1.0s on 5 files using 12 threads.\n");
     }
 
+    #[test]
+    fn lint_command_info_stats() {
+        let mut stats = DiagnosticStats::default();
+        stats.record(Path::new("src/a.js"), Some("eslint(no-debugger)".into()), true);
+        stats.record(Path::new("src/b.js"), Some("eslint(no-unused-vars)".into()), false);
+        stats.record(Path::new("src/b.js"), Some("eslint(no-unused-vars)".into()), false);
+        stats.record(Path::new("c.js"), None, true);
+        stats.skipped_files = 2;
+
+        let formatter = DefaultOutputFormatter;
+        let result = formatter.lint_command_info(&LintCommandInfo {
+            number_of_files: 3,
+            number_of_rules: None,
+            threads_count: 1,
+            start_time: Duration::new(1, 0),
+            stats: Some(stats),
+            promotions: Vec::new(),
+        });
+
+        assert_eq!(
+            result.unwrap(),
+            "
+  Errors  Warnings  Rule
+       0         2  eslint(no-unused-vars)
+       1         0  eslint(no-debugger)
+
+  Errors  Warnings  Directory
+       1         0  .
+       1         2  src
+       2         2  Total
+
+  Skipped 2 generated or minified files
+
+Finished in 1.0s on 3 files using 1 threads.
+"
+        );
+    }
+
     #[test]
     fn reporter_finish_no_results() {
         let mut reporter = GraphicalReporter::default();
@@ -1,9 +1,10 @@
 use std::cell::RefCell;
+use std::collections::BTreeMap;
 use std::rc::Rc;
 
 use oxc_diagnostics::{
     Error,
-    reporter::{DiagnosticReporter, DiagnosticResult},
+    reporter::{DiagnosticReporter, DiagnosticResult, DiagnosticStats, SeverityCounts},
 };
 use oxc_linter::{RuleCategory, rules::RULES};
 
@@ -42,13 +43,24 @@ impl InternalFormatter for JsonOutputFormatter {
         let number_of_rules =
             lint_command_info.number_of_rules.map_or("null".to_string(), |x| x.to_string());
         let start_time = lint_command_info.start_time.as_secs_f64();
+        let stats = lint_command_info.stats.as_ref().map_or(String::new(), |stats| {
+            format!(",\n              \"stats\": {}", format_stats(stats))
+        });
+        let promotions = if lint_command_info.promotions.is_empty() {
+            String::new()
+        } else {
+            format!(
+                ",\n              \"promotions\": {}",
+                serde_json::to_string(&lint_command_info.promotions).unwrap()
+            )
+        };
 
         Some(format!(
             r#"{{ "diagnostics": {},
               "number_of_files": {},
               "number_of_rules": {},
               "threads_count": {},
-              "start_time": {}
+              "start_time": {}{}{}
             }}
             "#,
             diagnostics,
@@ -56,6 +68,8 @@ impl InternalFormatter for JsonOutputFormatter {
             number_of_rules,
             lint_command_info.threads_count,
             start_time,
+            stats,
+            promotions,
         ))
     }
 
@@ -118,14 +132,39 @@ fn format_json(diagnostics: &mut Vec<Error>) -> String {
     format!("[{messages}]")
 }
 
+/// The counts per rule and per directory, e.g.
+/// `{ "rules": { "eslint(no-debugger)": { "errors": 1, "warnings": 0 } }, "directories": .., "total": .. }`
+fn format_stats(stats: &DiagnosticStats) -> String {
+    fn counts(counts: SeverityCounts) -> serde_json::Value {
+        serde_json::json!({ "errors": counts.errors, "warnings": counts.warnings })
+    }
+    let by_name = |map: &BTreeMap<String, SeverityCounts>| {
+        map.iter().map(|(name, c)| (name.clone(), counts(*c))).collect::<serde_json::Map<_, _>>()
+    };
+    serde_json::json!({
+        "rules": by_name(&stats.rules),
+        "directories": by_name(&stats.directories),
+        "total": counts(stats.total),
+        "skipped_files": stats.skipped_files,
+    })
+    .to_string()
+}
+
 #[cfg(test)]
 mod test {
-    use std::time::Duration;
+    use std::{path::Path, time::Duration};
 
-    use oxc_diagnostics::{NamedSource, OxcDiagnostic, reporter::DiagnosticResult};
+    use oxc_diagnostics::{
+        NamedSource, OxcDiagnostic,
+        reporter::{DiagnosticResult, DiagnosticStats},
+    };
+    use oxc_linter::{ConfigStoreBuilder, Oxlintrc};
     use oxc_span::Span;
 
-    use crate::output_formatter::{InternalFormatter, LintCommandInfo, json::JsonOutputFormatter};
+    use crate::output_formatter::{
+        InternalFormatter, LintCommandInfo,
+        json::{JsonOutputFormatter, format_stats},
+    };
 
     #[test]
     fn reporter() {
@@ -151,6 +190,8 @@ mod test {
                 number_of_rules: Some(0),
                 start_time: Duration::new(0, 0),
                 threads_count: 1,
+                stats: None,
+                promotions: Vec::new(),
             })
             .unwrap();
         assert_eq!(
@@ -158,4 +199,55 @@ mod test {
             "{ \"diagnostics\": [{\"message\": \"error message\",\"severity\": \"warning\",\"causes\": [],\"filename\": \"file://test.ts\",\"labels\": [{\"span\": {\"offset\": 0,\"length\": 8,\"line\": 1,\"column\": 1}}],\"related\": []}],\n              \"number_of_files\": 0,\n              \"number_of_rules\": 0,\n              \"threads_count\": 1,\n              \"start_time\": 0\n            }\n            "
         );
     }
+
+    #[test]
+    fn promotions() {
+        let oxlintrc: Oxlintrc = serde_json::from_value(serde_json::json!({
+            "rules": { "eqeqeq": "warn" },
+            "promote": { "eqeqeq": { "from": "warn", "to": "error", "after": "2000-01-01" } },
+        }))
+        .unwrap();
+        let builder = ConfigStoreBuilder::from_oxlintrc(true, oxlintrc).unwrap();
+
+        let formatter = JsonOutputFormatter::default();
+        let output = formatter
+            .lint_command_info(&LintCommandInfo {
+                number_of_files: 0,
+                number_of_rules: Some(1),
+                start_time: Duration::new(0, 0),
+                threads_count: 1,
+                stats: None,
+                promotions: builder.promotions().to_vec(),
+            })
+            .unwrap();
+
+        let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+        assert_eq!(
+            json["promotions"],
+            serde_json::json!([
+                { "rule": "eqeqeq", "from": "warn", "to": "deny", "after": "2000-01-01", "active": true }
+            ])
+        );
+    }
+
+    #[test]
+    fn stats() {
+        let mut stats = DiagnosticStats::default();
+        stats.record(Path::new("src/a.js"), Some("eslint(no-debugger)".into()), true);
+        stats.record(Path::new("a.js"), Some("eslint(no-debugger)".into()), false);
+
+        let json: serde_json::Value = serde_json::from_str(&format_stats(&stats)).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "rules": { "eslint(no-debugger)": { "errors": 1, "warnings": 1 } },
+                "directories": {
+                    ".": { "errors": 0, "warnings": 1 },
+                    "src": { "errors": 1, "warnings": 0 },
+                },
+                "total": { "errors": 1, "warnings": 1 },
+                "skipped_files": 0,
+            })
+        );
+    }
 }
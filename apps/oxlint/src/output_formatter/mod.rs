@@ -18,7 +18,8 @@ use junit::JUnitOutputFormatter;
 use stylish::StylishOutputFormatter;
 use unix::UnixOutputFormatter;
 
-use oxc_diagnostics::reporter::DiagnosticReporter;
+use oxc_diagnostics::reporter::{DiagnosticReporter, DiagnosticStats};
+use oxc_linter::PromotionStatus;
 
 use crate::output_formatter::{default::DefaultOutputFormatter, json::JsonOutputFormatter};
 
@@ -66,6 +67,10 @@ pub struct LintCommandInfo {
     pub threads_count: usize,
     /// Some reporters want to output the duration it took to finished the task
     pub start_time: Duration,
+    /// The counts of the diagnostics per rule and per directory, when `--stats` is passed.
+    pub stats: Option<DiagnosticStats>,
+    /// The severity promotions of the configuration, with whether they are in effect.
+    pub promotions: Vec<PromotionStatus>,
 }
 
 /// An Interface for the different output formats.
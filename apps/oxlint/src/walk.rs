@@ -14,15 +14,26 @@ impl Default for Extensions {
     }
 }
 
+/// Files skipped during the traversal, besides the ignored ones.
+#[derive(Clone, Copy)]
+struct FileFilter {
+    /// Skip files larger than this many bytes.
+    max_file_size: Option<u64>,
+    /// Skip files named like minified files, e.g. `foo.min.js`.
+    skip_minified: bool,
+}
+
 pub struct Walk {
     inner: ignore::WalkParallel,
     /// The file extensions to include during the traversal.
     extensions: Extensions,
+    filter: FileFilter,
 }
 
 struct WalkBuilder {
     sender: mpsc::Sender<Vec<Arc<OsStr>>>,
     extensions: Extensions,
+    filter: FileFilter,
 }
 
 impl<'s> ignore::ParallelVisitorBuilder<'s> for WalkBuilder {
@@ -31,6 +42,7 @@ impl<'s> ignore::ParallelVisitorBuilder<'s> for WalkBuilder {
             paths: vec![],
             sender: self.sender.clone(),
             extensions: self.extensions.clone(),
+            filter: self.filter,
         })
     }
 }
@@ -39,6 +51,7 @@ struct WalkCollector {
     paths: Vec<Arc<OsStr>>,
     sender: mpsc::Sender<Vec<Arc<OsStr>>>,
     extensions: Extensions,
+    filter: FileFilter,
 }
 
 impl Drop for WalkCollector {
@@ -52,7 +65,7 @@ impl ignore::ParallelVisitor for WalkCollector {
     fn visit(&mut self, entry: Result<ignore::DirEntry, ignore::Error>) -> ignore::WalkState {
         match entry {
             Ok(entry) => {
-                if Walk::is_wanted_entry(&entry, &self.extensions) {
+                if Walk::is_wanted_entry(&entry, &self.extensions, self.filter) {
                     self.paths.push(entry.path().as_os_str().into());
                 }
                 ignore::WalkState::Continue
@@ -97,12 +110,16 @@ impl Walk {
         // * it is super rare to have symlinked source code
         let inner =
             inner.ignore(false).git_global(false).follow_links(options.symlinks).build_parallel();
-        Self { inner, extensions: Extensions::default() }
+        let filter = FileFilter {
+            max_file_size: (options.max_file_size > 0).then_some(options.max_file_size),
+            skip_minified: !options.no_ignore_generated,
+        };
+        Self { inner, extensions: Extensions::default(), filter }
     }
 
     pub fn paths(self) -> Vec<Arc<OsStr>> {
         let (sender, receiver) = mpsc::channel::<Vec<Arc<OsStr>>>();
-        let mut builder = WalkBuilder { sender, extensions: self.extensions };
+        let mut builder = WalkBuilder { sender, extensions: self.extensions, filter: self.filter };
         self.inner.visit(&mut builder);
         drop(builder);
         receiver.into_iter().flatten().collect()
@@ -114,18 +131,26 @@ impl Walk {
         self
     }
 
-    fn is_wanted_entry(dir_entry: &DirEntry, extensions: &Extensions) -> bool {
+    fn is_wanted_entry(dir_entry: &DirEntry, extensions: &Extensions, filter: FileFilter) -> bool {
         let Some(file_type) = dir_entry.file_type() else { return false };
         if file_type.is_dir() {
             return false;
         }
         let Some(file_name) = dir_entry.path().file_name() else { return false };
-        if [".min.", "-min.", "_min."].iter().any(|e| file_name.to_string_lossy().contains(e)) {
+        if filter.skip_minified
+            && [".min.", "-min.", "_min."].iter().any(|e| file_name.to_string_lossy().contains(e))
+        {
             return false;
         }
         let Some(extension) = dir_entry.path().extension() else { return false };
         let extension = extension.to_string_lossy();
-        extensions.0.contains(&extension.as_ref())
+        if !extensions.0.contains(&extension.as_ref()) {
+            return false;
+        }
+        // Checked last, as it needs a `stat` call on most platforms.
+        filter.max_file_size.is_none_or(|max_file_size| {
+            dir_entry.metadata().is_ok_and(|metadata| metadata.len() <= max_file_size)
+        })
     }
 }
 
@@ -136,23 +161,15 @@ mod test {
     use ignore::overrides::OverrideBuilder;
 
     use super::{Extensions, Walk};
-    use crate::cli::IgnoreOptions;
+    use crate::cli::{DEFAULT_MAX_FILE_SIZE, IgnoreOptions};
 
-    #[test]
-    fn test_walk_with_extensions() {
+    fn walk(ignore_options: &IgnoreOptions, extensions: &[&'static str]) -> Vec<String> {
         let fixture = env::current_dir().unwrap().join("fixtures/walk_dir");
         let fixtures = vec![fixture.clone()];
-        let ignore_options = IgnoreOptions {
-            no_ignore: false,
-            ignore_path: OsString::from(".gitignore"),
-            ignore_pattern: vec![],
-            symlinks: false,
-        };
-
         let override_builder = OverrideBuilder::new("/").build().unwrap();
 
-        let mut paths = Walk::new(&fixtures, &ignore_options, Some(override_builder))
-            .with_extensions(Extensions(["js", "vue"].to_vec()))
+        let mut paths = Walk::new(&fixtures, ignore_options, Some(override_builder))
+            .with_extensions(Extensions(extensions.to_vec()))
             .paths()
             .into_iter()
             .map(|path| {
@@ -160,7 +177,32 @@ mod test {
             })
             .collect::<Vec<_>>();
         paths.sort();
+        paths
+    }
+
+    fn ignore_options() -> IgnoreOptions {
+        IgnoreOptions {
+            no_ignore: false,
+            ignore_path: OsString::from(".gitignore"),
+            ignore_pattern: vec![],
+            symlinks: false,
+            max_file_size: DEFAULT_MAX_FILE_SIZE,
+            no_ignore_generated: false,
+        }
+    }
+
+    #[test]
+    fn test_walk_with_extensions() {
+        assert_eq!(walk(&ignore_options(), &["js", "vue"]), vec!["bar.vue", "foo.js"]);
+    }
+
+    #[test]
+    fn test_walk_generated_and_large_files() {
+        let options = IgnoreOptions { no_ignore_generated: true, ..ignore_options() };
+        assert_eq!(walk(&options, &["js"]), vec!["foo.js", "foo.min.js"]);
 
-        assert_eq!(paths, vec!["bar.vue", "foo.js"]);
+        // `foo.js` is 37 bytes, `bar.vue` is 88 bytes
+        let options = IgnoreOptions { max_file_size: 40, ..ignore_options() };
+        assert_eq!(walk(&options, &["js", "vue"]), vec!["foo.js"]);
     }
 }
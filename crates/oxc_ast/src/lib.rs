@@ -50,6 +50,7 @@ pub mod ast;
 mod ast_builder_impl;
 mod ast_impl;
 mod ast_kind_impl;
+pub mod pragma;
 pub mod precedence;
 mod trivia;
 
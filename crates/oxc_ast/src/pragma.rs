@@ -0,0 +1,333 @@
+//! Pragmas in comments which change how a file is parsed, transformed or linted.
+//!
+//! * `@jsx Preact.h`
+//! * `@jsxRuntime classic` / `@jsxRuntime automatic`
+//! * `@jsxImportSource custom-jsx-library`
+//! * `@jsxFrag Preact.Fragment`
+//! * `@flow`
+//! * `@ts-check` / `@ts-nocheck`
+//!
+//! This module is the single place pragmas are recognized, so the parser, transformer and linter
+//! agree on which comments contain them.
+
+use memchr::memchr;
+
+use crate::ast::comment::Comment;
+
+/// Kind of pragma, the keyword following `@`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PragmaKind {
+    /// `@jsx`
+    Jsx,
+    /// `@jsxRuntime`
+    JsxRuntime,
+    /// `@jsxImportSource`
+    JsxImportSource,
+    /// `@jsxFrag`
+    JsxFrag,
+    /// `@flow`
+    Flow,
+    /// `@ts-check`
+    TsCheck,
+    /// `@ts-nocheck`
+    TsNocheck,
+}
+
+impl PragmaKind {
+    fn from_keyword(keyword: &str) -> Option<Self> {
+        Some(match keyword {
+            "jsx" => Self::Jsx,
+            "jsxRuntime" => Self::JsxRuntime,
+            "jsxImportSource" => Self::JsxImportSource,
+            "jsxFrag" => Self::JsxFrag,
+            "flow" => Self::Flow,
+            "ts-check" => Self::TsCheck,
+            "ts-nocheck" => Self::TsNocheck,
+            _ => return None,
+        })
+    }
+
+    /// Whether the pragma requires a value, e.g. `h` in `@jsx h`.
+    fn has_value(self) -> bool {
+        matches!(self, Self::Jsx | Self::JsxRuntime | Self::JsxImportSource | Self::JsxFrag)
+    }
+}
+
+/// Pragmas of a file, found by [`Pragmas::from_comments`].
+///
+/// When a pragma appears several times, the last one wins.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct Pragmas<'a> {
+    /// Value of `@jsx`, e.g. `Preact.h`.
+    pub jsx: Option<&'a str>,
+    /// Value of `@jsxRuntime`, `classic` or `automatic`. Other values are ignored.
+    pub jsx_runtime: Option<&'a str>,
+    /// Value of `@jsxImportSource`, e.g. `preact`.
+    pub jsx_import_source: Option<&'a str>,
+    /// Value of `@jsxFrag`, e.g. `Preact.Fragment`.
+    pub jsx_frag: Option<&'a str>,
+    /// `@flow` in the first comment of the file.
+    pub flow: bool,
+    /// `// @ts-check` in a line comment.
+    pub ts_check: bool,
+    /// `// @ts-nocheck` in a line comment.
+    pub ts_nocheck: bool,
+}
+
+impl<'a> Pragmas<'a> {
+    /// Scan all comments of a file for pragmas.
+    ///
+    /// JSX pragmas are recognized in any comment, and the comment does not need to be a JSDoc comment.
+    /// Several pragmas can appear in a single comment, e.g. `/** @jsx h @jsxRuntime classic */`.
+    /// This behavior is aligned with ESBuild.
+    /// Babel is less liberal - it doesn't accept multiple pragmas in a single line.
+    /// <https://github.com/oxc-project/oxc/issues/10955>
+    ///
+    /// `@flow` is only recognized in the first comment, and `@ts-check` / `@ts-nocheck` only in
+    /// line comments, like Flow and TypeScript do.
+    pub fn from_comments(comments: &[Comment], source_text: &'a str) -> Self {
+        let mut pragmas = Self::default();
+        for (i, comment) in comments.iter().enumerate() {
+            let mut comment_str = comment.content_span().source_text(source_text);
+            while let Some((kind, value, remainder)) = find_pragma(comment_str) {
+                match kind {
+                    PragmaKind::Jsx => pragmas.jsx = Some(value),
+                    PragmaKind::JsxRuntime => {
+                        if matches!(value, "classic" | "automatic") {
+                            pragmas.jsx_runtime = Some(value);
+                        }
+                    }
+                    PragmaKind::JsxImportSource => pragmas.jsx_import_source = Some(value),
+                    PragmaKind::JsxFrag => pragmas.jsx_frag = Some(value),
+                    PragmaKind::Flow => pragmas.flow |= i == 0,
+                    PragmaKind::TsCheck => pragmas.ts_check |= comment.is_line(),
+                    PragmaKind::TsNocheck => pragmas.ts_nocheck |= comment.is_line(),
+                }
+                // Search again for another pragma
+                comment_str = remainder;
+            }
+        }
+        pragmas
+    }
+
+    /// Whether JSX uses the automatic runtime according to the pragmas,
+    /// `None` if there is no `@jsxRuntime` or `@jsxImportSource` pragma.
+    ///
+    /// `@jsxImportSource` implies the automatic runtime.
+    pub fn is_automatic_jsx_runtime(&self) -> Option<bool> {
+        match self.jsx_runtime {
+            Some("automatic") => Some(true),
+            Some("classic") => Some(false),
+            _ => self.jsx_import_source.map(|_| true),
+        }
+    }
+}
+
+/// Search comment for a pragma.
+///
+/// If found, returns:
+///
+/// * [`PragmaKind`] representing the kind of the pragma.
+/// * Value following `@jsx` / `@jsxRuntime` / etc. Empty for pragmas without a value, e.g. `@flow`.
+/// * The remainder of the comment, to search again for another pragma.
+///
+/// If no pragma found, returns `None`.
+pub fn find_pragma(mut comment_str: &str) -> Option<(PragmaKind, &str, &str)> {
+    let kind = loop {
+        // Search for `@`.
+        // Note: Using `memchr::memmem::Finder` to search for keywords is slower than only using `memchr`
+        // to find `@` characters, and then checking the keyword separately.
+        let at_sign_index = memchr(b'@', comment_str.as_bytes())?;
+        // SAFETY: Byte at `at_sign_index` is `@`, so `at_sign_index + 1` is either within string
+        // or end of string, and on a UTF-8 char boundary.
+        comment_str = unsafe { comment_str.get_unchecked(at_sign_index + 1..) };
+
+        // Get keyword e.g. `jsxRuntime` in `@jsxRuntime`
+        let keyword_len = comment_str
+            .as_bytes()
+            .iter()
+            .position(|&b| !(b.is_ascii_alphanumeric() || b == b'-'))
+            .unwrap_or(comment_str.len());
+        // SAFETY: Bytes before `keyword_len` are ASCII, so `keyword_len` is on a UTF-8 char boundary
+        let (keyword_str, rest) = unsafe {
+            (comment_str.get_unchecked(..keyword_len), comment_str.get_unchecked(keyword_len..))
+        };
+        let Some(kind) = PragmaKind::from_keyword(keyword_str) else {
+            // Unrecognised pragma - search for another
+            continue;
+        };
+
+        let next_byte = rest.as_bytes().first().copied();
+        if kind.has_value() {
+            // Value must be separated from keyword by a space or tab
+            if matches!(next_byte, Some(b' ' | b'\t')) {
+                comment_str = rest;
+                break kind;
+            }
+        } else if next_byte.is_none_or(is_ascii_whitespace) {
+            return Some((kind, "", rest));
+        }
+        comment_str = rest;
+    };
+
+    // Consume any spaces / tabs after keyword
+    comment_str = comment_str.trim_start_matches([' ', '\t']);
+
+    // Get value
+    let space_index = comment_str.as_bytes().iter().position(|&b| is_ascii_whitespace(b));
+    let value;
+    if let Some(space_index) = space_index {
+        // SAFETY: Byte at `space_index` is ASCII, so `space_index` is in bounds and on a UTF-8 char boundary
+        value = unsafe { comment_str.get_unchecked(..space_index) };
+        // SAFETY: Byte at `space_index` is ASCII, so `space_index + 1` is in bounds and on a UTF-8 char boundary
+        comment_str = unsafe { comment_str.get_unchecked(space_index + 1..) };
+    } else {
+        value = comment_str;
+        comment_str = "";
+    }
+
+    if value.is_empty() { None } else { Some((kind, value, comment_str)) }
+}
+
+/// Test if a byte is ASCII whitespace, using the same group of ASCII chars that `std::str::trim_start` uses.
+/// These the are ASCII chars which `char::is_whitespace` returns `true` for.
+/// Note: Slightly different from `u8::is_ascii_whitespace`, which does not include VT.
+/// <https://doc.rust-lang.org/std/primitive.u8.html#method.is_ascii_whitespace>
+#[inline]
+fn is_ascii_whitespace(byte: u8) -> bool {
+    const VT: u8 = 0x0B;
+    const FF: u8 = 0x0C;
+    matches!(byte, b' ' | b'\t' | b'\r' | b'\n' | VT | FF)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ast::comment::CommentKind;
+
+    #[test]
+    fn test_find_pragma() {
+        let cases: &[(&str, &[(PragmaKind, &str)])] = &[
+            // No valid pragmas
+            ("", &[]),
+            ("blah blah blah", &[]),
+            ("@jsxDonkey abc", &[]),
+            ("@flowtype", &[]),
+            ("@ts-nocheckx", &[]),
+            // Single pragma
+            ("@jsx h", &[(PragmaKind::Jsx, "h")]),
+            ("@jsx React.createDumpling", &[(PragmaKind::Jsx, "React.createDumpling")]),
+            ("@jsxRuntime classic", &[(PragmaKind::JsxRuntime, "classic")]),
+            ("@jsxImportSource preact", &[(PragmaKind::JsxImportSource, "preact")]),
+            ("@jsxFrag Fraggy", &[(PragmaKind::JsxFrag, "Fraggy")]),
+            ("@flow", &[(PragmaKind::Flow, "")]),
+            ("@flow strict", &[(PragmaKind::Flow, "")]),
+            ("@ts-nocheck", &[(PragmaKind::TsNocheck, "")]),
+            ("@ts-check because", &[(PragmaKind::TsCheck, "")]),
+            // Multiple pragmas
+            (
+                "@jsx h @jsxRuntime classic",
+                &[(PragmaKind::Jsx, "h"), (PragmaKind::JsxRuntime, "classic")],
+            ),
+            (
+                "* @jsx h\n  * @jsxRuntime classic\n  *",
+                &[(PragmaKind::Jsx, "h"), (PragmaKind::JsxRuntime, "classic")],
+            ),
+            (
+                "@jsx h @jsxRuntime classic @jsxImportSource importer-a-go-go @jsxFrag F",
+                &[
+                    (PragmaKind::Jsx, "h"),
+                    (PragmaKind::JsxRuntime, "classic"),
+                    (PragmaKind::JsxImportSource, "importer-a-go-go"),
+                    (PragmaKind::JsxFrag, "F"),
+                ],
+            ),
+            (
+                "* @jsx h\n  * @jsxRuntime classic\n  * @jsxImportSource importer-a-go-go\n  * @jsxFrag F\n  *",
+                &[
+                    (PragmaKind::Jsx, "h"),
+                    (PragmaKind::JsxRuntime, "classic"),
+                    (PragmaKind::JsxImportSource, "importer-a-go-go"),
+                    (PragmaKind::JsxFrag, "F"),
+                ],
+            ),
+            ("@flow @jsx h", &[(PragmaKind::Flow, ""), (PragmaKind::Jsx, "h")]),
+            // Text in between pragmas
+            (
+                "@jsx h blah blah @jsxRuntime classic",
+                &[(PragmaKind::Jsx, "h"), (PragmaKind::JsxRuntime, "classic")],
+            ),
+            (
+                "blah blah\n  * @jsx h \n  * blah blah\n  * @jsxRuntime classic \n  * blah blah",
+                &[(PragmaKind::Jsx, "h"), (PragmaKind::JsxRuntime, "classic")],
+            ),
+            // Pragma without value
+            ("@jsx", &[]),
+            ("@jsxRuntime", &[]),
+            // Other invalid pragmas surrounding valid one
+            ("@moon @jsx h @moon", &[(PragmaKind::Jsx, "h")]),
+            ("@jsxX @jsx h @jsxX", &[(PragmaKind::Jsx, "h")]),
+            ("@jsxMoon @jsx h @jsxMoon", &[(PragmaKind::Jsx, "h")]),
+            ("@jsx @jsx h", &[(PragmaKind::Jsx, "@jsx")]),
+            // Multiple `@` signs
+            ("@@@@@jsx h", &[(PragmaKind::Jsx, "h")]),
+        ];
+
+        let prefixes = ["", "    ", "\n\n", "*\n* "];
+        let postfixes = ["", "    ", "\n\n", "\n*"];
+
+        for (comment_str, expected) in cases {
+            for prefix in prefixes {
+                for postfix in postfixes {
+                    let comment_str = format!("{prefix}{comment_str}{postfix}");
+                    let mut comment_str = comment_str.as_str();
+                    let mut pragmas = vec![];
+                    while let Some((kind, value, remaining)) = find_pragma(comment_str) {
+                        pragmas.push((kind, value));
+                        comment_str = remaining;
+                    }
+                    assert_eq!(&pragmas, expected, "{comment_str:?}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_pragmas_from_comments() {
+        let comment_texts = [
+            "/* @flow @ts-nocheck */",
+            "// @ts-check",
+            "/** @jsx h */",
+            "/* @jsx React.h @jsxImportSource preact @jsxRuntime other */",
+            "// @flow",
+        ];
+        let source_text = comment_texts.join("\n");
+        let mut start = 0;
+        let comments = comment_texts
+            .iter()
+            .map(|text| {
+                let kind =
+                    if text.starts_with("//") { CommentKind::Line } else { CommentKind::Block };
+                let len = u32::try_from(text.len()).unwrap();
+                let comment = Comment::new(start, start + len, kind);
+                start += len + 1;
+                comment
+            })
+            .collect::<Vec<_>>();
+
+        let pragmas = Pragmas::from_comments(&comments, &source_text);
+        assert_eq!(
+            pragmas,
+            Pragmas {
+                jsx: Some("React.h"),
+                jsx_runtime: None,
+                jsx_import_source: Some("preact"),
+                jsx_frag: None,
+                flow: true,
+                ts_check: true,
+                ts_nocheck: false,
+            }
+        );
+        assert_eq!(pragmas.is_automatic_jsx_runtime(), Some(true));
+    }
+}
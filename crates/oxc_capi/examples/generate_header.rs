@@ -0,0 +1,18 @@
+#![expect(clippy::print_stdout)]
+//! Generate `include/oxc.h` from the `extern "C"` functions of `src/lib.rs`.
+//!
+//! ```bash
+//! cargo run -p oxc_capi --example generate_header
+//! ```
+
+use std::{fs, path::Path};
+
+fn main() -> Result<(), String> {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+    let source_text = fs::read_to_string(dir.join("src/lib.rs")).map_err(|err| err.to_string())?;
+    let header = oxc_capi::generate_header(&source_text)?;
+    let path = dir.join("include/oxc.h");
+    fs::write(&path, header).map_err(|err| err.to_string())?;
+    println!("Wrote {}", path.display());
+    Ok(())
+}
@@ -0,0 +1,74 @@
+// Auto-generated by `cargo run -p oxc_capi --example generate_header`. Do not edit.
+
+// Strings returned by these functions are JSON, owned by the caller, and must be freed with
+// `oxc_free_string`. NULL is returned if an argument is NULL or not valid UTF-8.
+// Spans are UTF-8 byte offsets into the source text.
+
+#ifndef OXC_H
+#define OXC_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/**
+ * Parse `source_text` to an ESTree AST.
+ *
+ * Returns `{ "program": Program, "errors": Error[] }`. TypeScript files, by the extension of
+ * `filename`, are parsed to a TS-ESTree AST.
+ *
+ * # Safety
+ *
+ * `filename` must be NULL or a NUL-terminated string, and `source_text` NULL or valid for reads
+ * of `source_len` bytes.
+ */
+char *oxc_parse(const char *filename, const uint8_t *source_text, size_t source_len);
+
+/**
+ * Transform `source_text`, e.g. strip TypeScript types and lower syntax to `target`.
+ *
+ * `options` is NULL or a JSON object `{ "target"?: string, "sourcemap"?: boolean }`, where
+ * `target` is e.g. `"es2015"` or `"chrome80"`.
+ *
+ * Returns `{ "code": string, "map"?: SourceMap, "errors": Error[] }`.
+ *
+ * # Safety
+ *
+ * `filename` must be NULL or a NUL-terminated string, `source_text` NULL or valid for reads of
+ * `source_len` bytes, and `options` NULL or a NUL-terminated string.
+ */
+char *oxc_transform(const char *filename, const uint8_t *source_text, size_t source_len, const char *options);
+
+/**
+ * Lint `source_text`.
+ *
+ * `config` is NULL for the default rules, or the contents of an `.oxlintrc.json` file.
+ *
+ * Returns `{ "errors": Error[] }`, with the syntax errors if the file can not be parsed.
+ *
+ * Only available with the `linter` feature, which is enabled by default.
+ *
+ * # Safety
+ *
+ * `filename` must be NULL or a NUL-terminated string, `source_text` NULL or valid for reads of
+ * `source_len` bytes, and `config` NULL or a NUL-terminated string.
+ */
+char *oxc_lint(const char *filename, const uint8_t *source_text, size_t source_len, const char *config);
+
+/**
+ * Free a string returned by this library. Does nothing if `string` is NULL.
+ *
+ * # Safety
+ *
+ * `string` must be NULL or a string returned by this library, which is not used after this call.
+ */
+void oxc_free_string(char *string);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* OXC_H */
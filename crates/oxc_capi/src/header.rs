@@ -0,0 +1,121 @@
+//! Generates the C header of the `extern "C"` functions of this crate.
+
+const PREAMBLE: &str = "\
+// Auto-generated by `cargo run -p oxc_capi --example generate_header`. Do not edit.
+
+// Strings returned by these functions are JSON, owned by the caller, and must be freed with
+// `oxc_free_string`. NULL is returned if an argument is NULL or not valid UTF-8.
+// Spans are UTF-8 byte offsets into the source text.
+
+#ifndef OXC_H
+#define OXC_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern \"C\" {
+#endif
+";
+
+const POSTAMBLE: &str = "
+#ifdef __cplusplus
+}
+#endif
+
+#endif /* OXC_H */
+";
+
+/// Generate the C header declaring the `pub unsafe extern "C"` functions of `source_text`, the
+/// source of this crate's `lib.rs`, with their doc comments.
+///
+/// # Errors
+///
+/// A parameter or return type has no C equivalent.
+pub fn generate_header(source_text: &str) -> Result<String, String> {
+    let mut header = String::from(PREAMBLE);
+    let mut docs = vec![];
+    let mut lines = source_text.lines();
+    while let Some(line) = lines.next() {
+        let line = line.trim();
+        if let Some(doc) = line.strip_prefix("///") {
+            docs.push(doc.strip_prefix(' ').unwrap_or(doc));
+            continue;
+        }
+        if line.starts_with("#[") {
+            continue;
+        }
+        let Some(signature) = line.strip_prefix("pub unsafe extern \"C\" fn ") else {
+            docs.clear();
+            continue;
+        };
+        // rustfmt puts the parameters on separate lines when they don't fit.
+        let mut signature = signature.to_string();
+        while !signature.ends_with('{') {
+            let Some(line) = lines.next() else { break };
+            signature.push_str(line.trim());
+        }
+        header.push('\n');
+        write_docs(&mut header, &docs);
+        header.push_str(&declaration(&signature)?);
+        header.push('\n');
+        docs.clear();
+    }
+    header.push_str(POSTAMBLE);
+    Ok(header)
+}
+
+fn write_docs(header: &mut String, docs: &[&str]) {
+    if docs.is_empty() {
+        return;
+    }
+    header.push_str("/**\n");
+    for doc in docs {
+        header.push_str(if doc.is_empty() { " *" } else { " * " });
+        header.push_str(doc);
+        header.push('\n');
+    }
+    header.push_str(" */\n");
+}
+
+/// `name(param: Type, ...) -> Type {` to `Type name(Type param, ...);`
+fn declaration(signature: &str) -> Result<String, String> {
+    let signature = signature.trim_end_matches('{').trim();
+    let (name, rest) =
+        signature.split_once('(').ok_or_else(|| format!("Invalid signature `{signature}`"))?;
+    let (params, ret) =
+        rest.rsplit_once(')').ok_or_else(|| format!("Invalid signature `{signature}`"))?;
+    let ret = match ret.trim().strip_prefix("->") {
+        Some(ret) => c_type(ret.trim())?,
+        None => "void",
+    };
+    let params = params
+        .split(',')
+        .map(str::trim)
+        .filter(|param| !param.is_empty())
+        .map(|param| {
+            let (name, ty) =
+                param.split_once(':').ok_or_else(|| format!("Invalid parameter `{param}`"))?;
+            Ok(format!("{}{}", c_type_prefix(c_type(ty.trim())?), name.trim()))
+        })
+        .collect::<Result<Vec<_>, String>>()?;
+    let params = if params.is_empty() { "void".to_string() } else { params.join(", ") };
+    Ok(format!("{}{name}({params});", c_type_prefix(ret)))
+}
+
+fn c_type(ty: &str) -> Result<&'static str, String> {
+    Ok(match ty {
+        "*const c_char" => "const char *",
+        "*mut c_char" => "char *",
+        "*const u8" => "const uint8_t *",
+        "usize" => "size_t",
+        "bool" => "bool",
+        "u32" => "uint32_t",
+        _ => return Err(format!("Type `{ty}` has no C equivalent")),
+    })
+}
+
+/// A C type followed by a name, without a space after `*`.
+fn c_type_prefix(ty: &str) -> String {
+    if ty.ends_with('*') { ty.to_string() } else { format!("{ty} ") }
+}
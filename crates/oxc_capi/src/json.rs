@@ -0,0 +1,180 @@
+//! The operations of the C API, with their results serialized to JSON.
+
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use oxc::{
+    allocator::Allocator,
+    codegen::{Codegen, CodegenOptions},
+    diagnostics::{OxcDiagnostic, Severity},
+    parser::Parser,
+    semantic::SemanticBuilder,
+    span::SourceType,
+    transformer::{TransformOptions as TransformerOptions, Transformer},
+};
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Error {
+    severity: &'static str,
+    message: String,
+    labels: Vec<ErrorLabel>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    help_message: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct ErrorLabel {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    message: Option<String>,
+    start: usize,
+    end: usize,
+}
+
+impl Error {
+    pub fn new(message: String) -> Self {
+        Self { severity: "Error", message, labels: vec![], help_message: None }
+    }
+}
+
+impl From<&OxcDiagnostic> for Error {
+    fn from(diagnostic: &OxcDiagnostic) -> Self {
+        let labels = diagnostic
+            .labels
+            .iter()
+            .flatten()
+            .map(|label| ErrorLabel {
+                message: label.label().map(ToString::to_string),
+                start: label.offset(),
+                end: label.offset() + label.len(),
+            })
+            .collect();
+        Self {
+            severity: match diagnostic.severity {
+                Severity::Error => "Error",
+                Severity::Warning => "Warning",
+                Severity::Advice => "Advice",
+            },
+            message: diagnostic.message.to_string(),
+            labels,
+            help_message: diagnostic.help.as_ref().map(ToString::to_string),
+        }
+    }
+}
+
+fn to_errors(diagnostics: &[OxcDiagnostic]) -> Vec<Error> {
+    diagnostics.iter().map(Error::from).collect()
+}
+
+/// `{ "errors": Error[] }`
+pub fn errors(errors: &[Error]) -> String {
+    #[derive(Serialize)]
+    struct Errors<'a> {
+        errors: &'a [Error],
+    }
+    serde_json::to_string(&Errors { errors }).unwrap()
+}
+
+fn source_type(filename: &Path) -> SourceType {
+    SourceType::from_path(filename).unwrap_or_default()
+}
+
+pub fn parse(filename: &Path, source_text: &str) -> String {
+    let allocator = Allocator::default();
+    let source_type = source_type(filename);
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    let program = if source_type.is_typescript() {
+        ret.program.to_estree_ts_json(false)
+    } else {
+        ret.program.to_estree_js_json(false)
+    };
+    let errors = serde_json::to_string(&to_errors(&ret.errors)).unwrap();
+    // The program is serialized already.
+    format!("{{\"program\":{program},\"errors\":{errors}}}")
+}
+
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TransformOptions {
+    target: Option<String>,
+    #[serde(default)]
+    sourcemap: bool,
+}
+
+#[derive(Debug, Serialize)]
+struct TransformResult {
+    code: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    map: Option<serde_json::Value>,
+    errors: Vec<Error>,
+}
+
+pub fn transform(filename: &Path, source_text: &str, options: &TransformOptions) -> String {
+    let transformer_options = match options.target.as_deref().map(TransformerOptions::from_target) {
+        Some(Ok(options)) => options,
+        Some(Err(error)) => return self::errors(&[Error::new(error)]),
+        None => TransformerOptions::default(),
+    };
+
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type(filename)).parse();
+    let mut program = ret.program;
+    let mut diagnostics = ret.errors;
+    let (code, map) = if ret.panicked {
+        (String::new(), None)
+    } else {
+        let scoping = SemanticBuilder::new().build(&program).semantic.into_scoping();
+        let ret = Transformer::new(&allocator, filename, &transformer_options)
+            .build_with_scoping(scoping, &mut program);
+        diagnostics.extend(ret.errors);
+        let ret = Codegen::new()
+            .with_options(CodegenOptions {
+                source_map_path: options.sourcemap.then(|| filename.to_path_buf()),
+                ..CodegenOptions::default()
+            })
+            .build(&program);
+        let map = ret.map.map(|map| serde_json::from_str(&map.to_json_string()).unwrap());
+        (ret.code, map)
+    };
+    serde_json::to_string(&TransformResult { code, map, errors: to_errors(&diagnostics) }).unwrap()
+}
+
+#[cfg(feature = "linter")]
+pub fn lint(filename: &Path, source_text: &str, config: Option<&str>) -> String {
+    use std::{rc::Rc, sync::Arc};
+
+    use oxc_linter::{
+        ConfigStore, ConfigStoreBuilder, LintOptions, Linter, ModuleRecord, Oxlintrc,
+    };
+    use rustc_hash::FxHashMap;
+
+    let config_builder = match config {
+        Some(config) => match Oxlintrc::from_string(config).and_then(|oxlintrc| {
+            ConfigStoreBuilder::from_oxlintrc(false, oxlintrc)
+                .map_err(|err| OxcDiagnostic::error(format!("Invalid linter config: {err}")))
+        }) {
+            Ok(config_builder) => config_builder,
+            Err(error) => return errors(&[Error::from(&error)]),
+        },
+        None => ConfigStoreBuilder::default(),
+    };
+    let linter = Linter::new(
+        LintOptions::default(),
+        ConfigStore::new(config_builder.build(), FxHashMap::default()),
+    );
+
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type(filename)).parse();
+    if !ret.errors.is_empty() {
+        return errors(&to_errors(&ret.errors));
+    }
+    let semantic_ret = SemanticBuilder::new().with_cfg(true).build(&ret.program);
+    if !semantic_ret.errors.is_empty() {
+        return errors(&to_errors(&semantic_ret.errors));
+    }
+    let module_record =
+        Arc::new(ModuleRecord::new(filename, &ret.module_record, &semantic_ret.semantic));
+    let messages = linter.run(filename, Rc::new(semantic_ret.semantic), module_record);
+    errors(&messages.iter().map(|message| Error::from(&message.error)).collect::<Vec<_>>())
+}
@@ -0,0 +1,168 @@
+//! C API of Oxc, for embedding Oxc in hosts other than Node.js, e.g. the JVM, .NET, or Python,
+//! without spawning processes.
+//!
+//! Functions take the source text as UTF-8 bytes with their length, and other strings as
+//! NUL-terminated UTF-8. They return a NUL-terminated JSON string owned by the caller, which must
+//! be freed with `oxc_free_string`, or NULL if an argument is NULL or not valid UTF-8.
+//!
+//! Spans, e.g. `start` and `end` of AST nodes and error labels, are UTF-8 byte offsets into the
+//! source text.
+//!
+//! `include/oxc.h` is generated from the declarations of this file with
+//! `cargo run -p oxc_capi --example generate_header`.
+
+mod header;
+mod json;
+
+use std::{
+    ffi::{CStr, CString, c_char},
+    path::Path,
+    ptr,
+};
+
+pub use header::generate_header;
+
+use crate::json::{Error, TransformOptions};
+
+/// Parse `source_text` to an ESTree AST.
+///
+/// Returns `{ "program": Program, "errors": Error[] }`. TypeScript files, by the extension of
+/// `filename`, are parsed to a TS-ESTree AST.
+///
+/// # Safety
+///
+/// `filename` must be NULL or a NUL-terminated string, and `source_text` NULL or valid for reads
+/// of `source_len` bytes.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn oxc_parse(
+    filename: *const c_char,
+    source_text: *const u8,
+    source_len: usize,
+) -> *mut c_char {
+    // SAFETY: Guaranteed by the caller.
+    let Some((filename, source_text)) = (unsafe { args(filename, source_text, source_len) }) else {
+        return ptr::null_mut();
+    };
+    into_c_string(json::parse(filename, source_text))
+}
+
+/// Transform `source_text`, e.g. strip TypeScript types and lower syntax to `target`.
+///
+/// `options` is NULL or a JSON object `{ "target"?: string, "sourcemap"?: boolean }`, where
+/// `target` is e.g. `"es2015"` or `"chrome80"`.
+///
+/// Returns `{ "code": string, "map"?: SourceMap, "errors": Error[] }`.
+///
+/// # Safety
+///
+/// `filename` must be NULL or a NUL-terminated string, `source_text` NULL or valid for reads of
+/// `source_len` bytes, and `options` NULL or a NUL-terminated string.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn oxc_transform(
+    filename: *const c_char,
+    source_text: *const u8,
+    source_len: usize,
+    options: *const c_char,
+) -> *mut c_char {
+    // SAFETY: Guaranteed by the caller.
+    let Some((filename, source_text)) = (unsafe { args(filename, source_text, source_len) }) else {
+        return ptr::null_mut();
+    };
+    let options = if options.is_null() {
+        TransformOptions::default()
+    } else {
+        // SAFETY: Guaranteed by the caller.
+        let Some(options) = (unsafe { c_str(options) }) else { return ptr::null_mut() };
+        match serde_json::from_str(options) {
+            Ok(options) => options,
+            Err(error) => {
+                return into_c_string(json::errors(&[Error::new(format!(
+                    "Invalid transform options: {error}"
+                ))]));
+            }
+        }
+    };
+    into_c_string(json::transform(filename, source_text, &options))
+}
+
+/// Lint `source_text`.
+///
+/// `config` is NULL for the default rules, or the contents of an `.oxlintrc.json` file.
+///
+/// Returns `{ "errors": Error[] }`, with the syntax errors if the file can not be parsed.
+///
+/// Only available with the `linter` feature, which is enabled by default.
+///
+/// # Safety
+///
+/// `filename` must be NULL or a NUL-terminated string, `source_text` NULL or valid for reads of
+/// `source_len` bytes, and `config` NULL or a NUL-terminated string.
+#[cfg(feature = "linter")]
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn oxc_lint(
+    filename: *const c_char,
+    source_text: *const u8,
+    source_len: usize,
+    config: *const c_char,
+) -> *mut c_char {
+    // SAFETY: Guaranteed by the caller.
+    let Some((filename, source_text)) = (unsafe { args(filename, source_text, source_len) }) else {
+        return ptr::null_mut();
+    };
+    let config = if config.is_null() {
+        None
+    } else {
+        // SAFETY: Guaranteed by the caller.
+        let Some(config) = (unsafe { c_str(config) }) else { return ptr::null_mut() };
+        Some(config)
+    };
+    into_c_string(json::lint(filename, source_text, config))
+}
+
+/// Free a string returned by this library. Does nothing if `string` is NULL.
+///
+/// # Safety
+///
+/// `string` must be NULL or a string returned by this library, which is not used after this call.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn oxc_free_string(string: *mut c_char) {
+    if !string.is_null() {
+        // SAFETY: `string` was returned by `into_c_string`, and is not used after this call.
+        drop(unsafe { CString::from_raw(string) });
+    }
+}
+
+/// # Safety
+///
+/// `filename` must be NULL or a NUL-terminated string, and `source_text` NULL or valid for reads
+/// of `source_len` bytes.
+unsafe fn args<'a>(
+    filename: *const c_char,
+    source_text: *const u8,
+    source_len: usize,
+) -> Option<(&'a Path, &'a str)> {
+    // SAFETY: Guaranteed by the caller.
+    let filename = unsafe { c_str(filename) }?;
+    if source_text.is_null() {
+        return None;
+    }
+    // SAFETY: Guaranteed by the caller.
+    let source_text = unsafe { std::slice::from_raw_parts(source_text, source_len) };
+    Some((Path::new(filename), std::str::from_utf8(source_text).ok()?))
+}
+
+/// # Safety
+///
+/// `string` must be NULL or a NUL-terminated string.
+unsafe fn c_str<'a>(string: *const c_char) -> Option<&'a str> {
+    if string.is_null() {
+        return None;
+    }
+    // SAFETY: Guaranteed by the caller.
+    unsafe { CStr::from_ptr(string) }.to_str().ok()
+}
+
+fn into_c_string(json: String) -> *mut c_char {
+    // JSON escapes NUL characters in strings.
+    CString::new(json).map_or(ptr::null_mut(), CString::into_raw)
+}
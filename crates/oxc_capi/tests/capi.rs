@@ -0,0 +1,72 @@
+use std::ffi::{CStr, c_char};
+
+use oxc_capi::{generate_header, oxc_free_string, oxc_parse, oxc_transform};
+
+fn call(f: impl FnOnce(*const c_char, *const u8, usize) -> *mut c_char, source: &str) -> String {
+    let result = f(c"test.ts".as_ptr(), source.as_ptr(), source.len());
+    assert!(!result.is_null());
+    // SAFETY: `result` is a string returned by the library, freed right after.
+    let json = unsafe { CStr::from_ptr(result) }.to_str().unwrap().to_string();
+    // SAFETY: `result` is not used anymore.
+    unsafe { oxc_free_string(result) };
+    json
+}
+
+#[test]
+fn header_is_up_to_date() {
+    let header = generate_header(include_str!("../src/lib.rs")).unwrap();
+    assert!(
+        header == include_str!("../include/oxc.h"),
+        "Run `cargo run -p oxc_capi --example generate_header` to update `include/oxc.h`"
+    );
+}
+
+#[test]
+fn parse() {
+    // SAFETY: The arguments are valid.
+    let json =
+        call(|filename, source, len| unsafe { oxc_parse(filename, source, len) }, "let x: T");
+    let json: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(json["program"]["type"], "Program");
+    assert_eq!(json["program"]["body"][0]["declarations"][0]["id"]["typeAnnotation"]["end"], 8);
+    assert_eq!(json["errors"], serde_json::json!([]));
+
+    // SAFETY: The arguments are valid.
+    let json = call(|filename, source, len| unsafe { oxc_parse(filename, source, len) }, "let");
+    let json: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(json["errors"][0]["severity"], "Error");
+}
+
+#[test]
+fn transform() {
+    let options = c"{ \"target\": \"es2015\", \"sourcemap\": true }";
+    let json = call(
+        // SAFETY: The arguments are valid.
+        |filename, source, len| unsafe { oxc_transform(filename, source, len, options.as_ptr()) },
+        "const f = (a: number) => a ** 2;",
+    );
+    let json: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert_eq!(json["code"], "const f = (a) => Math.pow(a, 2);\n");
+    assert_eq!(json["map"]["sources"][0], "test.ts");
+    assert_eq!(json["errors"], serde_json::json!([]));
+
+    let options = c"{ \"target\": \"es1\" }";
+    let json = call(
+        // SAFETY: The arguments are valid.
+        |filename, source, len| unsafe { oxc_transform(filename, source, len, options.as_ptr()) },
+        "",
+    );
+    let json: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert!(json["errors"][0]["message"].as_str().unwrap().contains("es1"));
+}
+
+#[test]
+fn invalid_arguments() {
+    let source = [0xff];
+    // SAFETY: The arguments are valid pointers.
+    let result = unsafe { oxc_parse(c"test.js".as_ptr(), source.as_ptr(), source.len()) };
+    assert!(result.is_null());
+    // SAFETY: NULL is allowed.
+    let result = unsafe { oxc_parse(std::ptr::null(), source.as_ptr(), 0) };
+    assert!(result.is_null());
+}
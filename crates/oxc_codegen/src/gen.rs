@@ -44,15 +44,7 @@ pub trait GenExpr: GetSpan {
 
 impl Gen for Program<'_> {
     fn r#gen(&self, p: &mut Codegen, ctx: Context) {
-        p.is_jsx = self.source_type.is_jsx();
-
-        if let Some(hashbang) = &self.hashbang {
-            hashbang.print(p, ctx);
-        }
-        p.print_directives_and_statements(&self.directives, &self.body, ctx);
-        p.print_semicolon_if_needed();
-        // Print trailing statement comments.
-        p.print_comments_at(self.span.end);
+        p.print_program(self, ctx, |_, _| {});
     }
 }
 
@@ -101,6 +93,15 @@ impl Gen for Directive<'_> {
 
 impl Gen for Statement<'_> {
     fn r#gen(&self, p: &mut Codegen, ctx: Context) {
+        if let Some(text) = p.unmodified_source_text(self.span()) {
+            p.print_comments_at(self.span().start);
+            p.print_unmodified_statement(self.span(), text);
+            return;
+        }
+        // Other statements start with a keyword or an identifier.
+        if !matches!(self, Self::ExpressionStatement(_)) {
+            p.pending_semicolon = None;
+        }
         match self {
             Self::BlockStatement(stmt) => {
                 p.print_comments_at(stmt.span.start);
@@ -137,7 +138,7 @@ impl Gen for Statement<'_> {
             }
             Self::FunctionDeclaration(decl) => {
                 p.print_comments_at(decl.span.start);
-                if decl.pure && p.options.print_annotation_comment() {
+                if decl.pure && p.options.print_pure_annotation() {
                     p.print_indent();
                     p.print_str(NO_SIDE_EFFECTS_NEW_LINE_COMMENT);
                 }
@@ -187,11 +188,16 @@ impl Gen for Statement<'_> {
 
 impl Gen for ExpressionStatement<'_> {
     fn r#gen(&self, p: &mut Codegen, _ctx: Context) {
+        let pending_semicolon = p.pending_semicolon.take();
         p.print_comments_at(self.span.start);
         p.add_source_mapping(self.span);
         p.print_indent();
-        p.start_of_stmt = p.code_len();
+        let start = p.code_len();
+        p.start_of_stmt = start;
         p.print_expression(&self.expression);
+        if let Some(position) = pending_semicolon {
+            p.print_omitted_semicolon(position, start);
+        }
         if self.expression.is_specific_id("let") {
             p.print_semicolon();
         } else {
@@ -927,7 +933,7 @@ impl Gen for ImportAttribute<'_> {
     fn r#gen(&self, p: &mut Codegen, _ctx: Context) {
         match &self.key {
             ImportAttributeKey::Identifier(identifier) => {
-                p.print_str(identifier.name.as_str());
+                p.print_str_ascii_only(identifier.name.as_str(), true);
             }
             ImportAttributeKey::StringLiteral(literal) => {
                 p.print_string_literal(literal, false);
@@ -943,7 +949,7 @@ impl Gen for ExportNamedDeclaration<'_> {
     fn r#gen(&self, p: &mut Codegen, ctx: Context) {
         p.print_comments_at(self.span.start);
         if let Some(Declaration::FunctionDeclaration(func)) = &self.declaration {
-            if func.pure && p.options.print_annotation_comment() {
+            if func.pure && p.options.print_pure_annotation() {
                 p.print_str(NO_SIDE_EFFECTS_NEW_LINE_COMMENT);
             }
         }
@@ -991,6 +997,10 @@ impl Gen for ExportNamedDeclaration<'_> {
                 p.print_str("from");
                 p.print_soft_space();
                 p.print_string_literal(source, false);
+                if let Some(with_clause) = &self.with_clause {
+                    p.print_hard_space();
+                    with_clause.print(p, ctx);
+                }
             }
             p.print_semicolon_after_statement();
         }
@@ -1090,7 +1100,7 @@ impl Gen for ExportDefaultDeclaration<'_> {
     fn r#gen(&self, p: &mut Codegen, ctx: Context) {
         p.print_comments_at(self.span.start);
         if let ExportDefaultDeclarationKind::FunctionDeclaration(func) = &self.declaration {
-            if func.pure && p.options.print_annotation_comment() {
+            if func.pure && p.options.print_pure_annotation() {
                 p.print_str(NO_SIDE_EFFECTS_NEW_LINE_COMMENT);
             }
         }
@@ -1139,13 +1149,13 @@ impl GenExpr for Expression<'_> {
             Self::ArrayExpression(expr) => expr.print(p, ctx),
             Self::ObjectExpression(expr) => expr.print_expr(p, precedence, ctx),
             Self::FunctionExpression(func) => {
-                if func.pure && p.options.print_annotation_comment() {
+                if func.pure && p.options.print_pure_annotation() {
                     p.print_str(NO_SIDE_EFFECTS_COMMENT);
                 }
                 func.print(p, ctx);
             }
             Self::ArrowFunctionExpression(func) => {
-                if func.pure && p.options.print_annotation_comment() {
+                if func.pure && p.options.print_pure_annotation() {
                     p.print_str(NO_SIDE_EFFECTS_COMMENT);
                 }
                 func.print_expr(p, precedence, ctx);
@@ -1192,15 +1202,15 @@ impl Gen for IdentifierReference<'_> {
         let name = p.get_identifier_reference_name(self);
         p.print_space_before_identifier();
         p.add_source_mapping_for_name(self.span, name);
-        p.print_str(name);
+        p.print_str_ascii_only(name, true);
     }
 }
 
 impl Gen for IdentifierName<'_> {
     fn r#gen(&self, p: &mut Codegen, _ctx: Context) {
         p.print_space_before_identifier();
-        p.add_source_mapping(self.span);
-        p.print_str(self.name.as_str());
+        p.add_source_mapping_for_name(self.span, &self.name);
+        p.print_str_ascii_only(self.name.as_str(), true);
     }
 }
 
@@ -1209,7 +1219,7 @@ impl Gen for BindingIdentifier<'_> {
         let name = p.get_binding_identifier_name(self);
         p.print_space_before_identifier();
         p.add_source_mapping_for_name(self.span, name);
-        p.print_str(name);
+        p.print_str_ascii_only(name, true);
     }
 }
 
@@ -1217,7 +1227,7 @@ impl Gen for LabelIdentifier<'_> {
     fn r#gen(&self, p: &mut Codegen, _ctx: Context) {
         p.print_space_before_identifier();
         p.add_source_mapping_for_name(self.span, &self.name);
-        p.print_str(self.name.as_str());
+        p.print_str_ascii_only(self.name.as_str(), true);
     }
 }
 
@@ -1310,7 +1320,7 @@ impl Gen for RegExpLiteral<'_> {
             p.print_hard_space();
         }
         p.print_ascii_byte(b'/');
-        p.print_str(self.regex.pattern.text.as_str());
+        p.print_str_ascii_only(self.regex.pattern.text.as_str(), false);
         p.print_ascii_byte(b'/');
         p.print_str(self.regex.flags.to_inline_string().as_str());
         p.prev_reg_exp_end = p.code().len();
@@ -1387,7 +1397,8 @@ impl GenExpr for CallExpression<'_> {
         let is_statement = p.start_of_stmt == p.code_len();
         let is_export_default = p.start_of_default_export == p.code_len();
         let mut wrap = precedence >= Precedence::New || ctx.intersects(Context::FORBID_CALL);
-        let pure = self.pure && p.options.print_annotation_comment();
+        let pure =
+            (self.pure || p.pure_calls.contains(&self.span)) && p.options.print_pure_annotation();
         if precedence >= Precedence::Postfix && pure {
             wrap = true;
         }
@@ -1951,10 +1962,10 @@ impl Gen for AssignmentTargetPropertyIdentifier<'_> {
             self.binding.print(p, ctx);
         } else {
             // `({x: a} = y);`
-            p.print_str(self.binding.name.as_str());
+            p.print_str_ascii_only(self.binding.name.as_str(), true);
             p.print_colon();
             p.print_soft_space();
-            p.print_str(ident_name);
+            p.print_str_ascii_only(ident_name, true);
         }
         if let Some(expr) = &self.init {
             p.print_soft_space();
@@ -2077,22 +2088,41 @@ impl GenExpr for ImportExpression<'_> {
 
 impl Gen for TemplateLiteral<'_> {
     fn r#gen(&self, p: &mut Codegen, _ctx: Context) {
-        p.print_ascii_byte(b'`');
-        let mut expressions = self.expressions.iter();
+        // `` `a\`b` `` -> `"a`b"`
+        // Not at the start of a statement, where a string would become a directive.
+        if p.options.minify && self.expressions.is_empty() && p.start_of_stmt != p.code_len() {
+            if let Some(quasi) = self.quasis.first() {
+                if let Some(cooked) = &quasi.value.cooked {
+                    p.print_quoted_str(self.span, cooked, quasi.lone_surrogates, true);
+                    return;
+                }
+            }
+        }
+        print_template_literal(self, /* is_tagged */ false, p);
+    }
+}
 
-        for quasi in &self.quasis {
-            p.add_source_mapping(quasi.span);
-            p.print_str(quasi.value.raw.as_str());
+fn print_template_literal(template: &TemplateLiteral<'_>, is_tagged: bool, p: &mut Codegen) {
+    p.print_ascii_byte(b'`');
+    let mut expressions = template.expressions.iter();
 
-            if let Some(expr) = expressions.next() {
-                p.print_str("${");
-                p.print_expression(expr);
-                p.print_ascii_byte(b'}');
-            }
+    for quasi in &template.quasis {
+        p.add_source_mapping(quasi.span);
+        // Escaping would change the raw strings passed to the tag
+        if is_tagged {
+            p.print_str(quasi.value.raw.as_str());
+        } else {
+            p.print_str_ascii_only(quasi.value.raw.as_str(), false);
         }
 
-        p.print_ascii_byte(b'`');
+        if let Some(expr) = expressions.next() {
+            p.print_str("${");
+            p.print_expression(expr);
+            p.print_ascii_byte(b'}');
+        }
     }
+
+    p.print_ascii_byte(b'`');
 }
 
 impl Gen for TaggedTemplateExpression<'_> {
@@ -2102,7 +2132,7 @@ impl Gen for TaggedTemplateExpression<'_> {
         if let Some(type_parameters) = &self.type_arguments {
             type_parameters.print(p, ctx);
         }
-        self.quasi.print(p, ctx);
+        print_template_literal(&self.quasi, /* is_tagged */ true, p);
     }
 }
 
@@ -2141,7 +2171,8 @@ impl GenExpr for ChainExpression<'_> {
 impl GenExpr for NewExpression<'_> {
     fn gen_expr(&self, p: &mut Codegen, precedence: Precedence, ctx: Context) {
         let mut wrap = precedence >= self.precedence();
-        let pure = self.pure && p.options.print_annotation_comment();
+        let pure =
+            (self.pure || p.pure_calls.contains(&self.span)) && p.options.print_pure_annotation();
         if precedence >= Precedence::Postfix && pure {
             wrap = true;
         }
@@ -2178,13 +2209,13 @@ impl GenExpr for TSAsExpression<'_> {
 
 impl GenExpr for TSSatisfiesExpression<'_> {
     fn gen_expr(&self, p: &mut Codegen, precedence: Precedence, ctx: Context) {
-        p.print_ascii_byte(b'(');
-        p.print_ascii_byte(b'(');
-        self.expression.print_expr(p, precedence, Context::default());
-        p.print_ascii_byte(b')');
-        p.print_str(" satisfies ");
-        self.type_annotation.print(p, ctx);
-        p.print_ascii_byte(b')');
+        let wrap = precedence >= Precedence::Shift;
+
+        p.wrap(wrap, |p| {
+            self.expression.print_expr(p, Precedence::Exponentiation, ctx);
+            p.print_str(" satisfies ");
+            self.type_annotation.print(p, ctx);
+        });
     }
 }
 
@@ -2303,15 +2334,15 @@ impl Gen for ClassElement<'_> {
             }
             Self::PropertyDefinition(elem) => {
                 elem.print(p, ctx);
-                p.print_semicolon_after_statement();
+                p.print_semicolon_after_class_element();
             }
             Self::AccessorProperty(elem) => {
                 elem.print(p, ctx);
-                p.print_semicolon_after_statement();
+                p.print_semicolon_after_class_element();
             }
             Self::TSIndexSignature(elem) => {
                 elem.print(p, ctx);
-                p.print_semicolon_after_statement();
+                p.print_semicolon_after_class_element();
             }
         }
     }
@@ -2327,13 +2358,20 @@ impl Gen for JSXIdentifier<'_> {
 impl Gen for JSXMemberExpressionObject<'_> {
     fn r#gen(&self, p: &mut Codegen, ctx: Context) {
         match self {
-            Self::IdentifierReference(ident) => ident.print(p, ctx),
+            Self::IdentifierReference(ident) => print_jsx_identifier_reference(ident, p),
             Self::MemberExpression(member_expr) => member_expr.print(p, ctx),
             Self::ThisExpression(expr) => expr.print(p, ctx),
         }
     }
 }
 
+/// JSX has no syntax for escapes, so the name is printed as-is even when `ascii_only` is set.
+fn print_jsx_identifier_reference(ident: &IdentifierReference<'_>, p: &mut Codegen) {
+    let name = p.get_identifier_reference_name(ident);
+    p.add_source_mapping_for_name(ident.span, name);
+    p.print_str(name);
+}
+
 impl Gen for JSXMemberExpression<'_> {
     fn r#gen(&self, p: &mut Codegen, ctx: Context) {
         self.object.print(p, ctx);
@@ -2346,7 +2384,7 @@ impl Gen for JSXElementName<'_> {
     fn r#gen(&self, p: &mut Codegen, ctx: Context) {
         match self {
             Self::Identifier(identifier) => identifier.print(p, ctx),
-            Self::IdentifierReference(identifier) => identifier.print(p, ctx),
+            Self::IdentifierReference(identifier) => print_jsx_identifier_reference(identifier, p),
             Self::NamespacedName(namespaced_name) => namespaced_name.print(p, ctx),
             Self::MemberExpression(member_expr) => member_expr.print(p, ctx),
             Self::ThisExpression(expr) => expr.print(p, ctx),
@@ -2391,7 +2429,8 @@ impl Gen for JSXExpression<'_> {
     fn r#gen(&self, p: &mut Codegen, ctx: Context) {
         match self {
             Self::EmptyExpression(expr) => expr.print(p, ctx),
-            _ => p.print_expression(self.to_expression()),
+            // Sequence expressions are not allowed in JSX expression containers without parentheses.
+            _ => self.to_expression().print_expr(p, Precedence::Comma, Context::empty()),
         }
     }
 }
@@ -2410,10 +2449,22 @@ impl Gen for JSXAttributeValue<'_> {
             Self::Fragment(fragment) => fragment.print(p, ctx),
             Self::Element(el) => el.print(p, ctx),
             Self::StringLiteral(lit) => {
-                let quote = if lit.value.contains('"') { b'\'' } else { b'"' };
-                p.print_ascii_byte(quote);
-                p.print_str(&lit.value);
-                p.print_ascii_byte(quote);
+                // JSX strings have no escapes, so a string containing both quotes
+                // (which can only be created by a transform) uses an HTML entity.
+                let value = lit.value.as_str();
+                if !value.contains('"') {
+                    p.print_ascii_byte(b'"');
+                    p.print_str(value);
+                    p.print_ascii_byte(b'"');
+                } else if !value.contains('\'') {
+                    p.print_ascii_byte(b'\'');
+                    p.print_str(value);
+                    p.print_ascii_byte(b'\'');
+                } else {
+                    p.print_ascii_byte(b'"');
+                    p.print_str(&value.cow_replace('"', "&quot;"));
+                    p.print_ascii_byte(b'"');
+                }
             }
             Self::ExpressionContainer(expr_container) => expr_container.print(p, ctx),
         }
@@ -2445,6 +2496,9 @@ impl Gen for JSXElement<'_> {
         p.add_source_mapping(self.opening_element.span);
         p.print_ascii_byte(b'<');
         self.opening_element.name.print(p, ctx);
+        if let Some(type_arguments) = &self.opening_element.type_arguments {
+            type_arguments.print(p, ctx);
+        }
         for attr in &self.opening_element.attributes {
             match attr {
                 JSXAttributeItem::Attribute(_) => {
@@ -2501,7 +2555,7 @@ impl Gen for JSXText<'_> {
 impl Gen for JSXSpreadChild<'_> {
     fn r#gen(&self, p: &mut Codegen, _ctx: Context) {
         p.print_str("{...");
-        p.print_expression(&self.expression);
+        self.expression.print_expr(p, Precedence::Comma, Context::empty());
         p.print_ascii_byte(b'}');
     }
 }
@@ -2563,6 +2617,11 @@ impl Gen for MethodDefinition<'_> {
             p.print_str("static");
             p.print_soft_space();
         }
+        if self.r#override {
+            p.print_space_before_identifier();
+            p.print_str("override");
+            p.print_soft_space();
+        }
         match &self.kind {
             MethodDefinitionKind::Constructor | MethodDefinitionKind::Method => {}
             MethodDefinitionKind::Get => {
@@ -2665,6 +2724,9 @@ impl Gen for PropertyDefinition<'_> {
         if self.optional {
             p.print_str("?");
         }
+        if self.definite {
+            p.print_ascii_byte(b'!');
+        }
         if let Some(type_annotation) = &self.type_annotation {
             p.print_colon();
             p.print_soft_space();
@@ -2717,6 +2779,9 @@ impl Gen for AccessorProperty<'_> {
         if self.computed {
             p.print_ascii_byte(b']');
         }
+        if self.definite {
+            p.print_ascii_byte(b'!');
+        }
         if let Some(type_annotation) = &self.type_annotation {
             p.print_colon();
             p.print_soft_space();
@@ -2735,7 +2800,7 @@ impl Gen for PrivateIdentifier<'_> {
     fn r#gen(&self, p: &mut Codegen, _ctx: Context) {
         p.add_source_mapping_for_name(self.span, &self.name);
         p.print_ascii_byte(b'#');
-        p.print_str(self.name.as_str());
+        p.print_str_ascii_only(self.name.as_str(), true);
     }
 }
 
@@ -3351,7 +3416,7 @@ impl Gen for TSSignature<'_> {
                             key.print(p, ctx);
                         }
                         PropertyKey::PrivateIdentifier(key) => {
-                            p.print_str(key.name.as_str());
+                            p.print_str_ascii_only(key.name.as_str(), true);
                         }
                         PropertyKey::StringLiteral(key) => {
                             p.print_string_literal(key, false);
@@ -3402,7 +3467,7 @@ impl Gen for TSPropertySignature<'_> {
                     key.print(p, ctx);
                 }
                 PropertyKey::PrivateIdentifier(key) => {
-                    p.print_str(key.name.as_str());
+                    p.print_str_ascii_only(key.name.as_str(), true);
                 }
                 PropertyKey::StringLiteral(key) => {
                     p.print_string_literal(key, false);
@@ -3445,11 +3510,14 @@ impl Gen for TSTypeQueryExprName<'_> {
 impl Gen for TSImportType<'_> {
     fn r#gen(&self, p: &mut Codegen, ctx: Context) {
         p.print_str("import(");
+        // The argument and import attributes must be string literals.
+        p.forbid_backtick = true;
         self.argument.print(p, ctx);
         if let Some(options) = &self.options {
             p.print_str(", ");
             options.print_expr(p, Precedence::Lowest, ctx);
         }
+        p.forbid_backtick = false;
         p.print_str(")");
         if let Some(qualifier) = &self.qualifier {
             p.print_ascii_byte(b'.');
@@ -3480,7 +3548,7 @@ impl Gen for TSIndexSignature<'_> {
                 p.print_str(",");
                 p.print_soft_space();
             }
-            p.print_str(parameter.name.as_str());
+            p.print_str_ascii_only(parameter.name.as_str(), true);
             p.print_colon();
             p.print_soft_space();
             parameter.type_annotation.print(p, ctx);
@@ -11,9 +11,16 @@ mod r#gen;
 mod operator;
 mod options;
 mod sourcemap_builder;
+mod sourcemap_merge;
 mod str;
 
-use std::borrow::Cow;
+use std::{
+    borrow::Cow,
+    io::{self, Write},
+    iter,
+};
+
+use rustc_hash::FxHashSet;
 
 use oxc_ast::ast::*;
 use oxc_data_structures::{code_buffer::CodeBuffer, stack::Stack};
@@ -32,7 +39,8 @@ use crate::{
 pub use crate::{
     context::Context,
     r#gen::{Gen, GenExpr},
-    options::{CodegenOptions, LegalComment},
+    options::{CodegenOptions, IndentChar, LegalComment, LineEnding, QuoteStyle, Semicolons},
+    sourcemap_merge::merge_source_maps,
 };
 
 /// Output from [`Codegen::build`]
@@ -76,6 +84,12 @@ pub struct Codegen<'a> {
 
     scoping: Option<Scoping>,
 
+    /// Spans of call and new expressions printed with a pure annotation.
+    pure_calls: FxHashSet<Span>,
+
+    /// Spans of nodes changed by a transform, see [`Codegen::with_modified_spans`].
+    modified_spans: Option<Vec<Span>>,
+
     /// Output Code
     code: CodeBuffer,
 
@@ -92,8 +106,18 @@ pub struct Codegen<'a> {
     /// For avoiding `;` if the previous statement ends with `}`.
     needs_semicolon: bool,
 
+    /// Position of the `;` omitted after the last statement by [Semicolons::AsNeeded].
+    semicolon_position: usize,
+
+    /// [Codegen::semicolon_position] of the statement before the statement being printed.
+    pending_semicolon: Option<usize>,
+
     prev_op: Option<Operator>,
 
+    /// Print string literals with quotes even when backticks are shorter,
+    /// e.g. in `import("a", { with: { "resolution-mode": "import" } })` types.
+    forbid_backtick: bool,
+
     start_of_stmt: usize,
     start_of_arrow_expr: usize,
     start_of_default_export: usize,
@@ -101,7 +125,7 @@ pub struct Codegen<'a> {
     /// Track the current indentation level
     indent: u32,
 
-    /// Fast path for [CodegenOptions::single_quote]
+    /// Fast path for [CodegenOptions::quote_style]
     quote: Quote,
 
     // Builders
@@ -140,14 +164,19 @@ impl<'a> Codegen<'a> {
             options,
             source_text: None,
             scoping: None,
+            pure_calls: FxHashSet::default(),
+            modified_spans: None,
             code: CodeBuffer::default(),
             needs_semicolon: false,
+            semicolon_position: 0,
+            pending_semicolon: None,
             need_space_before_dot: 0,
             print_next_indent_as_space: false,
             binary_expr_stack: Stack::with_capacity(12),
             prev_op_end: 0,
             prev_reg_exp_end: 0,
             prev_op: None,
+            forbid_backtick: false,
             start_of_stmt: 0,
             start_of_arrow_expr: 0,
             start_of_default_export: 0,
@@ -162,7 +191,7 @@ impl<'a> Codegen<'a> {
     /// Pass options to the code generator.
     #[must_use]
     pub fn with_options(mut self, options: CodegenOptions) -> Self {
-        self.quote = if options.single_quote { Quote::Single } else { Quote::Double };
+        self.quote = Quote::from(options.quote_style);
         self.options = options;
         self
     }
@@ -183,25 +212,93 @@ impl<'a> Codegen<'a> {
         self
     }
 
+    /// Print `/* @__PURE__ */` before the call and new expressions with these spans,
+    /// in addition to the ones marked as pure in the AST.
+    ///
+    /// Allows transforms and the minifier to mark calls as pure without modifying the AST.
+    /// Has no effect unless [`CodegenOptions::annotation_comments`] or
+    /// [`CodegenOptions::pure_annotations`] is set.
+    #[must_use]
+    pub fn with_pure_calls(mut self, spans: FxHashSet<Span>) -> Self {
+        self.pure_calls = spans;
+        self
+    }
+
+    /// Copy the source text of statements which don't contain any of these spans,
+    /// instead of printing them.
+    ///
+    /// Allows codemods to preserve the formatting of the code they didn't change.
+    /// The spans must include every node changed by the transform, including nodes whose
+    /// children were added, removed or replaced.
+    /// Statements created by the transform (with an empty span) are always printed.
+    #[must_use]
+    pub fn with_modified_spans(mut self, spans: Vec<Span>) -> Self {
+        self.modified_spans = Some(spans);
+        self
+    }
+
     /// Print a [`Program`] into a string of source code.
     ///
     /// A source map will be generated if [`CodegenOptions::source_map_path`] is set.
     #[must_use]
     pub fn build(mut self, program: &Program<'a>) -> CodegenReturn {
-        self.quote = if self.options.single_quote { Quote::Single } else { Quote::Double };
-        self.source_text = Some(program.source_text);
+        self.prepare(program);
         self.code.reserve(program.source_text.len());
-        self.build_comments(&program.comments);
-        if let Some(path) = &self.options.source_map_path {
-            self.sourcemap_builder = Some(SourcemapBuilder::new(path, program.source_text));
-        }
         program.print(&mut self, Context::default());
         let legal_comments = self.handle_eof_linked_or_external_comments(program);
-        let code = self.code.into_string();
+        self.print_footer();
+        let mut code = self.code.into_string();
+        if !self.options.minify && !self.options.trailing_newline {
+            code.truncate(code.trim_end_matches('\n').len());
+        }
+        if self.options.line_ending == LineEnding::Crlf {
+            code = crlf_line_endings(&code);
+        }
         let map = self.sourcemap_builder.map(SourcemapBuilder::into_sourcemap);
         CodegenReturn { code, map, legal_comments }
     }
 
+    /// Print a [`Program`] into `writer`.
+    ///
+    /// Unlike [`Codegen::build`], the code is written out between top-level statements
+    /// once more than `chunk_size` bytes are buffered, instead of holding the whole output in memory.
+    /// [`CodegenReturn::code`] is empty.
+    ///
+    /// # Errors
+    /// Returns the first error returned by `writer`.
+    pub fn build_to_writer<W: Write>(
+        mut self,
+        program: &Program<'a>,
+        mut writer: W,
+        chunk_size: usize,
+    ) -> io::Result<CodegenReturn> {
+        self.prepare(program);
+        self.code.reserve(chunk_size);
+        let mut result = Ok(());
+        self.print_program(program, Context::default(), |p, previous_statement_start| {
+            // Keep the previous statement, which may still be modified, e.g. by an omitted `;`.
+            if result.is_ok()
+                && previous_statement_start >= chunk_size
+                && previous_statement_start < p.code.len()
+            {
+                result = p.write_code(&mut writer, previous_statement_start);
+            }
+        });
+        result?;
+        let legal_comments = self.handle_eof_linked_or_external_comments(program);
+        self.print_footer();
+        let mut len = self.code.len();
+        if !self.options.minify && !self.options.trailing_newline {
+            while self.code.as_bytes()[..len].ends_with(b"\n") {
+                len -= 1;
+            }
+        }
+        self.write_code(&mut writer, len)?;
+        writer.flush()?;
+        let map = self.sourcemap_builder.map(SourcemapBuilder::into_sourcemap);
+        Ok(CodegenReturn { code: String::new(), map, legal_comments })
+    }
+
     /// Turn what's been built so far into a string. Like [`build`],
     /// this fininishes a print and returns the generated source code. Unlike
     /// [`build`], no source map is generated.
@@ -215,6 +312,12 @@ impl<'a> Codegen<'a> {
         self.code.into_string()
     }
 
+    /// Length of the source code built so far, in bytes.
+    #[inline]
+    pub fn code_len(&self) -> usize {
+        self.code().len()
+    }
+
     /// Push a single ASCII byte into the buffer.
     ///
     /// # Panics
@@ -240,12 +343,47 @@ impl<'a> Codegen<'a> {
 
 // Private APIs
 impl<'a> Codegen<'a> {
-    fn code(&self) -> &CodeBuffer {
-        &self.code
+    fn prepare(&mut self, program: &Program<'a>) {
+        self.quote = Quote::from(self.options.quote_style);
+        self.source_text = Some(program.source_text);
+        self.build_comments(&program.comments);
+        if let Some(path) = &self.options.source_map_path {
+            self.sourcemap_builder = Some(SourcemapBuilder::new(path, program.source_text));
+        }
     }
 
-    fn code_len(&self) -> usize {
-        self.code().len()
+    /// Write the first `len` bytes of the code to `writer`, and remove them from the buffer.
+    fn write_code(&mut self, writer: &mut impl Write, len: usize) -> io::Result<()> {
+        let code = &self.code.as_bytes()[..len];
+        if self.options.line_ending == LineEnding::Crlf {
+            // `code` is valid UTF-8, so `from_utf8_lossy` doesn't copy it.
+            writer.write_all(crlf_line_endings(&String::from_utf8_lossy(code)).as_bytes())?;
+        } else {
+            writer.write_all(code)?;
+        }
+        if let Some(sourcemap_builder) = self.sourcemap_builder.as_mut() {
+            sourcemap_builder.remove_generated_prefix(self.code.as_bytes(), len);
+        }
+        self.code.remove_prefix(len);
+        // Positions in the removed code can no longer match the end of the code.
+        for position in [
+            &mut self.prev_op_end,
+            &mut self.prev_reg_exp_end,
+            &mut self.need_space_before_dot,
+            &mut self.semicolon_position,
+            &mut self.start_of_stmt,
+            &mut self.start_of_arrow_expr,
+            &mut self.start_of_default_export,
+        ] {
+            *position = position.saturating_sub(len);
+        }
+        self.pending_semicolon =
+            self.pending_semicolon.and_then(|position| position.checked_sub(len));
+        Ok(())
+    }
+
+    fn code(&self) -> &CodeBuffer {
+        &self.code
     }
 
     #[inline]
@@ -346,11 +484,30 @@ impl<'a> Codegen<'a> {
             self.print_next_indent_as_space = false;
             return;
         }
-        self.code.print_indent(self.indent as usize);
+        let n = self.indent as usize * self.options.indent_width;
+        match self.options.indent_char {
+            IndentChar::Tab => self.code.print_indent(n),
+            IndentChar::Space => self.code.print_ascii_bytes(iter::repeat_n(b' ', n)),
+        }
     }
 
     #[inline]
     fn print_semicolon_after_statement(&mut self) {
+        if self.options.minify {
+            self.needs_semicolon = true;
+        } else if self.options.semicolons == Semicolons::AsNeeded {
+            self.needs_semicolon = true;
+            self.semicolon_position = self.code.len();
+            self.print_hard_newline();
+        } else {
+            self.print_str(";\n");
+        }
+    }
+
+    /// [Semicolons::AsNeeded] does not apply to class elements,
+    /// e.g. `a = 1` followed by `[b] = 2` or `*c() {}`.
+    #[inline]
+    fn print_semicolon_after_class_element(&mut self) {
         if self.options.minify {
             self.needs_semicolon = true;
         } else {
@@ -361,11 +518,81 @@ impl<'a> Codegen<'a> {
     #[inline]
     fn print_semicolon_if_needed(&mut self) {
         if self.needs_semicolon {
-            self.print_semicolon();
+            if self.options.minify {
+                self.print_semicolon();
+            } else {
+                self.pending_semicolon = Some(self.semicolon_position);
+            }
             self.needs_semicolon = false;
         }
     }
 
+    /// Print the `;` omitted after the previous statement at `position`,
+    /// if the expression statement starting at `start` would continue the previous statement.
+    ///
+    /// e.g. `a\n(b)` is `a(b)`
+    fn print_omitted_semicolon(&mut self, position: usize, start: usize) {
+        if !matches!(
+            self.code.as_bytes().get(start),
+            Some(b'(' | b'[' | b'`' | b'+' | b'-' | b'/' | b'.' | b'<')
+        ) {
+            return;
+        }
+        self.code.insert_ascii_byte(position, b';');
+        if let Some(sourcemap_builder) = self.sourcemap_builder.as_mut() {
+            sourcemap_builder.insert_generated_byte(position);
+        }
+    }
+
+    /// Source text of a statement which is not modified, see [`Codegen::with_modified_spans`].
+    fn unmodified_source_text(&self, span: Span) -> Option<&'a str> {
+        let modified_spans = self.modified_spans.as_ref()?;
+        if span.is_empty()
+            || modified_spans
+                .iter()
+                .any(|modified| span.start <= modified.start && modified.end <= span.end)
+        {
+            return None;
+        }
+        self.source_text?.get(span.start as usize..span.end as usize)
+    }
+
+    /// Print the source text of an unmodified statement,
+    /// keeping its original indentation if it starts on its own line.
+    fn print_unmodified_statement(&mut self, span: Span, text: &str) {
+        let pending_semicolon = self.pending_semicolon.take();
+        let line_start = self.source_text.map_or("", |source_text| {
+            let before = &source_text[..span.start as usize];
+            &before[before.rfind('\n').map_or(0, |i| i + 1)..]
+        });
+        if !self.print_next_indent_as_space
+            && !line_start.is_empty()
+            && line_start.bytes().all(|b| matches!(b, b' ' | b'\t'))
+        {
+            self.print_str(line_start);
+        } else {
+            self.print_indent();
+        }
+        let start = self.code.len();
+        let mut position = span.start;
+        for line in text.split_inclusive('\n') {
+            self.add_source_mapping(Span::empty(position));
+            self.print_str(line);
+            #[expect(clippy::cast_possible_truncation)]
+            {
+                position += line.len() as u32;
+            }
+        }
+        if let Some(position) = pending_semicolon {
+            self.print_omitted_semicolon(position, start);
+        }
+        if !text.ends_with(';') {
+            self.needs_semicolon = true;
+            self.semicolon_position = self.code.len();
+        }
+        self.print_soft_newline();
+    }
+
     #[inline]
     fn print_ellipsis(&mut self) {
         self.print_str("...");
@@ -442,11 +669,62 @@ impl<'a> Codegen<'a> {
         self.needs_semicolon = false;
     }
 
+    /// Print a [`Program`], calling `before_statement` with the start of the previous statement
+    /// in the code before printing each top-level statement after the first.
+    fn print_program(
+        &mut self,
+        program: &Program<'_>,
+        ctx: Context,
+        before_statement: impl FnMut(&mut Self, usize),
+    ) {
+        self.is_jsx = program.source_type.is_jsx();
+
+        if let Some(hashbang) = &program.hashbang {
+            hashbang.print(self, ctx);
+        }
+        if let Some(banner) = &self.options.banner {
+            self.code.print_str(banner);
+            if !banner.ends_with('\n') {
+                self.print_hard_newline();
+            }
+        }
+        self.print_directives_and_statements_with(
+            &program.directives,
+            &program.body,
+            ctx,
+            before_statement,
+        );
+        self.print_semicolon_if_needed();
+        // Print trailing statement comments.
+        self.print_comments_at(program.span.end);
+    }
+
+    fn print_footer(&mut self) {
+        let Some(footer) = &self.options.footer else { return };
+        if self.code.last_byte().is_some_and(|b| b != b'\n') {
+            self.code.print_ascii_byte(b'\n');
+        }
+        self.code.print_str(footer);
+        if !footer.ends_with('\n') {
+            self.print_soft_newline();
+        }
+    }
+
     fn print_directives_and_statements(
         &mut self,
         directives: &[Directive<'_>],
         stmts: &[Statement<'_>],
         ctx: Context,
+    ) {
+        self.print_directives_and_statements_with(directives, stmts, ctx, |_, _| {});
+    }
+
+    fn print_directives_and_statements_with(
+        &mut self,
+        directives: &[Directive<'_>],
+        stmts: &[Statement<'_>],
+        ctx: Context,
+        mut before_statement: impl FnMut(&mut Self, usize),
     ) {
         for directive in directives {
             directive.print(self, ctx);
@@ -455,6 +733,7 @@ impl<'a> Codegen<'a> {
             return;
         };
 
+        let mut statement_start = self.code.len();
         // Ensure first string literal is not a directive.
         let mut first_needs_parens = false;
         if directives.is_empty() && !self.options.minify {
@@ -475,7 +754,9 @@ impl<'a> Codegen<'a> {
         }
 
         for stmt in rest {
+            before_statement(self, statement_start);
             self.print_semicolon_if_needed();
+            statement_start = self.code.len();
             stmt.print(self, ctx);
         }
     }
@@ -716,3 +997,18 @@ impl<'a> Codegen<'a> {
         }
     }
 }
+
+/// Convert `\n` line endings to `\r\n`, keeping the `\r\n` copied from the source text.
+fn crlf_line_endings(code: &str) -> String {
+    let mut crlf = String::with_capacity(code.len() + code.len() / 32);
+    for line in code.split_inclusive('\n') {
+        match line.strip_suffix('\n') {
+            Some(line) if !line.ends_with('\r') => {
+                crlf.push_str(line);
+                crlf.push_str("\r\n");
+            }
+            _ => crlf.push_str(line),
+        }
+    }
+    crlf
+}
@@ -3,10 +3,53 @@ use std::path::PathBuf;
 /// Codegen Options.
 #[derive(Debug, Clone)]
 pub struct CodegenOptions {
-    /// Use single quotes instead of double quotes.
+    /// Quotes of string literals.
+    ///
+    /// Ignored when [CodegenOptions::minify] is `true`,
+    /// the quote which produces the shortest output is used.
+    ///
+    /// Default is [QuoteStyle::Double].
+    pub quote_style: QuoteStyle,
+
+    /// When to print semicolons at the end of statements.
+    ///
+    /// Ignored when [CodegenOptions::minify] is `true`.
+    ///
+    /// Default is [Semicolons::Always].
+    pub semicolons: Semicolons,
+
+    /// Indentation character.
+    ///
+    /// Default is [IndentChar::Tab].
+    pub indent_char: IndentChar,
+
+    /// Number of [CodegenOptions::indent_char] per indentation level.
+    ///
+    /// Default is `1`.
+    pub indent_width: usize,
+
+    /// End the output with a newline.
+    ///
+    /// When `false`, the newline after the last statement is removed.
+    /// Ignored when [CodegenOptions::minify] is `true`.
+    ///
+    /// Default is `true`.
+    pub trailing_newline: bool,
+
+    /// Line endings of the output.
+    ///
+    /// Line breaks copied from the source text, e.g. in template literals and comments, are kept.
+    ///
+    /// Default is [LineEnding::Lf].
+    pub line_ending: LineEnding,
+
+    /// Escape non-ASCII characters in identifiers, strings, template literals and regular expressions,
+    /// for environments which don't read the output as UTF-8.
+    ///
+    /// Characters in comments, JSX and the raw strings of tagged templates are not escaped.
     ///
     /// Default is `false`.
-    pub single_quote: bool,
+    pub ascii_only: bool,
 
     /// Remove whitespace.
     ///
@@ -33,6 +76,14 @@ pub struct CodegenOptions {
     /// Default is `true`.
     pub annotation_comments: bool,
 
+    /// Print pure annotations: `/* @__PURE__ */` and `/* #__NO_SIDE_EFFECTS__ */`,
+    /// even if [CodegenOptions::annotation_comments] is `false`.
+    ///
+    /// Keeping them in minified code allows bundlers to tree shake it.
+    ///
+    /// Default is `false`.
+    pub pure_annotations: bool,
+
     /// Print legal comments.
     ///
     /// * starts with `//!` or `/*!`.
@@ -47,17 +98,38 @@ pub struct CodegenOptions {
     ///
     /// Default is `None` - no sourcemap is produced.
     pub source_map_path: Option<PathBuf>,
+
+    /// Text printed at the start of the output, after the hashbang, e.g. a license header.
+    ///
+    /// The sourcemap mappings account for its lines.
+    ///
+    /// Default is `None`.
+    pub banner: Option<String>,
+
+    /// Text printed at the end of the output.
+    ///
+    /// Default is `None`.
+    pub footer: Option<String>,
 }
 
 impl Default for CodegenOptions {
     fn default() -> Self {
         Self {
-            single_quote: false,
+            quote_style: QuoteStyle::Double,
+            semicolons: Semicolons::Always,
+            indent_char: IndentChar::Tab,
+            indent_width: 1,
+            trailing_newline: true,
+            line_ending: LineEnding::Lf,
+            ascii_only: false,
             minify: false,
             comments: true,
             annotation_comments: true,
+            pure_annotations: false,
             legal_comments: LegalComment::Inline,
             source_map_path: None,
+            banner: None,
+            footer: None,
         }
     }
 }
@@ -66,12 +138,21 @@ impl CodegenOptions {
     /// Minify whitespace and remove comments.
     pub fn minify() -> Self {
         Self {
-            single_quote: false,
+            quote_style: QuoteStyle::Double,
+            semicolons: Semicolons::Always,
+            indent_char: IndentChar::Tab,
+            indent_width: 1,
+            trailing_newline: false,
+            line_ending: LineEnding::Lf,
+            ascii_only: false,
             minify: true,
             comments: false,
             annotation_comments: false,
+            pure_annotations: false,
             legal_comments: LegalComment::None,
             source_map_path: None,
+            banner: None,
+            footer: None,
         }
     }
 
@@ -89,6 +170,58 @@ impl CodegenOptions {
     pub(crate) fn print_annotation_comment(&self) -> bool {
         self.annotation_comments
     }
+
+    #[inline]
+    pub(crate) fn print_pure_annotation(&self) -> bool {
+        self.annotation_comments || self.pure_annotations
+    }
+}
+
+/// Quotes of string literals.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub enum QuoteStyle {
+    /// `"a"` (default).
+    #[default]
+    Double,
+    /// `'a'`
+    Single,
+    /// The quote which needs the fewest escapes, double quotes if equal.
+    ///
+    /// `"a"`, `'"a"'`
+    Auto,
+}
+
+/// When to print semicolons at the end of statements.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub enum Semicolons {
+    /// Print a semicolon after every statement (default).
+    #[default]
+    Always,
+    /// Only print a semicolon where it can't be inserted automatically,
+    /// i.e. before a statement starting with `(`, `[`, `` ` ``, `+`, `-`, `/`, `.` or `<`.
+    ///
+    /// Semicolons after class fields are always printed.
+    AsNeeded,
+}
+
+/// Indentation character.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub enum IndentChar {
+    /// Tab (default).
+    #[default]
+    Tab,
+    /// Space.
+    Space,
+}
+
+/// Line ending.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq)]
+pub enum LineEnding {
+    /// `\n` (default).
+    #[default]
+    Lf,
+    /// `\r\n`.
+    Crlf,
 }
 
 /// Legal comment
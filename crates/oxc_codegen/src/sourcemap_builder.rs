@@ -2,8 +2,9 @@ use std::{path::Path, sync::Arc};
 
 use nonmax::NonMaxU32;
 use oxc_index::{Idx, IndexVec};
+use oxc_sourcemap::Token;
 use oxc_span::Span;
-use oxc_syntax::identifier::{LS, PS};
+use oxc_syntax::identifier::{LS, PS, is_identifier_name};
 
 // Irregular line breaks - '\u{2028}' (LS) and '\u{2029}' (PS)
 const LS_OR_PS_FIRST: u8 = 0xE2;
@@ -70,6 +71,9 @@ pub struct SourcemapBuilder {
     original_source: Arc<str>,
     last_generated_update: usize,
     last_position: Option<u32>,
+    /// The last token is not added to `sourcemap_builder` until the next token,
+    /// so that a name can still be added to it.
+    last_token: Option<Token>,
     line_offset_tables: LineOffsetTables,
     sourcemap_builder: oxc_sourcemap::SourceMapBuilder,
     generated_line: u32,
@@ -91,6 +95,7 @@ impl SourcemapBuilder {
             original_source: Arc::from(source_text),
             last_generated_update: 0,
             last_position: None,
+            last_token: None,
             line_offset_tables,
             sourcemap_builder,
             generated_line: 0,
@@ -99,7 +104,8 @@ impl SourcemapBuilder {
         }
     }
 
-    pub fn into_sourcemap(self) -> oxc_sourcemap::SourceMap {
+    pub fn into_sourcemap(mut self) -> oxc_sourcemap::SourceMap {
+        self.flush_last_token();
         self.sourcemap_builder.into_sourcemap()
     }
 
@@ -114,27 +120,89 @@ impl SourcemapBuilder {
         let original_name = self.original_source.get(span.start as usize..span.end as usize);
         // The token name should be original name.
         // If it hasn't change, name should be `None` to reduce `SourceMap` size.
-        let token_name =
-            if original_name == Some(name) { None } else { original_name.map(Into::into) };
+        // Private names are printed without the leading `#`.
+        let token_name = original_name
+            .filter(|original_name| {
+                let original_name = original_name.strip_prefix('#').unwrap_or(original_name);
+                // A property key may have been a string literal, e.g. `a["b"]` -> `a.b`.
+                original_name != name && is_identifier_name(original_name)
+            })
+            .map(Into::into);
         self.add_source_mapping(output, span.start, token_name);
     }
 
     pub fn add_source_mapping(&mut self, output: &[u8], position: u32, name: Option<Arc<str>>) {
         if matches!(self.last_position, Some(last_position) if last_position == position) {
+            // A node and its first child start at the same position, e.g. `#a` in `#a = 1`.
+            // Add the name of the child to the token of the node.
+            let Some(name) = name else { return };
+            self.update_generated_line_and_column(output);
+            if let Some(token) = &self.last_token {
+                if token.get_dst_line() == self.generated_line
+                    && token.get_dst_col() == self.generated_column
+                {
+                    if token.get_name_id().is_none() {
+                        let name_id = self.sourcemap_builder.add_name(&name);
+                        self.last_token = Some(Token::new(
+                            token.get_dst_line(),
+                            token.get_dst_col(),
+                            token.get_src_line(),
+                            token.get_src_col(),
+                            token.get_source_id(),
+                            Some(name_id),
+                        ));
+                    }
+                    return;
+                }
+            }
+            self.add_token(position, Some(&name));
             return;
         }
-        let (original_line, original_column) = self.search_original_line_and_column(position);
         self.update_generated_line_and_column(output);
-        let name_id = name.map(|s| self.sourcemap_builder.add_name(&s));
-        self.sourcemap_builder.add_token(
+        self.add_token(position, name.as_deref());
+        self.last_position = Some(position);
+    }
+
+    fn add_token(&mut self, position: u32, name: Option<&str>) {
+        let (original_line, original_column) = self.search_original_line_and_column(position);
+        let name_id = name.map(|s| self.sourcemap_builder.add_name(s));
+        self.flush_last_token();
+        self.last_token = Some(Token::new(
             self.generated_line,
             self.generated_column,
             original_line,
             original_column,
             Some(self.source_id),
             name_id,
-        );
-        self.last_position = Some(position);
+        ));
+    }
+
+    fn flush_last_token(&mut self) {
+        if let Some(token) = self.last_token.take() {
+            self.sourcemap_builder.add_token(
+                token.get_dst_line(),
+                token.get_dst_col(),
+                token.get_src_line(),
+                token.get_src_col(),
+                token.get_source_id(),
+                token.get_name_id(),
+            );
+        }
+    }
+
+    /// Account for the first `len` bytes of `output` being removed, after they have been written out.
+    pub fn remove_generated_prefix(&mut self, output: &[u8], len: usize) {
+        self.update_generated_line_and_column(output);
+        self.last_generated_update -= len;
+    }
+
+    /// Update the generated positions after a byte is inserted into the output at `index`.
+    ///
+    /// `index` must be before any line with a mapping after `index`.
+    pub fn insert_generated_byte(&mut self, index: usize) {
+        if self.last_generated_update > index {
+            self.last_generated_update += 1;
+        }
     }
 
     #[expect(clippy::cast_possible_truncation)]
@@ -554,6 +622,32 @@ mod test {
         );
     }
 
+    #[test]
+    fn add_source_mapping_for_name_at_same_position() {
+        // `#b = 1` -> `#c = 1`, the property definition starts at the same position as its key.
+        let output = b"";
+        let mut builder = SourcemapBuilder::new(Path::new("x.js"), "#b = 1");
+        builder.add_source_mapping(output, 0, None);
+        builder.add_source_mapping_for_name(output, Span::new(0, 2), "c");
+        let sm = builder.into_sourcemap();
+        assert_eq!(sm.get_tokens().count(), 1);
+        assert_eq!(
+            sm.get_source_view_token(0_u32).as_ref().and_then(|token| token.get_name()),
+            Some("#b")
+        );
+    }
+
+    #[test]
+    fn add_source_mapping_for_unchanged_name() {
+        let output = b"#a;b";
+        let mut builder = SourcemapBuilder::new(Path::new("x.js"), "#a;'b'");
+        builder.add_source_mapping_for_name(output, Span::new(0, 2), "a");
+        // `'b'` -> `b`
+        builder.add_source_mapping_for_name(output, Span::new(3, 6), "b");
+        let sm = builder.into_sourcemap();
+        assert_eq!(sm.get_names().count(), 0);
+    }
+
     #[test]
     fn add_source_mapping_for_unordered_position() {
         let output = b"";
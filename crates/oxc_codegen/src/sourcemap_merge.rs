@@ -0,0 +1,67 @@
+use oxc_sourcemap::{SourceMap, SourceMapBuilder};
+
+/// Compose `map`, the source map of code generated from an intermediate source, with `input_map`,
+/// the source map of the intermediate source, e.g. the output of a compiler which ran before.
+///
+/// The returned source map maps the generated code to the original sources of `input_map`.
+/// Generated positions whose intermediate position is not mapped by `input_map` are left unmapped,
+/// and names are taken from `input_map`, falling back to the names of `map`.
+pub fn merge_source_maps(map: &SourceMap, input_map: &SourceMap) -> SourceMap {
+    let lookup_table = input_map.generate_lookup_table();
+    let mut builder = SourceMapBuilder::default();
+    if let Some(file) = map.get_file() {
+        builder.set_file(file);
+    }
+
+    // Ids of the sources of `input_map` in the builder, added when they are first mapped to.
+    let mut source_ids = vec![None; input_map.get_sources().count()];
+    let mut is_mapped = false;
+    for token in map.get_tokens() {
+        let input_token = token.get_source_id().and_then(|_| {
+            input_map.lookup_token(&lookup_table, token.get_src_line(), token.get_src_col())
+        });
+        let Some((input_token, input_source_id)) =
+            input_token.and_then(|input_token| Some((input_token, input_token.get_source_id()?)))
+        else {
+            // Only end the previous mapping, which would otherwise extend over this position.
+            if is_mapped {
+                builder.add_token(token.get_dst_line(), token.get_dst_col(), 0, 0, None, None);
+                is_mapped = false;
+            }
+            continue;
+        };
+
+        let source_id = *source_ids[input_source_id as usize].get_or_insert_with(|| {
+            let source = input_map.get_source(input_source_id).unwrap_or_default();
+            let content = input_map.get_source_content(input_source_id).unwrap_or_default();
+            builder.add_source_and_content(source, content)
+        });
+        let name = input_token
+            .get_name_id()
+            .and_then(|id| input_map.get_name(id))
+            .or_else(|| token.get_name_id().and_then(|id| map.get_name(id)));
+        let name_id = name.map(|name| builder.add_name(name));
+        builder.add_token(
+            token.get_dst_line(),
+            token.get_dst_col(),
+            input_token.get_src_line(),
+            input_token.get_src_col(),
+            Some(source_id),
+            name_id,
+        );
+        is_mapped = true;
+    }
+
+    let mut merged = builder.into_sourcemap();
+    // Sources of `input_map` without content keep having no content.
+    let mut source_contents = vec![None; merged.get_sources().count()];
+    for (input_source_id, source_id) in source_ids.iter().enumerate() {
+        if let Some(source_id) = source_id {
+            #[expect(clippy::cast_possible_truncation)]
+            let content = input_map.get_source_content(input_source_id as u32);
+            source_contents[*source_id as usize] = content;
+        }
+    }
+    merged.set_source_contents(source_contents);
+    merged
+}
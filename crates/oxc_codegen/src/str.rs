@@ -1,10 +1,11 @@
-use std::slice;
+use std::{slice, str};
 
 use oxc_ast::ast::StringLiteral;
 use oxc_data_structures::{assert_unchecked, pointer_ext::PointerExt};
+use oxc_span::Span;
 use oxc_syntax::identifier::{LS, NBSP, PS};
 
-use crate::Codegen;
+use crate::{Codegen, QuoteStyle};
 
 /// Quote character.
 #[derive(Clone, Copy, PartialEq, Eq)]
@@ -15,6 +16,15 @@ pub enum Quote {
     Backtick = b'`',
 }
 
+impl From<QuoteStyle> for Quote {
+    fn from(quote_style: QuoteStyle) -> Self {
+        match quote_style {
+            QuoteStyle::Single => Self::Single,
+            QuoteStyle::Double | QuoteStyle::Auto => Self::Double,
+        }
+    }
+}
+
 impl Quote {
     #[inline]
     pub fn print(self, codegen: &mut Codegen<'_>) {
@@ -26,7 +36,20 @@ impl Quote {
 impl Codegen<'_> {
     /// Print a [`StringLiteral`].
     pub(crate) fn print_string_literal(&mut self, s: &StringLiteral<'_>, allow_backtick: bool) {
-        self.add_source_mapping(s.span);
+        self.print_quoted_str(s.span, &s.value, s.lone_surrogates, allow_backtick);
+    }
+
+    /// Print `value` as a quoted string.
+    ///
+    /// `value` is encoded like [`StringLiteral::value`], see [`StringLiteral::lone_surrogates`].
+    pub(crate) fn print_quoted_str(
+        &mut self,
+        span: Span,
+        value: &str,
+        lone_surrogates: bool,
+        allow_backtick: bool,
+    ) {
+        self.add_source_mapping(span);
 
         // If `minify` option enabled, quote will be chosen depending on what produces shortest output.
         // What is the best quote to use will be determined when first character needing escape is found.
@@ -34,7 +57,9 @@ impl Codegen<'_> {
         // Don't print opening quote now, because we don't know what it is yet.
         //
         // If not in `minify` mode, print the quote requested in options.
-        let quote = if self.options.minify {
+        // `QuoteStyle::Auto` chooses the quote in the same way, but never uses backticks.
+        let allow_backtick = allow_backtick && self.options.minify && !self.forbid_backtick;
+        let quote = if self.options.minify || self.options.quote_style == QuoteStyle::Auto {
             None
         } else {
             let quote = self.quote;
@@ -44,19 +69,22 @@ impl Codegen<'_> {
 
         // Loop through bytes, looking for any which need to be escaped.
         // String is written to buffer in chunks.
-        let bytes = s.value.as_bytes().iter();
+        let bytes = value.as_bytes().iter();
         let mut state = PrintStringState {
             chunk_start: bytes.as_slice().as_ptr(),
             bytes,
             quote,
-            lone_surrogates: s.lone_surrogates,
+            lone_surrogates,
             allow_backtick,
         };
 
+        // With `ascii_only` option, the first byte of every non-ASCII character needs escaping too
+        let escapes = if self.options.ascii_only { &ESCAPES_ASCII_ONLY.0 } else { &ESCAPES.0 };
+
         // Loop through bytes.
         while let Some(b) = state.peek() {
             // Look up whether byte needs escaping
-            let escape = ESCAPES.0[b as usize];
+            let escape = escapes[b as usize];
             if escape == Escape::__ {
                 // No escape required.
                 // SAFETY: We just checked there's a byte to consume.
@@ -86,6 +114,65 @@ impl Codegen<'_> {
         let quote = unsafe { state.quote.unwrap_unchecked() };
         quote.print(self);
     }
+
+    /// Print the text of an identifier, a template literal or a regular expression,
+    /// escaping non-ASCII characters if [`crate::CodegenOptions::ascii_only`] is enabled.
+    ///
+    /// `text` may contain escapes. A non-ASCII character escaped by a backslash is replaced by
+    /// an escape sequence of the character, which has the same meaning.
+    pub(crate) fn print_str_ascii_only(&mut self, text: &str, is_identifier: bool) {
+        if !self.options.ascii_only || text.is_ascii() {
+            self.print_str(text);
+            return;
+        }
+
+        let mut escaped = false;
+        for c in text.chars() {
+            if c.is_ascii() {
+                if escaped || c != '\\' {
+                    if escaped {
+                        self.print_ascii_byte(b'\\');
+                    }
+                    self.print_ascii_byte(c as u8);
+                    escaped = false;
+                } else {
+                    // Print the backslash with the next character
+                    escaped = true;
+                }
+            } else if escaped && matches!(c, LS | PS) {
+                // Line continuation in a template literal
+                self.print_str("\\\n");
+                escaped = false;
+            } else {
+                self.print_unicode_escape(c, is_identifier);
+                escaped = false;
+            }
+        }
+        if escaped {
+            self.print_ascii_byte(b'\\');
+        }
+    }
+
+    /// Print an escape sequence for a non-ASCII character.
+    ///
+    /// Identifiers can only contain `\uXXXX` and `\u{XXXXX}` escapes.
+    /// Otherwise characters outside the BMP are printed as a surrogate pair,
+    /// which is also valid in regular expressions without the `u` flag.
+    fn print_unicode_escape(&mut self, c: char, is_identifier: bool) {
+        let code_point = c as u32;
+        if code_point <= 0xFF && !is_identifier {
+            self.print_str(&format!("\\x{code_point:02X}"));
+        } else if code_point <= 0xFFFF {
+            self.print_str(&format!("\\u{code_point:04X}"));
+        } else if is_identifier {
+            self.print_str(&format!("\\u{{{code_point:X}}}"));
+        } else {
+            let mut units = [0; 2];
+            for unit in c.encode_utf16(&mut units) {
+                self.print_str(&format!("\\u{unit:04X}"));
+            }
+        }
+    }
 }
 
 /// String printer state.
@@ -343,6 +430,7 @@ enum Escape {
     LS = 14, // LS/PS - U+2028 LINE SEPARATOR or U+2029 PARAGRAPH SEPARATOR (first byte)
     NB = 15, // NBSP  - Non-breaking space (first byte)
     LO = 16, // �     - U+FFFD lossy replacement character (first byte)
+    NA = 17, // Non-ASCII character with `ascii_only` option (first byte)
 }
 
 /// Struct which ensures content is aligned on 128.
@@ -354,10 +442,25 @@ struct Aligned128<T>(T);
 /// Aligned on 128, so top half (ASCII chars) occupies a pair of L1 cache lines.
 /// Bottom half (non-ASCII chars) also occupies a pair of L1 cache lines,
 /// but will not be accessed for strings which only contain ASCII (common case).
-static ESCAPES: Aligned128<[Escape; 256]> = {
+static ESCAPES: Aligned128<[Escape; 256]> = Aligned128(ESCAPES_TABLE);
+
+/// Table mapping bytes to `Escape`s, when `ascii_only` option is enabled.
+///
+/// Same as [`ESCAPES`], except that first bytes of non-ASCII characters map to [`Escape::NA`].
+static ESCAPES_ASCII_ONLY: Aligned128<[Escape; 256]> = {
+    let mut escapes = ESCAPES_TABLE;
+    let mut b = 0xC0;
+    while b <= 0xFF {
+        escapes[b] = Escape::NA;
+        b += 1;
+    }
+    Aligned128(escapes)
+};
+
+const ESCAPES_TABLE: [Escape; 256] = {
     #[allow(clippy::enum_glob_use, clippy::allow_attributes)]
     use Escape::*;
-    Aligned128([
+    [
         //   1   2   3   4   5   6   7   8   9   A   B   C   D   E   F
         NU, __, __, __, __, __, __, BE, BK, __, NL, VT, FF, CR, __, __, // 0
         __, __, __, __, __, __, __, __, __, __, __, ES, __, __, __, __, // 1
@@ -375,7 +478,7 @@ static ESCAPES: Aligned128<[Escape; 256]> = {
         __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, // D
         __, __, LS, __, __, __, __, __, __, __, __, __, __, __, __, LO, // E
         __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, __, // F
-    ])
+    ]
 };
 
 type ByteHandler = unsafe fn(&mut Codegen, &mut PrintStringState);
@@ -385,9 +488,10 @@ type ByteHandler = unsafe fn(&mut Codegen, &mut PrintStringState);
 /// Indexed by `escape as usize - 1` (where `escape` is not `Escape::__`).
 /// Must be in same order as discriminants in `Escape`.
 ///
-/// Function pointers are 8 bytes each, so `BYTE_HANDLERS` is 128 bytes in total.
-/// Aligned on 128, so occupies a pair of L1 cache lines.
-static BYTE_HANDLERS: Aligned128<[ByteHandler; 16]> = Aligned128([
+/// Function pointers are 8 bytes each, so the first 16 handlers are 128 bytes in total.
+/// Aligned on 128, so they occupy a pair of L1 cache lines.
+/// The last handler is only used with `ascii_only` option.
+static BYTE_HANDLERS: Aligned128<[ByteHandler; 17]> = Aligned128([
     print_null,
     print_bell,
     print_backspace,
@@ -404,6 +508,7 @@ static BYTE_HANDLERS: Aligned128<[ByteHandler; 16]> = Aligned128([
     print_ls_or_ps,
     print_non_breaking_space,
     print_lossy_replacement,
+    print_non_ascii,
 ]);
 
 /// Call byte handler for byte which needs escaping.
@@ -645,6 +750,18 @@ unsafe fn print_lossy_replacement(codegen: &mut Codegen, state: &mut PrintString
             let bytes = &mut state.bytes;
             let hex: [u8; 4] = bytes.as_slice()[3..7].try_into().unwrap();
 
+            if hex == *b"fffd" && codegen.options.ascii_only {
+                // Actual lossy replacement character, print it escaped.
+                state.flush(codegen);
+                // SAFETY: `bytes.as_slice()[3..7]` would have panicked if there weren't at least 7 bytes
+                // remaining. First 3 bytes are lossy replacement character, and the next 4 bytes are
+                // ASCII, so this leaves `bytes` on a UTF-8 char boundary.
+                unsafe { state.consume_bytes_unchecked::<7>() };
+                state.start_chunk();
+                codegen.print_str("\\uFFFD");
+                return;
+            }
+
             if hex == *b"fffd" {
                 // Actual lossy replacement character.
                 // Flush up to and including the lossy replacement character, then skip the 4 hex bytes.
@@ -688,6 +805,26 @@ unsafe fn print_lossy_replacement(codegen: &mut Codegen, state: &mut PrintString
     unsafe { state.consume_bytes_unchecked::<3>() };
 }
 
+// 0xC0 - 0xFF - first byte of a non-ASCII character, with `ascii_only` option
+unsafe fn print_non_ascii(codegen: &mut Codegen, state: &mut PrintStringState) {
+    debug_assert!(state.peek().is_some_and(|b| b >= 0xC0));
+
+    // SAFETY: `bytes` iterator is always positioned on a UTF-8 character boundary
+    let c = unsafe { str::from_utf8_unchecked(state.bytes.as_slice()) }.chars().next().unwrap();
+    if c == '\u{FFFD}' && state.lone_surrogates {
+        // SAFETY: Next byte is 0xEF, the first byte of the lossy replacement character
+        unsafe { print_lossy_replacement(codegen, state) };
+        return;
+    }
+
+    state.flush(codegen);
+    for _ in 0..c.len_utf8() {
+        state.bytes.next().unwrap();
+    }
+    state.start_chunk();
+    codegen.print_unicode_escape(c, false);
+}
+
 /// Call a closure while hinting to compiler that this branch is rarely taken.
 ///
 /// "Cold trampoline function", suggested in:
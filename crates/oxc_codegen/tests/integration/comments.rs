@@ -248,7 +248,15 @@ pub mod legal {
 }
 
 pub mod pure {
-    use crate::snapshot;
+    use rustc_hash::FxHashSet;
+
+    use oxc_allocator::Allocator;
+    use oxc_ast::ast::{Expression, Statement};
+    use oxc_codegen::{Codegen, CodegenOptions};
+    use oxc_parser::Parser;
+    use oxc_span::{GetSpan, SourceType};
+
+    use crate::{codegen_options, snapshot};
 
     #[test]
     fn annotate_comment() {
@@ -464,6 +472,51 @@ delete /* @__PURE__ */ (() => {})();",
 
         snapshot("pure_comments", &cases);
     }
+
+    #[test]
+    fn pure_annotations_option() {
+        let code = "
+/* #__NO_SIDE_EFFECTS__ */ function f() {}
+/* @__PURE__ */ f();
+const a = /* @__PURE__ */ new F(), b = /* #__PURE__ */ g()();
+/** JSDoc Comment */
+const c = 1;
+";
+        let options = CodegenOptions { pure_annotations: true, ..CodegenOptions::minify() };
+        assert_eq!(
+            codegen_options(code, &options).code,
+            "/* @__NO_SIDE_EFFECTS__ */\nfunction f(){}/* @__PURE__ */ f();const a=/* @__PURE__ */ new F,b=/* @__PURE__ */ g()();const c=1;"
+        );
+        assert!(!codegen_options(code, &CodegenOptions::minify()).code.contains("__"));
+    }
+
+    #[test]
+    fn pure_calls() {
+        let code = "f(); new F(); g(h());";
+        let allocator = Allocator::default();
+        let program = Parser::new(&allocator, code, SourceType::mjs()).parse().program;
+        let mut spans = FxHashSet::default();
+        for stmt in &program.body {
+            let Statement::ExpressionStatement(stmt) = stmt else { unreachable!() };
+            if let Expression::CallExpression(call) = &stmt.expression {
+                // Mark the argument of `g` as pure.
+                if let Some(arg) = call.arguments.first() {
+                    spans.insert(arg.span());
+                    continue;
+                }
+            }
+            spans.insert(stmt.expression.span());
+        }
+        let code = Codegen::new().with_pure_calls(spans.clone()).build(&program).code;
+        assert_eq!(
+            code,
+            "/* @__PURE__ */ f();\n/* @__PURE__ */ new F();\ng(/* @__PURE__ */ h());\n"
+        );
+
+        let options = CodegenOptions { annotation_comments: false, ..CodegenOptions::default() };
+        let code = Codegen::new().with_options(options).with_pure_calls(spans).build(&program).code;
+        assert_eq!(code, "f();\nnew F();\ng(h());\n");
+    }
 }
 
 pub mod options {
@@ -1,4 +1,11 @@
-use oxc_codegen::CodegenOptions;
+use oxc_allocator::Allocator;
+use oxc_ast::ast::{Expression, Statement};
+use oxc_codegen::{
+    Codegen, CodegenOptions, IndentChar, LineEnding, QuoteStyle, Semicolons, merge_source_maps,
+};
+use oxc_parser::Parser;
+use oxc_sourcemap::SourceMap;
+use oxc_span::{GetSpan, SourceType};
 
 use crate::tester::{
     test, test_minify, test_minify_same, test_options, test_same, test_with_parse_options,
@@ -23,6 +30,10 @@ fn module_decl() {
     test("import x from './foo.js' with {}", "import x from \"./foo.js\" with {};\n");
     test("import {} from './foo.js' with {}", "import {} from \"./foo.js\" with {};\n");
     test("export * from './foo.js' with {}", "export * from \"./foo.js\" with {};\n");
+    test(
+        "export { a } from './foo.json' with { type: 'json' }",
+        "export { a } from \"./foo.json\" with { type: \"json\" };\n",
+    );
     test_minify("export { '☿' } from 'mod';", "export{\"☿\"}from\"mod\";");
     test_minify("export { '☿' as '☿' } from 'mod';", "export{\"☿\"}from\"mod\";");
     test_minify(
@@ -506,10 +517,12 @@ fn big_int_minify() {
 
 #[test]
 fn directive() {
-    let single_quote = CodegenOptions { single_quote: true, ..CodegenOptions::default() };
+    let single_quote =
+        CodegenOptions { quote_style: QuoteStyle::Single, ..CodegenOptions::default() };
     test_options("\"'\"", "\"'\";\n", single_quote.clone());
     test_options("'\"'", "'\"';\n", single_quote);
-    let double_quote = CodegenOptions { single_quote: false, ..CodegenOptions::default() };
+    let double_quote =
+        CodegenOptions { quote_style: QuoteStyle::Double, ..CodegenOptions::default() };
     test_options("\"'\"", "\"'\";\n", double_quote.clone());
     test_options("'\"'", "'\"';\n", double_quote.clone());
     test_options(r#""'\"""#, "\"'\\\"\";\n", double_quote);
@@ -580,6 +593,362 @@ fn string() {
     test_minify(r#"foo("\n")"#, "foo(`\n`);");
 }
 
+#[test]
+fn quote_style() {
+    let single = CodegenOptions { quote_style: QuoteStyle::Single, ..CodegenOptions::default() };
+    test_options(r#"x = "a""#, "x = 'a';\n", single.clone());
+    test_options(r#"x = "'a'""#, "x = '\\'a\\'';\n", single);
+    let auto = CodegenOptions { quote_style: QuoteStyle::Auto, ..CodegenOptions::default() };
+    test_options(r"x = 'a'", "x = \"a\";\n", auto.clone());
+    test_options(r#"x = "'a'""#, "x = \"'a'\";\n", auto.clone());
+    test_options(r#"x = '"a"'"#, "x = '\"a\"';\n", auto.clone());
+    test_options(r#"x = '"a\''"#, "x = \"\\\"a'\";\n", auto.clone());
+    test_options(r#"x = '"a\'\''"#, "x = \"\\\"a''\";\n", auto.clone());
+    // Backticks are only used when minifying.
+    test_options(r#"x = '""a\''"#, "x = '\"\"a\\'';\n", auto);
+}
+
+#[test]
+fn template_literal_minify() {
+    // Untagged templates without substitutions are printed as the shortest string.
+    test_minify("x = `a`", "x=`a`;");
+    test_minify("x = `'a'`", "x=`'a'`;");
+    test_minify("x = `\\``", "x=\"`\";");
+    test_minify("x = `a\\${`", "x=\"a${\";");
+    test_minify("x = `\\x41\\u{42}`", "x=`AB`;");
+    test_minify("x = `a\\\nb`", "x=`ab`;");
+    test_minify("x = `\\uD800`", "x=`\\ud800`;");
+    // A string at the start of a statement would be a directive.
+    test_minify("`use strict`", "`use strict`;");
+    test_minify("`a`.length", "`a`.length;");
+    // Templates with substitutions, tagged templates and invalid escapes are kept.
+    test_minify("x = `\\`${a}`", "x=`\\`${a}`;");
+    test_minify("x = tag`\\``", "x=tag`\\``;");
+    test_minify("x = tag`\\unicode`", "x=tag`\\unicode`;");
+    test("x = `\\``", "x = `\\``;\n");
+}
+
+/// Minified numbers and strings are parsed back to the same value.
+#[test]
+fn minify_round_trip() {
+    fn minify(allocator: &Allocator, source_text: &str) -> String {
+        let program = Parser::new(allocator, source_text, SourceType::mjs()).parse().program;
+        Codegen::new().with_options(CodegenOptions::minify()).build(&program).code
+    }
+
+    fn assigned_value<'a>(allocator: &'a Allocator, source_text: &'a str) -> Expression<'a> {
+        let ret = Parser::new(allocator, source_text, SourceType::mjs()).parse();
+        assert!(ret.errors.is_empty(), "{source_text}: {:?}", ret.errors);
+        let Some(Statement::ExpressionStatement(stmt)) = ret.program.body.into_iter().next() else {
+            unreachable!()
+        };
+        let Expression::AssignmentExpression(assignment) = stmt.unbox().expression else {
+            unreachable!()
+        };
+        assignment.unbox().right
+    }
+
+    let numbers = [
+        0.0,
+        1.0,
+        0.5,
+        0.123,
+        10.0,
+        100.0,
+        1000.0,
+        123_000.0,
+        1_000_000.0,
+        1e21,
+        1e100,
+        1.5e300,
+        f64::MAX,
+        0.001,
+        0.000_001,
+        1.5e-7,
+        1e-100,
+        f64::MIN_POSITIVE,
+        5e-324,
+        2_147_483_647.0,
+        4_294_967_295.0,
+        9_007_199_254_740_991.0,
+        9_007_199_254_740_993.0,
+        1_000_000_000_000_000_128.0,
+        0.1 + 0.2,
+        std::f64::consts::PI,
+    ];
+    for number in numbers {
+        let allocator = Allocator::default();
+        let source_text = format!("x = {number:?}");
+        let code = minify(&allocator, &source_text);
+        let value = assigned_value(&allocator, allocator.alloc_str(&code));
+        let Expression::NumericLiteral(value) = value else { unreachable!("{code}") };
+        let value = value.value;
+        assert_eq!(value.to_bits(), number.to_bits(), "{source_text} was minified to {code}");
+    }
+
+    let strings = [
+        "''",
+        "'a'",
+        "'\\''",
+        "'\"'",
+        "'\\'\"'",
+        "'\\'\"`'",
+        "'${a}'",
+        "'\\\\'",
+        "'\\n\\r\\t\\v\\f\\b\\0'",
+        "'\\0001'",
+        "'\\u2028\\u2029'",
+        "'\\xA0é😀'",
+        "'\\uD800'",
+        "'\\uDC00\\uD800'",
+        "'\\uFFFD\\uD800'",
+        "'</script>'",
+        "`a`",
+        "`'\"`",
+        "`\\``",
+        "`$\\{`",
+        "`\\${a}`",
+        "`\r\n`",
+        "`\\uD83D`",
+    ];
+    for string in strings {
+        let allocator = Allocator::default();
+        let source_text = format!("x = {string}");
+        let code = minify(&allocator, &source_text);
+        let expected = assigned_value(&allocator, allocator.alloc_str(&source_text));
+        let value = assigned_value(&allocator, allocator.alloc_str(&code));
+        let cooked = |expr: &Expression| match expr {
+            Expression::StringLiteral(lit) => (lit.value.to_string(), lit.lone_surrogates),
+            Expression::TemplateLiteral(lit) => {
+                let quasi = &lit.quasis[0];
+                (quasi.value.cooked.unwrap().to_string(), quasi.lone_surrogates)
+            }
+            _ => unreachable!("{code}"),
+        };
+        assert_eq!(cooked(&value), cooked(&expected), "{source_text} was minified to {code}");
+        assert!(code.len() < source_text.len(), "{source_text} was minified to {code}");
+    }
+}
+
+#[test]
+fn ascii_only() {
+    let options = CodegenOptions { ascii_only: true, ..CodegenOptions::default() };
+    test_options("x = 'é中😀'", "x = \"\\xE9\\u4E2D\\uD83D\\uDE00\";\n", options.clone());
+    test_options("x = 'a\\é'", "x = \"a\\xE9\";\n", options.clone());
+    test_options("x = '\\uD800\\uFFFD'", "x = \"\\ud800\\uFFFD\";\n", options.clone());
+    test_options("let café, 𐊧", "let caf\\u00E9, \\u{102A7};\n", options.clone());
+    test_options("x = /é\\中/u", "x = /\\xE9\\u4E2D/u;\n", options.clone());
+    test_options("x = `é${a}中`", "x = `\\xE9${a}\\u4E2D`;\n", options.clone());
+    // Escaping would change the raw strings passed to the tag.
+    test_options("x = tag`é`", "x = tag`é`;\n", options);
+}
+
+#[test]
+fn jsx() {
+    test_same(
+        "<div {...props} key=\"k\" b='say \"hi\"'>\n\t{/* comment */}\n\t{...children}\n</div>;\n",
+    );
+    test_same("<>\n\ttext &amp; more\n\t{cond ? <A.B /> : null}\n</>;\n");
+    test("<a b={x, y}>{x, y}{...(x, y)}</a>", "<a b={(x, y)}>{(x, y)}{...(x, y)}</a>;\n");
+    test_minify("<a b={x, y}>{x, y}</a>", "<a b={(x,y)}>{(x,y)}</a>;");
+    // JSX has no syntax for escapes.
+    let ascii_only = CodegenOptions { ascii_only: true, ..CodegenOptions::default() };
+    test_options("<π.𐀀 a='π'>π</π.𐀀>", "<π.𐀀 a=\"π\">π</π.𐀀>;\n", ascii_only.clone());
+    test_options("<Π/>", "<Π />;\n", ascii_only);
+}
+
+#[test]
+fn modified_spans() {
+    let source_text =
+        "// a\nlet   a = 1 ;\nfunction f ( ) {\n    foo ( a )\n    bar ( )\n    if (a)   b( )\n}\n";
+    let allocator = Allocator::default();
+    let mut program = Parser::new(&allocator, source_text, SourceType::mjs()).parse().program;
+    // Remove `bar ( )`.
+    let Statement::FunctionDeclaration(f) = &mut program.body[1] else { unreachable!() };
+    let body = f.body.as_mut().unwrap();
+    body.statements.remove(1);
+    let modified_spans = vec![body.span()];
+    let code = Codegen::new().with_modified_spans(modified_spans).build(&program).code;
+    assert_eq!(code, "// a\nlet   a = 1 ;\nfunction f() {\n    foo ( a )\n    if (a)   b( )\n}\n");
+}
+
+#[test]
+fn semicolons() {
+    let as_needed =
+        CodegenOptions { semicolons: Semicolons::AsNeeded, ..CodegenOptions::default() };
+    test_options("a; b; let c = 1; return", "a\nb\nlet c = 1\nreturn\n", as_needed.clone());
+    test_options(
+        "if (a) b; else c; do d; while (e)",
+        "if (a) b\nelse c\ndo\n\td\nwhile (e)\n",
+        as_needed.clone(),
+    );
+    // Statements which would continue the previous statement.
+    test_options("a; (b || c)()", "a;\n(b || c)()\n", as_needed.clone());
+    test_options("a; [b] = c", "a;\n[b] = c\n", as_needed.clone());
+    test_options("a; `b`", "a;\n`b`\n", as_needed.clone());
+    test_options("a; +b; -c; /d/.e()", "a;\n+b;\n-c;\n/d/.e()\n", as_needed.clone());
+    test_options("a; ({} = b)", "a;\n({} = b)\n", as_needed.clone());
+    test_options(
+        "let a = b; (function() {})()",
+        "let a = b;\n(function() {})()\n",
+        as_needed.clone(),
+    );
+    test_options("if (a) b; (c || d)()", "if (a) b;\n(c || d)()\n", as_needed.clone());
+    test_options("function a() {} (b || c)()", "function a() {}\n(b || c)()\n", as_needed.clone());
+    test_options("{ a } (b || c)()", "{\n\ta\n}\n(b || c)()\n", as_needed.clone());
+    // Mappings after the inserted semicolon are not shifted.
+    let options = CodegenOptions {
+        semicolons: Semicolons::AsNeeded,
+        source_map_path: Some("x.js".into()),
+        ..CodegenOptions::default()
+    };
+    let ret = crate::codegen_options("a;\n(b || c)()", &options);
+    assert_eq!(ret.code, "a;\n(b || c)()\n");
+    for token in ret.map.unwrap().get_tokens() {
+        assert_eq!(
+            (token.get_dst_line(), token.get_dst_col()),
+            (token.get_src_line(), token.get_src_col())
+        );
+    }
+    // Class fields keep their semicolons.
+    test_options("class A { a = 1; [b] = 2 }", "class A {\n\ta = 1;\n\t[b] = 2;\n}\n", as_needed);
+}
+
+#[test]
+fn build_to_writer() {
+    let source_text = "#!/usr/bin/env node\n'use strict';\nlet a = 1;\nfoo(a)\n(b || c)()\n/* é */ if (a) {\n\tbar();\n}\n// end\n";
+    for semicolons in [Semicolons::Always, Semicolons::AsNeeded] {
+        let options = CodegenOptions {
+            semicolons,
+            source_map_path: Some("x.js".into()),
+            ..CodegenOptions::default()
+        };
+        let allocator = Allocator::default();
+        let program = Parser::new(&allocator, source_text, SourceType::mjs()).parse().program;
+        let expected = Codegen::new().with_options(options.clone()).build(&program);
+        for chunk_size in [0, 1, 30, 1000] {
+            let mut code = vec![];
+            let ret = Codegen::new()
+                .with_options(options.clone())
+                .build_to_writer(&program, &mut code, chunk_size)
+                .unwrap();
+            assert_eq!(String::from_utf8(code).unwrap(), expected.code);
+            assert_eq!(
+                ret.map.unwrap().to_json_string(),
+                expected.map.as_ref().unwrap().to_json_string()
+            );
+        }
+    }
+}
+
+#[test]
+fn merge_source_maps_of_stages() {
+    fn build<'a>(
+        allocator: &'a Allocator,
+        source_text: &'a str,
+        path: &str,
+    ) -> (String, SourceMap) {
+        let options =
+            CodegenOptions { source_map_path: Some(path.into()), ..CodegenOptions::minify() };
+        let program = Parser::new(allocator, source_text, SourceType::mjs()).parse().program;
+        let ret = Codegen::new().with_options(options).build(&program);
+        (ret.code, ret.map.unwrap())
+    }
+
+    let allocator = Allocator::default();
+    let source_text = "let a = 1;\n\nfoo(a);\n";
+    let (intermediate, input_map) = build(&allocator, source_text, "a.js");
+    let (code, map) = build(&allocator, allocator.alloc_str(&intermediate), "b.js");
+    let merged = merge_source_maps(&map, &input_map);
+    assert_eq!(merged.get_sources().collect::<Vec<_>>(), ["a.js"]);
+    assert_eq!(merged.get_source_contents().collect::<Vec<_>>(), [Some(source_text)]);
+    // `foo` is mapped to its position in the original source text.
+    let foo = code.find("foo").unwrap();
+    let token = merged
+        .get_tokens()
+        .find(|token| token.get_dst_line() == 0 && token.get_dst_col() as usize == foo)
+        .unwrap();
+    assert_eq!((token.get_src_line(), token.get_src_col()), (2, 0));
+    // Positions which are not mapped by the input source map are left unmapped.
+    let empty_map =
+        SourceMap::from_json_string(r#"{"version":3,"sources":["a.js"],"names":[],"mappings":""}"#)
+            .unwrap();
+    let merged = merge_source_maps(&map, &empty_map);
+    assert!(merged.get_tokens().all(|token| token.get_source_id().is_none()));
+}
+
+#[test]
+fn indent() {
+    let spaces = CodegenOptions {
+        indent_char: IndentChar::Space,
+        indent_width: 2,
+        ..CodegenOptions::default()
+    };
+    test_options("if (a) { if (b) { c } }", "if (a) {\n  if (b) {\n    c;\n  }\n}\n", spaces);
+    let tabs = CodegenOptions { indent_width: 2, ..CodegenOptions::default() };
+    test_options("if (a) { b }", "if (a) {\n\t\tb;\n}\n", tabs);
+}
+
+#[test]
+fn trailing_newline() {
+    let options = CodegenOptions { trailing_newline: false, ..CodegenOptions::default() };
+    test_options("a; b", "a;\nb;", options.clone());
+    test_options("function a() {}", "function a() {}", options);
+    test("", "");
+}
+
+#[test]
+fn line_ending() {
+    let options = CodegenOptions { line_ending: LineEnding::Crlf, ..CodegenOptions::default() };
+    test_options("a; if (b) { c }", "a;\r\nif (b) {\r\n\tc;\r\n}\r\n", options.clone());
+    // Template literals copied from the source text keep their line breaks.
+    test_options("x = `a\r\nb\nc`", "x = `a\r\nb\r\nc`;\r\n", options.clone());
+    let mut code = vec![];
+    let allocator = Allocator::default();
+    let program = Parser::new(&allocator, "a;\nb;", SourceType::mjs()).parse().program;
+    Codegen::new().with_options(options).build_to_writer(&program, &mut code, 0).unwrap();
+    assert_eq!(code, b"a;\r\nb;\r\n");
+}
+
+#[test]
+fn banner_and_footer() {
+    let options = CodegenOptions {
+        banner: Some("/* banner */".into()),
+        footer: Some("// footer".into()),
+        ..CodegenOptions::default()
+    };
+    test_options(
+        "#!/usr/bin/env node\na",
+        "#!/usr/bin/env node\n/* banner */\na;\n// footer\n",
+        options.clone(),
+    );
+    test_options(
+        "a",
+        "/* banner */\na;\n// footer",
+        CodegenOptions { trailing_newline: false, ..options.clone() },
+    );
+    test_options(
+        "a",
+        "/* banner */\na;\n// footer",
+        CodegenOptions {
+            banner: options.banner.clone(),
+            footer: options.footer,
+            ..CodegenOptions::minify()
+        },
+    );
+    // Mappings are shifted by the lines of the banner.
+    let options = CodegenOptions {
+        banner: Some("/**\n * banner\n */".into()),
+        source_map_path: Some("x.js".into()),
+        ..CodegenOptions::default()
+    };
+    let ret = crate::codegen_options("a;\nb;", &options);
+    assert_eq!(ret.code, "/**\n * banner\n */\na;\nb;\n");
+    for token in ret.map.unwrap().get_tokens() {
+        assert_eq!(token.get_dst_line(), token.get_src_line() + 3);
+    }
+}
+
 #[test]
 fn v8_intrinsics() {
     let parse_opts = oxc_parser::ParseOptions {
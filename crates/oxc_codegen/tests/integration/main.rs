@@ -6,7 +6,7 @@ pub mod tester;
 pub mod ts;
 
 use oxc_allocator::Allocator;
-use oxc_codegen::{Codegen, CodegenOptions, CodegenReturn};
+use oxc_codegen::{Codegen, CodegenOptions, CodegenReturn, QuoteStyle};
 use oxc_parser::Parser;
 use oxc_span::SourceType;
 
@@ -21,7 +21,7 @@ pub fn codegen_options(source_text: &str, options: &CodegenOptions) -> CodegenRe
     let source_type = SourceType::ts();
     let ret = Parser::new(&allocator, source_text, source_type).parse();
     let mut options = options.clone();
-    options.single_quote = true;
+    options.quote_style = QuoteStyle::Single;
     Codegen::new().with_options(options).build(&ret.program)
 }
 
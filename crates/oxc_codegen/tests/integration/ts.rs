@@ -16,6 +16,11 @@ fn cases() {
     );
     test_same("class B {\n\tconstructor(override readonly a: number) {}\n}\n");
     test_same("export { type as as };\n");
+    test_same("class C extends B {\n\toverride m() {}\n\tpublic static override async n() {}\n}\n");
+    test_same("class C {\n\tx!: string;\n\taccessor y!: number;\n}\n");
+    test_same("const a = { x: 1 } satisfies X;\n");
+    test_same("f([1 satisfies number], (a satisfies b).c, -(d satisfies number));\n");
+    test_same("async function f() {\n\tfor (await using x of y) {}\n}\n");
 }
 
 #[test]
@@ -28,6 +33,8 @@ fn decorators() {
 fn tsx() {
     test_tsx("<T,>() => {}", "<T,>() => {};\n");
     test_tsx("<T, B>() => {}", "<\n\tT,\n\tB\n>() => {};\n");
+    test_tsx("<Foo<string> a={1} />", "<Foo<string> a={1} />;\n");
+    test_tsx("<Foo<T>></Foo>", "<Foo<T>></Foo>;\n");
 }
 
 #[test]
@@ -132,6 +139,7 @@ export import b = require("b");
   static
   bar() {}
 }",
+        r#"type A = import("a", { with: { "resolution-mode": "import" } }).B;"#,
     ];
 
     snapshot("ts", &cases);
@@ -458,6 +458,60 @@ impl CodeBuffer {
         unsafe { self.buf.set_len(len + n) };
     }
 
+    /// Insert a single ASCII byte into the buffer at `index`.
+    ///
+    /// This is *O*(*n*) in the number of bytes after `index`.
+    ///
+    /// # Panics
+    /// Panics if `byte` is not an ASCII byte (`0 - 0x7F`),
+    /// or if `index` is greater than the length of the buffer or not on a UTF-8 character boundary.
+    ///
+    /// # Example
+    /// ```
+    /// # use oxc_data_structures::code_buffer::CodeBuffer;
+    /// let mut code = CodeBuffer::new();
+    /// code.print_str("a\n(b)");
+    /// code.insert_ascii_byte(1, b';');
+    ///
+    /// let source = code.into_string();
+    /// assert_eq!(source, "a;\n(b)");
+    /// ```
+    pub fn insert_ascii_byte(&mut self, index: usize, byte: u8) {
+        assert!(byte.is_ascii(), "byte {byte} is not ASCII");
+        // UTF-8 continuation bytes are `0b10xx_xxxx`
+        assert!(
+            self.buf.get(index).is_none_or(|&b| !matches!(b, 0x80..=0xBF)),
+            "index {index} is not on a UTF-8 character boundary"
+        );
+        self.buf.insert(index, byte);
+    }
+
+    /// Remove the first `len` bytes from the buffer, e.g. after writing them to a file.
+    ///
+    /// # Panics
+    /// Panics if `len` is greater than the length of the buffer,
+    /// or is not on a UTF-8 character boundary.
+    ///
+    /// # Example
+    /// ```
+    /// # use oxc_data_structures::code_buffer::CodeBuffer;
+    /// let mut code = CodeBuffer::new();
+    /// code.print_str("a;\nb;");
+    /// code.remove_prefix(3);
+    ///
+    /// let source = code.into_string();
+    /// assert_eq!(source, "b;");
+    /// ```
+    pub fn remove_prefix(&mut self, len: usize) {
+        assert!(len <= self.buf.len(), "len {len} is greater than the length of the buffer");
+        // UTF-8 continuation bytes are `0b10xx_xxxx`
+        assert!(
+            self.buf.get(len).is_none_or(|&b| !matches!(b, 0x80..=0xBF)),
+            "len {len} is not on a UTF-8 character boundary"
+        );
+        self.buf.drain(..len);
+    }
+
     /// Get contents of buffer as a byte slice.
     ///
     /// # Example
@@ -605,6 +659,25 @@ mod test {
         assert_eq!(String::from(code), "foo");
     }
 
+    #[test]
+    fn insert_ascii_byte() {
+        let mut code = CodeBuffer::new();
+        code.print_str("ÖÖ");
+        code.insert_ascii_byte(2, b'a');
+        code.insert_ascii_byte(5, b'b');
+        code.insert_ascii_byte(0, b'c');
+
+        assert_eq!(String::from(code), "cÖaÖb");
+    }
+
+    #[test]
+    #[should_panic(expected = "is not on a UTF-8 character boundary")]
+    fn insert_ascii_byte_not_on_char_boundary() {
+        let mut code = CodeBuffer::new();
+        code.print_str("Ö");
+        code.insert_ascii_byte(1, b'a');
+    }
+
     #[test]
     fn peek_nth_char_back() {
         let mut code = CodeBuffer::new();
@@ -0,0 +1,269 @@
+//! Translations of diagnostic messages.
+
+use cow_utils::CowUtils;
+use miette::LabeledSpan;
+use rustc_hash::FxHashMap;
+
+use crate::{OxcDiagnostic, Result};
+
+/// Translations of the messages, help texts and labels of diagnostics into one locale.
+///
+/// A catalog is a JSON object which maps error codes to the translations of their texts:
+///
+/// ```json
+/// {
+///   "eslint(no-debugger)": {
+///     "`debugger` statement is not allowed": "`debugger`-Anweisungen sind nicht erlaubt"
+///   },
+///   "oxc(no-barrel-file)": {
+///     "Barrel file detected, {total} modules are loaded.": "Barrel-Datei erkannt, {total} Module werden geladen."
+///   }
+/// }
+/// ```
+///
+/// A `{name}` placeholder matches any text, which is substituted for the same placeholder in the
+/// translation. Texts without a translation are kept in English.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct MessageCatalog {
+    locale: String,
+    translations: FxHashMap<String, Vec<Translation>>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Translation {
+    source: Vec<Segment>,
+    target: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Segment {
+    Text(String),
+    Placeholder(String),
+}
+
+impl MessageCatalog {
+    /// # Errors
+    ///
+    /// * `json` is not an object of objects of strings
+    pub fn from_json(locale: &str, json: &str) -> Result<Self> {
+        let invalid = |reason: String| {
+            OxcDiagnostic::error(format!("Invalid catalog for {locale}: {reason}"))
+        };
+        let value: serde_json::Value =
+            serde_json::from_str(json).map_err(|err| invalid(err.to_string()))?;
+        let Some(codes) = value.as_object() else {
+            return Err(invalid("expected an object".to_string()));
+        };
+
+        let mut translations = FxHashMap::default();
+        for (code, texts) in codes {
+            let Some(texts) = texts.as_object() else {
+                return Err(invalid(format!("expected an object for {code}")));
+            };
+            let texts = texts
+                .iter()
+                .map(|(source, target)| {
+                    let Some(target) = target.as_str() else {
+                        return Err(invalid(format!("expected a string for {source:?}")));
+                    };
+                    Ok(Translation { source: parse_template(source), target: target.to_string() })
+                })
+                .collect::<Result<Vec<_>>>()?;
+            translations.insert(code.clone(), texts);
+        }
+        Ok(Self { locale: locale.to_string(), translations })
+    }
+
+    pub fn locale(&self) -> &str {
+        &self.locale
+    }
+
+    /// The error codes with translations, e.g. `eslint(no-debugger)`.
+    pub fn codes(&self) -> impl Iterator<Item = &str> {
+        self.translations.keys().map(String::as_str)
+    }
+
+    /// The English texts translated for `code`, with their placeholders.
+    pub fn sources(&self, code: &str) -> impl Iterator<Item = String> + '_ {
+        self.translations.get(code).into_iter().flatten().map(|translation| {
+            translation
+                .source
+                .iter()
+                .map(|segment| match segment {
+                    Segment::Text(text) => text.clone(),
+                    Segment::Placeholder(name) => format!("{{{name}}}"),
+                })
+                .collect()
+        })
+    }
+
+    /// Translate the message, help text and labels of `diagnostic`.
+    pub fn translate(&self, mut diagnostic: OxcDiagnostic) -> OxcDiagnostic {
+        let Some(translations) = self.translations.get(&diagnostic.code.to_string()) else {
+            return diagnostic;
+        };
+        if let Some(message) = translate_text(translations, &diagnostic.message) {
+            diagnostic.message = message.into();
+        }
+        if let Some(help) =
+            diagnostic.help.as_deref().and_then(|help| translate_text(translations, help))
+        {
+            diagnostic.help = Some(help.into());
+        }
+        if let Some(labels) = &mut diagnostic.labels {
+            translate_labels(translations, labels);
+        }
+        for related in diagnostic.related.iter_mut().flatten() {
+            if let Some(message) = translate_text(translations, &related.message) {
+                related.message = message.into();
+            }
+            translate_labels(translations, &mut related.labels);
+        }
+        diagnostic
+    }
+}
+
+fn translate_labels(translations: &[Translation], labels: &mut [LabeledSpan]) {
+    for label in labels {
+        if let Some(text) = label.label().and_then(|text| translate_text(translations, text)) {
+            label.set_label(Some(text));
+        }
+    }
+}
+
+fn translate_text(translations: &[Translation], text: &str) -> Option<String> {
+    translations.iter().find_map(|translation| {
+        let mut captures = vec![];
+        if !match_template(&translation.source, text, &mut captures) {
+            return None;
+        }
+        let mut target = translation.target.clone();
+        for (name, value) in captures {
+            target = target.cow_replace(&format!("{{{name}}}"), value).into_owned();
+        }
+        Some(target)
+    })
+}
+
+/// Split `source` into texts and `{name}` placeholders.
+fn parse_template(source: &str) -> Vec<Segment> {
+    let mut segments = vec![];
+    let mut rest = source;
+    while let Some(start) = rest.find('{') {
+        let Some(len) = rest[start..].find('}') else { break };
+        let name = &rest[start + 1..start + len];
+        if name.is_empty() || !name.chars().all(|c| c.is_alphanumeric() || c == '_') {
+            // e.g. `{}` in code, which is not a placeholder.
+            segments.push(Segment::Text(rest[..=start].to_string()));
+            rest = &rest[start + 1..];
+            continue;
+        }
+        if start > 0 {
+            segments.push(Segment::Text(rest[..start].to_string()));
+        }
+        segments.push(Segment::Placeholder(name.to_string()));
+        rest = &rest[start + len + 1..];
+    }
+    if !rest.is_empty() {
+        segments.push(Segment::Text(rest.to_string()));
+    }
+    // Merge the texts split at `{`.
+    segments.dedup_by(|next, prev| match (prev, next) {
+        (Segment::Text(prev), Segment::Text(next)) => {
+            prev.push_str(next);
+            true
+        }
+        _ => false,
+    });
+    segments
+}
+
+fn match_template<'t>(
+    segments: &[Segment],
+    text: &'t str,
+    captures: &mut Vec<(String, &'t str)>,
+) -> bool {
+    match segments.split_first() {
+        None => text.is_empty(),
+        Some((Segment::Text(prefix), rest)) => text
+            .strip_prefix(prefix.as_str())
+            .is_some_and(|text| match_template(rest, text, captures)),
+        Some((Segment::Placeholder(name), rest)) => {
+            for (end, _) in text.char_indices().skip(1).chain([(text.len(), ' ')]) {
+                captures.push((name.clone(), &text[..end]));
+                if match_template(rest, &text[end..], captures) {
+                    return true;
+                }
+                captures.pop();
+            }
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::MessageCatalog;
+    use crate::OxcDiagnostic;
+
+    const CATALOG: &str = r#"{
+        "oxc(no-barrel-file)": {
+            "Barrel file detected, {total} modules are loaded.": "Barrel-Datei erkannt, {total} Module werden geladen.",
+            "{count} modules": "{count} Module"
+        },
+        "eslint(no-debugger)": {
+            "`debugger` statement is not allowed": "`debugger`-Anweisungen sind nicht erlaubt",
+            "Remove `{}` and the debugger statement": "Entferne `{}` und die debugger-Anweisung"
+        }
+    }"#;
+
+    #[test]
+    fn translate() {
+        let catalog = MessageCatalog::from_json("de", CATALOG).unwrap();
+        assert_eq!(catalog.locale(), "de");
+
+        let diagnostic = OxcDiagnostic::warn("Barrel file detected, 12 modules are loaded.")
+            .with_help("Loading 12 modules is slow.")
+            .with_label(label(0, 1, "12 modules"))
+            .with_error_code("oxc", "no-barrel-file");
+        let diagnostic = catalog.translate(diagnostic);
+        assert_eq!(diagnostic.message, "Barrel-Datei erkannt, 12 Module werden geladen.");
+        // Kept in English without a translation.
+        assert_eq!(diagnostic.help.as_deref(), Some("Loading 12 modules is slow."));
+        assert_eq!(diagnostic.labels.as_ref().unwrap()[0].label(), Some("12 Module"));
+
+        let diagnostic = OxcDiagnostic::warn("`debugger` statement is not allowed")
+            .with_help("Remove `{}` and the debugger statement")
+            .with_error_code("eslint", "no-debugger");
+        let diagnostic = catalog.translate(diagnostic);
+        assert_eq!(diagnostic.message, "`debugger`-Anweisungen sind nicht erlaubt");
+        assert_eq!(diagnostic.help.as_deref(), Some("Entferne `{}` und die debugger-Anweisung"));
+
+        // Only translated for the same code.
+        let diagnostic = OxcDiagnostic::warn("`debugger` statement is not allowed")
+            .with_error_code("eslint", "no-console");
+        assert_eq!(catalog.translate(diagnostic).message, "`debugger` statement is not allowed");
+    }
+
+    #[test]
+    fn sources() {
+        let catalog = MessageCatalog::from_json("de", CATALOG).unwrap();
+        let mut sources = catalog.sources("oxc(no-barrel-file)").collect::<Vec<_>>();
+        sources.sort();
+        assert_eq!(
+            sources,
+            ["Barrel file detected, {total} modules are loaded.", "{count} modules"]
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(MessageCatalog::from_json("de", "[]").is_err());
+        assert!(MessageCatalog::from_json("de", r#"{ "a": [] }"#).is_err());
+        assert!(MessageCatalog::from_json("de", r#"{ "a": { "b": 1 } }"#).is_err());
+    }
+
+    fn label(offset: usize, len: usize, label: &str) -> miette::LabeledSpan {
+        miette::LabeledSpan::new(Some(label.to_string()), offset, len)
+    }
+}
@@ -48,6 +48,8 @@
 //! service.run();
 //! ```
 
+mod catalog;
+mod related;
 mod service;
 
 use std::{
@@ -58,7 +60,11 @@ use std::{
 
 pub mod reporter;
 
-pub use crate::service::{DiagnosticSender, DiagnosticService, DiagnosticTuple};
+pub use crate::{
+    catalog::MessageCatalog,
+    related::RelatedInformation,
+    service::{DiagnosticSender, DiagnosticService, DiagnosticTuple},
+};
 
 pub type Error = miette::Error;
 pub type Severity = miette::Severity;
@@ -124,6 +130,8 @@ pub struct OxcDiagnosticInner {
     pub severity: Severity,
     pub code: OxcCode,
     pub url: Option<Cow<'static, str>>,
+    /// Labels in other files, see [`OxcDiagnostic::and_related`].
+    pub related: Option<Vec<RelatedInformation>>,
 }
 
 impl Display for OxcDiagnostic {
@@ -167,6 +175,14 @@ impl Diagnostic for OxcDiagnostic {
     fn url<'a>(&'a self) -> Option<Box<dyn Display + 'a>> {
         self.url.as_ref().map(Box::new).map(|c| c as Box<dyn Display>)
     }
+
+    /// Labels in other files, rendered after this diagnostic.
+    fn related<'a>(&'a self) -> Option<Box<dyn Iterator<Item = &'a dyn Diagnostic> + 'a>> {
+        self.related
+            .as_ref()
+            .map(|related| Box::new(related.iter().map(|r| r as &dyn Diagnostic)))
+            .map(|b| b as Box<dyn Iterator<Item = &'a dyn Diagnostic>>)
+    }
 }
 
 impl OxcDiagnostic {
@@ -180,6 +196,7 @@ impl OxcDiagnostic {
                 severity: Severity::Error,
                 code: OxcCode::default(),
                 url: None,
+                related: None,
             }),
         }
     }
@@ -194,6 +211,7 @@ impl OxcDiagnostic {
                 severity: Severity::Warning,
                 code: OxcCode::default(),
                 url: None,
+                related: None,
             }),
         }
     }
@@ -328,6 +346,15 @@ impl OxcDiagnostic {
         self
     }
 
+    /// Add labels in another file than the one this diagnostic is reported in, e.g. where an
+    /// imported binding is exported.
+    ///
+    /// The labels of this diagnostic itself are always in the file it is reported in.
+    pub fn and_related(mut self, related: RelatedInformation) -> Self {
+        self.inner.related.get_or_insert_with(Vec::new).push(related);
+        self
+    }
+
     /// Add a URL that provides more information about this diagnostic.
     pub fn with_url<S: Into<Cow<'static, str>>>(mut self, url: S) -> Self {
         self.inner.url = Some(url.into());
@@ -0,0 +1,148 @@
+//! Labels in other files than the one a diagnostic is reported in.
+
+use std::{
+    borrow::Cow,
+    fmt::{self, Display},
+    path::{Path, PathBuf},
+    sync::Arc,
+};
+
+use cow_utils::CowUtils;
+use miette::{Diagnostic, LabeledSpan, NamedSource, SourceCode};
+
+use crate::Severity;
+
+/// Labels covering portions of another file than the one a diagnostic is reported in, e.g. where
+/// an imported binding is exported.
+///
+/// Rendered after the diagnostic, with a snippet of the other file.
+///
+/// ## Example
+/// ```
+/// use oxc_diagnostics::{OxcDiagnostic, RelatedInformation};
+///
+/// OxcDiagnostic::warn("`Foo` is a type and cannot be imported as a value")
+///     .with_label(import_span.label("imported here"))
+///     .and_related(
+///         RelatedInformation::new("`Foo` is declared here", &exporter_path, exporter_source_text)
+///             .with_label(export_span.label("exported as a type here")),
+///     );
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct RelatedInformation {
+    pub message: Cow<'static, str>,
+    pub labels: Vec<LabeledSpan>,
+    path: PathBuf,
+    source: NamedSource<Arc<str>>,
+}
+
+impl RelatedInformation {
+    /// Create related information for the file at `path`, which contains `source_text`.
+    ///
+    /// Add the labels covering portions of `source_text` with
+    /// [`with_label`](RelatedInformation::with_label).
+    pub fn new<T: Into<Cow<'static, str>>, S: Into<Arc<str>>>(
+        message: T,
+        path: &Path,
+        source_text: S,
+    ) -> Self {
+        let path_display = path.to_string_lossy();
+        // replace windows \ path separator with posix style one, like `DiagnosticService`
+        let path_display = path_display.cow_replace('\\', "/");
+        Self {
+            message: message.into(),
+            labels: vec![],
+            path: path.to_path_buf(),
+            source: NamedSource::new(path_display, source_text.into()),
+        }
+    }
+
+    /// Add a label covering a portion of the source text of the other file.
+    #[must_use]
+    pub fn with_label<T: Into<LabeledSpan>>(mut self, label: T) -> Self {
+        self.labels.push(label.into());
+        self
+    }
+
+    /// The path of the other file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// The source text of the other file.
+    pub fn source_text(&self) -> &str {
+        self.source.inner()
+    }
+}
+
+impl Display for RelatedInformation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.message.fmt(f)
+    }
+}
+
+impl std::error::Error for RelatedInformation {}
+
+impl Diagnostic for RelatedInformation {
+    fn severity(&self) -> Option<Severity> {
+        Some(Severity::Advice)
+    }
+
+    fn labels(&self) -> Option<Box<dyn Iterator<Item = LabeledSpan> + '_>> {
+        Some(Box::new(self.labels.iter().cloned()))
+    }
+
+    fn source_code(&self) -> Option<&dyn SourceCode> {
+        Some(&self.source)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{path::Path, sync::Arc};
+
+    use miette::{GraphicalReportHandler, GraphicalTheme, JSONReportHandler, LabeledSpan};
+
+    use super::RelatedInformation;
+    use crate::{NamedSource, OxcDiagnostic};
+
+    const IMPORTER: &str = "import { Foo } from './b';\nnew Foo();\n";
+    const EXPORTER: &str = "type Foo = {};\nexport type { Foo };\n";
+
+    fn diagnostic() -> crate::Error {
+        OxcDiagnostic::error("`Foo` is a type and cannot be used as a value")
+            .with_label(LabeledSpan::new(Some("imported here".into()), 9, 3))
+            .and_related(
+                RelatedInformation::new("`Foo` is declared here", Path::new("b.ts"), EXPORTER)
+                    .with_label(LabeledSpan::new(Some("exported as a type here".into()), 29, 3)),
+            )
+            .with_source_code(Arc::new(NamedSource::new("a.ts", IMPORTER.to_string())))
+    }
+
+    #[test]
+    fn render_graphical() {
+        let handler = GraphicalReportHandler::new_themed(GraphicalTheme::unicode_nocolor());
+        let mut output = String::new();
+        handler.render_report(&mut output, diagnostic().as_ref()).unwrap();
+        assert!(output.contains("╭─[a.ts:1:10]"), "{output}");
+        assert!(output.contains("imported here"), "{output}");
+        assert!(output.contains("`Foo` is declared here"), "{output}");
+        assert!(output.contains("╭─[b.ts:2:15]"), "{output}");
+        assert!(output.contains("exported as a type here"), "{output}");
+    }
+
+    #[test]
+    fn render_json() {
+        let mut output = String::new();
+        JSONReportHandler::new().render_report(&mut output, diagnostic().as_ref()).unwrap();
+        let json: serde_json::Value = serde_json::from_str(&output).unwrap();
+        assert_eq!(json["filename"], "a.ts");
+        let related = &json["related"][0];
+        assert_eq!(related["message"], "`Foo` is declared here");
+        assert_eq!(related["severity"], "advice");
+        assert_eq!(related["filename"], "b.ts");
+        assert_eq!(related["labels"][0]["label"], "exported as a type here");
+        assert_eq!(related["labels"][0]["span"]["line"], 2);
+        assert_eq!(related["labels"][0]["span"]["column"], 15);
+    }
+}
@@ -1,5 +1,8 @@
 //! [Reporters](DiagnosticReporter) for rendering and writing diagnostics.
 
+use std::{collections::BTreeMap, path::Path};
+
+use cow_utils::CowUtils;
 use miette::SourceSpan;
 
 use crate::{Error, Severity};
@@ -68,11 +71,20 @@ pub struct DiagnosticResult {
     /// Did the threshold for warnings exceeded the max_warnings?
     /// ToDo: We giving the input from outside, let the owner calculate the result
     max_warnings_exceeded: bool,
+
+    /// Counts per rule and per directory, when collected.
+    stats: Option<DiagnosticStats>,
 }
 
 impl DiagnosticResult {
     pub fn new(warnings_count: usize, errors_count: usize, max_warnings_exceeded: bool) -> Self {
-        Self { warnings_count, errors_count, max_warnings_exceeded }
+        Self { warnings_count, errors_count, max_warnings_exceeded, stats: None }
+    }
+
+    #[must_use]
+    pub fn with_stats(mut self, stats: Option<DiagnosticStats>) -> Self {
+        self.stats = stats;
+        self
     }
 
     /// Get the number of warning-level diagnostics received.
@@ -89,6 +101,66 @@ impl DiagnosticResult {
     pub fn max_warnings_exceeded(&self) -> bool {
         self.max_warnings_exceeded
     }
+
+    /// Counts per rule and per directory, collected when
+    /// [`with_stats`](crate::DiagnosticService::with_stats) is enabled.
+    pub fn stats(&self) -> Option<&DiagnosticStats> {
+        self.stats.as_ref()
+    }
+}
+
+/// Number of error and warning diagnostics.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct SeverityCounts {
+    pub errors: usize,
+    pub warnings: usize,
+}
+
+impl SeverityCounts {
+    pub fn total(self) -> usize {
+        self.errors + self.warnings
+    }
+
+    fn add(&mut self, is_error: bool) {
+        if is_error {
+            self.errors += 1;
+        } else {
+            self.warnings += 1;
+        }
+    }
+}
+
+/// Numbers of diagnostics per rule, per directory and per severity.
+#[derive(Debug, Default, Clone)]
+pub struct DiagnosticStats {
+    /// Counts per diagnostic code, e.g. `eslint(no-debugger)`.
+    /// Diagnostics without a code, e.g. parse errors, are only counted in the other totals.
+    pub rules: BTreeMap<String, SeverityCounts>,
+
+    /// Counts per directory of the files, with `/` separators.
+    pub directories: BTreeMap<String, SeverityCounts>,
+
+    pub total: SeverityCounts,
+
+    /// Number of files which were not linted because they are generated or minified.
+    pub skipped_files: usize,
+}
+
+impl DiagnosticStats {
+    /// Count a diagnostic reported in the file at `path`.
+    pub fn record(&mut self, path: &Path, code: Option<String>, is_error: bool) {
+        if let Some(code) = code {
+            self.rules.entry(code).or_default().add(is_error);
+        }
+        let directory = match path.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => {
+                parent.to_string_lossy().cow_replace('\\', "/").into_owned()
+            }
+            _ => ".".to_string(),
+        };
+        self.directories.entry(directory).or_default().add(is_error);
+        self.total.add(is_error);
+    }
 }
 
 #[derive(Debug)]
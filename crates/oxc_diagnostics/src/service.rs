@@ -9,7 +9,7 @@ use miette::LabeledSpan;
 
 use crate::{
     Error, NamedSource, OxcDiagnostic, Severity,
-    reporter::{DiagnosticReporter, DiagnosticResult},
+    reporter::{DiagnosticReporter, DiagnosticResult, DiagnosticStats},
 };
 
 pub type DiagnosticTuple = (PathBuf, Vec<Error>);
@@ -61,6 +61,9 @@ pub struct DiagnosticService {
     /// which can be used to force exit with an error status if there are too many warning-level rule violations in your project
     max_warnings: Option<usize>,
 
+    /// Counts of the diagnostics per rule and per directory, when enabled
+    stats: Option<DiagnosticStats>,
+
     sender: DiagnosticSender,
     receiver: DiagnosticReceiver,
 }
@@ -70,7 +73,15 @@ impl DiagnosticService {
     /// provided [`DiagnosticReporter`].
     pub fn new(reporter: Box<dyn DiagnosticReporter>) -> Self {
         let (sender, receiver) = mpsc::channel();
-        Self { reporter, quiet: false, silent: false, max_warnings: None, sender, receiver }
+        Self {
+            reporter,
+            quiet: false,
+            silent: false,
+            max_warnings: None,
+            stats: None,
+            sender,
+            receiver,
+        }
     }
 
     /// Set to `true` to only report errors and ignore warnings.
@@ -109,6 +120,19 @@ impl DiagnosticService {
         self
     }
 
+    /// Set to `true` to count the errors and warnings per rule and per directory.
+    /// Warnings are counted even when they are not reported because of
+    /// [`with_quiet`](DiagnosticService::with_quiet).
+    ///
+    /// Use [`DiagnosticResult::stats`] to get the counts.
+    ///
+    /// Default: `false`
+    #[must_use]
+    pub fn with_stats(mut self, yes: bool) -> Self {
+        self.stats = yes.then(DiagnosticStats::default);
+        self
+    }
+
     /// Channel for sending [diagnostic messages] to the service.
     ///
     /// The service will only start processing diagnostics after [`run`](DiagnosticService::run)
@@ -189,6 +213,10 @@ impl DiagnosticService {
                 let is_warning = severity == Some(Severity::Warning);
                 let is_error = severity == Some(Severity::Error) || severity.is_none();
                 if is_warning || is_error {
+                    if let Some(stats) = &mut self.stats {
+                        let code = diagnostic.code().map(|code| code.to_string());
+                        stats.record(&path, code, is_error);
+                    }
                     if is_warning {
                         warnings_count += 1;
                     }
@@ -237,7 +265,8 @@ impl DiagnosticService {
             warnings_count,
             errors_count,
             self.max_warnings_exceeded(warnings_count),
-        );
+        )
+        .with_stats(self.stats.take());
 
         if let Some(finish_output) = self.reporter.finish(&result) {
             writer
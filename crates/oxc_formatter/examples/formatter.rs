@@ -3,9 +3,7 @@
 use std::{fs, path::Path};
 
 use oxc_allocator::Allocator;
-use oxc_formatter::{FormatOptions, Formatter};
-use oxc_parser::{ParseOptions, Parser};
-use oxc_span::SourceType;
+use oxc_formatter::{FileKind, FormatOptions, Formatter};
 use pico_args::Arguments;
 
 fn main() -> Result<(), String> {
@@ -14,22 +12,28 @@ fn main() -> Result<(), String> {
 
     let path = Path::new(&name);
     let source_text = fs::read_to_string(path).map_err(|_| format!("Missing '{name}'"))?;
-    let source_type = SourceType::from_path(path).unwrap();
+    let kind = FileKind::from_path(path).ok_or_else(|| format!("Cannot format '{name}'"))?;
     let allocator = Allocator::new();
-    let ret = Parser::new(&allocator, &source_text, source_type)
-        .with_options(ParseOptions { parse_regular_expression: true, ..ParseOptions::default() })
-        .parse();
 
-    for error in ret.errors {
-        let error = error.with_source_code(source_text.clone());
-        println!("{error:?}");
-        println!("Parsed with Errors.");
+    let options = match FormatOptions::discover_prettier_config(
+        path.parent().unwrap_or_else(|| Path::new(".")),
+    )? {
+        Some((config_path, options)) => {
+            println!("Using {}", config_path.display());
+            options
+        }
+        None => FormatOptions::default(),
+    };
+    match Formatter::new(&allocator, options).format_source(&source_text, kind) {
+        Ok(code) => println!("{code}"),
+        Err(errors) => {
+            for error in errors {
+                let error = error.with_source_code(source_text.clone());
+                println!("{error:?}");
+            }
+            println!("Parsed with Errors.");
+        }
     }
 
-    let options = FormatOptions::default();
-    let code = Formatter::new(&allocator, options).build(&ret.program);
-
-    println!("{code}");
-
     Ok(())
 }
@@ -30,6 +30,11 @@ impl<'a> Comments<'a> {
         Comments { source_text, comments, printed_count: 0 }
     }
 
+    #[inline]
+    pub fn printed_comments(&self) -> &'a [Comment] {
+        &self.comments[..self.printed_count]
+    }
+
     #[inline]
     pub fn unprinted_comments(&self) -> &'a [Comment] {
         &self.comments[self.printed_count..]
@@ -44,7 +44,7 @@ mod text_size;
 pub mod token;
 mod token_text;
 pub mod trivia;
-mod verbatim;
+pub mod verbatim;
 
 use std::{
     fmt::{Debug, Display},
@@ -710,8 +710,32 @@ impl<'a> Format<'a> for Comment {
                     Ok(())
                 })]
             )
+        } else if let Some(words) = wrappable_line_comment_text(self, source_text, f) {
+            // Break the comment between words when it exceeds the line width, continuing it
+            // on the next line with another `//`.
+            let separator = format_with(|f| {
+                write!(f, [soft_line_break_or_space(), if_group_breaks(&text("// "))])
+            });
+            write!(f, [text("// ")])?;
+            f.fill().entries(&separator, words.split(' ').map(dynamic_text)).finish()
         } else {
             write!(f, [dynamic_text(source_text)])
         }
     }
 }
+
+/// Returns the text of an own-line `// ...` comment if it should be wrapped with `proseWrap: "always"`.
+///
+/// Comments with consecutive spaces are left alone, since they are likely formatted on purpose,
+/// e.g. commented out code or tables.
+fn wrappable_line_comment_text<'a>(
+    comment: &Comment,
+    source_text: &'a str,
+    f: &Formatter<'_, 'a>,
+) -> Option<&'a str> {
+    if !f.options().prose_wrap.is_always() || !comment.is_line() || !comment.preceded_by_newline() {
+        return None;
+    }
+    let words = source_text.strip_prefix("// ")?.trim_end();
+    (!words.is_empty() && !words.contains("  ")).then_some(words)
+}
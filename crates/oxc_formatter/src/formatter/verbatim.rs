@@ -1,3 +1,77 @@
+use std::borrow::Cow;
+
+use cow_utils::CowUtils;
+use oxc_ast::Comment;
+use oxc_span::Span;
+
+use crate::{
+    formatter::{Buffer, Format, FormatElement, FormatResult, Formatter},
+    write::FormatWrite,
+};
+
+/// Returns `true` if `comment` is `prettier-ignore` or `oxc-format-ignore`, which leave the
+/// following node as it is in the source text.
+pub fn is_suppression_comment(comment: &Comment, source_text: &str) -> bool {
+    matches!(
+        comment.content_span().source_text(source_text).trim(),
+        "prettier-ignore" | "oxc-format-ignore"
+    )
+}
+
+/// Returns `true` if `comment` is `oxc-format-off`, which starts a range of statements that are
+/// left as they are in the source text.
+pub fn is_format_off_comment(comment: &Comment, source_text: &str) -> bool {
+    comment.content_span().source_text(source_text).trim() == "oxc-format-off"
+}
+
+/// Returns `true` if `comment` is `oxc-format-on`, which ends a range started by `oxc-format-off`.
+pub fn is_format_on_comment(comment: &Comment, source_text: &str) -> bool {
+    comment.content_span().source_text(source_text).trim() == "oxc-format-on"
+}
+
+/// Returns `true` if the node at `span` directly follows a suppression comment, which has been
+/// printed as one of its leading comments.
+pub fn is_suppressed(span: Span, f: &Formatter<'_, '_>) -> bool {
+    let source_text = f.source_text();
+    f.context().comments().printed_comments().last().is_some_and(|comment| {
+        comment.span.end <= span.start
+            && is_suppression_comment(comment, source_text)
+            && Span::new(comment.span.end, span.start).source_text(source_text).trim().is_empty()
+    })
+}
+
+/// Writes `node`, or its source text if it is [suppressed](is_suppressed).
+pub fn write_unless_suppressed<'a>(
+    node: &impl FormatWrite<'a>,
+    span: Span,
+    f: &mut Formatter<'_, 'a>,
+) -> FormatResult<()> {
+    if is_suppressed(span, f) { FormatSuppressedNode(span).fmt(f) } else { node.write(f) }
+}
+
+/// Prints the source text of `span` as it is, including the comments in it.
+pub struct FormatSuppressedNode(pub Span);
+
+impl<'a> Format<'a> for FormatSuppressedNode {
+    fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
+        while f
+            .context()
+            .comments()
+            .unprinted_comments()
+            .first()
+            .is_some_and(|comment| comment.span.start < self.0.end)
+        {
+            f.context_mut().increment_printed_count();
+        }
+        let text = self.0.source_text(f.source_text());
+        let text = match text.cow_replace("\r\n", "\n") {
+            Cow::Borrowed(text) => text,
+            Cow::Owned(text) => f.context().allocator().alloc_str(&text),
+        };
+        f.write_element(FormatElement::DynamicText { text })
+    }
+}
+
 // use super::trivia::{FormatLeadingComments, FormatTrailingComments};
 // use biome_rowan::{AstNode, Direction, Language, SyntaxElement, SyntaxNode, TextRange};
 
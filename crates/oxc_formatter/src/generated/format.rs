@@ -7,6 +7,7 @@ use crate::{
     formatter::{
         Buffer, Format, FormatResult, Formatter,
         trivia::{FormatTrailingComments, format_leading_comments, format_trailing_comments},
+        verbatim::write_unless_suppressed,
     },
     generated::ast_nodes::{AstNode, SiblingNode},
     parentheses::NeedsParentheses,
@@ -30,7 +31,7 @@ impl<'a> Format<'a> for AstNode<'a, Expression<'a>> {
 impl<'a> Format<'a> for AstNode<'a, IdentifierName<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -44,7 +45,7 @@ impl<'a> Format<'a> for AstNode<'a, IdentifierName<'a>> {
 impl<'a> Format<'a> for AstNode<'a, IdentifierReference<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -58,7 +59,7 @@ impl<'a> Format<'a> for AstNode<'a, IdentifierReference<'a>> {
 impl<'a> Format<'a> for AstNode<'a, BindingIdentifier<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -72,7 +73,7 @@ impl<'a> Format<'a> for AstNode<'a, BindingIdentifier<'a>> {
 impl<'a> Format<'a> for AstNode<'a, LabelIdentifier<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -90,7 +91,7 @@ impl<'a> Format<'a> for AstNode<'a, ThisExpression> {
         if needs_parentheses {
             "(".fmt(f)?;
         }
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         if needs_parentheses {
             ")".fmt(f)?;
         }
@@ -111,7 +112,7 @@ impl<'a> Format<'a> for AstNode<'a, ArrayExpression<'a>> {
         if needs_parentheses {
             "(".fmt(f)?;
         }
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         if needs_parentheses {
             ")".fmt(f)?;
         }
@@ -134,7 +135,7 @@ impl<'a> Format<'a> for AstNode<'a, ArrayExpressionElement<'a>> {
 impl<'a> Format<'a> for AstNode<'a, Elision> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -152,7 +153,7 @@ impl<'a> Format<'a> for AstNode<'a, ObjectExpression<'a>> {
         if needs_parentheses {
             "(".fmt(f)?;
         }
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         if needs_parentheses {
             ")".fmt(f)?;
         }
@@ -175,7 +176,7 @@ impl<'a> Format<'a> for AstNode<'a, ObjectPropertyKind<'a>> {
 impl<'a> Format<'a> for AstNode<'a, ObjectProperty<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -195,7 +196,7 @@ impl<'a> Format<'a> for AstNode<'a, PropertyKey<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TemplateLiteral<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -213,7 +214,7 @@ impl<'a> Format<'a> for AstNode<'a, TaggedTemplateExpression<'a>> {
         if needs_parentheses {
             "(".fmt(f)?;
         }
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         if needs_parentheses {
             ")".fmt(f)?;
         }
@@ -254,7 +255,7 @@ impl<'a> Format<'a> for AstNode<'a, ComputedMemberExpression<'a>> {
         if needs_parentheses {
             "(".fmt(f)?;
         }
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         if needs_parentheses {
             ")".fmt(f)?;
         }
@@ -287,7 +288,7 @@ impl<'a> Format<'a> for AstNode<'a, CallExpression<'a>> {
         if needs_parentheses {
             "(".fmt(f)?;
         }
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         if needs_parentheses {
             ")".fmt(f)?;
         }
@@ -308,7 +309,7 @@ impl<'a> Format<'a> for AstNode<'a, NewExpression<'a>> {
         if needs_parentheses {
             "(".fmt(f)?;
         }
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         if needs_parentheses {
             ")".fmt(f)?;
         }
@@ -325,7 +326,7 @@ impl<'a> Format<'a> for AstNode<'a, NewExpression<'a>> {
 impl<'a> Format<'a> for AstNode<'a, MetaProperty<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -339,7 +340,7 @@ impl<'a> Format<'a> for AstNode<'a, MetaProperty<'a>> {
 impl<'a> Format<'a> for AstNode<'a, SpreadElement<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -363,7 +364,7 @@ impl<'a> Format<'a> for AstNode<'a, UpdateExpression<'a>> {
         if needs_parentheses {
             "(".fmt(f)?;
         }
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         if needs_parentheses {
             ")".fmt(f)?;
         }
@@ -384,7 +385,7 @@ impl<'a> Format<'a> for AstNode<'a, UnaryExpression<'a>> {
         if needs_parentheses {
             "(".fmt(f)?;
         }
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         if needs_parentheses {
             ")".fmt(f)?;
         }
@@ -405,7 +406,7 @@ impl<'a> Format<'a> for AstNode<'a, BinaryExpression<'a>> {
         if needs_parentheses {
             "(".fmt(f)?;
         }
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         if needs_parentheses {
             ")".fmt(f)?;
         }
@@ -426,7 +427,7 @@ impl<'a> Format<'a> for AstNode<'a, PrivateInExpression<'a>> {
         if needs_parentheses {
             "(".fmt(f)?;
         }
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         if needs_parentheses {
             ")".fmt(f)?;
         }
@@ -447,7 +448,7 @@ impl<'a> Format<'a> for AstNode<'a, LogicalExpression<'a>> {
         if needs_parentheses {
             "(".fmt(f)?;
         }
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         if needs_parentheses {
             ")".fmt(f)?;
         }
@@ -468,7 +469,7 @@ impl<'a> Format<'a> for AstNode<'a, ConditionalExpression<'a>> {
         if needs_parentheses {
             "(".fmt(f)?;
         }
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         if needs_parentheses {
             ")".fmt(f)?;
         }
@@ -489,7 +490,7 @@ impl<'a> Format<'a> for AstNode<'a, AssignmentExpression<'a>> {
         if needs_parentheses {
             "(".fmt(f)?;
         }
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         if needs_parentheses {
             ")".fmt(f)?;
         }
@@ -532,7 +533,7 @@ impl<'a> Format<'a> for AstNode<'a, AssignmentTargetPattern<'a>> {
 impl<'a> Format<'a> for AstNode<'a, ArrayAssignmentTarget<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -546,7 +547,7 @@ impl<'a> Format<'a> for AstNode<'a, ArrayAssignmentTarget<'a>> {
 impl<'a> Format<'a> for AstNode<'a, ObjectAssignmentTarget<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -572,7 +573,7 @@ impl<'a> Format<'a> for AstNode<'a, AssignmentTargetMaybeDefault<'a>> {
 impl<'a> Format<'a> for AstNode<'a, AssignmentTargetWithDefault<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -608,7 +609,7 @@ impl<'a> Format<'a> for AstNode<'a, SequenceExpression<'a>> {
         if needs_parentheses {
             "(".fmt(f)?;
         }
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         if needs_parentheses {
             ")".fmt(f)?;
         }
@@ -625,7 +626,7 @@ impl<'a> Format<'a> for AstNode<'a, SequenceExpression<'a>> {
 impl<'a> Format<'a> for AstNode<'a, Super> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -643,7 +644,7 @@ impl<'a> Format<'a> for AstNode<'a, AwaitExpression<'a>> {
         if needs_parentheses {
             "(".fmt(f)?;
         }
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         if needs_parentheses {
             ")".fmt(f)?;
         }
@@ -664,7 +665,7 @@ impl<'a> Format<'a> for AstNode<'a, ChainExpression<'a>> {
         if needs_parentheses {
             "(".fmt(f)?;
         }
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         if needs_parentheses {
             ")".fmt(f)?;
         }
@@ -691,7 +692,7 @@ impl<'a> Format<'a> for AstNode<'a, ParenthesizedExpression<'a>> {
         if needs_parentheses {
             "(".fmt(f)?;
         }
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         if needs_parentheses {
             ")".fmt(f)?;
         }
@@ -714,7 +715,7 @@ impl<'a> Format<'a> for AstNode<'a, Statement<'a>> {
 impl<'a> Format<'a> for AstNode<'a, Directive<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -728,7 +729,7 @@ impl<'a> Format<'a> for AstNode<'a, Directive<'a>> {
 impl<'a> Format<'a> for AstNode<'a, Hashbang<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -742,7 +743,7 @@ impl<'a> Format<'a> for AstNode<'a, Hashbang<'a>> {
 impl<'a> Format<'a> for AstNode<'a, BlockStatement<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -762,7 +763,7 @@ impl<'a> Format<'a> for AstNode<'a, Declaration<'a>> {
 impl<'a> Format<'a> for AstNode<'a, VariableDeclaration<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -776,7 +777,7 @@ impl<'a> Format<'a> for AstNode<'a, VariableDeclaration<'a>> {
 impl<'a> Format<'a> for AstNode<'a, VariableDeclarator<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -790,7 +791,7 @@ impl<'a> Format<'a> for AstNode<'a, VariableDeclarator<'a>> {
 impl<'a> Format<'a> for AstNode<'a, EmptyStatement> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -804,7 +805,7 @@ impl<'a> Format<'a> for AstNode<'a, EmptyStatement> {
 impl<'a> Format<'a> for AstNode<'a, ExpressionStatement<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -818,7 +819,7 @@ impl<'a> Format<'a> for AstNode<'a, ExpressionStatement<'a>> {
 impl<'a> Format<'a> for AstNode<'a, IfStatement<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -832,7 +833,7 @@ impl<'a> Format<'a> for AstNode<'a, IfStatement<'a>> {
 impl<'a> Format<'a> for AstNode<'a, DoWhileStatement<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -846,7 +847,7 @@ impl<'a> Format<'a> for AstNode<'a, DoWhileStatement<'a>> {
 impl<'a> Format<'a> for AstNode<'a, WhileStatement<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -860,7 +861,7 @@ impl<'a> Format<'a> for AstNode<'a, WhileStatement<'a>> {
 impl<'a> Format<'a> for AstNode<'a, ForStatement<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -880,7 +881,7 @@ impl<'a> Format<'a> for AstNode<'a, ForStatementInit<'a>> {
 impl<'a> Format<'a> for AstNode<'a, ForInStatement<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -900,7 +901,7 @@ impl<'a> Format<'a> for AstNode<'a, ForStatementLeft<'a>> {
 impl<'a> Format<'a> for AstNode<'a, ForOfStatement<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -914,7 +915,7 @@ impl<'a> Format<'a> for AstNode<'a, ForOfStatement<'a>> {
 impl<'a> Format<'a> for AstNode<'a, ContinueStatement<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -928,7 +929,7 @@ impl<'a> Format<'a> for AstNode<'a, ContinueStatement<'a>> {
 impl<'a> Format<'a> for AstNode<'a, BreakStatement<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -942,7 +943,7 @@ impl<'a> Format<'a> for AstNode<'a, BreakStatement<'a>> {
 impl<'a> Format<'a> for AstNode<'a, ReturnStatement<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -956,7 +957,7 @@ impl<'a> Format<'a> for AstNode<'a, ReturnStatement<'a>> {
 impl<'a> Format<'a> for AstNode<'a, WithStatement<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -970,7 +971,7 @@ impl<'a> Format<'a> for AstNode<'a, WithStatement<'a>> {
 impl<'a> Format<'a> for AstNode<'a, SwitchStatement<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -984,7 +985,7 @@ impl<'a> Format<'a> for AstNode<'a, SwitchStatement<'a>> {
 impl<'a> Format<'a> for AstNode<'a, SwitchCase<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -998,7 +999,7 @@ impl<'a> Format<'a> for AstNode<'a, SwitchCase<'a>> {
 impl<'a> Format<'a> for AstNode<'a, LabeledStatement<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -1012,7 +1013,7 @@ impl<'a> Format<'a> for AstNode<'a, LabeledStatement<'a>> {
 impl<'a> Format<'a> for AstNode<'a, ThrowStatement<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -1026,7 +1027,7 @@ impl<'a> Format<'a> for AstNode<'a, ThrowStatement<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TryStatement<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -1040,7 +1041,7 @@ impl<'a> Format<'a> for AstNode<'a, TryStatement<'a>> {
 impl<'a> Format<'a> for AstNode<'a, CatchClause<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -1054,7 +1055,7 @@ impl<'a> Format<'a> for AstNode<'a, CatchClause<'a>> {
 impl<'a> Format<'a> for AstNode<'a, CatchParameter<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -1068,7 +1069,7 @@ impl<'a> Format<'a> for AstNode<'a, CatchParameter<'a>> {
 impl<'a> Format<'a> for AstNode<'a, DebuggerStatement> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -1094,7 +1095,7 @@ impl<'a> Format<'a> for AstNode<'a, BindingPatternKind<'a>> {
 impl<'a> Format<'a> for AstNode<'a, AssignmentPattern<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -1108,7 +1109,7 @@ impl<'a> Format<'a> for AstNode<'a, AssignmentPattern<'a>> {
 impl<'a> Format<'a> for AstNode<'a, ObjectPattern<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -1128,7 +1129,7 @@ impl<'a> Format<'a> for AstNode<'a, BindingProperty<'a>> {
 impl<'a> Format<'a> for AstNode<'a, ArrayPattern<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -1142,7 +1143,7 @@ impl<'a> Format<'a> for AstNode<'a, ArrayPattern<'a>> {
 impl<'a> Format<'a> for AstNode<'a, BindingRestElement<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -1160,7 +1161,7 @@ impl<'a> Format<'a> for AstNode<'a, Function<'a>> {
         if needs_parentheses {
             "(".fmt(f)?;
         }
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         if needs_parentheses {
             ")".fmt(f)?;
         }
@@ -1177,7 +1178,7 @@ impl<'a> Format<'a> for AstNode<'a, Function<'a>> {
 impl<'a> Format<'a> for AstNode<'a, FormalParameters<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -1191,7 +1192,7 @@ impl<'a> Format<'a> for AstNode<'a, FormalParameters<'a>> {
 impl<'a> Format<'a> for AstNode<'a, FormalParameter<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -1205,7 +1206,7 @@ impl<'a> Format<'a> for AstNode<'a, FormalParameter<'a>> {
 impl<'a> Format<'a> for AstNode<'a, FunctionBody<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -1223,7 +1224,7 @@ impl<'a> Format<'a> for AstNode<'a, ArrowFunctionExpression<'a>> {
         if needs_parentheses {
             "(".fmt(f)?;
         }
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         if needs_parentheses {
             ")".fmt(f)?;
         }
@@ -1244,7 +1245,7 @@ impl<'a> Format<'a> for AstNode<'a, YieldExpression<'a>> {
         if needs_parentheses {
             "(".fmt(f)?;
         }
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         if needs_parentheses {
             ")".fmt(f)?;
         }
@@ -1265,7 +1266,7 @@ impl<'a> Format<'a> for AstNode<'a, Class<'a>> {
         if needs_parentheses {
             "(".fmt(f)?;
         }
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         if needs_parentheses {
             ")".fmt(f)?;
         }
@@ -1282,7 +1283,7 @@ impl<'a> Format<'a> for AstNode<'a, Class<'a>> {
 impl<'a> Format<'a> for AstNode<'a, ClassBody<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -1302,7 +1303,7 @@ impl<'a> Format<'a> for AstNode<'a, ClassElement<'a>> {
 impl<'a> Format<'a> for AstNode<'a, MethodDefinition<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -1316,7 +1317,7 @@ impl<'a> Format<'a> for AstNode<'a, MethodDefinition<'a>> {
 impl<'a> Format<'a> for AstNode<'a, PropertyDefinition<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -1330,7 +1331,7 @@ impl<'a> Format<'a> for AstNode<'a, PropertyDefinition<'a>> {
 impl<'a> Format<'a> for AstNode<'a, PrivateIdentifier<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -1344,7 +1345,7 @@ impl<'a> Format<'a> for AstNode<'a, PrivateIdentifier<'a>> {
 impl<'a> Format<'a> for AstNode<'a, StaticBlock<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -1364,7 +1365,7 @@ impl<'a> Format<'a> for AstNode<'a, ModuleDeclaration<'a>> {
 impl<'a> Format<'a> for AstNode<'a, AccessorProperty<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -1382,7 +1383,7 @@ impl<'a> Format<'a> for AstNode<'a, ImportExpression<'a>> {
         if needs_parentheses {
             "(".fmt(f)?;
         }
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         if needs_parentheses {
             ")".fmt(f)?;
         }
@@ -1399,7 +1400,7 @@ impl<'a> Format<'a> for AstNode<'a, ImportExpression<'a>> {
 impl<'a> Format<'a> for AstNode<'a, ImportDeclaration<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -1419,7 +1420,7 @@ impl<'a> Format<'a> for AstNode<'a, ImportDeclarationSpecifier<'a>> {
 impl<'a> Format<'a> for AstNode<'a, ImportSpecifier<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -1433,7 +1434,7 @@ impl<'a> Format<'a> for AstNode<'a, ImportSpecifier<'a>> {
 impl<'a> Format<'a> for AstNode<'a, ImportDefaultSpecifier<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -1447,7 +1448,7 @@ impl<'a> Format<'a> for AstNode<'a, ImportDefaultSpecifier<'a>> {
 impl<'a> Format<'a> for AstNode<'a, ImportNamespaceSpecifier<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -1461,7 +1462,7 @@ impl<'a> Format<'a> for AstNode<'a, ImportNamespaceSpecifier<'a>> {
 impl<'a> Format<'a> for AstNode<'a, WithClause<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -1475,7 +1476,7 @@ impl<'a> Format<'a> for AstNode<'a, WithClause<'a>> {
 impl<'a> Format<'a> for AstNode<'a, ImportAttribute<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -1495,7 +1496,7 @@ impl<'a> Format<'a> for AstNode<'a, ImportAttributeKey<'a>> {
 impl<'a> Format<'a> for AstNode<'a, ExportNamedDeclaration<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -1509,7 +1510,7 @@ impl<'a> Format<'a> for AstNode<'a, ExportNamedDeclaration<'a>> {
 impl<'a> Format<'a> for AstNode<'a, ExportDefaultDeclaration<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -1523,7 +1524,7 @@ impl<'a> Format<'a> for AstNode<'a, ExportDefaultDeclaration<'a>> {
 impl<'a> Format<'a> for AstNode<'a, ExportAllDeclaration<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -1537,7 +1538,7 @@ impl<'a> Format<'a> for AstNode<'a, ExportAllDeclaration<'a>> {
 impl<'a> Format<'a> for AstNode<'a, ExportSpecifier<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -1567,7 +1568,7 @@ impl<'a> Format<'a> for AstNode<'a, V8IntrinsicExpression<'a>> {
         if needs_parentheses {
             "(".fmt(f)?;
         }
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         if needs_parentheses {
             ")".fmt(f)?;
         }
@@ -1584,7 +1585,7 @@ impl<'a> Format<'a> for AstNode<'a, V8IntrinsicExpression<'a>> {
 impl<'a> Format<'a> for AstNode<'a, BooleanLiteral> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -1598,7 +1599,7 @@ impl<'a> Format<'a> for AstNode<'a, BooleanLiteral> {
 impl<'a> Format<'a> for AstNode<'a, NullLiteral> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -1616,7 +1617,7 @@ impl<'a> Format<'a> for AstNode<'a, NumericLiteral<'a>> {
         if needs_parentheses {
             "(".fmt(f)?;
         }
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         if needs_parentheses {
             ")".fmt(f)?;
         }
@@ -1637,7 +1638,7 @@ impl<'a> Format<'a> for AstNode<'a, StringLiteral<'a>> {
         if needs_parentheses {
             "(".fmt(f)?;
         }
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         if needs_parentheses {
             ")".fmt(f)?;
         }
@@ -1654,7 +1655,7 @@ impl<'a> Format<'a> for AstNode<'a, StringLiteral<'a>> {
 impl<'a> Format<'a> for AstNode<'a, BigIntLiteral<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -1668,7 +1669,7 @@ impl<'a> Format<'a> for AstNode<'a, BigIntLiteral<'a>> {
 impl<'a> Format<'a> for AstNode<'a, RegExpLiteral<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -1682,7 +1683,7 @@ impl<'a> Format<'a> for AstNode<'a, RegExpLiteral<'a>> {
 impl<'a> Format<'a> for AstNode<'a, JSXElement<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -1696,7 +1697,7 @@ impl<'a> Format<'a> for AstNode<'a, JSXElement<'a>> {
 impl<'a> Format<'a> for AstNode<'a, JSXOpeningElement<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -1710,7 +1711,7 @@ impl<'a> Format<'a> for AstNode<'a, JSXOpeningElement<'a>> {
 impl<'a> Format<'a> for AstNode<'a, JSXClosingElement<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -1724,7 +1725,7 @@ impl<'a> Format<'a> for AstNode<'a, JSXClosingElement<'a>> {
 impl<'a> Format<'a> for AstNode<'a, JSXFragment<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -1738,7 +1739,7 @@ impl<'a> Format<'a> for AstNode<'a, JSXFragment<'a>> {
 impl<'a> Format<'a> for AstNode<'a, JSXOpeningFragment> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -1752,7 +1753,7 @@ impl<'a> Format<'a> for AstNode<'a, JSXOpeningFragment> {
 impl<'a> Format<'a> for AstNode<'a, JSXClosingFragment> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -1772,7 +1773,7 @@ impl<'a> Format<'a> for AstNode<'a, JSXElementName<'a>> {
 impl<'a> Format<'a> for AstNode<'a, JSXNamespacedName<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -1790,7 +1791,7 @@ impl<'a> Format<'a> for AstNode<'a, JSXMemberExpression<'a>> {
         if needs_parentheses {
             "(".fmt(f)?;
         }
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         if needs_parentheses {
             ")".fmt(f)?;
         }
@@ -1813,7 +1814,7 @@ impl<'a> Format<'a> for AstNode<'a, JSXMemberExpressionObject<'a>> {
 impl<'a> Format<'a> for AstNode<'a, JSXExpressionContainer<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -1837,7 +1838,7 @@ impl<'a> Format<'a> for AstNode<'a, JSXEmptyExpression> {
         if needs_parentheses {
             "(".fmt(f)?;
         }
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         if needs_parentheses {
             ")".fmt(f)?;
         }
@@ -1860,7 +1861,7 @@ impl<'a> Format<'a> for AstNode<'a, JSXAttributeItem<'a>> {
 impl<'a> Format<'a> for AstNode<'a, JSXAttribute<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -1874,7 +1875,7 @@ impl<'a> Format<'a> for AstNode<'a, JSXAttribute<'a>> {
 impl<'a> Format<'a> for AstNode<'a, JSXSpreadAttribute<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -1900,7 +1901,7 @@ impl<'a> Format<'a> for AstNode<'a, JSXAttributeValue<'a>> {
 impl<'a> Format<'a> for AstNode<'a, JSXIdentifier<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -1920,7 +1921,7 @@ impl<'a> Format<'a> for AstNode<'a, JSXChild<'a>> {
 impl<'a> Format<'a> for AstNode<'a, JSXSpreadChild<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -1934,7 +1935,7 @@ impl<'a> Format<'a> for AstNode<'a, JSXSpreadChild<'a>> {
 impl<'a> Format<'a> for AstNode<'a, JSXText<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -1948,7 +1949,7 @@ impl<'a> Format<'a> for AstNode<'a, JSXText<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSThisParameter<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -1962,7 +1963,7 @@ impl<'a> Format<'a> for AstNode<'a, TSThisParameter<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSEnumDeclaration<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -1976,7 +1977,7 @@ impl<'a> Format<'a> for AstNode<'a, TSEnumDeclaration<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSEnumBody<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -1990,7 +1991,7 @@ impl<'a> Format<'a> for AstNode<'a, TSEnumBody<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSEnumMember<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -2010,7 +2011,7 @@ impl<'a> Format<'a> for AstNode<'a, TSEnumMemberName<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSTypeAnnotation<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -2024,7 +2025,7 @@ impl<'a> Format<'a> for AstNode<'a, TSTypeAnnotation<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSLiteralType<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -2050,7 +2051,7 @@ impl<'a> Format<'a> for AstNode<'a, TSType<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSConditionalType<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -2064,7 +2065,7 @@ impl<'a> Format<'a> for AstNode<'a, TSConditionalType<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSUnionType<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -2078,7 +2079,7 @@ impl<'a> Format<'a> for AstNode<'a, TSUnionType<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSIntersectionType<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -2092,7 +2093,7 @@ impl<'a> Format<'a> for AstNode<'a, TSIntersectionType<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSParenthesizedType<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -2106,7 +2107,7 @@ impl<'a> Format<'a> for AstNode<'a, TSParenthesizedType<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSTypeOperator<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -2120,7 +2121,7 @@ impl<'a> Format<'a> for AstNode<'a, TSTypeOperator<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSArrayType<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -2134,7 +2135,7 @@ impl<'a> Format<'a> for AstNode<'a, TSArrayType<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSIndexedAccessType<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -2148,7 +2149,7 @@ impl<'a> Format<'a> for AstNode<'a, TSIndexedAccessType<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSTupleType<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -2162,7 +2163,7 @@ impl<'a> Format<'a> for AstNode<'a, TSTupleType<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSNamedTupleMember<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -2176,7 +2177,7 @@ impl<'a> Format<'a> for AstNode<'a, TSNamedTupleMember<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSOptionalType<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -2190,7 +2191,7 @@ impl<'a> Format<'a> for AstNode<'a, TSOptionalType<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSRestType<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -2210,7 +2211,7 @@ impl<'a> Format<'a> for AstNode<'a, TSTupleElement<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSAnyKeyword> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -2224,7 +2225,7 @@ impl<'a> Format<'a> for AstNode<'a, TSAnyKeyword> {
 impl<'a> Format<'a> for AstNode<'a, TSStringKeyword> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -2238,7 +2239,7 @@ impl<'a> Format<'a> for AstNode<'a, TSStringKeyword> {
 impl<'a> Format<'a> for AstNode<'a, TSBooleanKeyword> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -2252,7 +2253,7 @@ impl<'a> Format<'a> for AstNode<'a, TSBooleanKeyword> {
 impl<'a> Format<'a> for AstNode<'a, TSNumberKeyword> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -2266,7 +2267,7 @@ impl<'a> Format<'a> for AstNode<'a, TSNumberKeyword> {
 impl<'a> Format<'a> for AstNode<'a, TSNeverKeyword> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -2280,7 +2281,7 @@ impl<'a> Format<'a> for AstNode<'a, TSNeverKeyword> {
 impl<'a> Format<'a> for AstNode<'a, TSIntrinsicKeyword> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -2294,7 +2295,7 @@ impl<'a> Format<'a> for AstNode<'a, TSIntrinsicKeyword> {
 impl<'a> Format<'a> for AstNode<'a, TSUnknownKeyword> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -2308,7 +2309,7 @@ impl<'a> Format<'a> for AstNode<'a, TSUnknownKeyword> {
 impl<'a> Format<'a> for AstNode<'a, TSNullKeyword> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -2322,7 +2323,7 @@ impl<'a> Format<'a> for AstNode<'a, TSNullKeyword> {
 impl<'a> Format<'a> for AstNode<'a, TSUndefinedKeyword> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -2336,7 +2337,7 @@ impl<'a> Format<'a> for AstNode<'a, TSUndefinedKeyword> {
 impl<'a> Format<'a> for AstNode<'a, TSVoidKeyword> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -2350,7 +2351,7 @@ impl<'a> Format<'a> for AstNode<'a, TSVoidKeyword> {
 impl<'a> Format<'a> for AstNode<'a, TSSymbolKeyword> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -2364,7 +2365,7 @@ impl<'a> Format<'a> for AstNode<'a, TSSymbolKeyword> {
 impl<'a> Format<'a> for AstNode<'a, TSThisType> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -2378,7 +2379,7 @@ impl<'a> Format<'a> for AstNode<'a, TSThisType> {
 impl<'a> Format<'a> for AstNode<'a, TSObjectKeyword> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -2392,7 +2393,7 @@ impl<'a> Format<'a> for AstNode<'a, TSObjectKeyword> {
 impl<'a> Format<'a> for AstNode<'a, TSBigIntKeyword> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -2406,7 +2407,7 @@ impl<'a> Format<'a> for AstNode<'a, TSBigIntKeyword> {
 impl<'a> Format<'a> for AstNode<'a, TSTypeReference<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -2426,7 +2427,7 @@ impl<'a> Format<'a> for AstNode<'a, TSTypeName<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSQualifiedName<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -2440,7 +2441,7 @@ impl<'a> Format<'a> for AstNode<'a, TSQualifiedName<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSTypeParameterInstantiation<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -2454,7 +2455,7 @@ impl<'a> Format<'a> for AstNode<'a, TSTypeParameterInstantiation<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSTypeParameter<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -2468,7 +2469,7 @@ impl<'a> Format<'a> for AstNode<'a, TSTypeParameter<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSTypeParameterDeclaration<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -2482,7 +2483,7 @@ impl<'a> Format<'a> for AstNode<'a, TSTypeParameterDeclaration<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSTypeAliasDeclaration<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -2496,7 +2497,7 @@ impl<'a> Format<'a> for AstNode<'a, TSTypeAliasDeclaration<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSClassImplements<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -2510,7 +2511,7 @@ impl<'a> Format<'a> for AstNode<'a, TSClassImplements<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSInterfaceDeclaration<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -2530,7 +2531,7 @@ impl<'a> Format<'a> for AstNode<'a, TSInterfaceBody<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSPropertySignature<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -2556,7 +2557,7 @@ impl<'a> Format<'a> for AstNode<'a, TSIndexSignature<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSCallSignatureDeclaration<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -2570,7 +2571,7 @@ impl<'a> Format<'a> for AstNode<'a, TSCallSignatureDeclaration<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSMethodSignature<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -2584,7 +2585,7 @@ impl<'a> Format<'a> for AstNode<'a, TSMethodSignature<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSConstructSignatureDeclaration<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -2598,7 +2599,7 @@ impl<'a> Format<'a> for AstNode<'a, TSConstructSignatureDeclaration<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSIndexSignatureName<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -2612,7 +2613,7 @@ impl<'a> Format<'a> for AstNode<'a, TSIndexSignatureName<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSInterfaceHeritage<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -2626,7 +2627,7 @@ impl<'a> Format<'a> for AstNode<'a, TSInterfaceHeritage<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSTypePredicate<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -2646,7 +2647,7 @@ impl<'a> Format<'a> for AstNode<'a, TSTypePredicateName<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSModuleDeclaration<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -2672,7 +2673,7 @@ impl<'a> Format<'a> for AstNode<'a, TSModuleDeclarationBody<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSModuleBlock<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -2686,7 +2687,7 @@ impl<'a> Format<'a> for AstNode<'a, TSModuleBlock<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSTypeLiteral<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -2700,7 +2701,7 @@ impl<'a> Format<'a> for AstNode<'a, TSTypeLiteral<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSInferType<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -2714,7 +2715,7 @@ impl<'a> Format<'a> for AstNode<'a, TSInferType<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSTypeQuery<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -2734,7 +2735,7 @@ impl<'a> Format<'a> for AstNode<'a, TSTypeQueryExprName<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSImportType<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -2760,7 +2761,7 @@ impl<'a> Format<'a> for AstNode<'a, TSConstructorType<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSMappedType<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -2774,7 +2775,7 @@ impl<'a> Format<'a> for AstNode<'a, TSMappedType<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSTemplateLiteralType<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -2792,7 +2793,7 @@ impl<'a> Format<'a> for AstNode<'a, TSAsExpression<'a>> {
         if needs_parentheses {
             "(".fmt(f)?;
         }
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         if needs_parentheses {
             ")".fmt(f)?;
         }
@@ -2813,7 +2814,7 @@ impl<'a> Format<'a> for AstNode<'a, TSSatisfiesExpression<'a>> {
         if needs_parentheses {
             "(".fmt(f)?;
         }
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         if needs_parentheses {
             ")".fmt(f)?;
         }
@@ -2834,7 +2835,7 @@ impl<'a> Format<'a> for AstNode<'a, TSTypeAssertion<'a>> {
         if needs_parentheses {
             "(".fmt(f)?;
         }
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         if needs_parentheses {
             ")".fmt(f)?;
         }
@@ -2851,7 +2852,7 @@ impl<'a> Format<'a> for AstNode<'a, TSTypeAssertion<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSImportEqualsDeclaration<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -2871,7 +2872,7 @@ impl<'a> Format<'a> for AstNode<'a, TSModuleReference<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSExternalModuleReference<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -2889,7 +2890,7 @@ impl<'a> Format<'a> for AstNode<'a, TSNonNullExpression<'a>> {
         if needs_parentheses {
             "(".fmt(f)?;
         }
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         if needs_parentheses {
             ")".fmt(f)?;
         }
@@ -2906,7 +2907,7 @@ impl<'a> Format<'a> for AstNode<'a, TSNonNullExpression<'a>> {
 impl<'a> Format<'a> for AstNode<'a, Decorator<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -2920,7 +2921,7 @@ impl<'a> Format<'a> for AstNode<'a, Decorator<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSExportAssignment<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -2934,7 +2935,7 @@ impl<'a> Format<'a> for AstNode<'a, TSExportAssignment<'a>> {
 impl<'a> Format<'a> for AstNode<'a, TSNamespaceExportDeclaration<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -2952,7 +2953,7 @@ impl<'a> Format<'a> for AstNode<'a, TSInstantiationExpression<'a>> {
         if needs_parentheses {
             "(".fmt(f)?;
         }
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         if needs_parentheses {
             ")".fmt(f)?;
         }
@@ -2969,7 +2970,7 @@ impl<'a> Format<'a> for AstNode<'a, TSInstantiationExpression<'a>> {
 impl<'a> Format<'a> for AstNode<'a, JSDocNullableType<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -2983,7 +2984,7 @@ impl<'a> Format<'a> for AstNode<'a, JSDocNullableType<'a>> {
 impl<'a> Format<'a> for AstNode<'a, JSDocNonNullableType<'a>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
@@ -2997,7 +2998,7 @@ impl<'a> Format<'a> for AstNode<'a, JSDocNonNullableType<'a>> {
 impl<'a> Format<'a> for AstNode<'a, JSDocUnknownType> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         format_leading_comments(self.span).fmt(f)?;
-        let result = self.write(f);
+        let result = write_unless_suppressed(self, self.span, f);
         format_trailing_comments(
             &self.parent.as_sibling_node(),
             &SiblingNode::from(self.inner),
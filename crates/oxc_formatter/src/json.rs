@@ -0,0 +1,291 @@
+//! Formatting of JSON and JSONC files.
+//!
+//! The source is parsed as a parenthesized JavaScript expression, so that comments are collected
+//! by the parser, and then checked to contain only JSON values. Keys and literals are printed as
+//! they are written in the source, which keeps the order of keys in files such as `package.json`.
+
+use oxc_allocator::Allocator;
+use oxc_ast::{Comment, ast::*};
+use oxc_diagnostics::{LabeledSpan, OxcDiagnostic};
+use oxc_parser::{ParseOptions, Parser};
+use oxc_span::{GetSpan, SourceType, Span};
+
+use crate::{
+    format_args,
+    formatter::{
+        self, Buffer, FormatContext, Formatter,
+        comments::is_own_line_comment,
+        prelude::*,
+        trivia::{FormatLeadingComments, FormatTrailingComments},
+    },
+    options::{Expand, FormatOptions},
+    write,
+};
+
+/// Format `source_text` as a JSON document, allowing comments and trailing commas.
+pub fn format_json(
+    allocator: &Allocator,
+    source_text: &str,
+    options: FormatOptions,
+) -> Result<String, Vec<OxcDiagnostic>> {
+    // Wrapping the document in parentheses makes an object literal parse as an expression.
+    // The newline ends a trailing line comment before the closing parenthesis.
+    let wrapped_text = allocator.alloc_str(&format!("({source_text}\n)"));
+    let ret = Parser::new(allocator, wrapped_text, SourceType::default())
+        .with_options(ParseOptions { preserve_parens: false, ..ParseOptions::default() })
+        .parse();
+    if !ret.errors.is_empty() {
+        return Err(ret.errors.into_iter().map(unwrap_span).collect());
+    }
+    let program = allocator.alloc(ret.program);
+    let value = match program.body.as_slice() {
+        [Statement::ExpressionStatement(stmt)] if program.directives.is_empty() => &stmt.expression,
+        _ => {
+            return Err(vec![unwrap_span(
+                OxcDiagnostic::error("Expected a single JSON value").with_label(program.span),
+            )]);
+        }
+    };
+    if let Err(span) = check_value(value) {
+        return Err(vec![unwrap_span(
+            OxcDiagnostic::error("Unexpected value in JSON").with_label(span),
+        )]);
+    }
+
+    let context = FormatContext::new(program, allocator, options);
+    let formatted = formatter::format(
+        program,
+        context,
+        formatter::Arguments::new(&[formatter::Argument::new(&FormatJsonDocument(value))]),
+    )
+    .unwrap();
+    Ok(formatted.print().unwrap().into_code())
+}
+
+/// Map a label in the wrapped source text back to the source text.
+fn unwrap_span(mut error: OxcDiagnostic) -> OxcDiagnostic {
+    if let Some(labels) = &mut error.labels {
+        for label in labels {
+            *label = LabeledSpan::new(
+                label.label().map(ToString::to_string),
+                label.offset().saturating_sub(1),
+                label.len(),
+            );
+        }
+    }
+    error
+}
+
+/// Returns the span of the first expression which is not a JSON value.
+fn check_value(value: &Expression) -> Result<(), Span> {
+    match value {
+        Expression::ObjectExpression(object) => {
+            for property in &object.properties {
+                let ObjectPropertyKind::ObjectProperty(property) = property else {
+                    return Err(property.span());
+                };
+                let is_json_key = matches!(
+                    property.key,
+                    PropertyKey::StringLiteral(_)
+                        | PropertyKey::NumericLiteral(_)
+                        | PropertyKey::StaticIdentifier(_)
+                );
+                if property.kind != PropertyKind::Init
+                    || property.method
+                    || property.shorthand
+                    || property.computed
+                    || !is_json_key
+                {
+                    return Err(property.span);
+                }
+                check_value(&property.value)?;
+            }
+            Ok(())
+        }
+        Expression::ArrayExpression(array) => {
+            for element in &array.elements {
+                match element.as_expression() {
+                    Some(element) => check_value(element)?,
+                    None => return Err(element.span()),
+                }
+            }
+            Ok(())
+        }
+        Expression::UnaryExpression(unary)
+            if matches!(
+                unary.operator,
+                UnaryOperator::UnaryNegation | UnaryOperator::UnaryPlus
+            ) && matches!(unary.argument, Expression::NumericLiteral(_)) =>
+        {
+            Ok(())
+        }
+        Expression::StringLiteral(_)
+        | Expression::NumericLiteral(_)
+        | Expression::BooleanLiteral(_)
+        | Expression::NullLiteral(_) => Ok(()),
+        _ => Err(value.span()),
+    }
+}
+
+struct FormatJsonDocument<'a>(&'a Expression<'a>);
+
+impl<'a> Format<'a> for FormatJsonDocument<'a> {
+    fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
+        let value = self.0;
+        write!(f, [FormatLeadingComments::Node(value.span()), FormatJsonValue(value)])?;
+        let comments = f.context().comments().unprinted_comments();
+        write!(f, [FormatTrailingComments::Comments(comments), hard_line_break()])
+    }
+}
+
+struct FormatJsonValue<'a>(&'a Expression<'a>);
+
+impl<'a> Format<'a> for FormatJsonValue<'a> {
+    fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
+        match self.0 {
+            Expression::ObjectExpression(object) => {
+                let should_expand = match f.options().expand {
+                    Expand::Auto => object.properties.first().is_some_and(|property| {
+                        f.source_text()[object.span.start as usize..property.span().start as usize]
+                            .contains('\n')
+                    }),
+                    Expand::Always => true,
+                    Expand::Never => false,
+                };
+                let entries = object.properties.iter().map(|property| {
+                    let ObjectPropertyKind::ObjectProperty(property) = property else {
+                        unreachable!("checked by `check_value`")
+                    };
+                    (property.span, FormatJsonProperty(property))
+                });
+                let bracket_spacing = f.options().bracket_spacing.value();
+                write!(
+                    f,
+                    [group(&format_args!(
+                        "{",
+                        FormatJsonEntries { entries, end: object.span.end, bracket_spacing },
+                        "}"
+                    ))
+                    .should_expand(should_expand)]
+                )
+            }
+            Expression::ArrayExpression(array) => {
+                let entries = array.elements.iter().map(|element| {
+                    let element = element.as_expression().expect("checked by `check_value`");
+                    (element.span(), FormatJsonValue(element))
+                });
+                write!(
+                    f,
+                    [group(&format_args!(
+                        "[",
+                        FormatJsonEntries { entries, end: array.span.end, bracket_spacing: false },
+                        "]"
+                    ))]
+                )
+            }
+            value => {
+                let span = value.span();
+                write!(f, dynamic_text(&f.source_text()[span.start as usize..span.end as usize]))
+            }
+        }
+    }
+}
+
+struct FormatJsonProperty<'a>(&'a ObjectProperty<'a>);
+
+impl<'a> Format<'a> for FormatJsonProperty<'a> {
+    fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
+        let key = self.0.key.span();
+        let value = &self.0.value;
+        write!(
+            f,
+            [
+                dynamic_text(&f.source_text()[key.start as usize..key.end as usize]),
+                ":",
+                space(),
+                FormatLeadingComments::Node(value.span()),
+                FormatJsonValue(value)
+            ]
+        )
+    }
+}
+
+/// The entries of an object or array, each with its comments, separated by commas.
+///
+/// Comments after the last entry are printed as its trailing comments, and comments in an
+/// empty object or array on lines of their own.
+struct FormatJsonEntries<I> {
+    entries: I,
+    /// End of the object or array.
+    end: u32,
+    bracket_spacing: bool,
+}
+
+impl<'a, I, F> Format<'a> for FormatJsonEntries<I>
+where
+    I: Iterator<Item = (Span, F)> + Clone,
+    F: Format<'a>,
+{
+    fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
+        let mut entries = self.entries.clone().peekable();
+        if entries.peek().is_none() {
+            let comments = comments_before(self.end, f);
+            if comments.is_empty() {
+                return Ok(());
+            }
+            return write!(
+                f,
+                [block_indent(&format_with(|f| {
+                    let mut join = f.join_with(hard_line_break());
+                    for comment in comments {
+                        join.entry(comment);
+                    }
+                    join.finish()?;
+                    for _ in comments {
+                        f.context_mut().increment_printed_count();
+                    }
+                    Ok(())
+                }))]
+            );
+        }
+
+        let content = format_with(|f| {
+            let source_text = f.source_text();
+            let mut join = f.join_nodes_with_soft_line();
+            let mut entries = self.entries.clone().peekable();
+            while let Some((span, entry)) = entries.next() {
+                let next_start = entries.peek().map(|(span, _)| span.start);
+                let format_entry = format_with(|f| {
+                    write!(f, [FormatLeadingComments::Node(span), entry])?;
+                    let trailing_comments = match next_start {
+                        // Comments on lines of their own are leading comments of the next entry.
+                        Some(next_start) => {
+                            let comments = comments_before(next_start, f);
+                            let count = comments
+                                .iter()
+                                .take_while(|comment| !is_own_line_comment(comment, source_text))
+                                .count();
+                            &comments[..count]
+                        }
+                        None => comments_before(self.end, f),
+                    };
+                    write!(f, FormatTrailingComments::Comments(trailing_comments))?;
+                    if next_start.is_some() {
+                        write!(f, ",")?;
+                    }
+                    Ok(())
+                });
+                join.entry(span, source_text, &format_entry);
+            }
+            join.finish()
+        });
+        write!(f, [soft_block_indent_with_maybe_space(&content, self.bracket_spacing)])
+    }
+}
+
+/// The unprinted comments which end before `end`.
+fn comments_before<'a>(end: u32, f: &Formatter<'_, 'a>) -> &'a [Comment] {
+    let comments = f.context().comments().unprinted_comments();
+    let count = comments.iter().take_while(|comment| comment.span.end <= end).count();
+    &comments[..count]
+}
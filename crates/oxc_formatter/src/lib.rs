@@ -15,8 +15,10 @@ mod generated {
     pub mod format_write;
 }
 mod formatter;
+mod json;
 mod options;
 mod parentheses;
+mod prettier;
 mod utils;
 mod write;
 
@@ -25,20 +27,44 @@ use std::{
     fmt::{self, Display},
     marker::PhantomData,
     mem::{self, transmute},
+    path::Path,
     vec::IntoIter,
 };
 
 use oxc_allocator::{Address, Allocator, GetAddress};
 use oxc_ast::{AstKind, ast::*};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_parser::{ParseOptions, Parser};
+use oxc_span::SourceType;
 use rustc_hash::{FxHashMap, FxHashSet};
 use write::FormatWrite;
 
 pub use crate::options::*;
+pub use crate::prettier::PRETTIER_CONFIG_FILES;
 use crate::{
     formatter::FormatContext,
     generated::ast_nodes::{AstNode, AstNodes},
 };
 
+/// The kinds of files which can be formatted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileKind {
+    /// JavaScript or TypeScript, including declaration files.
+    JavaScript(SourceType),
+    /// JSON, which may contain comments and trailing commas.
+    Json,
+}
+
+impl FileKind {
+    /// Returns the kind of file for `path`, or `None` if the file cannot be formatted.
+    pub fn from_path(path: &Path) -> Option<Self> {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("json" | "jsonc" | "json5") => Some(Self::Json),
+            _ => SourceType::from_path(path).ok().map(Self::JavaScript),
+        }
+    }
+}
+
 pub struct Formatter<'a> {
     allocator: &'a Allocator,
     source_text: &'a str,
@@ -65,4 +91,32 @@ impl<'a> Formatter<'a> {
         .unwrap();
         formatted.print().unwrap().into_code()
     }
+    /// Parse and format `source_text` as a file of the given kind.
+    ///
+    /// # Errors
+    ///
+    /// * `source_text` has syntax errors
+    /// * a JSON file contains a value which is not JSON, e.g. a function call
+    pub fn format_source(
+        self,
+        source_text: &'a str,
+        kind: FileKind,
+    ) -> Result<String, Vec<OxcDiagnostic>> {
+        match kind {
+            FileKind::JavaScript(source_type) => {
+                let ret = Parser::new(self.allocator, source_text, source_type)
+                    .with_options(ParseOptions {
+                        parse_regular_expression: true,
+                        ..ParseOptions::default()
+                    })
+                    .parse();
+                if !ret.errors.is_empty() {
+                    return Err(ret.errors);
+                }
+                let program = self.allocator.alloc(ret.program);
+                Ok(self.build(program))
+            }
+            FileKind::Json => json::format_json(self.allocator, source_text, self.options),
+        }
+    }
 }
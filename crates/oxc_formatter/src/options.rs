@@ -62,6 +62,9 @@ pub struct FormatOptions {
     /// - `"start"`: Places the operator at the beginning of the next line.
     /// - `"end"`: Places the operator at the end of the current line (default).
     pub experimental_operator_position: OperatorPosition,
+
+    /// Whether to wrap comments which exceed the line width. Defaults to "preserve".
+    pub prose_wrap: ProseWrap,
 }
 
 impl FormatOptions {
@@ -82,6 +85,7 @@ impl FormatOptions {
             attribute_position: AttributePosition::default(),
             expand: Expand::default(),
             experimental_operator_position: OperatorPosition::default(),
+            prose_wrap: ProseWrap::default(),
         }
     }
 
@@ -106,7 +110,8 @@ impl fmt::Display for FormatOptions {
         writeln!(f, "Bracket same line: {}", self.bracket_same_line.value())?;
         writeln!(f, "Attribute Position: {}", self.attribute_position)?;
         writeln!(f, "Expand lists: {}", self.expand)?;
-        writeln!(f, "Experimental operator position: {}", self.experimental_operator_position)
+        writeln!(f, "Experimental operator position: {}", self.experimental_operator_position)?;
+        writeln!(f, "Prose wrap: {}", self.prose_wrap)
     }
 }
 
@@ -907,3 +912,47 @@ impl fmt::Display for OperatorPosition {
         f.write_str(s)
     }
 }
+
+/// How to wrap the text of comments, like Prettier's `proseWrap` does for Markdown.
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum ProseWrap {
+    /// Wrap `//` comments on their own line that exceed the line width.
+    Always,
+    /// Print comments as written, without wrapping.
+    Never,
+    /// Print comments as written.
+    #[default]
+    Preserve,
+}
+
+impl ProseWrap {
+    pub const fn is_always(self) -> bool {
+        matches!(self, Self::Always)
+    }
+}
+
+impl FromStr for ProseWrap {
+    type Err = &'static str;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "always" => Ok(Self::Always),
+            "never" => Ok(Self::Never),
+            "preserve" => Ok(Self::Preserve),
+            _ => Err(
+                "Value not supported for ProseWrap. Supported values are 'always', 'never' and 'preserve'.",
+            ),
+        }
+    }
+}
+
+impl fmt::Display for ProseWrap {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let s = match self {
+            ProseWrap::Always => "Always",
+            ProseWrap::Never => "Never",
+            ProseWrap::Preserve => "Preserve",
+        };
+        f.write_str(s)
+    }
+}
@@ -0,0 +1,150 @@
+//! Compatibility with Prettier configuration files.
+
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+use serde_json::{Map, Value};
+
+use crate::options::{
+    ArrowParentheses, BracketSameLine, BracketSpacing, Expand, FormatOptions, IndentStyle,
+    IndentWidth, LineEnding, LineWidth, OperatorPosition, ProseWrap, QuoteProperties, QuoteStyle,
+    Semicolons, TrailingCommas,
+};
+
+/// Prettier configuration files, in the order they are searched for in each directory.
+///
+/// Only JSON is supported, so a `.prettierrc` file written in YAML is an error.
+pub const PRETTIER_CONFIG_FILES: [&str; 3] = ["package.json", ".prettierrc", ".prettierrc.json"];
+
+impl FormatOptions {
+    /// Find the Prettier configuration file for files in `dir`, searching up the directory tree.
+    ///
+    /// `package.json` files without a `prettier` key are skipped.
+    ///
+    /// # Errors
+    ///
+    /// * the configuration file cannot be read or parsed
+    /// * the configuration contains an unsupported value
+    pub fn discover_prettier_config(dir: &Path) -> Result<Option<(PathBuf, Self)>, String> {
+        for dir in dir.ancestors() {
+            for file_name in PRETTIER_CONFIG_FILES {
+                let path = dir.join(file_name);
+                if !path.is_file() {
+                    continue;
+                }
+                let source_text = fs::read_to_string(&path)
+                    .map_err(|err| format!("Failed to read {}: {err}", path.display()))?;
+                let mut config: Value = serde_json::from_str(&source_text)
+                    .map_err(|err| format!("Failed to parse {} as JSON: {err}", path.display()))?;
+                if file_name == "package.json" {
+                    match config.get_mut("prettier").map(Value::take) {
+                        Some(Value::Object(prettier)) => config = Value::Object(prettier),
+                        Some(_) => {
+                            return Err(format!(
+                                "Shared configurations in {} are not supported",
+                                path.display()
+                            ));
+                        }
+                        None => continue,
+                    }
+                }
+                let Value::Object(config) = config else {
+                    return Err(format!("Expected an object in {}", path.display()));
+                };
+                let options = Self::from_prettier_config(&config)
+                    .map_err(|err| format!("{err} in {}", path.display()))?;
+                return Ok(Some((path, options)));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Create options from a Prettier configuration, e.g. the contents of a `.prettierrc` file.
+    ///
+    /// Options which do not affect the formatting of JavaScript, e.g. `plugins` or `overrides`,
+    /// are ignored.
+    ///
+    /// # Errors
+    ///
+    /// * an option has a value which is not supported, e.g. `quoteProps: "consistent"`
+    pub fn from_prettier_config(config: &Map<String, Value>) -> Result<Self, String> {
+        let mut options = Self::default();
+        for (name, value) in config {
+            options
+                .set_prettier_option(name, value)
+                .map_err(|err| format!("Invalid value {value} for `{name}`: {err}"))?;
+        }
+        Ok(options)
+    }
+
+    fn set_prettier_option(&mut self, name: &str, value: &Value) -> Result<(), &'static str> {
+        match name {
+            "printWidth" => {
+                let width = value.as_u64().and_then(|width| u16::try_from(width).ok());
+                self.line_width = LineWidth::try_from(width.ok_or("expected a number")?)
+                    .map_err(|_| "expected a number between 1 and 320")?;
+            }
+            "tabWidth" => {
+                let width = value.as_u64().and_then(|width| u8::try_from(width).ok());
+                self.indent_width = IndentWidth::try_from(width.ok_or("expected a number")?)
+                    .map_err(|_| "expected a number between 0 and 24")?;
+            }
+            "useTabs" => {
+                self.indent_style =
+                    if as_bool(value)? { IndentStyle::Tab } else { IndentStyle::Space };
+            }
+            "semi" => {
+                self.semicolons =
+                    if as_bool(value)? { Semicolons::Always } else { Semicolons::AsNeeded };
+            }
+            "singleQuote" => self.quote_style = quote_style(value)?,
+            "jsxSingleQuote" => self.jsx_quote_style = quote_style(value)?,
+            "quoteProps" => self.quote_properties = QuoteProperties::from_str(as_str(value)?)?,
+            "trailingComma" => self.trailing_commas = TrailingCommas::from_str(as_str(value)?)?,
+            "bracketSpacing" => self.bracket_spacing = BracketSpacing::from(as_bool(value)?),
+            "bracketSameLine" => self.bracket_same_line = BracketSameLine::from(as_bool(value)?),
+            "objectWrap" => {
+                self.expand = match as_str(value)? {
+                    "preserve" => Expand::Auto,
+                    "collapse" => Expand::Never,
+                    _ => return Err("expected 'preserve' or 'collapse'"),
+                };
+            }
+            "arrowParens" => {
+                self.arrow_parentheses = match as_str(value)? {
+                    "always" => ArrowParentheses::Always,
+                    "avoid" => ArrowParentheses::AsNeeded,
+                    _ => return Err("expected 'always' or 'avoid'"),
+                };
+            }
+            "endOfLine" => {
+                // `auto` keeps the line endings of each file, which is not supported.
+                self.line_ending = match as_str(value)? {
+                    "auto" => LineEnding::default(),
+                    s => LineEnding::from_str(s)?,
+                };
+            }
+            "proseWrap" => self.prose_wrap = ProseWrap::from_str(as_str(value)?)?,
+            "experimentalOperatorPosition" => {
+                self.experimental_operator_position = OperatorPosition::from_str(as_str(value)?)?;
+            }
+            _ => {}
+        }
+        Ok(())
+    }
+}
+
+fn as_bool(value: &Value) -> Result<bool, &'static str> {
+    value.as_bool().ok_or("expected a boolean")
+}
+
+fn as_str(value: &Value) -> Result<&str, &'static str> {
+    value.as_str().ok_or("expected a string")
+}
+
+fn quote_style(value: &Value) -> Result<QuoteStyle, &'static str> {
+    Ok(if as_bool(value)? { QuoteStyle::Single } else { QuoteStyle::Double })
+}
@@ -785,6 +785,25 @@ fn has_rest_object_or_array_parameter(params: &FormalParameters) -> bool {
     params.rest.is_some()
 }
 
+/// Returns `true` if `arrowParens: "avoid"` can print the parameter of `arrow` without parentheses,
+/// i.e. it is a single identifier without a type annotation or default value, e.g. `a => a`.
+fn can_avoid_parentheses(arrow: &ArrowFunctionExpression, f: &Formatter) -> bool {
+    let params = &arrow.params;
+    f.options().arrow_parentheses.is_as_needed()
+        && arrow.type_parameters.is_none()
+        && arrow.return_type.is_none()
+        && params.rest.is_none()
+        && params.items.len() == 1
+        && params.items.first().is_some_and(|param| {
+            param.decorators.is_empty()
+                && param.accessibility.is_none()
+                && param.pattern.kind.is_binding_identifier()
+                && param.pattern.type_annotation.is_none()
+                && !param.pattern.optional
+        })
+        && !f.comments().has_comments_in_span(params.span)
+}
+
 /// Writes the arrow function type parameters, parameters, and return type annotation.
 ///
 /// Formats the parameters and return type annotation without any soft line breaks if `is_first_or_last_call_argument` is `true`
@@ -832,7 +851,10 @@ fn format_signature<'a, 'b>(
             // }
             // }
             // AnyJsArrowFunctionParameters::JsParameters(params) => {
-            write!(f, arrow.params())?;
+            match arrow.params().items().first() {
+                Some(param) if can_avoid_parentheses(arrow, f) => write!(f, param)?,
+                _ => write!(f, arrow.params())?,
+            }
             // }
             // };
 
@@ -1,6 +1,6 @@
 use oxc_ast::ast::*;
 
-use super::FormatWrite;
+use super::{FormatWrite, OptionalSemicolon};
 use crate::{
     format_args,
     formatter::{Buffer, FormatResult, Formatter, prelude::*, trivia::DanglingIndentMode},
@@ -10,6 +10,9 @@ use crate::{
 
 impl<'a> FormatWrite<'a> for AstNode<'a, Function<'a>> {
     fn write(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
+        if self.declare() {
+            write!(f, ["declare", space()]);
+        }
         if self.r#async() {
             write!(f, ["async", space()]);
         }
@@ -17,7 +20,16 @@ impl<'a> FormatWrite<'a> for AstNode<'a, Function<'a>> {
         if self.generator() {
             write!(f, "*");
         }
-        write!(f, [space(), self.id(), group(&self.params()), space(), self.body()]);
+        write!(f, [space(), self.id(), group(&self.type_parameters()), group(&self.params())]);
+        if let Some(return_type) = &self.return_type() {
+            write!(f, return_type);
+        }
+        if let Some(body) = &self.body() {
+            write!(f, [space(), body]);
+        } else if self.r#type() == FunctionType::TSDeclareFunction {
+            // Overloads and declarations, e.g. `declare function f(): void;`
+            write!(f, OptionalSemicolon);
+        }
         Ok(())
     }
 }
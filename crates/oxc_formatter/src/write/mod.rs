@@ -22,7 +22,7 @@ use cow_utils::CowUtils;
 
 use oxc_allocator::{Address, Box, FromIn, StringBuilder, Vec};
 use oxc_ast::{AstKind, ast::*};
-use oxc_span::{GetSpan, SPAN};
+use oxc_span::{GetSpan, SPAN, Span};
 use oxc_syntax::identifier::{ZWNBSP, is_identifier_name, is_line_terminator};
 
 use crate::{
@@ -33,6 +33,10 @@ use crate::{
         separated::FormatSeparatedIter,
         token::number::{NumberFormatOptions, format_number_token},
         trivia::FormatLeadingComments,
+        verbatim::{
+            FormatSuppressedNode, is_format_off_comment, is_format_on_comment,
+            is_suppression_comment,
+        },
     },
     generated::ast_nodes::{AstNode, AstNodes},
     options::{FormatTrailingCommas, QuoteProperties, TrailingSeparator},
@@ -567,9 +571,59 @@ impl<'a> FormatWrite<'a> for AstNode<'a, ParenthesizedExpression<'a>> {
 impl<'a> Format<'a> for AstNode<'a, Vec<'a, Statement<'a>>> {
     fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         let source_text = f.context().source_text();
+        let comments = f.context().comments().unprinted_comments();
         let mut join = f.join_nodes_with_hardline();
+        let mut previous_end = 0;
         for stmt in self {
-            join.entry(stmt.span(), source_text, stmt);
+            let span = stmt.span();
+            if span.start < previous_end {
+                // Inside an `oxc-format-off` range.
+                continue;
+            }
+            let format_off = comments.iter().find(|comment| {
+                previous_end <= comment.span.start
+                    && comment.span.end <= span.start
+                    && is_format_off_comment(comment, source_text)
+            });
+            let format_on = format_off.and_then(|format_off| {
+                comments.iter().find(|comment| {
+                    format_off.span.end <= comment.span.start
+                        && is_format_on_comment(comment, source_text)
+                })
+            });
+            previous_end = span.end;
+            let Some(format_off) =
+                format_off.filter(|_| format_on.is_none_or(|on| span.start < on.span.start))
+            else {
+                join.entry(span, source_text, stmt);
+                continue;
+            };
+
+            // The range ends at the `oxc-format-on` comment or at the end of the list, and
+            // includes the whole statement which contains the `oxc-format-on` comment.
+            let last_end = self
+                .iter()
+                .take_while(|stmt| format_on.is_none_or(|on| stmt.span().start < on.span.start))
+                .last()
+                .map_or(span.end, |stmt| stmt.span().end);
+            previous_end = format_on.map_or(last_end, |on| last_end.max(on.span.end));
+            let range = Span::new(format_off.span.start, previous_end);
+            join.entry(
+                range,
+                source_text,
+                &format_with(|f| {
+                    let comments = f.context().comments().unprinted_comments();
+                    let leading_comments = comments
+                        .partition_point(|comment| comment.span.start < format_off.span.start);
+                    write!(
+                        f,
+                        [
+                            FormatLeadingComments::Comments(&comments[..leading_comments]),
+                            FormatSuppressedNode(range)
+                        ]
+                    )
+                }),
+            );
         }
         join.finish()
     }
@@ -1713,10 +1767,7 @@ impl<'a> FormatWrite<'a> for AstNode<'a, JSXElement<'a>> {
             write!(f, [space(), "/"])?;
         }
         write!(f, ">")?;
-
-        for child in self.children() {
-            write!(f, child)?;
-        }
+        write!(f, FormatJsxChildren(self.children()))?;
         write!(f, self.closing_element())
     }
 }
@@ -1738,10 +1789,40 @@ impl<'a> FormatWrite<'a> for AstNode<'a, JSXClosingElement<'a>> {
 impl<'a> FormatWrite<'a> for AstNode<'a, JSXFragment<'a>> {
     fn write(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
         write!(f, self.opening_fragment())?;
-        for child in self.children() {
+        write!(f, FormatJsxChildren(self.children()))?;
+        write!(f, self.closing_fragment())
+    }
+}
+
+/// Formats JSX children, leaving an element after `{/* prettier-ignore */}` as it is in the source text.
+struct FormatJsxChildren<'a, 'b>(&'b AstNode<'a, Vec<'a, JSXChild<'a>>>);
+
+impl<'a> Format<'a> for FormatJsxChildren<'a, '_> {
+    fn fmt(&self, f: &mut Formatter<'_, 'a>) -> FormatResult<()> {
+        let source_text = f.source_text();
+        let mut suppressed = false;
+        for child in self.0 {
+            match child.as_ref() {
+                JSXChild::Element(_) | JSXChild::Fragment(_) if suppressed => {
+                    write!(f, FormatSuppressedNode(child.span()))?;
+                    suppressed = false;
+                    continue;
+                }
+                JSXChild::ExpressionContainer(container)
+                    if matches!(container.expression, JSXExpression::EmptyExpression(_)) =>
+                {
+                    suppressed = f
+                        .context()
+                        .comments()
+                        .filter_comments_in_span(container.span)
+                        .any(|comment| is_suppression_comment(comment, source_text));
+                }
+                JSXChild::Text(text) if text.value.trim().is_empty() => {}
+                _ => suppressed = false,
+            }
             write!(f, child)?;
         }
-        write!(f, self.closing_fragment())
+        Ok(())
     }
 }
 
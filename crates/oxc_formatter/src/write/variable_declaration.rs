@@ -30,6 +30,9 @@ impl<'a> FormatWrite<'a> for AstNode<'a, VariableDeclaration<'a>> {
             _ => true,
         };
 
+        if self.declare() {
+            write!(f, ["declare", space()])?;
+        }
         write!(
             f,
             group(&format_args!(
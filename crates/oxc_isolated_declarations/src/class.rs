@@ -104,7 +104,15 @@ impl<'a> IsolatedDeclarations<'a> {
             }
 
             if type_annotation.is_none() && value.is_none() {
-                self.error(property_must_have_explicit_type(property.key.span()));
+                self.error(property_must_have_explicit_type(
+                    property.key.span(),
+                    self.property_annotation_position(
+                        property.key.span(),
+                        property.computed,
+                        property.optional || property.definite,
+                    ),
+                    property.value.as_ref().and_then(|expr| self.suggest_type_annotation(expr)),
+                ));
             }
         }
 
@@ -195,11 +203,12 @@ impl<'a> IsolatedDeclarations<'a> {
         param: &FormalParameter<'a>,
         type_annotation: Option<ArenaBox<'a, TSTypeAnnotation<'a>>>,
     ) -> Option<ClassElement<'a>> {
-        let Some(ident_name) = param.pattern.get_identifier_name() else {
+        let Some(ident) = param.pattern.get_binding_identifier() else {
             // A parameter property may not be declared using a binding pattern.(1187)
             return None;
         };
-        let key = self.ast.property_key_static_identifier(SPAN, ident_name);
+        // Keep the span of the parameter so that declaration maps point at it.
+        let key = self.ast.property_key_static_identifier(ident.span, ident.name);
         Some(self.ast.class_element_property_definition(
             param.span,
             PropertyDefinitionType::PropertyDefinition,
@@ -268,6 +277,7 @@ impl<'a> IsolatedDeclarations<'a> {
                 .iter()
                 .filter(|param| param.has_modifier())
                 .enumerate()
+                .filter(|(_, param)| !self.has_internal_annotation(param.span))
                 .filter_map(|(index, param)| {
                     let type_annotation =
                         if param.accessibility.is_some_and(TSAccessibility::is_private) {
@@ -454,6 +464,8 @@ impl<'a> IsolatedDeclarations<'a> {
                             if rt.is_none() {
                                 self.error(method_must_have_explicit_return_type(
                                     method.key.span(),
+                                    function.params.span.end,
+                                    self.suggest_function_return_type(function),
                                 ));
                             }
                             rt
@@ -469,6 +481,8 @@ impl<'a> IsolatedDeclarations<'a> {
                             if rt.is_none() {
                                 self.error(accessor_must_have_explicit_return_type(
                                     method.key.span(),
+                                    function.params.span.end,
+                                    self.suggest_function_return_type(function),
                                 ));
                             }
                             rt
@@ -0,0 +1,142 @@
+//! CommonJS exports in TypeScript files, which are declared as their module equivalents.
+//!
+//! ```ts
+//! module.exports = Foo;      // export = Foo;
+//! exports.name = "foo";      // export let name = "foo";
+//! module.exports.bar = bar;  // export { bar };
+//! ```
+
+use oxc_allocator::{CloneIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_span::{Atom, GetSpan};
+
+use crate::{IsolatedDeclarations, diagnostics::commonjs_export_with_export_assignment};
+
+/// A CommonJS export assigned by an expression statement.
+enum CommonJsExport<'a, 'b> {
+    /// `module.exports = value`
+    Assignment(&'b Expression<'a>),
+    /// `exports.name = value` or `module.exports.name = value`
+    Property(&'b IdentifierName<'a>, &'b Expression<'a>),
+}
+
+impl<'a> IsolatedDeclarations<'a> {
+    /// Replace the CommonJS exports in `stmts` by `export =` and named exports.
+    ///
+    /// Returns `None` if there are no CommonJS exports.
+    pub(crate) fn transform_commonjs_exports(
+        &self,
+        stmts: &ArenaVec<'a, Statement<'a>>,
+    ) -> Option<ArenaVec<'a, Statement<'a>>> {
+        if !stmts.iter().any(|stmt| Self::get_commonjs_export(stmt).is_some()) {
+            return None;
+        }
+        let has_export_assignment = stmts.iter().any(|stmt| {
+            matches!(Self::get_commonjs_export(stmt), Some(CommonJsExport::Assignment(_)))
+        });
+
+        let mut exported_names: Vec<Atom<'a>> = vec![];
+        let mut new_stmts = self.ast.vec_with_capacity(stmts.len());
+        for stmt in stmts {
+            let Some(export) = Self::get_commonjs_export(stmt) else {
+                new_stmts.push(stmt.clone_in(self.ast.allocator));
+                continue;
+            };
+            match export {
+                CommonJsExport::Assignment(value) => {
+                    let value = value.clone_in(self.ast.allocator);
+                    new_stmts.push(Statement::from(
+                        self.ast.module_declaration_ts_export_assignment(stmt.span(), value),
+                    ));
+                }
+                CommonJsExport::Property(name, value) => {
+                    // The exported object is replaced by `module.exports = value`, so its
+                    // properties can't be declared as named exports.
+                    if has_export_assignment {
+                        self.error(commonjs_export_with_export_assignment(name.span));
+                        continue;
+                    }
+                    // Only the first assignment declares the export.
+                    if exported_names.contains(&name.name) {
+                        continue;
+                    }
+                    exported_names.push(name.name);
+                    new_stmts.push(self.transform_commonjs_named_export(stmt, name, value));
+                }
+            }
+        }
+        Some(new_stmts)
+    }
+
+    fn get_commonjs_export<'b>(stmt: &'b Statement<'a>) -> Option<CommonJsExport<'a, 'b>> {
+        let Statement::ExpressionStatement(stmt) = stmt else { return None };
+        let Expression::AssignmentExpression(assignment) = &stmt.expression else { return None };
+        if !assignment.operator.is_assign() {
+            return None;
+        }
+        let AssignmentTarget::StaticMemberExpression(member_expr) = &assignment.left else {
+            return None;
+        };
+        if Self::is_module_exports(&member_expr.object) {
+            Some(CommonJsExport::Property(&member_expr.property, &assignment.right))
+        } else if Self::is_module_exports_member(member_expr) {
+            Some(CommonJsExport::Assignment(&assignment.right))
+        } else {
+            None
+        }
+    }
+
+    /// Check if `expr` is `exports` or `module.exports`.
+    fn is_module_exports(expr: &Expression<'a>) -> bool {
+        match expr {
+            Expression::Identifier(ident) => ident.name == "exports",
+            Expression::StaticMemberExpression(member_expr) => {
+                Self::is_module_exports_member(member_expr)
+            }
+            _ => false,
+        }
+    }
+
+    /// Check if `member_expr` is `module.exports`.
+    fn is_module_exports_member(member_expr: &StaticMemberExpression<'a>) -> bool {
+        member_expr.property.name == "exports"
+            && matches!(&member_expr.object, Expression::Identifier(ident) if ident.name == "module")
+    }
+
+    /// `export { value as name }` for an identifier, otherwise `export let name = value`.
+    fn transform_commonjs_named_export(
+        &self,
+        stmt: &Statement<'a>,
+        name: &IdentifierName<'a>,
+        value: &Expression<'a>,
+    ) -> Statement<'a> {
+        let (declaration, specifiers) = if let Expression::Identifier(ident) = value {
+            let local = self.ast.module_export_name_identifier_reference(ident.span, ident.name);
+            let exported = self.ast.module_export_name_identifier_name(name.span, name.name);
+            let specifier =
+                self.ast.export_specifier(name.span, local, exported, ImportOrExportKind::Value);
+            (None, self.ast.vec1(specifier))
+        } else {
+            // The property can be reassigned, so it is declared with `let`.
+            let kind = VariableDeclarationKind::Let;
+            let id = self.ast.binding_pattern(
+                self.ast.binding_pattern_kind_binding_identifier(name.span, name.name),
+                NONE,
+                false,
+            );
+            let init = Some(value.clone_in(self.ast.allocator));
+            let declarator = self.ast.variable_declarator(name.span, kind, id, init, false);
+            let declaration =
+                self.ast.declaration_variable(name.span, kind, self.ast.vec1(declarator), false);
+            (Some(declaration), self.ast.vec())
+        };
+        Statement::from(self.ast.module_declaration_export_named_declaration(
+            stmt.span(),
+            declaration,
+            specifiers,
+            None,
+            ImportOrExportKind::Value,
+            NONE,
+        ))
+    }
+}
@@ -85,10 +85,17 @@ impl<'a> IsolatedDeclarations<'a> {
             if init.is_none() && binding_type.is_none() {
                 binding_type = Some(self.ast.ts_type_unknown_keyword(SPAN));
                 if !decl.init.as_ref().is_some_and(Expression::is_function) {
-                    self.error(variable_must_have_explicit_type(decl.id.span()));
+                    self.error(variable_must_have_explicit_type(
+                        decl.id.span(),
+                        decl.init.as_ref().and_then(|expr| self.suggest_type_annotation(expr)),
+                    ));
                 }
             }
         }
+        if let Some(name) = decl.id.get_identifier_name() {
+            binding_type =
+                binding_type.map(|ts_type| self.transform_expando_function_type(name, ts_type));
+        }
         let id = binding_type.map_or_else(
             || decl.id.clone_in(self.ast.allocator),
             |ts_type| {
@@ -255,7 +262,11 @@ impl<'a> VisitMut<'a> for IsolatedDeclarations<'a> {
                     self.error(inferred_type_of_expression(signature.span));
                 }
                 TSMethodSignatureKind::Get => {
-                    self.error(accessor_must_have_explicit_return_type(signature.key.span()));
+                    self.error(accessor_must_have_explicit_return_type(
+                        signature.key.span(),
+                        signature.params.span.end,
+                        None,
+                    ));
                 }
                 TSMethodSignatureKind::Set => {
                     // setter method don't need return type
@@ -1,32 +1,82 @@
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_span::Span;
 
-pub fn function_must_have_explicit_return_type(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error(
-        "TS9007: Function must have an explicit return type annotation with --isolatedDeclarations.",
+/// Point at `insert_at`, where the missing type annotation belongs, and suggest `annotation` if the
+/// type can be read from the syntax. Otherwise `help` says what to annotate.
+fn with_annotation_suggestion(
+    error: OxcDiagnostic,
+    insert_at: Option<u32>,
+    annotation: Option<String>,
+    help: &'static str,
+) -> OxcDiagnostic {
+    let Some(insert_at) = insert_at else { return error.with_help(help) };
+    let insert_at = Span::empty(insert_at);
+    match annotation {
+        Some(annotation) => error
+            .and_label(insert_at.label(format!("Insert `: {annotation}` here")))
+            .with_help(format!("Add the type annotation `: {annotation}`")),
+        None => error.and_label(insert_at.label("Type annotation is missing here")).with_help(help),
+    }
+}
+
+pub fn function_must_have_explicit_return_type(
+    span: Span,
+    insert_at: Option<u32>,
+    annotation: Option<String>,
+) -> OxcDiagnostic {
+    with_annotation_suggestion(
+        OxcDiagnostic::error(
+            "TS9007: Function must have an explicit return type annotation with --isolatedDeclarations.",
+        )
+        .with_label(span),
+        insert_at,
+        annotation,
+        "Add a return type to the function",
     )
-    .with_label(span)
 }
 
-pub fn method_must_have_explicit_return_type(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error(
-        "TS9008: Method must have an explicit return type annotation with --isolatedDeclarations.",
+pub fn method_must_have_explicit_return_type(
+    span: Span,
+    insert_at: u32,
+    annotation: Option<String>,
+) -> OxcDiagnostic {
+    with_annotation_suggestion(
+        OxcDiagnostic::error(
+            "TS9008: Method must have an explicit return type annotation with --isolatedDeclarations.",
+        )
+        .with_label(span),
+        Some(insert_at),
+        annotation,
+        "Add a return type to the method",
     )
-    .with_label(span)
 }
 
-pub fn accessor_must_have_explicit_return_type(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error(
-        "TS9009: At least one accessor must have an explicit return type annotation with --isolatedDeclarations.",
+pub fn accessor_must_have_explicit_return_type(
+    span: Span,
+    insert_at: u32,
+    annotation: Option<String>,
+) -> OxcDiagnostic {
+    with_annotation_suggestion(
+        OxcDiagnostic::error(
+            "TS9009: At least one accessor must have an explicit return type annotation with --isolatedDeclarations.",
+        )
+        .with_label(span),
+        Some(insert_at),
+        annotation,
+        "Add a return type to the get accessor declaration",
     )
-    .with_label(span)
 }
 
-pub fn variable_must_have_explicit_type(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error(
-        "TS9010: Variable must have an explicit type annotation with --isolatedDeclarations.",
+pub fn variable_must_have_explicit_type(span: Span, annotation: Option<String>) -> OxcDiagnostic {
+    with_annotation_suggestion(
+        OxcDiagnostic::error(
+            "TS9010: Variable must have an explicit type annotation with --isolatedDeclarations.",
+        )
+        .with_label(span),
+        Some(span.end),
+        annotation,
+        "Add a type annotation to the variable",
     )
-    .with_label(span)
 }
 
 pub fn parameter_must_have_explicit_type(span: Span) -> OxcDiagnostic {
@@ -36,11 +86,20 @@ pub fn parameter_must_have_explicit_type(span: Span) -> OxcDiagnostic {
     .with_label(span)
 }
 
-pub fn property_must_have_explicit_type(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error(
-        "TS9012: Property must have an explicit type annotation with --isolatedDeclarations.",
+pub fn property_must_have_explicit_type(
+    span: Span,
+    insert_at: u32,
+    annotation: Option<String>,
+) -> OxcDiagnostic {
+    with_annotation_suggestion(
+        OxcDiagnostic::error(
+            "TS9012: Property must have an explicit type annotation with --isolatedDeclarations.",
+        )
+        .with_label(span),
+        Some(insert_at),
+        annotation,
+        "Add a type annotation to the property",
     )
-    .with_label(span)
 }
 
 pub fn inferred_type_of_expression(span: Span) -> OxcDiagnostic {
@@ -70,6 +129,8 @@ pub fn shorthand_property(span: Span) -> OxcDiagnostic {
 pub fn array_inferred(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("TS9017: Only const arrays can be inferred with --isolatedDeclarations.")
         .with_label(span)
+        .and_label(Span::empty(span.end).label("Insert ` as const` here"))
+        .with_help("Add `as const` to infer the array as a readonly tuple, or annotate its type")
 }
 
 pub fn arrays_with_spread_elements(span: Span) -> OxcDiagnostic {
@@ -119,12 +180,30 @@ pub fn function_with_assigning_properties(span: Span) -> OxcDiagnostic {
     .with_label(span)
 }
 
+pub fn commonjs_export_with_export_assignment(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::error(
+        "Properties assigned to `module.exports` can't be declared together with `module.exports = ...`.",
+    )
+    .with_label(span)
+    .with_help("Declare the property on the value assigned to `module.exports`")
+}
+
 // TS9026: Declaration emit for this file requires preserving this import for augmentations. This is not supported with --isolatedDeclarations.
 // This error requires cross-file checking, which we cannot support.
 
-pub fn default_export_inferred(span: Span) -> OxcDiagnostic {
-    OxcDiagnostic::error("TS9037: Default exports can't be inferred with --isolatedDeclarations.")
-        .with_label(span)
+pub fn default_export_inferred(span: Span, annotation: Option<String>) -> OxcDiagnostic {
+    let error = OxcDiagnostic::error(
+        "TS9037: Default exports can't be inferred with --isolatedDeclarations.",
+    )
+    .with_label(span);
+    match annotation {
+        Some(annotation) => error.with_help(format!(
+            "Move the expression to a variable declared as `const _default: {annotation}` and export the variable"
+        )),
+        None => error.with_help(
+            "Move the expression in default export to a variable and add a type annotation to it",
+        ),
+    }
 }
 
 pub fn computed_property_name(span: Span) -> OxcDiagnostic {
@@ -45,6 +45,12 @@ impl<'a> IsolatedDeclarations<'a> {
                 prev_members.insert(member_name, value.clone());
             }
 
+            // The values of the following members are computed before stripping the member,
+            // so they do not change.
+            if self.has_internal_annotation(member.span) {
+                continue;
+            }
+
             let member = self.ast.ts_enum_member(
                 member.span,
                 member.id.clone_in(self.ast.allocator),
@@ -0,0 +1,246 @@
+//! Expando functions, i.e. functions with properties assigned to them.
+//!
+//! ```ts
+//! export function Button(): void {}
+//! Button.displayName = "Button";
+//! ```
+//!
+//! is emitted as
+//!
+//! ```ts
+//! export declare function Button(): void;
+//! export declare namespace Button {
+//!     var displayName: string;
+//! }
+//! ```
+//!
+//! A `const` cannot merge with a namespace, so the properties of a function assigned to a `const`
+//! are added to its type instead: `declare const Button: { (): void; displayName: string; }`.
+
+use oxc_allocator::{CloneIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_span::{Atom, SPAN, Span};
+
+use crate::{IsolatedDeclarations, diagnostics::function_with_assigning_properties};
+
+/// A property assigned to a function, e.g. `fn.displayName = "Fn"`.
+pub struct ExpandoProperty<'a> {
+    name: Atom<'a>,
+    /// Span of the assignment target, e.g. `fn.displayName`.
+    span: Span,
+    /// The assigned value, which the type of the property is inferred from.
+    value: Expression<'a>,
+}
+
+impl<'a> IsolatedDeclarations<'a> {
+    /// Collect the properties assigned to functions declared in `stmts`, by the name of the function.
+    ///
+    /// Properties which are exported from a namespace merged with the function are already declared,
+    /// and are skipped.
+    pub(crate) fn collect_expando_properties(
+        &self,
+        stmts: &ArenaVec<'a, Statement<'a>>,
+    ) -> Vec<(Atom<'a>, ExpandoProperty<'a>)> {
+        let assignable_properties_for_namespace =
+            IsolatedDeclarations::get_assignable_properties_for_namespaces(stmts);
+
+        // Names of functions, and whether properties can be assigned to them.
+        let mut function_names = vec![];
+        let mut properties: Vec<(Atom<'a>, ExpandoProperty<'a>)> = vec![];
+        for stmt in stmts {
+            match stmt {
+                Statement::FunctionDeclaration(func) => {
+                    Self::collect_expando_function_name(func, &mut function_names);
+                }
+                Statement::VariableDeclaration(decl) => {
+                    Self::collect_expando_variable_names(decl, &mut function_names);
+                }
+                Statement::ExportNamedDeclaration(decl) => match &decl.declaration {
+                    Some(Declaration::FunctionDeclaration(func)) => {
+                        Self::collect_expando_function_name(func, &mut function_names);
+                    }
+                    Some(Declaration::VariableDeclaration(decl)) => {
+                        Self::collect_expando_variable_names(decl, &mut function_names);
+                    }
+                    _ => {}
+                },
+                Statement::ExportDefaultDeclaration(decl) => {
+                    if let ExportDefaultDeclarationKind::FunctionDeclaration(func) =
+                        &decl.declaration
+                    {
+                        Self::collect_expando_function_name(func, &mut function_names);
+                    }
+                }
+                Statement::ExpressionStatement(stmt) => {
+                    let Expression::AssignmentExpression(assignment) = &stmt.expression else {
+                        continue;
+                    };
+                    let AssignmentTarget::StaticMemberExpression(member_expr) = &assignment.left
+                    else {
+                        continue;
+                    };
+                    let Expression::Identifier(ident) = &member_expr.object else { continue };
+                    let name = member_expr.property.name;
+                    let is_declared = assignable_properties_for_namespace
+                        .get(&ident.name.as_str())
+                        .is_some_and(|properties| properties.contains(&name));
+                    let is_assigned = properties.iter().any(|(function_name, property)| {
+                        *function_name == ident.name && property.name == name
+                    });
+                    let can_assign = function_names
+                        .iter()
+                        .rev()
+                        .find(|(function_name, _)| *function_name == ident.name)
+                        .map(|(_, can_assign)| *can_assign);
+                    if can_assign.is_none() || is_declared || is_assigned {
+                        continue;
+                    }
+                    if can_assign == Some(false) {
+                        self.error(function_with_assigning_properties(member_expr.span));
+                        continue;
+                    }
+                    let value = assignment.right.clone_in(self.ast.allocator);
+                    let property = ExpandoProperty { name, span: member_expr.span, value };
+                    properties.push((ident.name, property));
+                }
+                _ => {}
+            }
+        }
+        properties
+    }
+
+    fn collect_expando_function_name(
+        func: &Function<'a>,
+        function_names: &mut Vec<(Atom<'a>, bool)>,
+    ) {
+        if func.body.is_some() {
+            if let Some(name) = func.name() {
+                function_names.push((name, true));
+            }
+        }
+    }
+
+    fn collect_expando_variable_names(
+        decl: &VariableDeclaration<'a>,
+        function_names: &mut Vec<(Atom<'a>, bool)>,
+    ) {
+        for declarator in &decl.declarations {
+            if declarator.id.type_annotation.is_some()
+                || !declarator.init.as_ref().is_some_and(Expression::is_function)
+            {
+                continue;
+            }
+            let Some(name) = declarator.id.get_identifier_name() else { continue };
+            // Only a `const` keeps the type of the function it is initialized with.
+            function_names.push((name, decl.kind.is_const()));
+        }
+    }
+
+    /// Take the properties assigned to the function `name`.
+    fn take_expando_properties(&self, name: Atom<'a>) -> Vec<ExpandoProperty<'a>> {
+        let mut expando_properties = self.expando_properties.borrow_mut();
+        let (properties, rest) =
+            expando_properties.drain(..).partition(|(function_name, _)| *function_name == name);
+        *expando_properties = rest;
+        properties.into_iter().map(|(_, property)| property).collect()
+    }
+
+    /// Returns the namespace declaring the properties assigned to the function declared by `stmt`.
+    pub(crate) fn transform_expando_function(&self, stmt: &Statement<'a>) -> Option<Statement<'a>> {
+        let (func, is_export) = match stmt {
+            Statement::FunctionDeclaration(func) => (func, false),
+            Statement::ExportNamedDeclaration(decl) => match &decl.declaration {
+                Some(Declaration::FunctionDeclaration(func)) => (func, true),
+                _ => return None,
+            },
+            Statement::ExportDefaultDeclaration(decl) => match &decl.declaration {
+                ExportDefaultDeclarationKind::FunctionDeclaration(func) => (func, false),
+                _ => return None,
+            },
+            _ => return None,
+        };
+        let id = func.id.as_ref()?;
+        let properties = self.take_expando_properties(id.name);
+        if properties.is_empty() {
+            return None;
+        }
+
+        let stmts = self.ast.vec_from_iter(properties.into_iter().map(|property| {
+            let ts_type = self.infer_expando_property_type(&property);
+            let id = self.ast.binding_pattern(
+                self.ast.binding_pattern_kind_binding_identifier(property.span, property.name),
+                Some(self.ast.ts_type_annotation(SPAN, ts_type)),
+                false,
+            );
+            let kind = VariableDeclarationKind::Var;
+            let declarations =
+                self.ast.vec1(self.ast.variable_declarator(SPAN, kind, id, None, false));
+            Statement::from(self.ast.declaration_variable(SPAN, kind, declarations, false))
+        }));
+        let namespace = self.ast.declaration_ts_module(
+            SPAN,
+            self.ast.ts_module_declaration_name_identifier(id.span, id.name),
+            Some(self.ast.ts_module_declaration_body_module_block(SPAN, self.ast.vec(), stmts)),
+            TSModuleDeclarationKind::Namespace,
+            self.is_declare(),
+        );
+        Some(if is_export {
+            Statement::ExportNamedDeclaration(self.ast.alloc_export_named_declaration(
+                SPAN,
+                Some(namespace),
+                self.ast.vec(),
+                None,
+                ImportOrExportKind::Value,
+                NONE,
+            ))
+        } else {
+            Statement::from(namespace)
+        })
+    }
+
+    /// Add the properties assigned to the function `name` to `ts_type`, the type of the function.
+    pub(crate) fn transform_expando_function_type(
+        &self,
+        name: Atom<'a>,
+        ts_type: TSType<'a>,
+    ) -> TSType<'a> {
+        let TSType::TSFunctionType(func) = ts_type else { return ts_type };
+        let properties = self.take_expando_properties(name);
+        if properties.is_empty() {
+            return TSType::TSFunctionType(func);
+        }
+
+        let func = func.unbox();
+        let call_signature = self.ast.ts_signature_call_signature_declaration(
+            func.span,
+            func.type_parameters,
+            func.this_param,
+            func.params,
+            Some(func.return_type),
+        );
+        let members = self.ast.vec_from_iter(std::iter::once(call_signature).chain(
+            properties.into_iter().map(|property| {
+                let ts_type = self.infer_expando_property_type(&property);
+                self.ast.ts_signature_property_signature(
+                    property.span,
+                    false,
+                    false,
+                    false,
+                    self.ast.property_key_static_identifier(property.span, property.name),
+                    Some(self.ast.ts_type_annotation(SPAN, ts_type)),
+                )
+            }),
+        ));
+        self.ast.ts_type_type_literal(SPAN, members)
+    }
+
+    fn infer_expando_property_type(&self, property: &ExpandoProperty<'a>) -> TSType<'a> {
+        self.infer_type_from_expression(&property.value).unwrap_or_else(|| {
+            // Functions report their own errors when their type cannot be inferred.
+            if !property.value.is_function() {
+                self.error(function_with_assigning_properties(property.span));
+            }
+            self.ast.ts_type_unknown_keyword(SPAN)
+        })
+    }
+}
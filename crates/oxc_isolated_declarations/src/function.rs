@@ -19,7 +19,11 @@ impl<'a> IsolatedDeclarations<'a> {
     ) -> ArenaBox<'a, Function<'a>> {
         let return_type = self.infer_function_return_type(func);
         if return_type.is_none() {
-            self.error(function_must_have_explicit_return_type(get_function_span(func)));
+            self.error(function_must_have_explicit_return_type(
+                get_function_span(func),
+                Some(func.params.span.end),
+                self.suggest_function_return_type(func),
+            ));
         }
         let params = self.transform_formal_parameters(&func.params, false);
         self.ast.alloc_function(
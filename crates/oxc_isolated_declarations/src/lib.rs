@@ -15,12 +15,14 @@ use oxc_ast_visit::Visit;
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_span::{Atom, GetSpan, SPAN, SourceType};
 
-use crate::{diagnostics::function_with_assigning_properties, scope::ScopeTree};
+use crate::{expando::ExpandoProperty, scope::ScopeTree};
 
 mod class;
+mod commonjs;
 mod declaration;
 mod diagnostics;
 mod r#enum;
+mod expando;
 mod formal_parameter_binding_pattern;
 mod function;
 mod inferrer;
@@ -29,6 +31,7 @@ mod module;
 mod return_type;
 mod scope;
 mod signatures;
+mod suggestion;
 mod types;
 
 #[derive(Debug, Default, Clone, Copy)]
@@ -52,10 +55,14 @@ pub struct IsolatedDeclarationsReturn<'a> {
 
 pub struct IsolatedDeclarations<'a> {
     ast: AstBuilder<'a>,
+    /// Source text of the program, for suggesting type annotations written as in the source.
+    source_text: &'a str,
 
     // state
     scope: ScopeTree<'a>,
     errors: RefCell<Vec<OxcDiagnostic>>,
+    /// Properties assigned to the functions of the statements being transformed.
+    expando_properties: RefCell<Vec<(Atom<'a>, ExpandoProperty<'a>)>>,
 
     // options
     strip_internal: bool,
@@ -69,10 +76,12 @@ impl<'a> IsolatedDeclarations<'a> {
         let strip_internal = options.strip_internal;
         Self {
             ast: AstBuilder::new(allocator),
+            source_text: "",
             strip_internal,
             internal_annotations: FxHashSet::default(),
             scope: ScopeTree::new(),
             errors: RefCell::new(vec![]),
+            expando_properties: RefCell::new(vec![]),
         }
     }
 
@@ -80,6 +89,7 @@ impl<'a> IsolatedDeclarations<'a> {
     ///
     /// Returns `Vec<Error>` if any errors were collected during the transformation.
     pub fn build(mut self, program: &Program<'a>) -> IsolatedDeclarationsReturn<'a> {
+        self.source_text = program.source_text;
         self.internal_annotations = self
             .strip_internal
             .then(|| Self::build_internal_annotations(program))
@@ -133,12 +143,14 @@ impl<'a> IsolatedDeclarations<'a> {
 
 impl<'a> IsolatedDeclarations<'a> {
     fn transform_program(&mut self, program: &Program<'a>) -> ArenaVec<'a, Statement<'a>> {
-        let has_import_or_export = program.body.iter().any(Statement::is_module_declaration);
+        let commonjs_stmts = self.transform_commonjs_exports(&program.body);
+        let stmts = commonjs_stmts.as_ref().unwrap_or(&program.body);
+        let has_import_or_export = stmts.iter().any(Statement::is_module_declaration);
 
         if has_import_or_export {
-            self.transform_statements_on_demand(&program.body)
+            self.transform_statements_on_demand(stmts)
         } else {
-            self.transform_program_without_module_declaration(&program.body)
+            self.transform_program_without_module_declaration(stmts)
         }
     }
 
@@ -146,7 +158,8 @@ impl<'a> IsolatedDeclarations<'a> {
         &mut self,
         stmts: &ArenaVec<'a, Statement<'a>>,
     ) -> ArenaVec<'a, Statement<'a>> {
-        self.report_error_for_expando_function(stmts);
+        let expando_properties = self.collect_expando_properties(stmts);
+        let outer_expando_properties = self.expando_properties.replace(expando_properties);
 
         let mut stmts = stmts
             .iter()
@@ -155,20 +168,29 @@ impl<'a> IsolatedDeclarations<'a> {
 
         Self::remove_function_overloads_implementation(&mut stmts);
 
-        self.ast.vec_from_iter(stmts.iter().map(|stmt| {
-            if let Some(new_decl) = self.transform_declaration(stmt.to_declaration(), false) {
-                Statement::from(new_decl)
-            } else {
-                stmt.clone_in(self.ast.allocator)
-            }
-        }))
+        let mut new_stmts = self.ast.vec_with_capacity(stmts.len());
+        for stmt in stmts {
+            let new_stmt =
+                if let Some(new_decl) = self.transform_declaration(stmt.to_declaration(), false) {
+                    Statement::from(new_decl)
+                } else {
+                    stmt.clone_in(self.ast.allocator)
+                };
+            let namespace = self.transform_expando_function(&new_stmt);
+            new_stmts.push(new_stmt);
+            new_stmts.extend(namespace);
+        }
+
+        self.expando_properties.replace(outer_expando_properties);
+        new_stmts
     }
 
     fn transform_statements_on_demand(
         &mut self,
         stmts: &ArenaVec<'a, Statement<'a>>,
     ) -> ArenaVec<'a, Statement<'a>> {
-        self.report_error_for_expando_function(stmts);
+        let expando_properties = self.collect_expando_properties(stmts);
+        let outer_expando_properties = self.expando_properties.replace(expando_properties);
 
         let mut stmts = stmts
             .iter()
@@ -380,7 +402,9 @@ impl<'a> IsolatedDeclarations<'a> {
                         new_stmts.push(export_external_var_statement);
                     }
                 }
+                let namespace = self.transform_expando_function(&new_stmt);
                 new_stmts.push(new_stmt);
+                new_stmts.extend(namespace);
                 continue;
             }
             match stmt {
@@ -430,6 +454,7 @@ impl<'a> IsolatedDeclarations<'a> {
             self.strip_export_keyword(&mut new_stmts);
         }
 
+        self.expando_properties.replace(outer_expando_properties);
         new_stmts
     }
 
@@ -564,94 +589,6 @@ impl<'a> IsolatedDeclarations<'a> {
         assignable_properties_for_namespace
     }
 
-    fn report_error_for_expando_function(&self, stmts: &ArenaVec<'a, Statement<'a>>) {
-        let assignable_properties_for_namespace =
-            IsolatedDeclarations::get_assignable_properties_for_namespaces(stmts);
-
-        let mut can_expando_function_names = FxHashSet::default();
-        for stmt in stmts {
-            match stmt {
-                Statement::ExportNamedDeclaration(decl) => match decl.declaration.as_ref() {
-                    Some(Declaration::FunctionDeclaration(func)) => {
-                        if func.body.is_some() {
-                            if let Some(id) = func.id.as_ref() {
-                                can_expando_function_names.insert(id.name);
-                            }
-                        }
-                    }
-                    Some(Declaration::VariableDeclaration(decl)) => {
-                        for declarator in &decl.declarations {
-                            if declarator.id.type_annotation.is_none()
-                                && declarator.init.as_ref().is_some_and(Expression::is_function)
-                            {
-                                if let Some(name) = declarator.id.get_identifier_name() {
-                                    can_expando_function_names.insert(name);
-                                }
-                            }
-                        }
-                    }
-                    _ => (),
-                },
-                Statement::ExportDefaultDeclaration(decl) => {
-                    if let ExportDefaultDeclarationKind::FunctionDeclaration(func) =
-                        &decl.declaration
-                    {
-                        if func.body.is_some() {
-                            if let Some(name) = func.name() {
-                                can_expando_function_names.insert(name);
-                            }
-                        }
-                    }
-                }
-                Statement::FunctionDeclaration(func) => {
-                    if func.body.is_some() {
-                        if let Some(name) = func.name() {
-                            if self.scope.has_reference(&name) {
-                                can_expando_function_names.insert(name);
-                            }
-                        }
-                    }
-                }
-                Statement::VariableDeclaration(decl) => {
-                    for declarator in &decl.declarations {
-                        if declarator.id.type_annotation.is_none()
-                            && declarator.init.as_ref().is_some_and(Expression::is_function)
-                        {
-                            if let Some(name) = declarator.id.get_identifier_name() {
-                                if self.scope.has_reference(&name) {
-                                    can_expando_function_names.insert(name);
-                                }
-                            }
-                        }
-                    }
-                }
-                Statement::ExpressionStatement(stmt) => {
-                    if let Expression::AssignmentExpression(assignment) = &stmt.expression {
-                        if let AssignmentTarget::StaticMemberExpression(static_member_expr) =
-                            &assignment.left
-                        {
-                            if let Expression::Identifier(ident) = &static_member_expr.object {
-                                if can_expando_function_names.contains(&ident.name)
-                                    && !assignable_properties_for_namespace
-                                        .get(&ident.name.as_str())
-                                        .is_some_and(|properties| {
-                                            properties.contains(&static_member_expr.property.name)
-                                        })
-                                {
-                                    self.error(function_with_assigning_properties(
-                                        static_member_expr.span,
-                                    ));
-                                }
-                            }
-                        }
-                    }
-                }
-
-                _ => {}
-            }
-        }
-    }
-
     fn is_declare(&self) -> bool {
         // If we are in a module block, we don't need to add declare
         !self.scope.is_ts_module_block()
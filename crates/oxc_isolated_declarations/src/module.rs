@@ -98,13 +98,16 @@ impl<'a> IsolatedDeclarations<'a> {
             // declare const _default: Type
             let kind = VariableDeclarationKind::Const;
             let name = self.create_unique_name("_default");
-            let id = self.ast.binding_pattern_kind_binding_identifier(SPAN, name);
+            let id = self.ast.binding_pattern_kind_binding_identifier(expr.span(), name);
             let type_annotation = self
                 .infer_type_from_expression(expr)
                 .map(|ts_type| self.ast.ts_type_annotation(SPAN, ts_type));
 
             if type_annotation.is_none() {
-                self.error(default_export_inferred(expr.span()));
+                self.error(default_export_inferred(
+                    expr.span(),
+                    self.suggest_type_annotation(expr),
+                ));
             }
 
             let id = self.ast.binding_pattern(id, type_annotation, false);
@@ -0,0 +1,167 @@
+//! Suggested type annotations for declarations whose type cannot be inferred.
+//!
+//! The suggestions are read from the syntax alone, e.g. `Foo` for `new Foo()` or `number | string`
+//! for a function returning `1` or `"a"`, so that they can be inserted as they are.
+
+use oxc_ast::ast::*;
+use oxc_ast_visit::Visit;
+use oxc_span::{GetSpan, Span};
+use oxc_syntax::scope::ScopeFlags;
+
+use crate::IsolatedDeclarations;
+
+impl<'a> IsolatedDeclarations<'a> {
+    /// The type annotation which declares the type of `expr`, without the leading `: `.
+    pub(crate) fn suggest_type_annotation(&self, expr: &Expression<'a>) -> Option<String> {
+        let mut types = vec![];
+        self.collect_suggested_types(expr, &mut types)?;
+        Some(types.join(" | "))
+    }
+
+    /// The return type annotation of `func`, without the leading `: `.
+    pub(crate) fn suggest_function_return_type(&self, func: &Function<'a>) -> Option<String> {
+        if func.generator {
+            return None;
+        }
+        let body = func.body.as_ref()?;
+        let mut returns = SuggestedReturnTypes::new(self);
+        returns.visit_function_body(body);
+        returns.finish(func.r#async)
+    }
+
+    /// The return type annotation of `func`, without the leading `: `.
+    pub(crate) fn suggest_arrow_function_return_type(
+        &self,
+        func: &ArrowFunctionExpression<'a>,
+    ) -> Option<String> {
+        let mut returns = SuggestedReturnTypes::new(self);
+        match func.get_expression() {
+            Some(expr) => returns.add(Some(expr)),
+            None => returns.visit_function_body(&func.body),
+        }
+        returns.finish(func.r#async)
+    }
+
+    /// Position of the type annotation of a property with the key at `key_span`, after the closing
+    /// bracket of a computed key and after the `?` or `!` modifier.
+    pub(crate) fn property_annotation_position(
+        &self,
+        key_span: Span,
+        computed: bool,
+        has_modifier: bool,
+    ) -> u32 {
+        let mut position = key_span.end;
+        if computed {
+            position = self.position_after_token(position, &[']']);
+        }
+        if has_modifier {
+            position = self.position_after_token(position, &['?', '!']);
+        }
+        position
+    }
+
+    /// The position after the first of `tokens` at `position`, skipping whitespace before it.
+    fn position_after_token(&self, position: u32, tokens: &[char]) -> u32 {
+        let rest = &self.source_text[position as usize..];
+        let trimmed = rest.trim_start();
+        if trimmed.starts_with(tokens) {
+            #[expect(clippy::cast_possible_truncation)]
+            let skipped = (rest.len() - trimmed.len()) as u32;
+            position + skipped + 1
+        } else {
+            position
+        }
+    }
+
+    /// Push the types of `expr` to `types`, widening literals as TypeScript does for mutable
+    /// declarations and return types.
+    fn collect_suggested_types(
+        &self,
+        expr: &Expression<'a>,
+        types: &mut Vec<String>,
+    ) -> Option<()> {
+        let ts_type = match expr {
+            Expression::StringLiteral(_) | Expression::TemplateLiteral(_) => "string".to_string(),
+            Expression::NumericLiteral(_) => "number".to_string(),
+            Expression::BigIntLiteral(_) => "bigint".to_string(),
+            Expression::BooleanLiteral(_) => "boolean".to_string(),
+            Expression::NullLiteral(_) => "null".to_string(),
+            Expression::Identifier(ident) if ident.name == "undefined" => "undefined".to_string(),
+            Expression::UnaryExpression(expr) if Self::can_infer_unary_expression(expr) => {
+                "number".to_string()
+            }
+            Expression::NewExpression(expr) if expr.callee.is_entity_name_expression() => {
+                let callee = expr.callee.span().source_text(self.source_text);
+                match &expr.type_arguments {
+                    Some(type_arguments) => {
+                        format!("{callee}{}", type_arguments.span.source_text(self.source_text))
+                    }
+                    None => callee.to_string(),
+                }
+            }
+            Expression::TSAsExpression(expr) if !expr.type_annotation.is_const_type_reference() => {
+                expr.type_annotation.span().source_text(self.source_text).to_string()
+            }
+            Expression::ConditionalExpression(expr) => {
+                self.collect_suggested_types(&expr.consequent, types)?;
+                return self.collect_suggested_types(&expr.alternate, types);
+            }
+            Expression::ParenthesizedExpression(expr) => {
+                return self.collect_suggested_types(&expr.expression, types);
+            }
+            _ => return None,
+        };
+        if !types.contains(&ts_type) {
+            types.push(ts_type);
+        }
+        Some(())
+    }
+}
+
+/// Collect the types of the values returned by a function body.
+struct SuggestedReturnTypes<'a, 'b> {
+    transformer: &'b IsolatedDeclarations<'a>,
+    /// `None` if the type of a returned value is unknown.
+    types: Option<Vec<String>>,
+}
+
+impl<'a, 'b> SuggestedReturnTypes<'a, 'b> {
+    fn new(transformer: &'b IsolatedDeclarations<'a>) -> Self {
+        Self { transformer, types: Some(vec![]) }
+    }
+
+    fn add(&mut self, argument: Option<&Expression<'a>>) {
+        let Some(types) = &mut self.types else { return };
+        if let Some(argument) = argument {
+            if self.transformer.collect_suggested_types(argument, types).is_none() {
+                self.types = None;
+            }
+        } else if !types.iter().any(|ts_type| ts_type == "undefined") {
+            types.push("undefined".to_string());
+        }
+    }
+
+    fn finish(self, is_async: bool) -> Option<String> {
+        let mut types = self.types?;
+        // A function which only returns without a value is declared to return `void`.
+        if types.is_empty() || types == ["undefined"] {
+            types = vec!["void".to_string()];
+        }
+        let return_type = types.join(" | ");
+        Some(if is_async { format!("Promise<{return_type}>") } else { return_type })
+    }
+}
+
+impl<'a> Visit<'a> for SuggestedReturnTypes<'a, '_> {
+    fn visit_function(&mut self, _func: &Function<'a>, _flags: ScopeFlags) {
+        // Returns of nested functions don't return from this function
+    }
+
+    fn visit_arrow_function_expression(&mut self, _expr: &ArrowFunctionExpression<'a>) {
+        // Returns of nested functions don't return from this function
+    }
+
+    fn visit_return_statement(&mut self, stmt: &ReturnStatement<'a>) {
+        self.add(stmt.argument.as_ref());
+    }
+}
@@ -24,7 +24,11 @@ impl<'a> IsolatedDeclarations<'a> {
     pub(crate) fn transform_function_to_ts_type(&self, func: &Function<'a>) -> Option<TSType<'a>> {
         let return_type = self.infer_function_return_type(func);
         if return_type.is_none() {
-            self.error(function_must_have_explicit_return_type(get_function_span(func)));
+            self.error(function_must_have_explicit_return_type(
+                get_function_span(func),
+                Some(func.params.span.end),
+                self.suggest_function_return_type(func),
+            ));
         }
 
         let params = self.transform_formal_parameters(&func.params, false);
@@ -47,10 +51,14 @@ impl<'a> IsolatedDeclarations<'a> {
         let return_type = self.infer_arrow_function_return_type(func);
 
         if return_type.is_none() {
-            self.error(function_must_have_explicit_return_type(Span::new(
-                func.params.span.start,
-                func.body.span.start + 1,
-            )));
+            // A return type can't be added to a single parameter without parentheses.
+            let is_parenthesized =
+                self.source_text[func.params.span.start as usize..].starts_with('(');
+            self.error(function_must_have_explicit_return_type(
+                Span::new(func.params.span.start, func.body.span.start + 1),
+                is_parenthesized.then_some(func.params.span.end),
+                self.suggest_arrow_function_return_type(func),
+            ));
         }
 
         let params = self.transform_formal_parameters(&func.params, false);
@@ -131,7 +139,11 @@ impl<'a> IsolatedDeclarations<'a> {
                         };
                         let return_type = self.infer_function_return_type(function);
                         if return_type.is_none() {
-                            self.error(method_must_have_explicit_return_type(object.key.span()));
+                            self.error(method_must_have_explicit_return_type(
+                                object.key.span(),
+                                function.params.span.end,
+                                self.suggest_function_return_type(function),
+                            ));
                         }
                         let params = self.transform_formal_parameters(&function.params, false);
                         let key = self.transform_property_key(key);
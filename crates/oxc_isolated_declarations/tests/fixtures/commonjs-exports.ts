@@ -0,0 +1,13 @@
+import type { Options } from "./options";
+
+function parse(input: string, options?: Options): number {
+  return 0;
+}
+
+function internal(): void {}
+
+exports.parse = parse;
+module.exports.format = (value: number): string => `${value}`;
+exports.version = "1.0.0";
+exports.version = "2.0.0";
+exports.defaults = new Map();
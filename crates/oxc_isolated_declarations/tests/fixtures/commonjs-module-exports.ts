@@ -0,0 +1,12 @@
+import path = require("path");
+
+function basename(p: string): string {
+  return path.basename(p);
+}
+
+module.exports = {
+  basename: basename as typeof basename,
+  version: "1.0.0",
+};
+
+module.exports.extra = 1;
@@ -0,0 +1,19 @@
+export function Button(): void {}
+Button.displayName = "Button";
+Button.defaultProps = { size: 1 };
+
+export const Card = (): void => {};
+Card.displayName = "Card";
+Card.displayName = "Other";
+
+function internal(): void {}
+internal.version = 1;
+
+export default function App(): void {}
+App.displayName = "App";
+
+export let notConst = (): void => {};
+notConst.displayName = "NotConst";
+
+export function unknownValue(): void {}
+unknownValue.value = Math.random();
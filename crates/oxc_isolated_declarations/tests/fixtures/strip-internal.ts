@@ -79,4 +79,18 @@ export namespace StripInternalNamespaceInner {
 /**
  * @internal
  */
-export namespace StripInternalNamespace {}
\ No newline at end of file
+export namespace StripInternalNamespace {}
+export enum StripInternalEnum {
+  A,
+  /** @internal */
+  B,
+  C,
+}
+
+export class StripInternalParameterProperties {
+  constructor(
+    public visible: number,
+    /** @internal */
+    public internal: number,
+  ) {}
+}
@@ -0,0 +1,23 @@
+export const map = new Map<string, number>();
+export let value = Math.random() > 0.5 ? 1 : "one";
+export let list = [1, 2, 3];
+
+export class Cls {
+  cache? = new Cache();
+  [key]! = new Ns.Value();
+
+  method(a: number) {
+    if (a) {
+      return new Date();
+    }
+    return null;
+  }
+}
+
+export const load = async () => {
+  return;
+};
+
+export const unknown = (x) => compute(x);
+
+export default new Ns.Value();
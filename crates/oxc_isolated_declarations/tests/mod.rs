@@ -3,9 +3,10 @@ mod deno;
 use std::{fmt::Write, fs, path::Path, sync::Arc};
 
 use oxc_allocator::Allocator;
-use oxc_codegen::Codegen;
+use oxc_codegen::{Codegen, CodegenOptions};
 use oxc_isolated_declarations::{IsolatedDeclarations, IsolatedDeclarationsOptions};
 use oxc_parser::Parser;
+use oxc_sourcemap::Token;
 use oxc_span::SourceType;
 
 fn transform(path: &Path, source_text: &str) -> String {
@@ -49,3 +50,54 @@ fn snapshots() {
         });
     });
 }
+
+/// Names in the declaration map point at the same names in the source, so that "go to definition"
+/// lands in the `.ts` file.
+#[test]
+fn declaration_map() {
+    let source_text = "export const a = 1;
+export function f(x: number): void {}
+export class C {
+  constructor(public p: number) {}
+  method(y: string): void {}
+}
+export interface I { k: string }
+export type T = I | string;
+export enum E { A }
+export namespace N { export const q: string = ''; }
+";
+    let allocator = Allocator::default();
+    let program = Parser::new(&allocator, source_text, SourceType::ts()).parse().program;
+    let ret = IsolatedDeclarations::new(&allocator, IsolatedDeclarationsOptions::default())
+        .build(&program);
+    assert!(ret.errors.is_empty(), "{:?}", ret.errors);
+    let ret = Codegen::new()
+        .with_options(CodegenOptions {
+            source_map_path: Some("test.ts".into()),
+            ..CodegenOptions::default()
+        })
+        .build(&ret.program);
+    let map = ret.map.unwrap();
+
+    let starts_with_name = |text: &str, name: &str| {
+        text.strip_prefix(name)
+            .is_some_and(|rest| !rest.starts_with(|c: char| c.is_alphanumeric() || c == '_'))
+    };
+    for name in ["a", "f", "x", "C", "p", "method", "y", "I", "k", "T", "E", "A", "N", "q"] {
+        let mut tokens = map.get_tokens().filter(|token| {
+            starts_with_name(text_at(&ret.code, token.get_dst_line(), token.get_dst_col()), name)
+        });
+        let first =
+            tokens.next().unwrap_or_else(|| panic!("`{name}` is not mapped in\n{}", ret.code));
+        let position = |token: &Token| (token.get_dst_line(), token.get_dst_col());
+        // The last mapping at a position is the most specific one, e.g. a key after its property.
+        let token = tokens.take_while(|token| position(token) == position(first)).last();
+        let token = token.unwrap_or(first);
+        let source = text_at(source_text, token.get_src_line(), token.get_src_col());
+        assert!(starts_with_name(source, name), "`{name}` is mapped to `{source}`");
+    }
+}
+
+fn text_at(text: &str, line: u32, col: u32) -> &str {
+    text.lines().nth(line as usize).map_or("", |line| &line[col as usize..])
+}
@@ -1,11 +1,16 @@
 use tower_lsp_server::lsp_types::{
     ClientCapabilities, CodeActionKind, CodeActionOptions, CodeActionProviderCapability,
-    ExecuteCommandOptions, OneOf, SaveOptions, ServerCapabilities, TextDocumentSyncCapability,
+    DiagnosticOptions, DiagnosticServerCapabilities, ExecuteCommandOptions, OneOf, RenameOptions,
+    SaveOptions, SemanticTokensFullOptions, SemanticTokensOptions,
+    SemanticTokensServerCapabilities, ServerCapabilities, TextDocumentSyncCapability,
     TextDocumentSyncKind, TextDocumentSyncOptions, TextDocumentSyncSaveOptions,
     WorkDoneProgressOptions, WorkspaceFoldersServerCapabilities, WorkspaceServerCapabilities,
 };
 
-use crate::{code_actions::CODE_ACTION_KIND_SOURCE_FIX_ALL_OXC, commands::FIX_ALL_COMMAND_ID};
+use crate::{
+    code_actions::CODE_ACTION_KIND_SOURCE_FIX_ALL_OXC, commands::FIX_ALL_COMMAND_ID,
+    semantic_tokens,
+};
 
 #[derive(Clone, Default)]
 pub struct Capabilities {
@@ -14,6 +19,9 @@ pub struct Capabilities {
     pub workspace_execute_command: bool,
     pub workspace_configuration: bool,
     pub dynamic_watchers: bool,
+    pub rename_prepare_support: bool,
+    pub pull_diagnostics: bool,
+    pub workspace_diagnostic_refresh: bool,
 }
 
 impl From<ClientCapabilities> for Capabilities {
@@ -34,11 +42,22 @@ impl From<ClientCapabilities> for Capabilities {
             .workspace
             .as_ref()
             .is_some_and(|workspace| workspace.configuration.is_some_and(|config| config));
+        let workspace_diagnostic_refresh = value.workspace.as_ref().is_some_and(|workspace| {
+            workspace
+                .diagnostic
+                .as_ref()
+                .is_some_and(|diagnostic| diagnostic.refresh_support.is_some_and(|refresh| refresh))
+        });
         let dynamic_watchers = value.workspace.is_some_and(|workspace| {
             workspace.did_change_watched_files.is_some_and(|watched_files| {
                 watched_files.dynamic_registration.is_some_and(|dynamic| dynamic)
             })
         });
+        let rename_prepare_support = value.text_document.as_ref().is_some_and(|capability| {
+            capability.rename.as_ref().is_some_and(|rename| rename.prepare_support == Some(true))
+        });
+        let pull_diagnostics =
+            value.text_document.as_ref().is_some_and(|capability| capability.diagnostic.is_some());
 
         Self {
             code_action_provider,
@@ -46,6 +65,9 @@ impl From<ClientCapabilities> for Capabilities {
             workspace_execute_command,
             workspace_configuration,
             dynamic_watchers,
+            rename_prepare_support,
+            pull_diagnostics,
+            workspace_diagnostic_refresh,
         }
     }
 }
@@ -84,6 +106,39 @@ impl From<Capabilities> for ServerCapabilities {
             } else {
                 None
             },
+            references_provider: Some(OneOf::Left(true)),
+            diagnostic_provider: if value.pull_diagnostics {
+                Some(DiagnosticServerCapabilities::Options(DiagnosticOptions {
+                    identifier: Some("oxc".into()),
+                    // with the import plugin, a file can be reported because of the files it imports
+                    inter_file_dependencies: true,
+                    workspace_diagnostics: true,
+                    work_done_progress_options: WorkDoneProgressOptions {
+                        work_done_progress: Some(true),
+                    },
+                }))
+            } else {
+                None
+            },
+            document_symbol_provider: Some(OneOf::Left(true)),
+            semantic_tokens_provider: Some(
+                SemanticTokensServerCapabilities::SemanticTokensOptions(SemanticTokensOptions {
+                    legend: semantic_tokens::legend(),
+                    range: None,
+                    full: Some(SemanticTokensFullOptions::Delta { delta: Some(true) }),
+                    work_done_progress_options: WorkDoneProgressOptions::default(),
+                }),
+            ),
+            document_highlight_provider: Some(OneOf::Left(true)),
+            // `RenameOptions` may only be sent when the client supports `textDocument/prepareRename`
+            rename_provider: Some(if value.rename_prepare_support {
+                OneOf::Right(RenameOptions {
+                    prepare_provider: Some(true),
+                    work_done_progress_options: WorkDoneProgressOptions::default(),
+                })
+            } else {
+                OneOf::Left(true)
+            }),
             execute_command_provider: if value.workspace_execute_command {
                 Some(ExecuteCommandOptions {
                     commands: vec![FIX_ALL_COMMAND_ID.to_string()],
@@ -101,8 +156,10 @@ impl From<Capabilities> for ServerCapabilities {
 mod test {
     use tower_lsp_server::lsp_types::{
         ClientCapabilities, CodeActionClientCapabilities, CodeActionKindLiteralSupport,
-        CodeActionLiteralSupport, DidChangeWatchedFilesClientCapabilities,
-        DynamicRegistrationClientCapabilities, TextDocumentClientCapabilities,
+        CodeActionLiteralSupport, DiagnosticClientCapabilities, DiagnosticOptions,
+        DiagnosticServerCapabilities, DiagnosticWorkspaceClientCapabilities,
+        DidChangeWatchedFilesClientCapabilities, DynamicRegistrationClientCapabilities, OneOf,
+        RenameClientCapabilities, ServerCapabilities, TextDocumentClientCapabilities,
         WorkspaceClientCapabilities,
     };
 
@@ -273,4 +330,62 @@ mod test {
         let capabilities = Capabilities::from(client_capabilities);
         assert!(capabilities.dynamic_watchers);
     }
+
+    #[test]
+    fn test_rename_prepare_support() {
+        let client_capabilities = ClientCapabilities {
+            text_document: Some(TextDocumentClientCapabilities {
+                rename: Some(RenameClientCapabilities {
+                    prepare_support: Some(true),
+                    ..RenameClientCapabilities::default()
+                }),
+                ..TextDocumentClientCapabilities::default()
+            }),
+            ..ClientCapabilities::default()
+        };
+
+        let capabilities = Capabilities::from(client_capabilities);
+        assert!(capabilities.rename_prepare_support);
+        let server_capabilities = ServerCapabilities::from(capabilities);
+        assert!(matches!(server_capabilities.rename_provider, Some(OneOf::Right(_))));
+
+        let capabilities = Capabilities::from(ClientCapabilities::default());
+        assert!(!capabilities.rename_prepare_support);
+        let server_capabilities = ServerCapabilities::from(capabilities);
+        assert!(matches!(server_capabilities.rename_provider, Some(OneOf::Left(true))));
+    }
+
+    #[test]
+    fn test_pull_diagnostics() {
+        let client_capabilities = ClientCapabilities {
+            text_document: Some(TextDocumentClientCapabilities {
+                diagnostic: Some(DiagnosticClientCapabilities::default()),
+                ..TextDocumentClientCapabilities::default()
+            }),
+            workspace: Some(WorkspaceClientCapabilities {
+                diagnostic: Some(DiagnosticWorkspaceClientCapabilities {
+                    refresh_support: Some(true),
+                }),
+                ..WorkspaceClientCapabilities::default()
+            }),
+            ..ClientCapabilities::default()
+        };
+
+        let capabilities = Capabilities::from(client_capabilities);
+        assert!(capabilities.pull_diagnostics);
+        assert!(capabilities.workspace_diagnostic_refresh);
+        let server_capabilities = ServerCapabilities::from(capabilities);
+        assert!(matches!(
+            server_capabilities.diagnostic_provider,
+            Some(DiagnosticServerCapabilities::Options(DiagnosticOptions {
+                workspace_diagnostics: true,
+                ..
+            }))
+        ));
+
+        let capabilities = Capabilities::from(ClientCapabilities::default());
+        assert!(!capabilities.pull_diagnostics);
+        assert!(!capabilities.workspace_diagnostic_refresh);
+        assert!(ServerCapabilities::from(capabilities).diagnostic_provider.is_none());
+    }
 }
@@ -62,32 +62,52 @@ pub fn apply_fix_code_actions(report: &DiagnosticReport, uri: &Uri) -> Option<Ve
     }
 }
 
-pub fn apply_all_fix_code_action<'a>(
-    reports: impl Iterator<Item = &'a DiagnosticReport>,
-    uri: &Uri,
-) -> Option<CodeAction> {
-    let mut quick_fixes: Vec<TextEdit> = vec![];
+/// Whether a code action request with `only` kinds asks for [CODE_ACTION_KIND_SOURCE_FIX_ALL_OXC].
+///
+/// Kinds are hierarchical, editors configured with `source.fixAll` or `source` in
+/// `editor.codeActionsOnSave` request the parent kinds.
+pub fn is_source_fix_all_oxc(only: &[CodeActionKind]) -> bool {
+    let fix_all = CODE_ACTION_KIND_SOURCE_FIX_ALL_OXC.as_str();
+    only.iter().any(|kind| {
+        let kind = kind.as_str();
+        fix_all == kind || fix_all.strip_prefix(kind).is_some_and(|rest| rest.starts_with('.'))
+    })
+}
 
-    for report in reports {
-        let fix = match &report.fixed_content {
+/// The text edits fixing all `reports`, to be applied at once.
+///
+/// The text edits of a workspace edit must not overlap, so when two fixes overlap,
+/// only the first one is applied. The other one is still reported, and is fixed by the next fix all.
+pub fn fix_all_text_edits<'a>(
+    reports: impl Iterator<Item = &'a DiagnosticReport>,
+) -> Vec<TextEdit> {
+    let mut fixes = reports
+        .filter_map(|report| match &report.fixed_content {
             PossibleFixContent::None => None,
             PossibleFixContent::Single(fixed_content) => Some(fixed_content),
             // For multiple fixes, we take the first one as a representative fix.
             // Applying all possible fixes at once is not possible in this context.
             PossibleFixContent::Multiple(multi) => multi.first(),
-        };
-
-        if let Some(fixed_content) = &fix {
-            // when source.fixAll.oxc we collect all changes at ones
-            // and return them as one workspace edit.
-            // it is possible that one fix will change the range for the next fix
-            // see oxc-project/oxc#10422
-            quick_fixes.push(TextEdit {
-                range: fixed_content.range,
-                new_text: fixed_content.code.clone(),
-            });
+        })
+        .collect::<Vec<_>>();
+    fixes.sort_by_key(|fixed_content| (fixed_content.range.start, fixed_content.range.end));
+
+    let mut text_edits: Vec<TextEdit> = vec![];
+    for fixed_content in fixes {
+        if text_edits.last().is_some_and(|last| fixed_content.range.start < last.range.end) {
+            continue;
         }
+        text_edits
+            .push(TextEdit { range: fixed_content.range, new_text: fixed_content.code.clone() });
     }
+    text_edits
+}
+
+pub fn apply_all_fix_code_action<'a>(
+    reports: impl Iterator<Item = &'a DiagnosticReport>,
+    uri: &Uri,
+) -> Option<CodeAction> {
+    let quick_fixes = fix_all_text_edits(reports);
 
     if quick_fixes.is_empty() {
         return None;
@@ -187,3 +207,43 @@ pub fn ignore_this_rule_code_action(report: &DiagnosticReport, uri: &Uri) -> Cod
         command: None,
     }
 }
+
+#[cfg(test)]
+mod test {
+    use tower_lsp_server::lsp_types::{CodeActionKind, Diagnostic, Position, Range};
+
+    use super::{fix_all_text_edits, is_source_fix_all_oxc};
+    use crate::linter::error_with_position::{DiagnosticReport, FixedContent, PossibleFixContent};
+
+    fn report(start: u32, end: u32, code: &str) -> DiagnosticReport {
+        let range = Range::new(Position::new(0, start), Position::new(0, end));
+        DiagnosticReport {
+            diagnostic: Diagnostic { range, ..Diagnostic::default() },
+            fixed_content: PossibleFixContent::Single(FixedContent {
+                message: None,
+                code: code.to_string(),
+                range,
+            }),
+            rule_name: None,
+        }
+    }
+
+    #[test]
+    fn test_is_source_fix_all_oxc() {
+        for kind in ["source.fixAll.oxc", "source.fixAll", "source"] {
+            assert!(is_source_fix_all_oxc(&[CodeActionKind::from(kind)]), "{kind}");
+        }
+        for kind in ["quickfix", "source.fixAll.eslint", "source.fix", "source.organizeImports"] {
+            assert!(!is_source_fix_all_oxc(&[CodeActionKind::from(kind)]), "{kind}");
+        }
+    }
+
+    #[test]
+    fn test_fix_all_text_edits_skips_overlapping_fixes() {
+        let reports =
+            [report(10, 20, "b"), report(0, 5, "a"), report(15, 25, "c"), report(20, 20, "d")];
+        let text_edits = fix_all_text_edits(reports.iter());
+        let new_texts = text_edits.iter().map(|edit| edit.new_text.as_str()).collect::<Vec<_>>();
+        assert_eq!(new_texts, ["a", "b", "d"]);
+    }
+}
@@ -0,0 +1,320 @@
+//! The outline of a single file: its declarations, nested in the functions, classes,
+//! interfaces, enums and namespaces which contain them.
+
+use std::mem;
+
+use oxc_allocator::Allocator;
+use oxc_ast::ast::{
+    AccessorProperty, ArrowFunctionExpression, BindingPatternKind, Class, Expression, Function,
+    MethodDefinition, MethodDefinitionKind, PropertyDefinition, PropertyKey, TSEnumDeclaration,
+    TSEnumMember, TSEnumMemberName, TSInterfaceDeclaration, TSMethodSignature, TSModuleDeclaration,
+    TSModuleDeclarationName, TSPropertySignature, TSTypeAliasDeclaration, VariableDeclarationKind,
+    VariableDeclarator,
+};
+use oxc_ast_visit::{Visit, walk};
+use oxc_data_structures::rope::Rope;
+use oxc_parser::Parser;
+use oxc_span::{GetSpan, SourceType, Span};
+use oxc_syntax::scope::ScopeFlags;
+use tower_lsp_server::lsp_types::{DocumentSymbol, SymbolKind};
+
+use crate::symbols::span_to_range;
+
+/// The symbols declared in `source_text`, nested in their parents.
+///
+/// The contents of anonymous callbacks, e.g. `describe(() => {})`, are not listed.
+/// Returns no symbols when the file can not be parsed.
+pub fn document_symbols(source_text: &str, source_type: SourceType) -> Vec<DocumentSymbol> {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    if ret.panicked {
+        return vec![];
+    }
+    let mut collector =
+        DocumentSymbols { source_text, rope: Rope::from_str(source_text), symbols: vec![] };
+    collector.visit_program(&ret.program);
+    collector.symbols
+}
+
+struct DocumentSymbols<'s> {
+    source_text: &'s str,
+    rope: Rope,
+    /// Symbols of the declaration being visited.
+    symbols: Vec<DocumentSymbol>,
+}
+
+impl DocumentSymbols<'_> {
+    /// Add a symbol declared at `span`, with its name at `selection_span`.
+    fn push(
+        &mut self,
+        name: String,
+        kind: SymbolKind,
+        span: Span,
+        selection_span: Span,
+        children: Vec<DocumentSymbol>,
+    ) {
+        #[expect(deprecated)] // `deprecated` is deprecated in favor of `tags`
+        self.symbols.push(DocumentSymbol {
+            name,
+            detail: None,
+            kind,
+            tags: None,
+            deprecated: None,
+            range: span_to_range(&self.rope, self.source_text, span),
+            selection_range: span_to_range(&self.rope, self.source_text, selection_span),
+            children: (!children.is_empty()).then_some(children),
+        });
+    }
+
+    /// The symbols added by `f`.
+    fn nest(&mut self, f: impl FnOnce(&mut Self)) -> Vec<DocumentSymbol> {
+        let parent = mem::take(&mut self.symbols);
+        f(self);
+        mem::replace(&mut self.symbols, parent)
+    }
+
+    /// Visit the value of a variable or property, including the body of a function.
+    fn visit_initializer(&mut self, init: &Expression<'_>) {
+        match init {
+            Expression::ArrowFunctionExpression(arrow) => {
+                walk::walk_arrow_function_expression(self, arrow);
+            }
+            Expression::FunctionExpression(func) => {
+                walk::walk_function(self, func, ScopeFlags::Function);
+            }
+            Expression::ClassExpression(class) => walk::walk_class(self, class),
+            _ => self.visit_expression(init),
+        }
+    }
+
+    fn key_name(&self, key: &PropertyKey<'_>) -> String {
+        key.static_name()
+            .filter(|name| !name.is_empty())
+            .map_or_else(|| key.span().source_text(self.source_text).to_string(), Into::into)
+    }
+}
+
+/// The kind of a variable or property with the value `init`.
+fn initializer_kind(init: Option<&Expression<'_>>, default: SymbolKind) -> SymbolKind {
+    match init {
+        Some(Expression::ArrowFunctionExpression(_) | Expression::FunctionExpression(_)) => {
+            SymbolKind::FUNCTION
+        }
+        Some(Expression::ClassExpression(_)) => SymbolKind::CLASS,
+        _ => default,
+    }
+}
+
+impl<'a> Visit<'a> for DocumentSymbols<'_> {
+    fn visit_variable_declarator(&mut self, it: &VariableDeclarator<'a>) {
+        let default_kind = if it.kind == VariableDeclarationKind::Const {
+            SymbolKind::CONSTANT
+        } else {
+            SymbolKind::VARIABLE
+        };
+        if let BindingPatternKind::BindingIdentifier(ident) = &it.id.kind {
+            let children = self.nest(|v| {
+                if let Some(init) = &it.init {
+                    v.visit_initializer(init);
+                }
+            });
+            let kind = initializer_kind(it.init.as_ref(), default_kind);
+            self.push(ident.name.to_string(), kind, it.span, ident.span, children);
+        } else {
+            // Destructuring, e.g. `const { a, b } = c`
+            for ident in it.id.get_binding_identifiers() {
+                self.push(ident.name.to_string(), default_kind, it.span, ident.span, vec![]);
+            }
+            if let Some(init) = &it.init {
+                self.visit_expression(init);
+            }
+        }
+    }
+
+    fn visit_function(&mut self, it: &Function<'a>, flags: ScopeFlags) {
+        // Function expressions which are not the value of a variable or property.
+        if !it.is_declaration() {
+            return;
+        }
+        let children = self.nest(|v| walk::walk_function(v, it, flags));
+        let (name, selection_span) = match &it.id {
+            Some(id) => (id.name.to_string(), id.span),
+            None => ("default".to_string(), it.span),
+        };
+        self.push(name, SymbolKind::FUNCTION, it.span, selection_span, children);
+    }
+
+    fn visit_arrow_function_expression(&mut self, _it: &ArrowFunctionExpression<'a>) {
+        // Arrow functions which are not the value of a variable or property.
+    }
+
+    fn visit_class(&mut self, it: &Class<'a>) {
+        let children = self.nest(|v| walk::walk_class(v, it));
+        let (name, selection_span) = match &it.id {
+            Some(id) => (id.name.to_string(), id.span),
+            None if it.is_declaration() => ("default".to_string(), it.span),
+            None => ("<class>".to_string(), it.span),
+        };
+        self.push(name, SymbolKind::CLASS, it.span, selection_span, children);
+    }
+
+    fn visit_method_definition(&mut self, it: &MethodDefinition<'a>) {
+        let children = self.nest(|v| walk::walk_function(v, &it.value, ScopeFlags::Function));
+        let kind = match it.kind {
+            MethodDefinitionKind::Constructor => SymbolKind::CONSTRUCTOR,
+            MethodDefinitionKind::Method => SymbolKind::METHOD,
+            MethodDefinitionKind::Get | MethodDefinitionKind::Set => SymbolKind::PROPERTY,
+        };
+        self.push(self.key_name(&it.key), kind, it.span, it.key.span(), children);
+    }
+
+    fn visit_property_definition(&mut self, it: &PropertyDefinition<'a>) {
+        let children = self.nest(|v| {
+            if let Some(value) = &it.value {
+                v.visit_initializer(value);
+            }
+        });
+        let kind = match initializer_kind(it.value.as_ref(), SymbolKind::PROPERTY) {
+            SymbolKind::FUNCTION => SymbolKind::METHOD,
+            kind => kind,
+        };
+        self.push(self.key_name(&it.key), kind, it.span, it.key.span(), children);
+    }
+
+    fn visit_accessor_property(&mut self, it: &AccessorProperty<'a>) {
+        self.push(self.key_name(&it.key), SymbolKind::PROPERTY, it.span, it.key.span(), vec![]);
+    }
+
+    fn visit_ts_interface_declaration(&mut self, it: &TSInterfaceDeclaration<'a>) {
+        let children = self.nest(|v| walk::walk_ts_interface_declaration(v, it));
+        self.push(it.id.name.to_string(), SymbolKind::INTERFACE, it.span, it.id.span, children);
+    }
+
+    fn visit_ts_property_signature(&mut self, it: &TSPropertySignature<'a>) {
+        // Properties of object types, e.g. `{ a: { b: string } }`, are nested.
+        let children = self.nest(|v| walk::walk_ts_property_signature(v, it));
+        let name = self.key_name(&it.key);
+        self.push(name, SymbolKind::PROPERTY, it.span, it.key.span(), children);
+    }
+
+    fn visit_ts_method_signature(&mut self, it: &TSMethodSignature<'a>) {
+        self.push(self.key_name(&it.key), SymbolKind::METHOD, it.span, it.key.span(), vec![]);
+    }
+
+    fn visit_ts_type_alias_declaration(&mut self, it: &TSTypeAliasDeclaration<'a>) {
+        let children = self.nest(|v| walk::walk_ts_type_alias_declaration(v, it));
+        // There is no symbol kind for type aliases.
+        let kind = SymbolKind::TYPE_PARAMETER;
+        self.push(it.id.name.to_string(), kind, it.span, it.id.span, children);
+    }
+
+    fn visit_ts_enum_declaration(&mut self, it: &TSEnumDeclaration<'a>) {
+        let children = self.nest(|v| walk::walk_ts_enum_declaration(v, it));
+        self.push(it.id.name.to_string(), SymbolKind::ENUM, it.span, it.id.span, children);
+    }
+
+    fn visit_ts_enum_member(&mut self, it: &TSEnumMember<'a>) {
+        let name = match &it.id {
+            TSEnumMemberName::Identifier(_) | TSEnumMemberName::String(_) => {
+                it.id.static_name().to_string()
+            }
+            _ => it.id.span().source_text(self.source_text).to_string(),
+        };
+        self.push(name, SymbolKind::ENUM_MEMBER, it.span, it.id.span(), vec![]);
+    }
+
+    fn visit_ts_module_declaration(&mut self, it: &TSModuleDeclaration<'a>) {
+        let children = self.nest(|v| walk::walk_ts_module_declaration(v, it));
+        let kind = match &it.id {
+            TSModuleDeclarationName::Identifier(_) => SymbolKind::NAMESPACE,
+            TSModuleDeclarationName::StringLiteral(_) => SymbolKind::MODULE,
+        };
+        let name = it.id.span().source_text(self.source_text).to_string();
+        self.push(name, kind, it.span, it.id.span(), children);
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::fmt::Write;
+
+    use oxc_span::SourceType;
+    use tower_lsp_server::lsp_types::{DocumentSymbol, SymbolKind};
+
+    use super::document_symbols;
+
+    /// The outline of `source_text`, one symbol per line indented by its depth.
+    fn outline(source_text: &str) -> String {
+        fn write(symbols: &[DocumentSymbol], depth: usize, out: &mut String) {
+            for symbol in symbols {
+                writeln!(out, "{}{} {:?}", "  ".repeat(depth), symbol.name, symbol.kind).unwrap();
+                assert!(symbol.range.start <= symbol.selection_range.start);
+                assert!(symbol.selection_range.end <= symbol.range.end);
+                write(symbol.children.as_deref().unwrap_or_default(), depth + 1, out);
+            }
+        }
+        let mut out = String::new();
+        write(&document_symbols(source_text, SourceType::tsx()), 0, &mut out);
+        out
+    }
+
+    #[test]
+    fn test_document_symbols() {
+        let source_text = "
+import { a } from 'a';
+const { b, c: [d] } = a;
+let e;
+export default function () {
+    const f = () => {
+        function g() {}
+    };
+}
+class H {
+    #i = 1;
+    j = () => { let k; };
+    constructor() {}
+    get l() { return 1; }
+    ['m']() {}
+}
+describe('n', () => { const o = 1; });
+interface P { q: { r: string }; s(): void }
+type T = { u: number };
+enum V { W, 'X' }
+namespace Y.Z { export const aa = 1; }
+declare module 'bb' {}
+";
+        let expected = "\
+b Constant
+d Constant
+e Variable
+default Function
+  f Function
+    g Function
+H Class
+  #i Property
+  j Method
+    k Variable
+  constructor Constructor
+  l Property
+  m Method
+P Interface
+  q Property
+    r Property
+  s Method
+T TypeParameter
+  u Property
+V Enum
+  W EnumMember
+  X EnumMember
+Y Namespace
+  Z Namespace
+    aa Constant
+'bb' Module
+";
+        assert_eq!(outline(source_text), expected);
+        assert_eq!(
+            SymbolKind::FUNCTION,
+            document_symbols("function a() {}", SourceType::mjs())[0].kind
+        );
+    }
+}
@@ -90,6 +90,35 @@ fn message_with_position_to_lsp_diagnostic(
             ret_range
         },
     );
+    // Labels in other files are added after computing the range, which is in this file.
+    let related_information = match &message.related {
+        Some(related) if !related.is_empty() => {
+            let mut infos = related_information.unwrap_or_default();
+            infos.extend(related.iter().filter_map(|related| {
+                Some(lsp_types::DiagnosticRelatedInformation {
+                    location: lsp_types::Location {
+                        uri: Uri::from_file_path(&related.path)?,
+                        range: lsp_types::Range {
+                            start: lsp_types::Position {
+                                line: related.label.start().line,
+                                character: related.label.start().character,
+                            },
+                            end: lsp_types::Position {
+                                line: related.label.end().line,
+                                character: related.label.end().character,
+                            },
+                        },
+                    },
+                    message: related
+                        .label
+                        .message()
+                        .map_or_else(String::new, |message| message.clone().into_owned()),
+                })
+            }));
+            Some(infos)
+        }
+        _ => related_information,
+    };
     let code = message.code.to_string();
     let code_description =
         message.url.as_ref().map(|url| CodeDescription { href: Uri::from_str(url).ok().unwrap() });
@@ -13,7 +13,7 @@ use tower_lsp_server::{
 use oxc_allocator::{Allocator, AllocatorPool};
 use oxc_linter::{
     LINTABLE_EXTENSIONS, LintService, LintServiceOptions, Linter, MessageWithPosition,
-    loader::Loader, read_to_arena_str,
+    ResolutionCache, loader::Loader, read_to_arena_str,
 };
 use oxc_linter::{RuntimeFileSystem, read_to_string};
 
@@ -31,6 +31,8 @@ pub struct IsolatedLintHandlerOptions {
 pub struct IsolatedLintHandler {
     linter: Linter,
     options: IsolatedLintHandlerOptions,
+    /// Shared by the lint runs of all files and all workspace folders, until files on disk change
+    resolution_cache: ResolutionCache,
 }
 
 pub struct IsolatedLintHandlerFileSystem {
@@ -64,8 +66,16 @@ impl RuntimeFileSystem for IsolatedLintHandlerFileSystem {
 }
 
 impl IsolatedLintHandler {
-    pub fn new(linter: Linter, options: IsolatedLintHandlerOptions) -> Self {
-        Self { linter, options }
+    pub fn new(
+        linter: Linter,
+        options: IsolatedLintHandlerOptions,
+        resolution_cache: ResolutionCache,
+    ) -> Self {
+        Self { linter, options, resolution_cache }
+    }
+
+    pub fn clear_resolution_cache(&self) {
+        self.resolution_cache.clear();
     }
 
     pub fn run_single(&self, uri: &Uri, content: Option<String>) -> Option<Vec<DiagnosticReport>> {
@@ -143,7 +153,8 @@ impl IsolatedLintHandler {
             self.options.root_path.clone(),
             vec![Arc::from(path.as_os_str())],
         )
-        .with_cross_module(self.options.use_cross_module);
+        .with_cross_module(self.options.use_cross_module)
+        .with_resolution_cache(self.resolution_cache.clone());
 
         let mut lint_service =
             LintService::new(&self.linter, AllocatorPool::default(), lint_service_options)
@@ -156,7 +167,7 @@ impl IsolatedLintHandler {
         Some(result)
     }
 
-    fn should_lint_path(path: &Path) -> bool {
+    pub fn should_lint_path(path: &Path) -> bool {
         static WANTED_EXTENSIONS: OnceLock<FxHashSet<&'static str>> = OnceLock::new();
         let wanted_exts =
             WANTED_EXTENSIONS.get_or_init(|| LINTABLE_EXTENSIONS.iter().copied().collect());
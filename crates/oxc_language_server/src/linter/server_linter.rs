@@ -9,6 +9,7 @@ use tower_lsp_server::lsp_types::Uri;
 
 use oxc_linter::{
     AllowWarnDeny, Config, ConfigStore, ConfigStoreBuilder, LintOptions, Linter, Oxlintrc,
+    ResolutionCache,
 };
 use tower_lsp_server::UriExt;
 
@@ -28,7 +29,7 @@ pub struct ServerLinter {
 }
 
 impl ServerLinter {
-    pub fn new(root_uri: &Uri, options: &Options) -> Self {
+    pub fn new(root_uri: &Uri, options: &Options, resolution_cache: ResolutionCache) -> Self {
         let root_path = root_uri.to_file_path().unwrap();
         let (nested_configs, mut extended_paths) = Self::create_nested_configs(&root_path, options);
         let relative_config_path = options.config_path.clone();
@@ -96,6 +97,7 @@ impl ServerLinter {
         let isolated_linter = IsolatedLintHandler::new(
             linter,
             IsolatedLintHandlerOptions { use_cross_module, root_path: root_path.to_path_buf() },
+            resolution_cache,
         );
 
         Self {
@@ -213,6 +215,11 @@ impl ServerLinter {
 
         self.isolated_linter.run_single(uri, content)
     }
+
+    /// Forget the resolved `package.json` and `tsconfig.json` files, after files were saved.
+    pub fn clear_resolution_cache(&self) {
+        self.isolated_linter.clear_resolution_cache();
+    }
 }
 
 /// Normalize a path by removing `.` and resolving `..` components,
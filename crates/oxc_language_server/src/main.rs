@@ -1,33 +1,54 @@
 use futures::future::join_all;
 use log::{debug, info, warn};
 use options::{Options, Run, WorkspaceOption};
-use rustc_hash::FxBuildHasher;
+use rustc_hash::{FxBuildHasher, FxHashMap};
 use serde_json::json;
-use std::{str::FromStr, sync::Arc};
+use std::{
+    str::FromStr,
+    sync::{
+        Arc,
+        atomic::{AtomicU32, Ordering},
+    },
+};
 use tokio::sync::{OnceCell, RwLock, SetError};
 use tower_lsp_server::{
-    Client, LanguageServer, LspService, Server,
+    Client, LanguageServer, LspService, Server, UriExt,
     jsonrpc::{Error, ErrorCode, Result},
     lsp_types::{
         CodeActionParams, CodeActionResponse, ConfigurationItem, Diagnostic,
-        DidChangeConfigurationParams, DidChangeTextDocumentParams, DidChangeWatchedFilesParams,
+        DiagnosticServerCancellationData, DidChangeConfigurationParams,
+        DidChangeTextDocumentParams, DidChangeWatchedFilesParams,
         DidChangeWatchedFilesRegistrationOptions, DidChangeWorkspaceFoldersParams,
         DidCloseTextDocumentParams, DidOpenTextDocumentParams, DidSaveTextDocumentParams,
-        ExecuteCommandParams, InitializeParams, InitializeResult, InitializedParams, Registration,
-        ServerInfo, Unregistration, Uri, WorkspaceEdit,
+        DocumentDiagnosticParams, DocumentDiagnosticReport, DocumentDiagnosticReportResult,
+        DocumentHighlight, DocumentHighlightParams, DocumentSymbolParams, DocumentSymbolResponse,
+        ExecuteCommandParams, InitializeParams, InitializeResult, InitializedParams, Location,
+        PrepareRenameResponse, ReferenceParams, Registration, RelatedFullDocumentDiagnosticReport,
+        RenameParams, SemanticToken, SemanticTokens, SemanticTokensDelta,
+        SemanticTokensDeltaParams, SemanticTokensFullDeltaResult, SemanticTokensParams,
+        SemanticTokensResult, ServerInfo, TextDocumentPositionParams, Unregistration, Uri,
+        WorkspaceDiagnosticParams, WorkspaceDiagnosticReport, WorkspaceDiagnosticReportResult,
+        WorkspaceEdit, error_codes,
     },
 };
+
+use oxc_linter::ResolutionCache;
+use oxc_span::SourceType;
+
 // #
 use capabilities::Capabilities;
-use code_actions::CODE_ACTION_KIND_SOURCE_FIX_ALL_OXC;
+use code_actions::is_source_fix_all_oxc;
 use commands::{FIX_ALL_COMMAND_ID, FixAllCommandArgs};
-use worker::WorkspaceWorker;
+use worker::{WorkspaceWorker, find_responsible_worker, workspace_document_report};
 
 mod capabilities;
 mod code_actions;
 mod commands;
+mod document_symbols;
 mod linter;
 mod options;
+mod semantic_tokens;
+mod symbols;
 #[cfg(test)]
 mod tester;
 mod worker;
@@ -46,7 +67,17 @@ struct Backend {
     // 1. `initialize` request with workspace folders
     // 2. `workspace/didChangeWorkspaceFolders` request
     workspace_workers: Arc<RwLock<Vec<WorkspaceWorker>>>,
+    // Cache of the import resolver, shared by the workers. Imports are still resolved with the
+    // `tsconfig.json` of each workspace folder, the cache only saves reading the same files again.
+    resolution_cache: ResolutionCache,
     capabilities: OnceCell<Capabilities>,
+    // Text of the open documents, by uri, for finding references and renaming symbols.
+    documents: ConcurrentHashMap<String, String>,
+    // Result id and data of the last semantic tokens sent for each uri, for computing deltas.
+    semantic_tokens: ConcurrentHashMap<String, (String, Vec<SemanticToken>)>,
+    semantic_tokens_result_id: AtomicU32,
+    // Incremented when the configuration or files change, to cancel `workspace/diagnostic`.
+    workspace_diagnostics_generation: AtomicU32,
 }
 
 impl LanguageServer for Backend {
@@ -86,11 +117,11 @@ impl LanguageServer for Backend {
         let workers = if let Some(workspace_folders) = &params.workspace_folders {
             workspace_folders
                 .iter()
-                .map(|workspace_folder| WorkspaceWorker::new(workspace_folder.uri.clone()))
+                .map(|workspace_folder| self.new_worker(workspace_folder.uri.clone()))
                 .collect()
         // client sent deprecated root uri
         } else if let Some(root_uri) = params.root_uri {
-            vec![WorkspaceWorker::new(root_uri)]
+            vec![self.new_worker(root_uri)]
         // client is in single file mode, create no workers
         } else {
             vec![]
@@ -109,7 +140,7 @@ impl LanguageServer for Backend {
                             .unwrap_or_default()
                             .iter()
                             .find(|workspace_option| {
-                                worker.is_responsible_for_uri(&workspace_option.workspace_uri)
+                                worker.is_root_uri(&workspace_option.workspace_uri)
                             })
                             .map(|workspace_options| workspace_options.options.clone())
                             .unwrap_or_default(),
@@ -203,6 +234,7 @@ impl LanguageServer for Backend {
     }
 
     async fn did_change_configuration(&self, params: DidChangeConfigurationParams) {
+        self.cancel_workspace_diagnostics();
         let workers = self.workspace_workers.read().await;
         let new_diagnostics: papaya::HashMap<String, Vec<Diagnostic>, FxBuildHasher> =
             ConcurrentHashMap::default();
@@ -264,7 +296,7 @@ impl LanguageServer for Backend {
 
         for option in resolved_options {
             let Some(worker) =
-                workers.iter().find(|worker| worker.is_responsible_for_uri(&option.workspace_uri))
+                workers.iter().find(|worker| worker.is_root_uri(&option.workspace_uri))
             else {
                 continue;
             };
@@ -319,9 +351,12 @@ impl LanguageServer for Backend {
                 }
             }
         }
+
+        self.refresh_workspace_diagnostics().await;
     }
 
     async fn did_change_watched_files(&self, params: DidChangeWatchedFilesParams) {
+        self.cancel_workspace_diagnostics();
         let workers = self.workspace_workers.read().await;
         // ToDo: what if an empty changes flag is passed?
         debug!("watched file did change");
@@ -331,9 +366,7 @@ impl LanguageServer for Backend {
             // We do not expect multiple changes from the same workspace folder.
             // If we should consider it, we need to map the events to the workers first,
             // to only restart the internal linter / diagnostics for once
-            let Some(worker) =
-                workers.iter().find(|worker| worker.is_responsible_for_uri(&file_event.uri))
-            else {
+            let Some(worker) = find_responsible_worker(&workers, &file_event.uri) else {
                 continue;
             };
             let Some(diagnostics) = worker.did_change_watched_files(file_event).await else {
@@ -347,6 +380,8 @@ impl LanguageServer for Backend {
             }
         }
 
+        self.refresh_workspace_diagnostics().await;
+
         if all_diagnostics.is_empty() {
             return;
         }
@@ -361,16 +396,16 @@ impl LanguageServer for Backend {
     }
 
     async fn did_change_workspace_folders(&self, params: DidChangeWorkspaceFoldersParams) {
+        // release the running `workspace/diagnostic` request's lock on the workers
+        self.cancel_workspace_diagnostics();
         let mut workers = self.workspace_workers.write().await;
         let mut cleared_diagnostics = vec![];
         let mut added_registrations = vec![];
         let mut removed_registrations = vec![];
 
         for folder in params.event.removed {
-            let Some((index, worker)) = workers
-                .iter()
-                .enumerate()
-                .find(|(_, worker)| worker.is_responsible_for_uri(&folder.uri))
+            let Some((index, worker)) =
+                workers.iter().enumerate().find(|(_, worker)| worker.is_root_uri(&folder.uri))
             else {
                 continue;
             };
@@ -394,7 +429,7 @@ impl LanguageServer for Backend {
                 .await;
 
             for (index, folder) in params.event.added.iter().enumerate() {
-                let worker = WorkspaceWorker::new(folder.uri.clone());
+                let worker = self.new_worker(folder.uri.clone());
                 // get the configuration from the response and init the linter
                 let options = configurations.get(index).unwrap_or(&None);
                 worker.init_linter(options.as_ref().unwrap_or(&Options::default())).await;
@@ -410,7 +445,7 @@ impl LanguageServer for Backend {
         // client does not support the request
         } else {
             for folder in params.event.added {
-                let worker = WorkspaceWorker::new(folder.uri);
+                let worker = self.new_worker(folder.uri);
                 // use default options
                 worker.init_linter(&Options::default()).await;
                 workers.push(worker);
@@ -431,15 +466,18 @@ impl LanguageServer for Backend {
                 }
             }
         }
+
+        self.refresh_workspace_diagnostics().await;
     }
 
     async fn did_save(&self, params: DidSaveTextDocumentParams) {
         debug!("oxc server did save");
         let uri = &params.text_document.uri;
         let workers = self.workspace_workers.read().await;
-        let Some(worker) = workers.iter().find(|worker| worker.is_responsible_for_uri(uri)) else {
+        let Some(worker) = find_responsible_worker(&workers, uri) else {
             return;
         };
+        worker.did_save().await;
         if !worker.should_lint_on_run_type(Run::OnSave).await {
             return;
         }
@@ -458,8 +496,11 @@ impl LanguageServer for Backend {
     /// get the file context from the language client
     async fn did_change(&self, params: DidChangeTextDocumentParams) {
         let uri = &params.text_document.uri;
+        if let Some(change) = params.content_changes.first() {
+            self.documents.pin().insert(uri.to_string(), change.text.clone());
+        }
         let workers = self.workspace_workers.read().await;
-        let Some(worker) = workers.iter().find(|worker| worker.is_responsible_for_uri(uri)) else {
+        let Some(worker) = find_responsible_worker(&workers, uri) else {
             return;
         };
         if !worker.should_lint_on_run_type(Run::OnType).await {
@@ -479,8 +520,9 @@ impl LanguageServer for Backend {
 
     async fn did_open(&self, params: DidOpenTextDocumentParams) {
         let uri = &params.text_document.uri;
+        self.documents.pin().insert(uri.to_string(), params.text_document.text.clone());
         let workers = self.workspace_workers.read().await;
-        let Some(worker) = workers.iter().find(|worker| worker.is_responsible_for_uri(uri)) else {
+        let Some(worker) = find_responsible_worker(&workers, uri) else {
             return;
         };
 
@@ -498,8 +540,10 @@ impl LanguageServer for Backend {
 
     async fn did_close(&self, params: DidCloseTextDocumentParams) {
         let uri = &params.text_document.uri;
+        self.documents.pin().remove(uri.as_str());
+        self.semantic_tokens.pin().remove(uri.as_str());
         let workers = self.workspace_workers.read().await;
-        let Some(worker) = workers.iter().find(|worker| worker.is_responsible_for_uri(uri)) else {
+        let Some(worker) = find_responsible_worker(&workers, uri) else {
             return;
         };
         worker.remove_diagnostics(&params.text_document.uri);
@@ -508,14 +552,12 @@ impl LanguageServer for Backend {
     async fn code_action(&self, params: CodeActionParams) -> Result<Option<CodeActionResponse>> {
         let uri = &params.text_document.uri;
         let workers = self.workspace_workers.read().await;
-        let Some(worker) = workers.iter().find(|worker| worker.is_responsible_for_uri(uri)) else {
+        let Some(worker) = find_responsible_worker(&workers, uri) else {
             return Ok(None);
         };
 
-        let is_source_fix_all_oxc = params
-            .context
-            .only
-            .is_some_and(|only| only.contains(&CODE_ACTION_KIND_SOURCE_FIX_ALL_OXC));
+        let is_source_fix_all_oxc =
+            params.context.only.is_some_and(|only| is_source_fix_all_oxc(&only));
 
         let code_actions =
             worker.get_code_actions_or_commands(uri, &params.range, is_source_fix_all_oxc).await;
@@ -527,6 +569,216 @@ impl LanguageServer for Backend {
         Ok(Some(code_actions))
     }
 
+    async fn references(&self, params: ReferenceParams) -> Result<Option<Vec<Location>>> {
+        let TextDocumentPositionParams { text_document, position } = params.text_document_position;
+        let Some((source_text, source_type)) = self.get_source(&text_document.uri) else {
+            return Ok(None);
+        };
+        let ranges = symbols::references(
+            &source_text,
+            source_type,
+            position,
+            params.context.include_declaration,
+        );
+        if ranges.is_empty() {
+            return Ok(None);
+        }
+        let locations = ranges
+            .into_iter()
+            .map(|range| Location { uri: text_document.uri.clone(), range })
+            .collect();
+        Ok(Some(locations))
+    }
+
+    async fn document_highlight(
+        &self,
+        params: DocumentHighlightParams,
+    ) -> Result<Option<Vec<DocumentHighlight>>> {
+        let TextDocumentPositionParams { text_document, position } =
+            params.text_document_position_params;
+        let Some((source_text, source_type)) = self.get_source(&text_document.uri) else {
+            return Ok(None);
+        };
+        let highlights = symbols::document_highlights(&source_text, source_type, position);
+        if highlights.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(highlights))
+    }
+
+    async fn prepare_rename(
+        &self,
+        params: TextDocumentPositionParams,
+    ) -> Result<Option<PrepareRenameResponse>> {
+        let Some((source_text, source_type)) = self.get_source(&params.text_document.uri) else {
+            return Ok(None);
+        };
+        let response = symbols::prepare_rename(&source_text, source_type, params.position).map(
+            |(range, placeholder)| PrepareRenameResponse::RangeWithPlaceholder {
+                range,
+                placeholder,
+            },
+        );
+        Ok(response)
+    }
+
+    async fn rename(&self, params: RenameParams) -> Result<Option<WorkspaceEdit>> {
+        let TextDocumentPositionParams { text_document, position } = params.text_document_position;
+        let Some((source_text, source_type)) = self.get_source(&text_document.uri) else {
+            return Ok(None);
+        };
+        let text_edits = symbols::rename(&source_text, source_type, position, &params.new_name)
+            .map_err(Error::invalid_params)?;
+        Ok(text_edits.map(|text_edits| WorkspaceEdit {
+            #[expect(clippy::disallowed_types)]
+            changes: Some(std::collections::HashMap::from([(text_document.uri, text_edits)])),
+            ..WorkspaceEdit::default()
+        }))
+    }
+
+    async fn document_symbol(
+        &self,
+        params: DocumentSymbolParams,
+    ) -> Result<Option<DocumentSymbolResponse>> {
+        let Some((source_text, source_type)) = self.get_source(&params.text_document.uri) else {
+            return Ok(None);
+        };
+        let symbols = document_symbols::document_symbols(&source_text, source_type);
+        Ok(Some(DocumentSymbolResponse::Nested(symbols)))
+    }
+
+    async fn semantic_tokens_full(
+        &self,
+        params: SemanticTokensParams,
+    ) -> Result<Option<SemanticTokensResult>> {
+        let Some((result_id, data)) = self.semantic_tokens(&params.text_document.uri) else {
+            return Ok(None);
+        };
+        let tokens = SemanticTokens { result_id: Some(result_id), data };
+        Ok(Some(SemanticTokensResult::Tokens(tokens)))
+    }
+
+    async fn semantic_tokens_full_delta(
+        &self,
+        params: SemanticTokensDeltaParams,
+    ) -> Result<Option<SemanticTokensFullDeltaResult>> {
+        let uri = &params.text_document.uri;
+        let previous = self.semantic_tokens.pin().get(uri.as_str()).cloned();
+        let Some((result_id, data)) = self.semantic_tokens(uri) else {
+            return Ok(None);
+        };
+        // Send all the tokens when the client does not have the previous ones.
+        let result = match previous {
+            Some((previous_result_id, previous_data))
+                if previous_result_id == params.previous_result_id =>
+            {
+                SemanticTokensFullDeltaResult::TokensDelta(SemanticTokensDelta {
+                    result_id: Some(result_id),
+                    edits: semantic_tokens::semantic_tokens_edits(&previous_data, &data),
+                })
+            }
+            _ => SemanticTokensFullDeltaResult::Tokens(SemanticTokens {
+                result_id: Some(result_id),
+                data,
+            }),
+        };
+        Ok(Some(result))
+    }
+
+    async fn diagnostic(
+        &self,
+        _params: DocumentDiagnosticParams,
+    ) -> Result<DocumentDiagnosticReportResult> {
+        // The diagnostics of open documents are pushed with `textDocument/publishDiagnostics`,
+        // pulling them too would report them twice.
+        Ok(DocumentDiagnosticReportResult::Report(DocumentDiagnosticReport::Full(
+            RelatedFullDocumentDiagnosticReport::default(),
+        )))
+    }
+
+    async fn workspace_diagnostic(
+        &self,
+        params: WorkspaceDiagnosticParams,
+    ) -> Result<WorkspaceDiagnosticReportResult> {
+        let generation = self.workspace_diagnostics_generation.load(Ordering::Relaxed);
+        let previous_result_ids = params
+            .previous_result_ids
+            .into_iter()
+            .map(|previous| (previous.uri.to_string(), previous.value))
+            .collect::<FxHashMap<_, _>>();
+
+        let workers = self.workspace_workers.read().await;
+        // Open documents are reported by `textDocument/publishDiagnostics`.
+        // The files of nested workspace folders are reported by the worker of the nested folder.
+        let uris = workers
+            .iter()
+            .flat_map(|worker| worker.lintable_uris().into_iter().map(move |uri| (worker, uri)))
+            .filter(|(worker, uri)| {
+                find_responsible_worker(&workers, uri).is_some_and(|w| std::ptr::eq(w, *worker))
+            })
+            .filter(|(_, uri)| !self.documents.pin().contains_key(uri.as_str()))
+            .collect::<Vec<_>>();
+
+        let progress = match params.work_done_progress_params.work_done_token {
+            Some(token) => Some(
+                self.client
+                    .progress(token, "Linting the workspace")
+                    .with_percentage(0)
+                    .begin()
+                    .await,
+            ),
+            None => None,
+        };
+
+        let mut items = vec![];
+        let mut percentage = 0;
+        for (index, (worker, uri)) in uris.iter().enumerate() {
+            // The configuration or files changed, the client requests the diagnostics again.
+            if self.workspace_diagnostics_generation.load(Ordering::Relaxed) != generation {
+                if let Some(progress) = progress {
+                    progress.finish().await;
+                }
+                return Err(Error {
+                    code: ErrorCode::ServerError(error_codes::SERVER_CANCELLED),
+                    message: "the configuration or files changed".into(),
+                    data: serde_json::to_value(DiagnosticServerCancellationData {
+                        retrigger_request: true,
+                    })
+                    .ok(),
+                });
+            }
+
+            if let Some(progress) = &progress {
+                let new_percentage = u32::try_from(index * 100 / uris.len()).unwrap_or(100);
+                if new_percentage != percentage {
+                    percentage = new_percentage;
+                    progress
+                        .report_with_message(format!("{index}/{} files", uris.len()), percentage)
+                        .await;
+                }
+            }
+
+            let diagnostics = worker
+                .lint_workspace_file(uri)
+                .await
+                .unwrap_or_default()
+                .into_iter()
+                .map(|report| report.diagnostic)
+                .collect();
+            let previous_result_id = previous_result_ids.get(uri.as_str()).map(String::as_str);
+            items.push(workspace_document_report(uri.clone(), diagnostics, previous_result_id));
+
+            // Lint in the background, let the requests and notifications of the client through.
+            tokio::task::yield_now().await;
+        }
+
+        if let Some(progress) = progress {
+            progress.finish().await;
+        }
+
+        Ok(WorkspaceDiagnosticReportResult::Report(WorkspaceDiagnosticReport { items }))
+    }
+
     async fn execute_command(
         &self,
         params: ExecuteCommandParams,
@@ -541,8 +793,7 @@ impl LanguageServer for Backend {
 
             let uri = &Uri::from_str(&args.uri).unwrap();
             let workers = self.workspace_workers.read().await;
-            let Some(worker) = workers.iter().find(|worker| worker.is_responsible_for_uri(uri))
-            else {
+            let Some(worker) = find_responsible_worker(&workers, uri) else {
                 return Ok(None);
             };
 
@@ -565,6 +816,51 @@ impl LanguageServer for Backend {
 }
 
 impl Backend {
+    fn new_worker(&self, root_uri: Uri) -> WorkspaceWorker {
+        WorkspaceWorker::new(root_uri).with_resolution_cache(self.resolution_cache.clone())
+    }
+
+    /// The text of the document at `uri`, from the client when it is open or from the disk,
+    /// and its source type.
+    /// Files which are not JavaScript or TypeScript, e.g. Vue files, are not supported.
+    fn get_source(&self, uri: &Uri) -> Option<(String, SourceType)> {
+        let path = uri.to_file_path()?;
+        let source_type = SourceType::from_path(&path).ok()?;
+        let source_text = match self.documents.pin().get(uri.as_str()) {
+            Some(source_text) => source_text.clone(),
+            None => std::fs::read_to_string(&path).ok()?,
+        };
+        Some((source_text, source_type))
+    }
+
+    /// Compute the semantic tokens of the document at `uri`, and save them with a new result id.
+    fn semantic_tokens(&self, uri: &Uri) -> Option<(String, Vec<SemanticToken>)> {
+        let (source_text, source_type) = self.get_source(uri)?;
+        let data = semantic_tokens::semantic_tokens(&source_text, source_type);
+        let result_id = self.semantic_tokens_result_id.fetch_add(1, Ordering::Relaxed).to_string();
+        self.semantic_tokens.pin().insert(uri.to_string(), (result_id.clone(), data.clone()));
+        Some((result_id, data))
+    }
+
+    /// Cancel the running `workspace/diagnostic` request, its diagnostics are outdated.
+    fn cancel_workspace_diagnostics(&self) {
+        self.workspace_diagnostics_generation.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Ask the client to pull the diagnostics of the workspace again.
+    async fn refresh_workspace_diagnostics(&self) {
+        if !self
+            .capabilities
+            .get()
+            .is_some_and(|capabilities| capabilities.workspace_diagnostic_refresh)
+        {
+            return;
+        }
+        if let Err(err) = self.client.workspace_diagnostic_refresh().await {
+            warn!("sending workspace/diagnostic/refresh failed: {err}");
+        }
+    }
+
     /// Request the workspace configuration from the client
     /// and return the options for each workspace folder.
     /// The check if the client support workspace configuration, should be done before.
@@ -624,7 +920,12 @@ async fn main() {
     let (service, socket) = LspService::build(|client| Backend {
         client,
         workspace_workers: Arc::new(RwLock::new(vec![])),
+        resolution_cache: ResolutionCache::default(),
         capabilities: OnceCell::new(),
+        documents: ConcurrentHashMap::default(),
+        semantic_tokens: ConcurrentHashMap::default(),
+        semantic_tokens_result_id: AtomicU32::new(0),
+        workspace_diagnostics_generation: AtomicU32::new(0),
     })
     .finish();
 
@@ -0,0 +1,360 @@
+//! Semantic tokens of the identifiers of a single file, classified with the symbols of
+//! `oxc_semantic`, so that editors can highlight parameters, types and JSX components.
+
+use oxc_allocator::Allocator;
+use oxc_ast::{
+    AstKind,
+    ast::{Expression, PropertyKey},
+};
+use oxc_data_structures::rope::{Rope, get_line_column};
+use oxc_parser::Parser;
+use oxc_semantic::{Semantic, SemanticBuilder, SymbolId};
+use oxc_span::{SourceType, Span};
+use tower_lsp_server::lsp_types::{
+    SemanticToken, SemanticTokenModifier, SemanticTokenType, SemanticTokensEdit,
+    SemanticTokensLegend,
+};
+
+/// Token types, indexed by [TokenType].
+const TOKEN_TYPES: [SemanticTokenType; 12] = [
+    SemanticTokenType::NAMESPACE,
+    SemanticTokenType::TYPE,
+    SemanticTokenType::CLASS,
+    SemanticTokenType::ENUM,
+    SemanticTokenType::INTERFACE,
+    SemanticTokenType::TYPE_PARAMETER,
+    SemanticTokenType::PARAMETER,
+    SemanticTokenType::VARIABLE,
+    SemanticTokenType::PROPERTY,
+    SemanticTokenType::ENUM_MEMBER,
+    SemanticTokenType::FUNCTION,
+    SemanticTokenType::METHOD,
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TokenType {
+    Namespace = 0,
+    Type = 1,
+    Class = 2,
+    Enum = 3,
+    Interface = 4,
+    TypeParameter = 5,
+    Parameter = 6,
+    Variable = 7,
+    Property = 8,
+    EnumMember = 9,
+    Function = 10,
+    Method = 11,
+}
+
+/// Token modifiers, by bit of the modifiers of a token.
+const TOKEN_MODIFIERS: [SemanticTokenModifier; 3] = [
+    SemanticTokenModifier::DECLARATION,
+    SemanticTokenModifier::READONLY,
+    SemanticTokenModifier::DEFAULT_LIBRARY,
+];
+
+const DECLARATION: u32 = 1 << 0;
+const READONLY: u32 = 1 << 1;
+/// References to globals, e.g. `console` or `Promise`.
+const DEFAULT_LIBRARY: u32 = 1 << 2;
+
+/// The token types and modifiers of the tokens returned by [semantic_tokens].
+pub fn legend() -> SemanticTokensLegend {
+    SemanticTokensLegend {
+        token_types: TOKEN_TYPES.to_vec(),
+        token_modifiers: TOKEN_MODIFIERS.to_vec(),
+    }
+}
+
+/// The semantic tokens of the identifiers and class members of `source_text`.
+///
+/// Returns no tokens when the file can not be parsed.
+pub fn semantic_tokens(source_text: &str, source_type: SourceType) -> Vec<SemanticToken> {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    if ret.panicked {
+        return vec![];
+    }
+    let semantic = SemanticBuilder::new().build(&ret.program).semantic;
+    let scoping = semantic.scoping();
+
+    let mut tokens = vec![];
+    for node in semantic.nodes().iter() {
+        match node.kind() {
+            AstKind::BindingIdentifier(ident) => {
+                let Some(symbol_id) = ident.symbol_id.get() else { continue };
+                let (token_type, modifiers) = symbol_token(&semantic, symbol_id);
+                tokens.push((ident.span, token_type, modifiers | DECLARATION));
+            }
+            AstKind::IdentifierReference(ident) => {
+                let Some(reference_id) = ident.reference_id.get() else { continue };
+                let symbol_id = scoping.get_reference(reference_id).symbol_id();
+                let parent_kind = semantic.nodes().parent_kind(node.id());
+                let is_jsx_component = parent_kind.is_some_and(|kind| {
+                    matches!(kind, AstKind::JSXOpeningElement(_) | AstKind::JSXClosingElement(_))
+                });
+                let (token_type, modifiers) = if is_jsx_component {
+                    let is_class = symbol_id
+                        .is_some_and(|symbol_id| scoping.symbol_flags(symbol_id).is_class());
+                    (if is_class { TokenType::Class } else { TokenType::Function }, 0)
+                } else if let Some(symbol_id) = symbol_id {
+                    symbol_token(&semantic, symbol_id)
+                } else if parent_kind.is_some_and(|kind| {
+                    matches!(kind, AstKind::TSTypeName(_) | AstKind::TSTypeReference(_))
+                }) {
+                    (TokenType::Type, DEFAULT_LIBRARY)
+                } else {
+                    (TokenType::Variable, DEFAULT_LIBRARY)
+                };
+                tokens.push((ident.span, token_type, modifiers));
+            }
+            AstKind::MethodDefinition(method) => {
+                if let Some(span) = key_span(&method.key) {
+                    tokens.push((span, TokenType::Method, DECLARATION));
+                }
+            }
+            AstKind::TSMethodSignature(method) => {
+                if let Some(span) = key_span(&method.key) {
+                    tokens.push((span, TokenType::Method, DECLARATION));
+                }
+            }
+            AstKind::PropertyDefinition(property) => {
+                if let Some(span) = key_span(&property.key) {
+                    let readonly = if property.readonly { READONLY } else { 0 };
+                    tokens.push((span, TokenType::Property, DECLARATION | readonly));
+                }
+            }
+            AstKind::TSPropertySignature(property) => {
+                if let Some(span) = key_span(&property.key) {
+                    let readonly = if property.readonly { READONLY } else { 0 };
+                    tokens.push((span, TokenType::Property, DECLARATION | readonly));
+                }
+            }
+            _ => {}
+        }
+    }
+    tokens.sort_unstable_by_key(|(span, _, _)| span.start);
+    tokens.dedup_by_key(|(span, _, _)| span.start);
+
+    let rope = Rope::from_str(source_text);
+    let mut previous_line = 0;
+    let mut previous_start = 0;
+    tokens
+        .into_iter()
+        .map(|(span, token_type, modifiers)| {
+            let (line, start) = get_line_column(&rope, span.start, source_text);
+            let delta_line = line - previous_line;
+            let delta_start = if delta_line == 0 { start - previous_start } else { start };
+            (previous_line, previous_start) = (line, start);
+            SemanticToken {
+                delta_line,
+                delta_start,
+                length: to_u32(span.source_text(source_text).encode_utf16().count()),
+                token_type: token_type as u32,
+                token_modifiers_bitset: modifiers,
+            }
+        })
+        .collect()
+}
+
+/// The edit from `previous` tokens to `current` tokens, replacing the tokens between their
+/// common prefix and suffix. Returns no edits when the tokens did not change.
+pub fn semantic_tokens_edits(
+    previous: &[SemanticToken],
+    current: &[SemanticToken],
+) -> Vec<SemanticTokensEdit> {
+    let prefix = previous.iter().zip(current).take_while(|(a, b)| a == b).count();
+    if prefix == previous.len() && prefix == current.len() {
+        return vec![];
+    }
+    let suffix = previous[prefix..]
+        .iter()
+        .rev()
+        .zip(current[prefix..].iter().rev())
+        .take_while(|(a, b)| a == b)
+        .count();
+    // `start` and `delete_count` are indexes in the encoded data, with 5 integers per token.
+    vec![SemanticTokensEdit {
+        start: to_u32(prefix * 5),
+        delete_count: to_u32((previous.len() - prefix - suffix) * 5),
+        data: Some(current[prefix..current.len() - suffix].to_vec()),
+    }]
+}
+
+/// The token of the declaration and the references of `symbol_id`.
+fn symbol_token(semantic: &Semantic, symbol_id: SymbolId) -> (TokenType, u32) {
+    let flags = semantic.scoping().symbol_flags(symbol_id);
+    if flags.is_class() {
+        (TokenType::Class, 0)
+    } else if flags.is_interface() {
+        (TokenType::Interface, 0)
+    } else if flags.is_enum() {
+        (TokenType::Enum, 0)
+    } else if flags.is_enum_member() {
+        (TokenType::EnumMember, READONLY)
+    } else if flags.is_type_parameter() {
+        (TokenType::TypeParameter, 0)
+    } else if flags.is_type_alias() || flags.is_type_import() {
+        (TokenType::Type, 0)
+    } else if flags.is_namespace_module() || flags.is_value_module() {
+        (TokenType::Namespace, 0)
+    } else if flags.is_function() {
+        (TokenType::Function, 0)
+    } else if flags.is_import() {
+        (TokenType::Variable, READONLY)
+    } else {
+        let declaration = semantic.symbol_declaration(symbol_id);
+        let is_parameter = match declaration.kind() {
+            AstKind::FormalParameter(_) => true,
+            AstKind::BindingRestElement(_) => matches!(
+                semantic.nodes().parent_kind(declaration.id()),
+                Some(AstKind::FormalParameters(_))
+            ),
+            _ => false,
+        };
+        let is_function = matches!(
+            declaration.kind(),
+            AstKind::VariableDeclarator(declarator)
+                if declarator.id.kind.is_binding_identifier()
+                    && matches!(
+                        declarator.init,
+                        Some(Expression::ArrowFunctionExpression(_) | Expression::FunctionExpression(_))
+                    )
+        );
+        let readonly = if flags.is_const_variable() { READONLY } else { 0 };
+        if is_parameter {
+            (TokenType::Parameter, 0)
+        } else if is_function {
+            (TokenType::Function, readonly)
+        } else {
+            (TokenType::Variable, readonly)
+        }
+    }
+}
+
+/// The span of the name of a class member, unless it is computed, e.g. `[key]`.
+fn key_span(key: &PropertyKey) -> Option<Span> {
+    match key {
+        PropertyKey::StaticIdentifier(ident) => Some(ident.span),
+        PropertyKey::PrivateIdentifier(ident) => Some(ident.span),
+        _ => None,
+    }
+}
+
+/// Lengths and positions of the tokens are less than the length of a file.
+#[expect(clippy::cast_possible_truncation)]
+fn to_u32(n: usize) -> u32 {
+    n as u32
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_span::SourceType;
+    use tower_lsp_server::lsp_types::{SemanticToken, SemanticTokenModifier, SemanticTokenType};
+
+    use super::{TOKEN_MODIFIERS, TOKEN_TYPES, semantic_tokens, semantic_tokens_edits};
+
+    /// The text, type and modifiers of the tokens of `source_text`.
+    fn decode(source_text: &str, source_type: SourceType) -> Vec<(String, String, Vec<String>)> {
+        let lines = source_text.lines().collect::<Vec<_>>();
+        let (mut line, mut start) = (0, 0);
+        semantic_tokens(source_text, source_type)
+            .into_iter()
+            .map(|token| {
+                if token.delta_line > 0 {
+                    start = 0;
+                }
+                line += token.delta_line as usize;
+                start += token.delta_start as usize;
+                let text = lines[line][start..start + token.length as usize].to_string();
+                let token_type = TOKEN_TYPES[token.token_type as usize].as_str().to_string();
+                let modifiers = TOKEN_MODIFIERS
+                    .iter()
+                    .enumerate()
+                    .filter(|(i, _)| token.token_modifiers_bitset & (1 << i) != 0)
+                    .map(|(_, modifier)| modifier.as_str().to_string())
+                    .collect();
+                (text, token_type, modifiers)
+            })
+            .collect()
+    }
+
+    /// The type of the `nth` token with the text `text`.
+    fn token_type_of<'t>(
+        tokens: &'t [(String, String, Vec<String>)],
+        text: &str,
+        nth: usize,
+    ) -> &'t str {
+        let (_, token_type, _) =
+            tokens.iter().filter(|(token_text, _, _)| token_text == text).nth(nth).unwrap();
+        token_type
+    }
+
+    #[test]
+    fn test_semantic_tokens() {
+        let source_text = "
+interface Props<T> { readonly value: T }
+class Store { count = 0; get() { return this.count; } }
+const Button = ({ value }: Props<string>, ...rest: unknown[]) => <div>{value}</div>;
+function App(store: Store): Promise<void> {
+    console.log(store, rest);
+    return <Button value={1} />;
+}
+";
+        let tokens = decode(source_text, SourceType::tsx());
+
+        assert_eq!(token_type_of(&tokens, "Props", 0), SemanticTokenType::INTERFACE.as_str());
+        assert_eq!(token_type_of(&tokens, "T", 0), SemanticTokenType::TYPE_PARAMETER.as_str());
+        assert_eq!(token_type_of(&tokens, "T", 1), SemanticTokenType::TYPE_PARAMETER.as_str());
+        assert_eq!(token_type_of(&tokens, "value", 0), SemanticTokenType::PROPERTY.as_str());
+        assert_eq!(token_type_of(&tokens, "count", 0), SemanticTokenType::PROPERTY.as_str());
+        assert_eq!(token_type_of(&tokens, "get", 0), SemanticTokenType::METHOD.as_str());
+        assert_eq!(token_type_of(&tokens, "Button", 0), SemanticTokenType::FUNCTION.as_str());
+        assert_eq!(token_type_of(&tokens, "value", 1), SemanticTokenType::PARAMETER.as_str());
+        assert_eq!(token_type_of(&tokens, "Props", 1), SemanticTokenType::INTERFACE.as_str());
+        assert_eq!(token_type_of(&tokens, "rest", 0), SemanticTokenType::PARAMETER.as_str());
+        assert_eq!(token_type_of(&tokens, "value", 2), SemanticTokenType::PARAMETER.as_str());
+        assert_eq!(token_type_of(&tokens, "App", 0), SemanticTokenType::FUNCTION.as_str());
+        assert_eq!(token_type_of(&tokens, "Store", 1), SemanticTokenType::CLASS.as_str());
+        assert_eq!(token_type_of(&tokens, "Promise", 0), SemanticTokenType::TYPE.as_str());
+        assert_eq!(token_type_of(&tokens, "console", 0), SemanticTokenType::VARIABLE.as_str());
+        // JSX components, not intrinsic elements
+        assert_eq!(token_type_of(&tokens, "Button", 1), SemanticTokenType::FUNCTION.as_str());
+        assert!(tokens.iter().all(|(text, _, _)| text != "div"));
+
+        let declaration = SemanticTokenModifier::DECLARATION.as_str().to_string();
+        let readonly = SemanticTokenModifier::READONLY.as_str().to_string();
+        let default_library = SemanticTokenModifier::DEFAULT_LIBRARY.as_str().to_string();
+        let modifiers_of = |text: &str| {
+            tokens.iter().find(|(token_text, _, _)| token_text == text).unwrap().2.clone()
+        };
+        assert_eq!(modifiers_of("Button"), vec![declaration.clone(), readonly.clone()]);
+        assert_eq!(modifiers_of("value"), vec![declaration, readonly]);
+        assert_eq!(modifiers_of("console"), vec![default_library]);
+    }
+
+    #[test]
+    fn test_semantic_tokens_edits() {
+        let token = |delta_line| SemanticToken {
+            delta_line,
+            delta_start: 0,
+            length: 1,
+            token_type: 0,
+            token_modifiers_bitset: 0,
+        };
+        let previous = [token(0), token(1), token(2), token(3)];
+        assert!(semantic_tokens_edits(&previous, &previous).is_empty());
+
+        let current = [token(0), token(4), token(5), token(3)];
+        let edits = semantic_tokens_edits(&previous, &current);
+        assert_eq!(edits.len(), 1);
+        assert_eq!(edits[0].start, 5);
+        assert_eq!(edits[0].delete_count, 10);
+        assert_eq!(edits[0].data, Some(vec![token(4), token(5)]));
+
+        let edits = semantic_tokens_edits(&previous, &previous[..2]);
+        assert_eq!((edits[0].start, edits[0].delete_count), (10, 10));
+        assert_eq!(edits[0].data, Some(vec![]));
+    }
+}
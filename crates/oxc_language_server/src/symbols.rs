@@ -0,0 +1,284 @@
+//! Find references, highlight and rename the symbols of a single file,
+//! with the scopes and references of `oxc_semantic`.
+
+use oxc_allocator::Allocator;
+use oxc_ast::AstKind;
+use oxc_data_structures::rope::{Rope, get_line_column};
+use oxc_parser::Parser;
+use oxc_semantic::{Renamer, Semantic, SemanticBuilder, SymbolId};
+use oxc_span::{SourceType, Span};
+use tower_lsp_server::lsp_types::{
+    DocumentHighlight, DocumentHighlightKind, Position, Range, TextEdit,
+};
+
+/// An identifier of a symbol: its declaration, or a reference to it.
+struct Occurrence {
+    span: Span,
+    /// The identifier declares the symbol, or writes its value.
+    is_write: bool,
+    is_declaration: bool,
+}
+
+/// The ranges of the declarations and references of the symbol at `position`.
+pub fn references(
+    source_text: &str,
+    source_type: SourceType,
+    position: Position,
+    include_declaration: bool,
+) -> Vec<Range> {
+    with_symbol_at(source_text, source_type, position, |semantic, symbol_id, _| {
+        let rope = Rope::from_str(source_text);
+        occurrences(semantic, symbol_id)
+            .into_iter()
+            .filter(|occurrence| include_declaration || !occurrence.is_declaration)
+            .map(|occurrence| span_to_range(&rope, source_text, occurrence.span))
+            .collect()
+    })
+    .unwrap_or_default()
+}
+
+/// The declarations and references of the symbol at `position`, by whether they read or write it.
+pub fn document_highlights(
+    source_text: &str,
+    source_type: SourceType,
+    position: Position,
+) -> Vec<DocumentHighlight> {
+    with_symbol_at(source_text, source_type, position, |semantic, symbol_id, _| {
+        let rope = Rope::from_str(source_text);
+        occurrences(semantic, symbol_id)
+            .into_iter()
+            .map(|occurrence| DocumentHighlight {
+                range: span_to_range(&rope, source_text, occurrence.span),
+                kind: Some(if occurrence.is_write {
+                    DocumentHighlightKind::WRITE
+                } else {
+                    DocumentHighlightKind::READ
+                }),
+            })
+            .collect()
+    })
+    .unwrap_or_default()
+}
+
+/// The range and name of the identifier at `position`, if it can be renamed.
+///
+/// Only symbols declared in the file can be renamed, not globals.
+pub fn prepare_rename(
+    source_text: &str,
+    source_type: SourceType,
+    position: Position,
+) -> Option<(Range, String)> {
+    with_symbol_at(source_text, source_type, position, |semantic, symbol_id, span| {
+        let rope = Rope::from_str(source_text);
+        let name = semantic.scoping().symbol_name(symbol_id).to_string();
+        (span_to_range(&rope, source_text, span), name)
+    })
+}
+
+/// The edits renaming the symbol at `position` to `new_name`.
+///
+/// # Errors
+///
+/// * `new_name` is not a valid identifier
+/// * `new_name` is already declared in the scope of the symbol
+/// * A reference would resolve to another symbol after renaming
+pub fn rename(
+    source_text: &str,
+    source_type: SourceType,
+    position: Position,
+    new_name: &str,
+) -> Result<Option<Vec<TextEdit>>, String> {
+    if !Renamer::is_valid_name(new_name) {
+        return Err(format!("`{new_name}` is not a valid identifier."));
+    }
+    with_symbol_at(source_text, source_type, position, |semantic, symbol_id, _| {
+        let mut renamer = Renamer::new();
+        renamer.rename(symbol_id, new_name);
+        let edits =
+            renamer.edits(semantic.scoping(), semantic.nodes()).map_err(|err| err.to_string())?;
+        let rope = Rope::from_str(source_text);
+        let text_edits = edits
+            .into_iter()
+            .map(|edit| TextEdit {
+                range: span_to_range(&rope, source_text, edit.span),
+                new_text: edit.text,
+            })
+            .collect();
+        Ok(text_edits)
+    })
+    .transpose()
+}
+
+/// Parse `source_text` and call `f` with the symbol declared or referenced at `position`,
+/// and the span of the identifier at `position`.
+fn with_symbol_at<T>(
+    source_text: &str,
+    source_type: SourceType,
+    position: Position,
+    f: impl FnOnce(&Semantic, SymbolId, Span) -> T,
+) -> Option<T> {
+    let offset = position_to_offset(source_text, position)?;
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    if ret.panicked {
+        return None;
+    }
+    let semantic = SemanticBuilder::new().build(&ret.program).semantic;
+    // The cursor can be right after the identifier.
+    let contains = |span: Span| span.start <= offset && offset <= span.end;
+    let (symbol_id, span) = semantic.nodes().iter().find_map(|node| match node.kind() {
+        AstKind::BindingIdentifier(ident) if contains(ident.span) => {
+            Some((ident.symbol_id.get()?, ident.span))
+        }
+        AstKind::IdentifierReference(ident) if contains(ident.span) => {
+            let reference_id = ident.reference_id.get()?;
+            Some((semantic.scoping().get_reference(reference_id).symbol_id()?, ident.span))
+        }
+        _ => None,
+    })?;
+    Some(f(&semantic, symbol_id, span))
+}
+
+/// The declarations and references of `symbol_id`, in source order.
+fn occurrences(semantic: &Semantic, symbol_id: SymbolId) -> Vec<Occurrence> {
+    let scoping = semantic.scoping();
+    semantic
+        .nodes()
+        .iter()
+        .filter_map(|node| match node.kind() {
+            AstKind::BindingIdentifier(ident) if ident.symbol_id.get() == Some(symbol_id) => {
+                Some(Occurrence { span: ident.span, is_write: true, is_declaration: true })
+            }
+            AstKind::IdentifierReference(ident) => {
+                let reference = scoping.get_reference(ident.reference_id.get()?);
+                (reference.symbol_id() == Some(symbol_id)).then(|| Occurrence {
+                    span: ident.span,
+                    is_write: reference.is_write(),
+                    is_declaration: false,
+                })
+            }
+            _ => None,
+        })
+        .collect()
+}
+
+pub fn span_to_range(rope: &Rope, source_text: &str, span: Span) -> Range {
+    let (start_line, start_character) = get_line_column(rope, span.start, source_text);
+    let (end_line, end_character) = get_line_column(rope, span.end, source_text);
+    Range::new(Position::new(start_line, start_character), Position::new(end_line, end_character))
+}
+
+/// UTF-8 offset of an LSP `position`, which has a UTF-16 column.
+fn position_to_offset(source_text: &str, position: Position) -> Option<u32> {
+    let line_start = if position.line == 0 {
+        0
+    } else {
+        source_text.match_indices('\n').nth(position.line as usize - 1)?.0 + 1
+    };
+    let line = &source_text[line_start..];
+    let mut utf16_column = 0;
+    for (offset, c) in line.char_indices() {
+        if utf16_column >= position.character as usize || c == '\n' {
+            return u32::try_from(line_start + offset).ok();
+        }
+        utf16_column += c.len_utf16();
+    }
+    u32::try_from(source_text.len()).ok()
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_span::SourceType;
+    use tower_lsp_server::lsp_types::{DocumentHighlightKind, Position};
+
+    use super::{document_highlights, prepare_rename, references, rename};
+
+    fn rename_new_texts(source_text: &str, position: Position, new_name: &str) -> Vec<String> {
+        rename(source_text, SourceType::mjs(), position, new_name)
+            .unwrap()
+            .unwrap()
+            .into_iter()
+            .map(|edit| edit.new_text)
+            .collect()
+    }
+
+    #[test]
+    fn test_rename() {
+        let source_text = "let a = 1;\nconst s = '👍'; a = a + 1;";
+        let edits =
+            rename(source_text, SourceType::mjs(), Position::new(1, 16), "b").unwrap().unwrap();
+        let ranges = edits
+            .iter()
+            .map(|edit| (edit.range.start.line, edit.range.start.character))
+            .collect::<Vec<_>>();
+        assert_eq!(ranges, [(0, 4), (1, 16), (1, 20)]);
+    }
+
+    #[test]
+    fn test_rename_keeps_property_and_module_export_names() {
+        assert_eq!(
+            rename_new_texts("const { a } = obj; f(a);", Position::new(0, 8), "b"),
+            ["a: b", "b"]
+        );
+        assert_eq!(rename_new_texts("const { a = 1 } = obj;", Position::new(0, 8), "b"), ["a: b"]);
+        assert_eq!(
+            rename_new_texts("let a; ({ a } = obj); f({ a });", Position::new(0, 4), "b"),
+            ["b", "a: b", "a: b"]
+        );
+        assert_eq!(
+            rename_new_texts("import { a } from 'x'; export { a };", Position::new(0, 9), "b"),
+            ["a as b", "b as a"]
+        );
+    }
+
+    #[test]
+    fn test_rename_conflicts() {
+        let position = Position::new(0, 6);
+        for source_text in [
+            "const a = 1; const b = 2;",
+            "const a = 1; function f() { const b = 2; return a + b; }",
+            "const a = 1; function f() { return a + b; }",
+        ] {
+            assert!(
+                rename(source_text, SourceType::mjs(), position, "b").is_err(),
+                "{source_text}"
+            );
+        }
+        assert!(rename("let a = 1;", SourceType::mjs(), position, "class").is_err());
+        assert_eq!(
+            rename_new_texts("const a = 1; function f() { const b = 2; } a;", position, "b"),
+            ["b", "b"]
+        );
+    }
+
+    #[test]
+    fn test_globals_can_not_be_renamed() {
+        assert!(
+            prepare_rename("console.log(x);", SourceType::mjs(), Position::new(0, 12)).is_none()
+        );
+        assert!(prepare_rename("let a;", SourceType::mjs(), Position::new(0, 4)).is_some());
+    }
+
+    #[test]
+    fn test_references_and_document_highlights() {
+        let source_text = "let a = 1; a += 2; console.log(a);";
+        let position = Position::new(0, 31);
+        let references = references(source_text, SourceType::mjs(), position, false);
+        assert_eq!(
+            references.iter().map(|range| range.start.character).collect::<Vec<_>>(),
+            [11, 31]
+        );
+        let highlights = document_highlights(source_text, SourceType::mjs(), position);
+        assert_eq!(
+            highlights
+                .iter()
+                .map(|highlight| (highlight.range.start.character, highlight.kind))
+                .collect::<Vec<_>>(),
+            [
+                (4, Some(DocumentHighlightKind::WRITE)),
+                (11, Some(DocumentHighlightKind::WRITE)),
+                (31, Some(DocumentHighlightKind::READ)),
+            ]
+        );
+    }
+}
@@ -1,24 +1,34 @@
-use std::{str::FromStr, sync::Arc, vec};
+use std::{
+    hash::{Hash, Hasher},
+    str::FromStr,
+    sync::Arc,
+    vec,
+};
 
 use log::debug;
-use rustc_hash::FxBuildHasher;
+use rustc_hash::{FxBuildHasher, FxHasher};
 use tokio::sync::{Mutex, RwLock};
 use tower_lsp_server::{
     UriExt,
     lsp_types::{
-        CodeActionOrCommand, Diagnostic, FileEvent, FileSystemWatcher, GlobPattern, OneOf, Range,
-        RelativePattern, TextEdit, Uri, WatchKind,
+        CodeActionOrCommand, Diagnostic, FileEvent, FileSystemWatcher,
+        FullDocumentDiagnosticReport, GlobPattern, OneOf, Range, RelativePattern, TextEdit,
+        UnchangedDocumentDiagnosticReport, Uri, WatchKind, WorkspaceDocumentDiagnosticReport,
+        WorkspaceFullDocumentDiagnosticReport, WorkspaceUnchangedDocumentDiagnosticReport,
     },
 };
 
+use oxc_linter::ResolutionCache;
+
 use crate::{
     ConcurrentHashMap, Options, Run,
     code_actions::{
-        apply_all_fix_code_action, apply_fix_code_actions, ignore_this_line_code_action,
-        ignore_this_rule_code_action,
+        apply_all_fix_code_action, apply_fix_code_actions, fix_all_text_edits,
+        ignore_this_line_code_action, ignore_this_rule_code_action,
     },
     linter::{
-        error_with_position::{DiagnosticReport, PossibleFixContent},
+        error_with_position::DiagnosticReport,
+        isolated_lint_handler::IsolatedLintHandler,
         server_linter::{ServerLinter, normalize_path},
     },
 };
@@ -26,6 +36,8 @@ use crate::{
 pub struct WorkspaceWorker {
     root_uri: Uri,
     server_linter: RwLock<Option<ServerLinter>>,
+    /// Cache of the import resolver, shared with the workers of the other workspace folders
+    resolution_cache: ResolutionCache,
     diagnostics_report_map: Arc<ConcurrentHashMap<String, Vec<DiagnosticReport>>>,
     options: Mutex<Options>,
 }
@@ -35,15 +47,32 @@ impl WorkspaceWorker {
         Self {
             root_uri,
             server_linter: RwLock::new(None),
+            resolution_cache: ResolutionCache::default(),
             diagnostics_report_map: Arc::new(ConcurrentHashMap::default()),
             options: Mutex::new(Options::default()),
         }
     }
 
+    /// Share the cache of the import resolver with the workers of the other workspace folders.
+    /// The imports are still resolved with the `tsconfig.json` of each workspace folder.
+    #[must_use]
+    pub fn with_resolution_cache(mut self, resolution_cache: ResolutionCache) -> Self {
+        self.resolution_cache = resolution_cache;
+        self
+    }
+
     pub fn get_root_uri(&self) -> &Uri {
         &self.root_uri
     }
 
+    /// Whether `uri` is the root of this workspace folder, not one of its files or nested folders.
+    pub fn is_root_uri(&self, uri: &Uri) -> bool {
+        match (uri.to_file_path(), self.root_uri.to_file_path()) {
+            (Some(path), Some(root_path)) => path == root_path,
+            _ => uri == &self.root_uri,
+        }
+    }
+
     pub fn is_responsible_for_uri(&self, uri: &Uri) -> bool {
         if let Some(path) = uri.to_file_path() {
             return path.starts_with(self.root_uri.to_file_path().unwrap());
@@ -53,7 +82,8 @@ impl WorkspaceWorker {
 
     pub async fn init_linter(&self, options: &Options) {
         *self.options.lock().await = options.clone();
-        *self.server_linter.write().await = Some(ServerLinter::new(&self.root_uri, options));
+        *self.server_linter.write().await =
+            Some(ServerLinter::new(&self.root_uri, options, self.resolution_cache.clone()));
     }
 
     // WARNING: start all programs (linter, formatter) before calling this function
@@ -118,7 +148,8 @@ impl WorkspaceWorker {
 
     async fn refresh_server_linter(&self) {
         let options = self.options.lock().await;
-        let server_linter = ServerLinter::new(&self.root_uri, &options);
+        let server_linter =
+            ServerLinter::new(&self.root_uri, &options, self.resolution_cache.clone());
 
         *self.server_linter.write().await = Some(server_linter);
     }
@@ -162,6 +193,35 @@ impl WorkspaceWorker {
         server_linter.run_single(uri, content)
     }
 
+    /// Lint a file of the workspace for `workspace/diagnostic`.
+    /// The diagnostics are not saved, they are only reported to the client by that request.
+    pub async fn lint_workspace_file(&self, uri: &Uri) -> Option<Vec<DiagnosticReport>> {
+        self.lint_file_internal(uri, None).await
+    }
+
+    /// Uris of the files of the workspace which can be linted, in path order.
+    ///
+    /// Files ignored by `.gitignore` files and hidden files are skipped.
+    /// The other ignore files and the `ignorePatterns` of the config are checked when linting.
+    pub fn lintable_uris(&self) -> Vec<Uri> {
+        let Some(root_path) = self.root_uri.to_file_path() else {
+            return vec![];
+        };
+        let mut paths = ignore::WalkBuilder::new(root_path)
+            .git_global(false)
+            .follow_links(false)
+            .build()
+            .flatten()
+            .filter(|entry| {
+                entry.file_type().is_some_and(|file_type| file_type.is_file())
+                    && IsolatedLintHandler::should_lint_path(entry.path())
+            })
+            .map(ignore::DirEntry::into_path)
+            .collect::<Vec<_>>();
+        paths.sort_unstable();
+        paths.iter().filter_map(Uri::from_file_path).collect()
+    }
+
     fn update_diagnostics(&self, uri: &Uri, diagnostics: &[DiagnosticReport]) {
         self.diagnostics_report_map.pin().insert(uri.to_string(), diagnostics.to_owned());
     }
@@ -216,16 +276,17 @@ impl WorkspaceWorker {
             return vec![];
         }
 
-        let reports = value
-            .iter()
-            .filter(|r| r.diagnostic.range == *range || range_overlaps(*range, r.diagnostic.range));
-
+        // Fix all applies to the whole file, whatever the requested range is.
         if is_source_fix_all_oxc {
-            return apply_all_fix_code_action(reports, uri).map_or(vec![], |code_actions| {
+            return apply_all_fix_code_action(value.iter(), uri).map_or(vec![], |code_actions| {
                 vec![CodeActionOrCommand::CodeAction(code_actions)]
             });
         }
 
+        let reports = value
+            .iter()
+            .filter(|r| r.diagnostic.range == *range || range_overlaps(*range, r.diagnostic.range));
+
         let mut code_actions_vec: Vec<CodeActionOrCommand> = vec![];
 
         for report in reports {
@@ -268,36 +329,21 @@ impl WorkspaceWorker {
             None => &self.lint_file_internal(uri, None).await.unwrap_or_default(),
         };
 
-        if value.is_empty() {
-            return vec![];
-        }
+        fix_all_text_edits(value.iter())
+    }
 
-        let mut text_edits = vec![];
-
-        for report in value {
-            let fix = match &report.fixed_content {
-                PossibleFixContent::None => None,
-                PossibleFixContent::Single(fixed_content) => Some(fixed_content),
-                // For multiple fixes, we take the first one as a representative fix.
-                // Applying all possible fixes at once is not possible in this context.
-                PossibleFixContent::Multiple(multi) => multi.first(),
-            };
-
-            if let Some(fixed_content) = &fix {
-                text_edits.push(TextEdit {
-                    range: fixed_content.range,
-                    new_text: fixed_content.code.clone(),
-                });
-            }
+    /// Saved files can add, remove, or change the targets of imports.
+    pub async fn did_save(&self) {
+        if let Some(server_linter) = &*self.server_linter.read().await {
+            server_linter.clear_resolution_cache();
         }
-
-        text_edits
     }
 
     pub async fn did_change_watched_files(
         &self,
         _file_event: &FileEvent,
     ) -> Option<ConcurrentHashMap<String, Vec<DiagnosticReport>>> {
+        self.resolution_cache.clear();
         self.refresh_server_linter().await;
         Some(self.revalidate_diagnostics().await)
     }
@@ -346,6 +392,50 @@ impl WorkspaceWorker {
     }
 }
 
+/// The report of `diagnostics` of a file for `workspace/diagnostic`.
+///
+/// The result id is a hash of the diagnostics, so the report is unchanged when it equals the
+/// result id the client got for the file from the previous request.
+pub fn workspace_document_report(
+    uri: Uri,
+    diagnostics: Vec<Diagnostic>,
+    previous_result_id: Option<&str>,
+) -> WorkspaceDocumentDiagnosticReport {
+    let mut hasher = FxHasher::default();
+    serde_json::to_string(&diagnostics).unwrap_or_default().hash(&mut hasher);
+    let result_id = format!("{:x}", hasher.finish());
+
+    if previous_result_id == Some(result_id.as_str()) {
+        WorkspaceDocumentDiagnosticReport::Unchanged(WorkspaceUnchangedDocumentDiagnosticReport {
+            uri,
+            version: None,
+            unchanged_document_diagnostic_report: UnchangedDocumentDiagnosticReport { result_id },
+        })
+    } else {
+        WorkspaceDocumentDiagnosticReport::Full(WorkspaceFullDocumentDiagnosticReport {
+            uri,
+            version: None,
+            full_document_diagnostic_report: FullDocumentDiagnosticReport {
+                result_id: Some(result_id),
+                items: diagnostics,
+            },
+        })
+    }
+}
+
+/// The worker of the innermost workspace folder containing `uri`.
+///
+/// Workspace folders can be nested, e.g. a monorepo and one of its packages. The files of the
+/// package are linted with the configuration, ignore files and `tsconfig.json` of the package only.
+pub fn find_responsible_worker<'w>(
+    workers: &'w [WorkspaceWorker],
+    uri: &Uri,
+) -> Option<&'w WorkspaceWorker> {
+    workers.iter().filter(|worker| worker.is_responsible_for_uri(uri)).max_by_key(|worker| {
+        worker.root_uri.to_file_path().map_or(0, |path| path.components().count())
+    })
+}
+
 fn range_overlaps(a: Range, b: Range) -> bool {
     a.start <= b.end && a.end >= b.start
 }
@@ -353,6 +443,7 @@ fn range_overlaps(a: Range, b: Range) -> bool {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::tester::get_file_uri;
 
     #[test]
     fn test_get_root_uri() {
@@ -376,4 +467,73 @@ mod tests {
                 .is_responsible_for_uri(&Uri::from_str("file:///path/to/other/file.js").unwrap())
         );
     }
+
+    #[test]
+    fn test_is_root_uri() {
+        let worker = WorkspaceWorker::new(Uri::from_str("file:///path/to/root").unwrap());
+
+        assert!(worker.is_root_uri(&Uri::from_str("file:///path/to/root").unwrap()));
+        assert!(worker.is_root_uri(&Uri::from_str("file:///path/to/root/").unwrap()));
+        assert!(!worker.is_root_uri(&Uri::from_str("file:///path/to/root/packages/a").unwrap()));
+    }
+
+    #[test]
+    fn test_find_responsible_worker() {
+        let workers = [
+            WorkspaceWorker::new(Uri::from_str("file:///path/to/root").unwrap()),
+            WorkspaceWorker::new(Uri::from_str("file:///path/to/root/packages/a").unwrap()),
+            WorkspaceWorker::new(Uri::from_str("file:///path/to/other").unwrap()),
+        ];
+        let find = |uri: &str| {
+            find_responsible_worker(&workers, &Uri::from_str(uri).unwrap())
+                .map(|worker| worker.get_root_uri().as_str())
+        };
+
+        assert_eq!(find("file:///path/to/root/file.js"), Some("file:///path/to/root"));
+        assert_eq!(
+            find("file:///path/to/root/packages/a/src/file.js"),
+            Some("file:///path/to/root/packages/a")
+        );
+        assert_eq!(find("file:///path/to/root/packages/b/file.js"), Some("file:///path/to/root"));
+        assert_eq!(find("file:///path/to/other/file.js"), Some("file:///path/to/other"));
+        assert_eq!(find("file:///path/file.js"), None);
+    }
+
+    #[test]
+    fn test_lintable_uris() {
+        let worker = WorkspaceWorker::new(get_file_uri("fixtures/linter/cross_module"));
+
+        assert_eq!(
+            worker.lintable_uris(),
+            vec![
+                get_file_uri("fixtures/linter/cross_module/debugger.ts"),
+                get_file_uri("fixtures/linter/cross_module/dep-a.ts"),
+                get_file_uri("fixtures/linter/cross_module/dep-b.ts"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_workspace_document_report() {
+        let uri = Uri::from_str("file:///root/file.js").unwrap();
+        let diagnostics =
+            vec![Diagnostic { message: "error".to_string(), ..Diagnostic::default() }];
+
+        let WorkspaceDocumentDiagnosticReport::Full(report) =
+            workspace_document_report(uri.clone(), diagnostics.clone(), None)
+        else {
+            panic!("expected a full report without a previous result id");
+        };
+        let result_id = report.full_document_diagnostic_report.result_id.unwrap();
+        assert_eq!(report.full_document_diagnostic_report.items, diagnostics);
+
+        assert!(matches!(
+            workspace_document_report(uri.clone(), diagnostics, Some(&result_id)),
+            WorkspaceDocumentDiagnosticReport::Unchanged(_)
+        ));
+        assert!(matches!(
+            workspace_document_report(uri, vec![], Some(&result_id)),
+            WorkspaceDocumentDiagnosticReport::Full(_)
+        ));
+    }
 }
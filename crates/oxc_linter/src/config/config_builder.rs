@@ -11,7 +11,11 @@ use oxc_span::{CompactStr, format_compact_str};
 
 use crate::{
     AllowWarnDeny, LintConfig, LintFilter, LintFilterKind, Oxlintrc, RuleCategory, RuleEnum,
-    config::{ESLintRule, LintPlugins, OxlintOverrides, OxlintRules, overrides::OxlintOverride},
+    config::{
+        ESLintRule, LintPlugins, OxlintOverrides, OxlintRules, PromotionStatus,
+        overrides::OxlintOverride,
+    },
+    disable_directives::DirectiveDate,
     rules::RULES,
 };
 
@@ -24,6 +28,7 @@ pub struct ConfigStoreBuilder {
     categories: OxlintCategories,
     overrides: OxlintOverrides,
     cache: RulesCache,
+    promotions: Vec<PromotionStatus>,
 
     // Collect all `extends` file paths for the language server.
     // The server will tell the clients to watch for the extends files.
@@ -49,7 +54,7 @@ impl ConfigStoreBuilder {
         let cache = RulesCache::new(config.plugins);
         let extended_paths = Vec::new();
 
-        Self { rules, config, categories, overrides, cache, extended_paths }
+        Self { rules, config, categories, overrides, cache, promotions: vec![], extended_paths }
     }
 
     /// Warn on all rules in all plugins and categories, including those in `nursery`.
@@ -63,7 +68,7 @@ impl ConfigStoreBuilder {
         let cache = RulesCache::new(config.plugins);
         let rules = RULES.iter().map(|rule| (rule.clone(), AllowWarnDeny::Warn)).collect();
         let extended_paths = Vec::new();
-        Self { rules, config, categories, overrides, cache, extended_paths }
+        Self { rules, config, categories, overrides, cache, promotions: vec![], extended_paths }
     }
 
     /// Create a [`ConfigStoreBuilder`] from a loaded or manually built [`Oxlintrc`].
@@ -83,7 +88,8 @@ impl ConfigStoreBuilder {
     ///
     /// # Errors
     ///
-    /// Returns [`ConfigBuilderError::InvalidConfigFile`] if a referenced config file is not valid.
+    /// Returns [`ConfigBuilderError::InvalidConfigFile`] if a referenced config file is not valid,
+    /// or if a date in `promote` is not valid.
     pub fn from_oxlintrc(
         start_empty: bool,
         oxlintrc: Oxlintrc,
@@ -135,6 +141,13 @@ impl ConfigStoreBuilder {
 
         let (oxlintrc, extended_paths) = resolve_oxlintrc_config(oxlintrc)?;
 
+        let promotions = oxlintrc.promote.resolve(DirectiveDate::today()).map_err(|reason| {
+            ConfigBuilderError::InvalidConfigFile {
+                file: oxlintrc.path.display().to_string(),
+                reason,
+            }
+        })?;
+
         let rules = if start_empty {
             FxHashMap::default()
         } else {
@@ -162,6 +175,7 @@ impl ConfigStoreBuilder {
             categories,
             overrides: oxlintrc.overrides,
             cache,
+            promotions,
             extended_paths,
         };
 
@@ -173,6 +187,11 @@ impl ConfigStoreBuilder {
             let all_rules = builder.cache.borrow();
 
             oxlintrc.rules.override_rules(&mut builder.rules, all_rules.as_slice());
+
+            for promotion in &builder.promotions {
+                promotion.apply(&mut builder.rules, all_rules.as_slice());
+                promotion.apply_to_overrides(&mut builder.overrides);
+            }
         }
 
         Ok(builder)
@@ -219,6 +238,11 @@ impl ConfigStoreBuilder {
         self.config.plugins
     }
 
+    /// The promotions of the configuration, with whether they are in effect today.
+    pub fn promotions(&self) -> &[PromotionStatus] {
+        &self.promotions
+    }
+
     #[cfg(test)]
     pub(crate) fn with_rule(mut self, rule: RuleEnum, severity: AllowWarnDeny) -> Self {
         self.rules.insert(rule, severity);
@@ -750,6 +774,44 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_promotions() {
+        let oxlintrc: Oxlintrc = serde_json::from_str(
+            r#"
+        {
+            "rules": {
+                "eqeqeq": "warn",
+                "no-debugger": "warn"
+            },
+            "promote": {
+                "eqeqeq": { "from": "warn", "to": "error", "after": "2000-01-01" },
+                "no-debugger": { "from": "warn", "to": "error", "after": "2999-12-31" }
+            }
+        }
+        "#,
+        )
+        .unwrap();
+        let builder = ConfigStoreBuilder::from_oxlintrc(true, oxlintrc).unwrap();
+        let severity = |name: &str| {
+            builder
+                .rules
+                .iter()
+                .find(|(rule, _)| rule.name() == name)
+                .map(|(_, severity)| *severity)
+        };
+        assert_eq!(severity("eqeqeq"), Some(AllowWarnDeny::Deny));
+        assert_eq!(severity("no-debugger"), Some(AllowWarnDeny::Warn));
+        let active = builder.promotions().iter().map(|p| (p.rule.as_str(), p.active)).collect_vec();
+        assert_eq!(active, [("eqeqeq", true), ("no-debugger", false)]);
+
+        let oxlintrc: Oxlintrc = serde_json::from_str(
+            r#"{ "promote": { "eqeqeq": { "from": "warn", "to": "error", "after": "2025-13-01" } } }"#,
+        )
+        .unwrap();
+        let err = ConfigStoreBuilder::from_oxlintrc(true, oxlintrc).unwrap_err();
+        assert!(matches!(err, ConfigBuilderError::InvalidConfigFile { .. }));
+    }
+
     #[test]
     fn test_extends_rules_single() {
         let base_config = config_store_from_path("fixtures/extends_config/rules_config.json");
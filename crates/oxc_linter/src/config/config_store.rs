@@ -8,6 +8,7 @@ use rustc_hash::FxHashMap;
 use super::{LintConfig, LintPlugins, categories::OxlintCategories, overrides::OxlintOverrides};
 use crate::{
     AllowWarnDeny,
+    rule::RuleRequirements,
     rules::{RULES, RuleEnum},
 };
 
@@ -19,6 +20,15 @@ pub struct ResolvedLinterState {
     pub config: Arc<LintConfig>,
 }
 
+impl ResolvedLinterState {
+    /// The analyses needed by the enabled rules.
+    pub fn requirements(&self) -> RuleRequirements {
+        self.rules.iter().fold(RuleRequirements::empty(), |requirements, (rule, _)| {
+            requirements | rule.requirements()
+        })
+    }
+}
+
 impl Clone for ResolvedLinterState {
     fn clone(&self) -> Self {
         Self { rules: Arc::clone(&self.rules), config: Arc::clone(&self.config) }
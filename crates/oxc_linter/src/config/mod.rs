@@ -8,6 +8,7 @@ mod globals;
 mod overrides;
 mod oxlintrc;
 mod plugins;
+mod promotions;
 mod rules;
 mod settings;
 pub use config_builder::{ConfigBuilderError, ConfigStoreBuilder};
@@ -18,6 +19,7 @@ pub use globals::{GlobalValue, OxlintGlobals};
 pub use overrides::OxlintOverrides;
 pub use oxlintrc::Oxlintrc;
 pub use plugins::LintPlugins;
+pub use promotions::{OxlintPromotions, PromotionStatus, RulePromotion};
 pub use rules::{ESLintRule, OxlintRules};
 pub use settings::{OxlintSettings, jsdoc::JSDocPluginSettings};
 
@@ -13,7 +13,8 @@ use crate::utils::read_to_string;
 
 use super::{
     categories::OxlintCategories, env::OxlintEnv, globals::OxlintGlobals,
-    overrides::OxlintOverrides, plugins::LintPlugins, rules::OxlintRules, settings::OxlintSettings,
+    overrides::OxlintOverrides, plugins::LintPlugins, promotions::OxlintPromotions,
+    rules::OxlintRules, settings::OxlintSettings,
 };
 
 /// Oxlint Configuration File
@@ -83,6 +84,9 @@ pub struct Oxlintrc {
     /// See [Oxlint Rules](https://oxc.rs/docs/guide/usage/linter/rules.html) for the list of
     /// rules.
     pub rules: OxlintRules,
+    /// Change the severity of rules after a date, to roll out rules in stages.
+    #[serde(skip_serializing_if = "OxlintPromotions::is_empty")]
+    pub promote: OxlintPromotions,
     pub settings: OxlintSettings,
     /// Environments enable and disable collections of global variables.
     pub env: OxlintEnv,
@@ -186,6 +190,9 @@ impl Oxlintrc {
             .map(|rule| (**rule).clone())
             .collect::<Vec<_>>();
 
+        let mut promote = self.promote.clone();
+        promote.extend(other.promote);
+
         let settings = self.settings.clone();
         let env = self.env.clone();
         let globals = self.globals.clone();
@@ -200,6 +207,7 @@ impl Oxlintrc {
             ),
             categories,
             rules: OxlintRules::new(rules),
+            promote,
             settings,
             env,
             globals,
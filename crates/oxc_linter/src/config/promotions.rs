@@ -0,0 +1,240 @@
+use std::collections::BTreeMap;
+
+use rustc_hash::FxHashMap;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::{AllowWarnDeny, disable_directives::DirectiveDate, rules::RuleEnum};
+
+use super::{
+    OxlintOverrides,
+    rules::{parse_rule_key, transform_rule_and_plugin_name},
+};
+
+/// Change the severity of rules after a date.
+///
+/// A rule configured with the `from` severity gets the `to` severity from the day after the
+/// `after` date (in UTC), so that a rule can be rolled out as a warning first and become an error
+/// later without another configuration change. Rules configured with another severity, e.g. in
+/// `overrides`, are left untouched.
+///
+/// Example
+///
+/// `.oxlintrc.json`
+///
+/// ```json
+/// {
+///   "$schema": "./node_modules/oxlint/configuration_schema.json",
+///   "promote": {
+///     "eqeqeq": { "after": "2025-06-01", "from": "warn", "to": "error" }
+///   },
+///   "rules": {
+///     "eqeqeq": "warn"
+///   }
+/// }
+/// ```
+#[derive(Debug, Default, Clone, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+pub struct OxlintPromotions(BTreeMap<String, RulePromotion>);
+
+/// A severity change of a rule, see [`OxlintPromotions`].
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize, JsonSchema)]
+#[serde(deny_unknown_fields)]
+pub struct RulePromotion {
+    /// Severity of the rule before the promotion.
+    pub from: AllowWarnDeny,
+    /// Severity of the rule after the promotion.
+    pub to: AllowWarnDeny,
+    /// Last day before the promotion, as `YYYY-MM-DD`.
+    pub after: String,
+}
+
+/// A configured promotion, and whether it is in effect.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct PromotionStatus {
+    /// Name of the rule, as configured, e.g. `import/no-cycle`.
+    pub rule: String,
+    pub from: AllowWarnDeny,
+    pub to: AllowWarnDeny,
+    pub after: String,
+    /// `true` once the `after` date has passed.
+    pub active: bool,
+    #[serde(skip)]
+    plugin_name: String,
+    #[serde(skip)]
+    rule_name: String,
+}
+
+impl OxlintPromotions {
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Adds the promotions of `other`, for the rules without a promotion in `self`.
+    pub(crate) fn extend(&mut self, other: Self) {
+        for (rule, promotion) in other.0 {
+            self.0.entry(rule).or_insert(promotion);
+        }
+    }
+
+    /// Checks the dates of the promotions, and which of them are in effect on `today`.
+    ///
+    /// # Errors
+    ///
+    /// Returns a message when a date is not a valid `YYYY-MM-DD` date.
+    pub(crate) fn resolve(&self, today: DirectiveDate) -> Result<Vec<PromotionStatus>, String> {
+        self.0
+            .iter()
+            .map(|(rule, promotion)| {
+                let Some(after) = DirectiveDate::parse(&promotion.after) else {
+                    return Err(format!(
+                        "invalid date \"{}\" in the promotion of {rule}, expected YYYY-MM-DD",
+                        promotion.after
+                    ));
+                };
+                let (plugin_name, rule_name) = parse_rule_key(rule);
+                let (rule_name, plugin_name) =
+                    transform_rule_and_plugin_name(&rule_name, &plugin_name);
+                Ok(PromotionStatus {
+                    rule: rule.clone(),
+                    from: promotion.from,
+                    to: promotion.to,
+                    after: promotion.after.clone(),
+                    active: after < today,
+                    plugin_name: plugin_name.to_string(),
+                    rule_name: rule_name.to_string(),
+                })
+            })
+            .collect()
+    }
+}
+
+impl PromotionStatus {
+    fn matches(&self, plugin_name: &str, rule_name: &str) -> bool {
+        let (rule_name, plugin_name) = transform_rule_and_plugin_name(rule_name, plugin_name);
+        self.rule_name == rule_name && self.plugin_name == plugin_name
+    }
+
+    /// Promotes the rule in `rules` if it has the `from` severity.
+    ///
+    /// A rule promoted from `off` is enabled from `all_rules` when it is not configured.
+    pub(crate) fn apply(
+        &self,
+        rules: &mut FxHashMap<RuleEnum, AllowWarnDeny>,
+        all_rules: &[RuleEnum],
+    ) {
+        if !self.active {
+            return;
+        }
+        let configured =
+            rules.iter_mut().find(|(rule, _)| self.matches(rule.plugin_name(), rule.name()));
+        match configured {
+            Some((_, severity)) => {
+                if *severity == self.from {
+                    *severity = self.to;
+                }
+            }
+            None if self.from == AllowWarnDeny::Allow => {
+                if let Some(rule) =
+                    all_rules.iter().find(|rule| self.matches(rule.plugin_name(), rule.name()))
+                {
+                    rules.insert(rule.clone(), self.to);
+                }
+            }
+            None => {}
+        }
+    }
+
+    /// Promotes the rule in the `rules` of `overrides` where it has the `from` severity.
+    pub(crate) fn apply_to_overrides(&self, overrides: &mut OxlintOverrides) {
+        if !self.active {
+            return;
+        }
+        for rule in overrides.iter_mut().flat_map(|r#override| r#override.rules.rules.iter_mut()) {
+            if rule.severity == self.from && self.matches(&rule.plugin_name, &rule.rule_name) {
+                rule.severity = self.to;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use rustc_hash::FxHashMap;
+    use serde_json::json;
+
+    use super::OxlintPromotions;
+    use crate::{
+        AllowWarnDeny, config::OxlintOverrides, disable_directives::DirectiveDate, rules::RULES,
+    };
+
+    fn promotions(value: serde_json::Value) -> OxlintPromotions {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn test_resolve() {
+        let statuses = promotions(json!({
+            "eqeqeq": { "from": "warn", "to": "error", "after": "2025-06-01" },
+            "@typescript-eslint/no-explicit-any": { "from": "off", "to": 1, "after": "2025-06-02" },
+        }));
+        let today = DirectiveDate::parse("2025-06-02").unwrap();
+        let statuses = statuses.resolve(today).unwrap();
+        assert_eq!(statuses.len(), 2);
+        let eqeqeq = statuses.iter().find(|s| s.rule == "eqeqeq").unwrap();
+        assert!(eqeqeq.active);
+        assert_eq!((eqeqeq.from, eqeqeq.to), (AllowWarnDeny::Warn, AllowWarnDeny::Deny));
+        // Not on the `after` day itself
+        let no_explicit_any =
+            statuses.iter().find(|s| s.rule.ends_with("no-explicit-any")).unwrap();
+        assert!(!no_explicit_any.active);
+        assert!(no_explicit_any.matches("typescript", "no-explicit-any"));
+
+        let invalid =
+            promotions(json!({ "eqeqeq": { "from": "warn", "to": "error", "after": "June 1st" } }));
+        assert!(invalid.resolve(today).is_err());
+
+        assert!(
+            serde_json::from_value::<OxlintPromotions>(
+                json!({ "eqeqeq": { "from": "warn", "to": "error", "after": "2025-06-01", "before": "" } })
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_apply() {
+        let today = DirectiveDate::parse("2025-06-02").unwrap();
+        let statuses = promotions(json!({
+            "eqeqeq": { "from": "warn", "to": "error", "after": "2025-06-01" },
+            "no-debugger": { "from": "warn", "to": "error", "after": "2025-06-01" },
+            "no-console": { "from": "off", "to": "warn", "after": "2025-06-01" },
+            "no-alert": { "from": "off", "to": "warn", "after": "2999-12-31" },
+        }))
+        .resolve(today)
+        .unwrap();
+
+        let rule = |name: &str| RULES.iter().find(|r| r.name() == name).unwrap().clone();
+        let mut rules = FxHashMap::default();
+        rules.insert(rule("eqeqeq"), AllowWarnDeny::Warn);
+        // Configured with another severity than `from`
+        rules.insert(rule("no-debugger"), AllowWarnDeny::Allow);
+        for status in &statuses {
+            status.apply(&mut rules, &RULES);
+        }
+        assert_eq!(rules.get(&rule("eqeqeq")), Some(&AllowWarnDeny::Deny));
+        assert_eq!(rules.get(&rule("no-debugger")), Some(&AllowWarnDeny::Allow));
+        assert_eq!(rules.get(&rule("no-console")), Some(&AllowWarnDeny::Warn));
+        assert_eq!(rules.get(&rule("no-alert")), None);
+
+        let mut overrides: OxlintOverrides = serde_json::from_value(json!([
+            { "files": ["*.test.js"], "rules": { "eqeqeq": "warn", "no-debugger": "error" } }
+        ]))
+        .unwrap();
+        for status in &statuses {
+            status.apply_to_overrides(&mut overrides);
+        }
+        let severities =
+            overrides[0].rules.rules.iter().map(|rule| rule.severity).collect::<Vec<_>>();
+        assert_eq!(severities, [AllowWarnDeny::Deny, AllowWarnDeny::Deny]);
+    }
+}
@@ -1,11 +1,13 @@
 use std::{borrow::Cow, cell::RefCell, path::Path, rc::Rc, sync::Arc};
 
-use oxc_diagnostics::{OxcDiagnostic, Severity};
+use itertools::Itertools;
+use oxc_ast::pragma::Pragmas;
+use oxc_diagnostics::{MessageCatalog, OxcDiagnostic, Severity};
 use oxc_semantic::Semantic;
 use oxc_span::{SourceType, Span};
 
 use crate::{
-    AllowWarnDeny, FrameworkFlags,
+    AllowWarnDeny, FrameworkFlags, ModuleFormat,
     config::{LintConfig, LintPlugins},
     disable_directives::{DisableDirectives, DisableDirectivesBuilder, RuleCommentType},
     fixer::{Fix, FixKind, Message, PossibleFixes},
@@ -46,6 +48,8 @@ pub struct ContextHost<'a> {
     /// Information about specific rules that should be disabled or enabled, via comment directives like
     /// `eslint-disable` or `eslint-disable-next-line`.
     pub(super) disable_directives: DisableDirectives<'a>,
+    /// Pragmas in comments, like `@jsxRuntime automatic`.
+    pub(super) pragmas: Pragmas<'a>,
     /// Diagnostics reported by the linter.
     ///
     /// Contains diagnostics for all rules across a single file.
@@ -65,11 +69,13 @@ pub struct ContextHost<'a> {
     pub(super) frameworks: FrameworkFlags,
     /// A list of all available linter plugins.
     pub(super) plugins: LintPlugins,
+    /// Translations of the diagnostics of the rules.
+    ///
+    /// Set via the `--locale` CLI flag.
+    pub(super) message_catalog: Option<&'static MessageCatalog>,
 }
 
 impl<'a> ContextHost<'a> {
-    /// # Panics
-    /// If `semantic.cfg()` is `None`.
     pub fn new<P: AsRef<Path>>(
         file_path: P,
         semantic: Rc<Semantic<'a>>,
@@ -79,15 +85,9 @@ impl<'a> ContextHost<'a> {
     ) -> Self {
         const DIAGNOSTICS_INITIAL_CAPACITY: usize = 512;
 
-        // We should always check for `semantic.cfg()` being `Some` since we depend on it and it is
-        // unwrapped without any runtime checks after construction.
-        assert!(
-            semantic.cfg().is_some(),
-            "`LintContext` depends on `Semantic::cfg`, Build your semantic with cfg enabled(`SemanticBuilder::with_cfg`)."
-        );
-
         let disable_directives =
             DisableDirectivesBuilder::new().build(semantic.source_text(), semantic.comments());
+        let pragmas = Pragmas::from_comments(semantic.comments(), semantic.source_text());
 
         let file_path = file_path.as_ref().to_path_buf().into_boxed_path();
         let plugins = config.plugins;
@@ -96,12 +96,14 @@ impl<'a> ContextHost<'a> {
             semantic,
             module_record,
             disable_directives,
+            pragmas,
             diagnostics: RefCell::new(Vec::with_capacity(DIAGNOSTICS_INITIAL_CAPACITY)),
             fix: options.fix,
             file_path,
             config,
             frameworks: options.framework_hints,
             plugins,
+            message_catalog: options.message_catalog,
         }
         .sniff_for_frameworks()
     }
@@ -132,6 +134,18 @@ impl<'a> ContextHost<'a> {
         &self.module_record
     }
 
+    /// Pragmas in the comments of the file, like `@jsxRuntime automatic`.
+    #[inline]
+    pub fn pragmas(&self) -> &Pragmas<'a> {
+        &self.pragmas
+    }
+
+    /// How Node.js loads the file being linted, see [`ModuleFormat`].
+    #[inline]
+    pub fn module_format(&self) -> ModuleFormat {
+        self.module_record.module_format
+    }
+
     /// Path to the file being linted.
     ///
     /// When created from a [`LintService`](`crate::service::LintService`), this
@@ -239,6 +253,74 @@ impl<'a> ContextHost<'a> {
         );
     }
 
+    /// Report expired disable directives and directives with invalid expiry dates, and depending
+    /// on `options`, directives without a reason and every directive as an advice.
+    pub fn report_suppressions(&self, options: &LintOptions) {
+        let mut diagnostics = vec![];
+        for suppression in self.disable_directives.suppressions() {
+            let span = suppression.comment.span;
+            if let Some(expiry) = &suppression.expiry {
+                if suppression.expired {
+                    diagnostics.push(
+                        OxcDiagnostic::error(format!(
+                            "Expired eslint-disable directive (expired on {}).",
+                            expiry.text
+                        ))
+                        .with_help("Fix the problems it disables, or extend its expiry date.")
+                        .with_label(expiry.span),
+                    );
+                } else if expiry.date.is_none() {
+                    diagnostics.push(
+                        OxcDiagnostic::error(format!(
+                            "Invalid expiry date `{}` in eslint-disable directive.",
+                            expiry.text
+                        ))
+                        .with_help("Use the `YYYY-MM-DD` format, e.g. `(expires: 2025-12-31)`.")
+                        .with_label(expiry.span),
+                    );
+                }
+            }
+
+            if options.require_directive_reasons && suppression.reason.is_none() {
+                diagnostics.push(
+                    OxcDiagnostic::error("Missing reason for eslint-disable directive.")
+                        .with_help("Add a reason after `--`, e.g. `// oxlint-disable-next-line no-debugger -- reason`.")
+                        .with_label(span),
+                );
+            }
+
+            if options.report_suppressions {
+                let rules = match &suppression.comment.r#type {
+                    RuleCommentType::All => Cow::Borrowed("all rules"),
+                    RuleCommentType::Single(rules) => {
+                        Cow::Owned(rules.iter().map(|rule| rule.rule_name).join(", "))
+                    }
+                };
+                let reason =
+                    suppression.reason.map_or(Cow::Borrowed("No reason given."), |reason| {
+                        format!("Reason: {reason}").into()
+                    });
+                let help = match &suppression.expiry {
+                    Some(expiry) => format!("{reason} (expires: {})", expiry.text),
+                    None => reason.into_owned(),
+                };
+                diagnostics.push(
+                    OxcDiagnostic::warn(format!("Suppression of {rules}."))
+                        .with_help(help)
+                        .with_label(span)
+                        .with_severity(Severity::Advice),
+                );
+            }
+        }
+
+        self.append_diagnostics(
+            diagnostics
+                .into_iter()
+                .map(|diagnostic| Message::new(diagnostic, PossibleFixes::None))
+                .collect(),
+        );
+    }
+
     /// Take ownership of all diagnostics collected during linting.
     pub fn take_diagnostics(&self) -> Vec<Message<'a>> {
         // NOTE: diagnostics are only ever borrowed here and in push_diagnostic, append_diagnostics.
@@ -4,7 +4,7 @@ use std::{ops::Deref, path::Path, rc::Rc};
 
 use javascript_globals::GLOBALS;
 
-use oxc_ast::ast::IdentifierReference;
+use oxc_ast::{ast::IdentifierReference, pragma::Pragmas};
 use oxc_cfg::ControlFlowGraph;
 use oxc_diagnostics::{OxcDiagnostic, Severity};
 use oxc_semantic::Semantic;
@@ -13,7 +13,8 @@ use oxc_span::{GetSpan, Span};
 #[cfg(debug_assertions)]
 use crate::rule::RuleFixMeta;
 use crate::{
-    AllowWarnDeny, FrameworkFlags, ModuleRecord, OxlintEnv, OxlintGlobals, OxlintSettings,
+    AllowWarnDeny, FrameworkFlags, ModuleFormat, ModuleRecord, OxlintEnv, OxlintGlobals,
+    OxlintSettings,
     config::GlobalValue,
     disable_directives::DisableDirectives,
     fixer::{Fix, FixKind, Message, PossibleFixes, RuleFix, RuleFixer},
@@ -115,11 +116,14 @@ impl<'a> LintContext<'a> {
     }
 
     /// Get the control flow graph for the current program.
+    ///
+    /// # Panics
+    /// If the rule does not declare `requires = cfg`, so the graph was not built.
     #[inline]
     pub fn cfg(&self) -> &ControlFlowGraph {
-        // SAFETY: `LintContext::new` is the only way to construct a `LintContext` and we always
-        // assert the existence of control flow so it should always be `Some`.
-        unsafe { self.parent.semantic.cfg().unwrap_unchecked() }
+        self.parent.semantic.cfg().expect(
+            "The control flow graph is only built for rules which declare `requires = cfg`.",
+        )
     }
 
     /// List of all disable directives in the file being linted.
@@ -128,6 +132,21 @@ impl<'a> LintContext<'a> {
         &self.parent.disable_directives
     }
 
+    /// Pragmas in the comments of the file being linted, like `@jsxRuntime automatic`.
+    #[inline]
+    pub fn pragmas(&self) -> &Pragmas<'a> {
+        &self.parent.pragmas
+    }
+
+    /// How Node.js loads the file being linted: as CommonJS (`.cjs`, or a `.js` file of a package
+    /// with `"type": "commonjs"`), as an ES module, or ambiguously.
+    ///
+    /// `module`, `exports` and `require` are globals of CommonJS files.
+    #[inline]
+    pub fn module_format(&self) -> ModuleFormat {
+        self.parent.module_format()
+    }
+
     /// Get a snippet of source text covered by the given [`Span`]. For details,
     /// see [`Span::source_text`].
     pub fn source_range(&self, span: Span) -> &'a str {
@@ -186,6 +205,12 @@ impl<'a> LintContext<'a> {
             return Some(GlobalValue::Readonly);
         }
 
+        if self.module_format().is_commonjs() {
+            if let Some(value) = GLOBALS["commonjs"].get(var) {
+                return Some(GlobalValue::from(*value));
+            }
+        }
+
         for env in self.env().iter() {
             if let Some(env) = GLOBALS.get(env) {
                 if let Some(value) = env.get(var) {
@@ -207,6 +232,9 @@ impl<'a> LintContext<'a> {
         if GLOBALS["builtin"].contains_key(var) {
             return true;
         }
+        if self.module_format().is_commonjs() && GLOBALS["commonjs"].contains_key(var) {
+            return true;
+        }
         for env in self.env().iter() {
             if let Some(env) = GLOBALS.get(env) {
                 if env.contains_key(var) {
@@ -234,6 +262,9 @@ impl<'a> LintContext<'a> {
                 self.current_plugin_name,
                 self.current_rule_name
             ));
+        if let Some(catalog) = self.parent.message_catalog {
+            message.error = catalog.translate(message.error);
+        }
         if message.error.severity != self.severity {
             message.error = message.error.with_severity(self.severity);
         }
@@ -431,7 +462,7 @@ impl<'a> LintContext<'a> {
 /// assert_eq!(plugin_name_to_prefix("react"), "eslint-plugin-react");
 /// ```
 #[inline]
-fn plugin_name_to_prefix(plugin_name: &'static str) -> &'static str {
+pub(crate) fn plugin_name_to_prefix(plugin_name: &'static str) -> &'static str {
     PLUGIN_PREFIXES.get(plugin_name).copied().unwrap_or(plugin_name)
 }
 
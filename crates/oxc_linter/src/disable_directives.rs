@@ -1,4 +1,8 @@
-use std::cell::RefCell;
+use std::{
+    cell::RefCell,
+    fmt,
+    time::{SystemTime, UNIX_EPOCH},
+};
 
 use itertools::Itertools;
 use oxc_ast::Comment;
@@ -101,11 +105,107 @@ pub struct DisableRuleComment<'a> {
     pub r#type: RuleCommentType<'a>,
 }
 
+/// A date in the `(expires: YYYY-MM-DD)` of a directive, or the `after` of a rule promotion.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub struct DirectiveDate {
+    year: u16,
+    month: u8,
+    day: u8,
+}
+
+impl DirectiveDate {
+    /// Parse a `YYYY-MM-DD` date.
+    pub fn parse(text: &str) -> Option<Self> {
+        let mut parts = text.splitn(3, '-');
+        let (year, month, day) = (parts.next()?, parts.next()?, parts.next()?);
+        if year.len() != 4 || month.len() != 2 || day.len() != 2 {
+            return None;
+        }
+        let date =
+            Self { year: year.parse().ok()?, month: month.parse().ok()?, day: day.parse().ok()? };
+        // Divisible by 4 but not by 100, or divisible by 400
+        let leap_year =
+            matches!((date.year % 4, date.year % 100, date.year % 400), (0, 1.., _) | (_, _, 0));
+        let days_in_month = match date.month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if leap_year => 29,
+            2 => 28,
+            _ => return None,
+        };
+        (1..=days_in_month).contains(&date.day).then_some(date)
+    }
+
+    /// The current date in UTC.
+    #[expect(clippy::cast_possible_truncation, clippy::cast_possible_wrap, clippy::cast_sign_loss)]
+    pub fn today() -> Self {
+        let secs = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_secs());
+        // Convert the days since the Unix epoch to a civil date.
+        // ref: <https://howardhinnant.github.io/date_algorithms.html#civil_from_days>
+        let days = (secs / 86_400) as i64 + 719_468;
+        let era = days / 146_097;
+        let day_of_era = days - era * 146_097;
+        let year_of_era =
+            (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let month_index = (5 * day_of_year + 2) / 153;
+        let day = day_of_year - (153 * month_index + 2) / 5 + 1;
+        let month = if month_index < 10 { month_index + 3 } else { month_index - 9 };
+        let year = year_of_era + era * 400 + i64::from(month <= 2);
+        Self { year: year as u16, month: month as u8, day: day as u8 }
+    }
+}
+
+impl fmt::Display for DirectiveDate {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// The `(expires: YYYY-MM-DD)` of a directive.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct DirectiveExpiry<'a> {
+    /// The text of the date, e.g. `2025-12-31`
+    pub text: &'a str,
+    /// `None` when the text is not a valid `YYYY-MM-DD` date
+    pub date: Option<DirectiveDate>,
+    /// Span of `(expires: YYYY-MM-DD)`
+    pub span: Span,
+}
+
+impl DirectiveExpiry<'_> {
+    /// A directive stops suppressing problems on the day after its expiry date.
+    pub fn is_expired(&self, today: DirectiveDate) -> bool {
+        self.date.is_some_and(|date| date < today)
+    }
+}
+
+/// The description of a directive after `--`, e.g.
+/// `// oxlint-disable-next-line no-console -- reason (expires: 2025-12-31)`.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+struct DirectiveDescription<'a> {
+    reason: Option<&'a str>,
+    expiry: Option<DirectiveExpiry<'a>>,
+}
+
+/// A comment which disables all rules or some rules, with its description.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct Suppression<'a> {
+    pub comment: DisableRuleComment<'a>,
+    /// The reason after `--`, without the expiry
+    pub reason: Option<&'a str>,
+    pub expiry: Option<DirectiveExpiry<'a>>,
+    /// The expiry date is in the past, the comment doesn't disable any rule
+    pub expired: bool,
+}
+
 pub struct DisableDirectives<'a> {
     /// All the disabled rules with their corresponding covering spans
     intervals: Lapper<u32, DisabledRule<'a>>,
     /// All comments that disable one or more specific rules
     disable_rule_comments: Box<[DisableRuleComment<'a>]>,
+    /// All disable comments with their reasons and expiry dates
+    suppressions: Box<[Suppression<'a>]>,
     /// Spans of unused enable directives
     unused_enable_comments: Box<[(Option<&'a str>, Span)]>,
     /// Spans of used enable directives, to filter out unused
@@ -144,6 +244,13 @@ impl<'a> DisableDirectives<'a> {
         &self.disable_rule_comments
     }
 
+    /// All disable comments with their reasons and expiry dates, in source order.
+    ///
+    /// Expired comments are included, but don't disable any rule.
+    pub fn suppressions(&self) -> &[Suppression<'a>] {
+        &self.suppressions
+    }
+
     pub fn unused_enable_comments(&self) -> &[(Option<&'a str>, Span)] {
         &self.unused_enable_comments
     }
@@ -212,6 +319,8 @@ pub struct DisableDirectivesBuilder<'a> {
     disable_start_map: FxHashMap<&'a str, (u32, Span, Span)>,
     /// All comments that disable one or more specific rules
     disable_rule_comments: Vec<DisableRuleComment<'a>>,
+    /// All disable comments with their reasons and expiry dates
+    suppressions: Vec<Suppression<'a>>,
     /// Spans of unused enable directives
     unused_enable_comments: Vec<(Option<&'a str>, Span)>,
 }
@@ -223,6 +332,7 @@ impl<'a> DisableDirectivesBuilder<'a> {
             disable_all_start: None,
             disable_start_map: FxHashMap::default(),
             disable_rule_comments: vec![],
+            suppressions: vec![],
             unused_enable_comments: vec![],
         }
     }
@@ -230,9 +340,34 @@ impl<'a> DisableDirectivesBuilder<'a> {
     pub fn build(mut self, source_text: &'a str, comments: &[Comment]) -> DisableDirectives<'a> {
         self.build_impl(source_text, comments);
 
+        // Expired directives don't disable rules anymore.
+        // Only get the date when needed, most directives don't expire.
+        if self.suppressions.iter().any(|suppression| suppression.expiry.is_some()) {
+            let today = DirectiveDate::today();
+            for suppression in &mut self.suppressions {
+                suppression.expired = suppression.expiry.is_some_and(|e| e.is_expired(today));
+            }
+            let expired = self
+                .suppressions
+                .iter()
+                .filter(|suppression| suppression.expired)
+                .map(|suppression| suppression.comment.span)
+                .collect::<Vec<_>>();
+            if !expired.is_empty() {
+                self.intervals = Lapper::new(
+                    self.intervals
+                        .iter()
+                        .filter(|interval| !expired.contains(interval.val.comment_span()))
+                        .cloned()
+                        .collect(),
+                );
+            }
+        }
+
         DisableDirectives {
             intervals: self.intervals,
             disable_rule_comments: self.disable_rule_comments.into_boxed_slice(),
+            suppressions: self.suppressions.into_boxed_slice(),
             unused_enable_comments: self.unused_enable_comments.into_boxed_slice(),
             used_disable_comments: RefCell::new(Vec::new()),
         }
@@ -242,6 +377,20 @@ impl<'a> DisableDirectivesBuilder<'a> {
         self.intervals.insert(Interval { start, stop, val });
     }
 
+    fn push_disable_rule_comment(
+        &mut self,
+        comment: DisableRuleComment<'a>,
+        description: DirectiveDescription<'a>,
+    ) {
+        self.suppressions.push(Suppression {
+            comment: comment.clone(),
+            reason: description.reason,
+            expiry: description.expiry,
+            expired: false,
+        });
+        self.disable_rule_comments.push(comment);
+    }
+
     #[expect(clippy::cast_possible_truncation)] // for `as u32`
     fn build_impl(&mut self, source_text: &'a str, comments: &[Comment]) {
         let source_len = source_text.len() as u32;
@@ -267,15 +416,16 @@ impl<'a> DisableDirectivesBuilder<'a> {
                 text.strip_prefix("eslint-disable").or_else(|| text.strip_prefix("oxlint-disable"))
             {
                 rule_name_start += 14; // eslint-disable is 14 bytes
+                let (text, description) = Self::split_description(text, comment_span.end);
                 // `eslint-disable`
                 if text.trim().is_empty() {
                     if self.disable_all_start.is_none() {
                         self.disable_all_start = Some((comment_span.end, comment_span));
                     }
-                    self.disable_rule_comments.push(DisableRuleComment {
-                        span: comment_span,
-                        r#type: RuleCommentType::All,
-                    });
+                    self.push_disable_rule_comment(
+                        DisableRuleComment { span: comment_span, r#type: RuleCommentType::All },
+                        description,
+                    );
                     continue;
                 }
                 // `eslint-disable-next-line`
@@ -301,10 +451,10 @@ impl<'a> DisableDirectivesBuilder<'a> {
                             stop,
                             DisabledRule::All { comment_span },
                         );
-                        self.disable_rule_comments.push(DisableRuleComment {
-                            span: comment_span,
-                            r#type: RuleCommentType::All,
-                        });
+                        self.push_disable_rule_comment(
+                            DisableRuleComment { span: comment_span, r#type: RuleCommentType::All },
+                            description,
+                        );
                     } else {
                         // `eslint-disable-next-line rule_name1, rule_name2`
                         let mut rules = vec![];
@@ -316,10 +466,13 @@ impl<'a> DisableDirectivesBuilder<'a> {
                             );
                             rules.push(RuleCommentRule { rule_name, name_span });
                         });
-                        self.disable_rule_comments.push(DisableRuleComment {
-                            span: comment_span,
-                            r#type: RuleCommentType::Single(rules),
-                        });
+                        self.push_disable_rule_comment(
+                            DisableRuleComment {
+                                span: comment_span,
+                                r#type: RuleCommentType::Single(rules),
+                            },
+                            description,
+                        );
                     }
                     continue;
                 }
@@ -337,10 +490,10 @@ impl<'a> DisableDirectivesBuilder<'a> {
                     // `eslint-disable-line`
                     if text.trim().is_empty() {
                         self.add_interval(start, stop, DisabledRule::All { comment_span });
-                        self.disable_rule_comments.push(DisableRuleComment {
-                            span: comment_span,
-                            r#type: RuleCommentType::All,
-                        });
+                        self.push_disable_rule_comment(
+                            DisableRuleComment { span: comment_span, r#type: RuleCommentType::All },
+                            description,
+                        );
                     } else {
                         // `eslint-disable-line rule-name1, rule-name2`
                         let mut rules = vec![];
@@ -352,10 +505,13 @@ impl<'a> DisableDirectivesBuilder<'a> {
                             );
                             rules.push(RuleCommentRule { rule_name, name_span });
                         });
-                        self.disable_rule_comments.push(DisableRuleComment {
-                            span: comment_span,
-                            r#type: RuleCommentType::Single(rules),
-                        });
+                        self.push_disable_rule_comment(
+                            DisableRuleComment {
+                                span: comment_span,
+                                r#type: RuleCommentType::Single(rules),
+                            },
+                            description,
+                        );
                     }
                     continue;
                 }
@@ -372,10 +528,13 @@ impl<'a> DisableDirectivesBuilder<'a> {
                         ));
                         rules.push(RuleCommentRule { rule_name, name_span });
                     });
-                    self.disable_rule_comments.push(DisableRuleComment {
-                        span: comment_span,
-                        r#type: RuleCommentType::Single(rules),
-                    });
+                    self.push_disable_rule_comment(
+                        DisableRuleComment {
+                            span: comment_span,
+                            r#type: RuleCommentType::Single(rules),
+                        },
+                        description,
+                    );
                     continue;
                 }
             }
@@ -384,6 +543,7 @@ impl<'a> DisableDirectivesBuilder<'a> {
                 text.strip_prefix("eslint-enable").or_else(|| text.strip_prefix("oxlint-enable"))
             {
                 rule_name_start += 13; // eslint-enable is 13 bytes
+                let (text, _) = Self::split_description(text, comment_span.end);
                 // `eslint-enable`
                 if text.trim().is_empty() {
                     if let Some((start, _)) = self.disable_all_start.take() {
@@ -433,6 +593,40 @@ impl<'a> DisableDirectivesBuilder<'a> {
         self.unused_enable_comments = unused_enable_directives;
     }
 
+    /// Split the text after `eslint-disable` into the rule names and the description after `--`.
+    ///
+    /// `text_end` is the end of `text` in the source.
+    #[expect(clippy::cast_possible_truncation)] // for `as u32`
+    fn split_description(text: &'a str, text_end: u32) -> (&'a str, DirectiveDescription<'a>) {
+        let Some((rules, description)) = text.split_once("--") else {
+            return (text, DirectiveDescription::default());
+        };
+        let description_start = text_end - description.len() as u32;
+
+        let expiry = description.find("(expires:").and_then(|start| {
+            let end = start + description[start..].find(')')? + 1;
+            let text = description[start + "(expires:".len()..end - 1].trim();
+            let span = Span::new(description_start + start as u32, description_start + end as u32);
+            Some((start, end, DirectiveExpiry { text, date: DirectiveDate::parse(text), span }))
+        });
+
+        // The reason is the text before the expiry, or after when there is nothing before it.
+        let reason = match expiry {
+            Some((start, end, _)) => Some(description[..start].trim())
+                .filter(|reason| !reason.is_empty())
+                .unwrap_or_else(|| description[end..].trim()),
+            None => description.trim(),
+        };
+
+        (
+            rules,
+            DirectiveDescription {
+                reason: Some(reason).filter(|reason| !reason.is_empty()),
+                expiry: expiry.map(|(_, _, expiry)| expiry),
+            },
+        )
+    }
+
     #[expect(clippy::cast_possible_truncation)] // for `as u32`
     fn get_rule_names<F: FnMut(&'a str, Span)>(text: &'a str, rule_name_start: u32, mut cb: F) {
         if let Some(text) = text.split_terminator("--").next() {
@@ -671,7 +865,20 @@ fn test() {
                 /* {prefix}-disable , ,no-debugger, , */
                 debugger;
             "),
-            format!("debugger;//{prefix}-disable-line")
+            format!("debugger;//{prefix}-disable-line"),
+            // Reasons and expiry dates
+            format!(
+                "
+            debugger; // {prefix}-disable-line no-debugger -- reason
+            debugger; // {prefix}-disable-line -- reason
+
+            // {prefix}-disable-next-line no-debugger -- reason (expires: 2999-12-31)
+            debugger;
+
+            /* {prefix}-disable-next-line no-debugger -- (expires: 2999-12-31) reason */
+            debugger;
+        "
+            ),
         ];
 
         let fail = vec![
@@ -845,6 +1052,18 @@ semi*/
   \t\t quotes,  \t\t
   \t\t semi  \t\t */
             debugger;
+        "
+            ),
+            // Expired directives don't disable rules
+            format!(
+                "
+            // {prefix}-disable-next-line no-debugger -- reason (expires: 2000-01-01)
+            debugger;
+        "
+            ),
+            format!(
+                "
+            debugger; /* {prefix}-disable-line -- (expires: 2000-01-01) */
         "
             ),
         ];
@@ -863,7 +1082,9 @@ mod tests {
     use oxc_semantic::{Semantic, SemanticBuilder};
     use oxc_span::{SourceType, Span};
 
-    use crate::disable_directives::{DisabledRule, RuleCommentRule, RuleCommentType};
+    use crate::disable_directives::{
+        DirectiveDate, DisabledRule, RuleCommentRule, RuleCommentType,
+    };
 
     use super::{DisableDirectives, DisableDirectivesBuilder};
 
@@ -1063,6 +1284,91 @@ mod tests {
         );
     }
 
+    #[test]
+    fn suppression_reasons() {
+        test_directives(
+            |prefix| {
+                format!(
+                    r"
+                    // {prefix}-disable-next-line no-debugger -- needed for tests
+                    debugger;
+                    /* {prefix}-disable no-console, no-alert -- legacy code (expires: 2999-12-31) */
+                    // {prefix}-disable-next-line -- (expires: 2999-12-31) generated
+                    // {prefix}-disable-line no-console
+                    // {prefix}-disable-line -- (expires: 2025-02-30)
+                    "
+                )
+            },
+            |comments, directives| {
+                let suppressions = directives.suppressions();
+                assert_eq!(suppressions.len(), 5);
+
+                assert_eq!(suppressions[0].reason, Some("needed for tests"));
+                assert_eq!(suppressions[0].expiry, None);
+                assert!(matches!(
+                    &suppressions[0].comment.r#type,
+                    RuleCommentType::Single(rules) if rules.len() == 1 && rules[0].rule_name == "no-debugger"
+                ));
+
+                assert_eq!(suppressions[1].reason, Some("legacy code"));
+                let expiry = suppressions[1].expiry.unwrap();
+                assert_eq!(expiry.text, "2999-12-31");
+                assert_eq!(expiry.date, DirectiveDate::parse("2999-12-31"));
+                // `(expires: 2999-12-31) ` is at the end of the comment
+                let comment_end = comments[1].content_span().end;
+                assert_eq!(expiry.span, Span::new(comment_end - 22, comment_end - 1));
+                assert!(!suppressions[1].expired);
+                assert!(matches!(
+                    &suppressions[1].comment.r#type,
+                    RuleCommentType::Single(rules) if rules.len() == 2
+                ));
+
+                assert_eq!(suppressions[2].reason, Some("generated"));
+                assert_eq!(suppressions[2].comment.r#type, RuleCommentType::All);
+
+                assert_eq!(suppressions[3].reason, None);
+                assert_eq!(suppressions[3].expiry, None);
+
+                assert_eq!(suppressions[4].reason, None);
+                assert_eq!(suppressions[4].expiry.unwrap().date, None);
+            },
+        );
+    }
+
+    #[test]
+    fn expired_directives() {
+        test_directives(
+            |prefix| {
+                format!(
+                    r"
+                    // {prefix}-disable-next-line no-debugger -- reason (expires: 2000-01-01)
+                    debugger;
+                    "
+                )
+            },
+            |_, directives| {
+                assert!(directives.suppressions()[0].expired);
+                assert!(directives.intervals.is_empty());
+                assert!(directives.collect_unused_disable_comments().is_empty());
+            },
+        );
+    }
+
+    #[test]
+    fn directive_date() {
+        assert!(DirectiveDate::parse("2024-02-29").is_some());
+        assert!(DirectiveDate::parse("2025-02-29").is_none());
+        assert!(DirectiveDate::parse("2025-13-01").is_none());
+        assert!(DirectiveDate::parse("2025-1-01").is_none());
+        assert!(DirectiveDate::parse("tomorrow").is_none());
+        assert_eq!(DirectiveDate::parse("2025-12-31").unwrap().to_string(), "2025-12-31");
+        assert!(DirectiveDate::parse("2025-01-31") < DirectiveDate::parse("2025-02-01"));
+
+        let today = DirectiveDate::today();
+        assert_eq!(DirectiveDate::parse(&today.to_string()), Some(today));
+        assert!(DirectiveDate::parse("2025-01-01").unwrap() < today);
+    }
+
     #[test]
     fn next_line_span_of_line_comment() {
         test_directive_span("// eslint-disable-next-line max-params", 38, 38);
@@ -1,6 +1,6 @@
 use std::borrow::Cow;
 
-use oxc_codegen::{Codegen, CodegenOptions};
+use oxc_codegen::{Codegen, CodegenOptions, QuoteStyle};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_span::{GetSpan, Span};
 
@@ -12,6 +12,8 @@ use crate::service::offset_to_position::SpanPositionMessage;
 pub use fix::{FixWithPosition, PossibleFixesWithPosition};
 #[cfg(feature = "language_server")]
 use oxc_diagnostics::{OxcCode, Severity};
+#[cfg(feature = "language_server")]
+use std::path::PathBuf;
 
 mod fix;
 pub use fix::{CompositeFix, Fix, FixKind, PossibleFixes, RuleFix};
@@ -186,9 +188,10 @@ impl<'c, 'a: 'c> RuleFixer<'c, 'a> {
 
     #[must_use]
     pub fn codegen(self) -> Codegen<'a> {
-        Codegen::new()
-            .with_source_text(self.source_text())
-            .with_options(CodegenOptions { single_quote: true, ..CodegenOptions::default() })
+        Codegen::new().with_source_text(self.source_text()).with_options(CodegenOptions {
+            quote_style: QuoteStyle::Single,
+            ..CodegenOptions::default()
+        })
     }
 
     pub fn noop(&self) -> RuleFix<'a> {
@@ -242,6 +245,16 @@ pub struct MessageWithPosition<'a> {
     pub code: OxcCode,
     pub url: Option<Cow<'a, str>>,
     pub fixes: PossibleFixesWithPosition<'a>,
+    /// Labels in other files, see [`oxc_diagnostics::RelatedInformation`].
+    pub related: Option<Vec<RelatedLabelWithPosition<'a>>>,
+}
+
+/// A label in another file than the one a [`MessageWithPosition`] is reported in.
+#[cfg(feature = "language_server")]
+#[derive(Debug)]
+pub struct RelatedLabelWithPosition<'a> {
+    pub path: PathBuf,
+    pub label: SpanPositionMessage<'a>,
 }
 
 #[cfg(feature = "language_server")]
@@ -255,6 +268,7 @@ impl From<OxcDiagnostic> for MessageWithPosition<'_> {
             code: from.code.clone(),
             url: from.url.clone(),
             fixes: PossibleFixesWithPosition::None,
+            related: None,
         }
     }
 }
@@ -339,7 +353,8 @@ impl<'a> Fixer<'a> {
             };
         }
 
-        self.messages.sort_unstable_by_key(|m| m.fixes.span());
+        // Stable sort, so that of the fixes with the same span, the one reported first is applied.
+        self.messages.sort_by_key(|m| m.fixes.span());
         let mut fixed = false;
         let mut output = String::with_capacity(source_text.len());
         let mut last_pos: i64 = -1;
@@ -672,6 +687,26 @@ mod test {
         assert_eq!(result1.fixed_code, result2.fixed_code);
     }
 
+    #[test]
+    fn apply_first_reported_fix_when_spans_are_the_same() {
+        let replace_id_with_bar =
+            Fix { span: REPLACE_ID.span, content: Cow::Borrowed("bar"), message: None };
+
+        let result = get_fix_result(vec![
+            create_message(replace_id(), PossibleFixes::Single(replace_id_with_bar.clone())),
+            create_message(replace_id(), PossibleFixes::Single(REPLACE_ID)),
+        ]);
+        assert_eq!(result.fixed_code, TEST_CODE.cow_replace("answer", "bar"));
+        assert_eq!(result.messages.len(), 1);
+
+        let result = get_fix_result(vec![
+            create_message(replace_id(), PossibleFixes::Single(REPLACE_ID)),
+            create_message(replace_id(), PossibleFixes::Single(replace_id_with_bar)),
+        ]);
+        assert_eq!(result.fixed_code, TEST_CODE.cow_replace("answer", "foo"));
+        assert_eq!(result.messages.len(), 1);
+    }
+
     #[test]
     fn should_not_apply_fix_with_one_no_fix() {
         let result =
@@ -11,6 +11,8 @@ mod disable_directives;
 mod fixer;
 mod frameworks;
 mod globals;
+mod locales;
+mod module_format;
 mod module_graph_visitor;
 mod module_record;
 mod options;
@@ -24,24 +26,27 @@ pub mod table;
 
 use std::{path::Path, rc::Rc, sync::Arc};
 
+use oxc_diagnostics::MessageCatalog;
 use oxc_semantic::{AstNode, Semantic};
 
 pub use crate::{
     config::{
         Config, ConfigBuilderError, ConfigStore, ConfigStoreBuilder, ESLintRule, LintPlugins,
-        Oxlintrc,
+        Oxlintrc, PromotionStatus,
     },
     context::LintContext,
     fixer::FixKind,
     frameworks::FrameworkFlags,
     loader::LINTABLE_EXTENSIONS,
+    locales::message_catalog,
+    module_format::ModuleFormat,
     module_record::ModuleRecord,
     options::LintOptions,
     options::{AllowWarnDeny, InvalidFilterKind, LintFilter, LintFilterKind},
-    rule::{RuleCategory, RuleFixMeta, RuleMeta},
-    service::{LintService, LintServiceOptions, RuntimeFileSystem},
-    utils::read_to_arena_str,
+    rule::{RuleCategory, RuleFixMeta, RuleMeta, RuleRequirements},
+    service::{LintService, LintServiceOptions, ResolutionCache, RuntimeFileSystem},
     utils::read_to_string,
+    utils::{is_generated_source, read_to_arena_str},
 };
 use crate::{
     config::{LintConfig, OxlintEnv, OxlintGlobals, OxlintSettings, ResolvedLinterState},
@@ -52,7 +57,9 @@ use crate::{
 };
 
 #[cfg(feature = "language_server")]
-pub use crate::fixer::{FixWithPosition, MessageWithPosition, PossibleFixesWithPosition};
+pub use crate::fixer::{
+    FixWithPosition, MessageWithPosition, PossibleFixesWithPosition, RelatedLabelWithPosition,
+};
 
 #[cfg(target_pointer_width = "64")]
 #[test]
@@ -88,6 +95,27 @@ impl Linter {
         self
     }
 
+    /// Report disable directives without a reason, like `// oxlint-disable-line no-debugger`.
+    #[must_use]
+    pub fn with_require_directive_reasons(mut self, yes: bool) -> Self {
+        self.options.require_directive_reasons = yes;
+        self
+    }
+
+    /// Report every disable directive with its reason and expiry date, for auditing.
+    #[must_use]
+    pub fn with_report_suppressions(mut self, yes: bool) -> Self {
+        self.options.report_suppressions = yes;
+        self
+    }
+
+    /// Translate the diagnostics of the rules with `catalog`, see [`message_catalog`].
+    #[must_use]
+    pub fn with_message_catalog(mut self, catalog: Option<&'static MessageCatalog>) -> Self {
+        self.options.message_catalog = catalog;
+        self
+    }
+
     pub(crate) fn options(&self) -> &LintOptions {
         &self.options
     }
@@ -99,6 +127,13 @@ impl Linter {
         self.config.number_of_rules()
     }
 
+    /// The analyses needed by the rules which are enabled for the file at `path`.
+    ///
+    /// The semantic passed to [`Linter::run`] must be built with them.
+    pub fn requirements(&self, path: &Path) -> RuleRequirements {
+        self.config.resolve(path).requirements()
+    }
+
     pub fn run<'a>(
         &self,
         path: &Path,
@@ -191,6 +226,8 @@ impl Linter {
             }
         }
 
+        ctx_host.report_suppressions(&self.options);
+
         ctx_host.take_diagnostics()
     }
 }
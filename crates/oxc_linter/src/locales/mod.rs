@@ -0,0 +1,96 @@
+//! Translations of the diagnostics of the rules.
+//!
+//! Every catalog is checked against the rules by the tests below: each error code must belong to
+//! a rule, and each English text must appear in the source of that rule.
+
+use std::sync::LazyLock;
+
+use cow_utils::CowUtils;
+use oxc_diagnostics::MessageCatalog;
+
+/// The locales with translations, and their catalogs, see [`MessageCatalog`].
+const CATALOGS: &[(&str, &str)] = &[("de", include_str!("de.json"))];
+
+static MESSAGE_CATALOGS: LazyLock<Vec<MessageCatalog>> = LazyLock::new(|| {
+    CATALOGS
+        .iter()
+        .map(|(locale, json)| {
+            MessageCatalog::from_json(locale, json).unwrap_or_else(|err| panic!("{}", err.message))
+        })
+        .collect()
+});
+
+/// The translations for `locale`, e.g. `de`, `de-AT` or `de_AT.UTF-8`.
+///
+/// Falls back to the language of `locale`, returns `None` for English and for locales without
+/// translations.
+pub fn message_catalog(locale: &str) -> Option<&'static MessageCatalog> {
+    let locale = locale.split('.').next().unwrap_or(locale).cow_replace('_', "-");
+    let language = locale.split('-').next().unwrap_or(&locale);
+    let find = |locale: &str| {
+        MESSAGE_CATALOGS.iter().find(|catalog| catalog.locale().eq_ignore_ascii_case(locale))
+    };
+    find(&locale).or_else(|| find(language))
+}
+
+#[cfg(test)]
+mod test {
+    use std::{fs, path::Path};
+
+    use cow_utils::CowUtils;
+    use oxc_diagnostics::MessageCatalog;
+
+    use super::{CATALOGS, message_catalog};
+    use crate::{context::plugin_name_to_prefix, rules::RULES};
+
+    #[test]
+    fn test_message_catalog() {
+        assert_eq!(message_catalog("de").unwrap().locale(), "de");
+        assert_eq!(message_catalog("de-AT").unwrap().locale(), "de");
+        assert_eq!(message_catalog("DE_at.UTF-8").unwrap().locale(), "de");
+        assert!(message_catalog("en").is_none());
+        assert!(message_catalog("en-US").is_none());
+        assert!(message_catalog("").is_none());
+    }
+
+    /// The catalogs must stay in sync with the rules: a renamed rule or reworded message must be
+    /// updated in the catalogs too.
+    #[test]
+    fn test_catalogs_in_sync_with_rules() {
+        let rules_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("src/rules");
+        for (locale, json) in CATALOGS {
+            let catalog = MessageCatalog::from_json(locale, json).unwrap();
+            for code in catalog.codes() {
+                let rule = RULES.iter().find(|rule| {
+                    format!("{}({})", plugin_name_to_prefix(rule.plugin_name()), rule.name())
+                        == code
+                });
+                let Some(rule) = rule else {
+                    panic!("{locale}.json: {code} is not a rule");
+                };
+                let path = rules_dir
+                    .join(rule.plugin_name())
+                    .join(rule.name().cow_replace('-', "_").as_ref());
+                let source = rule_source(&path);
+                for text in catalog.sources(code) {
+                    assert!(
+                        source.contains(&text) || source.contains(&text.escape_debug().to_string()),
+                        "{locale}.json: {text:?} is not a message of {code}"
+                    );
+                }
+            }
+        }
+    }
+
+    /// The source of a rule in `{path}.rs` or `{path}/*.rs`.
+    fn rule_source(path: &Path) -> String {
+        if let Ok(source) = fs::read_to_string(path.with_extension("rs")) {
+            return source;
+        }
+        fs::read_dir(path)
+            .unwrap()
+            .flatten()
+            .filter_map(|entry| fs::read_to_string(entry.path()).ok())
+            .collect()
+    }
+}
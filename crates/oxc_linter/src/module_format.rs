@@ -0,0 +1,98 @@
+use std::path::Path;
+
+use oxc_resolver::{PackageJson, PackageType, Resolver};
+
+/// How Node.js loads a file: as a CommonJS module, with `require`, `module` and `exports`, or as
+/// an ES module.
+///
+/// This is not the goal the file is parsed with, see [`SourceType`](oxc_span::SourceType):
+/// files with ESM syntax are often compiled to CommonJS by a bundler or by `tsc`.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ModuleFormat {
+    /// `.cjs` and `.cts` files, and the other files of a package with `"type": "commonjs"`.
+    CommonJs,
+    /// `.mjs` and `.mts` files, and the other files of a package with `"type": "module"`.
+    Module,
+    /// Files of a package without a `"type"`, or outside of any package.
+    ///
+    /// Node.js loads them as CommonJS, but they are often ES modules for a bundler.
+    #[default]
+    Ambiguous,
+}
+
+impl ModuleFormat {
+    /// The format of the file at `path`, from its extension only.
+    pub fn from_path(path: &Path) -> Self {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("cjs" | "cts") => Self::CommonJs,
+            Some("mjs" | "mts") => Self::Module,
+            _ => Self::Ambiguous,
+        }
+    }
+
+    /// The format of the file at `path`, from its extension, or from the `"type"` of the closest
+    /// `package.json` as found by `resolver`, which caches the `package.json` files.
+    pub(crate) fn resolve(path: &Path, resolver: &Resolver) -> Self {
+        let format = Self::from_path(path);
+        if format != Self::Ambiguous {
+            return format;
+        }
+        let (Some(dir), Some(file_name)) = (path.parent(), path.file_name()) else {
+            return format;
+        };
+        let Ok(resolution) = resolver.resolve(dir, &format!("./{}", file_name.to_string_lossy()))
+        else {
+            return format;
+        };
+        match resolution.package_json().and_then(|package_json| package_json.r#type()) {
+            Some(PackageType::CommonJs) => Self::CommonJs,
+            Some(PackageType::Module) => Self::Module,
+            None => format,
+        }
+    }
+
+    #[inline]
+    pub fn is_commonjs(self) -> bool {
+        self == Self::CommonJs
+    }
+
+    #[inline]
+    pub fn is_module(self) -> bool {
+        self == Self::Module
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::{env, path::Path};
+
+    use oxc_resolver::{ResolveOptions, Resolver};
+
+    use super::ModuleFormat;
+
+    #[test]
+    fn from_path() {
+        assert_eq!(ModuleFormat::from_path(Path::new("a.cjs")), ModuleFormat::CommonJs);
+        assert_eq!(ModuleFormat::from_path(Path::new("a.cts")), ModuleFormat::CommonJs);
+        assert_eq!(ModuleFormat::from_path(Path::new("a.mjs")), ModuleFormat::Module);
+        assert_eq!(ModuleFormat::from_path(Path::new("a.d.mts")), ModuleFormat::Module);
+        assert_eq!(ModuleFormat::from_path(Path::new("a.js")), ModuleFormat::Ambiguous);
+        assert_eq!(ModuleFormat::from_path(Path::new("a.tsx")), ModuleFormat::Ambiguous);
+    }
+
+    #[test]
+    fn resolve_package_type() {
+        let fixtures = env::current_dir().unwrap().join("fixtures/module_format");
+        let resolver = Resolver::new(ResolveOptions::default());
+        let resolve = |path: &str| ModuleFormat::resolve(&fixtures.join(path), &resolver);
+
+        assert_eq!(resolve("commonjs/index.js"), ModuleFormat::CommonJs);
+        assert_eq!(resolve("commonjs/index.mjs"), ModuleFormat::Module);
+        assert_eq!(resolve("module/index.ts"), ModuleFormat::Module);
+        assert_eq!(resolve("module/index.cjs"), ModuleFormat::CommonJs);
+        // The closest `package.json` has no `type`.
+        assert_eq!(resolve("module/untyped/index.js"), ModuleFormat::Ambiguous);
+        // The file does not exist.
+        assert_eq!(resolve("module/missing.js"), ModuleFormat::Ambiguous);
+    }
+}
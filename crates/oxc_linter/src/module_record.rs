@@ -12,6 +12,8 @@ use oxc_semantic::Semantic;
 use oxc_span::{CompactStr, Span};
 pub use oxc_syntax::module_record::RequestedModule;
 
+use crate::ModuleFormat;
+
 /// ESM Module Record
 ///
 /// All data inside this data structure are for ESM, no commonjs data is allowed.
@@ -27,6 +29,9 @@ pub struct ModuleRecord {
     /// Resolved absolute path to this module record
     pub resolved_absolute_path: PathBuf,
 
+    /// How Node.js loads this module, from its extension or its `package.json`.
+    pub module_format: ModuleFormat,
+
     /// `[[RequestedModules]]`
     ///
     /// A List of all the ModuleSpecifier strings used by the module represented by this record to request the importation of a module. The List is in source text occurrence order.
@@ -100,6 +105,7 @@ impl fmt::Debug for ModuleRecord {
         f.debug_struct("ModuleRecord")
             .field("has_module_syntax", &self.has_module_syntax)
             .field("resolved_absolute_path", &self.resolved_absolute_path)
+            .field("module_format", &self.module_format)
             .field("requested_modules", &self.requested_modules)
             .field("loaded_modules", &loaded_modules)
             .field("import_entries", &self.import_entries)
@@ -460,6 +466,7 @@ impl ModuleRecord {
         Self {
             has_module_syntax: other.has_module_syntax,
             resolved_absolute_path: path.to_path_buf(),
+            module_format: ModuleFormat::from_path(path),
             requested_modules: other
                 .requested_modules
                 .iter()
@@ -4,6 +4,8 @@ mod filter;
 pub use allow_warn_deny::AllowWarnDeny;
 pub use filter::{InvalidFilterKind, LintFilter, LintFilterKind};
 
+use oxc_diagnostics::MessageCatalog;
+
 use crate::{FrameworkFlags, fixer::FixKind};
 
 /// Subset of options used directly by the linter.
@@ -13,4 +15,10 @@ pub struct LintOptions {
     pub fix: FixKind,
     pub framework_hints: FrameworkFlags,
     pub report_unused_directive: Option<AllowWarnDeny>,
+    /// Report disable directives without a reason after `--`.
+    pub require_directive_reasons: bool,
+    /// Report every disable directive, with its reason, as an advice.
+    pub report_suppressions: bool,
+    /// Translations of the diagnostics of the rules, `None` for English.
+    pub message_catalog: Option<&'static MessageCatalog>,
 }
@@ -2,6 +2,7 @@
 use std::borrow::Cow;
 use std::{fmt, hash::Hash};
 
+use bitflags::bitflags;
 use oxc_semantic::SymbolId;
 use schemars::{JsonSchema, SchemaGenerator, schema::Schema};
 use serde::{Deserialize, Serialize};
@@ -75,6 +76,9 @@ pub trait RuleMeta {
     /// What kind of auto-fixing can this rule do?
     const FIX: RuleFixMeta = RuleFixMeta::None;
 
+    /// Which analyses, beyond the AST, scopes, symbols and module record, does this rule need?
+    const REQUIREMENTS: RuleRequirements = RuleRequirements::empty();
+
     fn documentation() -> Option<&'static str> {
         None
     }
@@ -85,6 +89,24 @@ pub trait RuleMeta {
     }
 }
 
+bitflags! {
+    /// Analyses which are only built when an enabled rule needs them.
+    ///
+    /// The AST, scopes, symbols and module record of a file are always built.
+    /// Declared with `requires = ...` in [`declare_oxc_lint!`](oxc_macros::declare_oxc_lint).
+    #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct RuleRequirements: u8 {
+        /// The control flow graph, see [`LintContext::cfg`].
+        const CFG = 1 << 0;
+        /// JSDoc comments attached to nodes, see [`oxc_semantic::Semantic::jsdoc`].
+        const JSDOC = 1 << 1;
+        /// The patterns of regular expression literals, parsed into an AST.
+        ///
+        /// Without it, syntax errors in regular expression literals are not reported either.
+        const REGEX = 1 << 2;
+    }
+}
+
 /// Rule categories defined by rust-clippy
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema)]
 #[serde(rename_all = "lowercase")]
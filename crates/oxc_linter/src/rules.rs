@@ -293,7 +293,9 @@ mod react {
     pub mod jsx_curly_brace_presence;
     pub mod jsx_filename_extension;
     pub mod jsx_key;
+    pub mod jsx_no_bind;
     pub mod jsx_no_comment_textnodes;
+    pub mod jsx_no_constructed_context_values;
     pub mod jsx_no_duplicate_props;
     pub mod jsx_no_script_url;
     pub mod jsx_no_target_blank;
@@ -313,6 +315,7 @@ mod react {
     pub mod no_string_refs;
     pub mod no_unescaped_entities;
     pub mod no_unknown_property;
+    pub mod no_unstable_nested_components;
     pub mod prefer_es6_class;
     pub mod react_in_jsx_scope;
     pub mod require_render_return;
@@ -427,6 +430,7 @@ mod unicorn {
     pub mod prefer_string_starts_ends_with;
     pub mod prefer_string_trim_start_end;
     pub mod prefer_structured_clone;
+    pub mod prefer_ternary;
     pub mod prefer_type_error;
     pub mod require_array_join_separator;
     pub mod require_number_to_fixed_digits_argument;
@@ -451,6 +455,7 @@ mod jsx_a11y {
     pub mod html_has_lang;
     pub mod iframe_has_title;
     pub mod img_redundant_alt;
+    pub mod interactive_supports_focus;
     pub mod label_has_associated_control;
     pub mod lang;
     pub mod media_has_caption;
@@ -459,8 +464,10 @@ mod jsx_a11y {
     pub mod no_aria_hidden_on_focusable;
     pub mod no_autofocus;
     pub mod no_distracting_elements;
+    pub mod no_noninteractive_element_to_interactive_role;
     pub mod no_noninteractive_tabindex;
     pub mod no_redundant_roles;
+    pub mod no_static_element_interactions;
     pub mod prefer_tag_over_role;
     pub mod role_has_required_aria_props;
     pub mod role_supports_aria_props;
@@ -490,6 +497,7 @@ mod oxc {
     pub mod no_map_spread;
     pub mod no_optional_chaining;
     pub mod no_rest_spread_properties;
+    pub mod no_unsafe_regex;
     pub mod number_arg_out_of_range;
     pub mod only_used_in_recursion;
     pub mod uninvoked_array_callback;
@@ -824,16 +832,19 @@ oxc_macros::declare_all_lint_rules! {
     jsx_a11y::html_has_lang,
     jsx_a11y::iframe_has_title,
     jsx_a11y::img_redundant_alt,
+    jsx_a11y::interactive_supports_focus,
     jsx_a11y::label_has_associated_control,
     jsx_a11y::lang,
     jsx_a11y::media_has_caption,
     jsx_a11y::mouse_events_have_key_events,
+    jsx_a11y::no_noninteractive_element_to_interactive_role,
     jsx_a11y::no_noninteractive_tabindex,
     jsx_a11y::no_access_key,
     jsx_a11y::no_aria_hidden_on_focusable,
     jsx_a11y::no_autofocus,
     jsx_a11y::no_distracting_elements,
     jsx_a11y::no_redundant_roles,
+    jsx_a11y::no_static_element_interactions,
     jsx_a11y::prefer_tag_over_role,
     jsx_a11y::role_has_required_aria_props,
     jsx_a11y::role_supports_aria_props,
@@ -883,6 +894,7 @@ oxc_macros::declare_all_lint_rules! {
     oxc::no_map_spread,
     oxc::no_optional_chaining,
     oxc::no_rest_spread_properties,
+    oxc::no_unsafe_regex,
     oxc::number_arg_out_of_range,
     oxc::only_used_in_recursion,
     oxc::uninvoked_array_callback,
@@ -910,7 +922,9 @@ oxc_macros::declare_all_lint_rules! {
     react::jsx_boolean_value,
     react::jsx_curly_brace_presence,
     react::jsx_key,
+    react::jsx_no_bind,
     react::jsx_no_comment_textnodes,
+    react::jsx_no_constructed_context_values,
     react::jsx_no_duplicate_props,
     react::jsx_no_script_url,
     react::jsx_no_target_blank,
@@ -930,6 +944,7 @@ oxc_macros::declare_all_lint_rules! {
     react::no_string_refs,
     react::no_unescaped_entities,
     react::no_unknown_property,
+    react::no_unstable_nested_components,
     react::prefer_es6_class,
     react::react_in_jsx_scope,
     react::require_render_return,
@@ -1080,6 +1095,7 @@ oxc_macros::declare_all_lint_rules! {
     unicorn::prefer_string_starts_ends_with,
     unicorn::prefer_string_trim_start_end,
     unicorn::prefer_structured_clone,
+    unicorn::prefer_ternary,
     unicorn::prefer_type_error,
     unicorn::require_post_message_target_origin,
     unicorn::require_array_join_separator,
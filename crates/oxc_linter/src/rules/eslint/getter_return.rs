@@ -79,7 +79,8 @@ declare_oxc_lint!(
     /// ```
     GetterReturn,
     eslint,
-    nursery
+    nursery,
+    requires = cfg
 );
 
 impl Rule for GetterReturn {
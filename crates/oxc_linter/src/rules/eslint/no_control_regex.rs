@@ -123,7 +123,8 @@ declare_oxc_lint!(
     /// ```
     NoControlRegex,
     eslint,
-    correctness
+    correctness,
+    requires = regex
 );
 
 impl Rule for NoControlRegex {
@@ -39,7 +39,8 @@ declare_oxc_lint!(
     NoDivRegex,
     eslint,
     restriction,
-    fix
+    fix,
+    requires = regex
 );
 
 impl Rule for NoDivRegex {
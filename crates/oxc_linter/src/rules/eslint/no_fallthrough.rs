@@ -242,7 +242,8 @@ declare_oxc_lint!(
     eslint,
     // TODO: add options section to docs
     pedantic, // Fall through code are still incorrect.
-    pending // TODO: add a dangerous suggestion for this rule.
+    pending, // TODO: add a dangerous suggestion for this rule.
+    requires = cfg
 );
 
 impl Rule for NoFallthrough {
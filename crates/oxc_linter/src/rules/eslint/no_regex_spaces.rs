@@ -47,7 +47,8 @@ declare_oxc_lint!(
     NoRegexSpaces,
     eslint,
     restriction,
-    pending // TODO: This is somewhat autofixable, but the fixer does not exist yet.
+    pending, // TODO: This is somewhat autofixable, but the fixer does not exist yet.
+    requires = regex
 );
 
 impl Rule for NoRegexSpaces {
@@ -47,7 +47,8 @@ declare_oxc_lint!(
     /// ```
     NoThisBeforeSuper,
     eslint,
-    correctness
+    correctness,
+    requires = cfg
 );
 
 #[derive(Default, Copy, Clone, Debug)]
@@ -88,6 +88,8 @@ fn has_typeof_operator(node: &AstNode<'_>, ctx: &LintContext<'_>) -> bool {
 
 #[test]
 fn test() {
+    use std::path::PathBuf;
+
     use crate::tester::Tester;
 
     let pass = vec![
@@ -220,4 +222,15 @@ fn test() {
     let fail = vec![("foo", None, Some(serde_json::json!({ "globals": { "foo": "off" } })))];
 
     Tester::new(NoUndef::NAME, NoUndef::PLUGIN, pass, fail).test();
+
+    // `module`, `exports` and `require` are globals of CommonJS files
+    let pass = vec![(
+        "module.exports = { a: require('a') }; exports.b = 1;",
+        None,
+        None,
+        Some(PathBuf::from("foo.cjs")),
+    )];
+    let fail = vec![("module.exports = {};", None, None, Some(PathBuf::from("foo.mjs")))];
+
+    Tester::new(NoUndef::NAME, NoUndef::PLUGIN, pass, fail).test();
 }
@@ -48,7 +48,8 @@ declare_oxc_lint!(
     /// ```
     NoUnreachable,
     eslint,
-    nursery
+    nursery,
+    requires = cfg
 );
 
 impl Rule for NoUnreachable {
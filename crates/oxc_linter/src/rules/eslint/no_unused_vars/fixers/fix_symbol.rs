@@ -1,4 +1,5 @@
-use oxc_ast::{AstKind, ast::*};
+use oxc_ast::ast::*;
+use oxc_semantic::Renamer;
 use oxc_span::{CompactStr, GetSpan, Span};
 
 use super::Symbol;
@@ -52,24 +53,16 @@ impl<'s, 'a> Symbol<'s, 'a> {
         fixer.delete(&delete_range)
     }
 
+    /// Rename the symbol and its references, unless `new_name` would conflict with another
+    /// symbol or a global.
     pub(super) fn rename(&self, new_name: &CompactStr) -> RuleFix<'a> {
-        let mut fixes: Vec<Fix<'a>> = vec![];
-        let decl_span = self.span();
-        fixes.push(Fix::new(new_name.clone(), decl_span));
-
-        for reference in self.references() {
-            match self.nodes().get_node(reference.node_id()).kind() {
-                AstKind::IdentifierReference(id) => {
-                    fixes.push(Fix::new(new_name.clone(), id.span()));
-                }
-                AstKind::TSTypeReference(ty) => {
-                    fixes.push(Fix::new(new_name.clone(), ty.type_name.span()));
-                }
-                // we found a reference to an unknown node and we don't know how
-                // to replace it, so we abort the whole process
-                _ => return Fix::empty().into(),
-            }
-        }
+        let mut renamer = Renamer::new();
+        renamer.rename(self.id(), new_name.clone());
+        let Ok(edits) = renamer.edits(self.scoping(), self.nodes()) else {
+            return Fix::empty().into();
+        };
+        let fixes =
+            edits.into_iter().map(|edit| Fix::new(edit.text, edit.span)).collect::<Vec<_>>();
 
         RuleFix::from(fixes).with_message(format!("Rename '{}' to '{new_name}'", self.name()))
     }
@@ -155,6 +155,13 @@ fn test_vars_simple() {
         ),
         // type annotations do not get clobbered
         ("let x: number = 1; x = 2;", "let _x: number = 1; _x = 2;", None, FixKind::DangerousFix),
+        // vars are not renamed to a name which is already taken
+        (
+            "let _x = 0; let x = 1; x = 2; f(_x);",
+            "let _x = 0; let x = 1; x = 2; f(_x);",
+            None,
+            FixKind::DangerousFix,
+        ),
     ];
 
     Tester::new(NoUnusedVars::NAME, NoUnusedVars::PLUGIN, pass, fail)
@@ -64,7 +64,8 @@ declare_oxc_lint!(
     /// ```
     NoUselessBackreference,
     eslint,
-    correctness
+    correctness,
+    requires = regex
 );
 
 impl Rule for NoUselessBackreference {
@@ -81,7 +81,8 @@ declare_oxc_lint!(
     NoUselessEscape,
     eslint,
     correctness,
-    fix
+    fix,
+    requires = regex
 );
 
 impl Rule for NoUselessEscape {
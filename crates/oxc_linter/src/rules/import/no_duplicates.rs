@@ -1,10 +1,14 @@
 use std::borrow::Cow;
 
 use itertools::Itertools;
+use oxc_ast::{
+    AstKind,
+    ast::{ImportDeclaration, ImportDeclarationSpecifier},
+};
 use oxc_diagnostics::{LabeledSpan, OxcDiagnostic};
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
-use rustc_hash::FxHashMap;
+use oxc_span::{GetSpan, Span};
+use rustc_hash::{FxHashMap, FxHashSet};
 
 use crate::{
     context::LintContext,
@@ -74,9 +78,25 @@ declare_oxc_lint!(
     /// import { c } from 'foo';      // separate type imports, unless
     /// import type { d } from 'foo'; // `preferInline` is true
     /// ```
+    ///
+    /// ### Options
+    ///
+    /// #### preferInline
+    ///
+    /// `{ type: boolean, default: false }`
+    ///
+    /// Report type imports of a module which is also imported as a value, and merge them into
+    /// inline type specifiers, e.g. `import { c, type d } from 'foo'`.
+    ///
+    /// ### Fixes
+    ///
+    /// The duplicate imports are merged into the first one. They are left as they are when
+    /// merging them would change what is imported, e.g. two different default imports, or lose
+    /// a comment.
     NoDuplicates,
     import,
-    suspicious
+    suspicious,
+    conditional_fix
 );
 
 impl Rule for NoDuplicates {
@@ -92,6 +112,15 @@ impl Rule for NoDuplicates {
     fn run_once(&self, ctx: &LintContext<'_>) {
         let module_record = ctx.module_record();
 
+        let import_declarations = ctx
+            .nodes()
+            .iter()
+            .filter_map(|node| match node.kind() {
+                AstKind::ImportDeclaration(decl) => Some((decl.source.span, decl)),
+                _ => None,
+            })
+            .collect::<FxHashMap<_, _>>();
+
         let loaded_modules = module_record.loaded_modules.read().unwrap();
         let groups = module_record
             .requested_modules
@@ -147,21 +176,216 @@ impl Rule for NoDuplicates {
             }
 
             for i in 0..4 {
-                check_duplicates(ctx, import_entries_maps.get(&i));
+                check_duplicates(ctx, import_entries_maps.get(&i), &import_declarations);
             }
         }
     }
 }
 
-fn check_duplicates(ctx: &LintContext, requested_modules: Option<&Vec<&RequestedModule>>) {
+fn check_duplicates(
+    ctx: &LintContext,
+    requested_modules: Option<&Vec<&RequestedModule>>,
+    import_declarations: &FxHashMap<Span, &ImportDeclaration>,
+) {
     if let Some(requested_modules) = requested_modules {
         if requested_modules.len() > 1 {
             let mut labels = requested_modules.iter().map(|m| m.span);
             let first = labels.next().unwrap(); // we know there is at least one
             let module_name = ctx.source_range(first).trim_matches('\'').trim_matches('"');
-            ctx.diagnostic(no_duplicates_diagnostic(module_name, first, labels));
+            let diagnostic = no_duplicates_diagnostic(module_name, first, labels);
+
+            let edits = requested_modules
+                .iter()
+                .map(|m| import_declarations.get(&m.span).copied())
+                .collect::<Option<Vec<_>>>()
+                .and_then(|mut imports| {
+                    imports.sort_unstable_by_key(|decl| decl.span.start);
+                    merge_imports(ctx, &imports)
+                });
+            match edits {
+                Some(edits) => ctx.diagnostic_with_fix(diagnostic, |fixer| {
+                    let fixer = fixer.for_multifix();
+                    let mut fix = fixer.new_fix_with_capacity(edits.len());
+                    for (span, text) in edits {
+                        fix.push(fixer.replace(span, text));
+                    }
+                    fix.with_message("Merge the imports into the first one")
+                }),
+                None => ctx.diagnostic(diagnostic),
+            }
+        }
+    }
+}
+
+/// The edits which merge `imports` into the first of them.
+///
+/// Returns `None` when they cannot be merged without changing what is imported, or losing or
+/// moving a comment, e.g. `// eslint-disable-line`.
+fn merge_imports(ctx: &LintContext, imports: &[&ImportDeclaration]) -> Option<Vec<(Span, String)>> {
+    let (first, rest) = imports.split_first()?;
+    if imports.iter().any(|decl| decl.with_clause.is_some() || decl.phase.is_some()) {
+        return None;
+    }
+    // A type-only import cannot hold the value imports.
+    if first.import_kind.is_type() && rest.iter().any(|decl| !decl.import_kind.is_type()) {
+        return None;
+    }
+    let source_text = ctx.source_text();
+    let comments = ctx.semantic().comments();
+    if comments.iter().any(|comment| is_trailing_comment(source_text, first.span, comment.span))
+        || rest.iter().any(|decl| {
+            comments.iter().any(|comment| is_attached_comment(source_text, decl.span, comment.span))
+        })
+    {
+        return None;
+    }
+
+    let (first_default, first_named) = split_specifiers(first)?;
+    let mut locals = first_default
+        .iter()
+        .chain(&first_named)
+        .map(|spec| spec.local().name.as_str())
+        .collect::<FxHashSet<_>>();
+    let mut default = None;
+    let mut named = vec![];
+    for decl in rest {
+        let (decl_default, decl_named) = split_specifiers(decl)?;
+        let inline_type = decl.import_kind.is_type() && !first.import_kind.is_type();
+        if let Some(spec) = decl_default {
+            if !locals.insert(spec.local().name.as_str()) {
+                // Imported twice under the same name.
+            } else if first_default.is_some() || default.is_some() || inline_type {
+                return None;
+            } else {
+                default = Some(ctx.source_range(spec.span()));
+            }
+        }
+        for spec in decl_named {
+            if locals.insert(spec.local().name.as_str()) {
+                let text = ctx.source_range(spec.span());
+                named.push(if inline_type { format!("type {text}") } else { text.to_string() });
+            }
+        }
+    }
+
+    // `import'./foo'` and `import{x} from './foo'`
+    let space_before = |pos: u32| {
+        if source_text[..pos as usize].ends_with(char::is_whitespace) { "" } else { " " }
+    };
+    let mut edits = vec![];
+    let named = named.join(", ");
+    let brace = find_open_brace(ctx, first);
+    match (first.specifiers.as_ref(), first_named.last(), brace) {
+        // `import './foo'`
+        (None, _, _) => {
+            let specifiers = match (default, named.is_empty()) {
+                (Some(default), true) => default.to_string(),
+                (Some(default), false) => format!("{default}, {{{named}}}"),
+                (None, false) => format!("{{{named}}}"),
+                (None, true) => String::new(),
+            };
+            if !specifiers.is_empty() {
+                let start = first.source.span.start;
+                let space = space_before(start);
+                edits.push((Span::empty(start), format!("{space}{specifiers} from ")));
+            }
+        }
+        // `import def, { x } from './foo'`
+        (Some(_), Some(last), Some(brace)) => {
+            if let Some(default) = default {
+                let space = space_before(brace);
+                edits.push((Span::empty(brace), format!("{space}{default}, ")));
+            }
+            if !named.is_empty() {
+                edits.push((Span::empty(last.span().end), format!(", {named}")));
+            }
+        }
+        // `import def, {} from './foo'`
+        (Some(_), None, Some(brace)) => {
+            if let Some(default) = default {
+                let space = space_before(brace);
+                edits.push((Span::empty(brace), format!("{space}{default}, ")));
+            }
+            if !named.is_empty() {
+                edits.push((Span::empty(brace + 1), named));
+            }
+        }
+        // `import def from './foo'`
+        (Some(_), _, None) => {
+            let last = first_default?;
+            if !named.is_empty() {
+                edits.push((Span::empty(last.span().end), format!(", {{{named}}}")));
+            }
         }
     }
+
+    edits.extend(rest.iter().map(|decl| (removal_span(source_text, decl.span), String::new())));
+    Some(edits)
+}
+
+/// The default and named specifiers of `decl`, `None` for a namespace import.
+fn split_specifiers<'a, 'b>(
+    decl: &'b ImportDeclaration<'a>,
+) -> Option<(Option<&'b ImportDeclarationSpecifier<'a>>, Vec<&'b ImportDeclarationSpecifier<'a>>)> {
+    let mut default = None;
+    let mut named = vec![];
+    for spec in decl.specifiers.iter().flatten() {
+        match spec {
+            ImportDeclarationSpecifier::ImportDefaultSpecifier(_) => default = Some(spec),
+            ImportDeclarationSpecifier::ImportSpecifier(_) => named.push(spec),
+            ImportDeclarationSpecifier::ImportNamespaceSpecifier(_) => return None,
+        }
+    }
+    Some((default, named))
+}
+
+/// The position of the `{` of the named specifiers of `decl`, which is not in a comment.
+#[expect(clippy::cast_possible_truncation)]
+fn find_open_brace(ctx: &LintContext, decl: &ImportDeclaration) -> Option<u32> {
+    let span = Span::new(decl.span.start, decl.source.span.start);
+    let comments = ctx.semantic().comments();
+    ctx.source_range(span).match_indices('{').map(|(i, _)| span.start + i as u32).find(|&pos| {
+        !comments.iter().any(|comment| comment.span.start <= pos && pos < comment.span.end)
+    })
+}
+
+/// Whether `comment` is on the same line after `span`.
+fn is_trailing_comment(source_text: &str, span: Span, comment: Span) -> bool {
+    comment.start >= span.end
+        && !source_text[span.end as usize..comment.start as usize].contains(['\n', '\r'])
+}
+
+/// Whether `comment` is inside `span`, right before it or on the same line after it, and would
+/// be lost when removing `span`.
+fn is_attached_comment(source_text: &str, span: Span, comment: Span) -> bool {
+    if comment.end <= span.start {
+        let between = &source_text[comment.end as usize..span.start as usize];
+        return between.trim().is_empty() && between.matches('\n').count() <= 1;
+    }
+    comment.start < span.end || is_trailing_comment(source_text, span, comment)
+}
+
+/// The span of `span` with its whitespace, so that removing it leaves no blank line or trailing
+/// whitespace.
+#[expect(clippy::cast_possible_truncation)]
+fn removal_span(source_text: &str, span: Span) -> Span {
+    let before = source_text[..span.start as usize].trim_end_matches([' ', '\t']);
+    let after = source_text[span.end as usize..].trim_start_matches([' ', '\t']);
+    let start = before.len() as u32;
+    let end = (source_text.len() - after.len()) as u32;
+    if !before.is_empty() && !before.ends_with('\n') {
+        // `import { x } from './foo'; import { y } from './foo'`
+        Span::new(start, span.end)
+    } else if after.starts_with('\n') {
+        Span::new(start, end + 1)
+    } else if after.starts_with("\r\n") {
+        Span::new(start, end + 2)
+    } else if after.is_empty() {
+        Span::new(start, end)
+    } else {
+        // `import { y } from './foo'; foo();`
+        Span::new(span.start, end)
+    }
 }
 
 #[test]
@@ -410,7 +634,104 @@ fn test() {
         ),
     ];
 
+    let fix = vec![
+        (
+            r"import { x } from './foo'; import { y } from './foo'",
+            r"import { x, y } from './foo';",
+            None,
+        ),
+        (
+            r"import {a,b} from './foo'; import { b, c } from './foo'; import {b,c,d} from './foo'",
+            r"import {a,b, c, d} from './foo';",
+            None,
+        ),
+        (r"import {x} from './foo'; import {} from './foo'", r"import {x} from './foo';", None),
+        (r"import { } from './foo'; import {x} from './foo'", r"import {x } from './foo';", None),
+        (r"import'./foo'; import {x} from './foo'", r"import {x} from './foo';", None),
+        (r"import './foo'; import def, {x} from './foo'", r"import def, {x} from './foo';", None),
+        (
+            r"import def from './foo'; import {x} from './foo'",
+            r"import def, {x} from './foo';",
+            None,
+        ),
+        (
+            r"import{x} from './foo'; import def from './foo'",
+            r"import def, {x} from './foo';",
+            None,
+        ),
+        (
+            r"import {x} from './foo'; import def, {y} from './foo'",
+            r"import def, {x, y} from './foo';",
+            None,
+        ),
+        (
+            "import {x} from './foo'\n        // comment\n\n        import {y} from './foo'",
+            "import {x, y} from './foo'\n        // comment\n\n",
+            None,
+        ),
+        (
+            "import {A1,} from 'foo';\n            import {B1,} from 'foo';\n            import {C1,} from 'foo';",
+            "import {A1, B1, C1,} from 'foo';\n",
+            None,
+        ),
+        (
+            "import {\n  A,\n} from '../constants';\nimport React from 'react';\nimport {\n  B\n} from '../constants';\n",
+            "import {\n  A, B,\n} from '../constants';\nimport React from 'react';\n",
+            None,
+        ),
+        (
+            r"import type {x} from './foo'; import type {y} from './foo'",
+            r"import type {x, y} from './foo';",
+            None,
+        ),
+        (
+            r"import {type x} from './foo'; import type {y} from './foo'",
+            r"import {type x, type y} from './foo';",
+            None,
+        ),
+        (
+            r"import {AValue} from './foo'; import type {AType} from './foo'",
+            r"import {AValue, type AType} from './foo';",
+            Some(json!({ "preferInline": true })),
+        ),
+        // Different default imports
+        (
+            r"import foo from 'non-existent'; import bar from 'non-existent';",
+            r"import foo from 'non-existent'; import bar from 'non-existent';",
+            None,
+        ),
+        // Namespace imports
+        (
+            r"import * as ns1 from './foo'; import * as ns2 from './foo'",
+            r"import * as ns1 from './foo'; import * as ns2 from './foo'",
+            None,
+        ),
+        // Comments which would be lost or apply to other imports
+        (
+            "import {x} from './foo' // some-tool-disable-line\n            import {y} from './foo'",
+            "import {x} from './foo' // some-tool-disable-line\n            import {y} from './foo'",
+            None,
+        ),
+        (
+            "import {x} from './foo'\n            // some-tool-disable-next-line\n            import {y} from './foo'",
+            "import {x} from './foo'\n            // some-tool-disable-next-line\n            import {y} from './foo'",
+            None,
+        ),
+        (
+            r"import {a} from './foo'; import { a/*,b*/ } from './foo'",
+            r"import {a} from './foo'; import { a/*,b*/ } from './foo'",
+            None,
+        ),
+        // A type-only import cannot hold the value imports
+        (
+            r"import type {AType} from './foo'; import {AValue} from './foo'",
+            r"import type {AType} from './foo'; import {AValue} from './foo'",
+            Some(json!({ "preferInline": true })),
+        ),
+    ];
+
     Tester::new(NoDuplicates::NAME, NoDuplicates::PLUGIN, pass, fail)
+        .expect_fix(fix)
         .change_rule_path("index.ts")
         .with_import_plugin(true)
         .test_and_snapshot();
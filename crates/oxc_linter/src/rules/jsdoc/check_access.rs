@@ -53,7 +53,8 @@ declare_oxc_lint!(
     /// ```
     CheckAccess,
     jsdoc,
-    restriction
+    restriction,
+    requires = jsdoc
 );
 
 const ACCESS_LEVELS: [&str; 4] = ["package", "private", "protected", "public"];
@@ -58,7 +58,8 @@ declare_oxc_lint!(
     /// ```
     CheckPropertyNames,
     jsdoc,
-    correctness
+    correctness,
+    requires = jsdoc
 );
 
 impl Rule for CheckPropertyNames {
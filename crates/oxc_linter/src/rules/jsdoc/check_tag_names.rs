@@ -79,7 +79,8 @@ declare_oxc_lint!(
     /// ```
     CheckTagNames,
     jsdoc,
-    correctness
+    correctness,
+    requires = jsdoc
 );
 
 #[derive(Debug, Default, Clone, Deserialize)]
@@ -58,7 +58,8 @@ declare_oxc_lint!(
     /// ```
     EmptyTags,
     jsdoc,
-    restriction
+    restriction,
+    requires = jsdoc
 );
 
 const EMPTY_TAGS: [&str; 18] = [
@@ -56,7 +56,8 @@ declare_oxc_lint!(
     /// ```
     ImplementsOnClasses,
     jsdoc,
-    correctness
+    correctness,
+    requires = jsdoc
 );
 
 fn is_function_inside_of_class<'a, 'b>(node: &'b AstNode<'a>, ctx: &'b LintContext<'a>) -> bool {
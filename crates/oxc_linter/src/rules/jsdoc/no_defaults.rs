@@ -47,7 +47,8 @@ declare_oxc_lint!(
     /// ```
     NoDefaults,
     jsdoc,
-    correctness
+    correctness,
+    requires = jsdoc
 );
 
 #[derive(Debug, Default, Clone, Deserialize)]
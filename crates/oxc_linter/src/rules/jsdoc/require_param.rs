@@ -55,6 +55,7 @@ declare_oxc_lint!(
     RequireParam,
     jsdoc,
     pedantic,
+    requires = jsdoc
 );
 
 #[derive(Debug, Clone, Deserialize)]
@@ -47,6 +47,7 @@ declare_oxc_lint!(
     RequireParamDescription,
     jsdoc,
     pedantic,
+    requires = jsdoc
 );
 
 impl Rule for RequireParamDescription {
@@ -44,6 +44,7 @@ declare_oxc_lint!(
     RequireParamName,
     jsdoc,
     pedantic,
+    requires = jsdoc
 );
 
 impl Rule for RequireParamName {
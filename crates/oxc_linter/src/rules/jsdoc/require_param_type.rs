@@ -47,6 +47,7 @@ declare_oxc_lint!(
     RequireParamType,
     jsdoc,
     pedantic,
+    requires = jsdoc
 );
 
 impl Rule for RequireParamType {
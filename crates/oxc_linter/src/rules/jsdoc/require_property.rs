@@ -58,7 +58,8 @@ declare_oxc_lint!(
     /// ```
     RequireProperty,
     jsdoc,
-    correctness
+    correctness,
+    requires = jsdoc
 );
 
 impl Rule for RequireProperty {
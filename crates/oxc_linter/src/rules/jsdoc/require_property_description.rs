@@ -45,7 +45,8 @@ declare_oxc_lint!(
     /// ```
     RequirePropertyDescription,
     jsdoc,
-    correctness
+    correctness,
+    requires = jsdoc
 );
 
 impl Rule for RequirePropertyDescription {
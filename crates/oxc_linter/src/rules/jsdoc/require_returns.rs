@@ -65,6 +65,7 @@ declare_oxc_lint!(
     RequireReturns,
     jsdoc,
     pedantic,
+    requires = jsdoc
 );
 
 #[derive(Debug, Clone, Deserialize)]
@@ -45,6 +45,7 @@ declare_oxc_lint!(
     RequireReturnsDescription,
     jsdoc,
     pedantic,
+    requires = jsdoc
 );
 
 impl Rule for RequireReturnsDescription {
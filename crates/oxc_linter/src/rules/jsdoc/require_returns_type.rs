@@ -44,6 +44,7 @@ declare_oxc_lint!(
     RequireReturnsType,
     jsdoc,
     pedantic,
+    requires = jsdoc
 );
 
 impl Rule for RequireReturnsType {
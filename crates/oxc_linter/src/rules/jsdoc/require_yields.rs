@@ -67,7 +67,8 @@ declare_oxc_lint!(
     /// ```
     RequireYields,
     jsdoc,
-    correctness
+    correctness,
+    requires = jsdoc
 );
 
 #[derive(Debug, Clone, Deserialize)]
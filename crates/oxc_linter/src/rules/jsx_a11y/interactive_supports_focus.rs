@@ -0,0 +1,255 @@
+use oxc_ast::{
+    AstKind,
+    ast::{JSXAttributeValue, JSXExpression, JSXOpeningElement},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{CompactStr, Span};
+
+use crate::{
+    AstNode,
+    context::LintContext,
+    globals::HTML_TAG,
+    rule::Rule,
+    utils::{
+        get_element_type, get_explicit_role, get_prop_value, get_string_literal_prop_value,
+        has_jsx_prop, has_jsx_prop_ignore_case, has_jsx_prop_with_value,
+        is_hidden_from_screen_reader, is_interactive_element, is_interactive_role,
+        is_non_interactive_element, is_presentation_role,
+    },
+};
+
+fn tabbable_diagnostic(role: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Elements with the '{role}' interactive role must be tabbable."))
+        .with_help("Add `tabIndex={0}` to make the element reachable with the keyboard.")
+        .with_label(span)
+}
+
+fn focusable_diagnostic(role: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("Elements with the '{role}' interactive role must be focusable."))
+        .with_help("Add `tabIndex={-1}` to make the element focusable, or `tabIndex={0}` to also make it reachable with the keyboard.")
+        .with_label(span)
+}
+
+#[derive(Debug, Clone)]
+pub struct InteractiveSupportsFocus(Box<InteractiveSupportsFocusConfig>);
+
+#[derive(Debug, Clone)]
+pub struct InteractiveSupportsFocusConfig {
+    tabbable: Vec<CompactStr>,
+}
+
+impl std::ops::Deref for InteractiveSupportsFocus {
+    type Target = InteractiveSupportsFocusConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+const DEFAULT_TABBABLE: [&str; 7] =
+    ["button", "checkbox", "link", "searchbox", "spinbutton", "switch", "textbox"];
+
+const INTERACTIVE_HANDLERS: [&str; 6] =
+    ["onClick", "onMouseDown", "onMouseUp", "onKeyPress", "onKeyDown", "onKeyUp"];
+
+impl Default for InteractiveSupportsFocus {
+    fn default() -> Self {
+        Self(Box::new(InteractiveSupportsFocusConfig {
+            tabbable: DEFAULT_TABBABLE.into_iter().map(CompactStr::new).collect(),
+        }))
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce that elements with an interactive role and interaction handlers (mouse or key
+    /// press) are focusable.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Keyboard users and users of assistive technologies can only interact with the elements
+    /// they can focus. Native interactive elements like `<button>` are focusable, but a `<div>`
+    /// with `role="button"` is not, unless it has a `tabIndex`.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```jsx
+    /// <div role="button" onClick={() => {}} />
+    /// <span role="menuitem" onKeyPress={() => {}} />
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```jsx
+    /// <div role="button" onClick={() => {}} tabIndex={0} />
+    /// <span role="menuitem" onKeyPress={() => {}} tabIndex={-1} />
+    /// <button onClick={() => {}} />
+    /// <div role="button" onClick={() => {}} aria-disabled="true" />
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// ```json
+    /// {
+    ///   "tabbable": ["button", "checkbox", "link", "searchbox", "spinbutton", "switch", "textbox"]
+    /// }
+    /// ```
+    ///
+    /// * `tabbable`: the roles which must be reachable with the <kbd>Tab</kbd> key, with
+    ///   `tabIndex={0}`. Elements with other interactive roles only need to be focusable, e.g.
+    ///   with `tabIndex={-1}`.
+    InteractiveSupportsFocus,
+    jsx_a11y,
+    correctness
+);
+
+impl Rule for InteractiveSupportsFocus {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let default = Self::default();
+        let Some(tabbable) = value
+            .as_array()
+            .and_then(|arr| arr.first())
+            .and_then(|config| config.get("tabbable"))
+            .and_then(serde_json::Value::as_array)
+        else {
+            return default;
+        };
+
+        Self(Box::new(InteractiveSupportsFocusConfig {
+            tabbable: tabbable
+                .iter()
+                .filter_map(serde_json::Value::as_str)
+                .map(CompactStr::new)
+                .collect(),
+        }))
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::JSXOpeningElement(jsx_opening_el) = node.kind() else {
+            return;
+        };
+
+        if !INTERACTIVE_HANDLERS
+            .iter()
+            .any(|handler| has_jsx_prop_with_value(jsx_opening_el, handler))
+        {
+            return;
+        }
+
+        // Check only native DOM elements or custom component via settings
+        let element_type = get_element_type(ctx, jsx_opening_el);
+        if !HTML_TAG.contains(element_type.as_ref()) {
+            return;
+        }
+
+        if is_disabled_element(jsx_opening_el)
+            || is_hidden_from_screen_reader(ctx, jsx_opening_el)
+            || is_presentation_role(jsx_opening_el)
+        {
+            return;
+        }
+
+        if is_interactive_element(&element_type, jsx_opening_el)
+            || is_non_interactive_element(&element_type, jsx_opening_el)
+        {
+            return;
+        }
+
+        let Some(role) = get_explicit_role(jsx_opening_el) else {
+            return;
+        };
+        if !is_interactive_role(role)
+            || has_jsx_prop_ignore_case(jsx_opening_el, "tabIndex").is_some()
+        {
+            return;
+        }
+
+        if self.tabbable.iter().any(|tabbable| tabbable == role) {
+            ctx.diagnostic(tabbable_diagnostic(role, jsx_opening_el.span));
+        } else {
+            ctx.diagnostic(focusable_diagnostic(role, jsx_opening_el.span));
+        }
+    }
+}
+
+/// `<div disabled />` or `<div aria-disabled="true" />`
+fn is_disabled_element(node: &JSXOpeningElement) -> bool {
+    let disabled = has_jsx_prop(node, "disabled").is_some_and(|item| match get_prop_value(item) {
+        Some(JSXAttributeValue::ExpressionContainer(container)) => !matches!(
+            &container.expression,
+            JSXExpression::BooleanLiteral(lit) if !lit.value
+        ),
+        _ => true,
+    });
+    let aria_disabled = has_jsx_prop_ignore_case(node, "aria-disabled").is_some_and(|item| {
+        match get_prop_value(item) {
+            Some(JSXAttributeValue::ExpressionContainer(container)) => matches!(
+                &container.expression,
+                JSXExpression::BooleanLiteral(lit) if lit.value
+            ),
+            _ => get_string_literal_prop_value(item) == Some("true"),
+        }
+    });
+    disabled || aria_disabled
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        (r"<div />", None, None),
+        (r"<div onClick={() => {}} />", None, None),
+        (r#"<div role="button" />"#, None, None),
+        (r#"<div role="button" onClick={undefined} />"#, None, None),
+        (r#"<div role="button" onClick={() => {}} tabIndex="0" />"#, None, None),
+        (r#"<div role="button" onClick={() => {}} tabIndex={0} />"#, None, None),
+        (r#"<div role="menuitem" onClick={() => {}} tabIndex={-1} />"#, None, None),
+        (r#"<div role="button" onClick={() => {}} aria-hidden="true" />"#, None, None),
+        (r#"<div role="button" onClick={() => {}} aria-disabled="true" />"#, None, None),
+        (r#"<div role="button" onClick={() => {}} aria-disabled={true} />"#, None, None),
+        (r#"<div role="button" onClick={() => {}} disabled />"#, None, None),
+        (r#"<div role="article" onClick={() => {}} />"#, None, None),
+        (r#"<div role="presentation" onClick={() => {}} />"#, None, None),
+        (r#"<div role="foo" onClick={() => {}} />"#, None, None),
+        (r"<button onClick={() => {}} />", None, None),
+        (r#"<a href="foo" role="button" onClick={() => {}} />"#, None, None),
+        (r#"<input type="text" onClick={() => {}} />"#, None, None),
+        (r#"<li role="button" onClick={() => {}} />"#, None, None),
+        (r#"<MyButton role="button" onClick={() => {}} />"#, None, None),
+        (r#"<div role="button" onFocus={() => {}} />"#, None, None),
+    ];
+
+    let fail = vec![
+        (r#"<div role="button" onClick={() => {}} />"#, None, None),
+        (r#"<span role="checkbox" onKeyPress={() => {}} />"#, None, None),
+        (r#"<div role="link" onMouseDown={() => {}} />"#, None, None),
+        (r#"<div role="textbox" onKeyDown={() => {}} />"#, None, None),
+        (r#"<div role="menuitem" onClick={() => {}} />"#, None, None),
+        (r#"<div role="tab" onClick={() => {}} />"#, None, None),
+        (r#"<div role="option" onClick={() => {}} />"#, None, None),
+        (r#"<a role="button" onClick={() => {}} />"#, None, None),
+        (r#"<div role="button" onClick={() => {}} disabled={false} />"#, None, None),
+        (
+            r#"<div role="tab" onClick={() => {}} />"#,
+            Some(serde_json::json!([{ "tabbable": ["tab"] }])),
+            None,
+        ),
+        (
+            r#"<Div role="button" onClick={() => {}} />"#,
+            None,
+            Some(serde_json::json!({
+                "settings": { "jsx-a11y": {
+                    "components": {
+                        "Div": "div",
+                    }
+                } }
+            })),
+        ),
+    ];
+
+    Tester::new(InteractiveSupportsFocus::NAME, InteractiveSupportsFocus::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
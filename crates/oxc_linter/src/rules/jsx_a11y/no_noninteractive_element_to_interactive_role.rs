@@ -0,0 +1,243 @@
+use oxc_ast::{AstKind, ast::JSXAttributeItem};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{CompactStr, Span};
+use rustc_hash::FxHashMap;
+
+use crate::{
+    AstNode,
+    context::LintContext,
+    globals::HTML_TAG,
+    rule::Rule,
+    utils::{
+        get_element_type, get_explicit_role, has_jsx_prop_ignore_case, is_interactive_role,
+        is_non_interactive_element,
+    },
+};
+
+fn no_noninteractive_element_to_interactive_role_diagnostic(
+    element: &str,
+    role: &str,
+    span: Span,
+) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Non-interactive elements should not be assigned interactive roles.")
+        .with_help(format!("Replace `<{element}>` with an element which is interactive, like `<button>`, instead of assigning it the `{role}` role."))
+        .with_label(span)
+}
+
+#[derive(Debug, Clone)]
+pub struct NoNoninteractiveElementToInteractiveRole(
+    Box<NoNoninteractiveElementToInteractiveRoleConfig>,
+);
+
+#[derive(Debug, Clone)]
+pub struct NoNoninteractiveElementToInteractiveRoleConfig {
+    /// The interactive roles allowed on each element.
+    allowed_roles: FxHashMap<CompactStr, Vec<CompactStr>>,
+}
+
+impl std::ops::Deref for NoNoninteractiveElementToInteractiveRole {
+    type Target = NoNoninteractiveElementToInteractiveRoleConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+const DEFAULT_ALLOWED_ROLES: [(&str, &[&str]); 6] = [
+    ("ul", &["listbox", "menu", "menubar", "radiogroup", "tablist", "tree", "treegrid"]),
+    ("ol", &["listbox", "menu", "menubar", "radiogroup", "tablist", "tree", "treegrid"]),
+    ("li", &["menuitem", "option", "row", "tab", "treeitem"]),
+    ("table", &["grid"]),
+    ("td", &["gridcell"]),
+    ("fieldset", &["radiogroup", "presentation"]),
+];
+
+impl Default for NoNoninteractiveElementToInteractiveRole {
+    fn default() -> Self {
+        Self(Box::new(NoNoninteractiveElementToInteractiveRoleConfig {
+            allowed_roles: DEFAULT_ALLOWED_ROLES
+                .into_iter()
+                .map(|(element, roles)| {
+                    (CompactStr::new(element), roles.iter().copied().map(CompactStr::new).collect())
+                })
+                .collect(),
+        }))
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow interactive roles, like `button`, on non-interactive elements, like `<h1>` or
+    /// `<li>`.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Non-interactive HTML elements indicate content and containers in the user interface.
+    /// Assigning them an interactive role tells users of assistive technologies that they can
+    /// interact with the content, which they can't without the behavior of an interactive
+    /// element, like being focusable. Wrap the content in an interactive element instead.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```jsx
+    /// <h1 role="button">Click me</h1>
+    /// <li role="link">Home</li>
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```jsx
+    /// <h1><button>Click me</button></h1>
+    /// <li><a href="/">Home</a></li>
+    /// <ul role="menu" />
+    /// <div role="button" />
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// An object which maps elements to the interactive roles allowed on them. The default is:
+    ///
+    /// ```json
+    /// {
+    ///   "ul": ["listbox", "menu", "menubar", "radiogroup", "tablist", "tree", "treegrid"],
+    ///   "ol": ["listbox", "menu", "menubar", "radiogroup", "tablist", "tree", "treegrid"],
+    ///   "li": ["menuitem", "option", "row", "tab", "treeitem"],
+    ///   "table": ["grid"],
+    ///   "td": ["gridcell"],
+    ///   "fieldset": ["radiogroup", "presentation"]
+    /// }
+    /// ```
+    NoNoninteractiveElementToInteractiveRole,
+    jsx_a11y,
+    correctness
+);
+
+impl Rule for NoNoninteractiveElementToInteractiveRole {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let Some(config) = value.as_array().and_then(|arr| arr.first()).and_then(|v| v.as_object())
+        else {
+            return Self::default();
+        };
+
+        Self(Box::new(NoNoninteractiveElementToInteractiveRoleConfig {
+            allowed_roles: config
+                .iter()
+                .map(|(element, roles)| {
+                    let roles = roles
+                        .as_array()
+                        .into_iter()
+                        .flatten()
+                        .filter_map(serde_json::Value::as_str)
+                        .map(CompactStr::new)
+                        .collect();
+                    (CompactStr::new(element), roles)
+                })
+                .collect(),
+        }))
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::JSXOpeningElement(jsx_opening_el) = node.kind() else {
+            return;
+        };
+
+        let Some(JSXAttributeItem::Attribute(role_attr)) =
+            has_jsx_prop_ignore_case(jsx_opening_el, "role")
+        else {
+            return;
+        };
+        let Some(role) = get_explicit_role(jsx_opening_el) else {
+            return;
+        };
+        if !is_interactive_role(role) {
+            return;
+        }
+
+        // Check only native DOM elements or custom component via settings
+        let element_type = get_element_type(ctx, jsx_opening_el);
+        if !HTML_TAG.contains(element_type.as_ref()) {
+            return;
+        }
+
+        if !is_non_interactive_element(&element_type, jsx_opening_el) {
+            return;
+        }
+
+        if self
+            .allowed_roles
+            .get(element_type.as_ref())
+            .is_some_and(|roles| roles.iter().any(|allowed| allowed == role))
+        {
+            return;
+        }
+
+        ctx.diagnostic(no_noninteractive_element_to_interactive_role_diagnostic(
+            &element_type,
+            role,
+            role_attr.span,
+        ));
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        (r"<TestComponent onClick={doFoo} />", None, None),
+        (r#"<Button role="button" />"#, None, None),
+        (r#"<div role="button" />"#, None, None),
+        (r#"<span role="link" />"#, None, None),
+        (r#"<button role="switch" />"#, None, None),
+        (r##"<a href="#" role="button" />"##, None, None),
+        (r#"<input type="checkbox" role="switch" />"#, None, None),
+        (r#"<h1 role="heading" />"#, None, None),
+        (r#"<article role="article" />"#, None, None),
+        (r#"<li role="presentation" />"#, None, None),
+        (r#"<li role="foo" />"#, None, None),
+        (r"<li role={ROLE} />", None, None),
+        (r#"<ul role="menu" />"#, None, None),
+        (r#"<ol role="tablist" />"#, None, None),
+        (r#"<li role="menuitem" />"#, None, None),
+        (r#"<li role="tab" />"#, None, None),
+        (r#"<table role="grid" />"#, None, None),
+        (r#"<td role="gridcell" />"#, None, None),
+        (r#"<fieldset role="radiogroup" />"#, None, None),
+        (r#"<p role="button" />"#, Some(serde_json::json!([{ "p": ["button"] }])), None),
+    ];
+
+    let fail = vec![
+        (r#"<h1 role="button" />"#, None, None),
+        (r#"<h2 role="link" />"#, None, None),
+        (r#"<li role="button" />"#, None, None),
+        (r#"<ul role="button" />"#, None, None),
+        (r#"<article role="checkbox" />"#, None, None),
+        (r#"<main role="menuitem" />"#, None, None),
+        (r#"<nav role="tab" />"#, None, None),
+        (r#"<p role="textbox" />"#, None, None),
+        (r#"<section role="switch" />"#, None, None),
+        (r#"<table role="listbox" />"#, None, None),
+        (r#"<ul role="menu" />"#, Some(serde_json::json!([{ "ol": ["menu"] }])), None),
+        (
+            r#"<Heading role="button" />"#,
+            None,
+            Some(serde_json::json!({
+                "settings": { "jsx-a11y": {
+                    "components": {
+                        "Heading": "h1",
+                    }
+                } }
+            })),
+        ),
+    ];
+
+    Tester::new(
+        NoNoninteractiveElementToInteractiveRole::NAME,
+        NoNoninteractiveElementToInteractiveRole::PLUGIN,
+        pass,
+        fail,
+    )
+    .test_and_snapshot();
+}
@@ -10,7 +10,9 @@ use crate::{
     AstNode,
     context::LintContext,
     rule::Rule,
-    utils::{get_element_type, has_jsx_prop_ignore_case},
+    utils::{
+        INTERACTIVE_ELEMENTS, get_element_type, has_jsx_prop_ignore_case, is_interactive_role,
+    },
 };
 
 fn no_noninteractive_tabindex_diagnostic(span: Span) -> OxcDiagnostic {
@@ -81,36 +83,6 @@ declare_oxc_lint!(
     correctness,
 );
 
-// https://html.spec.whatwg.org/multipage/dom.html#interactive-content
-const INTERACTIVE_HTML_ELEMENTS: [&str; 12] = [
-    "a", "audio", "button", "details", "embed", "iframe", "img", "input", "label", "select",
-    "textarea", "video",
-];
-
-// https://www.w3.org/TR/wai-aria/#widget_roles
-// NOTE: "tabpanel" is not included here because it's technically a section role. It can optionally be considered interactive within the context of a tablist, because its visibility is dynamically controlled by an element with the "tab" aria role. It's included in the recommended jsx-a11y config for this reason.
-const INTERACTIVE_HTML_ROLES: [&str; 19] = [
-    "button",
-    "checkbox",
-    "gridcell",
-    "link",
-    "menuitem",
-    "menuitemcheckbox",
-    "menuitemradio",
-    "option",
-    "progressbar",
-    "radio",
-    "scrollbar",
-    "searchbox",
-    "separator",
-    "slider",
-    "spinbutton",
-    "switch",
-    "tab",
-    "textbox",
-    "treeitem",
-];
-
 impl Rule for NoNoninteractiveTabindex {
     fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
         let AstKind::JSXOpeningElement(jsx_el) = node.kind() else {
@@ -133,7 +105,7 @@ impl Rule for NoNoninteractiveTabindex {
 
         let component = &get_element_type(ctx, jsx_el);
 
-        if INTERACTIVE_HTML_ELEMENTS.contains(&component.as_ref()) {
+        if INTERACTIVE_ELEMENTS.contains(component.as_ref()) {
             return;
         }
 
@@ -153,7 +125,8 @@ impl Rule for NoNoninteractiveTabindex {
             return;
         };
 
-        if !INTERACTIVE_HTML_ROLES.contains(&role.value.as_str())
+        // NOTE: "tabpanel" is not an interactive role because it's technically a section role. It can optionally be considered interactive within the context of a tablist, because its visibility is dynamically controlled by an element with the "tab" aria role. It's included in the default `roles` for this reason.
+        if !is_interactive_role(role.value.as_str())
             && !self.0.roles.contains(&CompactStr::new(role.value.as_str()))
         {
             ctx.diagnostic(no_noninteractive_tabindex_diagnostic(tabindex_attr.span));
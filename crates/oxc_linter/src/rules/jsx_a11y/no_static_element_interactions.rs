@@ -0,0 +1,235 @@
+use oxc_ast::{
+    AstKind,
+    ast::{JSXAttributeItem, JSXAttributeValue},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{CompactStr, Span};
+
+use crate::{
+    AstNode,
+    context::LintContext,
+    globals::HTML_TAG,
+    rule::Rule,
+    utils::{
+        get_element_type, get_explicit_role, has_jsx_prop_ignore_case, has_jsx_prop_with_value,
+        is_hidden_from_screen_reader, is_interactive_element, is_non_interactive_element,
+        is_presentation_role,
+    },
+};
+
+fn no_static_element_interactions_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Static HTML elements with event handlers require a role.")
+        .with_help("Avoid non-native interactive elements. If using native HTML is not possible, add an appropriate role and support for tabbing, mouse, key, and touch inputs to an interactive content element.")
+        .with_label(span)
+}
+
+#[derive(Debug, Clone)]
+pub struct NoStaticElementInteractions(Box<NoStaticElementInteractionsConfig>);
+
+#[derive(Debug, Clone)]
+pub struct NoStaticElementInteractionsConfig {
+    handlers: Vec<CompactStr>,
+    allow_expression_values: bool,
+}
+
+impl std::ops::Deref for NoStaticElementInteractions {
+    type Target = NoStaticElementInteractionsConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+const DEFAULT_HANDLERS: [&str; 6] =
+    ["onClick", "onMouseDown", "onMouseUp", "onKeyPress", "onKeyDown", "onKeyUp"];
+
+impl Default for NoStaticElementInteractions {
+    fn default() -> Self {
+        Self(Box::new(NoStaticElementInteractionsConfig {
+            handlers: DEFAULT_HANDLERS.into_iter().map(CompactStr::new).collect(),
+            allow_expression_values: true,
+        }))
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Enforce that static, visible elements (such as `<div>`) that have click handlers use a
+    /// valid role attribute.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// Static HTML elements do not have semantic meaning. Assistive technologies don't know that
+    /// users can interact with a `<div>` with an `onClick` handler, and keyboard users can't
+    /// reach it. Use a native interactive element like `<button>` instead, or give the element
+    /// a role which describes the interaction.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```jsx
+    /// <div onClick={() => {}} />
+    /// <span onKeyDown={handleKeyDown} />
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```jsx
+    /// <button onClick={() => {}} />
+    /// <div role="button" onClick={() => {}} onKeyDown={handleKeyDown} tabIndex={0} />
+    /// <div onClick={() => {}} role="presentation" />
+    /// <div onClick={() => {}} aria-hidden="true" />
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// ```json
+    /// {
+    ///   "handlers": ["onClick", "onMouseDown", "onMouseUp", "onKeyPress", "onKeyDown", "onKeyUp"],
+    ///   "allowExpressionValues": true
+    /// }
+    /// ```
+    ///
+    /// * `handlers`: the event handlers which make an element interactive.
+    /// * `allowExpressionValues`: allow roles which are expressions, like `role={ROLE}`.
+    NoStaticElementInteractions,
+    jsx_a11y,
+    correctness
+);
+
+impl Rule for NoStaticElementInteractions {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let default = Self::default();
+        let Some(config) = value.as_array().and_then(|arr| arr.first()) else {
+            return default;
+        };
+
+        Self(Box::new(NoStaticElementInteractionsConfig {
+            handlers: config.get("handlers").and_then(serde_json::Value::as_array).map_or(
+                default.0.handlers,
+                |handlers| {
+                    handlers
+                        .iter()
+                        .filter_map(serde_json::Value::as_str)
+                        .map(CompactStr::new)
+                        .collect()
+                },
+            ),
+            allow_expression_values: config
+                .get("allowExpressionValues")
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or(default.0.allow_expression_values),
+        }))
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::JSXOpeningElement(jsx_opening_el) = node.kind() else {
+            return;
+        };
+
+        if !self.handlers.iter().any(|handler| has_jsx_prop_with_value(jsx_opening_el, handler)) {
+            return;
+        }
+
+        // Check only native DOM elements or custom component via settings
+        let element_type = get_element_type(ctx, jsx_opening_el);
+        if !HTML_TAG.contains(element_type.as_ref()) {
+            return;
+        }
+
+        if is_hidden_from_screen_reader(ctx, jsx_opening_el) || is_presentation_role(jsx_opening_el)
+        {
+            return;
+        }
+
+        if is_interactive_element(&element_type, jsx_opening_el)
+            || is_non_interactive_element(&element_type, jsx_opening_el)
+            || get_explicit_role(jsx_opening_el).is_some()
+        {
+            return;
+        }
+
+        if self.allow_expression_values
+            && matches!(
+                has_jsx_prop_ignore_case(jsx_opening_el, "role"),
+                Some(JSXAttributeItem::Attribute(attr))
+                    if matches!(attr.value, Some(JSXAttributeValue::ExpressionContainer(_)))
+            )
+        {
+            return;
+        }
+
+        ctx.diagnostic(no_static_element_interactions_diagnostic(jsx_opening_el.span));
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        (r#"<div className="foo" />;"#, None, None),
+        (r"<div onClick={undefined} />;", None, None),
+        (r"<div onClick={null} />;", None, None),
+        (r"<div onFocus={() => {}} />;", None, None),
+        (r"<div onClick={() => {}} aria-hidden />;", None, None),
+        (r#"<div onClick={() => {}} aria-hidden="true" />;"#, None, None),
+        (r#"<div onClick={() => {}} role="presentation" />;"#, None, None),
+        (r#"<div onClick={() => {}} role="none" />;"#, None, None),
+        (r#"<div onClick={() => {}} role="button" />;"#, None, None),
+        (r#"<div onClick={() => {}} role="article" />;"#, None, None),
+        (r#"<div onClick={() => {}} role="foo button" />;"#, None, None),
+        (r"<div onClick={() => {}} role={ROLE} />;", None, None),
+        (r#"<button onClick={() => {}} className="foo" />;"#, None, None),
+        (r#"<a onClick={() => {}} href="http://x.y.z" />;"#, None, None),
+        (r#"<input type="text" onClick={() => {}} />;"#, None, None),
+        (r"<select onClick={() => {}} />;", None, None),
+        (r"<textarea onKeyDown={() => {}} />;", None, None),
+        (r"<h1 onClick={() => {}} />;", None, None),
+        (r"<li onClick={() => {}} />;", None, None),
+        (r"<ul onClick={() => {}} />;", None, None),
+        (r"<article onClick={() => {}} />;", None, None),
+        (r"<p onClick={() => {}} />;", None, None),
+        (r"<section onClick={() => {}} />;", None, None),
+        (r"<TestComponent onClick={doFoo} />", None, None),
+        (r"<Button onClick={doFoo} />", None, None),
+        (
+            r"<div onMouseEnter={() => {}} />;",
+            Some(serde_json::json!([{ "handlers": ["onClick"] }])),
+            None,
+        ),
+    ];
+
+    let fail = vec![
+        (r"<div onClick={() => {}} />;", None, None),
+        (r"<div onMouseDown={() => {}} />;", None, None),
+        (r"<div onMouseUp={() => {}} />;", None, None),
+        (r"<div onKeyPress={() => {}} />;", None, None),
+        (r"<div onKeyDown={() => {}} />;", None, None),
+        (r"<span onKeyUp={() => {}} />;", None, None),
+        (r"<a onClick={() => {}} />;", None, None),
+        (r#"<div onClick={() => {}} role="foo" />;"#, None, None),
+        (r"<i onClick={() => {}} />;", None, None),
+        (
+            r"<div onMouseEnter={() => {}} />;",
+            Some(serde_json::json!([{ "handlers": ["onMouseEnter"] }])),
+            None,
+        ),
+        (
+            r"<div onClick={() => {}} role={ROLE} />;",
+            Some(serde_json::json!([{ "allowExpressionValues": false }])),
+            None,
+        ),
+        (
+            r"<Div onClick={() => {}} />;",
+            None,
+            Some(
+                serde_json::json!({ "settings": { "jsx-a11y": { "components": { "Div": "div" } } } }),
+            ),
+        ),
+    ];
+
+    Tester::new(NoStaticElementInteractions::NAME, NoStaticElementInteractions::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
@@ -0,0 +1,440 @@
+use oxc_ast::{AstKind, ast::Argument};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_regular_expression::{
+    ast::{
+        CharacterClass, CharacterClassContents, CharacterClassEscapeKind, Disjunction, Quantifier,
+        Term,
+    },
+    visit::{Visit, walk},
+};
+use oxc_semantic::IsGlobalReference;
+use oxc_span::{GetSpan, Span};
+
+use crate::{AstNode, context::LintContext, rule::Rule, utils::run_on_regex_node};
+
+fn nested_quantifier_diagnostic(outer: Span, inner: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Nested quantifiers can backtrack catastrophically")
+        .with_help("Text which almost matches takes exponential time to reject. Make the repeated parts match different characters, or remove the inner quantifier.")
+        .with_labels([
+            inner.label("This repetition can match the same text as the repetition around it"),
+            outer.label("which repeats it here"),
+        ])
+}
+
+fn overlapping_alternatives_diagnostic(first: Span, second: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Repeated alternatives which match the same character can backtrack catastrophically")
+        .with_help("Text which almost matches takes exponential time to reject. Merge the alternatives into a character class, e.g. `(\\w|\\d)*` into `\\w*`.")
+        .with_labels([
+            first.label("This alternative"),
+            second.label("matches some of the same characters as this one"),
+        ])
+}
+
+fn dynamic_pattern_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("RegExp is built from a dynamic pattern")
+        .with_help("A pattern which comes from user input can backtrack catastrophically. Escape or validate it before building the RegExp.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnsafeRegex {
+    check_dynamic: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow regular expressions which can backtrack catastrophically,
+    /// i.e. nested quantifiers and repeated alternatives which can match the same text.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// When such a regular expression fails to match, the engine tries every way of
+    /// splitting the text between the quantifiers, which takes exponential time.
+    /// A short input, e.g. `"aaaaaaaaaaaaaaaaaaaaaaaaaaaaaa!"` for `/^(a+)+$/`,
+    /// can block the event loop for minutes ([ReDoS](https://owasp.org/www-community/attacks/Regular_expression_Denial_of_Service_-_ReDoS)).
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// /^(a+)+$/;
+    /// /(\w+\s?)*$/;
+    /// /^(.|\s)*$/;
+    /// new RegExp("(\\d*)*");
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// /^a+$/;
+    /// /(\w+\s)*$/;
+    /// /^[^]*$/;
+    /// /("[^"]*")*/;
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// ```json
+    /// {
+    ///   "rules": {
+    ///     "no-unsafe-regex": ["error", { "checkDynamic": true }]
+    ///   }
+    /// }
+    /// ```
+    ///
+    /// - `checkDynamic`: Also report `RegExp`s built from a pattern which is not a constant string,
+    /// e.g. `new RegExp(input)`. Such patterns can not be analyzed, and an attacker controlling them
+    /// can always make them backtrack catastrophically. Defaults to `false`.
+    NoUnsafeRegex,
+    oxc,
+    suspicious,
+    requires = regex
+);
+
+impl Rule for NoUnsafeRegex {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let check_dynamic = value
+            .get(0)
+            .and_then(|config| config.get("checkDynamic"))
+            .and_then(serde_json::Value::as_bool)
+            .unwrap_or_default();
+        Self { check_dynamic }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        run_on_regex_node(node, ctx, |pattern, _span| {
+            let mut finder = BacktrackingFinder::default();
+            finder.visit_pattern(pattern);
+            for diagnostic in finder.diagnostics {
+                ctx.diagnostic(diagnostic);
+            }
+        });
+
+        if self.check_dynamic {
+            if let Some(span) = dynamic_pattern(node, ctx) {
+                ctx.diagnostic(dynamic_pattern_diagnostic(span));
+            }
+        }
+    }
+}
+
+/// The pattern argument of `new RegExp(pattern)` or `RegExp(pattern)`, if it is not a constant.
+fn dynamic_pattern(node: &AstNode, ctx: &LintContext) -> Option<Span> {
+    let (callee, arguments) = match node.kind() {
+        AstKind::NewExpression(expr) => (&expr.callee, &expr.arguments),
+        AstKind::CallExpression(expr) => (&expr.callee, &expr.arguments),
+        _ => return None,
+    };
+    if !callee.is_global_reference_name("RegExp", ctx.scoping()) {
+        return None;
+    }
+    let pattern = arguments.first()?;
+    match pattern {
+        Argument::StringLiteral(_) | Argument::RegExpLiteral(_) => None,
+        Argument::TemplateLiteral(template) if template.expressions.is_empty() => None,
+        _ => Some(pattern.span()),
+    }
+}
+
+#[derive(Default)]
+struct BacktrackingFinder {
+    diagnostics: Vec<OxcDiagnostic>,
+}
+
+impl<'a> Visit<'a> for BacktrackingFinder {
+    fn visit_quantifier(&mut self, it: &Quantifier<'a>) {
+        if it.max.is_none() {
+            // Only report the outermost repetition.
+            if let Some(inner) = find_ambiguous_quantifier(&it.body) {
+                let outer = Span::new(it.body.span().end, it.span.end);
+                self.diagnostics.push(nested_quantifier_diagnostic(outer, inner.span));
+                return;
+            }
+            if let Some((first, second)) = find_overlapping_alternatives(&it.body) {
+                self.diagnostics.push(overlapping_alternatives_diagnostic(first, second));
+                return;
+            }
+        }
+        walk::walk_quantifier(self, it);
+    }
+}
+
+/// A quantifier in `term` which matches a variable number of times, and whose characters
+/// can also be matched by the terms around it, so that repeating `term` can match the same
+/// text in many ways, e.g. `a+` in `(a+b?)`.
+fn find_ambiguous_quantifier<'t, 'a>(term: &'t Term<'a>) -> Option<&'t Quantifier<'a>> {
+    match term {
+        Term::Quantifier(quantifier)
+            if quantifier.max.is_none_or(|max| max > 1 && max > quantifier.min) =>
+        {
+            Some(quantifier)
+        }
+        Term::Quantifier(quantifier) => find_ambiguous_quantifier(&quantifier.body),
+        Term::CapturingGroup(group) => find_ambiguous_quantifier_in(&group.body),
+        Term::IgnoreGroup(group) => find_ambiguous_quantifier_in(&group.body),
+        _ => None,
+    }
+}
+
+fn find_ambiguous_quantifier_in<'t, 'a>(
+    disjunction: &'t Disjunction<'a>,
+) -> Option<&'t Quantifier<'a>> {
+    disjunction.body.iter().find_map(|alternative| {
+        alternative.body.iter().enumerate().find_map(|(i, term)| {
+            let quantifier = find_ambiguous_quantifier(term)?;
+            let chars = CharSet::of_term(&quantifier.body);
+            // e.g. `b` in `(a+b)+` separates the repetitions of `a+`.
+            let is_separated = alternative.body.iter().enumerate().any(|(j, other)| {
+                j != i && !is_nullable(other) && !CharSet::of_term(other).overlaps(&chars)
+            });
+            (!is_separated).then_some(quantifier)
+        })
+    })
+}
+
+/// Two alternatives of the group `term` which each match one character, and can match the same
+/// character, e.g. `\w` and `\d` in `(\w|\d)`.
+fn find_overlapping_alternatives(term: &Term) -> Option<(Span, Span)> {
+    let disjunction = match term {
+        Term::CapturingGroup(group) => &group.body,
+        Term::IgnoreGroup(group) => &group.body,
+        _ => return None,
+    };
+    let single_chars = disjunction
+        .body
+        .iter()
+        .filter_map(|alternative| match &alternative.body[..] {
+            [term] if matches_single_char(term) => Some((term.span(), CharSet::of_term(term))),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    single_chars.iter().enumerate().find_map(|(i, (first, first_chars))| {
+        single_chars[i + 1..]
+            .iter()
+            .find(|(_, second_chars)| first_chars.overlaps(second_chars))
+            .map(|(second, _)| (*first, *second))
+    })
+}
+
+fn matches_single_char(term: &Term) -> bool {
+    match term {
+        Term::Character(_)
+        | Term::Dot(_)
+        | Term::CharacterClassEscape(_)
+        | Term::UnicodePropertyEscape(_) => true,
+        Term::CharacterClass(class) => !class.strings,
+        _ => false,
+    }
+}
+
+/// Whether `term` can match the empty string.
+fn is_nullable(term: &Term) -> bool {
+    match term {
+        Term::BoundaryAssertion(_)
+        | Term::LookAroundAssertion(_)
+        | Term::IndexedReference(_)
+        | Term::NamedReference(_) => true,
+        Term::Quantifier(quantifier) => quantifier.min == 0 || is_nullable(&quantifier.body),
+        Term::CapturingGroup(group) => is_disjunction_nullable(&group.body),
+        Term::IgnoreGroup(group) => is_disjunction_nullable(&group.body),
+        Term::Character(_)
+        | Term::Dot(_)
+        | Term::CharacterClassEscape(_)
+        | Term::UnicodePropertyEscape(_)
+        | Term::CharacterClass(_) => false,
+    }
+}
+
+fn is_disjunction_nullable(disjunction: &Disjunction) -> bool {
+    disjunction.body.iter().any(|alternative| alternative.body.iter().all(is_nullable))
+}
+
+const DIGIT: &[(u32, u32)] = &[(0x30, 0x39)];
+const WORD: &[(u32, u32)] = &[(0x30, 0x39), (0x41, 0x5A), (0x5F, 0x5F), (0x61, 0x7A)];
+const WHITESPACE: &[(u32, u32)] = &[
+    (0x09, 0x0D),
+    (0x20, 0x20),
+    (0xA0, 0xA0),
+    (0x1680, 0x1680),
+    (0x2000, 0x200A),
+    (0x2028, 0x2029),
+    (0x202F, 0x202F),
+    (0x205F, 0x205F),
+    (0x3000, 0x3000),
+    (0xFEFF, 0xFEFF),
+];
+
+/// The characters a term can match, as inclusive ranges of code points.
+///
+/// This is an over-approximation: Unicode properties, case-insensitivity and
+/// unions with negated sets are treated as matching more characters.
+#[derive(Debug, Default, Clone)]
+struct CharSet {
+    ranges: Vec<(u32, u32)>,
+    /// The set is all characters except `ranges`.
+    negated: bool,
+}
+
+impl CharSet {
+    fn all() -> Self {
+        Self { ranges: vec![], negated: true }
+    }
+
+    fn of(ranges: &[(u32, u32)], negated: bool) -> Self {
+        Self { ranges: ranges.to_vec(), negated }
+    }
+
+    fn of_term(term: &Term) -> Self {
+        match term {
+            Term::BoundaryAssertion(_) | Term::LookAroundAssertion(_) => Self::default(),
+            Term::Character(character) => Self::of(&[(character.value, character.value)], false),
+            Term::Dot(_)
+            | Term::UnicodePropertyEscape(_)
+            | Term::IndexedReference(_)
+            | Term::NamedReference(_) => Self::all(),
+            Term::CharacterClassEscape(escape) => Self::of_escape(escape.kind),
+            Term::CharacterClass(class) => Self::of_class(class),
+            Term::Quantifier(quantifier) => Self::of_term(&quantifier.body),
+            Term::CapturingGroup(group) => Self::of_disjunction(&group.body),
+            Term::IgnoreGroup(group) => Self::of_disjunction(&group.body),
+        }
+    }
+
+    fn of_disjunction(disjunction: &Disjunction) -> Self {
+        disjunction
+            .body
+            .iter()
+            .flat_map(|alternative| alternative.body.iter())
+            .fold(Self::default(), |chars, term| chars.union(&Self::of_term(term)))
+    }
+
+    fn of_escape(kind: CharacterClassEscapeKind) -> Self {
+        match kind {
+            CharacterClassEscapeKind::D => Self::of(DIGIT, false),
+            CharacterClassEscapeKind::NegativeD => Self::of(DIGIT, true),
+            CharacterClassEscapeKind::S => Self::of(WHITESPACE, false),
+            CharacterClassEscapeKind::NegativeS => Self::of(WHITESPACE, true),
+            CharacterClassEscapeKind::W => Self::of(WORD, false),
+            CharacterClassEscapeKind::NegativeW => Self::of(WORD, true),
+        }
+    }
+
+    fn of_class(class: &CharacterClass) -> Self {
+        let chars = class.body.iter().fold(Self::default(), |chars, contents| {
+            let contents = match contents {
+                CharacterClassContents::CharacterClassRange(range) => {
+                    Self::of(&[(range.min.value, range.max.value)], false)
+                }
+                CharacterClassContents::Character(character) => {
+                    Self::of(&[(character.value, character.value)], false)
+                }
+                CharacterClassContents::CharacterClassEscape(escape) => {
+                    Self::of_escape(escape.kind)
+                }
+                CharacterClassContents::NestedCharacterClass(class) => Self::of_class(class),
+                CharacterClassContents::UnicodePropertyEscape(_)
+                | CharacterClassContents::ClassStringDisjunction(_) => Self::all(),
+            };
+            chars.union(&contents)
+        });
+        if class.negative { Self { ranges: chars.ranges, negated: !chars.negated } } else { chars }
+    }
+
+    fn union(&self, other: &Self) -> Self {
+        match (self.negated, other.negated) {
+            (false, false) => {
+                Self { ranges: [&self.ranges[..], &other.ranges[..]].concat(), negated: false }
+            }
+            // Keep only the excluded ranges which the positive set does not touch.
+            (false, true) | (true, false) => {
+                let (positive, negative) = if self.negated { (other, self) } else { (self, other) };
+                let ranges = negative
+                    .ranges
+                    .iter()
+                    .filter(|range| {
+                        !positive.ranges.iter().any(|other| intersects(**range, *other))
+                    })
+                    .copied()
+                    .collect();
+                Self { ranges, negated: true }
+            }
+            (true, true) => Self::all(),
+        }
+    }
+
+    fn overlaps(&self, other: &Self) -> bool {
+        match (self.negated, other.negated) {
+            (false, false) => self
+                .ranges
+                .iter()
+                .any(|range| other.ranges.iter().any(|other| intersects(*range, *other))),
+            (false, true) => self.has_chars_outside(other),
+            (true, false) => other.has_chars_outside(self),
+            (true, true) => true,
+        }
+    }
+
+    /// Whether a range of this positive set is not excluded by the negated set `negated`.
+    fn has_chars_outside(&self, negated: &Self) -> bool {
+        self.ranges.iter().any(|&(min, max)| {
+            !negated
+                .ranges
+                .iter()
+                .any(|&(other_min, other_max)| other_min <= min && max <= other_max)
+        })
+    }
+}
+
+fn intersects(a: (u32, u32), b: (u32, u32)) -> bool {
+    a.0 <= b.1 && b.0 <= a.1
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        (r"/^a+$/", None),
+        (r"/(\w+\s)*$/", None),
+        (r"/(\s*,\s*\w+)*/", None),
+        (r#"/("[^"]*")*/"#, None),
+        (r"/(a+b)+/", None),
+        (r"/(ab|ac)*/", None),
+        (r"/(a|b)*/", None),
+        (r"/(\d|[a-z])+/", None),
+        (r"/(\w|\W)*/", None),
+        (r"/(a{2})+/", None),
+        (r"/(a+){2}/", None),
+        (r"/(?:[^\\]|\\.)*/", None),
+        (r#"new RegExp("a+b+")"#, None),
+        ("new RegExp(pattern)", None),
+        ("new RegExp('a+', flags)", Some(serde_json::json!([{ "checkDynamic": true }]))),
+        ("new RegExp(`a+`)", Some(serde_json::json!([{ "checkDynamic": true }]))),
+        ("new RegExp(/a+/)", Some(serde_json::json!([{ "checkDynamic": true }]))),
+        (
+            "function f(RegExp) { return new RegExp(pattern) }",
+            Some(serde_json::json!([{ "checkDynamic": true }])),
+        ),
+    ];
+
+    let fail = vec![
+        (r"/^(a+)+$/", None),
+        (r"/(a*)*b/", None),
+        (r"/(\w+\s?)*$/", None),
+        (r"/(.*,)*/", None),
+        (r"/(?:[^\\]+|\\.)*/", None),
+        (r"/((ab)*c?)+/", None),
+        (r"/(a{1,5})+$/", None),
+        (r"/^(.|\s)*$/", None),
+        (r"/(\w|\d)+/", None),
+        (r"/(a|a)*/", None),
+        (r"/([a-f]|[^g-z])*/", None),
+        (r#"new RegExp("(\\d*)*")"#, None),
+        (r#"RegExp("(a|a)+", "u")"#, None),
+        ("new RegExp(input)", Some(serde_json::json!([{ "checkDynamic": true }]))),
+        ("RegExp(`^${prefix}`)", Some(serde_json::json!([{ "checkDynamic": true }]))),
+    ];
+
+    Tester::new(NoUnsafeRegex::NAME, NoUnsafeRegex::PLUGIN, pass, fail).test_and_snapshot();
+}
@@ -0,0 +1,370 @@
+use oxc_ast::{
+    AstKind,
+    ast::{
+        BindingPatternKind, Expression, IdentifierReference, JSXAttributeItem, JSXAttributeName,
+        JSXAttributeValue, JSXElementName,
+    },
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    AstNode,
+    context::{ContextHost, LintContext},
+    rule::Rule,
+    utils::is_react_function_call,
+};
+
+fn jsx_no_bind_diagnostic(violation: Violation, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(violation.message())
+        .with_help("Define the function outside of render, or memoize it with `useCallback`, so that the prop is the same on every render.")
+        .with_label(span)
+}
+
+fn jsx_no_bind_reference_diagnostic(
+    violation: Violation,
+    decl_span: Span,
+    prop_span: Span,
+) -> OxcDiagnostic {
+    OxcDiagnostic::warn(violation.message())
+        .with_help("Define the function outside of render, or memoize it with `useCallback`, so that the prop is the same on every render.")
+        .with_labels([
+            decl_span.label("The function is created here"),
+            prop_span.label("And passed as a prop here"),
+        ])
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct JsxNoBind(Box<JsxNoBindConfig>);
+
+#[derive(Debug, Default, Clone)]
+pub struct JsxNoBindConfig {
+    ignore_refs: bool,
+    allow_arrow_functions: bool,
+    allow_functions: bool,
+    allow_bind: bool,
+    ignore_dom_components: bool,
+    memoized_components_only: bool,
+}
+
+impl std::ops::Deref for JsxNoBind {
+    type Target = JsxNoBindConfig;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow `.bind()`, arrow functions and function expressions in JSX props.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A function created in render is a new function on every render. Passed as a prop, it makes
+    /// memoized children like `React.memo` components and `PureComponent`s rerender every time,
+    /// and it makes effects depending on the prop run again.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```jsx
+    /// <Foo onClick={this._handleClick.bind(this)} />
+    /// <Foo onClick={() => console.log('Hello!')} />
+    /// <Foo onClick={function () { alert('1337') }} />
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```jsx
+    /// function Component() {
+    ///   const handleClick = useCallback(() => console.log('Hello!'), []);
+    ///   return <Foo onClick={handleClick} />;
+    /// }
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// ```json
+    /// {
+    ///   "react/jsx-no-bind": ["error", {
+    ///     "ignoreRefs": false,
+    ///     "allowArrowFunctions": false,
+    ///     "allowFunctions": false,
+    ///     "allowBind": false,
+    ///     "ignoreDOMComponents": false,
+    ///     "memoizedComponentsOnly": false
+    ///   }]
+    /// }
+    /// ```
+    ///
+    /// - `ignoreRefs`: allow functions in the `ref` prop.
+    /// - `allowArrowFunctions`: allow arrow functions.
+    /// - `allowFunctions`: allow function expressions.
+    /// - `allowBind`: allow `.bind()` calls.
+    /// - `ignoreDOMComponents`: allow functions in the props of DOM elements, like `<div>`.
+    /// - `memoizedComponentsOnly`: only report the props of components defined in the same file
+    ///   with `memo()` or as a `PureComponent`, where a new function defeats the memoization.
+    ///
+    /// Examples of **incorrect** code with `{ "memoizedComponentsOnly": true }`:
+    /// ```jsx
+    /// const Item = memo(function Item({ onSelect }) { /* ... */ });
+    ///
+    /// function List({ items }) {
+    ///   return items.map((item) => <Item onSelect={() => select(item)} />);
+    /// }
+    /// ```
+    JsxNoBind,
+    react,
+    perf
+);
+
+impl Rule for JsxNoBind {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let value = value.as_array().and_then(|arr| arr.first()).and_then(|val| val.as_object());
+        let get_bool = |name: &str| {
+            value
+                .and_then(|val| val.get(name))
+                .and_then(serde_json::Value::as_bool)
+                .unwrap_or(false)
+        };
+
+        Self(Box::new(JsxNoBindConfig {
+            ignore_refs: get_bool("ignoreRefs"),
+            allow_arrow_functions: get_bool("allowArrowFunctions"),
+            allow_functions: get_bool("allowFunctions"),
+            allow_bind: get_bool("allowBind"),
+            ignore_dom_components: get_bool("ignoreDOMComponents"),
+            memoized_components_only: get_bool("memoizedComponentsOnly"),
+        }))
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::JSXOpeningElement(element) = node.kind() else {
+            return;
+        };
+        match &element.name {
+            // `<div>`
+            JSXElementName::Identifier(_) | JSXElementName::NamespacedName(_) => {
+                if self.ignore_dom_components || self.memoized_components_only {
+                    return;
+                }
+            }
+            JSXElementName::IdentifierReference(ident) => {
+                if self.memoized_components_only && !is_memoized_component(ident, ctx) {
+                    return;
+                }
+            }
+            JSXElementName::MemberExpression(_) | JSXElementName::ThisExpression(_) => {
+                if self.memoized_components_only {
+                    return;
+                }
+            }
+        }
+
+        for attr in &element.attributes {
+            let JSXAttributeItem::Attribute(attr) = attr else {
+                continue;
+            };
+            if self.ignore_refs
+                && matches!(&attr.name, JSXAttributeName::Identifier(ident) if ident.name == "ref")
+            {
+                continue;
+            }
+            let Some(JSXAttributeValue::ExpressionContainer(container)) = &attr.value else {
+                continue;
+            };
+            let Some(expr) = container.expression.as_expression() else {
+                continue;
+            };
+            self.check_prop_value(expr.get_inner_expression(), ctx);
+        }
+    }
+
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        ctx.source_type().is_jsx()
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Violation {
+    BindCall,
+    ArrowFunction,
+    Function,
+}
+
+impl Violation {
+    fn message(self) -> &'static str {
+        match self {
+            Self::BindCall => "JSX props should not use `.bind()`",
+            Self::ArrowFunction => "JSX props should not use arrow functions",
+            Self::Function => "JSX props should not use functions",
+        }
+    }
+}
+
+impl JsxNoBind {
+    fn check_prop_value<'a>(&self, expr: &Expression<'a>, ctx: &LintContext<'a>) {
+        if let Some((violation, span)) = self.get_violation(expr) {
+            ctx.diagnostic(jsx_no_bind_diagnostic(violation, span));
+            return;
+        }
+
+        // `const handleClick = () => {}; <Foo onClick={handleClick} />`
+        let Expression::Identifier(ident) = expr else {
+            return;
+        };
+        let Some(symbol_id) = ctx.scoping().get_reference(ident.reference_id()).symbol_id() else {
+            return;
+        };
+        // Functions declared at the root scope are created once.
+        if ctx.scoping().symbol_scope_id(symbol_id) == ctx.scoping().root_scope_id() {
+            return;
+        }
+        let declaration = ctx.nodes().get_node(ctx.scoping().symbol_declaration(symbol_id));
+        let violation = match declaration.kind() {
+            AstKind::VariableDeclarator(decl) => match (&decl.id.kind, &decl.init) {
+                (BindingPatternKind::BindingIdentifier(id), Some(init)) => self
+                    .get_violation(init.get_inner_expression())
+                    .map(|(violation, _)| (violation, id.span)),
+                _ => None,
+            },
+            AstKind::Function(func) if !self.allow_functions => {
+                Some((Violation::Function, func.id.as_ref().map_or(func.span, GetSpan::span)))
+            }
+            _ => None,
+        };
+        if let Some((violation, decl_span)) = violation {
+            ctx.diagnostic(jsx_no_bind_reference_diagnostic(violation, decl_span, ident.span));
+        }
+    }
+
+    fn get_violation(&self, expr: &Expression) -> Option<(Violation, Span)> {
+        match expr {
+            Expression::CallExpression(call) if !self.allow_bind => {
+                let member = call.callee.get_inner_expression().as_member_expression()?;
+                (member.static_property_name() == Some("bind"))
+                    .then_some((Violation::BindCall, call.span))
+            }
+            Expression::ArrowFunctionExpression(arrow) if !self.allow_arrow_functions => {
+                Some((Violation::ArrowFunction, arrow.span))
+            }
+            Expression::FunctionExpression(func) if !self.allow_functions => {
+                Some((Violation::Function, func.span))
+            }
+            Expression::ConditionalExpression(cond) => self
+                .get_violation(cond.consequent.get_inner_expression())
+                .or_else(|| self.get_violation(cond.alternate.get_inner_expression())),
+            _ => None,
+        }
+    }
+}
+
+/// Whether `ident` is a component declared with `memo()` or as a `PureComponent`.
+fn is_memoized_component(ident: &IdentifierReference, ctx: &LintContext) -> bool {
+    let Some(symbol_id) = ctx.scoping().get_reference(ident.reference_id()).symbol_id() else {
+        return false;
+    };
+    match ctx.nodes().kind(ctx.scoping().symbol_declaration(symbol_id)) {
+        // `const Foo = memo(() => <div />)`
+        AstKind::VariableDeclarator(decl) => {
+            let Some(Expression::CallExpression(call)) =
+                decl.init.as_ref().map(Expression::get_inner_expression)
+            else {
+                return false;
+            };
+            is_react_function_call(call, "memo")
+        }
+        // `class Foo extends PureComponent {}`
+        AstKind::Class(class) => class.super_class.as_ref().is_some_and(|super_class| {
+            match super_class.get_inner_expression() {
+                Expression::Identifier(ident) => ident.name == "PureComponent",
+                expr => expr
+                    .as_member_expression()
+                    .is_some_and(|member| member.static_property_name() == Some("PureComponent")),
+            }
+        }),
+        _ => false,
+    }
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("<div onClick={this._handleClick}></div>", None),
+        ("<div meaningOfLife={42}></div>", None),
+        ("<div onClick={getHandler()}></div>", None),
+        (
+            "const handleClick = () => {}; function Foo() { return <div onClick={handleClick} /> }",
+            None,
+        ),
+        (
+            "function Foo() { const handleClick = useCallback(() => {}, []); return <div onClick={handleClick} /> }",
+            None,
+        ),
+        (
+            "<div ref={c => this._input = c}></div>",
+            Some(serde_json::json!([{ "ignoreRefs": true }])),
+        ),
+        (
+            "<div onClick={() => alert('1337')}></div>",
+            Some(serde_json::json!([{ "allowArrowFunctions": true }])),
+        ),
+        (
+            "<div onClick={function () { alert('1337') }}></div>",
+            Some(serde_json::json!([{ "allowFunctions": true }])),
+        ),
+        (
+            "<div onClick={this._handleClick.bind(this)}></div>",
+            Some(serde_json::json!([{ "allowBind": true }])),
+        ),
+        (
+            "<div onClick={() => alert('1337')}></div>",
+            Some(serde_json::json!([{ "ignoreDOMComponents": true }])),
+        ),
+        (
+            "function List() { return <Item onSelect={() => {}} /> }",
+            Some(serde_json::json!([{ "memoizedComponentsOnly": true }])),
+        ),
+        (
+            "const Item = (props) => <div />; function List() { return <Item onSelect={() => {}} /> }",
+            Some(serde_json::json!([{ "memoizedComponentsOnly": true }])),
+        ),
+        (
+            "const Item = memo((props) => <div />); function List() { return <Item onSelect={select} /> }",
+            Some(serde_json::json!([{ "memoizedComponentsOnly": true }])),
+        ),
+    ];
+
+    let fail = vec![
+        ("<div onClick={this._handleClick.bind(this)}></div>", None),
+        ("<div onClick={someGlobalFunction.bind(this)}></div>", None),
+        ("<div onClick={() => alert('1337')}></div>", None),
+        ("<div onClick={async () => alert('1337')}></div>", None),
+        ("<div onClick={function () { alert('1337') }}></div>", None),
+        ("<div onClick={cond ? () => {} : undefined}></div>", None),
+        ("<Foo ref={c => this._input = c}></Foo>", None),
+        (
+            "function Foo() { const handleClick = () => {}; return <div onClick={handleClick} /> }",
+            None,
+        ),
+        ("function Foo() { function handleClick() {} return <div onClick={handleClick} /> }", None),
+        (
+            "<Foo onClick={() => alert('1337')}></Foo>",
+            Some(serde_json::json!([{ "ignoreDOMComponents": true }])),
+        ),
+        (
+            "const Item = memo((props) => <div />); function List() { return <Item onSelect={() => {}} /> }",
+            Some(serde_json::json!([{ "memoizedComponentsOnly": true }])),
+        ),
+        (
+            "class Item extends React.PureComponent {} function List() { return <Item onSelect={this.select.bind(this)} /> }",
+            Some(serde_json::json!([{ "memoizedComponentsOnly": true }])),
+        ),
+    ];
+
+    Tester::new(JsxNoBind::NAME, JsxNoBind::PLUGIN, pass, fail).test_and_snapshot();
+}
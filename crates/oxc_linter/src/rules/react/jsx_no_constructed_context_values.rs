@@ -0,0 +1,221 @@
+use oxc_ast::{
+    AstKind,
+    ast::{BindingPatternKind, Expression, JSXAttributeValue, JSXElementName},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    AstNode,
+    context::{ContextHost, LintContext},
+    rule::Rule,
+    utils::{get_prop_value, has_jsx_prop},
+};
+
+fn jsx_no_constructed_context_values_diagnostic(kind: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "The {kind} passed as the value prop to the Context provider changes every render."
+    ))
+    .with_help("Wrap it in a `useMemo` hook, or a `useCallback` hook for functions, to keep the same value between renders.")
+    .with_label(span)
+}
+
+fn jsx_no_constructed_context_values_reference_diagnostic(
+    kind: &str,
+    init_span: Span,
+    value_span: Span,
+) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!(
+        "The {kind} passed as the value prop to the Context provider changes every render."
+    ))
+    .with_help("Wrap it in a `useMemo` hook, or a `useCallback` hook for functions, to keep the same value between renders.")
+    .with_labels([
+        init_span.label(format!("The {kind} is constructed here")),
+        value_span.label("And passed to the Context provider here"),
+    ])
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct JsxNoConstructedContextValues;
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow values constructed during render, like objects, arrays and functions, as the
+    /// `value` prop of a Context provider.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A new object, array or function is constructed on every render. React compares the value
+    /// of a Context provider by identity, so every component using the context rerenders whenever
+    /// the component rendering the provider rerenders, even when the contents of the value did not
+    /// change.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```jsx
+    /// function Component() {
+    ///   const [user, setUser] = useState(null);
+    ///   return (
+    ///     <UserContext.Provider value={{ user, setUser }}>
+    ///       <App />
+    ///     </UserContext.Provider>
+    ///   );
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```jsx
+    /// function Component() {
+    ///   const [user, setUser] = useState(null);
+    ///   const value = useMemo(() => ({ user, setUser }), [user]);
+    ///   return (
+    ///     <UserContext.Provider value={value}>
+    ///       <App />
+    ///     </UserContext.Provider>
+    ///   );
+    /// }
+    /// ```
+    JsxNoConstructedContextValues,
+    react,
+    perf
+);
+
+impl Rule for JsxNoConstructedContextValues {
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::JSXOpeningElement(element) = node.kind() else {
+            return;
+        };
+        // Values constructed at the root scope are constructed once.
+        if node.scope_id() == ctx.scoping().root_scope_id() {
+            return;
+        }
+        // `<Context.Provider>`
+        let JSXElementName::MemberExpression(name) = &element.name else {
+            return;
+        };
+        if name.property.name != "Provider" {
+            return;
+        }
+        let Some(JSXAttributeValue::ExpressionContainer(container)) =
+            has_jsx_prop(element, "value").and_then(get_prop_value)
+        else {
+            return;
+        };
+        let Some(value) = container.expression.as_expression() else {
+            return;
+        };
+        let value = value.get_inner_expression();
+
+        if let Some((kind, span)) = get_constructed_kind(value) {
+            ctx.diagnostic(jsx_no_constructed_context_values_diagnostic(kind, span));
+            return;
+        }
+
+        // `const value = { user }; <Context.Provider value={value}>`
+        let Expression::Identifier(ident) = value else {
+            return;
+        };
+        let Some(symbol_id) = ctx.scoping().get_reference(ident.reference_id()).symbol_id() else {
+            return;
+        };
+        if ctx.scoping().symbol_scope_id(symbol_id) == ctx.scoping().root_scope_id() {
+            return;
+        }
+        let declaration = ctx.nodes().get_node(ctx.scoping().symbol_declaration(symbol_id));
+        let constructed = match declaration.kind() {
+            AstKind::VariableDeclarator(decl) => match (&decl.id.kind, &decl.init) {
+                (BindingPatternKind::BindingIdentifier(_), Some(init)) => {
+                    get_constructed_kind(init.get_inner_expression())
+                }
+                _ => None,
+            },
+            AstKind::Function(func) => {
+                Some(("function", func.id.as_ref().map_or(func.span, GetSpan::span)))
+            }
+            AstKind::Class(class) => {
+                Some(("class", class.id.as_ref().map_or(class.span, GetSpan::span)))
+            }
+            _ => None,
+        };
+        if let Some((kind, init_span)) = constructed {
+            ctx.diagnostic(jsx_no_constructed_context_values_reference_diagnostic(
+                kind, init_span, ident.span,
+            ));
+        }
+    }
+
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        ctx.source_type().is_jsx()
+    }
+}
+
+/// The kind and span of the value constructed by `expr`, if any.
+fn get_constructed_kind(expr: &Expression) -> Option<(&'static str, Span)> {
+    let kind = match expr {
+        Expression::ObjectExpression(_) => "object",
+        Expression::ArrayExpression(_) => "array",
+        Expression::ArrowFunctionExpression(_) | Expression::FunctionExpression(_) => "function",
+        Expression::ClassExpression(_) => "class",
+        Expression::NewExpression(_) => "new expression",
+        Expression::JSXElement(_) | Expression::JSXFragment(_) => "JSX element",
+        Expression::RegExpLiteral(_) => "regular expression",
+        Expression::ConditionalExpression(expr) => {
+            return get_constructed_kind(expr.consequent.get_inner_expression())
+                .or_else(|| get_constructed_kind(expr.alternate.get_inner_expression()));
+        }
+        Expression::LogicalExpression(expr) => {
+            return get_constructed_kind(expr.left.get_inner_expression())
+                .or_else(|| get_constructed_kind(expr.right.get_inner_expression()));
+        }
+        Expression::AssignmentExpression(expr) => {
+            return get_constructed_kind(expr.right.get_inner_expression());
+        }
+        _ => return None,
+    };
+    Some((kind, expr.span()))
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        "const Component = () => <Context.Provider value={props} />",
+        "const Component = () => <Context.Provider value={100} />",
+        "const Component = () => <Context.Provider value='Some string' />",
+        "function Component() { const a = useMemo(() => ({ foo: 'bar' }), []); return <Context.Provider value={a} /> }",
+        "function Component() { const foo = useCallback(() => {}, []); return <Context.Provider value={foo} /> }",
+        "function Component() { const [state, setState] = useState(); return <Context.Provider value={state} /> }",
+        "const value = { foo: 'bar' }; function Component() { return <Context.Provider value={value} /> }",
+        "function foo() {}; function Component() { return <Context.Provider value={foo} /> }",
+        "function Component() { return <Provider value={{ foo: 'bar' }} /> }",
+        "function Component() { return <Context.Consumer value={{ foo: 'bar' }} /> }",
+        "const element = <Context.Provider value={{ foo: 'bar' }} />",
+    ];
+
+    let fail = vec![
+        "function Component() { return <Context.Provider value={{ foo: 'bar' }} /> }",
+        "function Component() { return <Context.Provider value={[1, 2, 3]} /> }",
+        "function Component() { return <Context.Provider value={() => {}} /> }",
+        "function Component() { return <Context.Provider value={new Map()} /> }",
+        "function Component() { return <Context.Provider value={<div />} /> }",
+        "function Component() { return <Context.Provider value={props.value || {}} /> }",
+        "function Component() { return <Context.Provider value={cond ? [] : props.value} /> }",
+        "function Component() { const value = { foo: 'bar' }; return <Context.Provider value={value} /> }",
+        "function Component() { function foo() {} return <Context.Provider value={foo} /> }",
+        "function Component() { const foo = () => {}; return <Context.Provider value={foo} /> }",
+        "class Component extends React.Component { render() { return <Context.Provider value={{ foo: this.state.foo }} /> } }",
+        "function Component() { return <Ctx.Provider value={{ foo: 'bar' } as Value} /> }",
+    ];
+
+    Tester::new(
+        JsxNoConstructedContextValues::NAME,
+        JsxNoConstructedContextValues::PLUGIN,
+        pass,
+        fail,
+    )
+    .test_and_snapshot();
+}
@@ -0,0 +1,490 @@
+use oxc_ast::{
+    AstKind,
+    ast::{
+        ArrowFunctionExpression, BindingPatternKind, Class, Expression, Function, FunctionBody,
+        JSXAttributeName, PropertyKey, ReturnStatement, Statement,
+    },
+};
+use oxc_ast_visit::Visit;
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_semantic::ScopeFlags;
+use oxc_span::{GetSpan, Span};
+
+use crate::{
+    AstNode,
+    context::{ContextHost, LintContext},
+    rule::Rule,
+    utils::{
+        is_create_element_call, is_es6_component, is_react_component_name, is_react_function_call,
+    },
+};
+
+fn no_unstable_nested_components_diagnostic(
+    span: Span,
+    parent_name: Option<&str>,
+    as_prop: bool,
+) -> OxcDiagnostic {
+    let parent = parent_name.map_or("the parent component".to_string(), |name| format!("`{name}`"));
+    let help = if as_prop {
+        format!(
+            "Move this component definition out of {parent} and pass data as props, or set the `allowAsProps` option to allow components created in props."
+        )
+    } else {
+        format!("Move this component definition out of {parent} and pass data as props.")
+    };
+    OxcDiagnostic::warn(
+        "Do not define components during render. React sees a new component type on every render and remounts its subtree, losing its state.",
+    )
+    .with_help(help)
+    .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct NoUnstableNestedComponents {
+    allow_as_props: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Disallow defining components inside of other components.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A component defined during render is a new component type on every render. React unmounts
+    /// the previous subtree and mounts a new one each time, which destroys the state of the subtree
+    /// and its DOM nodes, and is slow.
+    ///
+    /// Functions passed as props whose name starts with `render` are render props, not components,
+    /// and are allowed.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```jsx
+    /// function Component() {
+    ///   function UnstableNestedComponent() {
+    ///     return <div />;
+    ///   }
+    ///   return <div><UnstableNestedComponent /></div>;
+    /// }
+    ///
+    /// function Component() {
+    ///   return <SomeComponent footer={() => <div />} />;
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```jsx
+    /// function OutsideDefinedComponent(props) {
+    ///   return <div />;
+    /// }
+    ///
+    /// function Component() {
+    ///   return <div><OutsideDefinedComponent /></div>;
+    /// }
+    ///
+    /// function Component() {
+    ///   return <SomeComponent renderFooter={() => <div />} />;
+    /// }
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// #### allowAsProps
+    ///
+    /// `{ type: boolean, default: false }`
+    ///
+    /// Allow components defined in props, e.g. `<SomeComponent footer={() => <div />} />`.
+    NoUnstableNestedComponents,
+    react,
+    suspicious
+);
+
+impl Rule for NoUnstableNestedComponents {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        let value = value.as_array().and_then(|arr| arr.first()).and_then(|val| val.as_object());
+
+        Self {
+            allow_as_props: value
+                .and_then(|val| val.get("allowAsProps").and_then(serde_json::Value::as_bool))
+                .unwrap_or(false),
+        }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let Some(component) = get_component(node, ctx) else {
+            return;
+        };
+        if component.as_prop && self.allow_as_props {
+            return;
+        }
+
+        let parent = ctx
+            .nodes()
+            .ancestors(node.id())
+            .skip(1)
+            .find_map(|ancestor| get_component(ancestor, ctx).filter(|parent| !parent.as_prop));
+        let Some(parent) = parent else {
+            return;
+        };
+
+        ctx.diagnostic(no_unstable_nested_components_diagnostic(
+            component.span,
+            parent.name,
+            component.as_prop,
+        ));
+    }
+
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        ctx.source_type().is_jsx()
+    }
+}
+
+struct Component<'a> {
+    name: Option<&'a str>,
+    span: Span,
+    /// Defined in a prop, e.g. `<SomeComponent footer={() => <div />} />`.
+    as_prop: bool,
+}
+
+/// The component defined by `node`: a class extending `React.Component`, or a function which
+/// returns JSX and is named like a component or passed as a prop.
+fn get_component<'a>(node: &AstNode<'a>, ctx: &LintContext<'a>) -> Option<Component<'a>> {
+    let (id, body) = match node.kind() {
+        AstKind::Function(Function { id, body: Some(body), .. }) => (id.as_ref(), body),
+        AstKind::ArrowFunctionExpression(ArrowFunctionExpression { body, .. }) => (None, body),
+        AstKind::Class(class) => {
+            if !is_es6_component(node) {
+                return None;
+            }
+            let (name, span) = match &class.id {
+                Some(id) => (Some(id.name.as_str()), id.span),
+                None => match definition(node, ctx) {
+                    Some((Some((name, span)), _)) => (Some(name), span),
+                    _ => (None, class.span),
+                },
+            };
+            return Some(Component { name, span, as_prop: false });
+        }
+        _ => return None,
+    };
+
+    let (name, span, as_prop) = match id {
+        Some(id) => (Some(id.name.as_str()), id.span, false),
+        None => match definition(node, ctx)? {
+            (Some((name, span)), as_prop) => (Some(name), span, as_prop),
+            (None, as_prop) => (None, node.kind().span(), as_prop),
+        },
+    };
+    // Components in props don't need to be named like components, e.g. `{ cell: () => <td /> }`.
+    if !as_prop && !name.is_some_and(is_react_component_name) {
+        return None;
+    }
+    if !returns_jsx(body) {
+        return None;
+    }
+    Some(Component { name, span, as_prop })
+}
+
+/// The name, and its span, of the function or class defined by `node`, and whether it is defined
+/// in a prop:
+/// * `Foo` in `const Foo = () => {}`
+/// * `Foo`, in a prop, in `<Table components={{ Foo: () => {} }} />`
+/// * no name, in a prop, in `<Table footer={() => {}} />`
+///
+/// Render props like `<Table renderFooter={() => {}} />` are not components.
+fn definition<'a>(
+    node: &AstNode<'a>,
+    ctx: &LintContext<'a>,
+) -> Option<(Option<(&'a str, Span)>, bool)> {
+    let parent = definition_parent(node, ctx)?;
+    match parent.kind() {
+        AstKind::VariableDeclarator(decl) => match &decl.id.kind {
+            BindingPatternKind::BindingIdentifier(id) => {
+                Some((Some((id.name.as_str(), id.span)), false))
+            }
+            _ => None,
+        },
+        AstKind::ObjectProperty(prop) => {
+            let PropertyKey::StaticIdentifier(key) = &prop.key else {
+                return None;
+            };
+            let object = ctx.nodes().parent_node(parent.id())?;
+            let as_prop = prop_name(object, ctx).is_some();
+            Some((Some((key.name.as_str(), key.span)), as_prop))
+        }
+        AstKind::JSXExpressionContainer(_) => {
+            if prop_name(node, ctx)?.starts_with("render") {
+                return None;
+            }
+            Some((None, true))
+        }
+        _ => None,
+    }
+}
+
+/// The parent of `node`, skipping parentheses and `memo` or `forwardRef` calls.
+fn definition_parent<'a, 'b>(
+    node: &AstNode<'a>,
+    ctx: &'b LintContext<'a>,
+) -> Option<&'b AstNode<'a>> {
+    ctx.nodes().ancestors(node.id()).skip(1).find(|ancestor| match ancestor.kind() {
+        AstKind::ParenthesizedExpression(_) | AstKind::Argument(_) => false,
+        AstKind::CallExpression(call) => {
+            !is_react_function_call(call, "memo") && !is_react_function_call(call, "forwardRef")
+        }
+        _ => true,
+    })
+}
+
+/// `footer` when `node` is the value of the prop in `<Table footer={node} />`.
+fn prop_name<'a>(node: &AstNode<'a>, ctx: &LintContext<'a>) -> Option<&'a str> {
+    let container = definition_parent(node, ctx)?;
+    let AstKind::JSXExpressionContainer(_) = container.kind() else {
+        return None;
+    };
+    let AstKind::JSXAttribute(attr) = ctx.nodes().parent_kind(container.id())? else {
+        return None;
+    };
+    match &attr.name {
+        JSXAttributeName::Identifier(ident) => Some(ident.name.as_str()),
+        JSXAttributeName::NamespacedName(_) => None,
+    }
+}
+
+fn returns_jsx(body: &FunctionBody) -> bool {
+    let mut finder = JsxReturnFinder { returns_jsx: false };
+    finder.visit_function_body(body);
+    finder.returns_jsx
+        || body.statements.first().is_some_and(|stmt| match stmt {
+            // The body of `() => <div />`.
+            Statement::ExpressionStatement(stmt) if body.statements.len() == 1 => {
+                is_jsx_like(&stmt.expression)
+            }
+            _ => false,
+        })
+}
+
+fn is_jsx_like(expr: &Expression) -> bool {
+    match expr.get_inner_expression() {
+        Expression::JSXElement(_) | Expression::JSXFragment(_) => true,
+        Expression::ConditionalExpression(expr) => {
+            is_jsx_like(&expr.consequent) || is_jsx_like(&expr.alternate)
+        }
+        Expression::LogicalExpression(expr) => is_jsx_like(&expr.left) || is_jsx_like(&expr.right),
+        Expression::CallExpression(call) => is_create_element_call(call),
+        _ => false,
+    }
+}
+
+/// Finds `return <div />`, without looking into nested functions and classes.
+struct JsxReturnFinder {
+    returns_jsx: bool,
+}
+
+impl<'a> Visit<'a> for JsxReturnFinder {
+    fn visit_return_statement(&mut self, stmt: &ReturnStatement<'a>) {
+        if stmt.argument.as_ref().is_some_and(is_jsx_like) {
+            self.returns_jsx = true;
+        }
+    }
+
+    fn visit_function(&mut self, _func: &Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_arrow_function_expression(&mut self, _expr: &ArrowFunctionExpression<'a>) {}
+
+    fn visit_class(&mut self, _class: &Class<'a>) {}
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        (
+            "
+            function ParentComponent() {
+              return (
+                <div>
+                  <OutsideDefinedFunctionComponent />
+                </div>
+              );
+            }
+            ",
+            None,
+        ),
+        (
+            "
+            function ParentComponent() {
+              return (
+                <SomeComponent
+                  renderFooter={() => <div />}
+                  renderHeader={function () { return <div /> }}
+                />
+              );
+            }
+            ",
+            None,
+        ),
+        (
+            "
+            function ParentComponent() {
+              return <SomeComponent>{() => <div />}</SomeComponent>;
+            }
+            ",
+            None,
+        ),
+        (
+            "
+            function ParentComponent() {
+              const renderItem = (item) => <li>{item}</li>;
+              return <ul>{items.map(renderItem)}</ul>;
+            }
+            ",
+            None,
+        ),
+        (
+            "
+            function ParentComponent() {
+              const list = useMemo(() => items.map((item) => <li>{item}</li>), [items]);
+              return <ul>{list}</ul>;
+            }
+            ",
+            None,
+        ),
+        (
+            "
+            function ParentComponent() {
+              const Title = 'title';
+              return <div title={Title} />;
+            }
+            ",
+            None,
+        ),
+        (
+            "
+            function ParentComponent() {
+              return <SomeComponent footer={() => <div />} />;
+            }
+            ",
+            Some(serde_json::json!([{ "allowAsProps": true }])),
+        ),
+        (
+            "
+            function ParentComponent() {
+              return <Table components={{ Cell: () => <td /> }} />;
+            }
+            ",
+            Some(serde_json::json!([{ "allowAsProps": true }])),
+        ),
+        (
+            "
+            function createComponent() {
+              return function Component() {
+                return <div />;
+              };
+            }
+            ",
+            None,
+        ),
+        (
+            "
+            function useHook() {
+              const Component = () => <div />;
+              return Component;
+            }
+            ",
+            None,
+        ),
+        (
+            "
+            const Component = memo(() => <div />);
+            class Parent extends React.Component {
+              render() {
+                return <Component />;
+              }
+            }
+            ",
+            None,
+        ),
+    ];
+
+    let fail = vec![
+        (
+            "
+            function ParentComponent() {
+              function UnstableNestedComponent() {
+                return <div />;
+              }
+              return <UnstableNestedComponent />;
+            }
+            ",
+            None,
+        ),
+        (
+            "
+            function ParentComponent() {
+              const UnstableNestedComponent = () => <div />;
+              return <UnstableNestedComponent />;
+            }
+            ",
+            None,
+        ),
+        (
+            "
+            const ParentComponent = () => {
+              const UnstableNestedComponent = React.memo(() => {
+                return <div />;
+              });
+              return <UnstableNestedComponent />;
+            };
+            ",
+            None,
+        ),
+        (
+            "
+            function ParentComponent() {
+              class UnstableNestedComponent extends React.Component {
+                render() {
+                  return <div />;
+                }
+              }
+              return <UnstableNestedComponent />;
+            }
+            ",
+            None,
+        ),
+        (
+            "
+            class ParentComponent extends React.Component {
+              render() {
+                const UnstableNestedComponent = () => <div />;
+                return <UnstableNestedComponent />;
+              }
+            }
+            ",
+            None,
+        ),
+        (
+            "
+            function ParentComponent() {
+              return <SomeComponent footer={() => <div />} />;
+            }
+            ",
+            None,
+        ),
+        (
+            "
+            function ParentComponent() {
+              return <Table components={{ Cell: () => <td /> }} />;
+            }
+            ",
+            None,
+        ),
+    ];
+
+    Tester::new(NoUnstableNestedComponents::NAME, NoUnstableNestedComponents::PLUGIN, pass, fail)
+        .test_and_snapshot();
+}
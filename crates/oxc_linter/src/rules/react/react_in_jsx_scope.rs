@@ -28,6 +28,9 @@ declare_oxc_lint!(
     /// When using JSX, `<a />` expands to `React.createElement("a")`. Therefore
     /// the `React` variable must be in scope.
     ///
+    /// Files opting into the automatic runtime with a `/** @jsxRuntime automatic */`
+    /// or `/** @jsxImportSource ... */` pragma are not checked.
+    ///
     /// ### Examples
     ///
     /// Examples of **incorrect** code for this rule:
@@ -64,7 +67,8 @@ impl Rule for ReactInJsxScope {
     }
 
     fn should_run(&self, ctx: &ContextHost) -> bool {
-        ctx.source_type().is_jsx()
+        // The automatic runtime imports its own factories
+        ctx.source_type().is_jsx() && ctx.pragmas().is_automatic_jsx_runtime() != Some(true)
     }
 }
 
@@ -95,6 +99,8 @@ fn test() {
             None,
         ),
         ("var React, a = <img />;", None),
+        ("/** @jsxRuntime automatic */ var a = <img />;", None),
+        ("/** @jsxImportSource preact */ var a = <App />;", None),
     ];
 
     let fail = vec![
@@ -103,6 +109,7 @@ fn test() {
         ("var a = <img />;", None),
         ("var a = <>fragment</>;", None),
         ("var Foo, a = <img />;", None),
+        ("/** @jsxRuntime classic @jsxImportSource preact */ var a = <img />;", None),
     ];
 
     Tester::new(ReactInJsxScope::NAME, ReactInJsxScope::PLUGIN, pass, fail).test_and_snapshot();
@@ -65,7 +65,8 @@ declare_oxc_lint!(
     /// ```
     RequireRenderReturn,
     react,
-    nursery
+    nursery,
+    requires = cfg
 );
 
 impl Rule for RequireRenderReturn {
@@ -105,7 +105,8 @@ declare_oxc_lint!(
     ///
     RulesOfHooks,
     react,
-    pedantic
+    pedantic,
+    requires = cfg
 );
 
 impl Rule for RulesOfHooks {
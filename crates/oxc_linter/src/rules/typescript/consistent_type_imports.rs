@@ -36,6 +36,13 @@ fn some_imports_are_only_types_diagnostic(span: Span, type_imports: &str) -> Oxc
     OxcDiagnostic::warn(format!("Imports {type_imports} are only used as type.")).with_label(span)
 }
 
+fn inline_type_side_effect_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(
+        "All imports in the declaration are inline types, which leave a side effect import behind with `verbatimModuleSyntax`. Use `import type`.",
+    )
+    .with_label(span)
+}
+
 #[derive(Debug, Default, Clone)]
 pub struct ConsistentTypeImports(Box<ConsistentTypeImportsConfig>);
 
@@ -53,6 +60,7 @@ pub struct ConsistentTypeImportsConfig {
     disallow_type_annotations: DisallowTypeAnnotations,
     fix_style: FixStyle,
     prefer: Prefer,
+    verbatim_module_syntax: bool,
 }
 
 // The default of `disallowTypeAnnotations` is `true`.
@@ -122,6 +130,35 @@ declare_oxc_lint!(
     /// ```ts
     /// import type { Foo } from 'Foo';
     /// ```
+    ///
+    /// ### Options
+    ///
+    /// ```json
+    /// {
+    ///   "typescript/consistent-type-imports": ["error", {
+    ///     "prefer": "type-imports",
+    ///     "fixStyle": "separate-type-imports",
+    ///     "disallowTypeAnnotations": true,
+    ///     "verbatimModuleSyntax": false
+    ///   }]
+    /// }
+    /// ```
+    ///
+    /// - `prefer`: `"type-imports"` (default) enforces `import type`, `"no-type-imports"`
+    ///   disallows it.
+    /// - `fixStyle`: `"separate-type-imports"` (default) fixes to `import type { Foo }`,
+    ///   `"inline-type-imports"` fixes to `import { type Foo }`.
+    /// - `disallowTypeAnnotations`: disallow `import()` type annotations. Default `true`.
+    /// - `verbatimModuleSyntax`: set when the project is compiled with the TypeScript
+    ///   `verbatimModuleSyntax` option, which keeps `import { type Foo } from 'Foo'` as
+    ///   `import 'Foo'` at runtime. Imports with only inline type qualifiers are reported and
+    ///   fixed to `import type`, even with `"inline-type-imports"`, and fixes never turn
+    ///   type imports into value imports. Default `false`.
+    ///
+    /// Examples of **incorrect** code with `{ "verbatimModuleSyntax": true }`:
+    /// ```ts
+    /// import { type Foo, type Bar } from 'Foo';
+    /// ```
     ConsistentTypeImports,
     typescript,
     style,
@@ -153,7 +190,17 @@ impl Rule for ConsistentTypeImports {
                     },
                 );
 
-                ConsistentTypeImportsConfig { disallow_type_annotations, fix_style, prefer }
+                let verbatim_module_syntax = config
+                    .get("verbatimModuleSyntax")
+                    .and_then(serde_json::Value::as_bool)
+                    .unwrap_or(false);
+
+                ConsistentTypeImportsConfig {
+                    disallow_type_annotations,
+                    fix_style,
+                    prefer,
+                    verbatim_module_syntax,
+                }
             },
         );
         Self(Box::new(config))
@@ -174,6 +221,11 @@ impl Rule for ConsistentTypeImports {
                 // `import type { Foo } from 'foo'`
                 AstKind::ImportDeclaration(import_decl) => {
                     if import_decl.import_kind.is_type() {
+                        // Types must be imported with `import type` with `verbatimModuleSyntax`.
+                        if self.verbatim_module_syntax {
+                            ctx.diagnostic(avoid_import_type_diagnostic(import_decl.span));
+                            return;
+                        }
                         ctx.diagnostic_with_fix(
                             avoid_import_type_diagnostic(import_decl.span),
                             |fixer| {
@@ -189,6 +241,10 @@ impl Rule for ConsistentTypeImports {
                 // import { type Foo } from 'foo'
                 AstKind::ImportSpecifier(import_specifier) => {
                     if import_specifier.import_kind.is_type() {
+                        if self.verbatim_module_syntax {
+                            ctx.diagnostic(avoid_import_type_diagnostic(import_specifier.span));
+                            return;
+                        }
                         ctx.diagnostic_with_fix(
                             avoid_import_type_diagnostic(import_specifier.span),
                             |fixer| {
@@ -210,6 +266,32 @@ impl Rule for ConsistentTypeImports {
             return;
         };
 
+        // `import { type A, type B } from 'foo'` is emitted as `import 'foo'` with
+        // `verbatimModuleSyntax`.
+        if self.verbatim_module_syntax && is_inline_type_only_import(import_decl) {
+            ctx.diagnostic_with_fix(
+                inline_type_side_effect_diagnostic(import_decl.span),
+                |fixer| {
+                    let fix_options = FixOptions {
+                        fixer,
+                        import_decl,
+                        type_names: &[],
+                        fix_style: self.fix_style,
+                        verbatim_module_syntax: true,
+                        ctx,
+                    };
+                    match fix_insert_type_specifier_for_import_declaration(&fix_options, false) {
+                        Ok(fixes) => fixes,
+                        Err(err) => {
+                            debug_assert!(false, "Failed to fix: {err}");
+                            fixer.noop()
+                        }
+                    }
+                },
+            );
+            return;
+        }
+
         // Store references that only used as type and without type qualifier.
         // For example:
         // ```typescript
@@ -271,6 +353,7 @@ impl Rule for ConsistentTypeImports {
                     import_decl,
                     type_names: &type_names,
                     fix_style: self.fix_style,
+                    verbatim_module_syntax: self.verbatim_module_syntax,
                     ctx,
                 };
 
@@ -343,6 +426,7 @@ struct FixOptions<'a, 'b> {
     import_decl: &'b ImportDeclaration<'a>,
     type_names: &'b [&'b str],
     fix_style: FixStyle,
+    verbatim_module_syntax: bool,
     ctx: &'b LintContext<'a>,
 }
 
@@ -356,7 +440,8 @@ fn fixer_error<S: Into<String>, T>(message: S) -> FixerResult<T> {
 // import { Foo, Bar } from 'foo' => import type { Foo, Bar } from 'foo'
 #[expect(clippy::cast_possible_truncation)]
 fn fix_to_type_import_declaration<'a>(options: &FixOptions<'a, '_>) -> FixerResult<RuleFix<'a>> {
-    let FixOptions { fixer, import_decl, type_names, fix_style, ctx } = options;
+    let FixOptions { fixer, import_decl, type_names, fix_style, verbatim_module_syntax, ctx } =
+        options;
     let fixer = fixer.for_multifix();
 
     let GroupedSpecifiers { namespace_specifier, named_specifiers, default_specifier } =
@@ -390,6 +475,18 @@ fn fix_to_type_import_declaration<'a>(options: &FixOptions<'a, '_>) -> FixerResu
             return fix_inline_type_import_declaration(options);
         }
     } else if namespace_specifier.is_none() {
+        if *verbatim_module_syntax
+            && named_specifiers.iter().all(|specifier| {
+                specifier.import_kind.is_type()
+                    || type_names.iter().contains(&specifier.local.name.as_str())
+            })
+        {
+            // Inline type qualifiers would leave `import 'foo'` behind with `verbatimModuleSyntax`.
+            // import { type Type1, Type2 } from 'foo' => import type { Type1, Type2 } from 'foo'
+            return fix_insert_type_specifier_for_import_declaration(
+                options, /* is_default_import */ false,
+            );
+        }
         if matches!(fix_style, FixStyle::InlineTypeImports)
             && named_specifiers
                 .iter()
@@ -430,6 +527,7 @@ fn fix_to_type_import_declaration<'a>(options: &FixOptions<'a, '_>) -> FixerResu
                 fixer: options.fixer,
                 type_names,
                 fix_style: options.fix_style,
+                verbatim_module_syntax: options.verbatim_module_syntax,
             };
             let fix = fix_insert_named_specifiers_in_named_specifier_list(
                 &new_options,
@@ -544,6 +642,20 @@ fn fix_to_type_import_declaration<'a>(options: &FixOptions<'a, '_>) -> FixerResu
         .with_message("Mark all type-only imports with the type specifier"))
 }
 
+// import { type A, type B } from 'foo'
+fn is_inline_type_only_import(import_decl: &ImportDeclaration) -> bool {
+    import_decl.import_kind.is_value()
+        && import_decl.specifiers.as_ref().is_some_and(|specifiers| {
+            !specifiers.is_empty()
+                && specifiers.iter().all(|specifier| match specifier {
+                    ImportDeclarationSpecifier::ImportSpecifier(specifier) => {
+                        specifier.import_kind.is_type()
+                    }
+                    _ => false,
+                })
+        })
+}
+
 fn fix_insert_named_specifiers_in_named_specifier_list<'a>(
     options: &FixOptions<'a, '_>,
     insert_text: &str,
@@ -1557,6 +1669,24 @@ export class Foo extends Bar {}
 ",
             None,
         ),
+        (
+            "import type { A } from 'foo'; type T = A;",
+            Some(serde_json::json!([{ "verbatimModuleSyntax": true }])),
+        ),
+        (
+            "import { type A, B } from 'foo'; type T = A; const b = B;",
+            Some(serde_json::json!([{ "verbatimModuleSyntax": true }])),
+        ),
+        (
+            "import { type A, type B } from 'foo'; type T = A | B;",
+            Some(serde_json::json!([{ "fixStyle": "inline-type-imports" }])),
+        ),
+        (
+            "import { A } from 'foo'; const a = A;",
+            Some(
+                serde_json::json!([{ "prefer": "no-type-imports", "verbatimModuleSyntax": true }]),
+            ),
+        ),
     ];
 
     let fail = vec![
@@ -2252,6 +2382,28 @@ export class Foo extends Bar {}
             ",
             None,
         ),
+        (
+            "import { type A, type B } from 'foo'; type T = A | B;",
+            Some(serde_json::json!([{ "verbatimModuleSyntax": true }])),
+        ),
+        (
+            "import { A, B } from 'foo'; type T = A | B;",
+            Some(serde_json::json!([
+                { "fixStyle": "inline-type-imports", "verbatimModuleSyntax": true }
+            ])),
+        ),
+        (
+            "import { type A, B } from 'foo'; type T = A | B;",
+            Some(serde_json::json!([
+                { "fixStyle": "inline-type-imports", "verbatimModuleSyntax": true }
+            ])),
+        ),
+        (
+            "import type { A } from 'foo'; type T = A;",
+            Some(
+                serde_json::json!([{ "prefer": "no-type-imports", "verbatimModuleSyntax": true }]),
+            ),
+        ),
     ];
 
     let fix = vec![
@@ -3391,6 +3543,37 @@ export class Foo extends Bar {}
             ",
             None,
         ),
+        (
+            "import { type A, type B } from 'foo'; type T = A | B;",
+            "import type { A, B } from 'foo'; type T = A | B;",
+            Some(serde_json::json!([{ "verbatimModuleSyntax": true }])),
+        ),
+        (
+            "import { A, B } from 'foo'; type T = A | B;",
+            "import { type A, type B } from 'foo'; type T = A | B;",
+            Some(serde_json::json!([{ "fixStyle": "inline-type-imports" }])),
+        ),
+        (
+            "import { A, B } from 'foo'; type T = A | B;",
+            "import type { A, B } from 'foo'; type T = A | B;",
+            Some(serde_json::json!([
+                { "fixStyle": "inline-type-imports", "verbatimModuleSyntax": true }
+            ])),
+        ),
+        (
+            "import { type A, B } from 'foo'; type T = A | B;",
+            "import type { A, B } from 'foo'; type T = A | B;",
+            Some(serde_json::json!([
+                { "fixStyle": "inline-type-imports", "verbatimModuleSyntax": true }
+            ])),
+        ),
+        (
+            "import { A, B } from 'foo'; type T = A; const b = B;",
+            "import { type A, B } from 'foo'; type T = A; const b = B;",
+            Some(serde_json::json!([
+                { "fixStyle": "inline-type-imports", "verbatimModuleSyntax": true }
+            ])),
+        ),
     ];
 
     // To format fix code.
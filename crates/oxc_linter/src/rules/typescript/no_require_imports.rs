@@ -9,7 +9,11 @@ use oxc_macros::declare_oxc_lint;
 use oxc_semantic::IsGlobalReference;
 use oxc_span::{CompactStr, Span};
 
-use crate::{AstNode, context::LintContext, rule::Rule};
+use crate::{
+    AstNode,
+    context::{ContextHost, LintContext},
+    rule::Rule,
+};
 
 fn no_require_imports_diagnostic(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Expected \"import\" statement instead of \"require\" call")
@@ -60,6 +64,9 @@ declare_oxc_lint!(
     ///
     /// In summary, while `require` works, the benefits of ES modules in terms of static analysis, better bundling, improved code organization, and easier maintainability make it the preferred method for importing modules in modern JavaScript projects.
     ///
+    /// CommonJS JavaScript files are not checked: `.cjs` files, and the `.js` files of a package
+    /// with `"type": "commonjs"` in its `package.json`, can only import modules with `require`.
+    ///
     /// ### Examples
     ///
     /// Examples of **incorrect** code for this rule:
@@ -126,6 +133,10 @@ fn match_argument_value_with_regex(allow: &[CompactStr], argument_value: &str) -
 }
 
 impl Rule for NoRequireImports {
+    fn should_run(&self, ctx: &ContextHost) -> bool {
+        !(ctx.module_format().is_commonjs() && ctx.source_type().is_javascript())
+    }
+
     fn from_configuration(value: serde_json::Value) -> Self {
         let obj = value.get(0);
         Self(Box::new(NoRequireImportsConfig {
@@ -218,6 +229,8 @@ impl Rule for NoRequireImports {
 
 #[test]
 fn test() {
+    use std::path::PathBuf;
+
     use crate::tester::Tester;
 
     let pass = vec![
@@ -402,4 +415,16 @@ fn test() {
     Tester::new(NoRequireImports::NAME, NoRequireImports::PLUGIN, pass, fail)
         .change_rule_path_extension("ts")
         .test_and_snapshot();
+
+    let pass = vec![
+        ("const lib = require('lib');", None, None, Some(PathBuf::from("foo.cjs"))),
+        ("const lib = require('lib');", None, None, Some(PathBuf::from("foo/index.cjs"))),
+    ];
+    let fail = vec![
+        ("const lib = require('lib');", None, None, Some(PathBuf::from("foo.mjs"))),
+        // `.cts` files are compiled to CommonJS, from `import` declarations
+        ("const lib = require('lib');", None, None, Some(PathBuf::from("foo.cts"))),
+    ];
+
+    Tester::new(NoRequireImports::NAME, NoRequireImports::PLUGIN, pass, fail).test();
 }
@@ -1,12 +1,25 @@
 use oxc_ast::{
     AstKind,
-    ast::{Expression, match_member_expression},
+    ast::{
+        Argument, ArrowFunctionExpression, BindingPatternKind, CallExpression, DoWhileStatement,
+        Expression, ForInStatement, ForOfStatement, ForStatement, FormalParameters, Function,
+        FunctionBody, IdentifierReference, ReturnStatement, ThisExpression, VariableDeclaration,
+        VariableDeclarationKind, WhileStatement, match_member_expression,
+    },
 };
+use oxc_ast_visit::{Visit, walk};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_macros::declare_oxc_lint;
-use oxc_span::Span;
+use oxc_semantic::ScopeFlags;
+use oxc_span::{GetSpan, Span};
 
-use crate::{AstNode, ast_util::is_method_call, context::LintContext, rule::Rule};
+use crate::{
+    AstNode,
+    ast_util::is_method_call,
+    context::LintContext,
+    fixer::{RuleFix, RuleFixer},
+    rule::Rule,
+};
 
 fn no_array_for_each_diagnostic(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Do not use `Array#forEach`")
@@ -45,10 +58,16 @@ declare_oxc_lint!(
     /// const foo = [1, 2, 3];
     /// for (const element of foo) { /* ... */ }
     /// ```
+    ///
+    /// The suggested fix replaces the call with a `for…of` statement, if the callback can be
+    /// moved into a loop: it is not `async` or a generator, doesn't use `this` or `arguments`, and
+    /// only returns without a value, which becomes `continue`. It is a suggestion, because
+    /// `forEach` may be a method of another object than an array, e.g. a `Map`, which passes
+    /// other arguments to the callback.
     NoArrayForEach,
     unicorn,
     restriction,
-    pending
+    conditional_suggestion
 );
 
 impl Rule for NoArrayForEach {
@@ -90,9 +109,238 @@ impl Rule for NoArrayForEach {
                 return;
             };
 
-            ctx.diagnostic(no_array_for_each_diagnostic(span));
+            let diagnostic = no_array_for_each_diagnostic(span);
+            if let Some(fix) = ForOfFix::new(node, call_expr, ctx) {
+                ctx.diagnostic_with_suggestion(diagnostic, |fixer| fix.fix(fixer, ctx));
+            } else {
+                ctx.diagnostic(diagnostic);
+            }
+        }
+    }
+}
+
+/// The replacement of `array.forEach((element, index) => { ... });` with
+/// `for (const [index, element] of array.entries()) { ... }`.
+struct ForOfFix<'a, 'b> {
+    statement: Span,
+    object: &'b Expression<'a>,
+    params: &'b FormalParameters<'a>,
+    body: &'b FunctionBody<'a>,
+    is_expression_body: bool,
+    returns: Vec<Span>,
+}
+
+impl<'a, 'b> ForOfFix<'a, 'b> {
+    fn new(
+        node: &AstNode<'a>,
+        call_expr: &'b CallExpression<'a>,
+        ctx: &LintContext<'a>,
+    ) -> Option<Self> {
+        // `array.forEach(callback);` as a statement, so it can be replaced by a `for…of` loop.
+        let parent = ctx.nodes().parent_node(node.id())?;
+        let AstKind::ExpressionStatement(statement) = parent.kind() else {
+            return None;
+        };
+        // `foo => array.forEach(callback)`
+        let body = ctx.nodes().parent_node(parent.id())?;
+        if matches!(
+            ctx.nodes().parent_kind(body.id()),
+            Some(AstKind::ArrowFunctionExpression(arrow)) if arrow.expression
+        ) {
+            return None;
+        }
+        let member_expr = call_expr.callee.get_member_expr()?;
+        if call_expr.optional || member_expr.optional() || call_expr.arguments.len() != 1 {
+            return None;
+        }
+
+        let (params, body, is_expression_body, is_function) = match &call_expr.arguments[0] {
+            Argument::ArrowFunctionExpression(arrow) if !arrow.r#async => {
+                (&*arrow.params, &*arrow.body, arrow.expression, false)
+            }
+            Argument::FunctionExpression(func) if !func.r#async && !func.generator => {
+                // A named function may call itself.
+                if func.this_param.is_some()
+                    || func.id.as_ref().is_some_and(|id| {
+                        !ctx.scoping().get_resolved_reference_ids(id.symbol_id()).is_empty()
+                    })
+                {
+                    return None;
+                }
+                (&*func.params, func.body.as_deref()?, false, true)
+            }
+            _ => return None,
+        };
+
+        if params.items.is_empty() || params.items.len() > 2 || params.rest.is_some() {
+            return None;
+        }
+        for param in &params.items {
+            if param.pattern.type_annotation.is_some()
+                || matches!(param.pattern.kind, BindingPatternKind::AssignmentPattern(_))
+            {
+                return None;
+            }
+            // `for (const element of element)` would read `element` before it is initialized.
+            if param
+                .pattern
+                .get_binding_identifiers()
+                .iter()
+                .any(|ident| ctx.scoping().find_binding(node.scope_id(), &ident.name).is_some())
+            {
+                return None;
+            }
+        }
+
+        let mut finder = CallbackBodyFinder::default();
+        finder.visit_function_body(body);
+        if finder.has_var_declaration
+            || finder.has_return_value
+            || (is_function && finder.has_this_or_arguments)
+        {
+            return None;
+        }
+
+        Some(Self {
+            statement: statement.span,
+            object: member_expr.object(),
+            params,
+            body,
+            is_expression_body,
+            returns: finder.returns,
+        })
+    }
+
+    fn fix(&self, fixer: RuleFixer<'_, 'a>, ctx: &LintContext<'a>) -> RuleFix<'a> {
+        let is_reassigned = self.params.items.iter().any(|param| {
+            param
+                .pattern
+                .get_binding_identifiers()
+                .iter()
+                .any(|ident| ctx.scoping().symbol_is_mutated(ident.symbol_id()))
+        });
+        let kind = if is_reassigned { "let" } else { "const" };
+
+        let object = ctx.source_range(self.object.span());
+        let head = match self.params.items.as_slice() {
+            [element] => format!("{kind} {} of {object}", ctx.source_range(element.span)),
+            [element, index] => format!(
+                "{kind} [{}, {}] of {object}.entries()",
+                ctx.source_range(index.span),
+                ctx.source_range(element.span)
+            ),
+            _ => unreachable!(),
+        };
+
+        let body = if self.is_expression_body {
+            format!("{};", ctx.source_range(self.body.statements[0].span()))
+        } else {
+            // `return;` -> `continue;`
+            let mut body = String::new();
+            let mut start = self.body.span.start;
+            for span in &self.returns {
+                body.push_str(ctx.source_range(Span::new(start, span.start)));
+                body.push_str("continue");
+                // Skip `return`
+                start = span.start + 6;
+            }
+            body.push_str(ctx.source_range(Span::new(start, self.body.span.end)));
+            body
+        };
+
+        fixer.replace(self.statement, format!("for ({head}) {body}"))
+    }
+}
+
+/// Finds what prevents moving the body of a callback into a loop.
+#[derive(Default)]
+struct CallbackBodyFinder {
+    /// `return;` statements, which become `continue;`.
+    returns: Vec<Span>,
+    has_return_value: bool,
+    has_var_declaration: bool,
+    has_this_or_arguments: bool,
+    in_function: usize,
+    in_arrow_function: usize,
+    in_loop: usize,
+}
+
+impl<'a> Visit<'a> for CallbackBodyFinder {
+    fn visit_return_statement(&mut self, it: &ReturnStatement<'a>) {
+        if self.in_function == 0 && self.in_arrow_function == 0 {
+            // `continue` in a nested loop would continue the nested loop.
+            if it.argument.is_some() || self.in_loop > 0 {
+                self.has_return_value = true;
+            } else {
+                self.returns.push(it.span);
+            }
+        }
+        walk::walk_return_statement(self, it);
+    }
+
+    fn visit_variable_declaration(&mut self, it: &VariableDeclaration<'a>) {
+        if self.in_function == 0
+            && self.in_arrow_function == 0
+            && it.kind == VariableDeclarationKind::Var
+        {
+            self.has_var_declaration = true;
+        }
+        walk::walk_variable_declaration(self, it);
+    }
+
+    fn visit_this_expression(&mut self, _it: &ThisExpression) {
+        if self.in_function == 0 {
+            self.has_this_or_arguments = true;
+        }
+    }
+
+    fn visit_identifier_reference(&mut self, it: &IdentifierReference<'a>) {
+        if self.in_function == 0 && it.name == "arguments" {
+            self.has_this_or_arguments = true;
         }
     }
+
+    fn visit_function(&mut self, it: &Function<'a>, flags: ScopeFlags) {
+        self.in_function += 1;
+        walk::walk_function(self, it, flags);
+        self.in_function -= 1;
+    }
+
+    fn visit_arrow_function_expression(&mut self, it: &ArrowFunctionExpression<'a>) {
+        self.in_arrow_function += 1;
+        walk::walk_arrow_function_expression(self, it);
+        self.in_arrow_function -= 1;
+    }
+
+    fn visit_for_statement(&mut self, it: &ForStatement<'a>) {
+        self.in_loop += 1;
+        walk::walk_for_statement(self, it);
+        self.in_loop -= 1;
+    }
+
+    fn visit_for_in_statement(&mut self, it: &ForInStatement<'a>) {
+        self.in_loop += 1;
+        walk::walk_for_in_statement(self, it);
+        self.in_loop -= 1;
+    }
+
+    fn visit_for_of_statement(&mut self, it: &ForOfStatement<'a>) {
+        self.in_loop += 1;
+        walk::walk_for_of_statement(self, it);
+        self.in_loop -= 1;
+    }
+
+    fn visit_while_statement(&mut self, it: &WhileStatement<'a>) {
+        self.in_loop += 1;
+        walk::walk_while_statement(self, it);
+        self.in_loop -= 1;
+    }
+
+    fn visit_do_while_statement(&mut self, it: &DoWhileStatement<'a>) {
+        self.in_loop += 1;
+        walk::walk_do_while_statement(self, it);
+        self.in_loop -= 1;
+    }
 }
 
 pub const IGNORED_OBJECTS: [&str; 3] = ["Children", "r", "pIteration"];
@@ -122,7 +370,109 @@ fn test() {
         r"foo.forEach(function element(element, element1) {})",
         r"this._listeners.forEach((listener: () => void) => listener());",
         r"return foo.forEach(element => {bar(element)});",
+        r"foo.forEach(element => bar(element));",
+    ];
+
+    let fix = vec![
+        (r"foo.forEach(element => bar(element));", r"for (const element of foo) bar(element);"),
+        (
+            r"foo.forEach(element => { bar(element); });",
+            r"for (const element of foo) { bar(element); }",
+        ),
+        (
+            r"foo.forEach((element, index) => { bar(element, index); });",
+            r"for (const [index, element] of foo.entries()) { bar(element, index); }",
+        ),
+        (
+            r"foo.forEach(({ a, b }) => { bar(a, b); });",
+            r"for (const { a, b } of foo) { bar(a, b); }",
+        ),
+        (
+            r"foo.forEach(function (element) { bar(element); });",
+            r"for (const element of foo) { bar(element); }",
+        ),
+        (
+            r"foo.forEach(element => { if (!element) return; bar(element); });",
+            r"for (const element of foo) { if (!element) continue; bar(element); }",
+        ),
+        (
+            r"foo.forEach(element => { element = element.trim(); bar(element); });",
+            r"for (let element of foo) { element = element.trim(); bar(element); }",
+        ),
+        (
+            r"foo.bar().forEach(element => { baz(() => { return element; }); });",
+            r"for (const element of foo.bar()) { baz(() => { return element; }); }",
+        ),
+        (
+            r"foo.forEach(function (element) { bar(function () { return this; }); });",
+            r"for (const element of foo) { bar(function () { return this; }); }",
+        ),
+        (
+            r"function foo() { bar.forEach(element => { baz(this, element); }); }",
+            r"function foo() { for (const element of bar) { baz(this, element); } }",
+        ),
+        // Not fixable
+        (
+            r"foo.forEach(element => { return element; });",
+            r"foo.forEach(element => { return element; });",
+        ),
+        (
+            r"foo.forEach(element => { for (const a of element) { if (a) return; } });",
+            r"foo.forEach(element => { for (const a of element) { if (a) return; } });",
+        ),
+        (
+            r"foo.forEach(async element => { await bar(element); });",
+            r"foo.forEach(async element => { await bar(element); });",
+        ),
+        (
+            r"foo.forEach(function (element) { this.bar(element); });",
+            r"foo.forEach(function (element) { this.bar(element); });",
+        ),
+        (
+            r"foo.forEach(function (element) { bar(arguments); });",
+            r"foo.forEach(function (element) { bar(arguments); });",
+        ),
+        (
+            r"foo.forEach(function baz(element) { baz(element); });",
+            r"foo.forEach(function baz(element) { baz(element); });",
+        ),
+        (
+            r"foo.forEach(element => { var a = element; });",
+            r"foo.forEach(element => { var a = element; });",
+        ),
+        (
+            r"foo.forEach((element = 1) => bar(element));",
+            r"foo.forEach((element = 1) => bar(element));",
+        ),
+        (
+            r"foo.forEach((...elements) => bar(elements));",
+            r"foo.forEach((...elements) => bar(elements));",
+        ),
+        (r"foo.forEach(bar);", r"foo.forEach(bar);"),
+        (
+            r"foo.forEach(element => element.forEach(item => bar(item)));",
+            r"for (const element of foo) element.forEach(item => bar(item));",
+        ),
+        (
+            r"foo.forEach(element => bar(element), thisArg);",
+            r"foo.forEach(element => bar(element), thisArg);",
+        ),
+        (
+            r"const element = []; element.forEach(element => bar(element));",
+            r"const element = []; element.forEach(element => bar(element));",
+        ),
+        (r"foo?.forEach(element => bar(element));", r"foo?.forEach(element => bar(element));"),
+        (
+            r"const a = foo.forEach(element => bar(element));",
+            r"const a = foo.forEach(element => bar(element));",
+        ),
+        (
+            r"this._listeners.forEach((listener: () => void) => listener());",
+            r"this._listeners.forEach((listener: () => void) => listener());",
+        ),
     ];
 
-    Tester::new(NoArrayForEach::NAME, NoArrayForEach::PLUGIN, pass, fail).test_and_snapshot();
+    Tester::new(NoArrayForEach::NAME, NoArrayForEach::PLUGIN, pass, fail)
+        .expect_fix(fix)
+        .test_and_snapshot();
 }
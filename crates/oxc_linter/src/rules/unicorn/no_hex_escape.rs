@@ -42,7 +42,8 @@ declare_oxc_lint!(
     NoHexEscape,
     unicorn,
     pedantic,
-    fix
+    fix,
+    requires = regex
 );
 
 // \x -> \u00
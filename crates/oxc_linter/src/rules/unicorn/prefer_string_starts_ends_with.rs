@@ -51,7 +51,8 @@ declare_oxc_lint!(
     PreferStringStartsEndsWith,
     unicorn,
     correctness,
-    fix
+    fix,
+    requires = regex
 );
 
 impl Rule for PreferStringStartsEndsWith {
@@ -0,0 +1,396 @@
+use oxc_ast::{
+    AstKind,
+    ast::{AssignmentTarget, Expression, Statement},
+};
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_macros::declare_oxc_lint;
+use oxc_span::{ContentEq, GetSpan, Span};
+use oxc_syntax::operator::AssignmentOperator;
+
+use crate::{AstNode, ast_util::get_preceding_indent_str, context::LintContext, rule::Rule};
+
+fn prefer_ternary_diagnostic(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("This `if` statement can be replaced by a ternary expression.")
+        .with_help("Replace the `if-else` statement with a ternary expression.")
+        .with_label(span)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct PreferTernary {
+    only_single_line: bool,
+}
+
+declare_oxc_lint!(
+    /// ### What it does
+    ///
+    /// Prefer ternary expressions over simple `if-else` statements, which return, throw, yield,
+    /// await or assign in both branches.
+    ///
+    /// ### Why is this bad?
+    ///
+    /// A ternary expression is shorter, and shows that both branches do the same thing with a
+    /// different value.
+    ///
+    /// The rule doesn't report `if-else` statements which would become nested ternaries.
+    ///
+    /// ### Examples
+    ///
+    /// Examples of **incorrect** code for this rule:
+    /// ```javascript
+    /// function unicorn() {
+    ///     if (test) {
+    ///         return a;
+    ///     } else {
+    ///         return b;
+    ///     }
+    /// }
+    ///
+    /// if (test) {
+    ///     foo = 1;
+    /// } else {
+    ///     foo = 2;
+    /// }
+    /// ```
+    ///
+    /// Examples of **correct** code for this rule:
+    /// ```javascript
+    /// function unicorn() {
+    ///     return test ? a : b;
+    /// }
+    ///
+    /// foo = test ? 1 : 2;
+    ///
+    /// if (test) {
+    ///     foo = 1;
+    /// } else {
+    ///     bar = 2;
+    /// }
+    /// ```
+    ///
+    /// ### Options
+    ///
+    /// `"always"` (default) or `"only-single-line"`, which only reports `if-else` statements
+    /// whose test and branches are each on a single line.
+    ///
+    /// ```json
+    /// {
+    ///   "unicorn/prefer-ternary": ["error", "only-single-line"]
+    /// }
+    /// ```
+    PreferTernary,
+    unicorn,
+    style,
+    conditional_fix
+);
+
+/// The statement of an `if` branch, which can be merged into a ternary expression.
+enum Branch<'a, 'b> {
+    Return(Option<&'b Expression<'a>>),
+    Throw(&'b Expression<'a>),
+    Yield {
+        delegate: bool,
+        argument: Option<&'b Expression<'a>>,
+    },
+    Await(&'b Expression<'a>),
+    Assign {
+        operator: AssignmentOperator,
+        target: &'b AssignmentTarget<'a>,
+        value: &'b Expression<'a>,
+    },
+}
+
+impl Rule for PreferTernary {
+    fn from_configuration(value: serde_json::Value) -> Self {
+        Self {
+            only_single_line: value.get(0).and_then(serde_json::Value::as_str)
+                == Some("only-single-line"),
+        }
+    }
+
+    fn run<'a>(&self, node: &AstNode<'a>, ctx: &LintContext<'a>) {
+        let AstKind::IfStatement(if_stmt) = node.kind() else {
+            return;
+        };
+        let Some(alternate) = &if_stmt.alternate else {
+            return;
+        };
+        let (Some(consequent), Some(alternate)) =
+            (unwrap_block(&if_stmt.consequent), unwrap_block(alternate))
+        else {
+            return;
+        };
+
+        if self.only_single_line
+            && [if_stmt.test.span(), consequent.span(), alternate.span()]
+                .iter()
+                .any(|span| ctx.source_range(*span).contains('\n'))
+        {
+            return;
+        }
+
+        let (Some(consequent), Some(alternate)) = (get_branch(consequent), get_branch(alternate))
+        else {
+            return;
+        };
+
+        let test = wrap_test(ctx, &if_stmt.test);
+        let fixed = match (consequent, alternate) {
+            (Branch::Return(consequent), Branch::Return(alternate)) => {
+                let Some(ternary) = ternary(ctx, &test, consequent, alternate) else {
+                    return;
+                };
+                format!("return {ternary};")
+            }
+            (
+                Branch::Yield { delegate, argument: consequent },
+                Branch::Yield { delegate: alternate_delegate, argument: alternate },
+            ) if delegate == alternate_delegate => {
+                let Some(ternary) = ternary(ctx, &test, consequent, alternate) else {
+                    return;
+                };
+                format!("yield{} {ternary};", if delegate { "*" } else { "" })
+            }
+            (Branch::Await(consequent), Branch::Await(alternate)) => {
+                let Some(ternary) = ternary(ctx, &test, Some(consequent), Some(alternate)) else {
+                    return;
+                };
+                format!("await ({ternary});")
+            }
+            (
+                Branch::Assign { operator, target, value: consequent },
+                Branch::Assign {
+                    operator: alternate_operator,
+                    target: alternate_target,
+                    value: alternate,
+                },
+            ) if operator == alternate_operator && target.content_eq(alternate_target) => {
+                let Some(ternary) = ternary(ctx, &test, Some(consequent), Some(alternate)) else {
+                    return;
+                };
+                format!("{} {} {ternary};", ctx.source_range(target.span()), operator.as_str())
+            }
+            (Branch::Throw(consequent), Branch::Throw(alternate)) => {
+                let Some(ternary) = ternary(ctx, &test, Some(consequent), Some(alternate)) else {
+                    return;
+                };
+                // `throw test ? new Error(a) : new Error(b)` is hard to read, so the error is
+                // assigned to a variable first, which needs a block for the declaration.
+                let parent = ctx.nodes().parent_kind(node.id());
+                if !matches!(
+                    parent,
+                    Some(
+                        AstKind::Program(_)
+                            | AstKind::FunctionBody(_)
+                            | AstKind::BlockStatement(_)
+                            | AstKind::StaticBlock(_)
+                    )
+                ) {
+                    ctx.diagnostic(prefer_ternary_diagnostic(if_stmt.span));
+                    return;
+                }
+                let name = get_available_name(ctx, node, "error");
+                let indent =
+                    get_preceding_indent_str(ctx.source_text(), if_stmt.span).unwrap_or_default();
+                format!("const {name} = {ternary};\n{indent}throw {name};")
+            }
+            _ => return,
+        };
+
+        if ctx.semantic().has_comments_between(if_stmt.span) {
+            ctx.diagnostic(prefer_ternary_diagnostic(if_stmt.span));
+            return;
+        }
+
+        ctx.diagnostic_with_fix(prefer_ternary_diagnostic(if_stmt.span), |fixer| {
+            fixer.replace(if_stmt.span, fixed)
+        });
+    }
+}
+
+/// `{ return a; }` -> `return a;`
+fn unwrap_block<'b, 'a>(stmt: &'b Statement<'a>) -> Option<&'b Statement<'a>> {
+    match stmt {
+        Statement::BlockStatement(block) => match block.body.as_slice() {
+            [stmt] => unwrap_block(stmt),
+            _ => None,
+        },
+        _ => Some(stmt),
+    }
+}
+
+fn get_branch<'b, 'a>(stmt: &'b Statement<'a>) -> Option<Branch<'a, 'b>> {
+    match stmt {
+        Statement::ReturnStatement(stmt) => Some(Branch::Return(stmt.argument.as_ref())),
+        Statement::ThrowStatement(stmt) => Some(Branch::Throw(&stmt.argument)),
+        Statement::ExpressionStatement(stmt) => match stmt.expression.without_parentheses() {
+            Expression::YieldExpression(expr) => {
+                Some(Branch::Yield { delegate: expr.delegate, argument: expr.argument.as_ref() })
+            }
+            Expression::AwaitExpression(expr) => Some(Branch::Await(&expr.argument)),
+            Expression::AssignmentExpression(expr) => Some(Branch::Assign {
+                operator: expr.operator,
+                target: &expr.left,
+                value: &expr.right,
+            }),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// `test ? consequent : alternate`, or `None` if it would be a nested ternary.
+fn ternary(
+    ctx: &LintContext,
+    test: &str,
+    consequent: Option<&Expression>,
+    alternate: Option<&Expression>,
+) -> Option<String> {
+    if [consequent, alternate]
+        .iter()
+        .flatten()
+        .any(|expr| matches!(expr.without_parentheses(), Expression::ConditionalExpression(_)))
+    {
+        return None;
+    }
+    let branch = |expr: Option<&Expression>| match expr {
+        None => "undefined".to_string(),
+        Some(expr @ Expression::SequenceExpression(_)) => {
+            format!("({})", ctx.source_range(expr.span()))
+        }
+        Some(expr) => ctx.source_range(expr.span()).to_string(),
+    };
+    Some(format!("{test} ? {} : {}", branch(consequent), branch(alternate)))
+}
+
+fn wrap_test(ctx: &LintContext, test: &Expression) -> String {
+    let text = ctx.source_range(test.span());
+    match test {
+        Expression::ConditionalExpression(_)
+        | Expression::AssignmentExpression(_)
+        | Expression::SequenceExpression(_)
+        | Expression::ArrowFunctionExpression(_)
+        | Expression::YieldExpression(_) => format!("({text})"),
+        _ => text.to_string(),
+    }
+}
+
+/// `name`, or `name_`, `name__`, ... if `name` is already declared or referenced in scope.
+fn get_available_name(ctx: &LintContext, node: &AstNode, name: &str) -> String {
+    let mut name = name.to_string();
+    while ctx.scoping().find_binding(node.scope_id(), &name).is_some()
+        || ctx.scoping().root_unresolved_references().contains_key(name.as_str())
+    {
+        name.push('_');
+    }
+    name
+}
+
+#[test]
+fn test() {
+    use crate::tester::Tester;
+
+    let pass = vec![
+        ("if (a) { b = 1; }", None),
+        ("if (a) { b = 1; } else { c = 2; }", None),
+        ("if (a) { b = 1; } else { b -= 2; }", None),
+        ("if (a) { b = 1; c = 2; } else { b = 2; }", None),
+        ("if (a) { b = 1; } else if (c) { b = 2; }", None),
+        ("function foo() { if (a) { return 1; } else { throw 2; } }", None),
+        ("function foo() { if (a) { return b ? 1 : 2; } else { return 3; } }", None),
+        ("if (a) { b = c ? 1 : 2; } else { b = 3; }", None),
+        ("function* foo() { if (a) { yield 1; } else { yield* b; } }", None),
+        ("if (a) { foo(1); } else { foo(2); }", None),
+        ("if (a) { const b = 1; } else { const b = 2; }", None),
+        (
+            "if (a) {\n  b = {\n    c: 1,\n  };\n} else {\n  b = 2;\n}",
+            Some(serde_json::json!(["only-single-line"])),
+        ),
+    ];
+
+    let fail = vec![
+        ("function foo() { if (a) { return 1; } else { return 2; } }", None),
+        ("function foo() { if (a) return 1; else return 2; }", None),
+        ("function foo() { if (a) { return; } else { return b; } }", None),
+        ("function* foo() { if (a) { yield 1; } else { yield 2; } }", None),
+        ("function* foo() { if (a) { yield* b; } else { yield* c; } }", None),
+        ("async function foo() { if (a) { await b; } else { await c; } }", None),
+        ("if (a) { b = 1; } else { b = 2; }", None),
+        ("if (a) { b.c += 1; } else { b.c += 2; }", None),
+        ("if (a, b) { c = (1, 2); } else { c = 3; }", None),
+        ("if (a = b) { c = 1; } else { c = 2; }", None),
+        ("if (a) { b = 1; } else if (c) { b = 2; } else { b = 3; }", None),
+        (
+            "function foo() {\n  if (a) {\n    throw new Error('a');\n  } else {\n    throw new TypeError('b');\n  }\n}",
+            None,
+        ),
+        (
+            "function foo(error) {\n  if (a) {\n    throw error;\n  } else {\n    throw new Error('b');\n  }\n}",
+            None,
+        ),
+        ("if (a) { throw b; } else { throw c; }; error;", None),
+        ("if (x) if (a) throw b; else throw c;", None),
+        ("if (a) { b = 1; } else { /* comment */ b = 2; }", None),
+        ("if (a) { b = 1; } else { b = 2; }", Some(serde_json::json!(["only-single-line"]))),
+    ];
+
+    let fix = vec![
+        (
+            "function foo() { if (a) { return 1; } else { return 2; } }",
+            "function foo() { return a ? 1 : 2; }",
+            None,
+        ),
+        (
+            "function foo() { if (a) return 1; else return 2; }",
+            "function foo() { return a ? 1 : 2; }",
+            None,
+        ),
+        (
+            "function foo() { if (a) { return; } else { return b; } }",
+            "function foo() { return a ? undefined : b; }",
+            None,
+        ),
+        (
+            "function* foo() { if (a) { yield 1; } else { yield 2; } }",
+            "function* foo() { yield a ? 1 : 2; }",
+            None,
+        ),
+        (
+            "function* foo() { if (a) { yield* b; } else { yield* c; } }",
+            "function* foo() { yield* a ? b : c; }",
+            None,
+        ),
+        (
+            "async function foo() { if (a) { await b; } else { await c; } }",
+            "async function foo() { await (a ? b : c); }",
+            None,
+        ),
+        ("if (a) { b = 1; } else { b = 2; }", "b = a ? 1 : 2;", None),
+        ("if (a) { b.c += 1; } else { b.c += 2; }", "b.c += a ? 1 : 2;", None),
+        ("if (a, b) { c = (1, 2); } else { c = 3; }", "c = (a, b) ? (1, 2) : 3;", None),
+        ("if (a = b) { c = 1; } else { c = 2; }", "c = (a = b) ? 1 : 2;", None),
+        (
+            "if (a) { b = 1; } else if (c) { b = 2; } else { b = 3; }",
+            "if (a) { b = 1; } else b = c ? 2 : 3;",
+            None,
+        ),
+        (
+            "function foo() {\n  if (a) {\n    throw new Error('a');\n  } else {\n    throw new TypeError('b');\n  }\n}",
+            "function foo() {\n  const error = a ? new Error('a') : new TypeError('b');\n  throw error;\n}",
+            None,
+        ),
+        (
+            "function foo(error) {\n  if (a) {\n    throw error;\n  } else {\n    throw new Error('b');\n  }\n}",
+            "function foo(error) {\n  const error_ = a ? error : new Error('b');\n  throw error_;\n}",
+            None,
+        ),
+        (
+            "if (a) { throw b; } else { throw c; }; error;",
+            "const error_ = a ? b : c;\nthrow error_;; error;",
+            None,
+        ),
+    ];
+
+    Tester::new(PreferTernary::NAME, PreferTernary::PLUGIN, pass, fail)
+        .expect_fix(fix)
+        .test_and_snapshot();
+}
@@ -1,16 +1,19 @@
 use std::{
     ffi::OsStr,
     path::{Path, PathBuf},
-    sync::Arc,
+    sync::{Arc, atomic::AtomicUsize},
 };
 
 use oxc_diagnostics::DiagnosticSender;
+pub use resolution_cache::ResolutionCache;
 use runtime::Runtime;
 pub use runtime::RuntimeFileSystem;
 
 use crate::Linter;
 
+mod resolution_cache;
 mod runtime;
+mod tsconfig;
 
 #[cfg(feature = "language_server")]
 pub mod offset_to_position;
@@ -25,7 +28,23 @@ pub struct LintServiceOptions {
     /// TypeScript `tsconfig.json` path for reading path alias and project references
     tsconfig: Option<PathBuf>,
 
+    /// Roots of the packages of a multi-root workspace, with a `tsconfig.json` each
+    workspace_roots: Vec<PathBuf>,
+
     cross_module: bool,
+
+    /// Condition names for resolving the `exports` and `imports` of `package.json` files,
+    /// or the default ones if empty
+    condition_names: Vec<String>,
+
+    /// Cache of the import resolver, shared with other services
+    resolution_cache: Option<ResolutionCache>,
+
+    /// Skip the paths whose content is generated or minified
+    skip_generated: bool,
+
+    /// Number of the paths skipped because their content is generated or minified
+    skipped_files: Arc<AtomicUsize>,
 }
 
 impl LintServiceOptions {
@@ -34,7 +53,17 @@ impl LintServiceOptions {
     where
         T: Into<Box<Path>>,
     {
-        Self { cwd: cwd.into(), paths, tsconfig: None, cross_module: false }
+        Self {
+            cwd: cwd.into(),
+            paths,
+            tsconfig: None,
+            workspace_roots: vec![],
+            cross_module: false,
+            condition_names: vec![],
+            resolution_cache: None,
+            skip_generated: false,
+            skipped_files: Arc::default(),
+        }
     }
 
     #[inline]
@@ -52,6 +81,21 @@ impl LintServiceOptions {
         self
     }
 
+    /// Resolve the imports of the files in each of `workspace_roots` with the `tsconfig.json` of
+    /// that root, and the projects it references, e.g. for the packages of a monorepo which are
+    /// linted together. Relative roots are relative to the current working directory.
+    ///
+    /// Files outside of the roots use the `tsconfig` of the service. Nested roots take precedence.
+    #[inline]
+    #[must_use]
+    pub fn with_workspace_roots(mut self, workspace_roots: Vec<PathBuf>) -> Self {
+        self.workspace_roots = workspace_roots
+            .into_iter()
+            .map(|root| if root.is_relative() { self.cwd.join(root) } else { root })
+            .collect();
+        self
+    }
+
     #[inline]
     #[must_use]
     pub fn with_cross_module(mut self, cross_module: bool) -> Self {
@@ -59,6 +103,40 @@ impl LintServiceOptions {
         self
     }
 
+    /// Resolve the `exports` and `imports` of `package.json` files with `condition_names`, in
+    /// order of priority, instead of `module` and `import`. Should match the bundler, so that
+    /// imports resolve to the same files.
+    #[inline]
+    #[must_use]
+    pub fn with_condition_names(mut self, condition_names: Vec<String>) -> Self {
+        self.condition_names = condition_names;
+        self
+    }
+
+    /// Resolve imports with `resolution_cache` instead of a cache of this service only.
+    #[inline]
+    #[must_use]
+    pub fn with_resolution_cache(mut self, resolution_cache: ResolutionCache) -> Self {
+        self.resolution_cache = Some(resolution_cache);
+        self
+    }
+
+    /// Do not lint the paths whose content is detected as generated or minified,
+    /// see [`is_generated_source`](crate::is_generated_source).
+    #[inline]
+    #[must_use]
+    pub fn with_skip_generated(mut self, skip_generated: bool) -> Self {
+        self.skip_generated = skip_generated;
+        self
+    }
+
+    /// Number of the paths skipped because their content is generated or minified, updated while
+    /// the service runs. It is final once the service has sent its last message.
+    #[inline]
+    pub fn skipped_files(&self) -> Arc<AtomicUsize> {
+        Arc::clone(&self.skipped_files)
+    }
+
     #[inline]
     pub fn cwd(&self) -> &Path {
         &self.cwd
@@ -0,0 +1,28 @@
+use std::sync::Arc;
+
+use oxc_resolver::{Cache, FileSystemOs, FsCache};
+
+/// Cache of the file system lookups of the import resolver: real paths, `package.json` files with
+/// their `exports` and `imports` maps, and `tsconfig.json` files.
+///
+/// A cache is cheap to clone and thread-safe. Clones share their entries, so a cache passed to the
+/// [`LintService`](super::LintService)s of consecutive runs, e.g. by the language server, saves
+/// reading the same `package.json` again for every file.
+#[derive(Clone, Default)]
+pub struct ResolutionCache(Arc<FsCache<FileSystemOs>>);
+
+impl ResolutionCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Forget all entries. Call this when files on disk change, e.g. in watch mode, as the
+    /// entries are not invalidated otherwise.
+    pub fn clear(&self) {
+        self.0.clear();
+    }
+
+    pub(super) fn fs_cache(&self) -> Arc<FsCache<FileSystemOs>> {
+        Arc::clone(&self.0)
+    }
+}
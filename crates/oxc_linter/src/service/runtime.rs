@@ -5,7 +5,11 @@ use std::{
     mem::take,
     path::{Path, PathBuf},
     rc::Rc,
-    sync::{Arc, mpsc},
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+        mpsc,
+    },
 };
 
 use indexmap::IndexSet;
@@ -18,28 +22,42 @@ use smallvec::SmallVec;
 use oxc_allocator::{Allocator, AllocatorGuard, AllocatorPool};
 use oxc_diagnostics::{DiagnosticSender, DiagnosticService, Error, OxcDiagnostic};
 use oxc_parser::{ParseOptions, Parser};
-use oxc_resolver::Resolver;
+use oxc_resolver::{ResolveOptions, Resolver};
 use oxc_semantic::{Semantic, SemanticBuilder};
 use oxc_span::{CompactStr, SourceType, VALID_EXTENSIONS};
 
-use super::LintServiceOptions;
+use super::{LintServiceOptions, ResolutionCache, tsconfig::ProjectResolvers};
 use crate::{
-    Fixer, Linter, Message,
-    fixer::PossibleFixes,
+    Fixer, Linter, Message, ModuleFormat,
     loader::{JavaScriptSource, LINT_PARTIAL_LOADER_EXTENSIONS, PartialLoader},
     module_record::ModuleRecord,
-    utils::read_to_arena_str,
+    rule::RuleRequirements,
+    utils::{is_generated_source, read_to_arena_str},
 };
 
+#[cfg(any(test, feature = "language_server"))]
+use crate::fixer::PossibleFixes;
+
 #[cfg(feature = "language_server")]
 use crate::fixer::MessageWithPosition;
 
+/// Maximum number of times the fixes are applied to a file, like ESLint.
+/// Fixing a problem can reveal new problems, or the fixes of overlapping problems are skipped
+/// until the next pass.
+const MAX_FIX_PASSES: usize = 10;
+
 pub struct Runtime<'l> {
     cwd: Box<Path>,
     /// All paths to lint
     paths: IndexSet<Arc<OsStr>, FxBuildHasher>,
     pub(super) linter: &'l Linter,
-    resolver: Option<Resolver>,
+    resolvers: Option<ProjectResolvers>,
+    /// Finds the `type` of the `package.json` of the linted files, for their [`ModuleFormat`]
+    package_type_resolver: Resolver,
+    /// Skip the paths whose content is generated or minified
+    skip_generated: bool,
+    /// Number of the paths skipped because their content is generated or minified
+    skipped_files: Arc<AtomicUsize>,
 
     pub(super) file_system: Box<dyn RuntimeFileSystem + Sync + Send>,
 
@@ -182,15 +200,26 @@ impl<'l> Runtime<'l> {
         allocator_pool: AllocatorPool,
         options: LintServiceOptions,
     ) -> Self {
-        let resolver = options.cross_module.then(|| {
-            Self::get_resolver(options.tsconfig.or_else(|| Some(options.cwd.join("tsconfig.json"))))
+        let resolution_cache = options.resolution_cache.unwrap_or_default();
+        let resolvers = options.cross_module.then(|| {
+            Self::get_resolvers(
+                options.tsconfig.or_else(|| Some(options.cwd.join("tsconfig.json"))),
+                &options.workspace_roots,
+                options.condition_names,
+                &resolution_cache,
+            )
         });
+        let package_type_resolver =
+            Resolver::new_with_cache(resolution_cache.fs_cache(), ResolveOptions::default());
         Self {
             allocator_pool,
             cwd: options.cwd,
             paths: options.paths.iter().cloned().collect(),
             linter,
-            resolver,
+            resolvers,
+            package_type_resolver,
+            skip_generated: options.skip_generated,
+            skipped_files: options.skipped_files,
             file_system: Box::new(OsFileSystem),
         }
     }
@@ -203,29 +232,42 @@ impl<'l> Runtime<'l> {
         self
     }
 
-    fn get_resolver(tsconfig_path: Option<PathBuf>) -> Resolver {
-        use oxc_resolver::{ResolveOptions, TsconfigOptions, TsconfigReferences};
-        let tsconfig = tsconfig_path.and_then(|path| {
-            path.is_file().then_some(TsconfigOptions {
-                config_file: path,
-                references: TsconfigReferences::Auto,
-            })
-        });
-        let extension_alias = tsconfig.as_ref().map_or_else(Vec::new, |_| {
+    fn get_resolvers(
+        tsconfig_path: Option<PathBuf>,
+        workspace_roots: &[PathBuf],
+        condition_names: Vec<String>,
+        resolution_cache: &ResolutionCache,
+    ) -> ProjectResolvers {
+        let tsconfig = tsconfig_path.filter(|path| path.is_file());
+        let workspace_tsconfigs = workspace_roots
+            .iter()
+            .map(|root| root.join("tsconfig.json"))
+            .filter(|path| path.is_file())
+            .collect::<Vec<_>>();
+        let has_tsconfig = tsconfig.is_some() || !workspace_tsconfigs.is_empty();
+        let extension_alias = if has_tsconfig {
             vec![
                 (".js".into(), vec![".js".into(), ".ts".into()]),
                 (".mjs".into(), vec![".mjs".into(), ".mts".into()]),
                 (".cjs".into(), vec![".cjs".into(), ".cts".into()]),
             ]
-        });
-        Resolver::new(ResolveOptions {
+        } else {
+            vec![]
+        };
+        let options = ResolveOptions {
             extensions: VALID_EXTENSIONS.iter().map(|ext| format!(".{ext}")).collect(),
             main_fields: vec!["module".into(), "main".into()],
-            condition_names: vec!["module".into(), "import".into()],
+            condition_names: if condition_names.is_empty() {
+                vec!["module".into(), "import".into()]
+            } else {
+                condition_names
+            },
+            // Subpath imports, e.g. `#internal/utils`, are resolved with the same conditions.
+            imports_fields: vec![vec!["imports".into()]],
             extension_alias,
-            tsconfig,
             ..ResolveOptions::default()
-        })
+        };
+        ProjectResolvers::new(options, tsconfig, workspace_tsconfigs, resolution_cache)
     }
 
     fn get_source_type_and_text<'a>(
@@ -270,7 +312,7 @@ impl<'l> Runtime<'l> {
         tx_error: &'a DiagnosticSender,
         on_module_to_lint: impl Fn(&'a Self, ModuleToLint) + Send + Sync + Clone + 'a,
     ) {
-        if self.resolver.is_none() {
+        if self.resolvers.is_none() {
             self.paths.par_iter().for_each(|path| {
                 let output = self.process_path(path, check_syntax_errors, tx_error);
                 let Some(entry) =
@@ -505,6 +547,9 @@ impl<'l> Runtime<'l> {
                     // Otherwise, spans for fixes will be incorrect due to varying size of the
                     // source code after each fix.
                     let mut fix_offset: i32 = 0;
+                    // Diagnostics of each section, with the start of the section in `new_source_text`.
+                    // They are reported after all sections are fixed.
+                    let mut section_diagnostics = vec![];
 
                     let path = Path::new(&module_to_lint.path);
 
@@ -517,49 +562,59 @@ impl<'l> Runtime<'l> {
                         .into_iter()
                         .zip(dep.section_contents.drain(..))
                     {
-                        let mut messages = match record_result {
-                            Ok(module_record) => me.linter.run(
-                                path,
-                                Rc::new(section.semantic.unwrap()),
-                                Arc::clone(&module_record),
-                            ),
-                            Err(errors) => errors
-                                .into_iter()
-                                .map(|err| Message::new(err, PossibleFixes::None))
-                                .collect(),
-                        };
-
                         let source_text = section.source.source_text;
-                        if me.linter.options().fix.is_some() {
-                            let fix_result = Fixer::new(source_text, messages).fix();
-                            if fix_result.fixed {
-                                // write to file, replacing only the changed part
-                                let start =
-                                    section.source.start.saturating_add_signed(fix_offset) as usize;
-                                let end = start + source_text.len();
-                                new_source_text
-                                    .to_mut()
-                                    .replace_range(start..end, &fix_result.fixed_code);
-                                let old_code_len = source_text.len() as u32;
-                                let new_code_len = fix_result.fixed_code.len() as u32;
-                                fix_offset += new_code_len as i32;
-                                fix_offset -= old_code_len as i32;
+                        let section_start = section.source.start.saturating_add_signed(fix_offset);
+
+                        let errors: Vec<OxcDiagnostic> = match record_result {
+                            Ok(module_record) => {
+                                let messages = me.linter.run(
+                                    path,
+                                    Rc::new(section.semantic.unwrap()),
+                                    Arc::clone(&module_record),
+                                );
+                                if me.linter.options().fix.is_some() {
+                                    let (fixed_code, errors) = me.fix_section(
+                                        path,
+                                        &section.source,
+                                        &module_record,
+                                        messages,
+                                    );
+                                    if let Some(fixed_code) = fixed_code {
+                                        // write to file, replacing only the changed part
+                                        let start = section_start as usize;
+                                        let end = start + source_text.len();
+                                        new_source_text
+                                            .to_mut()
+                                            .replace_range(start..end, &fixed_code);
+                                        let old_code_len = source_text.len() as u32;
+                                        let new_code_len = fixed_code.len() as u32;
+                                        fix_offset += new_code_len as i32;
+                                        fix_offset -= old_code_len as i32;
+                                    }
+                                    errors
+                                } else {
+                                    messages.into_iter().map(Into::into).collect()
+                                }
                             }
-                            messages = fix_result.messages;
-                        }
+                            Err(errors) => errors,
+                        };
 
-                        if !messages.is_empty() {
-                            let errors = messages.into_iter().map(Into::into).collect();
-                            let path = path.strip_prefix(&me.cwd).unwrap_or(path);
-                            let diagnostics = DiagnosticService::wrap_diagnostics(
-                                path,
-                                dep.source_text,
-                                section.source.start,
-                                errors,
-                            );
-                            tx_error.send(Some(diagnostics)).unwrap();
+                        if !errors.is_empty() {
+                            section_diagnostics.push((section_start, errors));
                         }
                     }
+
+                    let path_to_report = path.strip_prefix(&me.cwd).unwrap_or(path);
+                    for (section_start, errors) in section_diagnostics {
+                        let diagnostics = DiagnosticService::wrap_diagnostics(
+                            path_to_report,
+                            &new_source_text,
+                            section_start,
+                            errors,
+                        );
+                        tx_error.send(Some(diagnostics)).unwrap();
+                    }
+
                     // If the new source text is owned, that means it was modified,
                     // so we write the new source text to the file.
                     if let Cow::Owned(new_source_text) = new_source_text {
@@ -570,6 +625,64 @@ impl<'l> Runtime<'l> {
         });
     }
 
+    /// Apply the fixes of `messages` to the source of a section, then lint the fixed code and
+    /// apply the new fixes, until there is nothing left to fix or after [`MAX_FIX_PASSES`] passes.
+    ///
+    /// Fixes are applied in the order of their spans, and a fix which overlaps a previous one is
+    /// skipped until the next pass. When the fixed code does not parse anymore, the fixes of that
+    /// pass are discarded.
+    ///
+    /// Returns the fixed code, or `None` when no fix was applied, and the diagnostics which were not
+    /// fixed, with spans in the returned code.
+    fn fix_section<'a>(
+        &self,
+        path: &Path,
+        source: &JavaScriptSource<'a>,
+        module_record: &ModuleRecord,
+        messages: Vec<Message<'a>>,
+    ) -> (Option<String>, Vec<OxcDiagnostic>) {
+        // Holds the code and semantic of the passes after the first one.
+        let allocator = Allocator::default();
+        let requirements = self.linter.requirements(path);
+
+        let mut source_text = source.source_text;
+        let mut messages = messages;
+        let mut fixed = false;
+        for _ in 0..MAX_FIX_PASSES {
+            let unfixed_messages =
+                messages.iter().any(|message| !message.fixes.is_empty()).then(|| messages.clone());
+            let fix_result = Fixer::new(source_text, messages).fix();
+            let Some(unfixed_messages) = unfixed_messages.filter(|_| fix_result.fixed) else {
+                messages = fix_result.messages;
+                break;
+            };
+
+            let fixed_source_text = allocator.alloc_str(&fix_result.fixed_code);
+            let Ok((record, semantic)) = self.process_source_section(
+                path,
+                &allocator,
+                fixed_source_text,
+                source.source_type,
+                requirements,
+                true,
+            ) else {
+                // The fixes broke the code, keep the code and diagnostics of the previous pass.
+                messages = unfixed_messages;
+                break;
+            };
+
+            // The dependencies are the same, unless a fix changed the imports.
+            let loaded_modules = module_record.loaded_modules.read().unwrap().clone();
+            *record.module_record.loaded_modules.write().unwrap() = loaded_modules;
+
+            source_text = fixed_source_text;
+            fixed = true;
+            messages = self.linter.run(path, Rc::new(semantic), record.module_record);
+        }
+
+        (fixed.then(|| source_text.to_string()), messages.into_iter().map(Into::into).collect())
+    }
+
     // clippy: the source field is checked and assumed to be less than 4GB, and
     // we assume that the fix offset will not exceed 2GB in either direction
     // language_server: the language server needs line and character position
@@ -585,8 +698,10 @@ impl<'l> Runtime<'l> {
         use oxc_data_structures::rope::Rope;
         use std::sync::Mutex;
 
+        use oxc_diagnostics::RelatedInformation;
+
         use crate::{
-            FixWithPosition,
+            FixWithPosition, RelatedLabelWithPosition,
             fixer::{Fix, PossibleFixesWithPosition},
             service::offset_to_position::{SpanPositionMessage, offset_to_position},
         };
@@ -606,6 +721,35 @@ impl<'l> Runtime<'l> {
             }
         }
 
+        fn related_to_labels_with_position<'a>(
+            related: &[RelatedInformation],
+        ) -> Vec<RelatedLabelWithPosition<'a>> {
+            related
+                .iter()
+                .flat_map(|related| {
+                    let source_text = related.source_text();
+                    let rope = Rope::from_str(source_text);
+                    related.labels.iter().map(move |labeled_span| {
+                        let offset = labeled_span.offset() as u32;
+                        let start_position = offset_to_position(&rope, offset, source_text);
+                        let end_position = offset_to_position(
+                            &rope,
+                            offset + labeled_span.len() as u32,
+                            source_text,
+                        );
+                        let message = labeled_span
+                            .label()
+                            .map_or_else(|| related.message.to_string(), ToString::to_string);
+                        RelatedLabelWithPosition {
+                            path: related.path().to_path_buf(),
+                            label: SpanPositionMessage::new(start_position, end_position)
+                                .with_message(Some(Cow::Owned(message))),
+                        }
+                    })
+                })
+                .collect()
+        }
+
         let messages = Mutex::new(Vec::<MessageWithPosition<'a>>::new());
         let (sender, _receiver) = mpsc::channel();
         rayon::scope(|scope| {
@@ -678,6 +822,11 @@ impl<'l> Runtime<'l> {
                                                 url: message.error.url.clone(),
                                                 code: message.error.code.clone(),
                                                 labels: labels.clone(),
+                                                related: message
+                                                    .error
+                                                    .related
+                                                    .as_deref()
+                                                    .map(related_to_labels_with_position),
                                                 fixes: match &message.fixes {
                                                     PossibleFixes::None => {
                                                         PossibleFixesWithPosition::None
@@ -820,6 +969,10 @@ impl<'l> Runtime<'l> {
                         return Err(());
                     }
                 };
+                if self.skip_generated && is_generated_source(source_text) {
+                    self.skipped_files.fetch_add(1, Ordering::Relaxed);
+                    return Err(());
+                }
 
                 let mut section_contents = SmallVec::new();
                 records = self.process_source(
@@ -887,6 +1040,7 @@ impl<'l> Runtime<'l> {
     ) -> SmallVec<[Result<ResolvedModuleRecord, Vec<OxcDiagnostic>>; 1]> {
         let section_sources = PartialLoader::parse(ext, source_text)
             .unwrap_or_else(|| vec![JavaScriptSource::partial(source_text, source_type, 0)]);
+        let requirements = self.linter.requirements(path);
 
         let mut section_module_records = SmallVec::<
             [Result<ResolvedModuleRecord, Vec<OxcDiagnostic>>; 1],
@@ -897,6 +1051,7 @@ impl<'l> Runtime<'l> {
                 allocator,
                 section_source.source_text,
                 section_source.source_type,
+                requirements,
                 check_syntax_errors,
             ) {
                 Ok((record, semantic)) => {
@@ -925,11 +1080,13 @@ impl<'l> Runtime<'l> {
         allocator: &'a Allocator,
         source_text: &'a str,
         source_type: SourceType,
+        requirements: RuleRequirements,
         check_syntax_errors: bool,
     ) -> Result<(ResolvedModuleRecord, Semantic<'a>), Vec<OxcDiagnostic>> {
+        // Only build the analyses which the enabled rules need.
         let ret = Parser::new(allocator, source_text, source_type)
             .with_options(ParseOptions {
-                parse_regular_expression: true,
+                parse_regular_expression: requirements.contains(RuleRequirements::REGEX),
                 allow_return_outside_function: true,
                 ..ParseOptions::default()
             })
@@ -940,9 +1097,9 @@ impl<'l> Runtime<'l> {
         }
 
         let semantic_ret = SemanticBuilder::new()
-            .with_cfg(true)
+            .with_cfg(requirements.contains(RuleRequirements::CFG))
             .with_scope_tree_child_ids(true)
-            .with_build_jsdoc(true)
+            .with_build_jsdoc(requirements.contains(RuleRequirements::JSDOC))
             .with_check_syntax_error(check_syntax_errors)
             .build(allocator.alloc(ret.program));
 
@@ -953,14 +1110,17 @@ impl<'l> Runtime<'l> {
         let mut semantic = semantic_ret.semantic;
         semantic.set_irregular_whitespaces(ret.irregular_whitespaces);
 
-        let module_record = Arc::new(ModuleRecord::new(path, &ret.module_record, &semantic));
+        let mut module_record = ModuleRecord::new(path, &ret.module_record, &semantic);
+        module_record.module_format = ModuleFormat::resolve(path, &self.package_type_resolver);
+        let module_record = Arc::new(module_record);
 
         let mut resolved_module_requests: Vec<ResolvedModuleRequest> = vec![];
 
         // If import plugin is enabled.
-        if let Some(resolver) = &self.resolver {
+        if let Some(resolvers) = &self.resolvers {
             // Retrieve all dependent modules from this module.
             let dir = path.parent().unwrap();
+            let resolver = resolvers.resolver(path);
             resolved_module_requests = module_record
                 .requested_modules
                 .keys()
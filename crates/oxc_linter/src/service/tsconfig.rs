@@ -0,0 +1,143 @@
+use std::{
+    fs,
+    path::{Component, Path, PathBuf},
+};
+
+use oxc_resolver::{ResolveOptions, Resolver, TsconfigOptions, TsconfigReferences};
+use rustc_hash::FxHashSet;
+
+use super::ResolutionCache;
+
+/// Resolvers for the TypeScript projects of a workspace: a `tsconfig.json` and the projects it
+/// references, recursively, and the `tsconfig.json` of each workspace root with its references.
+///
+/// Imports are resolved with the `paths` and `baseUrl` of the project containing the importing
+/// file, after applying the `extends` chain of that project as `tsc` does.
+/// `oxc_resolver` only applies the `extends` chain of the configured `tsconfig.json`, so each
+/// project has a resolver of its own, and all of them share the [`ResolutionCache`].
+pub struct ProjectResolvers {
+    /// Resolver for files outside of the referenced projects and the workspace roots.
+    root: Resolver,
+    /// Directories of the referenced projects and the workspace roots, deepest first,
+    /// with their resolvers.
+    projects: Vec<(PathBuf, Resolver)>,
+}
+
+impl ProjectResolvers {
+    pub fn new(
+        options: ResolveOptions,
+        tsconfig: Option<PathBuf>,
+        workspace_tsconfigs: Vec<PathBuf>,
+        resolution_cache: &ResolutionCache,
+    ) -> Self {
+        if tsconfig.is_none() && workspace_tsconfigs.is_empty() {
+            let root = Resolver::new_with_cache(resolution_cache.fs_cache(), options);
+            return Self { root, projects: vec![] };
+        }
+        let with_tsconfig = |config_file: PathBuf| ResolveOptions {
+            // References are resolved by the resolvers of the referenced projects.
+            tsconfig: Some(TsconfigOptions {
+                config_file,
+                references: TsconfigReferences::Disabled,
+            }),
+            ..options.clone()
+        };
+        let mut references = vec![];
+        let mut visited = FxHashSet::default();
+        if let Some(tsconfig) = &tsconfig {
+            collect_references(tsconfig, &mut references, &mut visited);
+        }
+        // A workspace root is a project of its own, unless it is already referenced.
+        for workspace_tsconfig in workspace_tsconfigs {
+            if visited.contains(&workspace_tsconfig) {
+                continue;
+            }
+            collect_references(&workspace_tsconfig, &mut references, &mut visited);
+            references.push(workspace_tsconfig);
+        }
+        let root = Resolver::new_with_cache(
+            resolution_cache.fs_cache(),
+            tsconfig.map_or_else(|| options.clone(), with_tsconfig),
+        );
+
+        let mut projects = references
+            .into_iter()
+            .filter_map(|config_file| {
+                let dir = config_file.parent()?.to_path_buf();
+                Some((dir, root.clone_with_options(with_tsconfig(config_file))))
+            })
+            .collect::<Vec<_>>();
+        projects.sort_by_key(|(dir, _)| std::cmp::Reverse(dir.components().count()));
+        Self { root, projects }
+    }
+
+    /// The resolver for imports of the file at `path`.
+    pub fn resolver(&self, path: &Path) -> &Resolver {
+        self.projects
+            .iter()
+            .find(|(dir, _)| path.starts_with(dir))
+            .map_or(&self.root, |(_, resolver)| resolver)
+    }
+}
+
+/// Collect the `tsconfig.json` files referenced by `tsconfig`, and by the referenced projects.
+///
+/// Files which can't be read or parsed are skipped, they fall back to the resolver of the root.
+fn collect_references(
+    tsconfig: &Path,
+    references: &mut Vec<PathBuf>,
+    visited: &mut FxHashSet<PathBuf>,
+) {
+    if !visited.insert(tsconfig.to_path_buf()) {
+        return;
+    }
+    let Ok(mut source_text) = fs::read_to_string(tsconfig) else { return };
+    if json_strip_comments::strip(&mut source_text).is_err() {
+        return;
+    }
+    let Ok(json) = serde_json::from_str::<serde_json::Value>(&source_text) else { return };
+    let Some(dir) = tsconfig.parent() else { return };
+    let paths = json
+        .get("references")
+        .and_then(serde_json::Value::as_array)
+        .into_iter()
+        .flatten()
+        .filter_map(|reference| reference.get("path")?.as_str());
+    for path in paths {
+        let reference = reference_config_file(&normalize(&dir.join(path)));
+        if visited.contains(&reference) {
+            continue;
+        }
+        collect_references(&reference, references, visited);
+        references.push(reference);
+    }
+}
+
+/// The `tsconfig.json` file of a project reference, which can be a file or a directory.
+fn reference_config_file(path: &Path) -> PathBuf {
+    if path.is_dir() {
+        path.join("tsconfig.json")
+    } else if path.is_file() {
+        path.to_path_buf()
+    } else {
+        let mut path = path.as_os_str().to_os_string();
+        path.push(".json");
+        PathBuf::from(path)
+    }
+}
+
+/// Remove the `.` and `..` components of `path`, for matching the paths of linted files against
+/// reference paths such as `../packages/a`.
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                normalized.pop();
+            }
+            component => normalized.push(component),
+        }
+    }
+    normalized
+}
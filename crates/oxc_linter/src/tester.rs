@@ -75,6 +75,50 @@ impl From<(&str, Option<Value>, Option<Value>, Option<PathBuf>)> for TestCase {
     }
 }
 
+impl TestCase {
+    pub fn new<S: Into<String>>(source: S) -> Self {
+        Self { source: source.into(), ..Self::default() }
+    }
+
+    #[must_use]
+    pub fn with_rule_config(mut self, rule_config: Value) -> Self {
+        self.rule_config = Some(rule_config);
+        self
+    }
+
+    /// Lint the source as if it were in the file at `path`, relative to the fixtures directory.
+    ///
+    /// The extension decides the source type, e.g. `foo.cjs` is parsed as a script and
+    /// `foo.d.ts` as a TypeScript declaration file.
+    #[must_use]
+    pub fn with_path<P: Into<PathBuf>>(mut self, path: P) -> Self {
+        self.path = Some(path.into());
+        self
+    }
+
+    /// Set the `settings` of the config, e.g. `{ "jsx-a11y": { "components": { "Link": "a" } } }`.
+    #[must_use]
+    pub fn with_settings(mut self, settings: Value) -> Self {
+        set_eslint_config_field(&mut self.eslint_config, "settings", settings);
+        self
+    }
+
+    /// Set the `globals` of the config, e.g. `{ "foo": "readonly" }`.
+    #[must_use]
+    pub fn with_globals(mut self, globals: Value) -> Self {
+        set_eslint_config_field(&mut self.eslint_config, "globals", globals);
+        self
+    }
+}
+
+fn set_eslint_config_field(eslint_config: &mut Option<Value>, field: &str, value: Value) {
+    let config = eslint_config.get_or_insert_with(|| json!({}));
+    config
+        .as_object_mut()
+        .expect("eslint config of a test case must be an object")
+        .insert(field.into(), value);
+}
+
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 pub enum ExpectFixKind {
     /// We expect no fix to be applied
@@ -128,6 +172,8 @@ pub struct ExpectFixTestCase {
     source: String,
     expected: Vec<ExpectFix>,
     rule_config: Option<Value>,
+    eslint_config: Option<Value>,
+    path: Option<PathBuf>,
 }
 
 #[derive(Debug, Clone)]
@@ -143,6 +189,8 @@ impl<S: Into<String>> From<(S, S, Option<Value>)> for ExpectFixTestCase {
             source: value.0.into(),
             expected: vec![ExpectFix { expected: value.1.into(), kind: ExpectFixKind::Any }],
             rule_config: value.2,
+            eslint_config: None,
+            path: None,
         }
     }
 }
@@ -153,6 +201,8 @@ impl<S: Into<String>> From<(S, S)> for ExpectFixTestCase {
             source: value.0.into(),
             expected: vec![ExpectFix { expected: value.1.into(), kind: ExpectFixKind::Any }],
             rule_config: None,
+            eslint_config: None,
+            path: None,
         }
     }
 }
@@ -166,6 +216,8 @@ impl<S: Into<String>> From<(S, (S, S))> for ExpectFixTestCase {
                 ExpectFix { expected: value.1.1.into(), kind: ExpectFixKind::Any },
             ],
             rule_config: None,
+            eslint_config: None,
+            path: None,
         }
     }
 }
@@ -180,7 +232,53 @@ where
             source: source.into(),
             expected: vec![ExpectFix { expected: expected.into(), kind: kind.into() }],
             rule_config: config,
+            eslint_config: None,
+            path: None,
+        }
+    }
+}
+
+impl ExpectFixTestCase {
+    /// Expect `source` to be fixed into exactly `expected`.
+    pub fn new<S: Into<String>>(source: S, expected: S) -> Self {
+        Self::from((source, expected))
+    }
+
+    /// Expect the fix to be of a certain kind, e.g. [`FixKind::Suggestion`].
+    #[must_use]
+    pub fn with_fix_kind<F: Into<ExpectFixKind>>(mut self, kind: F) -> Self {
+        let kind = kind.into();
+        for expect in &mut self.expected {
+            expect.kind = kind;
         }
+        self
+    }
+
+    #[must_use]
+    pub fn with_rule_config(mut self, rule_config: Value) -> Self {
+        self.rule_config = Some(rule_config);
+        self
+    }
+
+    /// See [`TestCase::with_path`].
+    #[must_use]
+    pub fn with_path<P: Into<PathBuf>>(mut self, path: P) -> Self {
+        self.path = Some(path.into());
+        self
+    }
+
+    /// See [`TestCase::with_settings`].
+    #[must_use]
+    pub fn with_settings(mut self, settings: Value) -> Self {
+        set_eslint_config_field(&mut self.eslint_config, "settings", settings);
+        self
+    }
+
+    /// See [`TestCase::with_globals`].
+    #[must_use]
+    pub fn with_globals(mut self, globals: Value) -> Self {
+        set_eslint_config_field(&mut self.eslint_config, "globals", globals);
+        self
     }
 }
 
@@ -363,6 +461,14 @@ impl Tester {
     /// // the first argument is normally `MyRuleStruct::NAME`.
     /// Tester::new("no-undef", pass, fail).expect_fix(fix).test();
     /// ```
+    ///
+    /// Use [`ExpectFixTestCase::new`] to also set a virtual filename, settings or globals:
+    ///
+    /// ```ignore
+    /// let fix = vec![
+    ///     ExpectFixTestCase::new("let x = 1", "let x = 1").with_path("foo.cjs"),
+    /// ];
+    /// ```
     #[must_use]
     pub fn expect_fix<F: Into<ExpectFixTestCase>>(mut self, expect_fix: Vec<F>) -> Self {
         // prevent `expect_fix` abuse
@@ -473,10 +579,17 @@ impl Tester {
         };
 
         for fix in fix_test_cases {
-            let ExpectFixTestCase { source, expected, rule_config: config } = fix;
+            let ExpectFixTestCase { source, expected, rule_config: config, eslint_config, path } =
+                fix;
             for (index, expect) in expected.iter().enumerate() {
-                let result =
-                    self.run(&source, config.clone(), None, None, expect.kind, index as u8);
+                let result = self.run(
+                    &source,
+                    config.clone(),
+                    eslint_config.clone(),
+                    path.clone(),
+                    expect.kind,
+                    index as u8,
+                );
                 match result {
                     TestResult::Fixed(fixed_str) => assert_eq!(
                         expect.expected, fixed_str,
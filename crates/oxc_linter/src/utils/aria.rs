@@ -0,0 +1,218 @@
+//! The roles of ARIA and of HTML elements, shared by the `jsx-a11y` rules.
+//!
+//! ref: <https://github.com/A11yance/aria-query/blob/v5.3.2/src/rolesMap.js>
+//! ref: <https://www.w3.org/TR/html-aria/#docconformance>
+
+use oxc_ast::ast::JSXOpeningElement;
+
+use crate::{
+    globals::VALID_ARIA_ROLES,
+    utils::{get_string_literal_prop_value, has_jsx_prop, has_jsx_prop_ignore_case},
+};
+
+/// Roles of widgets, which users interact with.
+///
+/// The roles descending from `widget`, and `toolbar`, which supports `aria-activedescendant` like
+/// a widget. `progressbar` descends from `widget` but is read-only, and is not included.
+///
+/// ref: <https://github.com/jsx-eslint/eslint-plugin-jsx-a11y/blob/v6.9.0/src/util/isInteractiveRole.js>
+pub const INTERACTIVE_ROLES: phf::Set<&'static str> = phf::phf_set![
+    "button",
+    "checkbox",
+    "columnheader",
+    "combobox",
+    "doc-backlink",
+    "doc-biblioref",
+    "doc-glossref",
+    "doc-noteref",
+    "grid",
+    "gridcell",
+    "link",
+    "listbox",
+    "menu",
+    "menubar",
+    "menuitem",
+    "menuitemcheckbox",
+    "menuitemradio",
+    "option",
+    "radio",
+    "radiogroup",
+    "row",
+    "rowheader",
+    "scrollbar",
+    "searchbox",
+    "separator",
+    "slider",
+    "spinbutton",
+    "switch",
+    "tab",
+    "tablist",
+    "textbox",
+    "toolbar",
+    "tree",
+    "treegrid",
+    "treeitem",
+];
+
+/// Elements which are interactive content, some of them only with some attributes, see
+/// [`is_interactive_element`].
+///
+/// ref: <https://html.spec.whatwg.org/multipage/dom.html#interactive-content>
+pub const INTERACTIVE_ELEMENTS: phf::Set<&'static str> = phf::phf_set![
+    "a", "audio", "button", "details", "embed", "iframe", "img", "input", "label", "select",
+    "textarea", "video",
+];
+
+/// Elements whose role is not interactive, in addition to the elements with a non-interactive
+/// implicit role, see [`get_implicit_role`].
+pub const NON_INTERACTIVE_ELEMENTS: phf::Set<&'static str> = phf::phf_set![
+    "abbr",
+    "blockquote",
+    "br",
+    "caption",
+    "code",
+    "dd",
+    "del",
+    "dfn",
+    "dir",
+    "dl",
+    "dt",
+    "em",
+    "fieldset",
+    "figcaption",
+    "figure",
+    "footer",
+    "header",
+    "ins",
+    "legend",
+    "main",
+    "mark",
+    "marquee",
+    "p",
+    "pre",
+    "strong",
+    "sub",
+    "sup",
+    "table",
+    "td",
+    "time",
+];
+
+pub fn is_interactive_role(role: &str) -> bool {
+    INTERACTIVE_ROLES.contains(role)
+}
+
+/// Valid roles which are neither interactive nor presentational, like `article` or `heading`.
+pub fn is_non_interactive_role(role: &str) -> bool {
+    VALID_ARIA_ROLES.contains(role)
+        && !INTERACTIVE_ROLES.contains(role)
+        && !matches!(role, "presentation" | "none")
+}
+
+/// The first valid role of the `role` prop of `node`, e.g. `button` for `role="button link"`.
+pub fn get_explicit_role<'a>(node: &'a JSXOpeningElement<'_>) -> Option<&'a str> {
+    let role = has_jsx_prop_ignore_case(node, "role").and_then(get_string_literal_prop_value)?;
+    role.split_ascii_whitespace().find(|role| VALID_ARIA_ROLES.contains(role))
+}
+
+// TODO: Should re-implement
+// https://github.com/jsx-eslint/eslint-plugin-jsx-a11y/blob/4c7e7815c12a797587bb8e3cdced7f3003848964/src/util/isInteractiveElement.js
+// with `oxc-project/aria-query` which is currently W.I.P.
+//
+// Until then, use simplified version by https://html.spec.whatwg.org/multipage/dom.html#interactive-content
+pub fn is_interactive_element(element_type: &str, jsx_opening_el: &JSXOpeningElement) -> bool {
+    if !INTERACTIVE_ELEMENTS.contains(element_type) {
+        return false;
+    }
+    // Interactive contents are...
+    // - button, details, embed, iframe, label, select, textarea
+    // - input (if the `type` attribute is not in the Hidden state)
+    // - a (if the `href` attribute is present)
+    // - audio, video (if the `controls` attribute is present)
+    // - img (if the `usemap` attribute is present)
+    match element_type {
+        "input" => {
+            if let Some(input_type) = has_jsx_prop(jsx_opening_el, "type") {
+                if get_string_literal_prop_value(input_type)
+                    .is_some_and(|val| val.eq_ignore_ascii_case("hidden"))
+                {
+                    return false;
+                }
+            }
+            true
+        }
+        "a" => has_jsx_prop(jsx_opening_el, "href").is_some(),
+        "audio" | "video" => has_jsx_prop(jsx_opening_el, "controls").is_some(),
+        "img" => has_jsx_prop(jsx_opening_el, "usemap").is_some(),
+        _ => true,
+    }
+}
+
+/// Elements which users don't interact with, like `<article>`, `<h1>` or `<li>`.
+pub fn is_non_interactive_element(element_type: &str, jsx_opening_el: &JSXOpeningElement) -> bool {
+    if is_interactive_element(element_type, jsx_opening_el) {
+        return false;
+    }
+    NON_INTERACTIVE_ELEMENTS.contains(element_type)
+        || get_implicit_role(jsx_opening_el, element_type).is_some_and(is_non_interactive_role)
+}
+
+/// ref: <https://github.com/jsx-eslint/eslint-plugin-jsx-a11y/blob/v6.9.0/src/util/getImplicitRole.js>
+pub fn get_implicit_role<'a>(
+    node: &'a JSXOpeningElement<'a>,
+    element_type: &str,
+) -> Option<&'static str> {
+    let implicit_role = match element_type {
+        "a" | "area" | "link" => match has_jsx_prop_ignore_case(node, "href") {
+            Some(_) => "link",
+            None => "",
+        },
+        "article" => "article",
+        "aside" => "complementary",
+        "body" => "document",
+        "button" => "button",
+        "datalist" | "select" => "listbox",
+        "details" => "group",
+        "dialog" => "dialog",
+        "form" => "form",
+        "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => "heading",
+        "hr" => "separator",
+        "img" => has_jsx_prop_ignore_case(node, "alt").map_or("img", |i| {
+            get_string_literal_prop_value(i)
+                .map_or("img", |v| if v.is_empty() { "" } else { "img" })
+        }),
+        "input" => has_jsx_prop_ignore_case(node, "type").map_or("textbox", |input_type| {
+            match get_string_literal_prop_value(input_type) {
+                Some("button" | "image" | "reset" | "submit") => "button",
+                Some("checkbox") => "checkbox",
+                Some("radio") => "radio",
+                Some("range") => "slider",
+                _ => "textbox",
+            }
+        }),
+        "li" => "listitem",
+        "menu" => has_jsx_prop_ignore_case(node, "type").map_or("", |v| {
+            get_string_literal_prop_value(v)
+                .map_or("", |v| if v == "toolbar" { "toolbar" } else { "" })
+        }),
+        "menuitem" => has_jsx_prop_ignore_case(node, "type").map_or("", |v| {
+            match get_string_literal_prop_value(v) {
+                Some("checkbox") => "menuitemcheckbox",
+                Some("command") => "menuitem",
+                Some("radio") => "menuitemradio",
+                _ => "",
+            }
+        }),
+        "meter" | "progress" => "progressbar",
+        "nav" => "navigation",
+        "ol" | "ul" => "list",
+        "option" => "option",
+        "output" => "status",
+        "section" => "region",
+        "tbody" | "tfoot" | "thead" => "rowgroup",
+        "textarea" => "textbox",
+        _ => "",
+    };
+
+    VALID_ARIA_ROLES.contains(implicit_role).then_some(implicit_role)
+}
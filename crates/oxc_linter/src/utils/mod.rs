@@ -9,6 +9,7 @@ use std::{
 
 use oxc_allocator::Allocator;
 
+mod aria;
 mod comment;
 mod config;
 mod express;
@@ -24,7 +25,7 @@ mod url;
 mod vitest;
 
 pub use self::{
-    comment::*, config::*, express::*, jest::*, jsdoc::*, nextjs::*, promise::*, react::*,
+    aria::*, comment::*, config::*, express::*, jest::*, jsdoc::*, nextjs::*, promise::*, react::*,
     react_perf::*, regex::*, unicorn::*, url::*, vitest::*,
 };
 
@@ -246,6 +247,53 @@ fn read_to_arena_bytes_known_size(
     Ok(bytes)
 }
 
+/// Markers of generated files, looked up in the comments at the start of the file.
+const GENERATED_MARKERS: [&str; 3] = ["@generated", "DO NOT EDIT", "auto-generated"];
+
+/// Whether `source_text` is generated or minified, and should not be linted.
+///
+/// A file is generated when a comment before its first token, after an optional hashbang,
+/// contains a marker such as `@generated` or `DO NOT EDIT`, and minified when it is larger than
+/// 1 KiB with an average line length above 500 characters.
+pub fn is_generated_source(source_text: &str) -> bool {
+    const MINIFIED_SIZE: usize = 1024;
+    const MINIFIED_LINE_LENGTH: usize = 500;
+
+    if has_generated_marker(source_text) {
+        return true;
+    }
+    if source_text.len() <= MINIFIED_SIZE {
+        return false;
+    }
+    let lines = source_text.matches('\n').count() + 1;
+    source_text.len() / lines > MINIFIED_LINE_LENGTH
+}
+
+/// Whether one of the leading comments of `source_text` contains one of [`GENERATED_MARKERS`].
+fn has_generated_marker(source_text: &str) -> bool {
+    let mut rest = source_text.trim_start_matches('\u{feff}');
+    if rest.starts_with("#!") {
+        rest = rest.find('\n').map_or("", |end| &rest[end..]);
+    }
+    loop {
+        rest = rest.trim_start();
+        let comment = if let Some(comment) = rest.strip_prefix("//") {
+            let end = comment.find('\n').unwrap_or(comment.len());
+            rest = &comment[end..];
+            &comment[..end]
+        } else if let Some(comment) = rest.strip_prefix("/*") {
+            let end = comment.find("*/").unwrap_or(comment.len());
+            rest = comment.get(end + 2..).unwrap_or_default();
+            &comment[..end]
+        } else {
+            return false;
+        };
+        if GENERATED_MARKERS.iter().any(|marker| comment.contains(marker)) {
+            return true;
+        }
+    }
+}
+
 /// Fallback for when file size is unknown.
 /// Read file contents into a `Vec`, and then copy into arena.
 fn read_to_arena_bytes_unknown_size(mut file: File, allocator: &Allocator) -> io::Result<&[u8]> {
@@ -256,3 +304,28 @@ fn read_to_arena_bytes_unknown_size(mut file: File, allocator: &Allocator) -> io
     // Allocate bytes into arena
     Ok(allocator.alloc_slice_copy(&bytes))
 }
+
+#[cfg(test)]
+mod test {
+    use super::is_generated_source;
+
+    #[test]
+    fn generated_source() {
+        assert!(is_generated_source("// @generated by protoc\nexport const a = 1;"));
+        assert!(is_generated_source("/* This file is auto-generated. DO NOT EDIT. */"));
+        assert!(!is_generated_source("export const a = 1;\n"));
+        assert!(is_generated_source(
+            "#!/usr/bin/env node\n// License\n\n/** @generated */\nrun();"
+        ));
+        // Only comments before the code
+        assert!(!is_generated_source("const message = 'DO NOT EDIT';\n"));
+        assert!(!is_generated_source("// Helpers\nconst a = 1;\n// DO NOT EDIT below\n"));
+        assert!(is_generated_source("/* @generated"));
+
+        let minified = "var a=1;".repeat(200);
+        assert!(is_generated_source(&minified));
+        let formatted = "var a = 1;\n".repeat(200);
+        assert!(!is_generated_source(&formatted));
+        assert!(!is_generated_source(&format!("{formatted}// DO NOT EDIT")));
+    }
+}
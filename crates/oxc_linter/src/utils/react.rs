@@ -114,35 +114,19 @@ pub fn is_presentation_role(jsx_opening_el: &JSXOpeningElement) -> bool {
     matches!(get_string_literal_prop_value(role), Some("presentation" | "none"))
 }
 
-// TODO: Should re-implement
-// https://github.com/jsx-eslint/eslint-plugin-jsx-a11y/blob/4c7e7815c12a797587bb8e3cdced7f3003848964/src/util/isInteractiveElement.js
-// with `oxc-project/aria-query` which is currently W.I.P.
-//
-// Until then, use simplified version by https://html.spec.whatwg.org/multipage/dom.html#interactive-content
-pub fn is_interactive_element(element_type: &str, jsx_opening_el: &JSXOpeningElement) -> bool {
-    // Interactive contents are...
-    // - button, details, embed, iframe, label, select, textarea
-    // - input (if the `type` attribute is not in the Hidden state)
-    // - a (if the `href` attribute is present)
-    // - audio, video (if the `controls` attribute is present)
-    // - img (if the `usemap` attribute is present)
-    match element_type {
-        "button" | "details" | "embed" | "iframe" | "label" | "select" | "textarea" => true,
-        "input" => {
-            if let Some(input_type) = has_jsx_prop(jsx_opening_el, "type") {
-                if get_string_literal_prop_value(input_type)
-                    .is_some_and(|val| val.eq_ignore_ascii_case("hidden"))
-                {
-                    return false;
-                }
-            }
-            true
+/// Whether `node` has the prop `name` with a value, which is not `null` or `undefined`.
+///
+/// e.g. `onClick={handleClick}`, but not `onClick={undefined}`.
+pub fn has_jsx_prop_with_value(node: &JSXOpeningElement, name: &str) -> bool {
+    has_jsx_prop(node, name).is_some_and(|item| match get_prop_value(item) {
+        Some(JSXAttributeValue::ExpressionContainer(container)) => {
+            !matches!(
+                &container.expression,
+                JSXExpression::NullLiteral(_) | JSXExpression::EmptyExpression(_)
+            ) && !container.expression.is_undefined()
         }
-        "a" => has_jsx_prop(jsx_opening_el, "href").is_some(),
-        "audio" | "video" => has_jsx_prop(jsx_opening_el, "controls").is_some(),
-        "img" => has_jsx_prop(jsx_opening_el, "usemap").is_some(),
-        _ => false,
-    }
+        _ => true,
+    })
 }
 
 const PRAGMA: &str = "React";
@@ -78,7 +78,7 @@ pub fn declare_all_lint_rules(metadata: AllLintRulesMeta) -> TokenStream {
 
         use crate::{
             context::{ContextHost, LintContext},
-            rule::{Rule, RuleCategory, RuleFixMeta, RuleMeta},
+            rule::{Rule, RuleCategory, RuleFixMeta, RuleMeta, RuleRequirements},
             utils::PossibleJestNode,
             AstNode
         };
@@ -116,6 +116,13 @@ pub fn declare_all_lint_rules(metadata: AllLintRulesMeta) -> TokenStream {
                 }
             }
 
+            /// The analyses this [`Rule`] needs.
+            pub fn requirements(&self) -> RuleRequirements {
+                match self {
+                    #(Self::#struct_names(_) => #struct_names::REQUIREMENTS),*
+                }
+            }
+
             #[cfg(feature = "ruledocs")]
             pub fn documentation(&self) -> Option<&'static str> {
                 match self {
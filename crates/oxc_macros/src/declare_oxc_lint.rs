@@ -3,8 +3,10 @@ use itertools::Itertools as _;
 use proc_macro::TokenStream;
 use quote::quote;
 use syn::{
-    Attribute, Error, Expr, Ident, Lit, LitStr, Meta, Result, Token,
+    Attribute, Error, Expr, Ident, Lit, LitStr, Meta, Result, Token, bracketed,
     parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    token,
 };
 
 pub struct LintRuleMeta {
@@ -20,6 +22,8 @@ pub struct LintRuleMeta {
     /// This is the name of a struct/enum/whatever implementing
     /// schemars::JsonSchema
     config: Option<Ident>,
+    /// Analyses the rule needs, e.g. `cfg` or `[jsdoc, regex]`
+    requires: Vec<Ident>,
 }
 
 impl Parse for LintRuleMeta {
@@ -60,6 +64,7 @@ impl Parse for LintRuleMeta {
         // Do not provide a default value here so that it can be set there instead.
         let mut fix: Option<Ident> = None;
         let mut config: Option<Ident> = None;
+        let mut requires: Vec<Ident> = vec![];
 
         // remaining options are `key = value` pairs, with the exception of
         // fix kinds. Those can be short-handed to just the fix kind
@@ -85,6 +90,19 @@ impl Parse for LintRuleMeta {
                     input.parse::<Token!(=)>()?;
                     config.replace(input.parse()?);
                 }
+                // requires = cfg
+                // requires = [jsdoc, regex]
+                "requires" => {
+                    input.parse::<Token!(=)>()?;
+                    if input.peek(token::Bracket) {
+                        let content;
+                        bracketed!(content in input);
+                        let idents = Punctuated::<Ident, Token!(,)>::parse_terminated(&content)?;
+                        requires.extend(idents);
+                    } else {
+                        requires.push(input.parse()?);
+                    }
+                }
                 _ => {
                     if input.peek(Token!(=)) || fix.is_some() {
                         panic!("invalid key: {key}");
@@ -108,6 +126,7 @@ impl Parse for LintRuleMeta {
             documentation,
             used_in_test: false,
             config,
+            requires,
         })
     }
 }
@@ -126,6 +145,7 @@ pub fn declare_oxc_lint(metadata: LintRuleMeta) -> TokenStream {
         documentation,
         used_in_test,
         config,
+        requires,
     } = metadata;
 
     let canonical_name = rule_name_converter().convert(name.to_string());
@@ -148,10 +168,19 @@ pub fn declare_oxc_lint(metadata: LintRuleMeta) -> TokenStream {
         }
     });
 
+    let requirements = (!requires.is_empty()).then(|| {
+        let flags = requires.iter().map(parse_requirement);
+        quote! {
+            const REQUIREMENTS: RuleRequirements = RuleRequirements::empty()#(.union(#flags))*;
+        }
+    });
+
     let import_statement = if used_in_test {
         None
     } else {
-        Some(quote! { use crate::{rule::{RuleCategory, RuleMeta, RuleFixMeta}, fixer::FixKind}; })
+        Some(quote! {
+            use crate::{rule::{RuleCategory, RuleMeta, RuleFixMeta, RuleRequirements}, fixer::FixKind};
+        })
     };
 
     #[cfg(not(feature = "ruledocs"))]
@@ -195,6 +224,8 @@ pub fn declare_oxc_lint(metadata: LintRuleMeta) -> TokenStream {
 
             #fix
 
+            #requirements
+
             #docs
 
             #config_schema
@@ -221,6 +252,17 @@ fn parse_attr<'a, const LEN: usize>(
     None
 }
 
+fn parse_requirement(requirement: &Ident) -> proc_macro2::TokenStream {
+    match requirement.to_string().as_str() {
+        "cfg" => quote! { RuleRequirements::CFG },
+        "jsdoc" => quote! { RuleRequirements::JSDOC },
+        "regex" => quote! { RuleRequirements::REGEX },
+        invalid => {
+            panic!("invalid requirement: {invalid}. Valid requirements are cfg, jsdoc and regex.")
+        }
+    }
+}
+
 fn parse_fix(s: &str) -> proc_macro2::TokenStream {
     const SEP: char = '_';
 
@@ -47,6 +47,16 @@ mod declare_oxc_lint;
 /// - `dangerous_fix_dangerous_suggestion` (provides dangerous fixes and suggestions in all cases)
 ///
 /// `pending` and `none` are special cases that do not follow this pattern.
+///
+/// ## Requirements
+///
+/// Some analyses are only built when an enabled rule needs them. Lints that
+/// use them **must** declare them with `requires = cfg` or
+/// `requires = [jsdoc, regex]`:
+/// - `cfg`: the control flow graph, `ctx.cfg()`
+/// - `jsdoc`: JSDoc comments, `ctx.jsdoc()`
+/// - `regex`: the parsed patterns of regular expression literals
+///
 /// # Example
 ///
 /// ```
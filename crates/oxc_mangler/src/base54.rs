@@ -1,4 +1,6 @@
+use oxc_ast::ast::Program;
 use oxc_data_structures::inline_string::InlineString;
+use oxc_semantic::Scoping;
 
 #[repr(C, align(64))]
 struct Aligned64([u8; 64]);
@@ -45,7 +47,6 @@ pub fn base54(n: u32) -> InlineString<7, u8> {
 
     // Base 54 at first because these are the usable first characters in JavaScript identifiers
     // <https://tc39.es/ecma262/#prod-IdentifierStart>
-    const FIRST_BASE: usize = 54;
     let byte = BASE54_CHARS.0[num % FIRST_BASE];
     // SAFETY: All `BASE54_CHARS` are ASCII. This is first byte we push, so can't be out of bounds.
     unsafe { str.push_unchecked(byte) };
@@ -66,9 +67,120 @@ pub fn base54(n: u32) -> InlineString<7, u8> {
     str
 }
 
+/// Number of usable first characters of identifiers in [BASE54_CHARS]. The rest are digits.
+const FIRST_BASE: usize = 54;
+
+/// Characters used by mangled names, most preferred first.
+///
+/// Names are generated like [base54], with this alphabet instead of [BASE54_CHARS].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MangleAlphabet {
+    /// Characters which can start a name, followed by digits.
+    chars: Vec<u8>,
+    /// Number of characters which can start a name.
+    first_len: usize,
+}
+
+impl Default for MangleAlphabet {
+    fn default() -> Self {
+        Self { chars: BASE54_CHARS.0.to_vec(), first_len: FIRST_BASE }
+    }
+}
+
+impl MangleAlphabet {
+    /// Creates an alphabet from the characters of `alphabet`, most preferred first.
+    ///
+    /// Digits are only used after the first character of a name.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `alphabet` contains characters other than ASCII letters, digits, `$` and `_`,
+    /// contains a character twice, has less than two characters, or only has digits.
+    pub fn new(alphabet: &str) -> Result<Self, String> {
+        let mut chars = vec![];
+        for c in alphabet.bytes() {
+            if !(c.is_ascii_alphanumeric() || c == b'$' || c == b'_') {
+                return Err(format!("Invalid character in mangle alphabet: {alphabet:?}"));
+            }
+            if chars.contains(&c) {
+                return Err(format!("Duplicate character in mangle alphabet: {alphabet:?}"));
+            }
+            chars.push(c);
+        }
+        // Digits last, since they can't start a name.
+        chars.sort_by_key(u8::is_ascii_digit);
+        let first_len = chars.iter().filter(|c| !c.is_ascii_digit()).count();
+        if chars.len() < 2 || first_len == 0 {
+            return Err(format!(
+                "Mangle alphabet needs at least two characters, one of which is not a digit: {alphabet:?}"
+            ));
+        }
+        Ok(Self { chars, first_len })
+    }
+
+    /// Orders [BASE54_CHARS] by the frequency of the characters in the output,
+    /// so that mangled names use the same characters as the rest of the code, which improves gzip compression.
+    ///
+    /// Like esbuild, the frequencies are estimated from the source text, without comments and the names of
+    /// the symbols which are going to be mangled. Symbols in the root scope are only counted if `top_level` is set.
+    ///
+    /// <https://github.com/evanw/esbuild/blob/v0.25.5/internal/js_parser/js_parser.go#L17365>
+    pub fn from_char_frequency(program: &Program<'_>, scoping: &Scoping, top_level: bool) -> Self {
+        let mut frequencies = [0i64; 128];
+        let mut count = |text: &str, delta: i64| {
+            for c in text.bytes().filter(u8::is_ascii) {
+                frequencies[c as usize] += delta;
+            }
+        };
+        count(program.source_text, 1);
+        for comment in &program.comments {
+            count(comment.span.source_text(program.source_text), -1);
+        }
+        let root_scope_id = scoping.root_scope_id();
+        for symbol_id in scoping.symbol_ids() {
+            if !top_level && scoping.symbol_scope_id(symbol_id) == root_scope_id {
+                continue;
+            }
+            let references = scoping.get_resolved_reference_ids(symbol_id).len();
+            let occurrences = i64::try_from(references).unwrap_or(i64::MAX - 1) + 1;
+            count(scoping.symbol_name(symbol_id), -occurrences);
+        }
+
+        let mut alphabet = Self::default();
+        // Stable sorts, so characters with the same frequency stay in the default order.
+        let (first, rest) = alphabet.chars.split_at_mut(FIRST_BASE);
+        first.sort_by_key(|&c| std::cmp::Reverse(frequencies[c as usize]));
+        rest.sort_by_key(|&c| std::cmp::Reverse(frequencies[c as usize]));
+        alphabet
+    }
+
+    /// Get the shortest mangled name for a given n.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the name is longer than `CAPACITY`.
+    /// Names are up to 6 characters long with a 64 character alphabet, and up to 33 characters with 2 characters.
+    pub fn name<const CAPACITY: usize>(&self, n: u32) -> InlineString<CAPACITY, u8> {
+        let mut str = InlineString::new();
+        let mut num = n as usize;
+
+        str.push(self.chars[num % self.first_len]);
+        num /= self.first_len;
+
+        let rest_base = self.chars.len();
+        while num > 0 {
+            num -= 1;
+            str.push(self.chars[num % rest_base]);
+            num /= rest_base;
+        }
+
+        str
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use super::base54;
+    use super::{MangleAlphabet, base54};
 
     #[test]
     fn test_base54() {
@@ -79,4 +191,25 @@ mod test {
         assert_eq!(&*base54(55), "te");
         assert_eq!(&*base54(u32::MAX), "xKrTKr");
     }
+
+    #[test]
+    fn test_alphabet() {
+        let alphabet = MangleAlphabet::default();
+        for n in [0, 52, 53, 54, 55, 1000, u32::MAX] {
+            assert_eq!(alphabet.name::<7>(n).as_str(), base54(n).as_str());
+        }
+
+        let alphabet = MangleAlphabet::new("1ab").unwrap();
+        assert_eq!(alphabet.name::<7>(0).as_str(), "a");
+        assert_eq!(alphabet.name::<7>(1).as_str(), "b");
+        assert_eq!(alphabet.name::<7>(2).as_str(), "aa");
+        assert_eq!(alphabet.name::<7>(4).as_str(), "ab");
+        assert_eq!(alphabet.name::<7>(6).as_str(), "a1");
+        assert_eq!(alphabet.name::<39>(u32::MAX).len(), 21);
+
+        assert!(MangleAlphabet::new("ab-").is_err());
+        assert!(MangleAlphabet::new("aba").is_err());
+        assert!(MangleAlphabet::new("a").is_err());
+        assert!(MangleAlphabet::new("12").is_err());
+    }
 }
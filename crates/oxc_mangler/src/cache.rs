@@ -0,0 +1,103 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use oxc_allocator::Allocator;
+use oxc_ast::{AstBuilder, ast::*};
+use oxc_ast_visit::{Visit, VisitMut};
+use oxc_semantic::Scoping;
+
+use crate::{PropertyNameCache, properties::PropertyCollector};
+
+/// Mangling state shared by the chunks of a bundle.
+///
+/// Mangling each chunk with the same cache gives the same property names and top level names
+/// the same mangled names in every chunk, so that objects and globals can be shared between chunks.
+///
+/// ```ignore
+/// let mut cache = MangleCache::default();
+/// // Count the property names of all chunks first,
+/// // so that mangled names don't depend on the order in which chunks are mangled.
+/// for program in &chunks {
+///     cache.collect_frequencies(program);
+/// }
+/// for program in &mut chunks {
+///     PropertyMangler::new(properties.clone()).build_with_cache(&allocator, program, &mut cache);
+/// }
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct MangleCache {
+    /// Mangled property names, keyed by the original property name.
+    pub properties: PropertyNameCache,
+
+    /// Mangled names of top level symbols, keyed by the original name.
+    ///
+    /// Only used by [crate::Mangler] when [crate::MangleOptions::top_level] is set.
+    /// Globals referenced by a chunk are cached with their own names,
+    /// so that top level symbols of other chunks with the same names are not renamed.
+    pub top_level: BTreeMap<String, String>,
+
+    /// Number of occurrences of each property name in all chunks.
+    pub property_frequencies: BTreeMap<String, usize>,
+
+    /// Property names which are quoted in any chunk.
+    pub quoted_properties: BTreeSet<String>,
+
+    /// Property names annotated with `/* @__KEEP__ */` in any chunk.
+    pub kept_properties: BTreeSet<String>,
+
+    /// Property names annotated with `/* @__MANGLE_PROP__ */` in any chunk.
+    pub mangled_properties: BTreeSet<String>,
+}
+
+impl MangleCache {
+    /// Counts the property names of `program`.
+    ///
+    /// Call this for every chunk before mangling any of them.
+    /// The most frequent property names of the whole bundle then get the shortest names,
+    /// and mangled names never collide with property names of other chunks.
+    pub fn collect_frequencies(&mut self, program: &Program<'_>) {
+        let mut collector = PropertyCollector::new(program);
+        collector.visit_program(program);
+        for (name, frequency) in collector.frequencies {
+            *self.property_frequencies.entry(name.to_string()).or_default() += frequency;
+        }
+        self.quoted_properties.extend(collector.quoted_names.iter().map(ToString::to_string));
+        self.kept_properties.extend(collector.kept_names.iter().map(ToString::to_string));
+        self.mangled_properties.extend(collector.mangled_names.iter().map(ToString::to_string));
+    }
+
+    /// Renames references to top level symbols of other chunks.
+    ///
+    /// Scripts share the global scope, so a top level symbol of one chunk is referenced by
+    /// unresolved references of other chunks. Call this with the `scoping` of `program` after
+    /// mangling it, so that those references use the names mangled by the other chunks.
+    pub fn rename_global_references<'a>(
+        &self,
+        allocator: &'a Allocator,
+        scoping: &Scoping,
+        program: &mut Program<'a>,
+    ) {
+        if self.top_level.is_empty() {
+            return;
+        }
+        let mut renamer =
+            GlobalReferenceRenamer { ast: AstBuilder::new(allocator), scoping, cache: self };
+        renamer.visit_program(program);
+    }
+}
+
+struct GlobalReferenceRenamer<'a, 'c> {
+    ast: AstBuilder<'a>,
+    scoping: &'c Scoping,
+    cache: &'c MangleCache,
+}
+
+impl<'a> VisitMut<'a> for GlobalReferenceRenamer<'a, '_> {
+    fn visit_identifier_reference(&mut self, it: &mut IdentifierReference<'a>) {
+        if self.scoping.get_reference(it.reference_id()).symbol_id().is_some() {
+            return;
+        }
+        if let Some(mangled) = self.cache.top_level.get(it.name.as_str()) {
+            it.name = self.ast.atom(mangled);
+        }
+    }
+}
@@ -1,9 +1,10 @@
 use itertools::Itertools;
+use lazy_regex::Regex;
 use oxc_ast::{AstKind, ast::*};
 use oxc_semantic::{AstNode, AstNodes, ReferenceId, Scoping, SymbolId};
 use rustc_hash::FxHashSet;
 
-#[derive(Debug, Clone, Copy, Default)]
+#[derive(Debug, Clone, Default)]
 pub struct MangleOptionsKeepNames {
     /// Preserve `name` property for functions.
     ///
@@ -14,15 +15,39 @@ pub struct MangleOptionsKeepNames {
     ///
     /// Default `false`
     pub class: bool,
+
+    /// Only preserve `name` property for functions whose name matches the regex.
+    ///
+    /// Has no effect unless `function` is `true`.
+    ///
+    /// Default `None`
+    pub function_regex: Option<Regex>,
+
+    /// Only preserve `name` property for classes whose name matches the regex.
+    ///
+    /// Has no effect unless `class` is `true`.
+    ///
+    /// Default `None`
+    pub class_regex: Option<Regex>,
 }
 
 impl MangleOptionsKeepNames {
     pub fn all_false() -> Self {
-        Self { function: false, class: false }
+        Self { function: false, class: false, function_regex: None, class_regex: None }
     }
 
     pub fn all_true() -> Self {
-        Self { function: true, class: true }
+        Self { function: true, class: true, function_regex: None, class_regex: None }
+    }
+
+    /// Whether the `name` property of a function named `name` is preserved.
+    pub fn keeps_function(&self, name: &str) -> bool {
+        self.function && self.function_regex.as_ref().is_none_or(|regex| regex.is_match(name))
+    }
+
+    /// Whether the `name` property of a class named `name` is preserved.
+    pub fn keeps_class(&self, name: &str) -> bool {
+        self.class && self.class_regex.as_ref().is_none_or(|regex| regex.is_match(name))
     }
 }
 
@@ -33,7 +58,7 @@ impl From<bool> for MangleOptionsKeepNames {
 }
 
 pub fn collect_name_symbols(
-    options: MangleOptionsKeepNames,
+    options: &MangleOptionsKeepNames,
     scoping: &Scoping,
     ast_nodes: &AstNodes,
 ) -> FxHashSet<SymbolId> {
@@ -43,14 +68,14 @@ pub fn collect_name_symbols(
 
 /// Collects symbols that are used to set `name` properties of functions and classes.
 struct NameSymbolCollector<'a, 'b> {
-    options: MangleOptionsKeepNames,
+    options: &'b MangleOptionsKeepNames,
     scoping: &'b Scoping,
     ast_nodes: &'b AstNodes<'a>,
 }
 
 impl<'a, 'b: 'a> NameSymbolCollector<'a, 'b> {
     fn new(
-        options: MangleOptionsKeepNames,
+        options: &'b MangleOptionsKeepNames,
         scoping: &'b Scoping,
         ast_nodes: &'b AstNodes<'a>,
     ) -> Self {
@@ -65,35 +90,37 @@ impl<'a, 'b: 'a> NameSymbolCollector<'a, 'b> {
         self.scoping
             .symbol_ids()
             .filter(|symbol_id| {
+                let name = self.scoping.symbol_name(*symbol_id);
                 let decl_node =
                     self.ast_nodes.get_node(self.scoping.symbol_declaration(*symbol_id));
-                self.is_name_set_declare_node(decl_node, *symbol_id)
-                    || self.has_name_set_reference_node(*symbol_id)
+                self.is_name_set_declare_node(decl_node, *symbol_id, name)
+                    || self.has_name_set_reference_node(*symbol_id, name)
             })
             .collect()
     }
 
-    fn has_name_set_reference_node(&self, symbol_id: SymbolId) -> bool {
+    fn has_name_set_reference_node(&self, symbol_id: SymbolId, name: &str) -> bool {
         self.scoping.get_resolved_reference_ids(symbol_id).into_iter().any(|reference_id| {
             let node = self.ast_nodes.get_node(self.scoping.get_reference(*reference_id).node_id());
-            self.is_name_set_reference_node(node, *reference_id)
+            self.is_name_set_reference_node(node, *reference_id, name)
         })
     }
 
-    fn is_name_set_declare_node(&self, node: &'a AstNode, symbol_id: SymbolId) -> bool {
+    fn is_name_set_declare_node(&self, node: &'a AstNode, symbol_id: SymbolId, name: &str) -> bool {
         match node.kind() {
             AstKind::Function(function) => {
-                self.options.function
+                self.options.keeps_function(name)
                     && function.id.as_ref().is_some_and(|id| id.symbol_id() == symbol_id)
             }
             AstKind::Class(cls) => {
-                self.options.class && cls.id.as_ref().is_some_and(|id| id.symbol_id() == symbol_id)
+                self.options.keeps_class(name)
+                    && cls.id.as_ref().is_some_and(|id| id.symbol_id() == symbol_id)
             }
             AstKind::VariableDeclarator(decl) => {
                 if let BindingPatternKind::BindingIdentifier(id) = &decl.id.kind {
                     if id.symbol_id() == symbol_id {
                         return decl.init.as_ref().is_some_and(|init| {
-                            self.is_expression_whose_name_needs_to_be_kept(init)
+                            self.is_expression_whose_name_needs_to_be_kept(init, name)
                         });
                     }
                 }
@@ -103,7 +130,8 @@ impl<'a, 'b: 'a> NameSymbolCollector<'a, 'b> {
                         symbol_id,
                     )
                 {
-                    return self.is_expression_whose_name_needs_to_be_kept(&assign_pattern.right);
+                    return self
+                        .is_expression_whose_name_needs_to_be_kept(&assign_pattern.right, name);
                 }
                 false
             }
@@ -111,7 +139,12 @@ impl<'a, 'b: 'a> NameSymbolCollector<'a, 'b> {
         }
     }
 
-    fn is_name_set_reference_node(&self, node: &AstNode, reference_id: ReferenceId) -> bool {
+    fn is_name_set_reference_node(
+        &self,
+        node: &AstNode,
+        reference_id: ReferenceId,
+        name: &str,
+    ) -> bool {
         let Some(parent_node) = self.ast_nodes.parent_node(node.id()) else { return false };
         match parent_node.kind() {
             AstKind::SimpleAssignmentTarget(_) => {
@@ -127,13 +160,15 @@ impl<'a, 'b: 'a> NameSymbolCollector<'a, 'b> {
                         Self::is_assignment_target_id_of_specific_reference(
                             &assign_expr.left,
                             reference_id,
-                        ) && self.is_expression_whose_name_needs_to_be_kept(&assign_expr.right)
+                        ) && self
+                            .is_expression_whose_name_needs_to_be_kept(&assign_expr.right, name)
                     }
                     AstKind::AssignmentTargetWithDefault(assign_target) => {
                         Self::is_assignment_target_id_of_specific_reference(
                             &assign_target.binding,
                             reference_id,
-                        ) && self.is_expression_whose_name_needs_to_be_kept(&assign_target.init)
+                        ) && self
+                            .is_expression_whose_name_needs_to_be_kept(&assign_target.init, name)
                     }
                     _ => false,
                 }
@@ -145,7 +180,7 @@ impl<'a, 'b: 'a> NameSymbolCollector<'a, 'b> {
                     {
                         if prop_id.binding.reference_id() == reference_id {
                             return prop_id.init.as_ref().is_some_and(|init| {
-                                self.is_expression_whose_name_needs_to_be_kept(init)
+                                self.is_expression_whose_name_needs_to_be_kept(init, name)
                             });
                         }
                     }
@@ -220,18 +255,17 @@ impl<'a, 'b: 'a> NameSymbolCollector<'a, 'b> {
         }
     }
 
-    fn is_expression_whose_name_needs_to_be_kept(&self, expr: &Expression) -> bool {
+    fn is_expression_whose_name_needs_to_be_kept(&self, expr: &Expression, name: &str) -> bool {
         let is_anonymous = expr.is_anonymous_function_definition();
         if !is_anonymous {
             return false;
         }
 
-        if self.options.class && self.options.function {
-            return true;
+        if matches!(expr, Expression::ClassExpression(_)) {
+            self.options.keeps_class(name)
+        } else {
+            self.options.keeps_function(name)
         }
-
-        let is_class = matches!(expr, Expression::ClassExpression(_));
-        (self.options.class && is_class) || (self.options.function && !is_class)
     }
 }
 
@@ -243,9 +277,11 @@ mod test {
     use oxc_span::SourceType;
     use rustc_hash::FxHashSet;
 
+    use lazy_regex::Regex;
+
     use super::{MangleOptionsKeepNames, collect_name_symbols};
 
-    fn collect(opts: MangleOptionsKeepNames, source_text: &str) -> FxHashSet<String> {
+    fn collect(opts: &MangleOptionsKeepNames, source_text: &str) -> FxHashSet<String> {
         let allocator = Allocator::default();
         let ret = Parser::new(&allocator, source_text, SourceType::mjs()).parse();
         assert!(!ret.panicked, "{source_text}");
@@ -265,60 +301,87 @@ mod test {
     }
 
     fn function_only() -> MangleOptionsKeepNames {
-        MangleOptionsKeepNames { function: true, class: false }
+        MangleOptionsKeepNames { function: true, ..MangleOptionsKeepNames::all_false() }
     }
 
     fn class_only() -> MangleOptionsKeepNames {
-        MangleOptionsKeepNames { function: false, class: true }
+        MangleOptionsKeepNames { class: true, ..MangleOptionsKeepNames::all_false() }
     }
 
     #[test]
     fn test_declarations() {
-        assert_eq!(collect(function_only(), "function foo() {}"), data("foo"));
-        assert_eq!(collect(class_only(), "class Foo {}"), data("Foo"));
+        assert_eq!(collect(&function_only(), "function foo() {}"), data("foo"));
+        assert_eq!(collect(&class_only(), "class Foo {}"), data("Foo"));
     }
 
     #[test]
     fn test_simple_declare_init() {
-        assert_eq!(collect(function_only(), "var foo = function() {}"), data("foo"));
-        assert_eq!(collect(function_only(), "var foo = () => {}"), data("foo"));
-        assert_eq!(collect(class_only(), "var Foo = class {}"), data("Foo"));
+        assert_eq!(collect(&function_only(), "var foo = function() {}"), data("foo"));
+        assert_eq!(collect(&function_only(), "var foo = () => {}"), data("foo"));
+        assert_eq!(collect(&class_only(), "var Foo = class {}"), data("Foo"));
     }
 
     #[test]
     fn test_simple_assign() {
-        assert_eq!(collect(function_only(), "var foo; foo = function() {}"), data("foo"));
-        assert_eq!(collect(function_only(), "var foo; foo = () => {}"), data("foo"));
-        assert_eq!(collect(class_only(), "var Foo; Foo = class {}"), data("Foo"));
+        assert_eq!(collect(&function_only(), "var foo; foo = function() {}"), data("foo"));
+        assert_eq!(collect(&function_only(), "var foo; foo = () => {}"), data("foo"));
+        assert_eq!(collect(&class_only(), "var Foo; Foo = class {}"), data("Foo"));
 
-        assert_eq!(collect(function_only(), "var foo; foo ||= function() {}"), data("foo"));
-        assert_eq!(collect(function_only(), "var foo = 1; foo &&= function() {}"), data("foo"));
-        assert_eq!(collect(function_only(), "var foo; foo ??= function() {}"), data("foo"));
+        assert_eq!(collect(&function_only(), "var foo; foo ||= function() {}"), data("foo"));
+        assert_eq!(collect(&function_only(), "var foo = 1; foo &&= function() {}"), data("foo"));
+        assert_eq!(collect(&function_only(), "var foo; foo ??= function() {}"), data("foo"));
     }
 
     #[test]
     fn test_default_declarations() {
-        assert_eq!(collect(function_only(), "var [foo = function() {}] = []"), data("foo"));
-        assert_eq!(collect(function_only(), "var [foo = () => {}] = []"), data("foo"));
-        assert_eq!(collect(class_only(), "var [Foo = class {}] = []"), data("Foo"));
-        assert_eq!(collect(function_only(), "var { foo = function() {} } = {}"), data("foo"));
+        assert_eq!(collect(&function_only(), "var [foo = function() {}] = []"), data("foo"));
+        assert_eq!(collect(&function_only(), "var [foo = () => {}] = []"), data("foo"));
+        assert_eq!(collect(&class_only(), "var [Foo = class {}] = []"), data("Foo"));
+        assert_eq!(collect(&function_only(), "var { foo = function() {} } = {}"), data("foo"));
     }
 
     #[test]
     fn test_default_assign() {
-        assert_eq!(collect(function_only(), "var foo; [foo = function() {}] = []"), data("foo"));
-        assert_eq!(collect(function_only(), "var foo; [foo = () => {}] = []"), data("foo"));
-        assert_eq!(collect(class_only(), "var Foo; [Foo = class {}] = []"), data("Foo"));
+        assert_eq!(collect(&function_only(), "var foo; [foo = function() {}] = []"), data("foo"));
+        assert_eq!(collect(&function_only(), "var foo; [foo = () => {}] = []"), data("foo"));
+        assert_eq!(collect(&class_only(), "var Foo; [Foo = class {}] = []"), data("Foo"));
         assert_eq!(
-            collect(function_only(), "var foo; ({ foo = function() {} } = {})"),
+            collect(&function_only(), "var foo; ({ foo = function() {} } = {})"),
             data("foo")
         );
     }
 
     #[test]
     fn test_for_in_declaration() {
-        assert_eq!(collect(function_only(), "for (var foo = function() {} in []) {}"), data("foo"));
-        assert_eq!(collect(function_only(), "for (var foo = () => {} in []) {}"), data("foo"));
-        assert_eq!(collect(class_only(), "for (var Foo = class {} in []) {}"), data("Foo"));
+        assert_eq!(
+            collect(&function_only(), "for (var foo = function() {} in []) {}"),
+            data("foo")
+        );
+        assert_eq!(collect(&function_only(), "for (var foo = () => {} in []) {}"), data("foo"));
+        assert_eq!(collect(&class_only(), "for (var Foo = class {} in []) {}"), data("Foo"));
+    }
+
+    #[test]
+    fn test_regex() {
+        let opts = MangleOptionsKeepNames {
+            function_regex: Some(Regex::new("^Use[A-Z]").unwrap()),
+            class_regex: Some(Regex::new("(Service|Provider)$").unwrap()),
+            ..MangleOptionsKeepNames::all_true()
+        };
+        let source_text = "
+            function UseFoo() {}
+            function bar() {}
+            var UseBaz = () => {};
+            class FooService {}
+            class Bar {}
+            var BazService = class {};
+            var UseQux = class {};
+        ";
+        assert_eq!(
+            collect(&opts, source_text),
+            FxHashSet::from_iter(
+                ["UseFoo", "UseBaz", "FooService", "BazService"].map(String::from)
+            )
+        );
     }
 }
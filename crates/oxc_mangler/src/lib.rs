@@ -5,20 +5,32 @@ use itertools::Itertools;
 use keep_names::collect_name_symbols;
 use rustc_hash::FxHashSet;
 
-use base54::base54;
-use oxc_allocator::{Allocator, Vec};
-use oxc_ast::ast::{Declaration, Program, Statement};
+use oxc_allocator::{Allocator, Vec as ArenaVec};
+use oxc_ast::{
+    AstKind,
+    ast::{Declaration, Program, Statement},
+};
 use oxc_data_structures::inline_string::InlineString;
 use oxc_index::Idx;
 use oxc_semantic::{AstNodes, Scoping, Semantic, SemanticBuilder, SymbolId};
-use oxc_span::Atom;
+use oxc_span::{Atom, GetSpan, Span};
 
 pub(crate) mod base54;
+mod cache;
 mod keep_names;
+mod private_members;
+mod properties;
 
+pub use base54::MangleAlphabet;
+pub use cache::MangleCache;
 pub use keep_names::MangleOptionsKeepNames;
+pub use private_members::PrivateMemberMangler;
+pub use properties::{ManglePropertiesOptions, PropertyMangler, PropertyNameCache};
 
-#[derive(Default, Debug, Clone, Copy)]
+/// Options for [Mangler].
+///
+/// This is not `Copy` since [MangleOptions::reserved] was added, clone it instead.
+#[derive(Default, Debug, Clone)]
 pub struct MangleOptions {
     /// Pass true to mangle names declared in the top level scope.
     ///
@@ -28,10 +40,47 @@ pub struct MangleOptions {
     /// Keep function / class names
     pub keep_names: MangleOptionsKeepNames,
 
+    /// Pass true to mangle names visible in scopes where a direct `eval` or `with` is used.
+    ///
+    /// This is unsafe, because the code passed to `eval` or the object passed to `with`
+    /// can refer to any variable by its original name.
+    ///
+    /// Default: `false`
+    pub eval: bool,
+
+    /// Names that are never mangled, and never used as mangled names.
+    ///
+    /// Default: `[]`
+    pub reserved: Vec<String>,
+
+    /// Mangle property names.
+    ///
+    /// Property names are mangled by [PropertyMangler], which modifies the AST.
+    /// [Mangler] itself only mangles variable names and ignores this option.
+    ///
+    /// Default: `None`
+    pub properties: Option<ManglePropertiesOptions>,
+
+    /// Pass true to keep the names of private class members (`#foo`).
+    ///
+    /// Private names are mangled by [PrivateMemberMangler], which modifies the AST.
+    /// [Mangler] itself ignores this option.
+    ///
+    /// Default: `false`
+    pub keep_private_names: bool,
+
+    /// Characters used by mangled names, most preferred first.
+    ///
+    /// If `None`, the characters are ordered by their frequency in the source text,
+    /// so that the output compresses better.
+    ///
+    /// Default: `None`
+    pub alphabet: Option<MangleAlphabet>,
+
     /// Use more readable mangled names
     /// (e.g. `slot_0`, `slot_1`, `slot_2`, ...) for debugging.
     ///
-    /// Uses base54 if false.
+    /// Uses [MangleOptions::alphabet] if false.
     pub debug: bool,
 }
 
@@ -75,7 +124,7 @@ impl TempAllocator<'_> {
 /// assert!(parsed.errors.is_empty());
 ///
 /// let mangled_symbols = Mangler::new()
-///     .with_options(MangleOptions { top_level: true, debug: true })
+///     .with_options(MangleOptions { top_level: true, debug: true, ..MangleOptions::default() })
 ///     .build(&parsed.program);
 ///
 /// let js = Codegen::new().with_symbol_table(mangled_symbols).build(&parsed.program);
@@ -269,10 +318,44 @@ impl<'t> Mangler<'t> {
     ///
     /// Panics if the child_ids does not exist in scope_tree.
     pub fn build_with_semantic(self, semantic: &mut Semantic<'_>, program: &Program<'_>) {
+        self.build_with_semantic_and_optional_cache(semantic, program, None);
+    }
+
+    /// Like [Mangler::build_with_semantic], with top level names shared with other chunks.
+    ///
+    /// When [MangleOptions::top_level] is set, top level symbols named in [MangleCache::top_level]
+    /// get their cached names, and the names mangled in this build are added to it.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the child_ids does not exist in scope_tree.
+    pub fn build_with_semantic_and_cache(
+        self,
+        semantic: &mut Semantic<'_>,
+        program: &Program<'_>,
+        cache: &mut MangleCache,
+    ) {
+        self.build_with_semantic_and_optional_cache(semantic, program, Some(cache));
+    }
+
+    fn build_with_semantic_and_optional_cache(
+        self,
+        semantic: &mut Semantic<'_>,
+        program: &Program<'_>,
+        cache: Option<&mut MangleCache>,
+    ) {
         if self.options.debug {
-            self.build_with_semantic_impl(semantic, program, debug_name);
+            self.build_with_semantic_impl(semantic, program, cache, debug_name);
+        } else if let Some(alphabet) = self.options.alphabet.clone() {
+            // Names are up to 33 characters long with the smallest alphabet.
+            self.build_with_semantic_impl(semantic, program, cache, |n| alphabet.name::<39>(n));
         } else {
-            self.build_with_semantic_impl(semantic, program, base54);
+            let alphabet = MangleAlphabet::from_char_frequency(
+                program,
+                semantic.scoping(),
+                self.options.top_level,
+            );
+            self.build_with_semantic_impl(semantic, program, cache, |n| alphabet.name::<7>(n));
         }
     }
 
@@ -280,35 +363,69 @@ impl<'t> Mangler<'t> {
         self,
         semantic: &mut Semantic<'_>,
         program: &Program<'_>,
+        cache: Option<&mut MangleCache>,
         generate_name: G,
     ) {
         let (scoping, ast_nodes) = semantic.scoping_mut_and_nodes();
 
         assert!(scoping.has_scope_child_ids(), "child_id needs to be generated");
 
-        // TODO: implement opt-out of direct-eval in a branch of scopes.
-        if scoping.root_scope_flags().contains_direct_eval() {
-            return;
-        }
-
         let (exported_names, exported_symbols) = if self.options.top_level {
             Mangler::collect_exported_symbols(program)
         } else {
             Default::default()
         };
-        let (keep_name_names, keep_name_symbols) =
-            Mangler::collect_keep_name_symbols(self.options.keep_names, scoping, ast_nodes);
+        let (mut keep_name_names, mut keep_name_symbols) =
+            Mangler::collect_keep_name_symbols(&self.options.keep_names, scoping, ast_nodes);
+        if !self.options.eval {
+            let eval_symbols = Mangler::collect_eval_symbols(scoping, ast_nodes);
+            keep_name_names.extend(eval_symbols.iter().map(|id| scoping.symbol_name(*id)));
+            keep_name_symbols.extend(eval_symbols);
+        }
+        if !self.options.reserved.is_empty() {
+            keep_name_names.extend(self.options.reserved.iter().map(String::as_str));
+            keep_name_symbols.extend(
+                scoping.symbol_ids().filter(|id| {
+                    self.options.reserved.iter().any(|n| n == scoping.symbol_name(*id))
+                }),
+            );
+        }
+
+        // Top level symbols with their original names, and the names cached for them.
+        let mut top_level_symbols = vec![];
+        let mut cached_names = FxHashSet::<String>::default();
+        let cache = cache.filter(|_| self.options.top_level);
+        if let Some(cache) = &cache {
+            for (name, &symbol_id) in scoping.get_bindings(scoping.root_scope_id()) {
+                if exported_symbols.contains(&symbol_id) || keep_name_symbols.contains(&symbol_id) {
+                    continue;
+                }
+                top_level_symbols.push((
+                    symbol_id,
+                    name.to_string(),
+                    cache.top_level.get(*name).cloned(),
+                ));
+            }
+            cached_names.extend(cache.top_level.values().cloned());
+            keep_name_symbols.extend(
+                top_level_symbols
+                    .iter()
+                    .filter(|(_, _, cached)| cached.is_some())
+                    .map(|(symbol_id, _, _)| *symbol_id),
+            );
+        }
 
         let temp_allocator = self.temp_allocator.as_ref();
 
         // All symbols with their assigned slots. Keyed by symbol id.
-        let mut slots = Vec::from_iter_in(iter::repeat_n(0, scoping.symbols_len()), temp_allocator);
+        let mut slots =
+            ArenaVec::from_iter_in(iter::repeat_n(0, scoping.symbols_len()), temp_allocator);
 
         // Stores the lived scope ids for each slot. Keyed by slot number.
-        let mut slot_liveness: std::vec::Vec<FixedBitSet> = vec![];
-        let mut tmp_bindings = Vec::with_capacity_in(100, temp_allocator);
+        let mut slot_liveness: Vec<FixedBitSet> = vec![];
+        let mut tmp_bindings = ArenaVec::with_capacity_in(100, temp_allocator);
 
-        let mut reusable_slots = Vec::new_in(temp_allocator);
+        let mut reusable_slots = ArenaVec::new_in(temp_allocator);
         // Walk down the scope tree and assign a slot number for each symbol.
         // It is possible to do this in a loop over the symbol list,
         // but walking down the scope tree seems to generate a better code.
@@ -388,7 +505,7 @@ impl<'t> Mangler<'t> {
         let root_unresolved_references = scoping.root_unresolved_references();
         let root_bindings = scoping.get_bindings(scoping.root_scope_id());
 
-        let mut reserved_names = Vec::with_capacity_in(total_number_of_slots, temp_allocator);
+        let mut reserved_names = ArenaVec::with_capacity_in(total_number_of_slots, temp_allocator);
 
         let mut count = 0;
         for _ in 0..total_number_of_slots {
@@ -404,6 +521,7 @@ impl<'t> Mangler<'t> {
                         && (!self.options.top_level || exported_names.contains(n)))
                         // TODO: only skip the names that are kept in the current scope
                         && !keep_name_names.contains(n)
+                        && !cached_names.contains(n)
                 {
                     break name;
                 }
@@ -426,8 +544,8 @@ impl<'t> Mangler<'t> {
         //    function fa() { .. } function ga() { .. }
 
         let mut freq_iter = frequencies.iter();
-        let mut symbols_renamed_in_this_batch = Vec::with_capacity_in(100, temp_allocator);
-        let mut slice_of_same_len_strings = Vec::with_capacity_in(100, temp_allocator);
+        let mut symbols_renamed_in_this_batch = ArenaVec::with_capacity_in(100, temp_allocator);
+        let mut slice_of_same_len_strings = ArenaVec::with_capacity_in(100, temp_allocator);
         // 2. "N number of vars are going to be assigned names of the same length"
         for (_, slice_of_same_len_strings_group) in
             &reserved_names.into_iter().chunk_by(InlineString::len)
@@ -460,6 +578,21 @@ impl<'t> Mangler<'t> {
                 }
             }
         }
+
+        if let Some(cache) = cache {
+            for (symbol_id, name, cached) in top_level_symbols {
+                if let Some(cached) = cached {
+                    scoping.set_symbol_name(symbol_id, &cached);
+                } else {
+                    cache.top_level.insert(name, scoping.symbol_name(symbol_id).to_string());
+                }
+            }
+            // Globals referenced by this chunk may be top level symbols of chunks mangled later,
+            // which must keep their names.
+            for name in scoping.root_unresolved_references().keys() {
+                cache.top_level.entry(name.to_string()).or_insert_with(|| name.to_string());
+            }
+        }
     }
 
     fn tally_slot_frequencies<'a>(
@@ -469,10 +602,10 @@ impl<'t> Mangler<'t> {
         keep_name_symbols: &FxHashSet<SymbolId>,
         total_number_of_slots: usize,
         slots: &[Slot],
-    ) -> Vec<'a, SlotFrequency<'a>> {
+    ) -> ArenaVec<'a, SlotFrequency<'a>> {
         let root_scope_id = scoping.root_scope_id();
         let temp_allocator = self.temp_allocator.as_ref();
-        let mut frequencies = Vec::from_iter_in(
+        let mut frequencies = ArenaVec::from_iter_in(
             repeat_with(|| SlotFrequency::new(temp_allocator)).take(total_number_of_slots),
             temp_allocator,
         );
@@ -525,13 +658,52 @@ impl<'t> Mangler<'t> {
     }
 
     fn collect_keep_name_symbols<'a>(
-        keep_names: MangleOptionsKeepNames,
+        keep_names: &MangleOptionsKeepNames,
         scoping: &'a Scoping,
         nodes: &AstNodes,
     ) -> (FxHashSet<&'a str>, FxHashSet<SymbolId>) {
         let ids = collect_name_symbols(keep_names, scoping, nodes);
         (ids.iter().map(|id| scoping.symbol_name(*id)).collect(), ids)
     }
+
+    /// Collects symbols that must keep their original names because they can be referenced by name
+    /// from a direct `eval`, or looked up on the object of a `with` statement.
+    fn collect_eval_symbols(scoping: &Scoping, nodes: &AstNodes) -> FxHashSet<SymbolId> {
+        let mut symbols = FxHashSet::default();
+
+        // `ScopeFlags::DirectEval` is propagated to all ancestor scopes,
+        // so it is set on every scope whose bindings are visible to a direct `eval`.
+        // Bindings in the other scopes can still be mangled.
+        if scoping.root_scope_flags().contains_direct_eval() {
+            for (scope_id, bindings) in scoping.iter_bindings() {
+                if scoping.scope_flags(scope_id).contains_direct_eval() {
+                    symbols.extend(bindings.values().copied());
+                }
+            }
+        }
+
+        // `with (obj) { x }`: `x` may resolve to `obj.x`, so symbols declared or referenced
+        // inside the body of a `with` statement must keep their names.
+        let with_bodies = nodes
+            .iter()
+            .filter_map(|node| match node.kind() {
+                AstKind::WithStatement(stmt) => Some(stmt.body.span()),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        if !with_bodies.is_empty() {
+            let is_in_with_body =
+                |span: Span| with_bodies.iter().any(|body| body.contains_inclusive(span));
+            symbols.extend(scoping.symbol_ids().filter(|&symbol_id| {
+                is_in_with_body(scoping.symbol_span(symbol_id))
+                    || scoping.get_resolved_references(symbol_id).any(|reference| {
+                        is_in_with_body(nodes.get_node(reference.node_id()).kind().span())
+                    })
+            }));
+        }
+
+        symbols
+    }
 }
 
 fn is_special_name(name: &str) -> bool {
@@ -542,12 +714,12 @@ fn is_special_name(name: &str) -> bool {
 struct SlotFrequency<'a> {
     pub slot: Slot,
     pub frequency: usize,
-    pub symbol_ids: Vec<'a, SymbolId>,
+    pub symbol_ids: ArenaVec<'a, SymbolId>,
 }
 
 impl<'t> SlotFrequency<'t> {
     fn new(temp_allocator: &'t Allocator) -> Self {
-        Self { slot: 0, frequency: 0, symbol_ids: Vec::new_in(temp_allocator) }
+        Self { slot: 0, frequency: 0, symbol_ids: ArenaVec::new_in(temp_allocator) }
     }
 }
 
@@ -0,0 +1,154 @@
+use rustc_hash::FxHashMap;
+
+use oxc_allocator::Allocator;
+use oxc_ast::{AstBuilder, ast::*};
+use oxc_ast_visit::{Visit, VisitMut, walk, walk_mut};
+use oxc_span::Atom;
+
+use crate::{MangleOptions, base54::base54};
+
+/// # Private Member Mangler
+///
+/// Renames private class members (`#foo`), i.e. private fields, methods and accessors.
+///
+/// Private names are only visible inside the body of the class declaring them,
+/// so they can be renamed without affecting code outside of the class.
+/// Names are assigned per class, with the most frequent names getting the shortest names.
+/// Classes nested in a class body get names distinct from the enclosing classes' names,
+/// since they can access the private members of the enclosing classes.
+///
+/// Unlike [crate::Mangler], the program is modified in place.
+///
+/// ```javascript
+/// class Foo {
+///   #count = 0;
+///   #increment() { this.#count++; }
+/// }
+/// ```
+///
+/// is mangled to:
+///
+/// ```javascript
+/// class Foo {
+///   #e = 0;
+///   #t() { this.#e++; }
+/// }
+/// ```
+pub struct PrivateMemberMangler {
+    eval: bool,
+    debug: bool,
+}
+
+impl PrivateMemberMangler {
+    /// Uses the `eval` and `debug` options.
+    pub fn new(options: &MangleOptions) -> Self {
+        Self { eval: options.eval, debug: options.debug }
+    }
+
+    /// Mangles private member names in `program`.
+    pub fn build<'a>(self, allocator: &'a Allocator, program: &mut Program<'a>) {
+        let mut renamer = PrivateMemberRenamer {
+            ast: AstBuilder::new(allocator),
+            options: self,
+            scopes: vec![],
+            count: 0,
+            kept: vec![],
+        };
+        renamer.visit_program(program);
+    }
+}
+
+struct PrivateMemberRenamer<'a> {
+    ast: AstBuilder<'a>,
+    options: PrivateMemberMangler,
+    /// Renames of the private names declared by each enclosing class body, innermost last.
+    scopes: Vec<FxHashMap<Atom<'a>, Atom<'a>>>,
+    /// Number of names used by the enclosing class bodies.
+    count: u32,
+    /// Names of the enclosing class bodies which are not renamed.
+    kept: Vec<Atom<'a>>,
+}
+
+impl<'a> PrivateMemberRenamer<'a> {
+    fn renames(&mut self, body: &ClassBody<'a>) -> FxHashMap<Atom<'a>, Atom<'a>> {
+        let mut collector = PrivateNameCollector::default();
+        for element in &body.body {
+            if let Some(name) = element.property_key().and_then(PropertyKey::private_name) {
+                collector.frequencies.entry(name).or_insert_with(|| {
+                    collector.names.push(name);
+                    0
+                });
+            }
+        }
+        collector.visit_class_body(body);
+        // Direct `eval` in a class body can access the private names.
+        // Nested classes must not use the names.
+        if collector.has_direct_eval && !self.options.eval {
+            self.kept.extend(collector.names);
+            return FxHashMap::default();
+        }
+
+        let mut names = collector.names;
+        names.sort_by_key(|name| std::cmp::Reverse(collector.frequencies[name]));
+        names
+            .into_iter()
+            .map(|name| {
+                let mangled = if self.options.debug {
+                    self.ast.atom(&format!("_${name}$_"))
+                } else {
+                    loop {
+                        let mangled = base54(self.count);
+                        self.count += 1;
+                        if !self.kept.iter().any(|kept| kept.as_str() == mangled.as_str()) {
+                            break self.ast.atom(mangled.as_str());
+                        }
+                    }
+                };
+                (name, mangled)
+            })
+            .collect()
+    }
+}
+
+impl<'a> VisitMut<'a> for PrivateMemberRenamer<'a> {
+    fn visit_class_body(&mut self, it: &mut ClassBody<'a>) {
+        let count = self.count;
+        let kept = self.kept.len();
+        let renames = self.renames(it);
+        self.scopes.push(renames);
+        walk_mut::walk_class_body(self, it);
+        self.scopes.pop();
+        self.count = count;
+        self.kept.truncate(kept);
+    }
+
+    fn visit_private_identifier(&mut self, it: &mut PrivateIdentifier<'a>) {
+        if let Some(&mangled) = self.scopes.iter().rev().find_map(|scope| scope.get(&it.name)) {
+            it.name = mangled;
+        }
+    }
+}
+
+/// Collects the frequencies of the private names declared by a class body.
+#[derive(Default)]
+struct PrivateNameCollector<'a> {
+    /// Declared names, in order of declaration.
+    names: Vec<Atom<'a>>,
+    frequencies: FxHashMap<Atom<'a>, usize>,
+    has_direct_eval: bool,
+}
+
+impl<'a> Visit<'a> for PrivateNameCollector<'a> {
+    fn visit_private_identifier(&mut self, it: &PrivateIdentifier<'a>) {
+        if let Some(frequency) = self.frequencies.get_mut(&it.name) {
+            *frequency += 1;
+        }
+    }
+
+    fn visit_call_expression(&mut self, it: &CallExpression<'a>) {
+        if it.callee.is_specific_id("eval") {
+            self.has_direct_eval = true;
+        }
+        walk::walk_call_expression(self, it);
+    }
+}
@@ -0,0 +1,499 @@
+use std::collections::BTreeMap;
+
+use lazy_regex::Regex;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use oxc_allocator::{Allocator, TakeIn};
+use oxc_ast::{AstBuilder, ast::*};
+use oxc_ast_visit::{Visit, VisitMut, walk, walk_mut};
+use oxc_span::{Atom, Span};
+
+use crate::{MangleCache, base54::base54};
+
+/// Mangled property names, keyed by the original property name.
+///
+/// Pass the cache returned by [PropertyMangler::build] to the next build,
+/// so that separately built bundles agree on the mangled names.
+pub type PropertyNameCache = BTreeMap<String, String>;
+
+#[derive(Debug, Default, Clone)]
+pub struct ManglePropertiesOptions {
+    /// Only mangle property names matching the regex.
+    ///
+    /// Without a regex, all property names except the reserved ones are mangled,
+    /// which is only safe when the code does not access properties of objects
+    /// created outside of it (e.g. builtins or DOM objects).
+    ///
+    /// Default: `None`
+    pub regex: Option<Regex>,
+
+    /// Property names that are never mangled.
+    ///
+    /// Default: `[]`
+    pub reserved: Vec<String>,
+
+    /// Do not mangle quoted property names (`{ "foo": 0 }`, `obj["foo"]`).
+    ///
+    /// A property name that is quoted anywhere is not mangled anywhere.
+    /// If false, quoted property names are mangled too.
+    ///
+    /// Default: `false`
+    pub keep_quoted: bool,
+
+    /// Use readable mangled names (e.g. `_$foo$_`) for debugging.
+    ///
+    /// Default: `false`
+    pub debug: bool,
+
+    /// Mangled names from a previous build.
+    ///
+    /// Default: empty
+    pub name_cache: PropertyNameCache,
+}
+
+/// # Property Mangler
+///
+/// Renames property names in member expressions, object literals, classes and destructuring patterns.
+///
+/// Unlike [crate::Mangler], the program is modified in place.
+///
+/// ```javascript
+/// obj._foo = { _bar: 1 };
+/// const { _bar } = obj._foo;
+/// ```
+///
+/// With `regex: /^_/`:
+///
+/// ```javascript
+/// obj.a = { b: 1 };
+/// const { b: _bar } = obj.a;
+/// ```
+///
+/// ## Annotations
+///
+/// Comments before a property name override the options for that name.
+/// The decision applies to every occurrence of the name, because a property renamed
+/// at some sites only would no longer be found at the others.
+///
+/// * `/* @__KEEP__ */` keeps the name: `obj./* @__KEEP__ */ foo`.
+/// * `/* @__MANGLE_PROP__ */` mangles the name even if it does not match [ManglePropertiesOptions::regex]
+///   or is quoted with [ManglePropertiesOptions::keep_quoted] set: `{ /* @__MANGLE_PROP__ */ "foo": 1 }`.
+///   Builtin and reserved names are still kept.
+/// * `/* @__KEY__ */` marks a string literal as a property name, so that it is mangled like one:
+///   `Object.defineProperty(obj, /* @__KEY__ */ "_foo", desc)`. The string is not considered quoted.
+///
+/// `@__KEEP__` wins over `@__MANGLE_PROP__` when a name has both.
+pub struct PropertyMangler {
+    options: ManglePropertiesOptions,
+}
+
+impl PropertyMangler {
+    pub fn new(options: ManglePropertiesOptions) -> Self {
+        Self { options }
+    }
+
+    /// Mangles property names in `program`.
+    ///
+    /// Returns the name cache updated with the names mangled in this build.
+    pub fn build<'a>(
+        self,
+        allocator: &'a Allocator,
+        program: &mut Program<'a>,
+    ) -> PropertyNameCache {
+        let mut cache =
+            MangleCache { properties: self.options.name_cache.clone(), ..MangleCache::default() };
+        self.build_with_cache(allocator, program, &mut cache);
+        cache.properties
+    }
+
+    /// Mangles property names in `program`, with mangled names shared with other chunks.
+    ///
+    /// Names of [MangleCache::properties] are reused, and the names mangled in this build are added to it.
+    /// Property names counted by [MangleCache::collect_frequencies] are mangled as if they were in `program`.
+    pub fn build_with_cache<'a>(
+        self,
+        allocator: &'a Allocator,
+        program: &mut Program<'a>,
+        cache: &mut MangleCache,
+    ) {
+        let mut collector = PropertyCollector::new(program);
+        collector.visit_program(program);
+
+        for (name, mangled) in &self.options.name_cache {
+            cache.properties.entry(name.clone()).or_insert_with(|| mangled.clone());
+        }
+
+        // Property names of this chunk, followed by the names of other chunks.
+        let names = collector
+            .names
+            .iter()
+            .map(Atom::as_str)
+            .chain(
+                cache
+                    .property_frequencies
+                    .keys()
+                    .map(String::as_str)
+                    .filter(|name| !collector.frequencies.contains_key(*name)),
+            )
+            .collect::<Vec<_>>();
+        let is_quoted = |name: &str| {
+            collector.quoted_names.contains(name) || cache.quoted_properties.contains(name)
+        };
+        let annotation = |name: &str| {
+            if collector.kept_names.contains(name) || cache.kept_properties.contains(name) {
+                Some(PropertyAnnotation::Keep)
+            } else if collector.mangled_names.contains(name)
+                || cache.mangled_properties.contains(name)
+            {
+                Some(PropertyAnnotation::Mangle)
+            } else {
+                None
+            }
+        };
+
+        // Names that must not be used as mangled names:
+        // property names which are kept, and names already used by the cache.
+        let mut used_names = names
+            .iter()
+            .copied()
+            .filter(|name| !self.should_mangle(name, is_quoted(name), annotation(name)))
+            .chain(self.options.reserved.iter().map(String::as_str))
+            .chain(cache.properties.values().map(String::as_str))
+            .map(str::to_string)
+            .collect::<FxHashSet<_>>();
+
+        // The most frequent names get the shortest mangled names.
+        let mut names = names
+            .into_iter()
+            .filter(|name| self.should_mangle(name, is_quoted(name), annotation(name)))
+            .map(|name| {
+                let frequency =
+                    cache.property_frequencies.get(name).copied().unwrap_or_else(|| {
+                        collector.frequencies.get(name).copied().unwrap_or_default()
+                    });
+                (name.to_string(), frequency)
+            })
+            .collect::<Vec<_>>();
+        names.sort_by_key(|(_, frequency)| std::cmp::Reverse(*frequency));
+
+        let ast = AstBuilder::new(allocator);
+        let mut renames = FxHashMap::default();
+        let mut count = 0;
+        for (name, _) in names {
+            let mangled = if let Some(mangled) = cache.properties.get(&name) {
+                mangled.clone()
+            } else {
+                let mangled = if self.options.debug {
+                    format!("_${name}$_")
+                } else {
+                    loop {
+                        let mangled = base54(count);
+                        count += 1;
+                        if !used_names.contains(mangled.as_str()) && !is_builtin(mangled.as_str()) {
+                            break mangled.as_str().to_string();
+                        }
+                    }
+                };
+                used_names.insert(mangled.clone());
+                cache.properties.insert(name.clone(), mangled.clone());
+                mangled
+            };
+            if collector.frequencies.contains_key(name.as_str()) {
+                renames.insert(ast.atom(&name), ast.atom(&mangled));
+            }
+        }
+
+        if !renames.is_empty() {
+            let mut renamer = PropertyRenamer { ast, renames, keys: collector.keys };
+            renamer.visit_program(program);
+        }
+    }
+
+    fn should_mangle(
+        &self,
+        name: &str,
+        quoted: bool,
+        annotation: Option<PropertyAnnotation>,
+    ) -> bool {
+        if is_builtin(name) || self.options.reserved.iter().any(|reserved| reserved == name) {
+            return false;
+        }
+        match annotation {
+            Some(PropertyAnnotation::Keep) => return false,
+            Some(PropertyAnnotation::Mangle) => return true,
+            Some(PropertyAnnotation::Key) | None => {}
+        }
+        if self.options.keep_quoted && quoted {
+            return false;
+        }
+        self.options.regex.as_ref().is_none_or(|regex| regex.is_match(name))
+    }
+}
+
+/// Property names that are accessed implicitly by the language,
+/// so renaming them changes behavior even if the code doesn't use any builtin objects.
+fn is_builtin(name: &str) -> bool {
+    matches!(
+        name,
+        "__proto__"
+            | "constructor"
+            | "prototype"
+            | "length"
+            | "name"
+            | "then"
+            | "toString"
+            | "valueOf"
+            | "toJSON"
+            | "next"
+            | "done"
+            | "value"
+            | "return"
+            | "throw"
+    )
+}
+
+/// A comment which overrides the mangling of the property name or string after it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PropertyAnnotation {
+    /// `/* @__KEY__ */`
+    Key,
+    /// `/* @__MANGLE_PROP__ */`
+    Mangle,
+    /// `/* @__KEEP__ */`
+    Keep,
+}
+
+impl PropertyAnnotation {
+    fn from_comment(comment: &Comment, source_text: &str) -> Option<Self> {
+        if !comment.is_block() || !comment.is_leading() {
+            return None;
+        }
+        let text = comment.content_span().source_text(source_text).trim_ascii();
+        let text = text.strip_prefix('@').or_else(|| text.strip_prefix('#'))?;
+        match text {
+            "__KEY__" => Some(Self::Key),
+            "__MANGLE_PROP__" => Some(Self::Mangle),
+            "__KEEP__" => Some(Self::Keep),
+            _ => None,
+        }
+    }
+}
+
+/// Collects property names with their frequencies, in order of appearance.
+pub struct PropertyCollector<'a> {
+    pub names: Vec<Atom<'a>>,
+    pub frequencies: FxHashMap<Atom<'a>, usize>,
+    pub quoted_names: FxHashSet<Atom<'a>>,
+    /// Names annotated with `@__KEEP__`.
+    pub kept_names: FxHashSet<Atom<'a>>,
+    /// Names annotated with `@__MANGLE_PROP__`.
+    pub mangled_names: FxHashSet<Atom<'a>>,
+    /// Start of the string literals annotated with `@__KEY__`.
+    keys: FxHashSet<u32>,
+    /// Annotations keyed by the start of the token they are attached to.
+    annotations: FxHashMap<u32, PropertyAnnotation>,
+}
+
+impl<'a> PropertyCollector<'a> {
+    pub fn new(program: &Program<'a>) -> Self {
+        let annotations = program
+            .comments
+            .iter()
+            .filter_map(|comment| {
+                PropertyAnnotation::from_comment(comment, program.source_text)
+                    .map(|annotation| (comment.attached_to, annotation))
+            })
+            .collect();
+        Self {
+            names: Vec::new(),
+            frequencies: FxHashMap::default(),
+            quoted_names: FxHashSet::default(),
+            kept_names: FxHashSet::default(),
+            mangled_names: FxHashSet::default(),
+            keys: FxHashSet::default(),
+            annotations,
+        }
+    }
+
+    fn add(&mut self, name: Atom<'a>, quoted: bool, span: Span) {
+        let frequency = self.frequencies.entry(name).or_insert_with(|| {
+            self.names.push(name);
+            0
+        });
+        *frequency += 1;
+        if quoted {
+            self.quoted_names.insert(name);
+        }
+        match self.annotations.get(&span.start) {
+            Some(PropertyAnnotation::Keep) => {
+                self.kept_names.insert(name);
+            }
+            Some(PropertyAnnotation::Mangle) => {
+                self.mangled_names.insert(name);
+            }
+            Some(PropertyAnnotation::Key) | None => {}
+        }
+    }
+
+    fn is_key(&self, lit: &StringLiteral<'a>) -> bool {
+        self.annotations.get(&lit.span.start) == Some(&PropertyAnnotation::Key)
+    }
+}
+
+impl<'a> Visit<'a> for PropertyCollector<'a> {
+    fn visit_static_member_expression(&mut self, it: &StaticMemberExpression<'a>) {
+        self.add(it.property.name, false, it.property.span);
+        walk::walk_static_member_expression(self, it);
+    }
+
+    fn visit_computed_member_expression(&mut self, it: &ComputedMemberExpression<'a>) {
+        if let Expression::StringLiteral(lit) = &it.expression {
+            // Keys are added by `visit_string_literal`.
+            if !self.is_key(lit) {
+                self.add(lit.value, true, lit.span);
+            }
+        }
+        walk::walk_computed_member_expression(self, it);
+    }
+
+    fn visit_property_key(&mut self, it: &PropertyKey<'a>) {
+        match it {
+            PropertyKey::StaticIdentifier(ident) => self.add(ident.name, false, ident.span),
+            PropertyKey::StringLiteral(lit) if self.is_key(lit) => self.visit_string_literal(lit),
+            PropertyKey::StringLiteral(lit) => self.add(lit.value, true, lit.span),
+            _ => walk::walk_property_key(self, it),
+        }
+    }
+
+    fn visit_string_literal(&mut self, it: &StringLiteral<'a>) {
+        // `/* @__KEY__ */ "foo"`
+        if self.is_key(it) {
+            self.keys.insert(it.span.start);
+            self.add(it.value, false, it.span);
+        }
+    }
+
+    fn visit_assignment_target_property_identifier(
+        &mut self,
+        it: &AssignmentTargetPropertyIdentifier<'a>,
+    ) {
+        self.add(it.binding.name, false, it.binding.span);
+        walk::walk_assignment_target_property_identifier(self, it);
+    }
+}
+
+struct PropertyRenamer<'a> {
+    ast: AstBuilder<'a>,
+    renames: FxHashMap<Atom<'a>, Atom<'a>>,
+    /// Start of the string literals annotated with `@__KEY__`.
+    keys: FxHashSet<u32>,
+}
+
+impl<'a> PropertyRenamer<'a> {
+    fn rename_string_literal(&self, lit: &mut StringLiteral<'a>) {
+        // Names kept by `keep_quoted` are not in `renames`.
+        // Keys are renamed by `visit_string_literal`.
+        if self.keys.contains(&lit.span.start) {
+            return;
+        }
+        if let Some(&mangled) = self.renames.get(&lit.value) {
+            lit.value = mangled;
+            lit.raw = None;
+        }
+    }
+}
+
+impl<'a> VisitMut<'a> for PropertyRenamer<'a> {
+    fn visit_static_member_expression(&mut self, it: &mut StaticMemberExpression<'a>) {
+        if let Some(&mangled) = self.renames.get(&it.property.name) {
+            it.property.name = mangled;
+        }
+        walk_mut::walk_static_member_expression(self, it);
+    }
+
+    fn visit_computed_member_expression(&mut self, it: &mut ComputedMemberExpression<'a>) {
+        if let Expression::StringLiteral(lit) = &mut it.expression {
+            self.rename_string_literal(lit);
+        }
+        walk_mut::walk_computed_member_expression(self, it);
+    }
+
+    fn visit_property_key(&mut self, it: &mut PropertyKey<'a>) {
+        match it {
+            PropertyKey::StaticIdentifier(ident) => {
+                if let Some(&mangled) = self.renames.get(&ident.name) {
+                    ident.name = mangled;
+                }
+            }
+            PropertyKey::StringLiteral(lit) if self.keys.contains(&lit.span.start) => {
+                self.visit_string_literal(lit);
+            }
+            PropertyKey::StringLiteral(lit) => self.rename_string_literal(lit),
+            _ => walk_mut::walk_property_key(self, it),
+        }
+    }
+
+    fn visit_string_literal(&mut self, it: &mut StringLiteral<'a>) {
+        // `/* @__KEY__ */ "foo"`
+        if self.keys.contains(&it.span.start) {
+            if let Some(&mangled) = self.renames.get(&it.value) {
+                it.value = mangled;
+                it.raw = None;
+            }
+        }
+    }
+
+    fn visit_object_property(&mut self, it: &mut ObjectProperty<'a>) {
+        // `{ foo }` -> `{ a: foo }`
+        walk_mut::walk_object_property(self, it);
+        if it.shorthand {
+            it.shorthand = matches!(
+                (&it.key, &it.value),
+                (PropertyKey::StaticIdentifier(key), Expression::Identifier(value))
+                    if key.name == value.name
+            );
+        }
+    }
+
+    fn visit_binding_property(&mut self, it: &mut BindingProperty<'a>) {
+        // `const { foo } = obj` -> `const { a: foo } = obj`
+        walk_mut::walk_binding_property(self, it);
+        if it.shorthand {
+            it.shorthand = match (&it.key, &it.value.kind) {
+                (
+                    PropertyKey::StaticIdentifier(key),
+                    BindingPatternKind::BindingIdentifier(value),
+                ) => key.name == value.name,
+                (
+                    PropertyKey::StaticIdentifier(key),
+                    BindingPatternKind::AssignmentPattern(pattern),
+                ) => pattern.left.get_identifier_name().is_some_and(|name| key.name == name),
+                _ => false,
+            };
+        }
+    }
+
+    fn visit_assignment_target_property(&mut self, it: &mut AssignmentTargetProperty<'a>) {
+        // `({ foo } = obj)` -> `({ a: foo } = obj)`
+        if let AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(ident) = it {
+            if let Some(&mangled) = self.renames.get(&ident.binding.name) {
+                let AssignmentTargetPropertyIdentifier { span, binding, init } =
+                    (**ident).take_in(self.ast.allocator);
+                let key = self.ast.property_key_static_identifier(binding.span, mangled);
+                let target = AssignmentTarget::AssignmentTargetIdentifier(self.ast.alloc(binding));
+                let binding = match init {
+                    Some(init) => {
+                        self.ast.assignment_target_maybe_default_assignment_target_with_default(
+                            span, target, init,
+                        )
+                    }
+                    None => AssignmentTargetMaybeDefault::from(target),
+                };
+                *it = self.ast.assignment_target_property_assignment_target_property_property(
+                    span, key, binding, false,
+                );
+            }
+        }
+        walk_mut::walk_assignment_target_property(self, it);
+    }
+}
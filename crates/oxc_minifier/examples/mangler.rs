@@ -26,10 +26,11 @@ fn main() -> std::io::Result<()> {
 
     let options = MangleOptions {
         top_level: source_type.is_module(),
-        keep_names: MangleOptionsKeepNames { function: keep_names, class: keep_names },
+        keep_names: MangleOptionsKeepNames::from(keep_names),
         debug,
+        ..MangleOptions::default()
     };
-    let printed = mangler(&source_text, source_type, options);
+    let printed = mangler(&source_text, source_type, options.clone());
     println!("{printed}");
 
     if twice {
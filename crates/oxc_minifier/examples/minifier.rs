@@ -19,6 +19,7 @@ fn main() -> std::io::Result<()> {
     let mangle = args.contains("--mangle");
     let nospace = args.contains("--nospace");
     let twice = args.contains("--twice");
+    let report = args.contains("--report");
     let name = args.free_from_str().unwrap_or_else(|_| "test.js".to_string());
 
     let path = Path::new(&name);
@@ -26,12 +27,12 @@ fn main() -> std::io::Result<()> {
     let source_type = SourceType::from_path(path).unwrap();
 
     let mut allocator = Allocator::default();
-    let printed = minify(&allocator, &source_text, source_type, mangle, nospace);
+    let printed = minify(&allocator, &source_text, source_type, mangle, nospace, report);
     println!("{printed}");
 
     if twice {
         allocator.reset();
-        let printed2 = minify(&allocator, &printed, source_type, mangle, nospace);
+        let printed2 = minify(&allocator, &printed, source_type, mangle, nospace, false);
         println!("{printed2}");
         println!("same = {}", printed == printed2);
     }
@@ -45,14 +46,19 @@ fn minify(
     source_type: SourceType,
     mangle: bool,
     nospace: bool,
+    report: bool,
 ) -> String {
     let ret = Parser::new(allocator, source_text, source_type).parse();
     let mut program = ret.program;
     let options = MinifierOptions {
         mangle: mangle.then(MangleOptions::default),
         compress: Some(CompressOptions::default()),
+        report,
     };
     let ret = Minifier::new(options).build(allocator, &mut program);
+    if let Some(report) = ret.report {
+        println!("{report}");
+    }
     Codegen::new()
         .with_options(CodegenOptions {
             minify: nospace,
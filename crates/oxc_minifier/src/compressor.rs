@@ -30,14 +30,27 @@ impl<'a> Compressor<'a> {
     }
 
     pub fn build_with_scoping(self, scoping: Scoping, program: &mut Program<'a>) {
+        self.build_with_scoping_and_inspect(scoping, program, |_, _| {});
+    }
+
+    /// Like [Compressor::build_with_scoping], calling `inspect` with the name of each pass after it has run.
+    pub(crate) fn build_with_scoping_and_inspect(
+        self,
+        scoping: Scoping,
+        program: &mut Program<'a>,
+        mut inspect: impl FnMut(&'static str, &Program<'a>),
+    ) {
         let state = MinifierState::new(Rc::clone(&self.options));
         let mut ctx = ReusableTraverseCtx::new(state, scoping, self.allocator);
         let normalize_options =
             NormalizeOptions { convert_while_to_fors: true, convert_const_to_let: true };
         Normalize::new(normalize_options).build(program, &mut ctx);
-        PeepholeOptimizations::new(self.options.target, self.options.keep_names)
+        inspect("normalize", program);
+        PeepholeOptimizations::new(self.options.target, self.options.keep_names.clone())
             .run_in_loop(program, &mut ctx);
+        inspect("peephole", program);
         LatePeepholeOptimizations::new(self.options.target).build(program, &mut ctx);
+        inspect("late_peephole", program);
     }
 
     pub fn dead_code_elimination(self, program: &mut Program<'a>) {
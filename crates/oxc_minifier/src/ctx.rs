@@ -82,16 +82,7 @@ impl<'a> oxc_ecmascript::side_effects::MayHaveSideEffectsContext<'a> for Ctx<'a,
     }
 
     fn manual_pure_functions(&self, callee: &Expression) -> bool {
-        if let Expression::Identifier(ident) = callee {
-            return self
-                .state
-                .options
-                .treeshake
-                .manual_pure_functions
-                .iter()
-                .any(|name| ident.name.as_str() == name);
-        }
-        false
+        self.state.options.treeshake.is_manual_pure_function(callee)
     }
 
     fn property_read_side_effects(&self) -> PropertyReadSideEffects {
@@ -7,33 +7,61 @@ mod ctx;
 mod keep_var;
 mod options;
 mod peephole;
+mod size_report;
+mod tree_shaking;
 
 #[cfg(test)]
 mod tester;
 
 use oxc_allocator::Allocator;
 use oxc_ast::ast::Program;
-use oxc_mangler::Mangler;
+use oxc_mangler::{Mangler, PrivateMemberMangler, PropertyMangler};
 use oxc_semantic::{Scoping, SemanticBuilder, Stats};
 
-pub use oxc_mangler::{MangleOptions, MangleOptionsKeepNames};
+pub use oxc_mangler::{
+    MangleAlphabet, MangleCache, MangleOptions, MangleOptionsKeepNames, ManglePropertiesOptions,
+    PropertyNameCache,
+};
 
-pub use crate::{compressor::Compressor, options::*};
+use crate::size_report::SizeReportBuilder;
+pub use crate::{
+    compressor::Compressor,
+    options::*,
+    size_report::{FunctionSize, PassSize, SizeReport, SymbolSize},
+    tree_shaking::{StatementLiveness, TreeShaker},
+};
 
 #[derive(Debug, Clone)]
 pub struct MinifierOptions {
     pub mangle: Option<MangleOptions>,
     pub compress: Option<CompressOptions>,
+
+    /// Return a [SizeReport] of the minified program.
+    ///
+    /// This prints the program after each pass, so it is slow.
+    ///
+    /// Default: `false`
+    pub report: bool,
 }
 
 impl Default for MinifierOptions {
     fn default() -> Self {
-        Self { mangle: Some(MangleOptions::default()), compress: Some(CompressOptions::default()) }
+        Self {
+            mangle: Some(MangleOptions::default()),
+            compress: Some(CompressOptions::default()),
+            report: false,
+        }
     }
 }
 
 pub struct MinifierReturn {
     pub scoping: Option<Scoping>,
+
+    /// Property name cache, when `mangle.properties` is set.
+    pub property_name_cache: Option<PropertyNameCache>,
+
+    /// Size report, when `report` is set.
+    pub report: Option<SizeReport>,
 }
 
 pub struct Minifier {
@@ -46,24 +74,90 @@ impl Minifier {
     }
 
     pub fn build<'a>(self, allocator: &'a Allocator, program: &mut Program<'a>) -> MinifierReturn {
+        self.build_impl(allocator, program, None)
+    }
+
+    /// Minifies one chunk of a bundle, with mangled names shared with the other chunks.
+    ///
+    /// See [MangleCache] for how to share the cache between chunks.
+    pub fn build_with_cache<'a>(
+        self,
+        allocator: &'a Allocator,
+        program: &mut Program<'a>,
+        cache: &mut MangleCache,
+    ) -> MinifierReturn {
+        self.build_impl(allocator, program, Some(cache))
+    }
+
+    fn build_impl<'a>(
+        self,
+        allocator: &'a Allocator,
+        program: &mut Program<'a>,
+        mut cache: Option<&mut MangleCache>,
+    ) -> MinifierReturn {
+        let mut report = self.options.report.then(|| SizeReportBuilder::new(program));
         let stats = if let Some(compress) = self.options.compress {
             let semantic = SemanticBuilder::new().build(program).semantic;
             let stats = semantic.stats();
             let scoping = semantic.into_scoping();
-            Compressor::new(allocator, compress).build_with_scoping(scoping, program);
+            Compressor::new(allocator, compress).build_with_scoping_and_inspect(
+                scoping,
+                program,
+                |name, program| {
+                    if let Some(report) = &mut report {
+                        report.add_pass(name, program, None);
+                    }
+                },
+            );
             stats
         } else {
             Stats::default()
         };
-        let scoping = self.options.mangle.map(|options| {
+        let mut mangle = self.options.mangle;
+        let property_name_cache =
+            mangle.as_mut().and_then(|options| options.properties.take()).map(|properties| {
+                let mangler = PropertyMangler::new(properties);
+                if let Some(cache) = cache.as_deref_mut() {
+                    mangler.build_with_cache(allocator, program, cache);
+                    cache.properties.clone()
+                } else {
+                    mangler.build(allocator, program)
+                }
+            });
+        if let (Some(report), Some(_)) = (&mut report, &property_name_cache) {
+            report.add_pass("mangle_properties", program, None);
+        }
+        if let Some(options) = mangle.as_ref().filter(|options| !options.keep_private_names) {
+            PrivateMemberMangler::new(options).build(allocator, program);
+            if let Some(report) = &mut report {
+                report.add_pass("mangle_private_members", program, None);
+            }
+        }
+        let scoping = mangle.map(|options| {
             let mut semantic = SemanticBuilder::new()
                 .with_stats(stats)
                 .with_scope_tree_child_ids(true)
                 .build(program)
                 .semantic;
-            Mangler::default().with_options(options).build_with_semantic(&mut semantic, program);
-            semantic.into_scoping()
+            let top_level = options.top_level;
+            let mangler = Mangler::default().with_options(options);
+            let Some(cache) = cache else {
+                mangler.build_with_semantic(&mut semantic, program);
+                return semantic.into_scoping();
+            };
+            mangler.build_with_semantic_and_cache(&mut semantic, program, cache);
+            let scoping = semantic.into_scoping();
+            if top_level && program.source_type.is_script() {
+                cache.rename_global_references(allocator, &scoping, program);
+            }
+            scoping
+        });
+        let report = report.map(|mut report| {
+            if scoping.is_some() {
+                report.add_pass("mangle", program, scoping.as_ref());
+            }
+            report.build(program, scoping.as_ref())
         });
-        MinifierReturn { scoping }
+        MinifierReturn { scoping, property_name_cache, report }
     }
 }
@@ -1,3 +1,5 @@
+use lazy_regex::Regex;
+use oxc_ast::ast::Expression;
 use oxc_syntax::es_target::ESTarget;
 
 pub use oxc_ecmascript::side_effects::PropertyReadSideEffects;
@@ -24,9 +26,104 @@ pub struct CompressOptions {
     /// Default `false`
     pub drop_console: bool,
 
+    /// Only remove calls to these `console` methods (e.g. `["log", "info"]`) when `drop_console` is enabled.
+    ///
+    /// All `console.*` calls are removed if empty.
+    ///
+    /// Default `[]`
+    pub drop_console_methods: Vec<String>,
+
+    /// Remove calls to these functions, e.g. `["assert", "logger.debug"]`.
+    ///
+    /// A call is matched by its callee, so `"logger.debug"` matches `logger.debug(a)`.
+    /// The arguments are removed along with the call,
+    /// and a call whose result is used is replaced with `void 0`.
+    ///
+    /// Default `[]`
+    pub pure_funcs: Vec<String>,
+
     /// Keep function / class names.
     pub keep_names: CompressOptionsKeepNames,
 
+    /// Join consecutive simple statements with the comma operator.
+    ///
+    /// `a(); b(); return c` -> `return a(), b(), c`
+    ///
+    /// Default `true`
+    pub sequences: bool,
+
+    /// Convert `if` statements into logical and conditional expressions.
+    ///
+    /// `if (a) b(); else c();` -> `a ? b() : c();`
+    ///
+    /// Default `true`
+    pub conditionals: bool,
+
+    /// Optimize `if` statements followed by `return`, `throw`, `continue` and `break`.
+    ///
+    /// `if (a) return b; return c;` -> `return a ? b : c;`
+    ///
+    /// Default `true`
+    pub if_return: bool,
+
+    /// Remove duplicated and unreachable `switch` branches.
+    ///
+    /// `switch (a) { case 1: b(); break; case 2: b(); break; case 3: }` -> `switch (a) { case 1: case 2: b(); }`
+    ///
+    /// Default `true`
+    pub switches: bool,
+
+    /// Optimize `for` and `do-while` loops.
+    ///
+    /// `do a(); while (true)` -> `for (;;) a()`, `for (;;) { var a = 1; b(a) }` -> `for (var a = 1;;) b(a)`,
+    /// `for (let i = 0; i < 10; i++);` -> ``
+    ///
+    /// Default `true`
+    pub loops: bool,
+
+    /// Evaluate calls to known global functions with constant arguments.
+    ///
+    /// `Math.pow(2, 8)` -> `256`, `parseInt("ff", 16)` -> `255`, `Number.isInteger(1)` -> `true`
+    ///
+    /// This assumes that the global functions are not modified.
+    ///
+    /// Default `true`
+    pub evaluate_known_globals: bool,
+
+    /// Convert anonymous function expressions which don't use `this`, `arguments`, `super` or
+    /// `new.target` into arrow functions.
+    ///
+    /// `a(function (b) { return b })` -> `a((b) => b)`
+    ///
+    /// This is unsafe because arrow functions can not be called with `new` and have no `prototype`.
+    /// Requires `target` >= ES2015.
+    ///
+    /// Default `false`
+    pub unsafe_arrows: bool,
+
+    /// Convert function expression properties into method shorthands.
+    ///
+    /// `({ a: function () { return this.b } })` -> `({ a() { return this.b } })`
+    ///
+    /// This is unsafe because methods can not be called with `new` and have no `prototype`.
+    /// With `unsafe_arrows`, functions which can be converted into arrow functions are converted
+    /// into arrow functions instead.
+    ///
+    /// Default `false`
+    pub unsafe_methods: bool,
+
+    /// Replace global variables and their members with constant values, like terser's `global_defs`.
+    ///
+    /// `[("DEBUG", Boolean(false)), ("process.env.NODE_ENV", String("production"))]`
+    /// replaces `DEBUG` with `false` and `process.env.NODE_ENV` with `"production"`.
+    ///
+    /// The value of a key prefixed with `@` is parsed as an expression,
+    /// e.g. `("@alert", String("console.log"))` replaces `alert` with `console.log`.
+    /// Expressions that fail to parse or contain functions or classes are ignored.
+    ///
+    /// Default `[]`
+    pub global_defs: Vec<(String, GlobalDefValue)>,
+
     /// Treeshake Options .
     /// <https://rollupjs.org/configuration-options/#treeshake>
     pub treeshake: TreeShakeOptions,
@@ -46,6 +143,17 @@ impl CompressOptions {
             keep_names: CompressOptionsKeepNames::all_false(),
             drop_debugger: true,
             drop_console: true,
+            drop_console_methods: vec![],
+            pure_funcs: vec![],
+            sequences: true,
+            conditionals: true,
+            if_return: true,
+            switches: true,
+            loops: true,
+            evaluate_known_globals: true,
+            unsafe_arrows: false,
+            unsafe_methods: false,
+            global_defs: vec![],
             treeshake: TreeShakeOptions::default(),
         }
     }
@@ -56,12 +164,33 @@ impl CompressOptions {
             keep_names: CompressOptionsKeepNames::all_true(),
             drop_debugger: false,
             drop_console: false,
+            drop_console_methods: vec![],
+            pure_funcs: vec![],
+            sequences: true,
+            conditionals: true,
+            if_return: true,
+            switches: true,
+            loops: true,
+            evaluate_known_globals: false,
+            unsafe_arrows: false,
+            unsafe_methods: false,
+            global_defs: vec![],
             treeshake: TreeShakeOptions::default(),
         }
     }
 }
 
-#[derive(Debug, Clone, Copy, Default)]
+/// Value of [CompressOptions::global_defs].
+#[derive(Debug, Clone)]
+pub enum GlobalDefValue {
+    Null,
+    Boolean(bool),
+    Number(f64),
+    /// A string literal, or the source text of an expression if the key is prefixed with `@`.
+    String(String),
+}
+
+#[derive(Debug, Clone, Default)]
 pub struct CompressOptionsKeepNames {
     /// Keep function names so that `Function.prototype.name` is preserved.
     ///
@@ -76,23 +205,47 @@ pub struct CompressOptionsKeepNames {
     ///
     /// Default `false`
     pub class: bool,
+
+    /// Only keep names of functions matching the regex.
+    ///
+    /// Has no effect unless `function` is `true`.
+    ///
+    /// Default `None`
+    pub function_regex: Option<Regex>,
+
+    /// Only keep names of classes matching the regex.
+    ///
+    /// Has no effect unless `class` is `true`.
+    ///
+    /// Default `None`
+    pub class_regex: Option<Regex>,
 }
 
 impl CompressOptionsKeepNames {
     pub fn all_false() -> Self {
-        Self { function: false, class: false }
+        Self { function: false, class: false, function_regex: None, class_regex: None }
     }
 
     pub fn all_true() -> Self {
-        Self { function: true, class: true }
+        Self { function: true, class: true, function_regex: None, class_regex: None }
     }
 
     pub fn function_only() -> Self {
-        Self { function: true, class: false }
+        Self { function: true, ..Self::all_false() }
     }
 
     pub fn class_only() -> Self {
-        Self { function: false, class: true }
+        Self { class: true, ..Self::all_false() }
+    }
+
+    /// Whether the name of a function named `name` is kept.
+    pub fn keeps_function(&self, name: &str) -> bool {
+        self.function && self.function_regex.as_ref().is_none_or(|regex| regex.is_match(name))
+    }
+
+    /// Whether the name of a class named `name` is kept.
+    pub fn keeps_class(&self, name: &str) -> bool {
+        self.class && self.class_regex.as_ref().is_none_or(|regex| regex.is_match(name))
     }
 }
 
@@ -144,3 +297,13 @@ impl Default for TreeShakeOptions {
         }
     }
 }
+
+impl TreeShakeOptions {
+    /// Whether `callee` is one of [TreeShakeOptions::manual_pure_functions].
+    pub(crate) fn is_manual_pure_function(&self, callee: &Expression) -> bool {
+        if let Expression::Identifier(ident) = callee {
+            return self.manual_pure_functions.iter().any(|name| ident.name.as_str() == name);
+        }
+        false
+    }
+}
@@ -0,0 +1,245 @@
+use oxc_allocator::TakeIn;
+use oxc_ast::ast::*;
+use oxc_ast_visit::{Visit, walk};
+use oxc_syntax::{es_target::ESTarget, scope::ScopeFlags};
+use oxc_traverse::Ancestor;
+
+use crate::ctx::Ctx;
+
+use super::{PeepholeOptimizations, State};
+
+/// Convert function expressions into shorter syntax.
+///
+/// Both conversions are opt-in because they change observable behavior:
+/// neither arrow functions nor methods can be called with `new`, and neither has a `prototype`.
+///
+/// The value of an object property exits before the property itself,
+/// so [PeepholeOptimizations::try_convert_to_arrow_function] runs before
+/// [PeepholeOptimizations::try_convert_to_method]. With both options enabled,
+/// only the functions which use `this` or `arguments` are converted into methods:
+///
+/// `({ a: function () { return 1 }, b: function () { return this } })` -> `({ a: () => 1, b() { return this } })`
+impl<'a> PeepholeOptimizations {
+    /// `function (a) { return a }` -> `(a) => { return a }`
+    ///
+    /// The body is compressed by [PeepholeOptimizations::try_compress_arrow_expression] in the next iteration.
+    pub fn try_convert_to_arrow_function(
+        &self,
+        expr: &mut Expression<'a>,
+        state: &mut State,
+        ctx: &mut Ctx<'a, '_>,
+    ) {
+        if !ctx.state.options.unsafe_arrows || self.target < ESTarget::ES2015 {
+            return;
+        }
+        let Expression::FunctionExpression(func) = expr else { return };
+        if func.id.is_some()
+            || func.generator
+            || func.this_param.is_some()
+            || func.body.is_none()
+            || Self::has_duplicate_parameters(&func.params)
+            || FunctionContextFinder::is_used_by(func)
+        {
+            return;
+        }
+        match ctx.parent() {
+            // `new (function () {})()`
+            Ancestor::NewExpressionCallee(_) => return,
+            // `({ a() {} })`, `({ get a() {} })`
+            Ancestor::ObjectPropertyValue(prop)
+                if *prop.method() || *prop.kind() != PropertyKind::Init =>
+            {
+                return;
+            }
+            _ => {}
+        }
+
+        let Expression::FunctionExpression(func) = expr.take_in(ctx.ast) else { unreachable!() };
+        let func = func.unbox();
+        let scope_id = func.scope_id();
+        let Some(body) = func.body else { unreachable!() };
+        ctx.scoping_mut().scope_flags_mut(scope_id).insert(ScopeFlags::Arrow);
+        *expr = ctx.ast.expression_arrow_function_with_scope_id_and_pure(
+            func.span,
+            false,
+            func.r#async,
+            func.type_parameters,
+            func.params,
+            func.return_type,
+            body,
+            scope_id,
+            func.pure,
+        );
+        state.changed = true;
+    }
+
+    /// `({ a: function () {} })` -> `({ a() {} })`
+    pub fn try_convert_to_method(
+        &self,
+        prop: &mut ObjectProperty<'a>,
+        state: &mut State,
+        ctx: &mut Ctx<'a, '_>,
+    ) {
+        if !ctx.state.options.unsafe_methods
+            || self.target < ESTarget::ES2015
+            || prop.method
+            || prop.shorthand
+            || prop.kind != PropertyKind::Init
+        {
+            return;
+        }
+        // "{ __proto__: function () {} }" sets prototype, while "{ __proto__() {} }" does not
+        if !prop.computed && prop.key.is_specific_static_name("__proto__") {
+            return;
+        }
+        let Expression::FunctionExpression(func) = &prop.value else { return };
+        if func.id.is_some() || func.this_param.is_some() || func.body.is_none() {
+            return;
+        }
+        prop.method = true;
+        state.changed = true;
+    }
+
+    /// Arrow functions can not have duplicate parameters, even in sloppy mode.
+    fn has_duplicate_parameters(params: &FormalParameters<'a>) -> bool {
+        let mut names = rustc_hash::FxHashSet::default();
+        params
+            .iter_bindings()
+            .flat_map(BindingPattern::get_binding_identifiers)
+            .any(|ident| !names.insert(ident.name))
+    }
+}
+
+/// Finds the uses of the `this`, `arguments`, `super` and `new.target` of a function,
+/// which are not available in an arrow function.
+#[derive(Default)]
+struct FunctionContextFinder {
+    found: bool,
+}
+
+impl<'a> FunctionContextFinder {
+    fn is_used_by(func: &Function<'a>) -> bool {
+        let mut finder = Self::default();
+        finder.visit_formal_parameters(&func.params);
+        if let Some(body) = &func.body {
+            finder.visit_function_body(body);
+        }
+        finder.found
+    }
+}
+
+impl<'a> Visit<'a> for FunctionContextFinder {
+    fn visit_this_expression(&mut self, _it: &ThisExpression) {
+        self.found = true;
+    }
+
+    fn visit_super(&mut self, _it: &Super) {
+        self.found = true;
+    }
+
+    fn visit_meta_property(&mut self, it: &MetaProperty<'a>) {
+        if it.meta.name == "new" {
+            self.found = true;
+        }
+    }
+
+    fn visit_identifier_reference(&mut self, it: &IdentifierReference<'a>) {
+        // A direct `eval` may use any of them.
+        if matches!(it.name.as_str(), "arguments" | "eval") {
+            self.found = true;
+        }
+    }
+
+    fn visit_expression(&mut self, it: &Expression<'a>) {
+        if !self.found {
+            walk::walk_expression(self, it);
+        }
+    }
+
+    // Nested functions have their own `this` and `arguments`.
+    fn visit_function(&mut self, _it: &Function<'a>, _flags: ScopeFlags) {}
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_syntax::es_target::ESTarget;
+
+    use crate::{
+        CompressOptions,
+        tester::{test_options, test_same_options},
+    };
+
+    #[test]
+    fn convert_to_arrow_function() {
+        let options = CompressOptions { unsafe_arrows: true, ..CompressOptions::smallest() };
+        let test =
+            |source_text: &str, expected: &str| test_options(source_text, expected, &options);
+        let test_same = |source_text: &str| test_same_options(source_text, &options);
+
+        test("x(function (a) { return a })", "x((a) => a)");
+        test("x(function () { a() })", "x(() => { a() })");
+        test("x(async function () { await a() })", "x(async () => { await a() })");
+        test("x(function (a = 1, ...b) { return b })", "x((a = 1, ...b) => b)");
+        test("x(function () { return () => 1 })", "x(() => () => 1)");
+        test(
+            "x(function () { return function () { return this } })",
+            "x(() => function () { return this })",
+        );
+        test("x({ a: function () { return 1 } })", "x({ a: () => 1 })");
+
+        test_same("x(function () { return this })");
+        test_same("x(function (a = this) {})");
+        test_same("x(function () { return () => this })");
+        test_same("x(function () { return arguments })");
+        test_same("x(function () { return new.target })");
+        test_same("x(function () { eval('a') })");
+        test_same("x(function () { return class { a = this } })");
+        test_same("x(function* () {})");
+        test_same("x(function a() { return a })");
+        test_same("x(new function () {})");
+        test_same("x({ a() { return 1 } })");
+        test_same("x({ get a() { return 1 } })");
+
+        test_same_options("x(function (a) { return a })", &CompressOptions::smallest());
+        test_same_options(
+            "x(function (a) { return a })",
+            &CompressOptions { target: ESTarget::ES5, ..options.clone() },
+        );
+    }
+
+    #[test]
+    fn convert_to_method() {
+        let options = CompressOptions { unsafe_methods: true, ..CompressOptions::smallest() };
+        let test =
+            |source_text: &str, expected: &str| test_options(source_text, expected, &options);
+        let test_same = |source_text: &str| test_same_options(source_text, &options);
+
+        test("x({ a: function () { return this.b } })", "x({ a() { return this.b } })");
+        test("x({ a: async function* () {} })", "x({ async *a() {} })");
+        test("x({ [a]: function () {} })", "x({ [a]() {} })");
+        test("x({ 'a': function () {} })", "x({ a() {} })");
+        test("x({ ['__proto__']: function () {} })", "x({ __proto__() {} })");
+
+        test_same("x({ __proto__: function () {} })");
+        test_same("x({ '__proto__': function () {} })");
+        test_same("x({ a: function b() { return b } })");
+        test_same("x({ a: () => {} })");
+        test_same("x({ get a() {} })");
+
+        test_same_options("x({ a: function () {} })", &CompressOptions::smallest());
+    }
+
+    #[test]
+    fn convert_to_arrow_function_and_method() {
+        let options = CompressOptions {
+            unsafe_arrows: true,
+            unsafe_methods: true,
+            ..CompressOptions::smallest()
+        };
+        test_options(
+            "x({ a: function () { return 1 }, b: function () { return this } })",
+            "x({ a: () => 1, b() { return this } })",
+            &options,
+        );
+    }
+}
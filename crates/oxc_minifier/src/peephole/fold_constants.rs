@@ -372,7 +372,7 @@ impl<'a> PeepholeOptimizations {
     // https://github.com/evanw/esbuild/blob/v0.24.2/internal/js_ast/js_ast_helpers.go#L1128
     #[expect(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
     #[must_use]
-    fn approximate_printed_int_char_count(value: f64) -> usize {
+    pub fn approximate_printed_int_char_count(value: f64) -> usize {
         let mut count = if value.is_infinite() {
             "Infinity".len()
         } else if value.is_nan() {
@@ -336,7 +336,7 @@ mod test {
         test_same("function f(){try{foo()}catch(e){bar(e)}finally{baz()}}");
 
         // Try it out with switch statements
-        test_same("function f(){switch(x){case 1:break}}");
+        test_same("function f(){switch(x){case 1:g();break;default:h()}}");
 
         // Do while loops stay in a block if that's where they started
         test(
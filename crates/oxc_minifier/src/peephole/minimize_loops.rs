@@ -0,0 +1,294 @@
+use oxc_allocator::TakeIn;
+use oxc_ast::ast::*;
+use oxc_ecmascript::{constant_evaluation::ConstantEvaluation, side_effects::MayHaveSideEffects};
+use oxc_span::GetSpan;
+use oxc_syntax::{reference::Reference, scope::ScopeFlags};
+
+use crate::ctx::Ctx;
+
+use super::{PeepholeOptimizations, State};
+
+/// Terser option: `loops: true`.
+///
+/// <https://github.com/terser/terser/blob/v5.39.0/lib/compress/index.js#L1401>
+impl<'a> PeepholeOptimizations {
+    pub fn minimize_loop(
+        &self,
+        stmt: &mut Statement<'a>,
+        state: &mut State,
+        ctx: &mut Ctx<'a, '_>,
+    ) {
+        Self::try_fold_do_while_true(stmt, state, ctx);
+        if let Statement::ForStatement(for_stmt) = stmt {
+            Self::hoist_loop_invariant_declarations(for_stmt, state, ctx);
+        }
+        Self::try_remove_empty_loop(stmt, state, ctx);
+    }
+
+    /// `while (true)` is converted to `for (;;)` by [super::Normalize],
+    /// `do` loops with a truthy test are converted here.
+    ///
+    /// "do a(); while (true)" => "for (;;) a()"
+    ///
+    /// `continue` jumps to the test of a `do` loop, which always passes.
+    fn try_fold_do_while_true(stmt: &mut Statement<'a>, state: &mut State, ctx: &mut Ctx<'a, '_>) {
+        let Statement::DoWhileStatement(do_stmt) = stmt else { return };
+        if do_stmt.test.may_have_side_effects(ctx)
+            || do_stmt.test.evaluate_value_to_boolean(ctx) != Some(true)
+        {
+            return;
+        }
+        let span = do_stmt.span;
+        let body = do_stmt.body.take_in(ctx.ast);
+        let scope_id = ctx.create_child_scope_of_current(ScopeFlags::empty());
+        *stmt = Statement::ForStatement(
+            ctx.ast.alloc_for_statement_with_scope_id(span, None, None, None, body, scope_id),
+        );
+        state.changed = true;
+    }
+
+    /// Move a `var` declaration at the start of the loop body into the loop initializer.
+    ///
+    /// "for (;;) { var a = 1; b(a) }" => "for (var a = 1;;) b(a)"
+    /// "for (; c;) { var a; b(a) }" => "for (var a; c;) b(a)"
+    ///
+    /// A declaration without initializer does nothing, so it can be moved into any loop.
+    /// An initializer is only moved if it is a constant, the variable is not assigned or declared anywhere else,
+    /// and the loop has no test, so that the body is run at least once and starts with
+    /// the declaration.
+    fn hoist_loop_invariant_declarations(
+        for_stmt: &mut ForStatement<'a>,
+        state: &mut State,
+        ctx: &mut Ctx<'a, '_>,
+    ) {
+        let first = match &for_stmt.body {
+            Statement::BlockStatement(block) => block.body.first(),
+            stmt => Some(stmt),
+        };
+        let Some(Statement::VariableDeclaration(decl)) = first else { return };
+        if !decl.kind.is_var() {
+            return;
+        }
+        match &for_stmt.init {
+            None => {}
+            Some(ForStatementInit::VariableDeclaration(init)) if init.kind.is_var() => {}
+            _ => return,
+        }
+        let is_invariant = decl.declarations.iter().all(|declarator| {
+            let BindingPatternKind::BindingIdentifier(ident) = &declarator.id.kind else {
+                return false;
+            };
+            declarator.init.as_ref().is_none_or(|init| {
+                let symbol_id = ident.symbol_id();
+                for_stmt.test.is_none()
+                    && Self::is_loop_invariant_value(init, ctx)
+                    // Another declaration with an initializer is not a write reference.
+                    && ctx.scoping().symbol_redeclarations(symbol_id).is_empty()
+                    && !ctx.scoping().get_resolved_references(symbol_id).any(Reference::is_write)
+            })
+        });
+        if !is_invariant {
+            return;
+        }
+
+        let decl = match &mut for_stmt.body {
+            Statement::BlockStatement(block) => block.body.remove(0),
+            body => {
+                let span = body.span();
+                std::mem::replace(body, ctx.ast.statement_empty(span))
+            }
+        };
+        let Statement::VariableDeclaration(mut decl) = decl else { unreachable!() };
+        match &mut for_stmt.init {
+            Some(ForStatementInit::VariableDeclaration(init)) => {
+                init.declarations.extend(decl.declarations.take_in(ctx.ast));
+            }
+            _ => for_stmt.init = Some(ForStatementInit::VariableDeclaration(decl)),
+        }
+        state.changed = true;
+    }
+
+    /// Whether `expr` evaluates to the same value in every iteration of a loop.
+    fn is_loop_invariant_value(expr: &Expression<'a>, ctx: &Ctx<'a, '_>) -> bool {
+        match expr {
+            Expression::BooleanLiteral(_)
+            | Expression::NullLiteral(_)
+            | Expression::NumericLiteral(_)
+            | Expression::BigIntLiteral(_)
+            | Expression::StringLiteral(_) => true,
+            Expression::TemplateLiteral(lit) => lit.is_no_substitution_template(),
+            Expression::Identifier(ident) => {
+                matches!(ident.name.as_str(), "undefined" | "Infinity" | "NaN")
+                    && ctx.is_global_reference(ident)
+            }
+            Expression::UnaryExpression(e) => {
+                matches!(
+                    e.operator,
+                    UnaryOperator::Void | UnaryOperator::LogicalNot | UnaryOperator::UnaryNegation
+                ) && Self::is_loop_invariant_value(&e.argument, ctx)
+            }
+            _ => false,
+        }
+    }
+
+    /// Remove counting loops without a body.
+    ///
+    /// "for (let i = 0; i < 10; i++);" => ""
+    ///
+    /// The counter must be a local variable which is only used by the loop,
+    /// and must be incremented by an integer, so that the loop always terminates.
+    fn try_remove_empty_loop(stmt: &mut Statement<'a>, state: &mut State, ctx: &Ctx<'a, '_>) {
+        let Statement::ForStatement(for_stmt) = stmt else { return };
+        let is_empty = match &for_stmt.body {
+            Statement::EmptyStatement(_) => true,
+            Statement::BlockStatement(block) => block.body.is_empty(),
+            _ => false,
+        };
+        if !is_empty {
+            return;
+        }
+        let Some(ForStatementInit::VariableDeclaration(decl)) = &for_stmt.init else { return };
+        if !matches!(decl.kind, VariableDeclarationKind::Var | VariableDeclarationKind::Let) {
+            return;
+        }
+        let [declarator] = decl.declarations.as_slice() else { return };
+        let BindingPatternKind::BindingIdentifier(ident) = &declarator.id.kind else { return };
+        let Some(Expression::NumericLiteral(start)) = &declarator.init else { return };
+        if !start.value.is_finite() {
+            return;
+        }
+
+        let symbol_id = ident.symbol_id();
+        let scoping = ctx.scoping();
+        let scope_id = scoping.symbol_scope_id(symbol_id);
+        // Top level variables may be used by other scripts.
+        if scope_id == scoping.root_scope_id()
+            || scoping.scope_flags(scope_id).contains_direct_eval()
+        {
+            return;
+        }
+        let is_counter = |expr: &Expression<'a>| {
+            matches!(expr, Expression::Identifier(ident)
+                if scoping.get_reference(ident.reference_id()).symbol_id() == Some(symbol_id))
+        };
+
+        // `i < 10`, `i <= 10`
+        let Some(Expression::BinaryExpression(test)) = &for_stmt.test else { return };
+        if !matches!(test.operator, BinaryOperator::LessThan | BinaryOperator::LessEqualThan)
+            || !is_counter(&test.left)
+        {
+            return;
+        }
+        let Expression::NumericLiteral(end) = &test.right else { return };
+        // Incrementing a larger number may not change it.
+        #[expect(clippy::cast_precision_loss)]
+        if !end.value.is_finite() || end.value > (1u64 << 53) as f64 {
+            return;
+        }
+
+        // `i++`, `++i`, `i += 1`
+        let is_increment = match &for_stmt.update {
+            Some(Expression::UpdateExpression(e)) => {
+                e.operator == UpdateOperator::Increment
+                    && matches!(&e.argument, SimpleAssignmentTarget::AssignmentTargetIdentifier(ident)
+                        if scoping.get_reference(ident.reference_id()).symbol_id() == Some(symbol_id))
+            }
+            Some(Expression::AssignmentExpression(e)) => {
+                e.operator == AssignmentOperator::Addition
+                    && matches!(&e.left, AssignmentTarget::AssignmentTargetIdentifier(ident)
+                        if scoping.get_reference(ident.reference_id()).symbol_id() == Some(symbol_id))
+                    && matches!(&e.right, Expression::NumericLiteral(step)
+                        if step.value >= 1.0 && step.value.fract() == 0.0)
+            }
+            _ => false,
+        };
+        // The test and the update are the only references.
+        if !is_increment || scoping.get_resolved_reference_ids(symbol_id).len() != 2 {
+            return;
+        }
+
+        *stmt = ctx.ast.statement_empty(for_stmt.span);
+        state.changed = true;
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        CompressOptions,
+        tester::{test, test_same, test_same_options},
+    };
+
+    #[test]
+    fn while_true() {
+        test("while (true) f()", "for (;;) f()");
+        test("while (1) f()", "for (;;) f()");
+        test("while (!0) f()", "for (;;) f()");
+        test_same("for (; x;) f()");
+    }
+
+    #[test]
+    fn do_while_true() {
+        test("do f(); while (true)", "for (;;) f()");
+        test("do { f(); g() } while (1)", "for (;;) f(), g()");
+        test("do { if (f()) continue; g() } while (!0)", "for (;;) f() || g()");
+        test(
+            "a: do { if (f()) continue a; g() } while (true)",
+            "a: for (;;) { if (f()) continue a; g() }",
+        );
+        test_same("do f(); while (x)");
+        test_same("do f(); while (x())");
+    }
+
+    #[test]
+    fn hoist_loop_invariant_declarations() {
+        test("for (;;) { var a = 1; f(a) }", "for (var a = 1;;) f(a)");
+        test(
+            "for (var i = 0;; i++) { var a = 'a'; f(a, i) }",
+            "for (var i = 0, a = 'a';; i++) f(a, i)",
+        );
+        test(
+            "for (;;) { var a = void 0, b = -1; f(a, b) }",
+            "for (var a = void 0, b = -1;;) f(a, b)",
+        );
+        test("for (; x;) { var a; f(a) }", "for (var a; x;) f(a)");
+        test("for (;;) var a", "for (var a;;);");
+        // The body may never run.
+        test_same("for (; x;) { var a = 1; f(a) }");
+        // The variable is assigned in the loop.
+        test_same("for (;;) { var a = 1; a = f(a) }");
+        test_same("for (;;) { var a = 1; f(a); var a = 2; if (f(a), g()) break }");
+        // A new object is created in every iteration.
+        test_same("for (;;) { var a = []; f(a) }");
+        test_same("for (;;) { var a = b; f(a) }");
+        test_same("for (;;) { let a = 1; f(a) }");
+        test_same("for (x = 0;;) { var a = 1; f(a) }");
+        test_same("for (let i = 0;;) { var a = 1; f(a, i) }");
+        test_same("for (;;) { var [a] = b; f(a) }");
+    }
+
+    #[test]
+    fn remove_empty_loop() {
+        test("function f() { for (let i = 0; i < 10; i++); }", "function f() {}");
+        test("function f() { for (var i = 0; i <= 10; ++i) {} }", "function f() {}");
+        test("function f() { for (let i = 10; i < 5; i += 2); }", "function f() {}");
+        test_same("function f() { for (var i = 0; i < 10; i++); return i }");
+        test_same("function f() { for (let i = 0; i < 10; i += 0.5); }");
+        test_same("function f() { for (let i = 0; i < 10; i--); }");
+        test_same("function f() { for (let i = 0; i > -10; i--); }");
+        test_same("function f() { for (let i = 0; i < n; i++); }");
+        test_same("function f() { for (let i = 0; i < 1e20; i++); }");
+        test_same("function f() { for (let i = 0; i < 10; i++) g(); }");
+        test_same("function f() { for (let i = 0; i < 10; i++) eval(''); }");
+        // Top level variables may be used by other scripts.
+        test_same("for (var i = 0; i < 10; i++);");
+    }
+
+    #[test]
+    fn loops_option() {
+        let options = CompressOptions { loops: false, ..CompressOptions::smallest() };
+        test_same_options("do f(); while (!0)", &options);
+        test_same_options("for (;;) { var a = 1; f(a) }", &options);
+        test_same_options("function f() { for (let i = 0; i < 10; i++); }", &options);
+    }
+}
@@ -96,7 +96,7 @@ impl<'a> PeepholeOptimizations {
                     let prev_index = result.len() - 2;
                     let prev_stmt = &result[prev_index];
                     match prev_stmt {
-                        Statement::ExpressionStatement(_) => {
+                        Statement::ExpressionStatement(_) if ctx.state.options.sequences => {
                             if let Some(Statement::ReturnStatement(last_return)) = result.last() {
                                 if last_return.argument.is_none() {
                                     break 'return_loop;
@@ -120,7 +120,7 @@ impl<'a> PeepholeOptimizations {
                             result.push(last_return_stmt);
                         }
                         // Merge the last two statements
-                        Statement::IfStatement(if_stmt) => {
+                        Statement::IfStatement(if_stmt) if ctx.state.options.if_return => {
                             // The previous statement must be an if statement with no else clause
                             if if_stmt.alternate.is_some() {
                                 break 'return_loop;
@@ -195,7 +195,7 @@ impl<'a> PeepholeOptimizations {
                     let prev_index = result.len() - 2;
                     let prev_stmt = &result[prev_index];
                     match prev_stmt {
-                        Statement::ExpressionStatement(_) => {
+                        Statement::ExpressionStatement(_) if ctx.state.options.sequences => {
                             state.changed = true;
                             // "a(); throw b;" => "throw a(), b;"
                             let last_stmt = result.pop().unwrap();
@@ -216,7 +216,7 @@ impl<'a> PeepholeOptimizations {
                             result.push(last_throw_stmt);
                         }
                         // Merge the last two statements
-                        Statement::IfStatement(if_stmt) => {
+                        Statement::IfStatement(if_stmt) if ctx.state.options.if_return => {
                             // The previous statement must be an if statement with no else clause
                             if if_stmt.alternate.is_some() {
                                 break 'throw_loop;
@@ -390,6 +390,10 @@ impl<'a> PeepholeOptimizations {
         state: &mut State,
         ctx: &mut Ctx<'a, '_>,
     ) {
+        if !ctx.state.options.sequences {
+            result.push(Statement::ExpressionStatement(expr_stmt));
+            return;
+        }
         if let Some(Statement::ExpressionStatement(prev_expr_stmt)) = result.last_mut() {
             let a = &mut prev_expr_stmt.expression;
             let b = &mut expr_stmt.expression;
@@ -407,6 +411,10 @@ impl<'a> PeepholeOptimizations {
         state: &mut State,
         ctx: &mut Ctx<'a, '_>,
     ) {
+        if !ctx.state.options.sequences {
+            result.push(Statement::SwitchStatement(switch_stmt));
+            return;
+        }
         if let Some(Statement::ExpressionStatement(prev_expr_stmt)) = result.last_mut() {
             let a = &mut prev_expr_stmt.expression;
             let b = &mut switch_stmt.discriminant;
@@ -428,15 +436,17 @@ impl<'a> PeepholeOptimizations {
         ctx: &mut Ctx<'a, '_>,
     ) -> ControlFlow<()> {
         // Absorb a previous expression statement
-        if let Some(Statement::ExpressionStatement(prev_expr_stmt)) = result.last_mut() {
-            let a = &mut prev_expr_stmt.expression;
-            let b = &mut if_stmt.test;
-            if_stmt.test = Self::join_sequence(a, b, ctx);
-            result.pop();
-            state.changed = true;
+        if ctx.state.options.sequences {
+            if let Some(Statement::ExpressionStatement(prev_expr_stmt)) = result.last_mut() {
+                let a = &mut prev_expr_stmt.expression;
+                let b = &mut if_stmt.test;
+                if_stmt.test = Self::join_sequence(a, b, ctx);
+                result.pop();
+                state.changed = true;
+            }
         }
 
-        if if_stmt.consequent.is_jump_statement() {
+        if if_stmt.consequent.is_jump_statement() && ctx.state.options.if_return {
             // Absorb a previous if statement
             if let Some(Statement::IfStatement(prev_if_stmt)) = result.last_mut() {
                 if prev_if_stmt.alternate.is_none()
@@ -530,8 +540,12 @@ impl<'a> PeepholeOptimizations {
                         Statement::BlockStatement(ctx.ast.alloc(block_stmt))
                     };
                     let mut if_stmt = ctx.ast.if_statement(test.span(), test, consequent, None);
-                    let if_stmt = self
-                        .try_minimize_if(&mut if_stmt, state, ctx)
+                    let if_stmt = ctx
+                        .state
+                        .options
+                        .conditionals
+                        .then(|| self.try_minimize_if(&mut if_stmt, state, ctx))
+                        .flatten()
                         .unwrap_or_else(|| Statement::IfStatement(ctx.ast.alloc(if_stmt)));
                     result.push(if_stmt);
                     state.changed = true;
@@ -574,7 +588,9 @@ impl<'a> PeepholeOptimizations {
         state: &mut State,
         ctx: &mut Ctx<'a, '_>,
     ) {
-        if let Some(Statement::ExpressionStatement(prev_expr_stmt)) = result.last_mut() {
+        if let Some(Statement::ExpressionStatement(prev_expr_stmt)) =
+            result.last_mut().filter(|_| ctx.state.options.sequences)
+        {
             if let Some(argument) = &mut ret_stmt.argument {
                 let a = &mut prev_expr_stmt.expression;
                 *argument = Self::join_sequence(a, argument, ctx);
@@ -594,7 +610,9 @@ impl<'a> PeepholeOptimizations {
         state: &mut State,
         ctx: &mut Ctx<'a, '_>,
     ) {
-        if let Some(Statement::ExpressionStatement(prev_expr_stmt)) = result.last_mut() {
+        if let Some(Statement::ExpressionStatement(prev_expr_stmt)) =
+            result.last_mut().filter(|_| ctx.state.options.sequences)
+        {
             let a = &mut prev_expr_stmt.expression;
             let b = &mut throw_stmt.argument;
             throw_stmt.argument = Self::join_sequence(a, b, ctx);
@@ -613,7 +631,7 @@ impl<'a> PeepholeOptimizations {
         ctx: &mut Ctx<'a, '_>,
     ) {
         match result.last_mut() {
-            Some(Statement::ExpressionStatement(prev_expr_stmt)) => {
+            Some(Statement::ExpressionStatement(prev_expr_stmt)) if ctx.state.options.sequences => {
                 if let Some(init) = &mut for_stmt.init {
                     if let Some(init) = init.as_expression_mut() {
                         let a = &mut prev_expr_stmt.expression;
@@ -663,7 +681,7 @@ impl<'a> PeepholeOptimizations {
     ) {
         match result.last_mut() {
             // "a; for (var b in c) d" => "for (var b in a, c) d"
-            Some(Statement::ExpressionStatement(prev_expr_stmt)) => {
+            Some(Statement::ExpressionStatement(prev_expr_stmt)) if ctx.state.options.sequences => {
                 // Annex B.3.5 allows initializers in non-strict mode
                 // <https://tc39.es/ecma262/multipage/additional-ecmascript-features-for-web-browsers.html#sec-initializers-in-forin-statement-heads>
                 // If there's a side-effectful initializer, we should not move the previous statement inside.
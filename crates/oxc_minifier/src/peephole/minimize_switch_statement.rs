@@ -0,0 +1,321 @@
+use oxc_allocator::TakeIn;
+use oxc_ast::ast::*;
+use oxc_ast_visit::{Visit, walk};
+use oxc_ecmascript::{constant_evaluation::ConstantValue, side_effects::MayHaveSideEffects};
+use oxc_span::{ContentEq, GetSpan};
+use oxc_syntax::scope::ScopeFlags;
+
+use crate::{ctx::Ctx, keep_var::KeepVar};
+
+use super::{PeepholeOptimizations, State};
+
+impl<'a> PeepholeOptimizations {
+    /// Remove redundant parts of switch statements.
+    ///
+    /// <https://github.com/terser/terser/blob/v5.39.0/lib/compress/index.js#L1730>
+    pub fn try_minimize_switch(
+        &self,
+        stmt: &mut Statement<'a>,
+        state: &mut State,
+        ctx: &mut Ctx<'a, '_>,
+    ) {
+        let Statement::SwitchStatement(switch_stmt) = stmt else { return };
+
+        let is_constant = Self::remove_unreachable_cases(switch_stmt, state, ctx);
+        Self::remove_last_break(switch_stmt, state);
+        Self::remove_trailing_empty_cases(switch_stmt, state, ctx);
+        Self::merge_duplicate_cases(switch_stmt, state);
+
+        // "switch (a) {}" => "a;"
+        if switch_stmt.cases.is_empty() {
+            let expr = switch_stmt.discriminant.take_in(ctx.ast);
+            *stmt = ctx.ast.statement_expression(expr.span(), expr);
+            state.changed = true;
+            return;
+        }
+
+        // "switch (1) { case 1: a(); case 2: b(); }" => "{ a(); b(); }"
+        if is_constant {
+            let mut finder = SwitchBreakFinder::default();
+            for case in &switch_stmt.cases {
+                finder.visit_statements(&case.consequent);
+            }
+            if !finder.found {
+                let mut body = ctx.ast.vec();
+                for case in &mut switch_stmt.cases {
+                    body.extend(case.consequent.take_in(ctx.ast));
+                }
+                *stmt = ctx.ast.statement_block_with_scope_id(
+                    switch_stmt.span,
+                    body,
+                    switch_stmt.scope_id(),
+                );
+                state.changed = true;
+            }
+        }
+    }
+
+    /// Remove the cases which can not be reached when the discriminant is a constant.
+    ///
+    /// "switch (1) { case 0: a(); case 1: b(); break; case 2: c(); }" => "switch (1) { case 1: b(); break; }"
+    ///
+    /// Returns `true` if the switch statement always runs the remaining cases from the first one.
+    fn remove_unreachable_cases(
+        switch_stmt: &mut SwitchStatement<'a>,
+        state: &mut State,
+        ctx: &mut Ctx<'a, '_>,
+    ) -> bool {
+        if switch_stmt.discriminant.may_have_side_effects(ctx) {
+            return false;
+        }
+        let mut matched = None;
+        for (i, case) in switch_stmt.cases.iter().enumerate() {
+            let Some(test) = &case.test else { continue };
+            if test.may_have_side_effects(ctx) {
+                return false;
+            }
+            match ctx.eval_binary_operation(
+                BinaryOperator::StrictEquality,
+                &switch_stmt.discriminant,
+                test,
+            ) {
+                Some(ConstantValue::Boolean(true)) => {
+                    matched = Some(i);
+                    break;
+                }
+                Some(ConstantValue::Boolean(false)) => {}
+                _ => return false,
+            }
+        }
+        let cases = &switch_stmt.cases;
+        let Some(start) = matched.or_else(|| cases.iter().position(SwitchCase::is_default_case))
+        else {
+            // Nothing matches, only the discriminant is evaluated.
+            if !Self::can_remove_cases(cases, ctx) {
+                return false;
+            }
+            switch_stmt.cases.clear();
+            state.changed = true;
+            return true;
+        };
+        // The cases following a jump can not be reached by falling through.
+        let end = cases[start..]
+            .iter()
+            .position(|case| case.consequent.iter().any(Statement::is_jump_statement))
+            .map_or(cases.len(), |i| start + i + 1);
+        if !Self::can_remove_cases(&cases[..start], ctx)
+            || !Self::can_remove_cases(&cases[end..], ctx)
+        {
+            return false;
+        }
+        if start > 0 || end < cases.len() {
+            switch_stmt.cases.truncate(end);
+            switch_stmt.cases.drain(..start);
+            state.changed = true;
+        }
+        true
+    }
+
+    /// Cases declaring variables can not be removed,
+    /// since other cases or code after the switch statement may reference them.
+    fn can_remove_cases(cases: &[SwitchCase<'a>], ctx: &Ctx<'a, '_>) -> bool {
+        cases.iter().all(|case| {
+            let mut keep_var = KeepVar::new(ctx.ast);
+            for stmt in &case.consequent {
+                if stmt.is_declaration() {
+                    return false;
+                }
+                keep_var.visit_statement(stmt);
+            }
+            keep_var.get_variable_declaration().is_none()
+        })
+    }
+
+    /// "switch (a) { case 1: b(); break; }" => "switch (a) { case 1: b(); }"
+    fn remove_last_break(switch_stmt: &mut SwitchStatement<'a>, state: &mut State) {
+        let Some(last_case) = switch_stmt.cases.last_mut() else { return };
+        let is_break = matches!(
+            last_case.consequent.last(),
+            Some(Statement::BreakStatement(break_stmt)) if break_stmt.label.is_none()
+        );
+        if is_break {
+            last_case.consequent.pop();
+            state.changed = true;
+        }
+    }
+
+    /// "switch (a) { case 1: b(); case 2: }" => "switch (a) { case 1: b(); }"
+    fn remove_trailing_empty_cases(
+        switch_stmt: &mut SwitchStatement<'a>,
+        state: &mut State,
+        ctx: &Ctx<'a, '_>,
+    ) {
+        while let Some(last_case) = switch_stmt.cases.last() {
+            if !last_case.consequent.is_empty() {
+                break;
+            }
+            // An empty default does nothing.
+            // Without an empty case, a matching discriminant would run the default instead.
+            if let Some(test) = &last_case.test {
+                if test.may_have_side_effects(ctx)
+                    || switch_stmt.cases.iter().any(SwitchCase::is_default_case)
+                {
+                    break;
+                }
+            }
+            switch_stmt.cases.pop();
+            state.changed = true;
+        }
+    }
+
+    /// "switch (a) { case 1: return b; case 2: return b; }" => "switch (a) { case 1: case 2: return b; }"
+    fn merge_duplicate_cases(switch_stmt: &mut SwitchStatement<'a>, state: &mut State) {
+        for i in 1..switch_stmt.cases.len() {
+            let (prev, next) = switch_stmt.cases.split_at_mut(i);
+            let prev = &mut prev[i - 1].consequent;
+            let next = &next[0].consequent;
+            // The body of the previous case must not fall through into the next case.
+            if prev.last().is_none_or(|stmt| !stmt.is_jump_statement())
+                || prev.iter().any(Statement::is_declaration)
+                || !prev.content_eq(next)
+            {
+                continue;
+            }
+            prev.clear();
+            state.changed = true;
+        }
+    }
+}
+
+/// Finds `break` statements which exit the switch statement.
+#[derive(Default)]
+struct SwitchBreakFinder {
+    /// Number of enclosing loops and switch statements inside the switch statement.
+    depth: usize,
+    found: bool,
+}
+
+impl<'a> Visit<'a> for SwitchBreakFinder {
+    fn visit_break_statement(&mut self, it: &BreakStatement<'a>) {
+        if it.label.is_none() && self.depth == 0 {
+            self.found = true;
+        }
+    }
+
+    fn visit_statement(&mut self, it: &Statement<'a>) {
+        let is_breakable = matches!(
+            it,
+            Statement::DoWhileStatement(_)
+                | Statement::ForInStatement(_)
+                | Statement::ForOfStatement(_)
+                | Statement::ForStatement(_)
+                | Statement::SwitchStatement(_)
+                | Statement::WhileStatement(_)
+        );
+        if is_breakable {
+            self.depth += 1;
+        }
+        walk::walk_statement(self, it);
+        if is_breakable {
+            self.depth -= 1;
+        }
+    }
+
+    fn visit_function(&mut self, _it: &Function<'a>, _flags: ScopeFlags) {}
+
+    fn visit_class(&mut self, _it: &Class<'a>) {}
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        CompressOptions,
+        tester::{test, test_options, test_same, test_same_options},
+    };
+
+    #[test]
+    fn remove_last_break() {
+        test("switch (x) { case 1: f(); break; }", "switch (x) { case 1: f(); }");
+        test(
+            "switch (x) { case 1: f(); break; case 2: g(); break; }",
+            "switch (x) { case 1: f(); break; case 2: g(); }",
+        );
+        test_same("a: switch (x) { case 1: f(); break; case 2: g(); break a; }");
+        test(
+            "for (;;) switch (x) { case 1: f(); break; default: g(); break; }",
+            "for (;;) switch (x) { case 1: f(); break; default: g(); }",
+        );
+    }
+
+    #[test]
+    fn remove_trailing_empty_cases() {
+        test(
+            "switch (x) { case 1: f(); break; case 2: g(); case 3: case 4: }",
+            "switch (x) { case 1: f(); break; case 2: g(); }",
+        );
+        test(
+            "switch (x) { case 1: f(); break; case 2: g(); default: }",
+            "switch (x) { case 1: f(); break; case 2: g(); }",
+        );
+        test_same("switch (x) { case 1: f(); break; default: g(); break; case 2: }");
+        test_same("switch (x) { case 1: f(); break; case 2: g(); case y(): }");
+        test("switch (x) { case 1: case 2: }", "x");
+        test("switch (x) { default: }", "x");
+        test("switch (x()) {}", "x()");
+    }
+
+    #[test]
+    fn merge_duplicate_cases() {
+        test(
+            "function f() { switch (x) { case 1: return a; case 2: return a; case 3: g(); } }",
+            "function f() { switch (x) { case 1: case 2: return a; case 3: g(); } }",
+        );
+        test(
+            "for (;;) switch (x) { case 1: f(); continue; case 2: f(); continue; default: g(); }",
+            "for (;;) switch (x) { case 1: case 2: f(); continue; default: g(); }",
+        );
+        // Without a jump, the first case falls through into the second one.
+        test_same("switch (x) { case 1: f(); case 2: f(); break; default: g(); }");
+        test_same(
+            "function f() { switch (x) { case 1: return a; case 2: return b; case 3: g(); } }",
+        );
+        test_same("switch (x) { case 1: let a; throw a; case 2: g(); }");
+    }
+
+    #[test]
+    fn remove_unreachable_cases() {
+        test("switch (1) { case 0: a(); case 1: b(); break; case 2: c(); }", "b()");
+        test("switch (1) { case 0: a(); case 1: b(); case 2: c(); }", "b(), c()");
+        test("switch ('x') { case 'y': a(); break; default: d(); }", "d()");
+        test("switch (3) { case 1: a(); break; case 2: b(); }", "");
+        test("switch (!0) { case 0: a(); break; case true: b(); }", "b()");
+        test_same("for (;;) switch (1) { case 1: if (x) break; b(); }");
+        test(
+            "function f() { switch (1) { case 1: a(); return; case 2: b(); } }",
+            "function f() { a(); }",
+        );
+        test("switch (1) { case 1: for (;;) break; }", "for (;;) break;");
+        test("switch (1) { case 1: let a = f(); g(a); }", "{ let a = f(); g(a); }");
+        test("a: switch (1) { case 1: if (x) break a; b(); }", "a: { if (x) break a; b(); }");
+        test_same("switch (x) { case 1: a(); break; case 2: b(); }");
+        test_same("switch (1) { case x: a(); break; case 1: b(); }");
+        test_same("switch (1) { case f(): a(); break; case 1: b(); }");
+        // Removed cases may declare variables used elsewhere.
+        test_same("switch (1) { case 0: var a = 1; break; case 1: f(a); }");
+        test_same("switch (1) { case 0: let a = 1; break; case 1: f(a); }");
+    }
+
+    #[test]
+    fn switches_option() {
+        let options = CompressOptions { switches: false, ..CompressOptions::smallest() };
+        test_same_options(
+            "switch (x) { case 1: f(); break; case 2: g(); break; case 3: }",
+            &options,
+        );
+        test_options(
+            "switch (x) { case 1: f(); break; case 2: g(); break; case 3: }",
+            "switch (x) { case 1: f(); break; case 2: g(); }",
+            &CompressOptions::smallest(),
+        );
+    }
+}
@@ -1,6 +1,7 @@
 #![allow(clippy::unused_self)]
 
 mod collapse_variable_declarations;
+mod convert_function_expressions;
 mod convert_to_dotted_properties;
 mod fold_constants;
 mod minimize_conditional_expression;
@@ -10,11 +11,14 @@ mod minimize_expression_in_boolean_context;
 mod minimize_for_statement;
 mod minimize_if_statement;
 mod minimize_logical_expression;
+mod minimize_loops;
 mod minimize_not_expression;
 mod minimize_statements;
+mod minimize_switch_statement;
 mod normalize;
 mod remove_dead_code;
 mod remove_unused_expression;
+mod remove_unused_imports;
 mod replace_known_methods;
 mod statement_fusion;
 mod substitute_alternate_syntax;
@@ -24,7 +28,7 @@ use rustc_hash::FxHashSet;
 use oxc_allocator::Vec;
 use oxc_ast::ast::*;
 use oxc_data_structures::stack::NonEmptyStack;
-use oxc_syntax::{es_target::ESTarget, scope::ScopeId};
+use oxc_syntax::{es_target::ESTarget, scope::ScopeId, symbol::SymbolId};
 use oxc_traverse::{ReusableTraverseCtx, Traverse, traverse_mut_with_ctx};
 
 use crate::{
@@ -186,11 +190,19 @@ impl<'a> Traverse<'a, MinifierState<'a>> for PeepholeOptimizations {
         self.try_fold_stmt_in_boolean_context(stmt, &mut ctx);
         self.remove_dead_code_exit_statement(stmt, &mut state, &mut ctx);
         if let Statement::IfStatement(if_stmt) = stmt {
-            if let Some(folded_stmt) = self.try_minimize_if(if_stmt, &mut state, &mut ctx) {
-                *stmt = folded_stmt;
-                self.mark_current_function_as_changed();
+            if ctx.state.options.conditionals {
+                if let Some(folded_stmt) = self.try_minimize_if(if_stmt, &mut state, &mut ctx) {
+                    *stmt = folded_stmt;
+                    self.mark_current_function_as_changed();
+                }
             }
         }
+        if ctx.state.options.switches {
+            self.try_minimize_switch(stmt, &mut state, &mut ctx);
+        }
+        if ctx.state.options.loops {
+            self.minimize_loop(stmt, &mut state, &mut ctx);
+        }
         if state.changed {
             self.mark_current_function_as_changed();
         }
@@ -450,6 +462,8 @@ impl<'a> Traverse<'a, MinifierState<'a>> for LatePeepholeOptimizations {
 
 pub struct DeadCodeElimination {
     inner: PeepholeOptimizations,
+    /// Import bindings which are referenced before dead code is removed.
+    referenced_imports: FxHashSet<SymbolId>,
 }
 
 impl<'a> DeadCodeElimination {
@@ -459,6 +473,7 @@ impl<'a> DeadCodeElimination {
                 ESTarget::ESNext,
                 CompressOptionsKeepNames::all_true(),
             ),
+            referenced_imports: FxHashSet::default(),
         }
     }
 
@@ -472,6 +487,14 @@ impl<'a> DeadCodeElimination {
 }
 
 impl<'a> Traverse<'a, MinifierState<'a>> for DeadCodeElimination {
+    fn enter_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.collect_referenced_imports(program, ctx.scoping());
+    }
+
+    fn exit_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.remove_unused_imports(program, ctx.scoping());
+    }
+
     fn exit_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
         let mut state = State::default();
         let mut ctx = Ctx::new(ctx);
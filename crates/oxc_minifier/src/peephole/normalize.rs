@@ -1,12 +1,25 @@
+use std::rc::Rc;
+
+use rustc_hash::FxHashSet;
+
 use oxc_allocator::{TakeIn, Vec};
 use oxc_ast::ast::*;
-use oxc_ecmascript::constant_evaluation::{DetermineValueType, ValueType};
+use oxc_ast_visit::{Visit, VisitMut, walk};
+use oxc_ecmascript::constant_evaluation::{ConstantValue, DetermineValueType, ValueType};
+use oxc_parser::Parser;
 use oxc_semantic::IsGlobalReference;
-use oxc_span::GetSpan;
-use oxc_syntax::scope::ScopeFlags;
+use oxc_span::{GetSpan, SourceType};
+use oxc_syntax::{
+    reference::{ReferenceFlags, ReferenceId},
+    scope::ScopeFlags,
+    symbol::SymbolId,
+};
 use oxc_traverse::{Ancestor, ReusableTraverseCtx, Traverse, traverse_mut_with_ctx};
 
-use crate::ctx::{Ctx, MinifierState, TraverseCtx};
+use crate::{
+    GlobalDefValue,
+    ctx::{Ctx, MinifierState, TraverseCtx},
+};
 
 #[derive(Default)]
 pub struct NormalizeOptions {
@@ -27,14 +40,23 @@ pub struct NormalizeOptions {
 /// * convert `var x; void x` to `void 0`
 /// * convert `undefined` to `void 0`
 /// * apply `pure` to side-effect free global constructors (e.g. `new WeakMap()`)
+/// * apply `pure` to calls of `/* #__NO_SIDE_EFFECTS__ */` functions, and of variables initialized with them
 ///
 /// Also
 ///
 /// * remove `debugger` and `console.log` (optional)
+/// * replace `global_defs` (optional)
 ///
 /// <https://github.com/google/closure-compiler/blob/v20240609/src/com/google/javascript/jscomp/Normalize.java>
 pub struct Normalize {
     options: NormalizeOptions,
+
+    /// Symbols of `/* #__NO_SIDE_EFFECTS__ */` functions,
+    /// including variables which are never reassigned and initialized with them (`const g = f`).
+    no_side_effects: FxHashSet<SymbolId>,
+
+    /// References created by `global_defs` expressions, which are not replaced again.
+    global_def_references: FxHashSet<ReferenceId>,
 }
 
 impl<'a> Normalize {
@@ -48,11 +70,15 @@ impl<'a> Normalize {
 }
 
 impl<'a> Traverse<'a, MinifierState<'a>> for Normalize {
+    fn enter_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.collect_no_side_effects(program, ctx);
+    }
+
     fn exit_statements(&mut self, stmts: &mut Vec<'a, Statement<'a>>, ctx: &mut TraverseCtx<'a>) {
         stmts.retain(|stmt| {
             !(matches!(stmt, Statement::EmptyStatement(_))
                 || Self::drop_debugger(stmt, ctx)
-                || Self::drop_console(stmt, ctx))
+                || Self::drop_call(stmt, ctx))
         });
     }
 
@@ -75,6 +101,12 @@ impl<'a> Traverse<'a, MinifierState<'a>> for Normalize {
         }
     }
 
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !ctx.state.options.global_defs.is_empty() {
+            self.replace_global_def(expr, ctx);
+        }
+    }
+
     fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
         if let Expression::ParenthesizedExpression(paren_expr) = expr {
             *expr = paren_expr.expression.take_in(ctx.ast);
@@ -89,9 +121,7 @@ impl<'a> Traverse<'a, MinifierState<'a>> for Normalize {
                 Self::recover_arrow_expression_after_drop_console(e, ctx);
                 None
             }
-            Expression::CallExpression(_) if ctx.state.options.drop_console => {
-                self.compress_console(expr, ctx)
-            }
+            Expression::CallExpression(_) => Self::compress_dropped_call(expr, ctx),
             Expression::StaticMemberExpression(e) => Self::fold_number_nan_to_nan(e, ctx),
             _ => None,
         } {
@@ -100,17 +130,55 @@ impl<'a> Traverse<'a, MinifierState<'a>> for Normalize {
     }
 
     fn exit_call_expression(&mut self, e: &mut CallExpression<'a>, ctx: &mut TraverseCtx<'a>) {
-        Self::set_no_side_effects_to_call_expr(e, ctx);
+        self.set_no_side_effects_to_call_expr(e, ctx);
     }
 
     fn exit_new_expression(&mut self, e: &mut NewExpression<'a>, ctx: &mut TraverseCtx<'a>) {
-        Self::set_pure_or_no_side_effects_to_new_expr(e, ctx);
+        self.set_pure_or_no_side_effects_to_new_expr(e, ctx);
     }
 }
 
 impl<'a> Normalize {
     pub fn new(options: NormalizeOptions) -> Self {
-        Self { options }
+        Self {
+            options,
+            no_side_effects: FxHashSet::default(),
+            global_def_references: FxHashSet::default(),
+        }
+    }
+
+    /// `/* #__NO_SIDE_EFFECTS__ */ function f() {}; const g = f; g()` -> `g()` is pure.
+    fn collect_no_side_effects(&mut self, program: &Program<'a>, ctx: &TraverseCtx<'a>) {
+        let scoping = ctx.scoping();
+        self.no_side_effects.clone_from(scoping.no_side_effects());
+        if self.no_side_effects.is_empty() {
+            return;
+        }
+
+        let mut collector = AliasCollector::default();
+        collector.visit_program(program);
+
+        // Follow chains of aliases (`const g = f, h = g`) until nothing changes.
+        loop {
+            let mut changed = false;
+            for &(symbol_id, reference_id) in &collector.aliases {
+                if self.no_side_effects.contains(&symbol_id) || scoping.symbol_is_mutated(symbol_id)
+                {
+                    continue;
+                }
+                let Some(aliased_symbol_id) = scoping.get_reference(reference_id).symbol_id()
+                else {
+                    continue;
+                };
+                if self.no_side_effects.contains(&aliased_symbol_id) {
+                    self.no_side_effects.insert(symbol_id);
+                    changed = true;
+                }
+            }
+            if !changed {
+                break;
+            }
+        }
     }
 
     /// Drop `drop_debugger` statement.
@@ -120,35 +188,127 @@ impl<'a> Normalize {
         matches!(stmt, Statement::DebuggerStatement(_)) && ctx.state.options.drop_debugger
     }
 
-    fn compress_console(
-        &self,
+    /// `console.log(a)` -> `void 0`
+    ///
+    /// Enabled by `compress.drop_console` and `compress.pure_funcs`
+    fn compress_dropped_call(
         expr: &Expression<'a>,
         ctx: &TraverseCtx<'a>,
     ) -> Option<Expression<'a>> {
-        debug_assert!(ctx.state.options.drop_console);
-        Self::is_console(expr).then(|| ctx.ast.void_0(expr.span()))
+        Self::is_dropped_call(expr, ctx).then(|| ctx.ast.void_0(expr.span()))
     }
 
-    fn drop_console(stmt: &Statement<'a>, ctx: &TraverseCtx<'a>) -> bool {
-        ctx.state.options.drop_console
-            && matches!(stmt, Statement::ExpressionStatement(expr) if Self::is_console(&expr.expression))
+    fn drop_call(stmt: &Statement<'a>, ctx: &TraverseCtx<'a>) -> bool {
+        matches!(stmt, Statement::ExpressionStatement(expr) if Self::is_dropped_call(&expr.expression, ctx))
     }
 
     fn recover_arrow_expression_after_drop_console(
         expr: &mut ArrowFunctionExpression<'a>,
         ctx: &TraverseCtx<'a>,
     ) {
-        if ctx.state.options.drop_console && expr.expression && expr.body.is_empty() {
+        let options = &ctx.state.options;
+        if (options.drop_console || !options.pure_funcs.is_empty())
+            && expr.expression
+            && expr.body.is_empty()
+        {
             expr.expression = false;
         }
     }
 
-    fn is_console(expr: &Expression<'_>) -> bool {
+    fn is_dropped_call(expr: &Expression<'_>, ctx: &TraverseCtx<'a>) -> bool {
         let Expression::CallExpression(call_expr) = &expr else { return false };
-        let Some(member_expr) = call_expr.callee.as_member_expression() else { return false };
+        let options = &ctx.state.options;
+        (options.drop_console && Self::is_console(&call_expr.callee, &options.drop_console_methods))
+            || (!options.pure_funcs.is_empty()
+                && Self::is_pure_func(&call_expr.callee, &options.pure_funcs))
+    }
+
+    fn is_console(callee: &Expression<'_>, methods: &[String]) -> bool {
+        let Some(member_expr) = callee.as_member_expression() else { return false };
         let obj = member_expr.object();
         let Some(ident) = obj.get_identifier_reference() else { return false };
         ident.name == "console"
+            && (methods.is_empty()
+                || member_expr
+                    .static_property_name()
+                    .is_some_and(|name| methods.iter().any(|method| method == name)))
+    }
+
+    /// Whether `callee` is `a.b.c` for one of `pure_funcs`.
+    fn is_pure_func(callee: &Expression<'_>, pure_funcs: &[String]) -> bool {
+        pure_funcs.iter().any(|pure_func| Self::match_dotted_name(callee, pure_func).is_some())
+    }
+
+    /// Returns the `a` of `expr` if `expr` is `a.b.c` for `name` `"a.b.c"`.
+    fn match_dotted_name<'b>(
+        expr: &'b Expression<'a>,
+        name: &str,
+    ) -> Option<&'b IdentifierReference<'a>> {
+        let mut expr = expr;
+        let mut parts = name.rsplit('.').peekable();
+        while let Some(part) = parts.next() {
+            match expr {
+                Expression::StaticMemberExpression(member) if member.property.name == part => {
+                    expr = &member.object;
+                }
+                Expression::Identifier(ident) if ident.name == part && parts.peek().is_none() => {
+                    return Some(ident);
+                }
+                _ => return None,
+            }
+        }
+        None
+    }
+
+    /// `process.env.NODE_ENV` -> `"production"`
+    ///
+    /// Enabled by `compress.global_defs`
+    fn replace_global_def(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !matches!(expr, Expression::Identifier(_) | Expression::StaticMemberExpression(_)) {
+            return;
+        }
+        let options = Rc::clone(&ctx.state.options);
+        let Some((key, value)) = options.global_defs.iter().find(|(key, _)| {
+            let name = key.strip_prefix('@').unwrap_or(key);
+            Self::match_dotted_name(expr, name).is_some_and(|ident| {
+                ident.is_global_reference(ctx.scoping())
+                    && !self.global_def_references.contains(&ident.reference_id())
+            })
+        }) else {
+            return;
+        };
+        let span = expr.span();
+        let value = match value {
+            GlobalDefValue::String(source_text) if key.starts_with('@') => {
+                if let Some(new_expr) = self.parse_global_def_expression(source_text, ctx) {
+                    *expr = new_expr;
+                }
+                return;
+            }
+            GlobalDefValue::Null => ConstantValue::Null,
+            GlobalDefValue::Boolean(b) => ConstantValue::Boolean(*b),
+            GlobalDefValue::Number(n) => ConstantValue::Number(*n),
+            GlobalDefValue::String(s) => ConstantValue::String(ctx.ast.atom(s).as_str().into()),
+        };
+        *expr = Ctx::new(ctx).value_to_expr(span, value);
+    }
+
+    fn parse_global_def_expression(
+        &mut self,
+        source_text: &str,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Expression<'a>> {
+        let allocator = ctx.ast.allocator;
+        let source_text = allocator.alloc_str(source_text);
+        let mut expr =
+            Parser::new(allocator, source_text, SourceType::default()).parse_expression().ok()?;
+        let mut creator = GlobalDefReferenceCreator {
+            ctx,
+            references: &mut self.global_def_references,
+            has_scope: false,
+        };
+        creator.visit_expression(&mut expr);
+        (!creator.has_scope).then_some(expr)
     }
 
     fn convert_while_to_for(stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
@@ -264,7 +424,11 @@ impl<'a> Normalize {
         Some(ctx.ast.nan(ident.span))
     }
 
-    fn set_no_side_effects_to_call_expr(call_expr: &mut CallExpression<'a>, ctx: &TraverseCtx<'a>) {
+    fn set_no_side_effects_to_call_expr(
+        &self,
+        call_expr: &mut CallExpression<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) {
         if call_expr.pure {
             return;
         }
@@ -273,7 +437,7 @@ impl<'a> Normalize {
         };
         if let Some(symbol_id) = ctx.scoping().get_reference(ident.reference_id()).symbol_id() {
             // Apply `/* #__NO_SIDE_EFFECTS__ */`
-            if ctx.scoping().no_side_effects().contains(&symbol_id) {
+            if self.no_side_effects.contains(&symbol_id) {
                 call_expr.pure = true;
             }
         }
@@ -282,6 +446,7 @@ impl<'a> Normalize {
     /// Set `pure` on side effect free `new Expr()`s.
     /// `PC` or `PC_WITH_ARRAY` in <https://github.com/rollup/rollup/blob/v4.42.0/src/ast/nodes/shared/knownGlobals.ts>
     fn set_pure_or_no_side_effects_to_new_expr(
+        &self,
         new_expr: &mut NewExpression<'a>,
         ctx: &mut TraverseCtx<'a>,
     ) {
@@ -293,7 +458,7 @@ impl<'a> Normalize {
         };
         if let Some(symbol_id) = ctx.scoping().get_reference(ident.reference_id()).symbol_id() {
             // Apply `/* #__NO_SIDE_EFFECTS__ */`
-            if ctx.scoping().no_side_effects().contains(&symbol_id) {
+            if self.no_side_effects.contains(&symbol_id) {
                 new_expr.pure = true;
             }
             return;
@@ -389,9 +554,57 @@ impl<'a> Normalize {
     }
 }
 
+/// Creates references for identifiers of a parsed `global_defs` expression.
+struct GlobalDefReferenceCreator<'a, 'b> {
+    ctx: &'b mut TraverseCtx<'a>,
+    references: &'b mut FxHashSet<ReferenceId>,
+    /// Functions and classes need scopes, which are not created.
+    has_scope: bool,
+}
+
+impl<'a> VisitMut<'a> for GlobalDefReferenceCreator<'a, '_> {
+    fn visit_identifier_reference(&mut self, it: &mut IdentifierReference<'a>) {
+        let reference_id = self.ctx.create_unbound_reference(&it.name, ReferenceFlags::Read);
+        it.reference_id.set(Some(reference_id));
+        self.references.insert(reference_id);
+    }
+
+    fn visit_function(&mut self, _it: &mut Function<'a>, _flags: ScopeFlags) {
+        self.has_scope = true;
+    }
+
+    fn visit_arrow_function_expression(&mut self, _it: &mut ArrowFunctionExpression<'a>) {
+        self.has_scope = true;
+    }
+
+    fn visit_class(&mut self, _it: &mut Class<'a>) {
+        self.has_scope = true;
+    }
+}
+
+/// Collects variables initialized with an identifier (`const g = f`).
+#[derive(Default)]
+struct AliasCollector {
+    aliases: std::vec::Vec<(SymbolId, ReferenceId)>,
+}
+
+impl<'a> Visit<'a> for AliasCollector {
+    fn visit_variable_declarator(&mut self, it: &VariableDeclarator<'a>) {
+        if let (BindingPatternKind::BindingIdentifier(id), Some(Expression::Identifier(init))) =
+            (&it.id.kind, it.init.as_ref().map(Expression::without_parentheses))
+        {
+            self.aliases.push((id.symbol_id(), init.reference_id()));
+        }
+        walk::walk_variable_declarator(self, it);
+    }
+}
+
 #[cfg(test)]
 mod test {
-    use crate::tester::{test, test_same};
+    use crate::{
+        CompressOptions, GlobalDefValue,
+        tester::{test, test_options, test_same, test_same_options},
+    };
 
     #[test]
     fn test_while() {
@@ -438,6 +651,71 @@ mod test {
         );
     }
 
+    #[test]
+    fn drop_console_methods() {
+        let options = CompressOptions {
+            drop_console_methods: vec!["log".to_string(), "info".to_string()],
+            ..CompressOptions::smallest()
+        };
+        test_options(
+            "console.log(a); console.info(b); console.error(c)",
+            "console.error(c)",
+            &options,
+        );
+        test_options("console['log'](a)", "", &options);
+        test_options("x = console.log(a)", "x = void 0", &options);
+        test_same_options("console.warn(a)", &options);
+        test_same_options("console[a](b)", &options);
+    }
+
+    #[test]
+    fn pure_funcs() {
+        let options = CompressOptions {
+            pure_funcs: vec!["assert".to_string(), "logger.debug".to_string()],
+            ..CompressOptions::default()
+        };
+        test_options("assert(a); logger.debug(b()); f()", "f()", &options);
+        test_options("x = assert(a)", "x = void 0", &options);
+        test_options("(() => logger.debug(a))()", "", &options);
+        test_same_options("logger.info(a)", &options);
+        test_same_options("a.logger.debug(b)", &options);
+        test_same_options("debug(a)", &options);
+        test_same_options("assert.ok(a)", &options);
+    }
+
+    #[test]
+    fn global_defs() {
+        let options = CompressOptions {
+            global_defs: vec![
+                ("DEBUG".to_string(), GlobalDefValue::Boolean(false)),
+                ("VERSION".to_string(), GlobalDefValue::Number(2.0)),
+                ("process.env.NODE_ENV".to_string(), GlobalDefValue::String("production".into())),
+                ("process.env.API".to_string(), GlobalDefValue::Null),
+                ("@alert".to_string(), GlobalDefValue::String("console.log".into())),
+                ("@log".to_string(), GlobalDefValue::String("log.info".into())),
+                ("@fn".to_string(), GlobalDefValue::String("() => {}".into())),
+            ],
+            ..CompressOptions::default()
+        };
+        test_options("if (DEBUG) foo()", "", &options);
+        test_options("x = VERSION", "x = 2", &options);
+        test_options(
+            "if (process.env.NODE_ENV !== 'production') foo(); else bar()",
+            "bar()",
+            &options,
+        );
+        test_options("x = process.env.API", "x = null", &options);
+        test_options("x = process.env.NODE_ENV.length", "x = 10", &options);
+        test_same_options("x = process.env.OTHER", &options);
+        test_options("alert(1)", "console.log(1)", &options);
+        // The identifiers in the replacement are not replaced again.
+        test_options("log(1)", "log.info(1)", &options);
+        test_same_options("fn()", &options);
+        test_same_options("let DEBUG; DEBUG && foo()", &options);
+        test_same_options("x = a.DEBUG", &options);
+        test_same_options("DEBUG = 1", &options);
+    }
+
     #[test]
     fn drop_debugger() {
         test("debugger", "");
@@ -1,14 +1,30 @@
 use oxc_allocator::{TakeIn, Vec};
 use oxc_ast::ast::*;
 use oxc_ast_visit::Visit;
-use oxc_ecmascript::{constant_evaluation::ConstantEvaluation, side_effects::MayHaveSideEffects};
-use oxc_span::GetSpan;
+use oxc_ecmascript::{
+    constant_evaluation::ConstantEvaluation,
+    side_effects::{MayHaveSideEffects, MayHaveSideEffectsContext},
+};
+use oxc_span::{Atom, GetSpan};
+use oxc_syntax::reference::ReferenceId;
 use oxc_traverse::Ancestor;
 
 use crate::{ctx::Ctx, keep_var::KeepVar};
 
 use super::{LatePeepholeOptimizations, PeepholeOptimizations, State};
 
+/// Collects identifier references in an expression.
+#[derive(Default)]
+struct ReferenceCollector<'a> {
+    references: std::vec::Vec<(ReferenceId, Atom<'a>)>,
+}
+
+impl<'a> Visit<'a> for ReferenceCollector<'a> {
+    fn visit_identifier_reference(&mut self, it: &IdentifierReference<'a>) {
+        self.references.push((it.reference_id(), it.name));
+    }
+}
+
 /// Remove Dead Code from the AST.
 ///
 /// Terser option: `dead_code: true`.
@@ -28,6 +44,7 @@ impl<'a> PeepholeOptimizations {
             Statement::ForStatement(s) => self.try_fold_for(s, state, ctx),
             Statement::TryStatement(s) => Self::try_fold_try(s, ctx),
             Statement::LabeledStatement(s) => Self::try_fold_labeled(s, ctx),
+            Statement::VariableDeclaration(s) => Self::remove_unused_declarators(s, state, ctx),
             _ => None,
         } {
             *stmt = new_stmt;
@@ -357,6 +374,72 @@ impl<'a> PeepholeOptimizations {
         }
     }
 
+    /// Remove unused local variables initialized by side effect free calls
+    /// annotated with `/* @__PURE__ */` or `/* #__NO_SIDE_EFFECTS__ */`.
+    ///
+    /// `function f() { var x = /* @__PURE__ */ foo(), y = 1; return y }` -> `function f() { var y = 1; return y }`
+    fn remove_unused_declarators(
+        decl: &mut VariableDeclaration<'a>,
+        state: &mut State,
+        ctx: &mut Ctx<'a, '_>,
+    ) -> Option<Statement<'a>> {
+        // Disposal of `using` declarations has side effects.
+        if matches!(decl.kind, VariableDeclarationKind::Using | VariableDeclarationKind::AwaitUsing)
+        {
+            return None;
+        }
+        let len = decl.declarations.len();
+        decl.declarations.retain(|declarator| {
+            if !Self::is_unused_declarator(declarator, ctx) {
+                return true;
+            }
+            // Delete the references in the initializer, so the variables they refer to can be removed as well.
+            if let Some(init) = &declarator.init {
+                let mut collector = ReferenceCollector::default();
+                collector.visit_expression(init);
+                for (reference_id, name) in collector.references {
+                    ctx.delete_reference(reference_id, &name);
+                }
+            }
+            false
+        });
+        if decl.declarations.len() == len {
+            return None;
+        }
+        if !decl.declarations.is_empty() {
+            state.changed = true;
+            return None;
+        }
+        Some(ctx.ast.statement_empty(decl.span))
+    }
+
+    fn is_unused_declarator(declarator: &VariableDeclarator<'a>, ctx: &Ctx<'a, '_>) -> bool {
+        let BindingPatternKind::BindingIdentifier(ident) = &declarator.id.kind else {
+            return false;
+        };
+        let Some(init) = &declarator.init else { return false };
+        let scoping = ctx.scoping();
+        let symbol_id = ident.symbol_id();
+        let scope_id = scoping.symbol_scope_id(symbol_id);
+        // Top level variables may be used by other scripts or exported.
+        if scope_id == scoping.root_scope_id() {
+            return false;
+        }
+        // Direct `eval` can reference the variable by name.
+        if scoping.scope_flags(scope_id).contains_direct_eval() {
+            return false;
+        }
+        let is_pure = match init {
+            Expression::CallExpression(e) => e.pure,
+            Expression::NewExpression(e) => e.pure,
+            _ => false,
+        };
+        is_pure
+            && ctx.annotations()
+            && scoping.symbol_is_unused(symbol_id)
+            && !init.may_have_side_effects(ctx)
+    }
+
     fn try_fold_try(s: &mut TryStatement<'a>, ctx: &mut Ctx<'a, '_>) -> Option<Statement<'a>> {
         if let Some(handler) = &mut s.handler {
             if s.block.body.is_empty() {
@@ -587,9 +670,9 @@ mod test {
         // test("for(x=0;x<100;x++){x}", "for(x=0;x<100;x++);");
         // test("for(x in y){x}", "for(x in y);");
         // test("for (x of y) {x}", "for(x of y);");
-        test("for (let x = 1; x <10; x++ ) {}", "for (let x = 1; x <10; x++ );");
+        test("for (let x = 1; x <10; x++ ) {}", "");
         test("for (var x = 1; x <10; x++ ) {}", "for (var x = 1; x <10; x++ );");
-        test("do { } while (true)", "do;while(!0)");
+        test("do { } while (true)", "for(;;);");
         test(
             "function z(a) {
               {
@@ -767,4 +850,27 @@ mod test {
     fn remove_constant_value() {
         test("const foo = false; if (foo) { console.log('foo') }", "const foo = !1;");
     }
+
+    #[test]
+    fn remove_unused_pure_declarations() {
+        test("function f() { var x = /* @__PURE__ */ foo() }", "function f() {}");
+        test("function f() { let x = /* @__PURE__ */ new Foo() }", "function f() {}");
+        test(
+            "function f() { var x = /* @__PURE__ */ foo(), y = 1; return y }",
+            "function f() { var y = 1; return y }",
+        );
+        test(
+            "function f() { let x = /* @__PURE__ */ foo(), y = /* @__PURE__ */ bar(x) }",
+            "function f() {}",
+        );
+        test(
+            "/* @__NO_SIDE_EFFECTS__ */ function g() {} function f() { let x = g() }",
+            "/* @__NO_SIDE_EFFECTS__ */ function g() {} function f() {}",
+        );
+        test_same("function f() { var x = /* @__PURE__ */ foo(bar()) }");
+        test_same("function f() { var x = /* @__PURE__ */ foo(); return x }");
+        test_same("function f() { var x = /* @__PURE__ */ foo(); eval('x') }");
+        test_same("function f() { using x = /* @__PURE__ */ foo() }");
+        test_same("var x = /* @__PURE__ */ foo()");
+    }
 }
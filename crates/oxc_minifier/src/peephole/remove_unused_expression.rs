@@ -940,6 +940,14 @@ mod test {
         check("export const f = /* @__NO_SIDE_EFFECTS__ */ () => {}");
         check("/* @__NO_SIDE_EFFECTS__ */ const f = () => {}");
         check("/* @__NO_SIDE_EFFECTS__ */ export const f = () => {}");
+
+        // Variables initialized with a `/* @__NO_SIDE_EFFECTS__ */` function.
+        check("/* @__NO_SIDE_EFFECTS__ */ function g() {} const f = g");
+        check("/* @__NO_SIDE_EFFECTS__ */ function h() {} const g = h, f = g");
+        test(
+            "/* @__NO_SIDE_EFFECTS__ */ function g() {} let f = g; f = h; f()",
+            "/* @__NO_SIDE_EFFECTS__ */ function g() {} let f = g; f = h, f()",
+        );
     }
 
     #[test]
@@ -0,0 +1,80 @@
+use rustc_hash::FxHashSet;
+
+use oxc_ast::ast::*;
+use oxc_ast_visit::Visit;
+use oxc_semantic::{Scoping, SymbolId};
+
+use super::DeadCodeElimination;
+
+/// Remove imports which are no longer referenced after dead code elimination.
+///
+/// e.g. `import { devtools } from 'devtools'; if (false) { devtools() }`
+///
+/// Only import bindings that were referenced before the dead code was removed are dropped,
+/// imports that were unused to begin with are left untouched.
+/// An import declaration whose bindings are all dropped is kept as a bare `import 'mod'`,
+/// because evaluating the module may have side effects.
+///
+/// This also runs as part of [crate::Compressor::dead_code_elimination], which is used after
+/// `define` replacement when the minifier is not run.
+impl<'a> DeadCodeElimination {
+    /// Record import bindings that are referenced before any code is removed.
+    pub fn collect_referenced_imports(&mut self, program: &Program<'a>, scoping: &Scoping) {
+        self.referenced_imports = program
+            .body
+            .iter()
+            .filter_map(|stmt| match stmt {
+                Statement::ImportDeclaration(decl) => decl.specifiers.as_ref(),
+                _ => None,
+            })
+            .flatten()
+            .map(|specifier| specifier.local().symbol_id())
+            .filter(|&symbol_id| !scoping.symbol_is_unused(symbol_id))
+            .collect();
+    }
+
+    pub fn remove_unused_imports(&self, program: &mut Program<'a>, scoping: &Scoping) {
+        if self.referenced_imports.is_empty() {
+            return;
+        }
+
+        let mut collector = ReferencedSymbols { scoping, symbols: FxHashSet::default() };
+        collector.visit_program(program);
+        let referenced_symbols = collector.symbols;
+
+        let is_removed = |specifier: &ImportDeclarationSpecifier<'a>| {
+            let symbol_id = specifier.local().symbol_id();
+            self.referenced_imports.contains(&symbol_id) && !referenced_symbols.contains(&symbol_id)
+        };
+
+        for stmt in &mut program.body {
+            let Statement::ImportDeclaration(decl) = stmt else { continue };
+            let Some(specifiers) = &mut decl.specifiers else { continue };
+            if specifiers.is_empty() || !specifiers.iter().any(is_removed) {
+                continue;
+            }
+            specifiers.retain(|specifier| !is_removed(specifier));
+            if specifiers.is_empty() {
+                decl.specifiers = None;
+            }
+        }
+    }
+}
+
+/// Collect symbols that are still referenced in the AST.
+struct ReferencedSymbols<'s> {
+    scoping: &'s Scoping,
+    symbols: FxHashSet<SymbolId>,
+}
+
+impl<'a> Visit<'a> for ReferencedSymbols<'_> {
+    fn visit_identifier_reference(&mut self, ident: &IdentifierReference<'a>) {
+        if let Some(symbol_id) = ident
+            .reference_id
+            .get()
+            .and_then(|reference_id| self.scoping.get_reference(reference_id).symbol_id())
+        {
+            self.symbols.insert(symbol_id);
+        }
+    }
+}
@@ -6,7 +6,7 @@ use oxc_ast::ast::*;
 use oxc_ecmascript::{
     StringCharAt, StringCharAtResult, StringCharCodeAt, StringIndexOf, StringLastIndexOf,
     StringSubstring, ToBigInt, ToInt32, ToIntegerIndex,
-    constant_evaluation::{ConstantEvaluation, DetermineValueType},
+    constant_evaluation::{ConstantEvaluation, ConstantValue, DetermineValueType},
     side_effects::MayHaveSideEffects,
 };
 use oxc_span::{Atom, SPAN, format_atom};
@@ -30,6 +30,7 @@ impl<'a> PeepholeOptimizations {
     ) {
         self.try_fold_concat_chain(node, state, ctx);
         self.try_fold_known_global_methods(node, state, ctx);
+        Self::try_fold_known_global_functions(node, state, ctx);
         self.try_fold_known_property_access(node, state, ctx);
     }
 
@@ -78,6 +79,15 @@ impl<'a> PeepholeOptimizations {
             }
             "min" | "max" => Self::try_fold_math_variadic(*span, arguments, name, object, ctx),
             "of" => Self::try_fold_array_of(*span, arguments, name, object, ctx),
+            "parseInt" | "parseFloat" => {
+                if !Self::validate_global_reference(object, "Number", ctx) {
+                    return;
+                }
+                Self::try_fold_parse_number(*span, arguments, name, ctx)
+            }
+            "isInteger" | "isSafeInteger" | "isFinite" | "isNaN" => {
+                Self::try_fold_number_predicate(*span, arguments, name, object, ctx)
+            }
             _ => None,
         };
         if let Some(replacement) = replacement {
@@ -86,6 +96,24 @@ impl<'a> PeepholeOptimizations {
         }
     }
 
+    /// `parseInt("ff", 16)` -> `255`, `parseFloat("1.5")` -> `1.5`
+    fn try_fold_known_global_functions(
+        node: &mut Expression<'a>,
+        state: &mut State,
+        ctx: &mut Ctx<'a, '_>,
+    ) {
+        let Expression::CallExpression(ce) = node else { return };
+        let Expression::Identifier(ident) = &ce.callee else { return };
+        let name = ident.name.as_str();
+        if !matches!(name, "parseInt" | "parseFloat") || !ctx.is_global_reference(ident) {
+            return;
+        }
+        if let Some(replacement) = Self::try_fold_parse_number(ce.span, &ce.arguments, name, ctx) {
+            state.changed = true;
+            *node = replacement;
+        }
+    }
+
     fn try_fold_string_casing(
         span: Span,
         args: &Arguments,
@@ -390,6 +418,7 @@ impl<'a> PeepholeOptimizations {
         (args.len() == expected_len) && args.iter().all(Argument::is_expression)
     }
 
+    /// `Math.pow(2, 8)` -> `256`
     /// `Math.pow(a, b)` -> `+(a) ** +b`
     fn try_fold_pow(
         &self,
@@ -398,14 +427,17 @@ impl<'a> PeepholeOptimizations {
         object: &Expression<'a>,
         ctx: &mut Ctx<'a, '_>,
     ) -> Option<Expression<'a>> {
-        if self.target < ESTarget::ES2016 {
-            return None;
-        }
         if !Self::validate_global_reference(object, "Math", ctx)
             || !Self::validate_arguments(arguments, 2)
         {
             return None;
         }
+        if let Some(result) = Self::try_evaluate_pow(span, arguments, ctx) {
+            return Some(result);
+        }
+        if self.target < ESTarget::ES2016 {
+            return None;
+        }
 
         let mut second_arg = arguments.pop().expect("checked len above");
         let second_arg = second_arg.to_expression_mut(); // checked above
@@ -429,6 +461,35 @@ impl<'a> PeepholeOptimizations {
         ))
     }
 
+    #[expect(clippy::float_cmp)]
+    fn try_evaluate_pow(
+        span: Span,
+        arguments: &Arguments<'a>,
+        ctx: &mut Ctx<'a, '_>,
+    ) -> Option<Expression<'a>> {
+        if !ctx.state.options.evaluate_known_globals {
+            return None;
+        }
+        let base = arguments[0].to_expression().get_side_free_number_value(ctx)?;
+        let exponent = arguments[1].to_expression().get_side_free_number_value(ctx)?;
+        // `f64::powf` returns `1` for these, but `Math.pow` returns `NaN`.
+        if exponent.is_nan() || (base.abs() == 1.0 && exponent.is_infinite()) {
+            return Some(ctx.value_to_expr(span, ConstantValue::Number(f64::NAN)));
+        }
+        let result = base.powf(exponent);
+        // Keep `a ** b` if the result is not an integer or is longer than it.
+        if !result.is_finite()
+            || result.fract() != 0.0
+            || Self::approximate_printed_int_char_count(result)
+                > Self::approximate_printed_int_char_count(base)
+                    + 2
+                    + Self::approximate_printed_int_char_count(exponent)
+        {
+            return None;
+        }
+        Some(ctx.value_to_expr(span, ConstantValue::Number(result)))
+    }
+
     /// `Math.sqrt(a)`, `Math.cbrt(a)`
     ///
     /// These cannot be replaced with `a ** .5`, `a ** (1/3)` because `Math.sqrt(-0)` returns `-0` where `(-0) ** .5` returns `0`.
@@ -557,6 +618,157 @@ impl<'a> PeepholeOptimizations {
         Some(ctx.ast.expression_numeric_literal(span, result, None, NumberBase::Decimal))
     }
 
+    /// `parseInt(a, b)`, `parseFloat(a)`, `Number.parseInt(a, b)`, `Number.parseFloat(a)`
+    fn try_fold_parse_number(
+        span: Span,
+        arguments: &Arguments<'a>,
+        name: &str,
+        ctx: &mut Ctx<'a, '_>,
+    ) -> Option<Expression<'a>> {
+        if !ctx.state.options.evaluate_known_globals {
+            return None;
+        }
+        let max_len = if name == "parseInt" { 2 } else { 1 };
+        if arguments.is_empty()
+            || arguments.len() > max_len
+            || !arguments.iter().all(Argument::is_expression)
+        {
+            return None;
+        }
+        let string = arguments[0].to_expression().get_side_free_string_value(ctx)?;
+        let result = if name == "parseInt" {
+            let radix = match arguments.get(1) {
+                Some(arg) => arg.to_expression().get_side_free_number_value(ctx)?.to_int_32(),
+                None => 0,
+            };
+            Self::parse_int(&string, radix)?
+        } else {
+            Self::parse_float(&string)
+        };
+        Some(ctx.value_to_expr(span, ConstantValue::Number(result)))
+    }
+
+    /// <https://tc39.es/ecma262/#sec-parseint-string-radix>
+    ///
+    /// Returns `None` if the result may not be exactly representable.
+    fn parse_int(string: &str, radix: i32) -> Option<f64> {
+        let mut s = string.trim_start_matches(Self::is_str_white_space_char);
+        let sign = if let Some(rest) = s.strip_prefix('-') {
+            s = rest;
+            -1.0
+        } else {
+            s = s.strip_prefix('+').unwrap_or(s);
+            1.0
+        };
+        let mut radix = radix;
+        let mut strip_prefix = true;
+        if radix != 0 {
+            if !(2..=36).contains(&radix) {
+                return Some(f64::NAN);
+            }
+            strip_prefix = radix == 16;
+        } else {
+            radix = 10;
+        }
+        if strip_prefix {
+            if let Some(rest) = s.strip_prefix("0x").or_else(|| s.strip_prefix("0X")) {
+                s = rest;
+                radix = 16;
+            }
+        }
+        #[expect(clippy::cast_sign_loss)]
+        let radix = radix as u32;
+        let digits = s.chars().map_while(|c| c.to_digit(radix)).collect::<Vec<_>>();
+        if digits.is_empty() {
+            return Some(f64::NAN);
+        }
+        let mut value: u64 = 0;
+        for digit in digits {
+            value = value.checked_mul(u64::from(radix))?.checked_add(u64::from(digit))?;
+        }
+        // Integers larger than 2^53 may not be exactly representable.
+        if value > (1 << 53) {
+            return None;
+        }
+        #[expect(clippy::cast_precision_loss)]
+        Some(sign * value as f64)
+    }
+
+    /// <https://tc39.es/ecma262/#sec-parsefloat-string>
+    fn parse_float(string: &str) -> f64 {
+        let s = string.trim_start_matches(Self::is_str_white_space_char);
+        let unsigned = s.strip_prefix(['+', '-']).unwrap_or(s);
+        if unsigned.starts_with("Infinity") {
+            return if s.starts_with('-') { f64::NEG_INFINITY } else { f64::INFINITY };
+        }
+        // Find the longest prefix that satisfies the syntax of a `StrDecimalLiteral`.
+        let bytes = s.as_bytes();
+        let count_digits =
+            |start: usize| bytes[start..].iter().take_while(|b| b.is_ascii_digit()).count();
+        let mut end = s.len() - unsigned.len();
+        let int_digits = count_digits(end);
+        end += int_digits;
+        let mut frac_digits = 0;
+        if bytes.get(end) == Some(&b'.') {
+            frac_digits = count_digits(end + 1);
+            if int_digits > 0 || frac_digits > 0 {
+                end += 1 + frac_digits;
+            }
+        }
+        if int_digits == 0 && frac_digits == 0 {
+            return f64::NAN;
+        }
+        if matches!(bytes.get(end), Some(b'e' | b'E')) {
+            let mut exponent_start = end + 1;
+            if matches!(bytes.get(exponent_start), Some(b'+' | b'-')) {
+                exponent_start += 1;
+            }
+            let exponent_digits = count_digits(exponent_start);
+            if exponent_digits > 0 {
+                end = exponent_start + exponent_digits;
+            }
+        }
+        s[..end].parse::<f64>().unwrap_or(f64::NAN)
+    }
+
+    /// `StrWhiteSpaceChar`: <https://tc39.es/ecma262/#prod-StrWhiteSpaceChar>
+    fn is_str_white_space_char(c: char) -> bool {
+        // `char::is_whitespace` includes U+0085 NEXT LINE, and excludes U+FEFF ZERO WIDTH NO-BREAK SPACE.
+        c == '\u{feff}' || (c != '\u{85}' && c.is_whitespace())
+    }
+
+    /// `Number.isInteger(1)` -> `true`
+    fn try_fold_number_predicate(
+        span: Span,
+        arguments: &Arguments<'a>,
+        name: &str,
+        object: &Expression<'a>,
+        ctx: &mut Ctx<'a, '_>,
+    ) -> Option<Expression<'a>> {
+        if !ctx.state.options.evaluate_known_globals
+            || !Self::validate_global_reference(object, "Number", ctx)
+            || !Self::validate_arguments(arguments, 1)
+        {
+            return None;
+        }
+        let arg = arguments[0].to_expression();
+        // These return `false` for non-number values without converting them.
+        if !arg.value_type(ctx).is_number() {
+            return None;
+        }
+        let value = arg.get_side_free_number_value(ctx)?;
+        let result = match name {
+            "isInteger" => value.is_finite() && value.fract() == 0.0,
+            "isSafeInteger" => {
+                value.is_finite() && value.fract() == 0.0 && value.abs() <= 9_007_199_254_740_991.0
+            }
+            "isFinite" => value.is_finite(),
+            "isNaN" => value.is_nan(),
+            _ => unreachable!(),
+        };
+        Some(ctx.ast.expression_boolean_literal(span, result))
+    }
+
     /// `[].concat(a).concat(b)` -> `[].concat(a, b)`
     /// `"".concat(a).concat(b)` -> `"".concat(a, b)`
     fn try_fold_concat_chain(
@@ -1009,7 +1221,7 @@ mod test {
 
     use crate::{
         CompressOptions,
-        tester::{run, test, test_same},
+        tester::{run, test, test_options, test_same, test_same_options},
     };
 
     fn test_es2015(code: &str, expected: &str) {
@@ -1965,7 +2177,9 @@ mod test {
 
     #[test]
     fn test_fold_pow() {
-        test("v = Math.pow(2, 3)", "v = 2 ** 3");
+        test("v = Math.pow(2, 3)", "v = 8");
+        test("v = Math.pow(2, 32)", "v = 2 ** 32");
+        test("v = Math.pow(2, .5)", "v = 2 ** .5");
         test("v = Math.pow(a, 3)", "v = a ** 3");
         test("v = Math.pow(2, b)", "v = 2 ** b");
         test("v = Math.pow(a, b)", "v = a ** +b");
@@ -1976,10 +2190,66 @@ mod test {
         test_same("v = Math.pow(...a, 1)");
         test_same("v = Math.pow(1, ...a)");
         test_same("v = Math.pow(1, 2, 3)");
-        test_es2015("v = Math.pow(2, 3)", "v = Math.pow(2, 3)");
+        test_es2015("v = Math.pow(2, 3)", "v = 8");
+        test_es2015("v = Math.pow(2, a)", "v = Math.pow(2, a)");
         test_same("v = Unknown.pow(1, 2)");
     }
 
+    #[test]
+    fn test_evaluate_known_globals() {
+        test_value("Math.pow(1, Infinity)", "NaN");
+        test_value("Math.pow(2, NaN)", "NaN");
+        test_value("Math.pow(-2, 3)", "-8");
+        test_value("Math.pow(2, 8) - 1", "255");
+
+        test_value("parseInt('123')", "123");
+        test_value("parseInt(' \\n123px')", "123");
+        test_value("parseInt('-0')", "-0");
+        test_value("parseInt('0xA')", "10");
+        test_value("parseInt('0xA', 16)", "10");
+        test_value("parseInt('0xA', 10)", "0");
+        test_value("parseInt('ff', 16)", "255");
+        test_value("parseInt('1111', 2)", "15");
+        test_value("parseInt('12', 13)", "15");
+        test_value("parseInt(15.99, 10)", "15");
+        test_value("parseInt('')", "NaN");
+        test_value("parseInt('1', 1)", "NaN");
+        test_value("Number.parseInt('12')", "12");
+        test_same_value("parseInt('123456789012345678901234567890')");
+        test_same_value("parseInt(a)");
+        test_same_value("parseInt('1', a)");
+        test_same_value("Number.parseInt(...a)");
+
+        test_value("parseFloat('1.5')", "1.5");
+        test_value("parseFloat(' 0.3333')", "0.3333");
+        test_value("parseFloat('-0')", "-0");
+        test_value("parseFloat('314e-2')", "3.14");
+        test_value("parseFloat('3.14more')", "3.14");
+        test_value("parseFloat('.5e')", ".5");
+        test_value("parseFloat('-Infinityx')", "-Infinity");
+        test_value("parseFloat('.')", "NaN");
+        test_value("Number.parseFloat('1.5')", "1.5");
+        test_same_value("parseFloat('1.5', a)");
+
+        test_value("Number.isInteger(1)", "!0");
+        test_value("Number.isInteger(1.5)", "!1");
+        test_value("Number.isSafeInteger(9007199254740991)", "!0");
+        test_value("Number.isSafeInteger(9007199254740992)", "!1");
+        test_value("Number.isFinite(Infinity)", "!1");
+        test_value("Number.isNaN(NaN)", "!0");
+        test_same_value("Number.isNaN('a')");
+        test_same_value("Number.isFinite(a)");
+
+        test_same("var parseInt; x = parseInt('1')");
+        test_same("var Number; x = Number.isNaN(1)");
+
+        let options =
+            CompressOptions { evaluate_known_globals: false, ..CompressOptions::default() };
+        test_options("x = Math.pow(2, 8)", "x = 2 ** 8", &options);
+        test_same_options("x = parseInt('1')", &options);
+        test_same_options("x = Number.isNaN(1)", &options);
+    }
+
     #[test]
     fn test_fold_roots() {
         test_same("v = Math.sqrt()");
@@ -40,7 +40,7 @@ mod test {
 
     #[test]
     fn fold_switch() {
-        test("a;b;c;switch(x){}", "switch(a,b,c,x){}");
+        test("a;b;c;switch(x){case y: z()}", "switch(a,b,c,x){case y: z()}");
     }
 
     #[test]
@@ -29,6 +29,8 @@ impl<'a> PeepholeOptimizations {
         state: &mut State,
         ctx: &mut Ctx<'a, '_>,
     ) {
+        self.try_convert_to_method(prop, state, ctx);
+
         // <https://tc39.es/ecma262/2024/multipage/ecmascript-language-expressions.html#sec-runtime-semantics-propertydefinitionevaluation>
         if !prop.method {
             if let PropertyKey::StringLiteral(str) = &prop.key {
@@ -199,6 +201,7 @@ impl<'a> PeepholeOptimizations {
             Expression::ClassExpression(e) => self.try_remove_name_from_classes(e, state, ctx),
             _ => {}
         }
+        self.try_convert_to_arrow_function(expr, state, ctx);
 
         // Fold
         if let Some(folded_expr) = match expr {
@@ -1023,11 +1026,10 @@ impl<'a> PeepholeOptimizations {
         state: &mut State,
         ctx: &mut Ctx<'a, '_>,
     ) {
-        if self.keep_names.function {
-            return;
-        }
-
-        if func.id.as_ref().is_some_and(|id| ctx.scoping().symbol_is_unused(id.symbol_id())) {
+        if func.id.as_ref().is_some_and(|id| {
+            !self.keep_names.keeps_function(&id.name)
+                && ctx.scoping().symbol_is_unused(id.symbol_id())
+        }) {
             func.id = None;
             state.changed = true;
         }
@@ -1044,11 +1046,9 @@ impl<'a> PeepholeOptimizations {
         state: &mut State,
         ctx: &mut Ctx<'a, '_>,
     ) {
-        if self.keep_names.class {
-            return;
-        }
-
-        if class.id.as_ref().is_some_and(|id| ctx.scoping().symbol_is_unused(id.symbol_id())) {
+        if class.id.as_ref().is_some_and(|id| {
+            !self.keep_names.keeps_class(&id.name) && ctx.scoping().symbol_is_unused(id.symbol_id())
+        }) {
             class.id = None;
             state.changed = true;
         }
@@ -1270,6 +1270,7 @@ where
 /// Port from <https://github.com/google/closure-compiler/blob/v20240609/test/com/google/javascript/jscomp/PeepholeSubstituteAlternateSyntaxTest.java>
 #[cfg(test)]
 mod test {
+    use lazy_regex::Regex;
     use oxc_syntax::es_target::ESTarget;
 
     use crate::{
@@ -1935,6 +1936,19 @@ mod test {
         test("var a = class C {}", "var a = class {}");
         test_same("var a = class C { foo() { return C } }");
         test_same_keep_names(CompressOptionsKeepNames::class_only(), "var a = class C {}");
+
+        let keep_names = CompressOptionsKeepNames {
+            function_regex: Some(Regex::new("^Use[A-Z]").unwrap()),
+            class_regex: Some(Regex::new("(Service|Provider)$").unwrap()),
+            ..CompressOptionsKeepNames::all_true()
+        };
+        test_same_keep_names(keep_names.clone(), "var a = function UseFoo() {}");
+        test_same_keep_names(keep_names.clone(), "var a = class FooService {}");
+        let result = run(
+            "var a = function f() {}; var b = class C {}",
+            Some(CompressOptions { keep_names, ..CompressOptions::smallest() }),
+        );
+        assert_eq!(result, run("var a = function () {}, b = class {}", None));
     }
 
     #[test]
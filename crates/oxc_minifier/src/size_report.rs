@@ -0,0 +1,328 @@
+use std::{cmp::Ordering, fmt};
+
+use rustc_hash::FxHashMap;
+
+use oxc_ast::ast::*;
+use oxc_ast_visit::{Visit, walk};
+use oxc_codegen::{Codegen, CodegenOptions, Context, Gen, GenExpr};
+use oxc_semantic::{ScopeFlags, Scoping};
+use oxc_span::Span;
+use oxc_syntax::precedence::Precedence;
+
+/// Breakdown of the minified size of a module,
+/// for finding out where the bytes of the output come from.
+///
+/// Created by [crate::Minifier::build] when [crate::MinifierOptions::report] is set.
+/// Sizes are in bytes. Code is printed without whitespace and comments.
+#[derive(Debug, Default, Clone)]
+pub struct SizeReport {
+    /// Size of the source text.
+    pub original_size: usize,
+
+    /// Size of the minified code.
+    pub minified_size: usize,
+
+    /// Size of the code after each pass, in the order the passes ran.
+    ///
+    /// The first entry is the unmodified program.
+    pub passes: Vec<PassSize>,
+
+    /// Functions of the source text, in order of appearance.
+    pub functions: Vec<FunctionSize>,
+
+    /// Top level declarations of the minified code, largest first.
+    pub symbols: Vec<SymbolSize>,
+}
+
+#[derive(Debug, Clone)]
+pub struct PassSize {
+    pub name: &'static str,
+    pub size: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct FunctionSize {
+    /// Name of the function, or of the variable, property or method it is assigned to.
+    pub name: Option<String>,
+
+    /// Span of the function in the source text.
+    pub span: Span,
+
+    pub original_size: usize,
+
+    /// `0` if the function was removed or inlined.
+    pub minified_size: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct SymbolSize {
+    /// Original name of the declared symbol, or `default` for default exports.
+    pub name: String,
+
+    pub minified_size: usize,
+}
+
+impl SizeReport {
+    /// Functions which remain in the minified code, largest first.
+    pub fn largest_functions(&self) -> Vec<&FunctionSize> {
+        let mut functions =
+            self.functions.iter().filter(|function| function.minified_size > 0).collect::<Vec<_>>();
+        functions.sort_by_key(|function| std::cmp::Reverse(function.minified_size));
+        functions
+    }
+}
+
+/// Number of entries printed for each list of a [SizeReport].
+const DISPLAY_LIMIT: usize = 10;
+
+impl fmt::Display for SizeReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Original size: {} bytes", self.original_size)?;
+        writeln!(f, "Minified size: {} bytes", self.minified_size)?;
+
+        writeln!(f, "\nPasses:")?;
+        let mut prev_size = None;
+        for pass in &self.passes {
+            write!(f, "  {:<20} {:>10}", pass.name, pass.size)?;
+            if let Some(prev_size) = prev_size {
+                let diff = match pass.size.cmp(&prev_size) {
+                    Ordering::Less => format!("-{}", prev_size - pass.size),
+                    Ordering::Equal => "0".to_string(),
+                    Ordering::Greater => format!("+{}", pass.size - prev_size),
+                };
+                write!(f, " {diff:>10}")?;
+            }
+            writeln!(f)?;
+            prev_size = Some(pass.size);
+        }
+
+        writeln!(f, "\nLargest functions:")?;
+        for function in self.largest_functions().into_iter().take(DISPLAY_LIMIT) {
+            writeln!(
+                f,
+                "  {:<30} {:>10} -> {:>10}  at {}..{}",
+                function.name.as_deref().unwrap_or("<anonymous>"),
+                function.original_size,
+                function.minified_size,
+                function.span.start,
+                function.span.end
+            )?;
+        }
+
+        writeln!(f, "\nLargest symbols:")?;
+        for symbol in self.symbols.iter().take(DISPLAY_LIMIT) {
+            writeln!(f, "  {:<30} {:>10}", symbol.name, symbol.minified_size)?;
+        }
+        Ok(())
+    }
+}
+
+/// Collects a [SizeReport] while a program is minified.
+pub struct SizeReportBuilder {
+    report: SizeReport,
+}
+
+impl SizeReportBuilder {
+    pub fn new(program: &Program<'_>) -> Self {
+        let mut collector = FunctionCollector::default();
+        collector.visit_program(program);
+        let report = SizeReport {
+            original_size: program.source_text.len(),
+            functions: collector.functions,
+            ..SizeReport::default()
+        };
+        let mut builder = Self { report };
+        builder.add_pass("original", program, None);
+        builder
+    }
+
+    /// Records the size of `program` after the pass `name`.
+    ///
+    /// `scoping` is required for printing mangled names.
+    pub fn add_pass(
+        &mut self,
+        name: &'static str,
+        program: &Program<'_>,
+        scoping: Option<&Scoping>,
+    ) {
+        let size = codegen(scoping).build(program).code.len();
+        self.report.passes.push(PassSize { name, size });
+    }
+
+    /// Records the sizes of the functions and symbols of the minified `program`.
+    pub fn build(mut self, program: &Program<'_>, scoping: Option<&Scoping>) -> SizeReport {
+        let mut codegen = codegen(scoping);
+
+        let mut printer = FunctionPrinter { codegen: &mut codegen, sizes: FxHashMap::default() };
+        printer.visit_program(program);
+        let sizes = printer.sizes;
+        for function in &mut self.report.functions {
+            function.minified_size = sizes.get(&function.span).copied().unwrap_or_default();
+        }
+
+        let mut symbols = vec![];
+        for stmt in &program.body {
+            match stmt {
+                Statement::ExportNamedDeclaration(decl) => {
+                    if let Some(decl) = &decl.declaration {
+                        add_symbols(decl, &mut codegen, &mut symbols);
+                    }
+                }
+                Statement::ExportDefaultDeclaration(decl) => {
+                    let minified_size =
+                        print_size(&mut codegen, |codegen| decl.print(codegen, Context::default()));
+                    symbols.push(SymbolSize { name: "default".to_string(), minified_size });
+                }
+                _ => {
+                    if let Some(decl) = stmt.as_declaration() {
+                        add_symbols(decl, &mut codegen, &mut symbols);
+                    }
+                }
+            }
+        }
+        symbols.sort_by_key(|symbol| std::cmp::Reverse(symbol.minified_size));
+        self.report.symbols = symbols;
+
+        self.report.minified_size = self.report.passes.last().map_or(0, |pass| pass.size);
+        self.report
+    }
+}
+
+fn add_symbols(decl: &Declaration<'_>, codegen: &mut Codegen, symbols: &mut Vec<SymbolSize>) {
+    match decl {
+        Declaration::FunctionDeclaration(func) => {
+            if let Some(id) = &func.id {
+                let minified_size = print_size(codegen, |codegen| {
+                    func.print(codegen, Context::default());
+                });
+                symbols.push(SymbolSize { name: id.name.to_string(), minified_size });
+            }
+        }
+        Declaration::ClassDeclaration(class) => {
+            if let Some(id) = &class.id {
+                let minified_size = print_size(codegen, |codegen| {
+                    class.print(codegen, Context::default());
+                });
+                symbols.push(SymbolSize { name: id.name.to_string(), minified_size });
+            }
+        }
+        Declaration::VariableDeclaration(decl) => {
+            for declarator in &decl.declarations {
+                let name = declarator
+                    .id
+                    .get_binding_identifiers()
+                    .iter()
+                    .map(|ident| ident.name.as_str())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let minified_size = print_size(codegen, |codegen| {
+                    declarator.print(codegen, Context::default());
+                });
+                symbols.push(SymbolSize { name, minified_size });
+            }
+        }
+        _ => {}
+    }
+}
+
+fn codegen<'a>(scoping: Option<&Scoping>) -> Codegen<'a> {
+    Codegen::new()
+        .with_options(CodegenOptions::minify())
+        .with_scoping(scoping.map(Scoping::clone_in_with_semantic_ids_with_another_arena))
+}
+
+/// Number of bytes printed by `print`.
+fn print_size(codegen: &mut Codegen, print: impl FnOnce(&mut Codegen)) -> usize {
+    let start = codegen.code_len();
+    print(codegen);
+    codegen.code_len() - start
+}
+
+/// Collects the functions of the source text, with the names they are assigned to.
+#[derive(Default)]
+struct FunctionCollector {
+    functions: Vec<FunctionSize>,
+    /// Name for the function visited next.
+    name: Option<String>,
+}
+
+impl FunctionCollector {
+    fn add(&mut self, name: Option<String>, span: Span) {
+        self.functions.push(FunctionSize {
+            name,
+            span,
+            original_size: span.size() as usize,
+            minified_size: 0,
+        });
+    }
+
+    /// Name the function `expr`, if it is one, after `name`.
+    fn set_name(&mut self, expr: &Expression<'_>, name: Option<&str>) {
+        if matches!(
+            expr.without_parentheses(),
+            Expression::FunctionExpression(_) | Expression::ArrowFunctionExpression(_)
+        ) {
+            self.name = name.map(str::to_string);
+        }
+    }
+}
+
+impl<'a> Visit<'a> for FunctionCollector {
+    fn visit_variable_declarator(&mut self, it: &VariableDeclarator<'a>) {
+        if let Some(init) = &it.init {
+            self.set_name(init, it.id.get_identifier_name().as_deref());
+        }
+        walk::walk_variable_declarator(self, it);
+    }
+
+    fn visit_object_property(&mut self, it: &ObjectProperty<'a>) {
+        self.set_name(&it.value, it.key.static_name().as_deref());
+        walk::walk_object_property(self, it);
+    }
+
+    fn visit_method_definition(&mut self, it: &MethodDefinition<'a>) {
+        self.name = it.key.static_name().map(|name| name.to_string());
+        walk::walk_method_definition(self, it);
+    }
+
+    fn visit_property_definition(&mut self, it: &PropertyDefinition<'a>) {
+        if let Some(value) = &it.value {
+            self.set_name(value, it.key.static_name().as_deref());
+        }
+        walk::walk_property_definition(self, it);
+    }
+
+    fn visit_function(&mut self, it: &Function<'a>, flags: ScopeFlags) {
+        let name = self.name.take();
+        self.add(it.id.as_ref().map(|id| id.name.to_string()).or(name), it.span);
+        walk::walk_function(self, it, flags);
+    }
+
+    fn visit_arrow_function_expression(&mut self, it: &ArrowFunctionExpression<'a>) {
+        let name = self.name.take();
+        self.add(name, it.span);
+        walk::walk_arrow_function_expression(self, it);
+    }
+}
+
+/// Prints the functions of the minified code, keyed by their span in the source text.
+struct FunctionPrinter<'a, 'c> {
+    codegen: &'c mut Codegen<'a>,
+    sizes: FxHashMap<Span, usize>,
+}
+
+impl<'a> Visit<'a> for FunctionPrinter<'_, '_> {
+    fn visit_function(&mut self, it: &Function<'a>, flags: ScopeFlags) {
+        let size = print_size(self.codegen, |codegen| it.print(codegen, Context::default()));
+        self.sizes.insert(it.span, size);
+        walk::walk_function(self, it, flags);
+    }
+
+    fn visit_arrow_function_expression(&mut self, it: &ArrowFunctionExpression<'a>) {
+        let size = print_size(self.codegen, |codegen| {
+            it.print_expr(codegen, Precedence::Lowest, Context::default());
+        });
+        self.sizes.insert(it.span, size);
+        walk::walk_arrow_function_expression(self, it);
+    }
+}
@@ -1,5 +1,5 @@
 use oxc_allocator::Allocator;
-use oxc_codegen::{Codegen, CodegenOptions};
+use oxc_codegen::{Codegen, CodegenOptions, QuoteStyle};
 use oxc_parser::{ParseOptions, Parser};
 use oxc_span::SourceType;
 
@@ -45,7 +45,7 @@ pub fn run(source_text: &str, options: Option<CompressOptions>) -> String {
     }
     Codegen::new()
         .with_options(CodegenOptions {
-            single_quote: true,
+            quote_style: QuoteStyle::Single,
             minify: false,
             ..CodegenOptions::default()
         })
@@ -0,0 +1,204 @@
+use rustc_hash::FxHashMap;
+
+use oxc_ast::ast::*;
+use oxc_ast_visit::Visit;
+use oxc_ecmascript::{
+    BoundNames,
+    is_global_reference::IsGlobalReference,
+    side_effects::{MayHaveSideEffects, MayHaveSideEffectsContext, PropertyReadSideEffects},
+};
+use oxc_semantic::{IsGlobalReference as _, Scoping, SymbolId};
+
+use crate::TreeShakeOptions;
+
+/// Statement level tree shaking of a module.
+///
+/// Finds the top level statements which are reachable from the exports of the module and from
+/// statements with side effects, using the side effect analysis of the compressor
+/// (including pure annotations and [TreeShakeOptions::manual_pure_functions]).
+///
+/// This is meant for bundlers which tree shake modules before minifying them.
+///
+/// ```ignore
+/// let scoping = SemanticBuilder::new().build(&program).semantic.into_scoping();
+/// let liveness = TreeShaker::new(TreeShakeOptions::default()).analyze(&program, &scoping);
+/// liveness.shake(&mut program);
+/// ```
+pub struct TreeShaker {
+    options: TreeShakeOptions,
+}
+
+/// Liveness of the top level statements of a program, returned by [TreeShaker::analyze].
+#[derive(Debug, Clone)]
+pub struct StatementLiveness {
+    live: Vec<bool>,
+}
+
+impl StatementLiveness {
+    /// Whether the statement at `index` in [Program::body] is live.
+    pub fn is_live(&self, index: usize) -> bool {
+        self.live[index]
+    }
+
+    /// Indices of the statements which are not live, in [Program::body].
+    ///
+    /// Dead import declarations are still needed for the side effects of the imported module,
+    /// unless the bundler knows the module has none.
+    pub fn dead_statements(&self) -> impl Iterator<Item = usize> + '_ {
+        self.live.iter().enumerate().filter(|(_, live)| !**live).map(|(index, _)| index)
+    }
+
+    /// Removes the dead statements of `program`, which must be the analyzed program.
+    ///
+    /// Dead import declarations are kept as bare `import 'mod'` declarations.
+    /// Symbols declared by the removed statements remain in the [Scoping] of the program.
+    pub fn shake(&self, program: &mut Program<'_>) {
+        let mut live = self.live.iter();
+        program.body.retain_mut(|stmt| {
+            if live.next().copied().unwrap_or(true) {
+                return true;
+            }
+            if let Statement::ImportDeclaration(decl) = stmt {
+                decl.specifiers = None;
+                return true;
+            }
+            false
+        });
+    }
+}
+
+impl TreeShaker {
+    pub fn new(options: TreeShakeOptions) -> Self {
+        Self { options }
+    }
+
+    /// Finds the live top level statements of `program`.
+    ///
+    /// A statement is live if it is an export, it may have side effects,
+    /// or it declares a symbol referenced by a live statement.
+    /// All statements of scripts are live.
+    pub fn analyze(&self, program: &Program<'_>, scoping: &Scoping) -> StatementLiveness {
+        // Direct `eval` can reference any top level symbol.
+        if scoping.root_scope_flags().contains_direct_eval() {
+            return StatementLiveness { live: vec![true; program.body.len()] };
+        }
+
+        // Top level declarations of scripts are globals, which may be used by other scripts.
+        let is_module = program.source_type.is_module();
+        let ctx = TreeShakeCtx { options: &self.options, scoping };
+        let mut declarations = FxHashMap::<SymbolId, Vec<usize>>::default();
+        let mut live = vec![false; program.body.len()];
+        let mut queue = vec![];
+        for (index, stmt) in program.body.iter().enumerate() {
+            let mut symbols = vec![];
+            if Self::is_root(stmt, &ctx, &mut symbols) || !is_module {
+                live[index] = true;
+                queue.push(index);
+            }
+            for symbol_id in symbols {
+                declarations.entry(symbol_id).or_default().push(index);
+            }
+        }
+
+        while let Some(index) = queue.pop() {
+            let mut collector = TopLevelReferenceCollector { scoping, symbols: vec![] };
+            collector.visit_statement(&program.body[index]);
+            for symbol_id in collector.symbols {
+                for &index in declarations.get(&symbol_id).into_iter().flatten() {
+                    if !live[index] {
+                        live[index] = true;
+                        queue.push(index);
+                    }
+                }
+            }
+        }
+        StatementLiveness { live }
+    }
+
+    /// Whether `stmt` is live regardless of other statements.
+    ///
+    /// Pushes the symbols declared by `stmt` to `symbols`.
+    fn is_root(stmt: &Statement<'_>, ctx: &TreeShakeCtx, symbols: &mut Vec<SymbolId>) -> bool {
+        match stmt {
+            Statement::VariableDeclaration(decl) => {
+                decl.bound_names(&mut |ident| symbols.push(ident.symbol_id()));
+                // Disposal of `using` declarations has side effects.
+                matches!(
+                    decl.kind,
+                    VariableDeclarationKind::Using | VariableDeclarationKind::AwaitUsing
+                ) || decl.declarations.iter().any(|declarator| {
+                    // Destructuring may call getters and iterators.
+                    !declarator.id.kind.is_binding_identifier()
+                        || declarator
+                            .init
+                            .as_ref()
+                            .is_some_and(|init| init.may_have_side_effects(ctx))
+                })
+            }
+            Statement::FunctionDeclaration(func) => {
+                symbols.extend(func.id.as_ref().map(BindingIdentifier::symbol_id));
+                false
+            }
+            Statement::ClassDeclaration(class) => {
+                symbols.extend(class.id.as_ref().map(BindingIdentifier::symbol_id));
+                class.may_have_side_effects(ctx)
+            }
+            Statement::ImportDeclaration(decl) => {
+                // Imports are kept for the side effects of the imported module
+                // when they import nothing.
+                let Some(specifiers) = &decl.specifiers else { return true };
+                symbols.extend(specifiers.iter().map(|specifier| specifier.local().symbol_id()));
+                specifiers.is_empty()
+            }
+            Statement::ExpressionStatement(stmt) => stmt.expression.may_have_side_effects(ctx),
+            Statement::EmptyStatement(_) => false,
+            _ => true,
+        }
+    }
+}
+
+/// Collects the references to top level symbols.
+struct TopLevelReferenceCollector<'s> {
+    scoping: &'s Scoping,
+    symbols: Vec<SymbolId>,
+}
+
+impl<'a> Visit<'a> for TopLevelReferenceCollector<'_> {
+    fn visit_identifier_reference(&mut self, it: &IdentifierReference<'a>) {
+        let Some(symbol_id) = self.scoping.get_reference(it.reference_id()).symbol_id() else {
+            return;
+        };
+        if self.scoping.symbol_scope_id(symbol_id) == self.scoping.root_scope_id() {
+            self.symbols.push(symbol_id);
+        }
+    }
+}
+
+struct TreeShakeCtx<'s> {
+    options: &'s TreeShakeOptions,
+    scoping: &'s Scoping,
+}
+
+impl<'a> IsGlobalReference<'a> for TreeShakeCtx<'_> {
+    fn is_global_reference(&self, ident: &IdentifierReference<'a>) -> Option<bool> {
+        Some(ident.is_global_reference(self.scoping))
+    }
+}
+
+impl MayHaveSideEffectsContext<'_> for TreeShakeCtx<'_> {
+    fn annotations(&self) -> bool {
+        self.options.annotations
+    }
+
+    fn manual_pure_functions(&self, callee: &Expression) -> bool {
+        self.options.is_manual_pure_function(callee)
+    }
+
+    fn property_read_side_effects(&self) -> PropertyReadSideEffects {
+        self.options.property_read_side_effects
+    }
+
+    fn unknown_global_side_effects(&self) -> bool {
+        self.options.unknown_global_side_effects
+    }
+}
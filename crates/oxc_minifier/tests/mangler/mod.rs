@@ -2,13 +2,24 @@ use std::fmt::Write;
 
 use oxc_allocator::Allocator;
 use oxc_codegen::Codegen;
-use oxc_mangler::{MangleOptions, MangleOptionsKeepNames, Mangler};
+use oxc_mangler::{
+    MangleAlphabet, MangleCache, MangleOptions, MangleOptionsKeepNames, ManglePropertiesOptions,
+    Mangler, PrivateMemberMangler, PropertyMangler, PropertyNameCache,
+};
+use oxc_minifier::{Minifier, MinifierOptions};
 use oxc_parser::Parser;
 use oxc_span::SourceType;
 
 fn mangle(source_text: &str, options: MangleOptions) -> String {
+    mangle_with_source_type(source_text, SourceType::mjs(), options)
+}
+
+fn mangle_with_source_type(
+    source_text: &str,
+    source_type: SourceType,
+    options: MangleOptions,
+) -> String {
     let allocator = Allocator::default();
-    let source_type = SourceType::mjs();
     let ret = Parser::new(&allocator, source_text, source_type).parse();
     let program = ret.program;
     let symbol_table = Mangler::new().with_options(options).build(&program);
@@ -23,6 +34,42 @@ fn direct_eval() {
     assert_eq!(mangled, "function foo() {\n\tlet NO_MANGLE;\n\teval(\"\");\n}\n");
 }
 
+#[test]
+fn alphabet() {
+    // Characters are ordered by their frequency in the source text, without the mangled names.
+    let source_text = "function foo(first, second) { return [first, second, xxx.xxx, xxx.zzzz] }";
+    let mangled = mangle(source_text, MangleOptions::default());
+    assert_eq!(
+        mangled,
+        "function foo(x, z) {\n\treturn [\n\t\tx,\n\t\tz,\n\t\txxx.xxx,\n\t\txxx.zzzz\n\t];\n}\n"
+    );
+
+    let options = MangleOptions {
+        alphabet: Some(MangleAlphabet::new("9Qk").unwrap()),
+        ..MangleOptions::default()
+    };
+    let mangled = mangle("function foo(a, b, c) { return [a, b, c] }", options);
+    assert_eq!(mangled, "function foo(Q, k, QQ) {\n\treturn [\n\t\tQ,\n\t\tk,\n\t\tQQ\n\t];\n}\n");
+}
+
+#[test]
+fn keep_names_regex() {
+    let source_text = "function foo() { function UseFoo() {} function bar() {} class FooService {} class Baz {} return [UseFoo, bar, FooService, Baz] }";
+    let options = MangleOptions {
+        keep_names: MangleOptionsKeepNames {
+            function_regex: Some(lazy_regex::Regex::new("^Use[A-Z]").unwrap()),
+            class_regex: Some(lazy_regex::Regex::new("(Service|Provider)$").unwrap()),
+            ..MangleOptionsKeepNames::all_true()
+        },
+        ..MangleOptions::default()
+    };
+    let mangled = mangle(source_text, options);
+    assert_eq!(
+        mangled,
+        "function foo() {\n\tfunction UseFoo() {}\n\tfunction n() {}\n\tclass FooService {}\n\tclass o {}\n\treturn [\n\t\tUseFoo,\n\t\tn,\n\t\tFooService,\n\t\to\n\t];\n}\n"
+    );
+}
+
 #[test]
 fn mangler() {
     let cases = [
@@ -68,6 +115,16 @@ fn mangler() {
         "function _() { class Foo { foo() { var x } } }",
         "function _() { var Foo = class { foo() { var x } } }",
     ];
+    let eval_cases = [
+        "function _() { let x; function foo() { let y; eval('') } function bar() { let z } }", // x, foo, y should not be mangled
+        "function _() { let x; { let y; eval('') } function bar() { let z; x } }", // z should not shadow x
+        "function _() { let x; with (o) { x } function bar() { let y } }", // x should not be mangled
+        "function _() { with (o) { var x = 1 } let y }", // x should not be mangled
+    ];
+    let reserved_cases = [
+        "function _() { let x, y, z }",              // x should not be mangled
+        "function _(a, b, c) { let d = a + b + c }", // a, b should not be used
+    ];
 
     let mut snapshot = String::new();
     cases.into_iter().fold(&mut snapshot, |w, case| {
@@ -89,7 +146,217 @@ fn mangler() {
         w
     });
 
+    // `with` is not allowed in strict mode
+    eval_cases.into_iter().fold(&mut snapshot, |w, case| {
+        let options = MangleOptions::default();
+        write!(w, "{case}\n{}\n", mangle_with_source_type(case, SourceType::cjs(), options))
+            .unwrap();
+        w
+    });
+    eval_cases.into_iter().fold(&mut snapshot, |w, case| {
+        let options = MangleOptions { eval: true, ..MangleOptions::default() };
+        write!(w, "{case}\n{}\n", mangle_with_source_type(case, SourceType::cjs(), options))
+            .unwrap();
+        w
+    });
+    reserved_cases.into_iter().fold(&mut snapshot, |w, case| {
+        let options = MangleOptions {
+            reserved: vec!["x".to_string(), "a".to_string(), "b".to_string()],
+            ..MangleOptions::default()
+        };
+        write!(w, "{case}\n{}\n", mangle(case, options)).unwrap();
+        w
+    });
+
     insta::with_settings!({ prepend_module_to_snapshot => false, omit_expression => true }, {
         insta::assert_snapshot!("mangler", snapshot);
     });
 }
+
+fn mangle_properties(
+    source_text: &str,
+    options: ManglePropertiesOptions,
+) -> (String, PropertyNameCache) {
+    let allocator = Allocator::default();
+    let source_type = SourceType::mjs();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    let mut program = ret.program;
+    let cache = PropertyMangler::new(options).build(&allocator, &mut program);
+    (Codegen::new().build(&program).code, cache)
+}
+
+#[test]
+fn properties() {
+    let cases = [
+        "a.foo = a.bar; a.foo; a.baz",
+        "x = { foo: 1, bar() {}, get baz() {}, [qux]: 1, 0: 1 }",
+        "class A { foo = 1; #bar; static baz() {} }",
+        "const { foo, bar: b, baz = 1 } = a",
+        "({ foo, bar: b, baz = 1 } = a)",
+        "x = { foo, bar }",
+        "a.constructor.prototype.then; a.length", // builtins are not mangled
+    ];
+    let regex_cases = [
+        "a._foo = a.bar; a._foo; a.baz_",
+        "x = { _foo: 1, bar: 2 }",
+        "a._foo = { _bar: 1 }; const { _bar } = a._foo",
+    ];
+    let quoted_cases = [
+        "a.foo; a['bar']; x = { 'baz': 1 }",
+        "a.foo; a['foo']", // foo is quoted somewhere
+    ];
+    let annotation_cases = [
+        "a._foo; a./* @__KEEP__ */ _bar; x = { _bar: 1 }",
+        "a._foo; a./* @__MANGLE_PROP__ */ bar; x = { bar: 1, baz: 2 }",
+        "x = { _foo: 1 }; Object.defineProperty(x, /* @__KEY__ */ '_foo', {}); y = '_foo'",
+        "x = { /* @__KEY__ */ '_foo': 1, /* @__MANGLE_PROP__ */ 'bar': 2, '_baz': 3 }; a.bar",
+        "a./* @__KEEP__ */ _foo; a./* @__MANGLE_PROP__ */ _foo", // keep wins
+    ];
+
+    let mut snapshot = String::new();
+    cases.into_iter().fold(&mut snapshot, |w, case| {
+        let options = ManglePropertiesOptions::default();
+        write!(w, "{case}\n{}\n", mangle_properties(case, options).0).unwrap();
+        w
+    });
+    regex_cases.into_iter().fold(&mut snapshot, |w, case| {
+        let options = ManglePropertiesOptions {
+            regex: Some(lazy_regex::Regex::new("^_|_$").unwrap()),
+            ..ManglePropertiesOptions::default()
+        };
+        write!(w, "{case}\n{}\n", mangle_properties(case, options).0).unwrap();
+        w
+    });
+    quoted_cases.into_iter().fold(&mut snapshot, |w, case| {
+        let options = ManglePropertiesOptions::default();
+        write!(w, "{case}\n{}\n", mangle_properties(case, options).0).unwrap();
+        w
+    });
+    quoted_cases.into_iter().fold(&mut snapshot, |w, case| {
+        let options = ManglePropertiesOptions { keep_quoted: true, ..Default::default() };
+        write!(w, "{case}\n{}\n", mangle_properties(case, options).0).unwrap();
+        w
+    });
+    annotation_cases.into_iter().fold(&mut snapshot, |w, case| {
+        let options = ManglePropertiesOptions {
+            regex: Some(lazy_regex::Regex::new("^_|_$").unwrap()),
+            keep_quoted: true,
+            ..ManglePropertiesOptions::default()
+        };
+        write!(w, "{case}\n{}\n", mangle_properties(case, options).0).unwrap();
+        w
+    });
+    cases.into_iter().take(1).fold(&mut snapshot, |w, case| {
+        let options = ManglePropertiesOptions {
+            reserved: vec!["bar".to_string()],
+            debug: true,
+            ..ManglePropertiesOptions::default()
+        };
+        write!(w, "{case}\n{}\n", mangle_properties(case, options).0).unwrap();
+        w
+    });
+
+    insta::with_settings!({ prepend_module_to_snapshot => false, omit_expression => true }, {
+        insta::assert_snapshot!("mangle_properties", snapshot);
+    });
+}
+
+#[test]
+fn properties_name_cache() {
+    let (_, cache) = mangle_properties("a.foo; a.bar; a.bar", ManglePropertiesOptions::default());
+    assert_eq!(cache.get("bar").map(String::as_str), Some("e"));
+    assert_eq!(cache.get("foo").map(String::as_str), Some("t"));
+
+    // Cached names are reused, and new names do not collide with them.
+    let options = ManglePropertiesOptions { name_cache: cache, ..Default::default() };
+    let (code, cache) = mangle_properties("a.foo; a.baz; a.baz; a.baz", options);
+    assert_eq!(code, "a.t;\na.n;\na.n;\na.n;\n");
+    assert_eq!(cache.len(), 3);
+}
+
+fn mangle_private_members(source_text: &str, options: &MangleOptions) -> String {
+    let allocator = Allocator::default();
+    let source_type = SourceType::mjs();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    let mut program = ret.program;
+    PrivateMemberMangler::new(options).build(&allocator, &mut program);
+    Codegen::new().build(&program).code
+}
+
+#[test]
+fn private_members() {
+    let cases = [
+        "class A { #foo = 1; #bar() { return this.#foo + this.#foo } get #baz() {} set #baz(v) {} }",
+        "class A { #foo; static has(a) { return #foo in a } }",
+        "class A { #foo; #bar; m() { this.#bar } } class B { #baz; m() { this.#baz } }",
+        "class A { #foo; m() { return class B { #foo; #bar; m(a) { a.#foo; a.#bar } } } }",
+        "class A { #foo; m() { return class B extends (class { #qux; m(a) { a.#foo } }) { #bar; m(a) { a.#foo; a.#bar } } } }",
+        "class A { #foo; m() { return eval('this.#foo') } }", // direct eval can access private names
+        "class A { #e = 1; m(s) { eval(s); return class B { #foo = 3; g(o) { return o.#e + this.#foo } } } }", // `#e` is not renamed, so `#foo` must not become `#e`
+    ];
+
+    let mut snapshot = String::new();
+    cases.into_iter().fold(&mut snapshot, |w, case| {
+        let options = MangleOptions::default();
+        write!(w, "{case}\n{}\n", mangle_private_members(case, &options)).unwrap();
+        w
+    });
+    cases.into_iter().take(1).fold(&mut snapshot, |w, case| {
+        let options = MangleOptions { debug: true, ..MangleOptions::default() };
+        write!(w, "{case}\n{}\n", mangle_private_members(case, &options)).unwrap();
+        w
+    });
+
+    insta::with_settings!({ prepend_module_to_snapshot => false, omit_expression => true }, {
+        insta::assert_snapshot!("mangle_private_members", snapshot);
+    });
+}
+
+fn mangle_chunks(chunks: &[&str], source_type: SourceType, cache: &mut MangleCache) -> Vec<String> {
+    let allocator = Allocator::default();
+    let mut programs = chunks
+        .iter()
+        .map(|source_text| Parser::new(&allocator, source_text, source_type).parse().program)
+        .collect::<Vec<_>>();
+    for program in &programs {
+        cache.collect_frequencies(program);
+    }
+    programs
+        .iter_mut()
+        .map(|program| {
+            let options = MinifierOptions {
+                mangle: Some(MangleOptions {
+                    top_level: true,
+                    properties: Some(ManglePropertiesOptions::default()),
+                    ..MangleOptions::default()
+                }),
+                compress: None,
+                report: false,
+            };
+            let ret = Minifier::new(options).build_with_cache(&allocator, program, cache);
+            Codegen::new().with_scoping(ret.scoping).build(program).code
+        })
+        .collect()
+}
+
+#[test]
+fn mangle_cache() {
+    // Property names are counted in all chunks, so `bar` is the most frequent name.
+    let mut cache = MangleCache::default();
+    let chunks = ["a.foo = 1; a.bar", "a.bar; a.bar; a.foo"];
+    let mangled = mangle_chunks(&chunks, SourceType::mjs(), &mut cache);
+    assert_eq!(mangled, ["a.t = 1;\na.e;\n", "a.e;\na.e;\na.t;\n"]);
+    assert_eq!(cache.properties.get("bar").map(String::as_str), Some("e"));
+
+    // Top level names of scripts are shared through the global scope.
+    let mut cache = MangleCache::default();
+    let chunks = ["var foo = 1; var bar = 2;", "var baz = foo; bar = 3;", "var qux = baz;"];
+    let mangled = mangle_chunks(&chunks, SourceType::cjs(), &mut cache);
+    assert_eq!(mangled, ["var r = 1;\nvar a = 2;\n", "var o = r;\na = 3;\n", "var v = o;\n"]);
+
+    // Globals referenced by a chunk keep their names when declared by a later chunk.
+    let mut cache = MangleCache::default();
+    let chunks = ["foo();", "function foo() {} var bar;"];
+    let mangled = mangle_chunks(&chunks, SourceType::cjs(), &mut cache);
+    assert_eq!(mangled, ["foo();\n", "function foo() {}\nvar n;\n"]);
+}
@@ -3,9 +3,11 @@
 mod ecmascript;
 mod mangler;
 mod peephole;
+mod size_report;
+mod tree_shaking;
 
 use oxc_allocator::Allocator;
-use oxc_codegen::{Codegen, CodegenOptions};
+use oxc_codegen::{Codegen, CodegenOptions, QuoteStyle};
 use oxc_minifier::{CompressOptions, Compressor};
 use oxc_parser::{ParseOptions, Parser};
 use oxc_span::SourceType;
@@ -44,7 +46,10 @@ pub(crate) fn run(
         Compressor::new(&allocator, options).build(&mut program);
     }
     Codegen::new()
-        .with_options(CodegenOptions { single_quote: true, ..CodegenOptions::default() })
+        .with_options(CodegenOptions {
+            quote_style: QuoteStyle::Single,
+            ..CodegenOptions::default()
+        })
         .build(&program)
         .code
 }
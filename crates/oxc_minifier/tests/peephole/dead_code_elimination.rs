@@ -253,3 +253,31 @@ fn dce_from_terser() {
         ",
     );
 }
+
+#[test]
+fn dce_unused_imports() {
+    let source_type = SourceType::mjs();
+    let test = |source_text: &str, expected: &str| {
+        let result = run(source_text, source_type, Some(CompressOptions::default()));
+        let expected = run(expected, source_type, None);
+        assert_eq!(
+            result, expected,
+            "\nfor source\n{source_text}\nexpect\n{expected}\ngot\n{result}"
+        );
+    };
+
+    test("import { devtools } from 'devtools'; if (false) { devtools() }", "import 'devtools'");
+    test("import { a, b } from 'mod'; if (false) { a() } b()", "import { b } from 'mod'; b()");
+    test("import a from 'mod'; false ? a() : foo()", "import 'mod'; foo()");
+    test("import * as ns from 'mod'; false && ns.foo()", "import 'mod'");
+    test(
+        "import { a } from 'mod'; if (false) { a() } else { a() }",
+        "import { a } from 'mod'; a()",
+    );
+
+    // Imports that were never referenced are kept.
+    test(
+        "import { a } from 'mod'; import 'side-effect'",
+        "import { a } from 'mod'; import 'side-effect'",
+    );
+}
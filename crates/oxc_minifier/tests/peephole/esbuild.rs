@@ -271,7 +271,7 @@ fn js_parser_test() {
     test("while(1) { while (1) {} }", "for (;;) for (;;) ;");
     test("while(1) { const x = y; }", "for (;;) { let x = y;}");
     test("while(1) { let x; }", "for (;;) { let x;}");
-    test("while(1) { var x; }", "for (;;) var x;");
+    test("while(1) { var x; }", "for (var x;;);");
     test("while(1) { class X {} }", "for (;;) { class X { }}");
     // test("while(1) { function x() {} }", "for (;;) var x = function() { };");
     test("while(1) { function* x() {} }", "for (;;) { function* x() { }}");
@@ -119,3 +119,28 @@ fn eval() {
     test_same("eval?.(x, y)");
     test_same("eval?.(x,y)");
 }
+
+#[test]
+fn compress_passes() {
+    let options = CompressOptions { sequences: false, ..CompressOptions::default() };
+    crate::test("a(); b(); c()", "a(); b(); c()", options.clone());
+    crate::test("function f() { a(); return b }", "function f() { a(); return b }", options);
+
+    let options = CompressOptions { conditionals: false, ..CompressOptions::default() };
+    crate::test("if (a) b(); else c()", "if (a) b(); else c()", options.clone());
+    crate::test("if (a) b()", "if (a) b()", options);
+
+    let options = CompressOptions { if_return: false, ..CompressOptions::default() };
+    crate::test(
+        "function f() { if (a) return b; return c }",
+        "function f() { if (a) return b; return c }",
+        options,
+    );
+
+    let options = CompressOptions { switches: false, ..CompressOptions::default() };
+    crate::test(
+        "switch (a) { case 1: b(); break; default: }",
+        "switch (a) { case 1: b(); break; default: }",
+        options,
+    );
+}
@@ -0,0 +1,69 @@
+use oxc_allocator::Allocator;
+use oxc_minifier::{Minifier, MinifierOptions, SizeReport};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+
+fn report(source_text: &str, options: MinifierOptions) -> SizeReport {
+    let allocator = Allocator::default();
+    let mut program = Parser::new(&allocator, source_text, SourceType::mjs()).parse().program;
+    let options = MinifierOptions { report: true, ..options };
+    Minifier::new(options).build(&allocator, &mut program).report.unwrap()
+}
+
+#[test]
+fn size_report() {
+    let source_text = "
+        export function longFunctionName(parameter) {
+            if (parameter) { return parameter * 2; } else { return 0; }
+        }
+        if (false) { f(function dropped() { return 1 }); }
+        const obj = { method() { return 'value'; }, arrow: () => 1 + 2 };
+        export default obj;
+    ";
+    let report = report(source_text, MinifierOptions::default());
+
+    assert_eq!(report.original_size, source_text.len());
+    let passes = report.passes.iter().map(|pass| pass.name).collect::<Vec<_>>();
+    assert_eq!(
+        passes,
+        ["original", "normalize", "peephole", "late_peephole", "mangle_private_members", "mangle"]
+    );
+    assert_eq!(report.minified_size, report.passes.last().unwrap().size);
+    assert!(report.minified_size < report.passes[0].size);
+
+    let functions = report
+        .functions
+        .iter()
+        .map(|function| (function.name.as_deref(), function.minified_size > 0))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        functions,
+        [
+            (Some("longFunctionName"), true),
+            (Some("dropped"), false),
+            (Some("method"), true),
+            (Some("arrow"), true),
+        ]
+    );
+    let function = &report.functions[0];
+    assert_eq!(function.original_size, function.span.size() as usize);
+    // `function longFunctionName(e){return e?e*2:0}`
+    assert_eq!(function.minified_size, 44);
+
+    let symbols = report.symbols.iter().map(|symbol| symbol.name.as_str()).collect::<Vec<_>>();
+    assert_eq!(symbols, ["longFunctionName", "obj", "default"]);
+
+    let printed = report.to_string();
+    assert!(printed.contains("Largest functions:\n  longFunctionName"), "{printed}");
+}
+
+#[test]
+fn size_report_without_mangle() {
+    let report = report(
+        "console.log(1 + 2)",
+        MinifierOptions { mangle: None, ..MinifierOptions::default() },
+    );
+    let passes = report.passes.iter().map(|pass| pass.name).collect::<Vec<_>>();
+    assert_eq!(passes, ["original", "normalize", "peephole", "late_peephole"]);
+    assert_eq!(report.minified_size, "console.log(3);".len());
+}
@@ -0,0 +1,102 @@
+use oxc_allocator::Allocator;
+use oxc_codegen::Codegen;
+use oxc_minifier::{TreeShakeOptions, TreeShaker};
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+
+#[track_caller]
+fn test_with_options(source_text: &str, expected: &str, options: TreeShakeOptions) {
+    let allocator = Allocator::default();
+    let mut program = Parser::new(&allocator, source_text, SourceType::mjs()).parse().program;
+    let scoping = SemanticBuilder::new().build(&program).semantic.into_scoping();
+    let liveness = TreeShaker::new(options).analyze(&program, &scoping);
+    liveness.shake(&mut program);
+    let code = Codegen::new().build(&program).code;
+    let expected = Codegen::new()
+        .build(&Parser::new(&allocator, expected, SourceType::mjs()).parse().program)
+        .code;
+    assert_eq!(code, expected, "\nfor source\n{source_text}");
+}
+
+#[track_caller]
+fn test(source_text: &str, expected: &str) {
+    test_with_options(source_text, expected, TreeShakeOptions::default());
+}
+
+#[track_caller]
+fn test_same(source_text: &str) {
+    test(source_text, source_text);
+}
+
+#[test]
+fn unused_declarations() {
+    test("const a = 1; function f() {} class C {} export const b = 2;", "export const b = 2;");
+    test("const a = 1; const b = a; export { b };", "const a = 1; const b = a; export { b };");
+    test(
+        "function f() { return g() } function g() {} export default f",
+        "function f() { return g() } function g() {} export default f",
+    );
+    test("function f() { g() } function g() { f() } export {}", "export {}");
+    test("import { a, b } from 'x'; export { a };", "import { a, b } from 'x'; export { a };");
+    test(
+        "import a from 'x'; import b from 'y'; export default a;",
+        "import a from 'x'; import 'y'; export default a;",
+    );
+    test("import 'x'; import {} from 'y';", "import 'x'; import {} from 'y';");
+}
+
+#[test]
+fn dead_imports() {
+    let allocator = Allocator::default();
+    let source_text = "import a from 'x'; import { b } from 'y'; export { a };";
+    let program = Parser::new(&allocator, source_text, SourceType::mjs()).parse().program;
+    let scoping = SemanticBuilder::new().build(&program).semantic.into_scoping();
+    let liveness = TreeShaker::new(TreeShakeOptions::default()).analyze(&program, &scoping);
+    assert_eq!(liveness.dead_statements().collect::<Vec<_>>(), vec![1]);
+}
+
+#[test]
+fn side_effects() {
+    test_same("const a = f();");
+    test_same("let a = 1; a = 2;");
+    test("const a = g(); const b = 1; f(a);", "const a = g(); f(a);");
+    test("class C { static { f() } }", "class C { static { f() } }");
+    test("class C { static x = 1 } 1;", "");
+    test_same("const { a } = b;");
+    test_same("if (x) { var a = 1 }");
+}
+
+#[test]
+fn pure_annotations() {
+    test("const a = /* @__PURE__ */ f(); const b = /* #__PURE__ */ new C();", "");
+    test_same("const a = /* @__PURE__ */ f(g());");
+    test_with_options(
+        "const a = /* @__PURE__ */ f();",
+        "const a = /* @__PURE__ */ f();",
+        TreeShakeOptions { annotations: false, ..TreeShakeOptions::default() },
+    );
+    test_with_options(
+        "const a = pure(1); const b = impure(2);",
+        "const b = impure(2);",
+        TreeShakeOptions {
+            manual_pure_functions: vec!["pure".to_string()],
+            ..TreeShakeOptions::default()
+        },
+    );
+}
+
+#[test]
+fn direct_eval() {
+    test_same("const a = 1; eval('a');");
+}
+
+#[test]
+fn script() {
+    let allocator = Allocator::default();
+    let source_text = "var a = 1; function f() {}";
+    let program = Parser::new(&allocator, source_text, SourceType::cjs()).parse().program;
+    let scoping = SemanticBuilder::new().build(&program).semantic.into_scoping();
+    let liveness = TreeShaker::new(TreeShakeOptions::default()).analyze(&program, &scoping);
+    assert_eq!(liveness.dead_statements().count(), 0);
+}
@@ -90,6 +90,7 @@ use oxc_allocator::{Allocator, Box as ArenaBox, Dummy};
 use oxc_ast::{
     AstBuilder,
     ast::{Expression, Program},
+    pragma::Pragmas,
 };
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_span::{ModuleKind, SourceType, Span};
@@ -551,10 +552,10 @@ impl<'a> ParserImpl<'a> {
         if !self.source_type.is_javascript() {
             return None;
         }
-        let span = self.lexer.trivia_builder.comments.first()?.span;
-        if span.source_text(self.source_text).contains("@flow") {
+        let first_comment = self.lexer.trivia_builder.comments.first()?;
+        if Pragmas::from_comments(std::slice::from_ref(first_comment), self.source_text).flow {
             self.errors.clear();
-            Some(diagnostics::flow(span))
+            Some(diagnostics::flow(first_comment.span))
         } else {
             None
         }
@@ -633,6 +634,12 @@ mod test {
             assert_eq!(ret.errors.len(), 1);
             assert_eq!(ret.errors.first().unwrap().to_string(), "Flow is not supported");
         }
+
+        // `@flow` must be a pragma in the first comment
+        for source in ["/* @flowtype */ super;", "/* a */ /* @flow */ super;"] {
+            let ret = Parser::new(&allocator, source, source_type).parse();
+            assert!(!ret.is_flow_language);
+        }
     }
 
     #[test]
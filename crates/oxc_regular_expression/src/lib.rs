@@ -2,6 +2,7 @@
 
 mod ast_impl;
 mod diagnostics;
+mod matcher;
 mod options;
 mod parser;
 mod surrogate_pair;
@@ -16,6 +17,7 @@ mod generated {
 pub mod ast;
 pub use crate::{
     ast_impl::visit,
+    matcher::{Match, MatchError, Matcher, MatcherFlags},
     options::Options,
     parser::{ConstructorParser, LiteralParser},
 };
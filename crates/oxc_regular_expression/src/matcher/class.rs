@@ -0,0 +1,193 @@
+use crate::ast::{CharacterClassContentsKind, CharacterClassEscapeKind};
+
+/// Set of characters matched by a character class, a class escape or a property escape.
+#[derive(Debug)]
+pub struct ClassSet {
+    pub negative: bool,
+    pub kind: CharacterClassContentsKind,
+    pub items: Vec<ClassItem>,
+}
+
+#[derive(Debug)]
+pub enum ClassItem {
+    Range(u32, u32),
+    Escape(CharacterClassEscapeKind),
+    Property(Property),
+    Nested(ClassSet),
+}
+
+/// Unicode properties which can be decided by the standard library.
+#[derive(Debug, Clone, Copy)]
+pub struct Property {
+    pub negative: bool,
+    pub kind: PropertyKind,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum PropertyKind {
+    Any,
+    Ascii,
+    AsciiHexDigit,
+    Alphabetic,
+    Lowercase,
+    Uppercase,
+    WhiteSpace,
+    Number,
+    Control,
+}
+
+impl PropertyKind {
+    /// `\p{name}` or `\p{name=value}`, `None` if the property is not supported.
+    pub fn from_name(name: &str, value: Option<&str>) -> Option<Self> {
+        let name = match (name, value) {
+            ("General_Category" | "gc", Some(value)) => value,
+            (_, Some(_)) => return None,
+            (name, None) => name,
+        };
+        Some(match name {
+            "Any" => Self::Any,
+            "ASCII" => Self::Ascii,
+            "ASCII_Hex_Digit" | "AHex" => Self::AsciiHexDigit,
+            "Alphabetic" | "Alpha" => Self::Alphabetic,
+            "Lowercase" | "Lower" => Self::Lowercase,
+            "Uppercase" | "Upper" => Self::Uppercase,
+            "White_Space" | "space" => Self::WhiteSpace,
+            "Number" | "N" => Self::Number,
+            "Control" | "Cc" | "cntrl" => Self::Control,
+            _ => return None,
+        })
+    }
+
+    fn contains(self, cp: u32) -> bool {
+        if matches!(self, Self::Any) {
+            return true;
+        }
+        let Some(c) = char::from_u32(cp) else { return false };
+        match self {
+            Self::Any => true,
+            Self::Ascii => c.is_ascii(),
+            Self::AsciiHexDigit => c.is_ascii_hexdigit(),
+            Self::Alphabetic => c.is_alphabetic(),
+            Self::Lowercase => c.is_lowercase(),
+            Self::Uppercase => c.is_uppercase(),
+            Self::WhiteSpace => c.is_whitespace(),
+            Self::Number => c.is_numeric(),
+            Self::Control => c.is_control(),
+        }
+    }
+}
+
+impl ClassSet {
+    pub fn escape(kind: CharacterClassEscapeKind) -> Self {
+        Self::union(vec![ClassItem::Escape(kind)])
+    }
+
+    pub fn property(property: Property) -> Self {
+        Self::union(vec![ClassItem::Property(property)])
+    }
+
+    fn union(items: Vec<ClassItem>) -> Self {
+        Self { negative: false, kind: CharacterClassContentsKind::Union, items }
+    }
+
+    /// Whether the set contains `cp`, ignoring the negation of this set.
+    pub fn contains_positive(&self, cp: u32, unicode_ignore_case: bool) -> bool {
+        let mut items = self.items.iter();
+        match self.kind {
+            CharacterClassContentsKind::Union => {
+                items.any(|item| item.contains(cp, unicode_ignore_case))
+            }
+            CharacterClassContentsKind::Intersection => {
+                items.all(|item| item.contains(cp, unicode_ignore_case))
+            }
+            CharacterClassContentsKind::Subtraction => {
+                items.next().is_some_and(|item| item.contains(cp, unicode_ignore_case))
+                    && !items.any(|item| item.contains(cp, unicode_ignore_case))
+            }
+        }
+    }
+
+    pub fn contains(&self, cp: u32, unicode_ignore_case: bool) -> bool {
+        self.contains_positive(cp, unicode_ignore_case) != self.negative
+    }
+}
+
+impl ClassItem {
+    fn contains(&self, cp: u32, unicode_ignore_case: bool) -> bool {
+        match self {
+            Self::Range(min, max) => (*min..=*max).contains(&cp),
+            Self::Escape(kind) => match kind {
+                CharacterClassEscapeKind::D => is_digit(cp),
+                CharacterClassEscapeKind::NegativeD => !is_digit(cp),
+                CharacterClassEscapeKind::S => is_white_space(cp),
+                CharacterClassEscapeKind::NegativeS => !is_white_space(cp),
+                CharacterClassEscapeKind::W => is_word_character(cp, unicode_ignore_case),
+                CharacterClassEscapeKind::NegativeW => !is_word_character(cp, unicode_ignore_case),
+            },
+            Self::Property(property) => property.kind.contains(cp) != property.negative,
+            Self::Nested(set) => set.contains(cp, unicode_ignore_case),
+        }
+    }
+}
+
+fn is_digit(cp: u32) -> bool {
+    (u32::from(b'0')..=u32::from(b'9')).contains(&cp)
+}
+
+/// `WhiteSpace` and `LineTerminator` of the spec, matched by `\s`.
+fn is_white_space(cp: u32) -> bool {
+    matches!(
+        cp,
+        0x09..=0x0d
+            | 0x20
+            | 0xa0
+            | 0x1680
+            | 0x2000..=0x200a
+            | 0x2028
+            | 0x2029
+            | 0x202f
+            | 0x205f
+            | 0x3000
+            | 0xfeff
+    )
+}
+
+pub fn is_line_terminator(cp: u32) -> bool {
+    matches!(cp, 0x0a | 0x0d | 0x2028 | 0x2029)
+}
+
+/// `\w`, which also contains `ſ` and `K` (Kelvin sign) when ignoring case in unicode mode,
+/// as they fold to `s` and `k`.
+pub fn is_word_character(cp: u32, unicode_ignore_case: bool) -> bool {
+    char::from_u32(cp).is_some_and(|c| c.is_ascii_alphanumeric() || c == '_')
+        || (unicode_ignore_case && matches!(cp, 0x017f | 0x212a))
+}
+
+/// `Canonicalize` of the spec, approximating simple case folding with the standard case mappings.
+pub fn canonicalize(cp: u32, unicode: bool) -> u32 {
+    let Some(c) = char::from_u32(cp) else { return cp };
+    if unicode {
+        // Folding maps to lowercase, but some characters only fold via their uppercase, e.g. `ſ`
+        let upper = single(c.to_uppercase()).unwrap_or(c);
+        return single(upper.to_lowercase()).or_else(|| single(c.to_lowercase())).unwrap_or(c)
+            as u32;
+    }
+    match single(c.to_uppercase()) {
+        // Non-ASCII characters never match ASCII ones, e.g. `ſ` and `s`
+        Some(upper) if !(cp >= 128 && upper.is_ascii()) => upper as u32,
+        _ => cp,
+    }
+}
+
+/// Characters which are candidates for having the same canonical form as `cp`.
+pub fn case_variants(cp: u32) -> [u32; 3] {
+    let Some(c) = char::from_u32(cp) else { return [cp; 3] };
+    let lower = single(c.to_lowercase()).unwrap_or(c);
+    let upper = single(c.to_uppercase()).unwrap_or(c);
+    [cp, lower as u32, upper as u32]
+}
+
+fn single(mut chars: impl Iterator<Item = char>) -> Option<char> {
+    let c = chars.next()?;
+    chars.next().is_none().then_some(c)
+}
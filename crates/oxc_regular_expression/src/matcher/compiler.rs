@@ -0,0 +1,440 @@
+use rustc_hash::FxHashMap;
+
+use crate::{
+    ast::*,
+    visit::{Visit, walk},
+};
+
+use super::{
+    MatchError,
+    class::{ClassItem, ClassSet, Property, PropertyKind, canonicalize},
+};
+
+/// Upper bound of the program size, to refuse patterns like `(a{1000}){1000}`.
+const MAX_PROGRAM_SIZE: usize = 100_000;
+
+#[derive(Debug)]
+pub enum Inst {
+    /// Match a single character, canonicalized if `ignore_case`.
+    Char {
+        value: u32,
+        ignore_case: bool,
+        backward: bool,
+    },
+    /// `.`
+    Any {
+        dot_all: bool,
+        backward: bool,
+    },
+    /// Match a single character in `Program::classes[class]`.
+    Class {
+        class: usize,
+        ignore_case: bool,
+        backward: bool,
+    },
+    Assert {
+        kind: BoundaryAssertionKind,
+        multiline: bool,
+    },
+    /// Continue at the first target, backtracking to the second.
+    Split(usize, usize),
+    Jump(usize),
+    /// Store the current position in a capture slot.
+    Save(usize),
+    /// Clear the capture slots in the range, at the start of a quantifier iteration.
+    ResetCaptures(usize, usize),
+    /// Store the current position in a register.
+    SetRegister(usize),
+    /// Fail if the current position is still the one stored in the register,
+    /// so an iteration of a quantifier can't match the empty string.
+    CheckProgress(usize),
+    /// Match the text of the first participating group in `Program::references[reference]`.
+    BackReference {
+        reference: usize,
+        ignore_case: bool,
+        backward: bool,
+    },
+    /// Lookaround whose body starts at the next instruction, continuing at `end` if it succeeds.
+    Look {
+        negative: bool,
+        end: usize,
+    },
+    LookMatch,
+    Match,
+}
+
+#[derive(Debug)]
+pub struct Program {
+    pub insts: Vec<Inst>,
+    pub classes: Vec<ClassSet>,
+    pub references: Vec<Vec<usize>>,
+    pub register_count: usize,
+    /// Number of capturing groups, including the whole match.
+    pub capture_count: usize,
+    /// Group names with their indices. A name can be used by several groups in different alternatives.
+    pub names: Vec<(String, Vec<usize>)>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Modes {
+    ignore_case: bool,
+    multiline: bool,
+    dot_all: bool,
+    backward: bool,
+}
+
+pub struct Compiler {
+    program: Program,
+    /// Capturing groups by address, to number them in source order in lookbehinds too.
+    groups: FxHashMap<usize, usize>,
+    unicode: bool,
+    modes: Modes,
+}
+
+impl Compiler {
+    pub fn compile(
+        pattern: &Pattern<'_>,
+        ignore_case: bool,
+        multiline: bool,
+        dot_all: bool,
+        unicode: bool,
+    ) -> Result<Program, MatchError> {
+        let mut collector = GroupCollector::default();
+        collector.visit_pattern(pattern);
+
+        let mut compiler = Self {
+            program: Program {
+                insts: vec![],
+                classes: vec![],
+                references: vec![],
+                register_count: 0,
+                capture_count: collector.groups.len() + 1,
+                names: collector.names,
+            },
+            groups: collector.groups.into_iter().enumerate().map(|(i, g)| (g, i + 1)).collect(),
+            unicode,
+            modes: Modes { ignore_case, multiline, dot_all, backward: false },
+        };
+        compiler.emit(Inst::Save(0));
+        compiler.compile_disjunction(&pattern.body)?;
+        compiler.emit(Inst::Save(1));
+        compiler.emit(Inst::Match);
+        Ok(compiler.program)
+    }
+
+    fn pc(&self) -> usize {
+        self.program.insts.len()
+    }
+
+    fn emit(&mut self, inst: Inst) -> usize {
+        self.program.insts.push(inst);
+        self.pc() - 1
+    }
+
+    fn compile_disjunction(&mut self, disjunction: &Disjunction<'_>) -> Result<(), MatchError> {
+        let Some((last, rest)) = disjunction.body.split_last() else { return Ok(()) };
+        let mut jumps = vec![];
+        for alternative in rest {
+            let split = self.emit(Inst::Split(0, 0));
+            self.compile_alternative(alternative)?;
+            jumps.push(self.emit(Inst::Jump(0)));
+            self.program.insts[split] = Inst::Split(split + 1, self.pc());
+        }
+        self.compile_alternative(last)?;
+        let end = self.pc();
+        for jump in jumps {
+            self.program.insts[jump] = Inst::Jump(end);
+        }
+        Ok(())
+    }
+
+    fn compile_alternative(&mut self, alternative: &Alternative<'_>) -> Result<(), MatchError> {
+        // Lookbehinds match from right to left
+        if self.modes.backward {
+            alternative.body.iter().rev().try_for_each(|term| self.compile_term(term))
+        } else {
+            alternative.body.iter().try_for_each(|term| self.compile_term(term))
+        }
+    }
+
+    fn compile_term(&mut self, term: &Term<'_>) -> Result<(), MatchError> {
+        if self.pc() > MAX_PROGRAM_SIZE {
+            return Err(MatchError::Unsupported);
+        }
+        let Modes { ignore_case, multiline, dot_all, backward } = self.modes;
+        match term {
+            Term::BoundaryAssertion(assertion) => {
+                self.emit(Inst::Assert { kind: assertion.kind, multiline });
+            }
+            Term::LookAroundAssertion(assertion) => {
+                let (negative, lookbehind) = match assertion.kind {
+                    LookAroundAssertionKind::Lookahead => (false, false),
+                    LookAroundAssertionKind::NegativeLookahead => (true, false),
+                    LookAroundAssertionKind::Lookbehind => (false, true),
+                    LookAroundAssertionKind::NegativeLookbehind => (true, true),
+                };
+                let look = self.emit(Inst::Look { negative, end: 0 });
+                self.modes.backward = lookbehind;
+                self.compile_disjunction(&assertion.body)?;
+                self.modes.backward = backward;
+                self.emit(Inst::LookMatch);
+                self.program.insts[look] = Inst::Look { negative, end: self.pc() };
+            }
+            Term::Quantifier(quantifier) => self.compile_quantifier(quantifier)?,
+            Term::Character(character) => {
+                let value = if ignore_case {
+                    canonicalize(character.value, self.unicode)
+                } else {
+                    character.value
+                };
+                self.emit(Inst::Char { value, ignore_case, backward });
+            }
+            Term::Dot(_) => {
+                self.emit(Inst::Any { dot_all, backward });
+            }
+            Term::CharacterClassEscape(escape) => {
+                self.emit_class(ClassSet::escape(escape.kind));
+            }
+            Term::UnicodePropertyEscape(escape) => {
+                let property = Self::property(escape)?;
+                self.emit_class(ClassSet::property(property));
+            }
+            Term::CharacterClass(class) => self.compile_class(class)?,
+            Term::CapturingGroup(group) => {
+                let index = self.groups[&(std::ptr::from_ref(&**group) as usize)];
+                let (first, second) =
+                    if backward { (index * 2 + 1, index * 2) } else { (index * 2, index * 2 + 1) };
+                self.emit(Inst::Save(first));
+                self.compile_disjunction(&group.body)?;
+                self.emit(Inst::Save(second));
+            }
+            Term::IgnoreGroup(group) => {
+                if let Some(modifiers) = &group.modifiers {
+                    for (modifier, enabled) in
+                        [(modifiers.enabling, true), (modifiers.disabling, false)]
+                    {
+                        if modifier.contains(Modifier::I) {
+                            self.modes.ignore_case = enabled;
+                        }
+                        if modifier.contains(Modifier::M) {
+                            self.modes.multiline = enabled;
+                        }
+                        if modifier.contains(Modifier::S) {
+                            self.modes.dot_all = enabled;
+                        }
+                    }
+                }
+                self.compile_disjunction(&group.body)?;
+                self.modes = Modes { ignore_case, multiline, dot_all, backward };
+            }
+            Term::IndexedReference(reference) => {
+                let index = reference.index as usize;
+                if index >= self.program.capture_count {
+                    return Err(MatchError::Unsupported);
+                }
+                self.emit_back_reference(vec![index]);
+            }
+            Term::NamedReference(reference) => {
+                let Some((_, indices)) =
+                    self.program.names.iter().find(|(name, _)| name == reference.name.as_str())
+                else {
+                    return Err(MatchError::Unsupported);
+                };
+                self.emit_back_reference(indices.clone());
+            }
+        }
+        Ok(())
+    }
+
+    fn compile_quantifier(&mut self, quantifier: &Quantifier<'_>) -> Result<(), MatchError> {
+        let groups = self.group_range(&quantifier.body);
+        let min = usize::try_from(quantifier.min).map_err(|_| MatchError::Unsupported)?;
+        let max = quantifier
+            .max
+            .map(|max| usize::try_from(max).map_err(|_| MatchError::Unsupported))
+            .transpose()?;
+        if min.max(max.unwrap_or(0)) > MAX_PROGRAM_SIZE {
+            return Err(MatchError::Unsupported);
+        }
+
+        for _ in 0..min {
+            self.compile_iteration(&quantifier.body, groups, None)?;
+        }
+        let register = self.program.register_count;
+        self.program.register_count += 1;
+        if let Some(max) = max {
+            let mut splits = vec![];
+            for _ in min..max {
+                splits.push(self.emit(Inst::Split(0, 0)));
+                self.compile_iteration(&quantifier.body, groups, Some(register))?;
+            }
+            let end = self.pc();
+            for split in splits {
+                self.program.insts[split] = Self::split(quantifier.greedy, split + 1, end);
+            }
+        } else {
+            let split = self.emit(Inst::Split(0, 0));
+            self.compile_iteration(&quantifier.body, groups, Some(register))?;
+            self.emit(Inst::Jump(split));
+            self.program.insts[split] = Self::split(quantifier.greedy, split + 1, self.pc());
+        }
+        Ok(())
+    }
+
+    fn split(greedy: bool, body: usize, end: usize) -> Inst {
+        if greedy { Inst::Split(body, end) } else { Inst::Split(end, body) }
+    }
+
+    /// A single iteration of a quantifier, with the groups inside it reset.
+    /// Optional iterations must not match the empty string.
+    fn compile_iteration(
+        &mut self,
+        body: &Term<'_>,
+        groups: Option<(usize, usize)>,
+        register: Option<usize>,
+    ) -> Result<(), MatchError> {
+        if let Some(register) = register {
+            self.emit(Inst::SetRegister(register));
+        }
+        if let Some((first, last)) = groups {
+            self.emit(Inst::ResetCaptures(first * 2, last * 2 + 2));
+        }
+        self.compile_term(body)?;
+        if let Some(register) = register {
+            self.emit(Inst::CheckProgress(register));
+        }
+        Ok(())
+    }
+
+    fn compile_class(&mut self, class: &CharacterClass<'_>) -> Result<(), MatchError> {
+        let mut strings = vec![];
+        let set = Self::class_set(class, &mut strings)?;
+        if strings.is_empty() {
+            self.emit_class(set);
+            return Ok(());
+        }
+
+        // A class containing strings matches the longest string first, then single characters
+        strings.sort_by_key(|string| std::cmp::Reverse(string.len()));
+        let Modes { ignore_case, backward, .. } = self.modes;
+        let mut jumps = vec![];
+        for string in strings {
+            let split = self.emit(Inst::Split(0, 0));
+            let chars: Box<dyn Iterator<Item = &u32>> =
+                if backward { Box::new(string.iter().rev()) } else { Box::new(string.iter()) };
+            for &value in chars {
+                let value = if ignore_case { canonicalize(value, self.unicode) } else { value };
+                self.emit(Inst::Char { value, ignore_case, backward });
+            }
+            jumps.push(self.emit(Inst::Jump(0)));
+            self.program.insts[split] = Inst::Split(split + 1, self.pc());
+        }
+        self.emit_class(set);
+        let end = self.pc();
+        for jump in jumps {
+            self.program.insts[jump] = Inst::Jump(end);
+        }
+        Ok(())
+    }
+
+    /// Set of the single characters of `class`. Strings of `\q{...}` are pushed to `strings`,
+    /// which is only supported for unions.
+    fn class_set(
+        class: &CharacterClass<'_>,
+        strings: &mut Vec<Vec<u32>>,
+    ) -> Result<ClassSet, MatchError> {
+        if class.strings && class.kind != CharacterClassContentsKind::Union {
+            return Err(MatchError::Unsupported);
+        }
+        let mut items = vec![];
+        for contents in &class.body {
+            items.push(match contents {
+                CharacterClassContents::CharacterClassRange(range) => {
+                    ClassItem::Range(range.min.value, range.max.value)
+                }
+                CharacterClassContents::CharacterClassEscape(escape) => {
+                    ClassItem::Escape(escape.kind)
+                }
+                CharacterClassContents::UnicodePropertyEscape(escape) => {
+                    ClassItem::Property(Self::property(escape)?)
+                }
+                CharacterClassContents::Character(character) => {
+                    ClassItem::Range(character.value, character.value)
+                }
+                CharacterClassContents::NestedCharacterClass(class) => {
+                    ClassItem::Nested(Self::class_set(class, strings)?)
+                }
+                CharacterClassContents::ClassStringDisjunction(disjunction) => {
+                    let mut nested = vec![];
+                    for string in &disjunction.body {
+                        if let [character] = string.body.as_slice() {
+                            nested.push(ClassItem::Range(character.value, character.value));
+                        } else {
+                            strings.push(string.body.iter().map(|c| c.value).collect());
+                        }
+                    }
+                    ClassItem::Nested(ClassSet {
+                        negative: false,
+                        kind: CharacterClassContentsKind::Union,
+                        items: nested,
+                    })
+                }
+            });
+        }
+        Ok(ClassSet { negative: class.negative, kind: class.kind, items })
+    }
+
+    fn property(escape: &UnicodePropertyEscape<'_>) -> Result<Property, MatchError> {
+        if escape.strings {
+            return Err(MatchError::Unsupported);
+        }
+        let kind = PropertyKind::from_name(&escape.name, escape.value.as_deref())
+            .ok_or(MatchError::Unsupported)?;
+        Ok(Property { negative: escape.negative, kind })
+    }
+
+    fn emit_class(&mut self, set: ClassSet) {
+        let Modes { ignore_case, backward, .. } = self.modes;
+        self.program.classes.push(set);
+        let class = self.program.classes.len() - 1;
+        self.emit(Inst::Class { class, ignore_case, backward });
+    }
+
+    fn emit_back_reference(&mut self, indices: Vec<usize>) {
+        let Modes { ignore_case, backward, .. } = self.modes;
+        self.program.references.push(indices);
+        let reference = self.program.references.len() - 1;
+        self.emit(Inst::BackReference { reference, ignore_case, backward });
+    }
+
+    /// Indices of the first and last capturing group inside `term`.
+    fn group_range(&self, term: &Term<'_>) -> Option<(usize, usize)> {
+        let mut collector = GroupCollector::default();
+        collector.visit_term(term);
+        let first = self.groups[collector.groups.first()?];
+        let last = self.groups[collector.groups.last()?];
+        Some((first, last))
+    }
+}
+
+/// Collects capturing groups in source order.
+#[derive(Default)]
+struct GroupCollector {
+    groups: Vec<usize>,
+    names: Vec<(String, Vec<usize>)>,
+}
+
+impl<'a> Visit<'a> for GroupCollector {
+    fn visit_capturing_group(&mut self, it: &CapturingGroup<'a>) {
+        self.groups.push(std::ptr::from_ref(it) as usize);
+        if let Some(name) = &it.name {
+            let index = self.groups.len();
+            if let Some((_, indices)) = self.names.iter_mut().find(|(n, _)| n == name.as_str()) {
+                indices.push(index);
+            } else {
+                self.names.push((name.to_string(), vec![index]));
+            }
+        }
+        walk::walk_capturing_group(self, it);
+    }
+}
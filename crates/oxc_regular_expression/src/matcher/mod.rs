@@ -0,0 +1,390 @@
+//! Matching of a parsed [`Pattern`] against a string, following the semantics of `RegExp.prototype.exec`.
+//!
+//! This is a backtracking interpreter meant for lint-time checks of literal strings against
+//! literal regular expressions, not a general purpose engine:
+//! - The number of steps is bounded, so catastrophic backtracking ends with
+//!   [`MatchError::StepLimitExceeded`] instead of hanging.
+//! - Case folding is approximated with the standard library's case mappings.
+//! - Only the Unicode properties which can be decided without Unicode data tables are supported,
+//!   e.g. `\p{ASCII}` or `\p{Lowercase}`. Other properties make [`Matcher::new`] return
+//!   [`MatchError::Unsupported`].
+
+mod class;
+mod compiler;
+mod vm;
+
+use std::ops::Range;
+
+use crate::ast::Pattern;
+
+use compiler::{Compiler, Program};
+use vm::{Input, Vm};
+
+/// Default upper bound of interpreted instructions for a single [`Matcher::exec`].
+const DEFAULT_STEP_LIMIT: usize = 1_000_000;
+
+/// Flags of the regular expression which affect matching.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct MatcherFlags {
+    /// `i`
+    pub ignore_case: bool,
+    /// `m`
+    pub multiline: bool,
+    /// `s`
+    pub dot_all: bool,
+    /// `u` or `v`
+    pub unicode: bool,
+    /// `y`
+    pub sticky: bool,
+}
+
+impl MatcherFlags {
+    /// Flags from the text after the closing `/` of a literal, e.g. `gimsu`.
+    /// Flags which don't affect matching and unknown flags are ignored.
+    pub fn from_flags_text(flags_text: &str) -> Self {
+        let mut flags = Self::default();
+        for c in flags_text.chars() {
+            match c {
+                'i' => flags.ignore_case = true,
+                'm' => flags.multiline = true,
+                's' => flags.dot_all = true,
+                'u' | 'v' => flags.unicode = true,
+                'y' => flags.sticky = true,
+                _ => {}
+            }
+        }
+        flags
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MatchError {
+    /// The pattern uses a feature which the matcher can't evaluate, e.g. an unsupported Unicode property.
+    Unsupported,
+    /// Matching didn't finish within the step limit.
+    StepLimitExceeded,
+}
+
+/// Compiled pattern, which can be matched against strings.
+///
+/// ```ignore
+/// let pattern = LiteralParser::new(&allocator, r"(?<year>\d{4})-\d\d", None, Options::default()).parse()?;
+/// let matcher = Matcher::new(&pattern, MatcherFlags::default())?;
+/// assert_eq!(matcher.exec("on 2024-05", 0)?.unwrap().named("year"), Some(3..7));
+/// ```
+#[derive(Debug)]
+pub struct Matcher {
+    program: Program,
+    flags: MatcherFlags,
+    step_limit: usize,
+}
+
+impl Matcher {
+    pub fn new(pattern: &Pattern<'_>, flags: MatcherFlags) -> Result<Self, MatchError> {
+        let program = Compiler::compile(
+            pattern,
+            flags.ignore_case,
+            flags.multiline,
+            flags.dot_all,
+            flags.unicode,
+        )?;
+        Ok(Self { program, flags, step_limit: DEFAULT_STEP_LIMIT })
+    }
+
+    /// Override the upper bound of interpreted instructions for a single [`Matcher::exec`].
+    #[must_use]
+    pub fn with_step_limit(mut self, step_limit: usize) -> Self {
+        self.step_limit = step_limit;
+        self
+    }
+
+    /// Number of capturing groups, not counting the whole match.
+    pub fn group_count(&self) -> usize {
+        self.program.capture_count - 1
+    }
+
+    /// Names of the named capturing groups, in source order, without duplicates.
+    pub fn group_names(&self) -> impl Iterator<Item = &str> {
+        self.program.names.iter().map(|(name, _)| name.as_str())
+    }
+
+    /// Find the first match starting at or after `last_index`, like `RegExp.prototype.exec`.
+    /// With the sticky flag, the match must start at `last_index`.
+    ///
+    /// `last_index` and the ranges of the [`Match`] are offsets in UTF-16 code units.
+    pub fn exec(&self, text: &str, last_index: usize) -> Result<Option<Match>, MatchError> {
+        let input = Input::new(text, self.flags.unicode);
+        let offsets = Utf16Offsets::new(&input);
+        let Some(start) = offsets.index_of(last_index) else { return Ok(None) };
+
+        let unicode_ignore_case = self.flags.unicode && self.flags.ignore_case;
+        let mut vm = Vm::new(&self.program, &input, unicode_ignore_case, self.step_limit);
+        let end = if self.flags.sticky { start } else { input.chars.len() };
+        for pos in start..=end {
+            if vm.run_at(pos)? {
+                let captures = vm
+                    .slots
+                    .chunks(2)
+                    .map(|slots| match slots {
+                        [Some(start), Some(end)] => {
+                            Some(offsets.offset_of(*start)..offsets.offset_of(*end))
+                        }
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>();
+                let names = self
+                    .program
+                    .names
+                    .iter()
+                    .map(|(name, indices)| {
+                        // Only one of the groups with the same name can participate
+                        let index = indices
+                            .iter()
+                            .copied()
+                            .find(|&index| captures[index].is_some())
+                            .unwrap_or(indices[0]);
+                        (name.clone(), index)
+                    })
+                    .collect();
+                return Ok(Some(Match { captures, names }));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Whether the pattern matches anywhere in `text`, like `RegExp.prototype.test` with `lastIndex` 0.
+    pub fn is_match(&self, text: &str) -> Result<bool, MatchError> {
+        Ok(self.exec(text, 0)?.is_some())
+    }
+}
+
+/// Result of [`Matcher::exec`], with ranges in UTF-16 code units.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Match {
+    captures: Vec<Option<Range<usize>>>,
+    names: Vec<(String, usize)>,
+}
+
+impl Match {
+    /// Range of the whole match.
+    pub fn range(&self) -> Range<usize> {
+        // The whole match always participates
+        self.group(0).unwrap_or_default()
+    }
+
+    /// Range of the capturing group `index`, where `0` is the whole match.
+    /// `None` if the group didn't participate in the match.
+    pub fn group(&self, index: usize) -> Option<Range<usize>> {
+        self.captures.get(index).cloned().flatten()
+    }
+
+    /// Range of the capturing group called `name`.
+    pub fn named(&self, name: &str) -> Option<Range<usize>> {
+        let (_, index) = self.names.iter().find(|(n, _)| n == name)?;
+        self.group(*index)
+    }
+
+    /// Number of capturing groups, not counting the whole match.
+    pub fn group_count(&self) -> usize {
+        self.captures.len() - 1
+    }
+}
+
+/// Conversion between indices of [`Input::chars`] and UTF-16 offsets.
+struct Utf16Offsets {
+    /// UTF-16 offset of each character in unicode mode, `None` if the characters are UTF-16 code units.
+    offsets: Option<Vec<usize>>,
+    len: usize,
+}
+
+impl Utf16Offsets {
+    fn new(input: &Input) -> Self {
+        if !input.unicode {
+            return Self { offsets: None, len: input.chars.len() };
+        }
+        let mut offset = 0;
+        let mut offsets = Vec::with_capacity(input.chars.len() + 1);
+        for &c in &input.chars {
+            offsets.push(offset);
+            offset += if c > 0xffff { 2 } else { 1 };
+        }
+        offsets.push(offset);
+        Self { offsets: Some(offsets), len: input.chars.len() }
+    }
+
+    /// Index of the character at the UTF-16 `offset`, rounding up inside a surrogate pair.
+    fn index_of(&self, offset: usize) -> Option<usize> {
+        match &self.offsets {
+            None => (offset <= self.len).then_some(offset),
+            Some(offsets) => offsets.iter().position(|&o| o >= offset),
+        }
+    }
+
+    fn offset_of(&self, index: usize) -> usize {
+        self.offsets.as_ref().map_or(index, |offsets| offsets[index])
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use oxc_allocator::Allocator;
+
+    use crate::{LiteralParser, Options};
+
+    use super::{MatchError, Matcher, MatcherFlags};
+
+    fn exec(
+        pattern: &str,
+        flags: &str,
+        text: &str,
+    ) -> Result<Option<Vec<Option<String>>>, MatchError> {
+        let allocator = Allocator::default();
+        let pattern = LiteralParser::new(&allocator, pattern, Some(flags), Options::default())
+            .parse()
+            .unwrap();
+        let matcher = Matcher::new(&pattern, MatcherFlags::from_flags_text(flags))?;
+        let utf16 = text.encode_utf16().collect::<Vec<_>>();
+        Ok(matcher.exec(text, 0)?.map(|m| {
+            (0..=m.group_count())
+                .map(|i| m.group(i).map(|r| String::from_utf16(&utf16[r]).unwrap()))
+                .collect()
+        }))
+    }
+
+    fn groups(pattern: &str, flags: &str, text: &str) -> Option<Vec<Option<String>>> {
+        exec(pattern, flags, text).unwrap()
+    }
+
+    fn expected(groups: &[Option<&str>]) -> Vec<Option<String>> {
+        groups.iter().map(|g| g.map(String::from)).collect()
+    }
+
+    #[test]
+    fn is_match() {
+        for (pattern, flags, text, expected) in [
+            ("abc", "", "xabcx", true),
+            ("abc", "", "ab", false),
+            ("^abc$", "", "abc", true),
+            ("^abc$", "", "xabc", false),
+            ("^b", "m", "a\nb", true),
+            ("a$", "m", "a\nb", true),
+            ("^b", "", "a\nb", false),
+            ("a.c", "", "a\nc", false),
+            ("a.c", "s", "a\nc", true),
+            (r"\bfoo\b", "", "a foo.", true),
+            (r"\bfoo\b", "", "afoo", false),
+            (r"\Bfoo", "", "afoo", true),
+            (r"\d+\s\w+", "", "12 ab", true),
+            (r"[^\d]", "", "123", false),
+            ("[a-c]+x", "", "bcax", true),
+            ("ABC", "i", "abc", true),
+            ("[a-z]", "i", "Q", true),
+            ("[^a-z]", "i", "Q", false),
+            ("\u{212A}", "i", "k", false),
+            ("\u{212A}", "iu", "k", true),
+            (r"\w", "iu", "\u{17f}", true),
+            (r"\w", "i", "\u{17f}", false),
+            ("colou?r", "", "color", true),
+            ("a{2,3}", "", "a", false),
+            ("a{2,3}", "", "aa", true),
+            ("(?=a)b", "", "ab", false),
+            ("a(?=b)", "", "ab", true),
+            ("a(?!b)", "", "ab", false),
+            ("(?<=a)b", "", "ab", true),
+            ("(?<!a)b", "", "ab", false),
+            ("(?i:a)b", "", "Ab", true),
+            ("(?i:a)b", "", "AB", false),
+            (r"\p{Lowercase}", "u", "A", false),
+            (r"\P{Lowercase}", "u", "A", true),
+            (r"^.$", "", "😀", false),
+            (r"^.$", "u", "😀", true),
+            (r"[\q{abc|d}]x", "v", "abcx", true),
+            (r"[[a-z]--[aeiou]]", "v", "e", false),
+            (r"[[a-z]&&[aeiou]]", "v", "e", true),
+        ] {
+            assert_eq!(
+                groups(pattern, flags, text).is_some(),
+                expected,
+                "/{pattern}/{flags} on {text:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn captures() {
+        assert_eq!(groups("(a)|(b)", "", "b"), Some(expected(&[Some("b"), None, Some("b")])));
+        assert_eq!(
+            groups("(a+)(a*)", "", "aaa"),
+            Some(expected(&[Some("aaa"), Some("aaa"), Some("")]))
+        );
+        assert_eq!(
+            groups("(a+?)(a*)", "", "aaa"),
+            Some(expected(&[Some("aaa"), Some("a"), Some("aa")]))
+        );
+        // Captures are reset in each iteration
+        assert_eq!(groups("(?:(a)|b)+", "", "ab"), Some(expected(&[Some("ab"), None])));
+        // Empty iterations end the loop
+        assert_eq!(groups("(a*)*", "", "b"), Some(expected(&[Some(""), None])));
+        assert_eq!(groups("(a*)+", "", "b"), Some(expected(&[Some(""), Some("")])));
+        // Lookbehinds match from right to left, so greedy groups take the most on the left
+        assert_eq!(
+            groups(r"(?<=(\d+)(\d+))$", "", "1053"),
+            Some(expected(&[Some(""), Some("1"), Some("053")]))
+        );
+        assert_eq!(groups("(?=(a+))a*b", "", "aaab"), Some(expected(&[Some("aaab"), Some("aaa")])));
+        assert_eq!(groups("(?!(a))b", "", "b"), Some(expected(&[Some("b"), None])));
+    }
+
+    #[test]
+    fn back_references() {
+        assert_eq!(groups(r"(a|b)\1", "", "abb"), Some(expected(&[Some("bb"), Some("b")])));
+        assert_eq!(groups(r"(a)\1", "i", "aA"), Some(expected(&[Some("aA"), Some("a")])));
+        assert_eq!(groups(r"\1(a)", "", "a"), Some(expected(&[Some("a"), Some("a")])));
+        assert_eq!(
+            groups(r"(?<q>['\x22]).*?\k<q>", "", r#"say "hi" now"#).unwrap()[0].as_deref(),
+            Some(r#""hi""#)
+        );
+        assert_eq!(groups(r"(?<=\1(a))b", "", "aab"), Some(expected(&[Some("b"), Some("a")])));
+        assert_eq!(
+            groups(r"(?:(?<x>a)|(?<x>b))\k<x>", "", "bb"),
+            Some(expected(&[Some("bb"), None, Some("b")]))
+        );
+    }
+
+    #[test]
+    fn offsets() {
+        let allocator = Allocator::default();
+        let pattern =
+            LiteralParser::new(&allocator, r"(?<year>\d{4})-\d\d", None, Options::default())
+                .parse()
+                .unwrap();
+        let matcher = Matcher::new(&pattern, MatcherFlags::default()).unwrap();
+        assert_eq!(matcher.group_count(), 1);
+        assert_eq!(matcher.group_names().collect::<Vec<_>>(), ["year"]);
+        let m = matcher.exec("on 2024-05 and 2025-06", 0).unwrap().unwrap();
+        assert_eq!(m.range(), 3..10);
+        assert_eq!(m.named("year"), Some(3..7));
+        assert_eq!(m.named("month"), None);
+        assert_eq!(matcher.exec("on 2024-05 and 2025-06", 4).unwrap().unwrap().range(), 15..22);
+
+        let pattern =
+            LiteralParser::new(&allocator, "b", Some("uy"), Options::default()).parse().unwrap();
+        let matcher = Matcher::new(&pattern, MatcherFlags::from_flags_text("uy")).unwrap();
+        assert_eq!(matcher.exec("😀b", 0).unwrap(), None);
+        assert_eq!(matcher.exec("😀b", 2).unwrap().unwrap().range(), 2..3);
+    }
+
+    #[test]
+    fn errors() {
+        assert_eq!(exec(r"\p{Script=Greek}", "u", "α"), Err(MatchError::Unsupported));
+        assert_eq!(exec(r"\p{RGI_Emoji}", "v", "😀"), Err(MatchError::Unsupported));
+
+        let allocator = Allocator::default();
+        let pattern =
+            LiteralParser::new(&allocator, "(a+)+b", None, Options::default()).parse().unwrap();
+        let matcher =
+            Matcher::new(&pattern, MatcherFlags::default()).unwrap().with_step_limit(10_000);
+        assert_eq!(matcher.exec(&"a".repeat(30), 0), Err(MatchError::StepLimitExceeded));
+        assert_eq!(matcher.is_match("aab"), Ok(true));
+    }
+}
@@ -0,0 +1,265 @@
+use crate::ast::BoundaryAssertionKind;
+
+use super::{
+    MatchError,
+    class::{canonicalize, case_variants, is_line_terminator, is_word_character},
+    compiler::{Inst, Program},
+};
+
+/// Characters of the input: code points in unicode mode, UTF-16 code units otherwise.
+pub struct Input {
+    pub chars: Vec<u32>,
+    pub unicode: bool,
+}
+
+impl Input {
+    pub fn new(text: &str, unicode: bool) -> Self {
+        let chars = if unicode {
+            text.chars().map(|c| c as u32).collect()
+        } else {
+            text.encode_utf16().map(u32::from).collect()
+        };
+        Self { chars, unicode }
+    }
+
+    /// The character after (or before if `backward`) `pos`, and the position past it.
+    fn step(&self, pos: usize, backward: bool) -> Option<(u32, usize)> {
+        if backward {
+            let pos = pos.checked_sub(1)?;
+            Some((self.chars[pos], pos))
+        } else {
+            self.chars.get(pos).map(|&c| (c, pos + 1))
+        }
+    }
+}
+
+enum Frame {
+    Branch { pc: usize, pos: usize },
+    Slot { slot: usize, value: Option<usize> },
+    Register { register: usize, value: usize },
+}
+
+pub struct Vm<'p> {
+    program: &'p Program,
+    input: &'p Input,
+    unicode_ignore_case: bool,
+    steps: usize,
+    step_limit: usize,
+    pub slots: Vec<Option<usize>>,
+    registers: Vec<usize>,
+}
+
+impl<'p> Vm<'p> {
+    pub fn new(
+        program: &'p Program,
+        input: &'p Input,
+        unicode_ignore_case: bool,
+        step_limit: usize,
+    ) -> Self {
+        Self {
+            program,
+            input,
+            unicode_ignore_case,
+            steps: 0,
+            step_limit,
+            slots: vec![None; program.capture_count * 2],
+            registers: vec![0; program.register_count],
+        }
+    }
+
+    /// Match the program at `pos`, leaving the captures in `slots` on success.
+    pub fn run_at(&mut self, pos: usize) -> Result<bool, MatchError> {
+        self.slots.fill(None);
+        Ok(self.run(0, pos)?.is_some())
+    }
+
+    /// Run from `pc` until `Match` or `LookMatch`, returning the position reached.
+    /// The captures are left as they were if there is no match.
+    fn run(&mut self, mut pc: usize, mut pos: usize) -> Result<Option<usize>, MatchError> {
+        let mut stack = vec![];
+        loop {
+            self.steps += 1;
+            if self.steps > self.step_limit {
+                return Err(MatchError::StepLimitExceeded);
+            }
+            let matched = match self.program.insts[pc] {
+                Inst::Char { value, ignore_case, backward } => {
+                    match self.input.step(pos, backward) {
+                        Some((c, next)) if self.char_eq(c, value, ignore_case) => {
+                            pos = next;
+                            pc += 1;
+                            true
+                        }
+                        _ => false,
+                    }
+                }
+                Inst::Any { dot_all, backward } => match self.input.step(pos, backward) {
+                    Some((c, next)) if dot_all || !is_line_terminator(c) => {
+                        pos = next;
+                        pc += 1;
+                        true
+                    }
+                    _ => false,
+                },
+                Inst::Class { class, ignore_case, backward } => {
+                    match self.input.step(pos, backward) {
+                        Some((c, next)) if self.class_contains(class, c, ignore_case) => {
+                            pos = next;
+                            pc += 1;
+                            true
+                        }
+                        _ => false,
+                    }
+                }
+                Inst::Assert { kind, multiline } => {
+                    pc += 1;
+                    self.assert(kind, multiline, pos)
+                }
+                Inst::Split(first, second) => {
+                    stack.push(Frame::Branch { pc: second, pos });
+                    pc = first;
+                    true
+                }
+                Inst::Jump(target) => {
+                    pc = target;
+                    true
+                }
+                Inst::Save(slot) => {
+                    stack.push(Frame::Slot { slot, value: self.slots[slot] });
+                    self.slots[slot] = Some(pos);
+                    pc += 1;
+                    true
+                }
+                Inst::ResetCaptures(from, to) => {
+                    for slot in from..to {
+                        stack.push(Frame::Slot { slot, value: self.slots[slot] });
+                        self.slots[slot] = None;
+                    }
+                    pc += 1;
+                    true
+                }
+                Inst::SetRegister(register) => {
+                    stack.push(Frame::Register { register, value: self.registers[register] });
+                    self.registers[register] = pos;
+                    pc += 1;
+                    true
+                }
+                Inst::CheckProgress(register) => {
+                    pc += 1;
+                    self.registers[register] != pos
+                }
+                Inst::BackReference { reference, ignore_case, backward } => {
+                    match self.back_reference(reference, ignore_case, backward, pos) {
+                        Some(next) => {
+                            pos = next;
+                            pc += 1;
+                            true
+                        }
+                        None => false,
+                    }
+                }
+                Inst::Look { negative, end } => {
+                    let saved = self.slots.clone();
+                    // Lookarounds are atomic, so they are run to completion without backtracking into them
+                    let matched = self.run(pc + 1, pos)?.is_some();
+                    if matched && negative {
+                        self.slots = saved;
+                    } else if matched {
+                        for (slot, value) in saved.into_iter().enumerate() {
+                            if self.slots[slot] != value {
+                                stack.push(Frame::Slot { slot, value });
+                            }
+                        }
+                    }
+                    pc = end;
+                    matched != negative
+                }
+                Inst::LookMatch | Inst::Match => return Ok(Some(pos)),
+            };
+            if matched {
+                continue;
+            }
+            loop {
+                match stack.pop() {
+                    None => return Ok(None),
+                    Some(Frame::Branch { pc: branch_pc, pos: branch_pos }) => {
+                        pc = branch_pc;
+                        pos = branch_pos;
+                        break;
+                    }
+                    Some(Frame::Slot { slot, value }) => self.slots[slot] = value,
+                    Some(Frame::Register { register, value }) => self.registers[register] = value,
+                }
+            }
+        }
+    }
+
+    fn char_eq(&self, c: u32, value: u32, ignore_case: bool) -> bool {
+        if ignore_case { canonicalize(c, self.input.unicode) == value } else { c == value }
+    }
+
+    fn class_contains(&self, class: usize, c: u32, ignore_case: bool) -> bool {
+        let set = &self.program.classes[class];
+        let unicode_ignore_case = self.unicode_ignore_case;
+        if !ignore_case {
+            return set.contains(c, unicode_ignore_case);
+        }
+        // Any member of the set with the same canonical form matches
+        let unicode = self.input.unicode;
+        let canonical = canonicalize(c, unicode);
+        let found = case_variants(c)
+            .into_iter()
+            .chain(case_variants(canonical))
+            .filter(|&v| canonicalize(v, unicode) == canonical)
+            .any(|v| set.contains_positive(v, unicode_ignore_case));
+        found != set.negative
+    }
+
+    fn assert(&self, kind: BoundaryAssertionKind, multiline: bool, pos: usize) -> bool {
+        let chars = &self.input.chars;
+        match kind {
+            BoundaryAssertionKind::Start => {
+                pos == 0 || (multiline && is_line_terminator(chars[pos - 1]))
+            }
+            BoundaryAssertionKind::End => {
+                pos == chars.len() || (multiline && is_line_terminator(chars[pos]))
+            }
+            BoundaryAssertionKind::Boundary | BoundaryAssertionKind::NegativeBoundary => {
+                let is_word = |c: Option<&u32>| {
+                    c.is_some_and(|&c| is_word_character(c, self.unicode_ignore_case))
+                };
+                let boundary = is_word(pos.checked_sub(1).and_then(|p| chars.get(p)))
+                    != is_word(chars.get(pos));
+                boundary == (kind == BoundaryAssertionKind::Boundary)
+            }
+        }
+    }
+
+    /// Match the text captured by the referenced group, which is empty if the group didn't participate.
+    fn back_reference(
+        &self,
+        reference: usize,
+        ignore_case: bool,
+        backward: bool,
+        pos: usize,
+    ) -> Option<usize> {
+        let captured = self.program.references[reference]
+            .iter()
+            .find_map(|&index| Some((self.slots[index * 2]?, self.slots[index * 2 + 1]?)));
+        let Some((start, end)) = captured else { return Some(pos) };
+        let len = end - start;
+        let (from, next) = if backward {
+            let from = pos.checked_sub(len)?;
+            (from, from)
+        } else {
+            (pos, pos + len)
+        };
+        let chars = &self.input.chars;
+        let target = chars.get(from..from + len)?;
+        let unicode = self.input.unicode;
+        let equal = chars[start..end].iter().zip(target).all(|(&a, &b)| {
+            if ignore_case { canonicalize(a, unicode) == canonicalize(b, unicode) } else { a == b }
+        });
+        equal.then_some(next)
+    }
+}
@@ -18,6 +18,15 @@ fn empty_type_parameter_list(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::error("Type parameter list cannot be empty.").with_label(span)
 }
 
+/// 'const' modifier can only appear on a type parameter of a function, method or class(1277)
+fn const_modifier_on_type_parameter(span: Span) -> OxcDiagnostic {
+    ts_error(
+        "1277",
+        "'const' modifier can only appear on a type parameter of a function, method or class",
+    )
+    .with_label(span)
+}
+
 pub fn check_ts_type_parameter_declaration(
     declaration: &TSTypeParameterDeclaration<'_>,
     ctx: &SemanticBuilder<'_>,
@@ -26,6 +35,18 @@ pub fn check_ts_type_parameter_declaration(
         ctx.error(empty_type_parameter_list(declaration.span));
     }
 }
+
+/// `type A<const T> = T` and `interface A<const T> {}`
+fn check_const_type_parameters(
+    type_parameters: Option<&TSTypeParameterDeclaration<'_>>,
+    ctx: &SemanticBuilder<'_>,
+) {
+    for param in type_parameters.into_iter().flat_map(|decl| &decl.params) {
+        if param.r#const {
+            ctx.error(const_modifier_on_type_parameter(param.span));
+        }
+    }
+}
 pub fn check_ts_type_parameter<'a>(param: &TSTypeParameter<'a>, ctx: &SemanticBuilder<'a>) {
     check_type_name_is_reserved(&param.name, ctx, "Type parameter");
 }
@@ -82,6 +103,7 @@ pub fn check_ts_type_alias_declaration<'a>(
     ctx: &SemanticBuilder<'a>,
 ) {
     check_type_name_is_reserved(&decl.id, ctx, "Type alias");
+    check_const_type_parameters(decl.type_parameters.as_deref(), ctx);
 }
 
 fn required_parameter_after_optional_parameter(span: Span) -> OxcDiagnostic {
@@ -278,6 +300,7 @@ pub fn check_ts_interface_declaration<'a>(
     ctx: &SemanticBuilder<'a>,
 ) {
     check_type_name_is_reserved(&decl.id, ctx, "Interface");
+    check_const_type_parameters(decl.type_parameters.as_deref(), ctx);
 }
 
 /// ```ts
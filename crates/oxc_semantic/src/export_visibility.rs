@@ -0,0 +1,255 @@
+use rustc_hash::FxHashMap;
+
+use oxc_ast::ast::*;
+use oxc_ast_visit::Visit;
+use oxc_syntax::{scope::ScopeFlags, symbol::SymbolId};
+
+use crate::Scoping;
+
+/// Visibility of a top level symbol, relative to the exports of its module.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SymbolVisibility {
+    /// Exported by the module.
+    ///
+    /// e.g. `a` in `export const a = 1`, `export { a }` and `export default a`.
+    Exported,
+    /// Not exported, but used by an exported symbol or an exported expression.
+    ///
+    /// e.g. `b` in `const b = 1; export function a() { return b }`.
+    Reachable,
+    /// Neither exported nor used by an exported symbol.
+    Internal,
+}
+
+/// Which top level symbols of a module are transitively reachable from its exports.
+///
+/// A symbol is reachable if it is exported, or if it is referenced by the declaration of a
+/// reachable symbol or by an exported expression (`export default a + b`, `export = a`).
+/// References in type annotations are followed too, so a type used in the signature of an
+/// exported function is reachable.
+///
+/// Code which runs when the module is evaluated (`a()`) is not a root: it is not part of the
+/// public API of the module. Consumers which remove code, like a tree shaker, must also keep the
+/// symbols referenced by the statements they keep.
+///
+/// Only top level symbols are analyzed. The top level symbols of scripts are globals,
+/// so they are all [SymbolVisibility::Internal].
+#[derive(Debug, Default, Clone)]
+pub struct ExportVisibility {
+    visibility: FxHashMap<SymbolId, SymbolVisibility>,
+}
+
+impl ExportVisibility {
+    /// Analyzes `program`, with the [Scoping] built from it.
+    pub fn new(program: &Program<'_>, scoping: &Scoping) -> Self {
+        let mut visibility = FxHashMap::default();
+        if !program.source_type.is_module() {
+            return Self { visibility };
+        }
+
+        let mut graph = DeclarationGraph {
+            scoping,
+            dependencies: FxHashMap::default(),
+            exported: vec![],
+            exported_references: vec![],
+        };
+        for stmt in &program.body {
+            graph.add_statement(stmt);
+        }
+
+        let mut queue = vec![];
+        for symbol_id in graph.exported {
+            if visibility.insert(symbol_id, SymbolVisibility::Exported).is_none() {
+                queue.push(symbol_id);
+            }
+        }
+        for symbol_id in graph.exported_references {
+            visibility.entry(symbol_id).or_insert_with(|| {
+                queue.push(symbol_id);
+                SymbolVisibility::Reachable
+            });
+        }
+
+        // A direct `eval` in reachable code can reference any top level symbol.
+        if scoping.root_scope_flags().contains_direct_eval() {
+            for &symbol_id in scoping.get_bindings(scoping.root_scope_id()).values() {
+                visibility.entry(symbol_id).or_insert(SymbolVisibility::Reachable);
+            }
+            return Self { visibility };
+        }
+
+        while let Some(symbol_id) = queue.pop() {
+            for references in graph.dependencies.get(&symbol_id).into_iter().flatten() {
+                for &reference in references {
+                    visibility.entry(reference).or_insert_with(|| {
+                        queue.push(reference);
+                        SymbolVisibility::Reachable
+                    });
+                }
+            }
+        }
+
+        Self { visibility }
+    }
+
+    /// Visibility of `symbol_id`.
+    ///
+    /// Symbols which are not declared at the top level are [SymbolVisibility::Internal].
+    pub fn visibility(&self, symbol_id: SymbolId) -> SymbolVisibility {
+        self.visibility.get(&symbol_id).copied().unwrap_or(SymbolVisibility::Internal)
+    }
+
+    /// Whether `symbol_id` is exported by the module.
+    pub fn is_exported(&self, symbol_id: SymbolId) -> bool {
+        self.visibility(symbol_id) == SymbolVisibility::Exported
+    }
+
+    /// Whether `symbol_id` is exported by the module or used by an exported symbol.
+    pub fn is_reachable(&self, symbol_id: SymbolId) -> bool {
+        self.visibility(symbol_id) != SymbolVisibility::Internal
+    }
+
+    /// Symbols exported by the module, in no particular order.
+    pub fn exported_symbols(&self) -> impl Iterator<Item = SymbolId> + '_ {
+        self.visibility
+            .iter()
+            .filter(|(_, visibility)| **visibility == SymbolVisibility::Exported)
+            .map(|(symbol_id, _)| *symbol_id)
+    }
+}
+
+/// The top level declarations of a module, and the top level symbols they reference.
+struct DeclarationGraph<'s> {
+    scoping: &'s Scoping,
+    /// References of the declarations of each symbol.
+    ///
+    /// A symbol may have multiple declarations, e.g. merged TypeScript interfaces.
+    dependencies: FxHashMap<SymbolId, Vec<Vec<SymbolId>>>,
+    exported: Vec<SymbolId>,
+    /// References of exported expressions.
+    exported_references: Vec<SymbolId>,
+}
+
+impl<'a, 's> DeclarationGraph<'s> {
+    fn add_statement(&mut self, stmt: &Statement<'a>) {
+        match stmt {
+            Statement::ExportNamedDeclaration(decl) => {
+                if let Some(declaration) = &decl.declaration {
+                    for symbol_id in self.add_declaration(declaration) {
+                        self.exported.push(symbol_id);
+                    }
+                } else if decl.source.is_none() {
+                    for specifier in &decl.specifiers {
+                        if let ModuleExportName::IdentifierReference(ident) = &specifier.local {
+                            self.exported.extend(self.resolve_top_level(ident));
+                        }
+                    }
+                }
+            }
+            Statement::ExportDefaultDeclaration(decl) => match &decl.declaration {
+                ExportDefaultDeclarationKind::FunctionDeclaration(func) => {
+                    let references = self.collect_references(|collector| {
+                        collector.visit_function(func, ScopeFlags::Function);
+                    });
+                    self.add_exported_declaration(func.id.as_ref(), references);
+                }
+                ExportDefaultDeclarationKind::ClassDeclaration(class) => {
+                    let references =
+                        self.collect_references(|collector| collector.visit_class(class));
+                    self.add_exported_declaration(class.id.as_ref(), references);
+                }
+                ExportDefaultDeclarationKind::TSInterfaceDeclaration(decl) => {
+                    let references = self.collect_references(|collector| {
+                        collector.visit_ts_interface_declaration(decl);
+                    });
+                    self.add_exported_declaration(Some(&decl.id), references);
+                }
+                kind => {
+                    let expr = kind.to_expression();
+                    let references =
+                        self.collect_references(|collector| collector.visit_expression(expr));
+                    self.exported_references.extend(references);
+                }
+            },
+            Statement::TSExportAssignment(assignment) => {
+                let references = self.collect_references(|collector| {
+                    collector.visit_expression(&assignment.expression);
+                });
+                self.exported_references.extend(references);
+            }
+            match_declaration!(Statement) => {
+                self.add_declaration(stmt.to_declaration());
+            }
+            _ => {}
+        }
+    }
+
+    /// Adds the declarations of `declaration`, and returns the declared symbols.
+    fn add_declaration(&mut self, declaration: &Declaration<'a>) -> Vec<SymbolId> {
+        if let Declaration::VariableDeclaration(decl) = declaration {
+            let mut symbols = vec![];
+            for declarator in &decl.declarations {
+                let references = self.collect_references(|collector| {
+                    collector.visit_variable_declarator(declarator);
+                });
+                for ident in declarator.id.get_binding_identifiers() {
+                    let symbol_id = ident.symbol_id();
+                    self.dependencies.entry(symbol_id).or_default().push(references.clone());
+                    symbols.push(symbol_id);
+                }
+            }
+            return symbols;
+        }
+        let Some(id) = declaration.id() else { return vec![] };
+        let references =
+            self.collect_references(|collector| collector.visit_declaration(declaration));
+        let symbol_id = id.symbol_id();
+        self.dependencies.entry(symbol_id).or_default().push(references);
+        vec![symbol_id]
+    }
+
+    /// `export default function f() {}`, `export default class {}`
+    fn add_exported_declaration(
+        &mut self,
+        id: Option<&BindingIdentifier<'a>>,
+        references: Vec<SymbolId>,
+    ) {
+        if let Some(id) = id {
+            let symbol_id = id.symbol_id();
+            self.dependencies.entry(symbol_id).or_default().push(references);
+            self.exported.push(symbol_id);
+        } else {
+            self.exported_references.extend(references);
+        }
+    }
+
+    fn collect_references(
+        &self,
+        visit: impl FnOnce(&mut TopLevelReferenceCollector<'s>),
+    ) -> Vec<SymbolId> {
+        let mut collector = TopLevelReferenceCollector { scoping: self.scoping, symbols: vec![] };
+        visit(&mut collector);
+        collector.symbols
+    }
+
+    fn resolve_top_level(&self, ident: &IdentifierReference<'a>) -> Option<SymbolId> {
+        resolve_top_level(self.scoping, ident)
+    }
+}
+
+/// Collects the references to top level symbols.
+struct TopLevelReferenceCollector<'s> {
+    scoping: &'s Scoping,
+    symbols: Vec<SymbolId>,
+}
+
+impl<'a> Visit<'a> for TopLevelReferenceCollector<'_> {
+    fn visit_identifier_reference(&mut self, it: &IdentifierReference<'a>) {
+        self.symbols.extend(resolve_top_level(self.scoping, it));
+    }
+}
+
+fn resolve_top_level(scoping: &Scoping, ident: &IdentifierReference<'_>) -> Option<SymbolId> {
+    let symbol_id = scoping.get_reference(ident.reference_id()).symbol_id()?;
+    (scoping.symbol_scope_id(symbol_id) == scoping.root_scope_id()).then_some(symbol_id)
+}
@@ -27,18 +27,22 @@ mod builder;
 mod checker;
 mod class;
 mod diagnostics;
+mod export_visibility;
 mod is_global_reference;
 mod jsdoc;
 mod label;
 mod node;
+mod renamer;
 mod scoping;
 mod stats;
 mod unresolved_stack;
 
 pub use builder::{SemanticBuilder, SemanticBuilderReturn};
+pub use export_visibility::{ExportVisibility, SymbolVisibility};
 pub use is_global_reference::IsGlobalReference;
 pub use jsdoc::{JSDoc, JSDocFinder, JSDocTag};
 pub use node::{AstNode, AstNodes};
+pub use renamer::{RenameConflict, RenameEdit, Renamer};
 pub use scoping::Scoping;
 pub use stats::Stats;
 
@@ -167,6 +171,16 @@ impl<'a> Semantic<'a> {
         self.cfg.as_ref()
     }
 
+    /// Which top level symbols are transitively reachable from the exports of the module.
+    ///
+    /// This walks the whole program, so the result should be reused.
+    pub fn export_visibility(&self) -> ExportVisibility {
+        match self.nodes.root_node().map(AstNode::kind) {
+            Some(AstKind::Program(program)) => ExportVisibility::new(program, &self.scoping),
+            _ => ExportVisibility::default(),
+        }
+    }
+
     /// Get statistics about data held in `Semantic`.
     pub fn stats(&self) -> Stats {
         #[expect(clippy::cast_possible_truncation)]
@@ -0,0 +1,353 @@
+use std::fmt;
+
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use oxc_ast::{
+    AstKind,
+    ast::{AssignmentTargetProperty, ObjectPattern},
+};
+use oxc_span::{CompactStr, GetSpan, Span};
+use oxc_syntax::{
+    identifier::is_identifier_name, keyword::is_reserved_keyword, reference::ReferenceId,
+    scope::ScopeId, symbol::SymbolId,
+};
+
+use crate::{AstNodes, Scoping};
+
+/// Renames symbols without changing which declaration any identifier resolves to.
+///
+/// A [`Renamer`] is a rename plan: a new name for each of a set of symbols. The plan is checked as
+/// a whole, so symbols can swap names. It can be turned into text edits of the source with
+/// [`Renamer::edits`], or applied to the [`Scoping`] with [`Renamer::apply`], for consumers which
+/// print the AST with the names of the [`Scoping`], like the mangler.
+///
+/// # Example
+/// ```ignore
+/// let mut renamer = Renamer::new();
+/// renamer.rename(symbol_id, "b");
+/// let edits = renamer.edits(semantic.scoping(), semantic.nodes())?;
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct Renamer {
+    renames: FxHashMap<SymbolId, CompactStr>,
+}
+
+/// Why a rename plan would produce an invalid program, or change its meaning.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RenameConflict {
+    /// The new name is not an identifier, or is a reserved keyword.
+    InvalidName { symbol_id: SymbolId, name: CompactStr },
+    /// Another symbol of the scope of the symbol has the new name.
+    Redeclaration { symbol_id: SymbolId, name: CompactStr, existing: SymbolId },
+    /// A reference to the symbol would resolve to `shadowing`, which has the new name and is
+    /// declared in a scope between the reference and the symbol.
+    Shadowed {
+        symbol_id: SymbolId,
+        name: CompactStr,
+        reference_id: ReferenceId,
+        shadowing: SymbolId,
+    },
+    /// A reference to another symbol or to a global with the new name would resolve to the symbol.
+    Captured { symbol_id: SymbolId, name: CompactStr, reference_id: ReferenceId },
+}
+
+impl RenameConflict {
+    /// The symbol whose rename conflicts.
+    pub fn symbol_id(&self) -> SymbolId {
+        match self {
+            Self::InvalidName { symbol_id, .. }
+            | Self::Redeclaration { symbol_id, .. }
+            | Self::Shadowed { symbol_id, .. }
+            | Self::Captured { symbol_id, .. } => *symbol_id,
+        }
+    }
+}
+
+impl fmt::Display for RenameConflict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidName { name, .. } => write!(f, "`{name}` is not a valid identifier."),
+            Self::Redeclaration { name, .. } => {
+                write!(f, "`{name}` is already declared in this scope.")
+            }
+            Self::Shadowed { name, .. } => {
+                write!(f, "`{name}` is declared in a scope using this symbol.")
+            }
+            Self::Captured { name, .. } => {
+                write!(f, "`{name}` is used in the scope of this symbol.")
+            }
+        }
+    }
+}
+
+impl std::error::Error for RenameConflict {}
+
+/// Replacement of the identifier at `span` by `text`.
+///
+/// `text` is more than the new name where the identifier is also the name of a property or of a
+/// module export, to keep it: renaming `a` to `b` in `({ a })` gives `({ a: b })`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenameEdit {
+    pub span: Span,
+    pub text: String,
+}
+
+impl Renamer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// `name` is a valid new name for a symbol, independently of the program.
+    pub fn is_valid_name(name: &str) -> bool {
+        is_identifier_name(name) && !is_reserved_keyword(name)
+    }
+
+    /// Add the renaming of `symbol_id` to `new_name` to the plan, replacing any previous one.
+    pub fn rename(&mut self, symbol_id: SymbolId, new_name: impl Into<CompactStr>) -> &mut Self {
+        self.renames.insert(symbol_id, new_name.into());
+        self
+    }
+
+    /// The new name of `symbol_id`, if it is renamed.
+    pub fn new_name(&self, symbol_id: SymbolId) -> Option<&str> {
+        self.renames.get(&symbol_id).map(CompactStr::as_str)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.renames.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.renames.len()
+    }
+
+    /// Check that applying the plan keeps every reference resolving to the same symbol.
+    ///
+    /// # Errors
+    ///
+    /// The first [`RenameConflict`] found, in symbol order.
+    pub fn check(&self, scoping: &Scoping, nodes: &AstNodes) -> Result<(), RenameConflict> {
+        let mut renames = self.renames.iter().collect::<Vec<_>>();
+        renames.sort_unstable_by_key(|(symbol_id, _)| **symbol_id);
+
+        // Bindings of the renamed symbols, after renaming.
+        let mut planned_bindings = FxHashMap::<(ScopeId, &str), SymbolId>::default();
+        for &(&symbol_id, name) in &renames {
+            if !Self::is_valid_name(name) {
+                return Err(RenameConflict::InvalidName { symbol_id, name: name.clone() });
+            }
+            let scope_id = scoping.symbol_scope_id(symbol_id);
+            if let Some(existing) = planned_bindings.insert((scope_id, name.as_str()), symbol_id) {
+                return Err(RenameConflict::Redeclaration {
+                    symbol_id,
+                    name: name.clone(),
+                    existing,
+                });
+            }
+        }
+        let binding_after = |scope_id: ScopeId, name: &str| {
+            planned_bindings.get(&(scope_id, name)).copied().or_else(|| {
+                scoping.get_binding(scope_id, name).filter(|id| !self.renames.contains_key(id))
+            })
+        };
+        let reference_scope_id = |reference_id: ReferenceId| {
+            nodes.get_node(scoping.get_reference(reference_id).node_id()).scope_id()
+        };
+
+        // Symbols by their name after renaming, for the names of the plan.
+        let new_names = renames.iter().map(|(_, name)| name.as_str()).collect::<FxHashSet<_>>();
+        let mut symbols_by_new_name = FxHashMap::<&str, Vec<SymbolId>>::default();
+        for symbol_id in scoping.symbol_ids() {
+            let name = self.new_name(symbol_id).unwrap_or_else(|| scoping.symbol_name(symbol_id));
+            if new_names.contains(name) {
+                symbols_by_new_name.entry(name).or_default().push(symbol_id);
+            }
+        }
+
+        for &(&symbol_id, name) in &renames {
+            if scoping.symbol_name(symbol_id) == name.as_str() {
+                continue;
+            }
+            let symbol_scope_id = scoping.symbol_scope_id(symbol_id);
+
+            // Other renamed symbols of the scope are checked by `planned_bindings`.
+            if let Some(existing) = scoping
+                .get_binding(symbol_scope_id, name)
+                .filter(|&existing| existing != symbol_id && !self.renames.contains_key(&existing))
+            {
+                return Err(RenameConflict::Redeclaration {
+                    symbol_id,
+                    name: name.clone(),
+                    existing,
+                });
+            }
+
+            // A reference to the symbol would resolve to a symbol with the new name declared in between.
+            for &reference_id in scoping.get_resolved_reference_ids(symbol_id) {
+                let shadowing = scoping
+                    .scope_ancestors(reference_scope_id(reference_id))
+                    .take_while(|&ancestor| ancestor != symbol_scope_id)
+                    .find_map(|ancestor| binding_after(ancestor, name));
+                if let Some(shadowing) = shadowing {
+                    return Err(RenameConflict::Shadowed {
+                        symbol_id,
+                        name: name.clone(),
+                        reference_id,
+                        shadowing,
+                    });
+                }
+            }
+
+            // A reference to a symbol with the new name declared outside of the scope of the
+            // symbol, or to a global with the new name, would resolve to the renamed symbol.
+            let is_outer_symbol = |id: SymbolId| {
+                let scope_id = scoping.symbol_scope_id(id);
+                scoping.scope_ancestors(symbol_scope_id).any(|ancestor| ancestor == scope_id)
+            };
+            let resolved = symbols_by_new_name
+                .get(name.as_str())
+                .into_iter()
+                .flatten()
+                .filter(|&&id| id != symbol_id && is_outer_symbol(id))
+                .flat_map(|&id| scoping.get_resolved_reference_ids(id).iter().copied());
+            let unresolved = scoping
+                .root_unresolved_references()
+                .get(name.as_str())
+                .into_iter()
+                .flatten()
+                .copied();
+            for reference_id in resolved.chain(unresolved) {
+                if scoping
+                    .scope_ancestors(reference_scope_id(reference_id))
+                    .any(|ancestor| ancestor == symbol_scope_id)
+                {
+                    return Err(RenameConflict::Captured {
+                        symbol_id,
+                        name: name.clone(),
+                        reference_id,
+                    });
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// The edits of the source text applying the plan, in source order.
+    ///
+    /// # Errors
+    ///
+    /// The plan is not valid, see [`Renamer::check`].
+    pub fn edits(
+        &self,
+        scoping: &Scoping,
+        nodes: &AstNodes,
+    ) -> Result<Vec<RenameEdit>, RenameConflict> {
+        self.check(scoping, nodes)?;
+        let mut edits = vec![];
+        for node in nodes.iter() {
+            let parent_kind = nodes.parent_kind(node.id());
+            match node.kind() {
+                AstKind::BindingIdentifier(ident) => {
+                    let Some(symbol_id) = ident.symbol_id.get() else { continue };
+                    let Some(new_name) = self.new_name(symbol_id) else { continue };
+                    let name = scoping.symbol_name(symbol_id);
+                    let text = match parent_kind {
+                        // `import { a }` -> `import { a as b }`
+                        Some(AstKind::ImportSpecifier(specifier))
+                            if specifier.imported.span() == ident.span =>
+                        {
+                            format!("{name} as {new_name}")
+                        }
+                        // `const { a } = obj` -> `const { a: b } = obj`
+                        Some(AstKind::ObjectPattern(pattern))
+                            if is_shorthand_binding(pattern, ident.span) =>
+                        {
+                            format!("{name}: {new_name}")
+                        }
+                        // `const { a = 1 } = obj` -> `const { a: b = 1 } = obj`
+                        Some(AstKind::AssignmentPattern(assignment))
+                            if matches!(
+                                nodes.parent_id(node.id()).and_then(|id| nodes.parent_kind(id)),
+                                Some(AstKind::ObjectPattern(pattern))
+                                    if is_shorthand_binding(pattern, assignment.span)
+                            ) =>
+                        {
+                            format!("{name}: {new_name}")
+                        }
+                        _ => new_name.to_string(),
+                    };
+                    edits.push(RenameEdit { span: ident.span, text });
+                }
+                AstKind::IdentifierReference(ident) => {
+                    let Some(reference_id) = ident.reference_id.get() else { continue };
+                    let Some(symbol_id) = scoping.get_reference(reference_id).symbol_id() else {
+                        continue;
+                    };
+                    let Some(new_name) = self.new_name(symbol_id) else { continue };
+                    let name = scoping.symbol_name(symbol_id);
+                    let text = match parent_kind {
+                        // `({ a })` -> `({ a: b })`
+                        Some(AstKind::ObjectProperty(property)) if property.shorthand => {
+                            format!("{name}: {new_name}")
+                        }
+                        // `({ a } = obj)` -> `({ a: b } = obj)`
+                        Some(AstKind::ObjectAssignmentTarget(target))
+                            if target.properties.iter().any(|property| {
+                                matches!(
+                                    property,
+                                    AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(property)
+                                        if property.binding.span == ident.span
+                                )
+                            }) =>
+                        {
+                            format!("{name}: {new_name}")
+                        }
+                        // `export { a }` -> `export { b as a }`
+                        Some(AstKind::ExportSpecifier(specifier))
+                            if specifier.exported.span() == ident.span =>
+                        {
+                            format!("{new_name} as {name}")
+                        }
+                        _ => new_name.to_string(),
+                    };
+                    edits.push(RenameEdit { span: ident.span, text });
+                }
+                _ => {}
+            }
+        }
+        edits.sort_by_key(|edit| edit.span.start);
+        Ok(edits)
+    }
+
+    /// Rename the symbols and their bindings in `scoping`.
+    ///
+    /// The AST is left as it is: it must be printed with the names of `scoping`.
+    ///
+    /// # Errors
+    ///
+    /// The plan is not valid, see [`Renamer::check`]. `scoping` is not changed then.
+    pub fn apply(&self, scoping: &mut Scoping, nodes: &AstNodes) -> Result<(), RenameConflict> {
+        self.check(scoping, nodes)?;
+        // Remove all the old bindings first, as symbols of a scope can swap names.
+        for &symbol_id in self.renames.keys() {
+            let scope_id = scoping.symbol_scope_id(symbol_id);
+            let name = scoping.symbol_name(symbol_id);
+            if scoping.get_binding(scope_id, name) == Some(symbol_id) {
+                let name = CompactStr::from(name);
+                scoping.remove_binding(scope_id, &name);
+            }
+        }
+        for (&symbol_id, new_name) in &self.renames {
+            let scope_id = scoping.symbol_scope_id(symbol_id);
+            scoping.set_symbol_name(symbol_id, new_name);
+            scoping.add_binding(scope_id, new_name, symbol_id);
+        }
+        Ok(())
+    }
+}
+
+/// `pattern` has a shorthand property with the value at `span`, e.g. `a` in `{ a }` or `a = 1` in `{ a = 1 }`.
+fn is_shorthand_binding(pattern: &ObjectPattern, span: Span) -> bool {
+    pattern.properties.iter().any(|property| property.shorthand && property.value.span() == span)
+}
@@ -3,6 +3,7 @@
 pub mod cfg;
 pub mod classes;
 pub mod modules;
+pub mod renamer;
 pub mod scopes;
 pub mod symbols;
 pub mod util;
@@ -1,4 +1,4 @@
-use oxc_semantic::SymbolFlags;
+use oxc_semantic::{SymbolFlags, SymbolVisibility};
 
 use crate::util::SemanticTester;
 
@@ -22,3 +22,73 @@ fn test_import_type() {
         .contains_flags(SymbolFlags::TypeImport)
         .test();
 }
+
+#[test]
+fn test_export_visibility() {
+    let tester = SemanticTester::js(
+        "
+        import { d } from './d';
+        const a = 1, b = 2;
+        function c() { const x = 1; return a + d + x }
+        function e() { return f() }
+        function f() { return e() }
+        class G { h() { return c() } }
+        export function i() { return new G() }
+        export { b as j };
+        export default a;
+        ",
+    );
+    tester.has_root_symbol("i").has_visibility(SymbolVisibility::Exported).test();
+    tester.has_root_symbol("b").has_visibility(SymbolVisibility::Exported).test();
+    tester.has_root_symbol("G").has_visibility(SymbolVisibility::Reachable).test();
+    tester.has_root_symbol("c").has_visibility(SymbolVisibility::Reachable).test();
+    tester.has_root_symbol("a").has_visibility(SymbolVisibility::Reachable).test();
+    tester.has_root_symbol("d").has_visibility(SymbolVisibility::Reachable).test();
+    tester.has_root_symbol("e").has_visibility(SymbolVisibility::Internal).test();
+    tester.has_root_symbol("f").has_visibility(SymbolVisibility::Internal).test();
+    tester.has_symbol("x").has_visibility(SymbolVisibility::Internal).test();
+
+    let tester = SemanticTester::js(
+        "
+        const a = 1, b = () => c, c = 2;
+        export default function () { return b() }
+        export const { d = a } = {};
+        ",
+    );
+    tester.has_root_symbol("d").has_visibility(SymbolVisibility::Exported).test();
+    tester.has_root_symbol("a").has_visibility(SymbolVisibility::Reachable).test();
+    tester.has_root_symbol("b").has_visibility(SymbolVisibility::Reachable).test();
+    tester.has_root_symbol("c").has_visibility(SymbolVisibility::Reachable).test();
+
+    // Code which runs when the module is evaluated is not part of the exports.
+    let tester = SemanticTester::js("function a() {} a(); export {}");
+    tester.has_root_symbol("a").has_visibility(SymbolVisibility::Internal).test();
+
+    // Scripts have no exports.
+    let tester = SemanticTester::new("function a() {}", oxc_span::SourceType::cjs());
+    tester.has_root_symbol("a").has_visibility(SymbolVisibility::Internal).test();
+}
+
+#[test]
+fn test_export_visibility_typescript() {
+    let tester = SemanticTester::ts(
+        "
+        interface A { b: B }
+        interface A { c: C }
+        type B = string;
+        type C = number;
+        type D = boolean;
+        export function e(a: A): void {}
+        ",
+    );
+    tester.has_root_symbol("e").has_visibility(SymbolVisibility::Exported).test();
+    tester.has_root_symbol("A").has_visibility(SymbolVisibility::Reachable).test();
+    tester.has_root_symbol("B").has_visibility(SymbolVisibility::Reachable).test();
+    tester.has_root_symbol("C").has_visibility(SymbolVisibility::Reachable).test();
+    tester.has_root_symbol("D").has_visibility(SymbolVisibility::Internal).test();
+
+    let tester =
+        SemanticTester::ts("namespace A { export const b = 1 } const c = A.b; export = c;");
+    tester.has_root_symbol("c").has_visibility(SymbolVisibility::Reachable).test();
+    tester.has_root_symbol("A").has_visibility(SymbolVisibility::Reachable).test();
+}
@@ -0,0 +1,117 @@
+use oxc_semantic::{RenameConflict, Renamer, Semantic, SymbolId};
+
+use crate::util::SemanticTester;
+
+/// The first declared symbol named `name`.
+fn symbol(semantic: &Semantic, name: &str) -> SymbolId {
+    let scoping = semantic.scoping();
+    scoping.symbol_ids().find(|&id| scoping.symbol_name(id) == name).unwrap()
+}
+
+/// Rename the symbols of `source_text` named like the first of each pair, and print the result.
+fn rename(source_text: &'static str, renames: &[(&str, &str)]) -> Result<String, RenameConflict> {
+    let tester = SemanticTester::js(source_text);
+    let semantic = tester.build();
+    let mut renamer = Renamer::new();
+    for (name, new_name) in renames {
+        renamer.rename(symbol(&semantic, name), *new_name);
+    }
+    let mut text = source_text.to_string();
+    for edit in renamer.edits(semantic.scoping(), semantic.nodes())?.into_iter().rev() {
+        text.replace_range(edit.span.start as usize..edit.span.end as usize, &edit.text);
+    }
+    Ok(text)
+}
+
+#[test]
+fn test_rename_edits() {
+    assert_eq!(
+        rename("let a = 1; a = a + 1; function f() { return a }", &[("a", "b")]).unwrap(),
+        "let b = 1; b = b + 1; function f() { return b }"
+    );
+    assert_eq!(rename("var a; var a; a;", &[("a", "b")]).unwrap(), "var b; var b; b;");
+    assert_eq!(
+        rename("const { a } = obj; const { c = 1 } = obj; f(a, c);", &[("a", "b"), ("c", "d")])
+            .unwrap(),
+        "const { a: b } = obj; const { c: d = 1 } = obj; f(b, d);"
+    );
+    assert_eq!(
+        rename("let a; ({ a } = obj); f({ a });", &[("a", "b")]).unwrap(),
+        "let b; ({ a: b } = obj); f({ a: b });"
+    );
+    assert_eq!(
+        rename("import { a } from 'x'; export { a };", &[("a", "b")]).unwrap(),
+        "import { a as b } from 'x'; export { b as a };"
+    );
+}
+
+#[test]
+fn test_rename_plan_is_checked_as_a_whole() {
+    assert_eq!(
+        rename("let a = 1, b = 2; f(a, b);", &[("a", "b"), ("b", "a")]).unwrap(),
+        "let b = 1, a = 2; f(b, a);"
+    );
+    assert_eq!(
+        rename("let a = 1; function f() { let b = 2; return a + b }", &[("a", "b"), ("b", "c")])
+            .unwrap(),
+        "let b = 1; function f() { let c = 2; return b + c }"
+    );
+    assert!(matches!(
+        rename("let a, b;", &[("a", "c"), ("b", "c")]),
+        Err(RenameConflict::Redeclaration { .. })
+    ));
+}
+
+#[test]
+fn test_rename_conflicts() {
+    assert!(matches!(rename("let a;", &[("a", "class")]), Err(RenameConflict::InvalidName { .. })));
+    assert!(matches!(rename("let a;", &[("a", "1a")]), Err(RenameConflict::InvalidName { .. })));
+    assert!(matches!(
+        rename("const a = 1; const b = 2;", &[("a", "b")]),
+        Err(RenameConflict::Redeclaration { .. })
+    ));
+    assert!(matches!(
+        rename("const a = 1; function f() { const b = 2; return a + b; }", &[("a", "b")]),
+        Err(RenameConflict::Shadowed { .. })
+    ));
+    // A global
+    assert!(matches!(
+        rename("const a = 1; function f() { return a + b; }", &[("a", "b")]),
+        Err(RenameConflict::Captured { .. })
+    ));
+    // A symbol of an outer scope
+    assert!(matches!(
+        rename("const b = 1; function f() { const a = 2; return a + b; }", &[("a", "b")]),
+        Err(RenameConflict::Captured { .. })
+    ));
+    // Unrelated symbols with the new name
+    assert_eq!(
+        rename("const a = 1; function f() { const b = 2; } a;", &[("a", "b")]).unwrap(),
+        "const b = 1; function f() { const b = 2; } b;"
+    );
+}
+
+#[test]
+fn test_rename_apply() {
+    let tester = SemanticTester::js("let a = 1, b = 2; function f() { return a }");
+    let mut semantic = tester.build();
+    let a = symbol(&semantic, "a");
+    let b = symbol(&semantic, "b");
+    let root_scope_id = semantic.scoping().root_scope_id();
+
+    let mut renamer = Renamer::new();
+    renamer.rename(a, "f");
+    let (scoping, nodes) = semantic.scoping_mut_and_nodes();
+    assert!(renamer.apply(scoping, nodes).is_err());
+    assert_eq!(semantic.scoping().symbol_name(a), "a");
+
+    let mut renamer = Renamer::new();
+    renamer.rename(a, "b").rename(b, "a");
+    let (scoping, nodes) = semantic.scoping_mut_and_nodes();
+    renamer.apply(scoping, nodes).unwrap();
+    let scoping = semantic.scoping();
+    assert_eq!(scoping.symbol_name(a), "b");
+    assert_eq!(scoping.symbol_name(b), "a");
+    assert_eq!(scoping.get_binding(root_scope_id, "b"), Some(a));
+    assert_eq!(scoping.get_binding(root_scope_id, "a"), Some(b));
+}
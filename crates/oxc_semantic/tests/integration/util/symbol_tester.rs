@@ -1,7 +1,7 @@
 use std::rc::Rc;
 
 use oxc_diagnostics::{Error, OxcDiagnostic};
-use oxc_semantic::{Reference, ScopeFlags, Semantic, SymbolFlags, SymbolId};
+use oxc_semantic::{Reference, ScopeFlags, Semantic, SymbolFlags, SymbolId, SymbolVisibility};
 
 use super::{Expect, SemanticTester};
 
@@ -256,6 +256,25 @@ impl<'a> SymbolTester<'a> {
         self
     }
 
+    /// Checks the [`SymbolVisibility`] of the symbol, relative to the exports of the module.
+    pub fn has_visibility(mut self, expected: SymbolVisibility) -> Self {
+        self.test_result = match self.test_result {
+            Ok(symbol_id) => {
+                let visibility = self.semantic.export_visibility().visibility(symbol_id);
+                if visibility == expected {
+                    Ok(symbol_id)
+                } else {
+                    Err(OxcDiagnostic::error(format!(
+                        "Expected {} to be {expected:?}, but it is {visibility:?}",
+                        self.target_symbol_name
+                    )))
+                }
+            }
+            e => e,
+        };
+        self
+    }
+
     /// Complete the test case. Will panic if any of the previously applied
     /// assertions failed.
     pub fn test(self) {
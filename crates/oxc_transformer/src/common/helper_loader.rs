@@ -60,12 +60,33 @@
 //!
 //! Based on [@babel/helper](https://github.com/babel/babel/tree/v7.26.2/packages/babel-helpers).
 //!
+//! ## Registry ([`HelperRegistry`])
+//!
+//! A registry shared by the transforms of all the modules of a build, in
+//! [`HelperLoaderOptions::registry`]. Modules import the helpers by name from a single module of
+//! the build, instead of from one module per helper, and the registry records the helpers used by
+//! all the modules. The embedder emits that module once, with [`HelperRegistry::module_source`].
+//! Scripts can't import, and load helpers with the mode of the options instead.
+//!
+//! Generated code example:
+//!
+//! ```js
+//! import { helperName as _helperName } from "\0oxc-helpers";
+//! _helperName(...arguments);
+//! ```
+//!
 //! ## Implementation
 //!
 //! Unlike other "common" utilities, this one has no transformer. It adds imports to the program
 //! via `ModuleImports` transform.
 
-use std::{borrow::Cow, cell::RefCell};
+use std::{
+    borrow::Cow,
+    cell::RefCell,
+    collections::BTreeMap,
+    fmt::{self, Write},
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+};
 
 use rustc_hash::FxHashMap;
 use serde::Deserialize;
@@ -125,11 +146,19 @@ pub struct HelperLoaderOptions {
     /// Default: `@oxc-project/runtime`
     pub module_name: Cow<'static, str>,
     pub mode: HelperLoaderMode,
+    /// Registry shared with the transforms of the other modules of a build.
+    /// If set, modules import the helpers from the module of the registry.
+    #[serde(skip)]
+    pub registry: Option<HelperRegistry>,
 }
 
 impl Default for HelperLoaderOptions {
     fn default() -> Self {
-        Self { module_name: default_as_module_name(), mode: HelperLoaderMode::default() }
+        Self {
+            module_name: default_as_module_name(),
+            mode: HelperLoaderMode::default(),
+            registry: None,
+        }
     }
 }
 
@@ -137,6 +166,84 @@ fn default_as_module_name() -> Cow<'static, str> {
     Cow::Borrowed("@oxc-project/runtime")
 }
 
+/// Helpers used by the transforms of many modules, which import them from a single module.
+///
+/// A registry is cheap to clone and thread-safe. Clones share their helpers, so the same registry
+/// can be passed to the transforms of all the modules of a build, in
+/// [`HelperLoaderOptions::registry`].
+///
+/// ```ignore
+/// let registry = HelperRegistry::new("\0oxc-helpers");
+/// let options = TransformOptions {
+///     helper_loader: HelperLoaderOptions { registry: Some(registry.clone()), ..Default::default() },
+///     ..TransformOptions::from(ESTarget::ES2015)
+/// };
+/// // Transform all modules with `options`, then add the module of the helpers to the build:
+/// let source = registry.module_source();
+/// ```
+#[derive(Clone)]
+pub struct HelperRegistry(Arc<HelperRegistryInner>);
+
+struct HelperRegistryInner {
+    module_name: String,
+    /// Used helpers, with the module which exports them
+    helpers: Mutex<BTreeMap<Helper, String>>,
+}
+
+impl HelperRegistry {
+    /// Create a registry of helpers which modules import from `module_name`.
+    pub fn new<S: Into<String>>(module_name: S) -> Self {
+        Self(Arc::new(HelperRegistryInner {
+            module_name: module_name.into(),
+            helpers: Mutex::new(BTreeMap::new()),
+        }))
+    }
+
+    /// The name of the module which exports the helpers.
+    pub fn module_name(&self) -> &str {
+        &self.0.module_name
+    }
+
+    /// Helpers imported by the transformed modules so far, in a stable order.
+    pub fn helpers(&self) -> Vec<Helper> {
+        self.lock().keys().copied().collect()
+    }
+
+    /// Source text of the module which exports the helpers imported by the transformed modules
+    /// so far, e.g.:
+    ///
+    /// ```js
+    /// export { default as objectSpread2 } from "@oxc-project/runtime/helpers/objectSpread2";
+    /// ```
+    ///
+    /// The helpers are re-exported from the runtime package of [`HelperLoaderOptions::module_name`].
+    pub fn module_source(&self) -> String {
+        let helpers = self.lock();
+        helpers.iter().fold(String::new(), |mut source, (helper, module)| {
+            let _ = writeln!(source, "export {{ default as {} }} from {module:?};", helper.name());
+            source
+        })
+    }
+
+    /// Inserting a helper can't leave the map in an invalid state, so a poisoned lock is fine.
+    fn lock(&self) -> MutexGuard<'_, BTreeMap<Helper, String>> {
+        self.0.helpers.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn register(&self, helper: Helper, module: &str) {
+        self.lock().entry(helper).or_insert_with(|| module.to_string());
+    }
+}
+
+impl fmt::Debug for HelperRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HelperRegistry")
+            .field("module_name", &self.0.module_name)
+            .field("helpers", &self.helpers())
+            .finish()
+    }
+}
+
 /// Available helpers.
 #[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
 pub enum Helper {
@@ -149,6 +256,7 @@ pub enum Helper {
     Extends,
     ObjectDestructuringEmpty,
     ObjectWithoutProperties,
+    ObjectWithoutPropertiesLoose,
     ToPropertyKey,
     DefineProperty,
     ClassPrivateFieldInitSpec,
@@ -168,6 +276,7 @@ pub enum Helper {
     DecorateParam,
     DecorateMetadata,
     UsingCtx,
+    WrapRegExp,
 }
 
 impl Helper {
@@ -182,6 +291,7 @@ impl Helper {
             Self::Extends => "extends",
             Self::ObjectDestructuringEmpty => "objectDestructuringEmpty",
             Self::ObjectWithoutProperties => "objectWithoutProperties",
+            Self::ObjectWithoutPropertiesLoose => "objectWithoutPropertiesLoose",
             Self::ToPropertyKey => "toPropertyKey",
             Self::DefineProperty => "defineProperty",
             Self::ClassPrivateFieldInitSpec => "classPrivateFieldInitSpec",
@@ -201,6 +311,7 @@ impl Helper {
             Self::DecorateParam => "decorateParam",
             Self::DecorateMetadata => "decorateMetadata",
             Self::UsingCtx => "usingCtx",
+            Self::WrapRegExp => "wrapRegExp",
         }
     }
 
@@ -213,6 +324,7 @@ impl Helper {
 pub struct HelperLoaderStore<'a> {
     module_name: Cow<'static, str>,
     mode: HelperLoaderMode,
+    registry: Option<HelperRegistry>,
     /// Loaded helpers, determined what helpers are loaded and what imports should be added.
     loaded_helpers: RefCell<FxHashMap<Helper, BoundIdentifier<'a>>>,
     pub(crate) used_helpers: RefCell<FxHashMap<Helper, String>>,
@@ -223,6 +335,7 @@ impl HelperLoaderStore<'_> {
         Self {
             module_name: options.module_name.clone(),
             mode: options.mode,
+            registry: options.registry.clone(),
             loaded_helpers: RefCell::new(FxHashMap::default()),
             used_helpers: RefCell::new(FxHashMap::default()),
         }
@@ -263,6 +376,13 @@ impl<'a> TransformCtx<'a> {
         let source = helper_loader.get_runtime_source(helper, ctx);
         helper_loader.used_helpers.borrow_mut().entry(helper).or_insert_with(|| source.to_string());
 
+        if let Some(registry) = &helper_loader.registry {
+            if self.source_type.is_module() {
+                registry.register(helper, &source);
+                return helper_loader.transform_for_registry_helper(helper, registry, self, ctx);
+            }
+        }
+
         match helper_loader.mode {
             HelperLoaderMode::Runtime => {
                 helper_loader.transform_for_runtime_helper(helper, source, self, ctx)
@@ -313,6 +433,29 @@ impl<'a> HelperLoaderStore<'a> {
         binding
     }
 
+    fn transform_for_registry_helper(
+        &self,
+        helper: Helper,
+        registry: &HelperRegistry,
+        transform_ctx: &TransformCtx<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let mut loaded_helpers = self.loaded_helpers.borrow_mut();
+        let binding = loaded_helpers.entry(helper).or_insert_with(|| {
+            let helper_name = helper.name();
+            let binding = ctx.generate_uid_in_root_scope(helper_name, SymbolFlags::Import);
+            let source = ctx.ast.atom(registry.module_name());
+            transform_ctx.module_imports.add_named_import(
+                source,
+                Atom::from(helper_name),
+                binding.clone(),
+                false,
+            );
+            binding
+        });
+        binding.create_read_expression(ctx)
+    }
+
     // Construct string directly in arena without an intermediate temp allocation
     fn get_runtime_source(&self, helper: Helper, ctx: &TraverseCtx<'a>) -> Atom<'a> {
         ctx.ast.atom_from_strs_array([&self.module_name, "/helpers/", helper.name()])
@@ -24,6 +24,9 @@ pub struct CompilerAssumptions {
     #[deprecated = "Not Implemented"]
     pub enumerable_module_meta: bool,
 
+    /// Functions have a `length` property, which is the number of parameters before the first
+    /// parameter with a default value or the rest parameter. Assume that the `length` of transformed
+    /// functions is never read, so placeholder parameters preserving it are not emitted.
     #[serde(default)]
     pub ignore_function_length: bool,
 
@@ -43,6 +46,10 @@ pub struct CompilerAssumptions {
     #[deprecated = "Not Implemented"]
     pub no_class_calls: bool,
 
+    /// Assume `document.all` (which is loosely equal to `null` but not strictly equal) is never used,
+    /// so `== null` can be emitted instead of checking for both `null` and `undefined`.
+    ///
+    /// `a ?? b` is transformed to `a != null ? a : b`.
     #[serde(default)]
     pub no_document_all: bool,
 
@@ -58,6 +65,8 @@ pub struct CompilerAssumptions {
     #[deprecated = "Not Implemented"]
     pub no_uninitialized_private_field_access: bool,
 
+    /// When using object rest, assume that the object being destructured has no symbol keys,
+    /// so the rest object is built with `_objectWithoutPropertiesLoose`.
     #[serde(default)]
     pub object_rest_no_symbols: bool,
 
@@ -68,6 +77,8 @@ pub struct CompilerAssumptions {
     #[serde(default)]
     pub private_fields_as_properties: bool,
 
+    /// Assume that getters have no side effects and always return the same value,
+    /// so property accesses can be repeated instead of cached in temporary variables.
     #[serde(default)]
     pub pure_getters: bool,
 
@@ -121,8 +132,12 @@ pub struct CompilerAssumptions {
     #[serde(default)]
     pub set_public_class_fields: bool,
 
+    /// When using object spread, assume that spread properties don't trigger getters on the target
+    /// object and thus it's safe to assign them rather than defining them using `Object.defineProperty`.
+    ///
+    /// `({ ...a, b })` is transformed to `_extends(_extends({}, a), {}, { b })` rather than
+    /// `_objectSpread2(_objectSpread2({}, a), {}, { b })`.
     #[serde(default)]
-    #[deprecated = "Not Implemented"]
     pub set_spread_properties: bool,
 
     #[serde(default)]
@@ -258,7 +258,7 @@ impl<'a, 'ctx> AsyncGeneratorExecutor<'a, 'ctx> {
             //   }
             //
             // The "_x" and "_x1" are dummy variables to ensure "foo.length" is 2.
-            let new_params = Self::create_placeholder_params(&func.params, wrapper_scope_id, ctx);
+            let new_params = self.create_placeholder_params(&func.params, wrapper_scope_id, ctx);
             mem::replace(&mut func.params, new_params)
         } else {
             Self::create_empty_params(ctx)
@@ -306,7 +306,7 @@ impl<'a, 'ctx> AsyncGeneratorExecutor<'a, 'ctx> {
         let id = wrapper_function.id.take();
         let has_function_id = id.is_some();
 
-        if !has_function_id && !Self::is_function_length_affected(&params) {
+        if !has_function_id && !self.is_function_length_affected(&params) {
             return self.create_async_to_generator_call(
                 params,
                 body,
@@ -341,7 +341,7 @@ impl<'a, 'ctx> AsyncGeneratorExecutor<'a, 'ctx> {
 
         let caller_function = {
             let scope_id = ctx.create_child_scope(wrapper_scope_id, ScopeFlags::Function);
-            let params = Self::create_placeholder_params(&params, scope_id, ctx);
+            let params = self.create_placeholder_params(&params, scope_id, ctx);
             let statements = ctx.ast.vec1(Self::create_apply_call_statement(&bound_ident, ctx));
             let body = ctx.ast.alloc_function_body(SPAN, ctx.ast.vec(), statements);
             let id = id.or_else(|| Self::infer_function_id_from_parent_node(wrapper_scope_id, ctx));
@@ -409,7 +409,7 @@ impl<'a, 'ctx> AsyncGeneratorExecutor<'a, 'ctx> {
         };
         let body = wrapper_function.body.take().unwrap();
         let params =
-            Self::create_placeholder_params(&wrapper_function.params, wrapper_scope_id, ctx);
+            self.create_placeholder_params(&wrapper_function.params, wrapper_scope_id, ctx);
         let params = mem::replace(&mut wrapper_function.params, params);
 
         let bound_ident = Self::create_bound_identifier(
@@ -481,7 +481,7 @@ impl<'a, 'ctx> AsyncGeneratorExecutor<'a, 'ctx> {
         ctx.scoping_mut().scope_flags_mut(generator_function_id).remove(ScopeFlags::Arrow);
         let function_name = Self::infer_function_name_from_parent_node(ctx);
 
-        if function_name.is_none() && !Self::is_function_length_affected(&params) {
+        if function_name.is_none() && !self.is_function_length_affected(&params) {
             return self.create_async_to_generator_call(params, body, generator_function_id, ctx);
         }
 
@@ -500,7 +500,7 @@ impl<'a, 'ctx> AsyncGeneratorExecutor<'a, 'ctx> {
 
         let caller_function = {
             let scope_id = ctx.create_child_scope(wrapper_scope_id, ScopeFlags::Function);
-            let params = Self::create_placeholder_params(&params, scope_id, ctx);
+            let params = self.create_placeholder_params(&params, scope_id, ctx);
             let statements = ctx.ast.vec1(Self::create_apply_call_statement(&bound_ident, ctx));
             let body = ctx.ast.alloc_function_body(SPAN, ctx.ast.vec(), statements);
             let id = function_name.map(|name| {
@@ -755,11 +755,17 @@ impl<'a, 'ctx> AsyncGeneratorExecutor<'a, 'ctx> {
 
     /// Creates placeholder [`FormalParameters`] which named `_x` based on the passed-in parameters.
     /// `function p(x, y, z, d = 0, ...rest) {}` -> `function* (_x, _x1, _x2) {}`
+    ///
+    /// With `ignoreFunctionLength` assumption, no placeholders are created.
     fn create_placeholder_params(
+        &self,
         params: &FormalParameters<'a>,
         scope_id: ScopeId,
         ctx: &mut TraverseCtx<'a>,
     ) -> ArenaBox<'a, FormalParameters<'a>> {
+        if self.ctx.assumptions.ignore_function_length {
+            return Self::create_empty_params(ctx);
+        }
         let mut parameters = ctx.ast.vec_with_capacity(params.items.len());
         for param in &params.items {
             if param.pattern.kind.is_assignment_pattern() {
@@ -815,11 +821,12 @@ impl<'a, 'ctx> AsyncGeneratorExecutor<'a, 'ctx> {
 
     /// Checks if the function length is affected by the parameters.
     ///
-    /// TODO: Needs to handle `ignoreFunctionLength` assumption.
+    /// Always `false` with `ignoreFunctionLength` assumption.
     // <https://github.com/babel/babel/blob/3bcfee232506a4cebe410f02042fb0f0adeeb0b1/packages/babel-helper-wrap-function/src/index.ts#L164>
     #[inline]
-    fn is_function_length_affected(params: &FormalParameters<'_>) -> bool {
-        params.items.first().is_some_and(|param| !param.pattern.kind.is_assignment_pattern())
+    fn is_function_length_affected(&self, params: &FormalParameters<'_>) -> bool {
+        !self.ctx.assumptions.ignore_function_length
+            && params.items.first().is_some_and(|param| !param.pattern.kind.is_assignment_pattern())
     }
 
     /// Check whether the function parameters could throw errors.
@@ -75,16 +75,6 @@ impl<'a, 'ctx> ObjectRestSpread<'a, 'ctx> {
                 "Option `useBuiltIns` is not implemented for object-rest-spread.",
             ));
         }
-        if ctx.assumptions.object_rest_no_symbols {
-            ctx.error(OxcDiagnostic::error(
-                "Compiler assumption `objectRestNoSymbols` is not implemented for object-rest-spread.",
-            ));
-        }
-        if ctx.assumptions.ignore_function_length {
-            ctx.error(OxcDiagnostic::error(
-                "Compiler assumption `ignoreFunctionLength` is not implemented for object-rest-spread.",
-            ));
-        }
         Self { ctx, options, excluded_variable_declarators: vec![] }
     }
 }
@@ -523,6 +513,16 @@ impl<'a, 'ctx> ObjectRestSpread<'a, 'ctx> {
             // Reserve maximize might be used space for new vec
             mem::replace(props, ctx.ast.vec_with_capacity(props.capacity() - props.len())),
         );
+        // With `pureGetters` assumption, append to the existing call rather than nesting calls.
+        // `_objectSpread2({}, a, { b })` instead of `_objectSpread2(_objectSpread2({}, a), {}, { b })`
+        if transform_ctx.assumptions.pure_getters {
+            if let Some(call_expr) = expr {
+                if had_props {
+                    call_expr.arguments.push(Argument::from(obj));
+                }
+                return;
+            }
+        }
         let arguments = if let Some(call_expr) = expr.take() {
             let arg = Expression::CallExpression(call_expr);
             let arg = Argument::from(arg);
@@ -535,7 +535,13 @@ impl<'a, 'ctx> ObjectRestSpread<'a, 'ctx> {
         } else {
             ctx.ast.vec1(Argument::from(obj))
         };
-        let new_expr = transform_ctx.helper_call(Helper::ObjectSpread2, SPAN, arguments, ctx);
+        // With `setSpreadProperties` assumption, assign properties with `_extends` rather than defining them.
+        let helper = if transform_ctx.assumptions.set_spread_properties {
+            Helper::Extends
+        } else {
+            Helper::ObjectSpread2
+        };
+        let new_expr = transform_ctx.helper_call(helper, SPAN, arguments, ctx);
         expr.replace(ctx.ast.alloc(new_expr));
     }
 }
@@ -1137,7 +1143,13 @@ impl<'a> SpreadPair<'a> {
                 key_expression
             };
             arguments.push(Argument::from(key_expression));
-            transform_ctx.helper_call_expr(Helper::ObjectWithoutProperties, SPAN, arguments, ctx)
+            // With `objectRestNoSymbols` assumption, symbol keys are not copied to the rest object.
+            let helper = if transform_ctx.assumptions.object_rest_no_symbols {
+                Helper::ObjectWithoutPropertiesLoose
+            } else {
+                Helper::ObjectWithoutProperties
+            };
+            transform_ctx.helper_call_expr(helper, SPAN, arguments, ctx)
         };
         (self.lhs, rhs)
     }
@@ -79,10 +79,9 @@ impl<'a> NullishCoalescingOperator<'a, '_> {
         match &logical_expr.left {
             Expression::ThisExpression(this) => {
                 let this_span = this.span;
-                return Self::create_conditional_expression(
+                return self.create_conditional_expression(
                     logical_expr.left,
-                    ctx.ast.expression_this(this_span),
-                    ctx.ast.expression_this(this_span),
+                    |ctx| ctx.ast.expression_this(this_span),
                     logical_expr.right,
                     logical_expr.span,
                     ctx,
@@ -96,10 +95,9 @@ impl<'a> NullishCoalescingOperator<'a, '_> {
                     if ctx.scoping().get_resolved_references(symbol_id).all(|r| !r.is_write()) {
                         let binding = BoundIdentifier::new(ident.name, symbol_id);
                         let ident_span = ident.span;
-                        return Self::create_conditional_expression(
+                        return self.create_conditional_expression(
                             logical_expr.left,
-                            binding.create_spanned_read_expression(ident_span, ctx),
-                            binding.create_spanned_read_expression(ident_span, ctx),
+                            |ctx| binding.create_spanned_read_expression(ident_span, ctx),
                             logical_expr.right,
                             logical_expr.span,
                             ctx,
@@ -135,10 +133,9 @@ impl<'a> NullishCoalescingOperator<'a, '_> {
             binding.create_write_target(ctx),
             logical_expr.left,
         );
-        let mut new_expr = Self::create_conditional_expression(
+        let mut new_expr = self.create_conditional_expression(
             assignment,
-            binding.create_read_expression(ctx),
-            binding.create_read_expression(ctx),
+            |ctx| binding.create_read_expression(ctx),
             logical_expr.right,
             logical_expr.span,
             ctx,
@@ -188,8 +185,8 @@ impl<'a> NullishCoalescingOperator<'a, '_> {
     ///
     /// // Output
     /// foo = bar !== null && bar !== void 0 ? bar : "qux"
-    /// //    ^^^ assignment  ^^^ reference1         ^^^^^ default
-    /// //                                     ^^^ reference2
+    /// //    ^^^ assignment  ^^^ reference         ^^^^^ default
+    /// //                                     ^^^ reference
     /// ```
     ///
     /// ```js
@@ -198,23 +195,33 @@ impl<'a> NullishCoalescingOperator<'a, '_> {
     ///
     /// // Output
     /// foo = (_bar$x = bar.x) !== null && _bar$x !== void 0 ? _bar$x : "qux"
-    /// //    ^^^^^^^^^^^^^^^^ assignment  ^^^^^^ reference1            ^^^^^ default
-    /// //                                                     ^^^^^^ reference2
+    /// //    ^^^^^^^^^^^^^^^^ assignment  ^^^^^^ reference            ^^^^^ default
+    /// //                                                     ^^^^^^ reference
+    /// ```
+    ///
+    /// With `noDocumentAll` assumption, a single loose comparison is used instead:
+    ///
+    /// ```js
+    /// foo = (_bar$x = bar.x) != null ? _bar$x : "qux"
     /// ```
     fn create_conditional_expression(
+        &self,
         assignment: Expression<'a>,
-        reference1: Expression<'a>,
-        reference2: Expression<'a>,
+        mut reference: impl FnMut(&mut TraverseCtx<'a>) -> Expression<'a>,
         default: Expression<'a>,
         span: Span,
-        ctx: &TraverseCtx<'a>,
+        ctx: &mut TraverseCtx<'a>,
     ) -> Expression<'a> {
-        let op = BinaryOperator::StrictInequality;
         let null = ctx.ast.expression_null_literal(SPAN);
-        let left = ctx.ast.expression_binary(SPAN, assignment, op, null);
-        let right = ctx.ast.expression_binary(SPAN, reference1, op, ctx.ast.void_0(SPAN));
-        let test = ctx.ast.expression_logical(SPAN, left, LogicalOperator::And, right);
+        let test = if self.ctx.assumptions.no_document_all {
+            ctx.ast.expression_binary(SPAN, assignment, BinaryOperator::Inequality, null)
+        } else {
+            let op = BinaryOperator::StrictInequality;
+            let left = ctx.ast.expression_binary(SPAN, assignment, op, null);
+            let right = ctx.ast.expression_binary(SPAN, reference(ctx), op, ctx.ast.void_0(SPAN));
+            ctx.ast.expression_logical(SPAN, left, LogicalOperator::And, right)
+        };
 
-        ctx.ast.expression_conditional(span, test, reference2, default)
+        ctx.ast.expression_conditional(span, test, reference(ctx), default)
     }
 }
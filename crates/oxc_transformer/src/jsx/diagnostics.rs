@@ -30,3 +30,8 @@ pub fn valueless_key(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Please provide an explicit key value. Using \"key\" as a shorthand for \"key={true}\" is not allowed.")
         .with_label(span)
 }
+
+pub fn vue_invalid_v_model(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("v-model value must be an assignable expression, optionally followed by a string argument and modifiers.")
+        .with_label(span)
+}
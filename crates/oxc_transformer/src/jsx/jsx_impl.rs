@@ -853,7 +853,7 @@ impl<'a> JsxImpl<'a, '_> {
         }
     }
 
-    fn transform_jsx_member_expression(
+    pub(super) fn transform_jsx_member_expression(
         expr: ArenaBox<'a, JSXMemberExpression<'a>>,
         ctx: &TraverseCtx<'a>,
     ) -> Expression<'a> {
@@ -876,17 +876,7 @@ impl<'a> JsxImpl<'a, '_> {
     ) -> Expression<'a> {
         match value {
             Some(JSXAttributeValue::StringLiteral(s)) => {
-                let mut decoded = None;
-                Self::decode_entities(s.value.as_str(), &mut decoded, s.value.len(), ctx);
-                let jsx_text = if let Some(decoded) = decoded {
-                    // Text contains HTML entities which were decoded.
-                    // `decoded` contains the decoded string as an `ArenaString`. Convert it to `Atom`.
-                    Atom::from(decoded)
-                } else {
-                    // No HTML entities needed to be decoded. Use the original `Atom` without copying.
-                    s.value
-                };
-                ctx.ast.expression_string_literal(s.span, jsx_text, None)
+                Self::transform_jsx_string_literal(&s, ctx)
             }
             Some(JSXAttributeValue::Element(e)) => self.transform_jsx_element(e, ctx),
             Some(JSXAttributeValue::Fragment(e)) => {
@@ -902,6 +892,24 @@ impl<'a> JsxImpl<'a, '_> {
         }
     }
 
+    /// Decode HTML entities in a string attribute value.
+    pub(super) fn transform_jsx_string_literal(
+        s: &StringLiteral<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let mut decoded = None;
+        Self::decode_entities(s.value.as_str(), &mut decoded, s.value.len(), ctx);
+        let jsx_text = if let Some(decoded) = decoded {
+            // Text contains HTML entities which were decoded.
+            // `decoded` contains the decoded string as an `ArenaString`. Convert it to `Atom`.
+            Atom::from(decoded)
+        } else {
+            // No HTML entities needed to be decoded. Use the original `Atom` without copying.
+            s.value
+        };
+        ctx.ast.expression_string_literal(s.span, jsx_text, None)
+    }
+
     fn transform_jsx_child_automatic(
         &mut self,
         child: JSXChild<'a>,
@@ -972,7 +980,10 @@ impl<'a> JsxImpl<'a, '_> {
         }
     }
 
-    fn transform_jsx_text(text: &JSXText<'a>, ctx: &TraverseCtx<'a>) -> Option<Expression<'a>> {
+    pub(super) fn transform_jsx_text(
+        text: &JSXText<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Option<Expression<'a>> {
         Self::fixup_whitespace_and_decode_entities(text.value, ctx)
             .map(|value| ctx.ast.expression_string_literal(text.span, value, None))
     }
@@ -1178,7 +1189,7 @@ impl<'a> JsxImpl<'a, '_> {
         false
     }
 
-    fn delete_reference_for_closing_element(
+    pub(super) fn delete_reference_for_closing_element(
         element: Option<&JSXClosingElement<'a>>,
         ctx: &mut TraverseCtx<'a>,
     ) {
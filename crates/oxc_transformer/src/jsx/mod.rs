@@ -15,12 +15,14 @@ mod jsx_self;
 mod jsx_source;
 mod options;
 mod refresh;
+mod vue;
 pub use comments::update_options_with_comments;
 use display_name::ReactDisplayName;
 use jsx_impl::JsxImpl;
 use jsx_self::JsxSelf;
-pub use options::{JsxOptions, JsxRuntime, ReactRefreshOptions};
+pub use options::{JsxOptions, JsxRuntime, ReactRefreshOptions, VueJsxOptions};
 use refresh::ReactRefresh;
+use vue::VueJsx;
 
 /// [Preset React](https://babel.dev/docs/babel-preset-react)
 ///
@@ -30,8 +32,11 @@ use refresh::ReactRefresh;
 /// * [plugin-transform-react-jsx-self](https://babeljs.io/docs/babel-plugin-transform-react-jsx-self)
 /// * [plugin-transform-react-jsx-source](https://babel.dev/docs/babel-plugin-transform-react-jsx-source)
 /// * [plugin-transform-react-display-name](https://babeljs.io/docs/babel-plugin-transform-react-display-name)
+///
+/// Or, when [`JsxOptions::vue`] is set, [@vue/babel-plugin-jsx](https://github.com/vuejs/babel-plugin-jsx).
 pub struct Jsx<'a, 'ctx> {
     implementation: JsxImpl<'a, 'ctx>,
+    vue: Option<VueJsx<'a, 'ctx>>,
     display_name: ReactDisplayName<'a, 'ctx>,
     refresh: ReactRefresh<'a, 'ctx>,
     enable_jsx_plugin: bool,
@@ -56,10 +61,12 @@ impl<'a, 'ctx> Jsx<'a, 'ctx> {
             jsx_plugin, display_name_plugin, jsx_self_plugin, jsx_source_plugin, ..
         } = options;
         let refresh = options.refresh.clone();
+        let vue = options.vue.take().map(|options| VueJsx::new(options, ctx));
         Self {
             implementation: JsxImpl::new(options, object_rest_spread_options, ast, ctx),
             display_name: ReactDisplayName::new(ctx),
-            enable_jsx_plugin: jsx_plugin,
+            enable_jsx_plugin: jsx_plugin && vue.is_none(),
+            vue,
             display_name_plugin,
             self_plugin: jsx_self_plugin,
             source_plugin: jsx_source_plugin,
@@ -71,7 +78,7 @@ impl<'a, 'ctx> Jsx<'a, 'ctx> {
 
 impl<'a> Traverse<'a, TransformState<'a>> for Jsx<'a, '_> {
     fn enter_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
-        if self.enable_jsx_plugin {
+        if self.enable_jsx_plugin || self.vue.is_some() {
             program.source_type = program.source_type.with_standard(true);
         }
         if self.refresh_plugin {
@@ -120,7 +127,9 @@ impl<'a> Traverse<'a, TransformState<'a>> for Jsx<'a, '_> {
     }
 
     fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
-        if self.enable_jsx_plugin {
+        if let Some(vue) = &mut self.vue {
+            vue.exit_expression(expr, ctx);
+        } else if self.enable_jsx_plugin {
             self.implementation.exit_expression(expr, ctx);
         }
         if self.refresh_plugin {
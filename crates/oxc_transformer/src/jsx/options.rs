@@ -105,6 +105,11 @@ pub struct JsxOptions {
 
     /// Fast Refresh
     pub refresh: Option<ReactRefreshOptions>,
+
+    /// Compile JSX to Vue 3 render functions instead of React.
+    ///
+    /// When set, the React specific plugins are disabled.
+    pub vue: Option<VueJsxOptions>,
 }
 
 impl Default for JsxOptions {
@@ -138,6 +143,7 @@ impl JsxOptions {
             use_built_ins: None,
             use_spread: None,
             refresh: None,
+            vue: None,
         }
     }
 
@@ -157,6 +163,56 @@ impl JsxOptions {
             use_built_ins: None,
             use_spread: None,
             refresh: None,
+            vue: None,
+        }
+    }
+
+    /// Compile JSX with the Vue 3 JSX transform.
+    pub fn vue() -> Self {
+        Self { vue: Some(VueJsxOptions::default()), ..Self::disable() }
+    }
+}
+
+/// Options for the Vue 3 JSX transform.
+///
+/// Mirrors the options of [@vue/babel-plugin-jsx](https://github.com/vuejs/babel-plugin-jsx#options).
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct VueJsxOptions {
+    /// Merge props with `mergeProps` when spread attributes are used.
+    ///
+    /// When `false`, spread attributes are inlined into the props object.
+    ///
+    /// Defaults to `true`.
+    #[serde(default = "default_as_true")]
+    pub merge_props: bool,
+
+    /// Allow a single object expression child of a component to be used as its slots.
+    ///
+    /// `<Comp>{{ default: () => 'foo' }}</Comp>`
+    ///
+    /// Defaults to `true`.
+    #[serde(default = "default_as_true")]
+    pub enable_object_slots: bool,
+
+    /// Emit patch flags and dynamic prop names, so Vue can skip diffing static parts of the tree.
+    ///
+    /// Defaults to `false`.
+    pub optimize: bool,
+
+    /// Replace the function used when compiling JSX expressions.
+    ///
+    /// Defaults to `createVNode` imported from `vue`.
+    pub pragma: Option<String>,
+}
+
+impl Default for VueJsxOptions {
+    fn default() -> Self {
+        Self {
+            merge_props: default_as_true(),
+            enable_object_slots: default_as_true(),
+            optimize: false,
+            pragma: None,
         }
     }
 }
@@ -0,0 +1,1064 @@
+//! Vue JSX
+//!
+//! This plugin transforms JSX to Vue 3 render functions.
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! <div class={cls}>foo</div>;
+//! <Comp v-model={value}>foo</Comp>;
+//! <input v-model={text} />;
+//! ```
+//!
+//! Output:
+//! ```js
+//! import { createTextVNode as _createTextVNode, createVNode as _createVNode, vModelText as _vModelText, withDirectives as _withDirectives } from "vue";
+//! _createVNode("div", { "class": cls }, [_createTextVNode("foo")]);
+//! _createVNode(Comp, {
+//!   "modelValue": value,
+//!   "onUpdate:modelValue": $event => value = $event
+//! }, { default: () => [_createTextVNode("foo")] });
+//! _withDirectives(_createVNode("input", { "onUpdate:modelValue": $event => text = $event }, null), [[_vModelText, text]]);
+//! ```
+//!
+//! Supported directives are `v-model` (with arguments and modifiers), `v-show`, `v-slots`,
+//! `v-html`, `v-text` and custom directives, which are resolved with `resolveDirective`.
+//! Both the `v-model` and `vModel` spellings are accepted.
+//!
+//! Lowercase tags which are not HTML or SVG elements are resolved with `resolveComponent`.
+//!
+//! When `optimize` is enabled, patch flags and dynamic prop names are emitted for elements
+//! whose props are not static.
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@vue/babel-plugin-jsx](https://github.com/vuejs/babel-plugin-jsx).
+//!
+//! ## References:
+//!
+//! * Babel plugin implementation: <https://github.com/vuejs/babel-plugin-jsx/tree/main/packages/babel-plugin-jsx/src>
+
+use rustc_hash::FxHashMap;
+
+use oxc_allocator::{Box as ArenaBox, TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_semantic::{ReferenceFlags, ScopeFlags, SymbolFlags};
+use oxc_span::{Atom, SPAN, Span};
+use oxc_traverse::{BoundIdentifier, Traverse};
+
+use crate::{
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+};
+
+use super::{diagnostics, jsx_impl::JsxImpl, options::VueJsxOptions};
+
+/// Patch flags of `createVNode`.
+///
+/// <https://github.com/vuejs/core/blob/main/packages/shared/src/patchFlags.ts>
+mod patch_flags {
+    pub const CLASS: u32 = 1 << 1;
+    pub const STYLE: u32 = 1 << 2;
+    pub const PROPS: u32 = 1 << 3;
+    pub const FULL_PROPS: u32 = 1 << 4;
+    pub const NEED_PATCH: u32 = 1 << 9;
+}
+
+/// `SlotFlags.STABLE`
+const STABLE_SLOTS: f64 = 1.0;
+
+const VUE_SOURCE: &str = "vue";
+
+pub struct VueJsx<'a, 'ctx> {
+    options: VueJsxOptions,
+    ctx: &'ctx TransformCtx<'a>,
+    /// Bindings imported from `vue`, keyed by imported name
+    imports: FxHashMap<&'static str, BoundIdentifier<'a>>,
+    /// `var _vue = require("vue")` in scripts
+    require_vue: Option<BoundIdentifier<'a>>,
+}
+
+/// Props of an element, and what is extracted from them.
+struct ElementProps<'a> {
+    /// Second argument of `createVNode`
+    props: Expression<'a>,
+    /// `[[vShow, value], ...]` for `withDirectives`
+    directives: ArenaVec<'a, ArrayExpressionElement<'a>>,
+    /// Value of `v-slots`
+    slots: Option<Expression<'a>>,
+    patch_flag: u32,
+    dynamic_prop_names: Vec<Atom<'a>>,
+}
+
+impl<'a, 'ctx> VueJsx<'a, 'ctx> {
+    pub fn new(options: VueJsxOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { options, ctx, imports: FxHashMap::default(), require_vue: None }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for VueJsx<'a, '_> {
+    fn exit_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if !matches!(expr, Expression::JSXElement(_) | Expression::JSXFragment(_)) {
+            return;
+        }
+        *expr = match expr.take_in(ctx.ast) {
+            Expression::JSXElement(e) => self.transform_jsx_element(e, ctx),
+            Expression::JSXFragment(e) => self.transform_jsx(e.span, None, e.unbox().children, ctx),
+            _ => unreachable!(),
+        };
+    }
+}
+
+impl<'a> VueJsx<'a, '_> {
+    fn transform_jsx_element(
+        &mut self,
+        element: ArenaBox<'a, JSXElement<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let JSXElement { span, opening_element, closing_element, children } = element.unbox();
+        JsxImpl::delete_reference_for_closing_element(closing_element.as_deref(), ctx);
+        self.transform_jsx(span, Some(opening_element), children, ctx)
+    }
+
+    fn transform_jsx(
+        &mut self,
+        span: Span,
+        opening_element: Option<ArenaBox<'a, JSXOpeningElement<'a>>>,
+        children: ArenaVec<'a, JSXChild<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        let (tag, native_tag, is_component, attributes) = match opening_element {
+            Some(opening_element) => {
+                let JSXOpeningElement { name, attributes, .. } = opening_element.unbox();
+                let (tag, native_tag) = self.transform_element_name(name, ctx);
+                let is_component = native_tag.is_none();
+                (tag, native_tag, is_component, attributes)
+            }
+            None => (self.import_expression("Fragment", ctx), None, false, ctx.ast.vec()),
+        };
+
+        let ElementProps { props, directives, slots, patch_flag, dynamic_prop_names } =
+            self.transform_attributes(attributes, native_tag, is_component, ctx);
+
+        let children = self.transform_children(children, ctx);
+        let children = if is_component {
+            self.create_slots(children, slots, ctx)
+        } else if children.is_empty() {
+            None
+        } else {
+            Some(ctx.ast.expression_array(SPAN, children))
+        };
+
+        let mut arguments = ctx.ast.vec_with_capacity(5);
+        arguments.push(Argument::from(tag));
+        arguments.push(Argument::from(props));
+        arguments.push(Argument::from(
+            children.unwrap_or_else(|| ctx.ast.expression_null_literal(SPAN)),
+        ));
+        if self.options.optimize && patch_flag != 0 {
+            arguments.push(Argument::from(ctx.ast.expression_numeric_literal(
+                SPAN,
+                f64::from(patch_flag),
+                None,
+                NumberBase::Decimal,
+            )));
+            if !dynamic_prop_names.is_empty() {
+                let names = dynamic_prop_names.into_iter().map(|name| {
+                    ArrayExpressionElement::from(
+                        ctx.ast.expression_string_literal(SPAN, name, None),
+                    )
+                });
+                let names = ctx.ast.vec_from_iter(names);
+                arguments.push(Argument::from(ctx.ast.expression_array(SPAN, names)));
+            }
+        }
+
+        let callee = self.get_create_vnode(ctx);
+        let vnode = ctx.ast.expression_call(span, callee, NONE, arguments, false);
+
+        if directives.is_empty() {
+            return vnode;
+        }
+        // `_withDirectives(vnode, [[vShow, value]])`
+        let callee = self.import_expression("withDirectives", ctx);
+        let arguments = ctx.ast.vec_from_array([
+            Argument::from(vnode),
+            Argument::from(ctx.ast.expression_array(SPAN, directives)),
+        ]);
+        ctx.ast.expression_call(span, callee, NONE, arguments, false)
+    }
+
+    /// Returns the tag expression, and the tag name if the element is a native HTML or SVG element.
+    fn transform_element_name(
+        &mut self,
+        name: JSXElementName<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> (Expression<'a>, Option<Atom<'a>>) {
+        match name {
+            JSXElementName::Identifier(ident) => {
+                let tag = ctx.ast.expression_string_literal(ident.span, ident.name, None);
+                if is_native_tag(&ident.name) {
+                    (tag, Some(ident.name))
+                } else {
+                    // `<my-comp />` -> `_resolveComponent("my-comp")`
+                    let callee = self.import_expression("resolveComponent", ctx);
+                    let arguments = ctx.ast.vec1(Argument::from(tag));
+                    (ctx.ast.expression_call(ident.span, callee, NONE, arguments, false), None)
+                }
+            }
+            JSXElementName::IdentifierReference(ident) => (Expression::Identifier(ident), None),
+            JSXElementName::MemberExpression(member_expr) => {
+                (JsxImpl::transform_jsx_member_expression(member_expr, ctx), None)
+            }
+            JSXElementName::NamespacedName(namespaced) => {
+                let name = ctx.ast.atom_from_strs_array([
+                    &namespaced.namespace.name,
+                    ":",
+                    &namespaced.name.name,
+                ]);
+                (ctx.ast.expression_string_literal(namespaced.span, name, None), Some(name))
+            }
+            JSXElementName::ThisExpression(expr) => (ctx.ast.expression_this(expr.span), None),
+        }
+    }
+
+    fn transform_attributes(
+        &mut self,
+        attributes: ArenaVec<'a, JSXAttributeItem<'a>>,
+        native_tag: Option<Atom<'a>>,
+        is_component: bool,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> ElementProps<'a> {
+        let native_model_directive =
+            native_tag.map(|tag| native_model_directive(&tag, &attributes));
+
+        // Arguments of `mergeProps`, `{ a: 1, ...b, c: 2 }` -> `[{ a: 1 }, b, { c: 2 }]`
+        let mut segments = vec![];
+        let mut properties = ctx.ast.vec();
+        let mut directives = ctx.ast.vec();
+        let mut slots = None;
+        let mut dynamic_prop_names = vec![];
+        let (mut has_dynamic_keys, mut has_class_binding, mut has_style_binding, mut has_ref) =
+            (false, false, false, false);
+
+        for attribute in attributes {
+            let JSXAttribute { span, name, value } = match attribute {
+                JSXAttributeItem::Attribute(attr) => attr.unbox(),
+                JSXAttributeItem::SpreadAttribute(spread) => {
+                    let JSXSpreadAttribute { span, argument } = spread.unbox();
+                    has_dynamic_keys = true;
+                    if self.options.merge_props {
+                        if !properties.is_empty() {
+                            segments.push(ctx.ast.expression_object(SPAN, properties));
+                            properties = ctx.ast.vec();
+                        }
+                        segments.push(argument);
+                    } else {
+                        properties
+                            .push(ctx.ast.object_property_kind_spread_property(span, argument));
+                    }
+                    continue;
+                }
+            };
+
+            let (attr_name, attr_arg) = match &name {
+                JSXAttributeName::Identifier(ident) => (ident.name, None),
+                JSXAttributeName::NamespacedName(namespaced) => {
+                    (namespaced.namespace.name, Some(namespaced.name.name))
+                }
+            };
+
+            let Some((directive, mut modifiers)) = parse_directive(attr_name.as_str()) else {
+                let key = match attr_arg {
+                    Some(arg) => ctx.ast.atom_from_strs_array([&attr_name, ":", &arg]),
+                    None => attr_name,
+                };
+                if !is_static_value(value.as_ref()) {
+                    match key.as_str() {
+                        "ref" => has_ref = true,
+                        "class" if !is_component => has_class_binding = true,
+                        "style" if !is_component => has_style_binding = true,
+                        "key" => {}
+                        _ => push_unique(&mut dynamic_prop_names, key),
+                    }
+                }
+                let value = self.transform_attribute_value(value, ctx);
+                properties.push(create_property(span, key, value, ctx));
+                continue;
+            };
+
+            // `v-model:arg_trim` -> arg `arg`, modifiers `["trim"]`
+            let arg = attr_arg.map(|arg| {
+                let mut parts = arg.as_str().split('_');
+                let arg = parts.next().unwrap_or_default();
+                modifiers.extend(parts);
+                Atom::from(arg)
+            });
+            let value = self.transform_attribute_value(value, ctx);
+
+            match directive {
+                "slots" => slots = Some(value),
+                "model" => {
+                    let Some((model, arg, modifiers)) = parse_model_value(value, arg, modifiers)
+                    else {
+                        self.ctx.error(diagnostics::vue_invalid_v_model(span));
+                        continue;
+                    };
+                    let Some(target) = create_model_target(&model, ctx) else {
+                        self.ctx.error(diagnostics::vue_invalid_v_model(span));
+                        continue;
+                    };
+                    let handler = create_update_handler(target, ctx);
+                    if let Some(model_directive) = native_model_directive {
+                        // `<input v-model={x} />`
+                        // -> `{ "onUpdate:modelValue": $event => x = $event }` + `[vModelText, x]`
+                        let key = Atom::from("onUpdate:modelValue");
+                        properties.push(create_property(span, key, handler, ctx));
+                        push_unique(&mut dynamic_prop_names, key);
+                        let directive = self.import_expression(model_directive, ctx);
+                        let arg = arg.map(|arg| ctx.ast.expression_string_literal(SPAN, arg, None));
+                        let modifiers = create_modifiers_object(&modifiers, ctx);
+                        directives.push(create_directive(directive, model, arg, modifiers, ctx));
+                    } else {
+                        // `<Comp v-model:foo={x} />`
+                        // -> `{ "foo": x, "onUpdate:foo": $event => x = $event }`
+                        let prop_name = arg.unwrap_or(Atom::from("modelValue"));
+                        let update_name = ctx.ast.atom_from_strs_array(["onUpdate:", &prop_name]);
+                        properties.push(create_property(span, prop_name, model, ctx));
+                        properties.push(create_property(span, update_name, handler, ctx));
+                        if let Some(modifiers) = create_modifiers_object(&modifiers, ctx) {
+                            let modifiers_name = match arg {
+                                Some(arg) => ctx.ast.atom_from_strs_array([&arg, "Modifiers"]),
+                                None => Atom::from("modelModifiers"),
+                            };
+                            properties.push(create_property(span, modifiers_name, modifiers, ctx));
+                        }
+                        push_unique(&mut dynamic_prop_names, prop_name);
+                        push_unique(&mut dynamic_prop_names, update_name);
+                    }
+                }
+                "html" | "text" => {
+                    let key =
+                        Atom::from(if directive == "html" { "innerHTML" } else { "textContent" });
+                    push_unique(&mut dynamic_prop_names, key);
+                    properties.push(create_property(span, key, value, ctx));
+                }
+                "show" => {
+                    let directive = self.import_expression("vShow", ctx);
+                    directives.push(create_directive(directive, value, None, None, ctx));
+                }
+                _ => {
+                    // `v-focus={x}` -> `[_resolveDirective("focus"), x]`
+                    let callee = self.import_expression("resolveDirective", ctx);
+                    let name =
+                        ctx.ast.expression_string_literal(SPAN, ctx.ast.atom(directive), None);
+                    let directive = ctx.ast.expression_call(
+                        SPAN,
+                        callee,
+                        NONE,
+                        ctx.ast.vec1(Argument::from(name)),
+                        false,
+                    );
+                    let arg = arg.map(|arg| ctx.ast.expression_string_literal(SPAN, arg, None));
+                    let modifiers = create_modifiers_object(&modifiers, ctx);
+                    directives.push(create_directive(directive, value, arg, modifiers, ctx));
+                }
+            }
+        }
+
+        if !properties.is_empty() {
+            segments.push(ctx.ast.expression_object(SPAN, properties));
+        }
+        let props = match segments.len() {
+            0 => ctx.ast.expression_null_literal(SPAN),
+            1 => segments.pop().unwrap(),
+            _ => {
+                let callee = self.import_expression("mergeProps", ctx);
+                let arguments = ctx.ast.vec_from_iter(segments.into_iter().map(Argument::from));
+                ctx.ast.expression_call(SPAN, callee, NONE, arguments, false)
+            }
+        };
+
+        let mut patch_flag = 0;
+        if has_dynamic_keys {
+            patch_flag |= patch_flags::FULL_PROPS;
+        } else {
+            if has_class_binding {
+                patch_flag |= patch_flags::CLASS;
+            }
+            if has_style_binding {
+                patch_flag |= patch_flags::STYLE;
+            }
+            if !dynamic_prop_names.is_empty() {
+                patch_flag |= patch_flags::PROPS;
+            }
+        }
+        if patch_flag == 0 && (has_ref || !directives.is_empty()) {
+            patch_flag |= patch_flags::NEED_PATCH;
+        }
+        // Full props diffing makes the list of dynamic prop names redundant.
+        if patch_flag & patch_flags::FULL_PROPS != 0 {
+            dynamic_prop_names.clear();
+        }
+
+        ElementProps { props, directives, slots, patch_flag, dynamic_prop_names }
+    }
+
+    fn transform_attribute_value(
+        &mut self,
+        value: Option<JSXAttributeValue<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        match value {
+            Some(JSXAttributeValue::StringLiteral(s)) => {
+                JsxImpl::transform_jsx_string_literal(&s, ctx)
+            }
+            Some(JSXAttributeValue::Element(e)) => self.transform_jsx_element(e, ctx),
+            Some(JSXAttributeValue::Fragment(e)) => {
+                self.transform_jsx(e.span, None, e.unbox().children, ctx)
+            }
+            Some(JSXAttributeValue::ExpressionContainer(c)) => match c.unbox().expression {
+                jsx_expr @ match_expression!(JSXExpression) => jsx_expr.into_expression(),
+                JSXExpression::EmptyExpression(e) => {
+                    ctx.ast.expression_boolean_literal(e.span, true)
+                }
+            },
+            None => ctx.ast.expression_boolean_literal(SPAN, true),
+        }
+    }
+
+    fn transform_children(
+        &mut self,
+        children: ArenaVec<'a, JSXChild<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> ArenaVec<'a, ArrayExpressionElement<'a>> {
+        let children = children.into_iter().filter_map(|child| match child {
+            JSXChild::Text(text) => {
+                // `foo` -> `_createTextVNode("foo")`
+                let text = JsxImpl::transform_jsx_text(&text, ctx)?;
+                let callee = self.import_expression("createTextVNode", ctx);
+                let arguments = ctx.ast.vec1(Argument::from(text));
+                Some(ArrayExpressionElement::from(
+                    ctx.ast.expression_call(SPAN, callee, NONE, arguments, false),
+                ))
+            }
+            JSXChild::ExpressionContainer(e) => match e.unbox().expression {
+                jsx_expr @ match_expression!(JSXExpression) => {
+                    Some(ArrayExpressionElement::from(jsx_expr.into_expression()))
+                }
+                JSXExpression::EmptyExpression(_) => None,
+            },
+            JSXChild::Element(e) => {
+                Some(ArrayExpressionElement::from(self.transform_jsx_element(e, ctx)))
+            }
+            JSXChild::Fragment(e) => Some(ArrayExpressionElement::from(self.transform_jsx(
+                e.span,
+                None,
+                e.unbox().children,
+                ctx,
+            ))),
+            JSXChild::Spread(e) => {
+                let JSXSpreadChild { span, expression } = e.unbox();
+                Some(ctx.ast.array_expression_element_spread_element(span, expression))
+            }
+        });
+        let children = children.collect::<Vec<_>>();
+        ctx.ast.vec_from_iter(children)
+    }
+
+    /// Children of a component are passed as slots.
+    ///
+    /// * `<Comp>foo</Comp>` -> `{ default: () => [_createTextVNode("foo")] }`
+    /// * `<Comp>{() => foo}</Comp>` -> `{ default: () => foo }`
+    /// * `<Comp>{{ a: () => foo }}</Comp>` -> `{ a: () => foo }`
+    /// * `<Comp v-slots={slots} />` -> `slots`
+    fn create_slots(
+        &self,
+        mut children: ArenaVec<'a, ArrayExpressionElement<'a>>,
+        slots: Option<Expression<'a>>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Expression<'a>> {
+        if children.is_empty() {
+            return slots;
+        }
+
+        let mut properties = ctx.ast.vec();
+        let single_child = if children.len() == 1 { children.first_mut() } else { None };
+        match single_child {
+            Some(ArrayExpressionElement::ObjectExpression(_))
+                if self.options.enable_object_slots =>
+            {
+                let Some(ArrayExpressionElement::ObjectExpression(object)) = children.pop() else {
+                    unreachable!()
+                };
+                let Some(slots) = slots else {
+                    return Some(Expression::ObjectExpression(object));
+                };
+                properties.push(ctx.ast.object_property_kind_spread_property(
+                    SPAN,
+                    Expression::ObjectExpression(object),
+                ));
+                properties.push(ctx.ast.object_property_kind_spread_property(SPAN, slots));
+                return Some(ctx.ast.expression_object(SPAN, properties));
+            }
+            Some(
+                ArrayExpressionElement::ArrowFunctionExpression(_)
+                | ArrayExpressionElement::FunctionExpression(_),
+            ) => {
+                let function = children.pop().unwrap().into_expression();
+                properties.push(create_slot_property("default", function, ctx));
+            }
+            _ => {
+                // `() => [children]`
+                let children = ctx.ast.expression_array(SPAN, children);
+                let scope_id = ctx.insert_scope_below_expression(
+                    &children,
+                    ScopeFlags::Arrow | ScopeFlags::Function,
+                );
+                let params = ctx.ast.alloc_formal_parameters(
+                    SPAN,
+                    FormalParameterKind::ArrowFormalParameters,
+                    ctx.ast.vec(),
+                    NONE,
+                );
+                let body = ctx.ast.alloc_function_body(
+                    SPAN,
+                    ctx.ast.vec(),
+                    ctx.ast.vec1(ctx.ast.statement_expression(SPAN, children)),
+                );
+                let function = ctx.ast.expression_arrow_function_with_scope_id_and_pure(
+                    SPAN, true, false, NONE, params, NONE, body, scope_id, false,
+                );
+                properties.push(create_slot_property("default", function, ctx));
+            }
+        }
+
+        if let Some(slots) = slots {
+            properties.push(ctx.ast.object_property_kind_spread_property(SPAN, slots));
+        } else if self.options.optimize {
+            let value =
+                ctx.ast.expression_numeric_literal(SPAN, STABLE_SLOTS, None, NumberBase::Decimal);
+            properties.push(create_slot_property("_", value, ctx));
+        }
+        Some(ctx.ast.expression_object(SPAN, properties))
+    }
+
+    fn get_create_vnode(&mut self, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        if let Some(pragma) = &self.options.pragma {
+            let name = ctx.ast.atom(pragma);
+            let reference_id =
+                ctx.create_reference_in_current_scope(name.as_str(), ReferenceFlags::Read);
+            return Expression::Identifier(ctx.ast.alloc_identifier_reference_with_reference_id(
+                SPAN,
+                name,
+                reference_id,
+            ));
+        }
+        self.import_expression("createVNode", ctx)
+    }
+
+    /// `import { name as _name } from "vue"`, added once per `name`.
+    ///
+    /// In scripts, `var _vue = require("vue")` is added once instead, and `_vue.name` is returned.
+    fn import_expression(
+        &mut self,
+        name: &'static str,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Expression<'a> {
+        if self.ctx.source_type.is_script() {
+            let binding = self.require_vue.get_or_insert_with(|| {
+                let binding =
+                    ctx.generate_uid_in_root_scope(VUE_SOURCE, SymbolFlags::FunctionScopedVariable);
+                self.ctx.module_imports.add_default_import(
+                    Atom::from(VUE_SOURCE),
+                    binding.clone(),
+                    false,
+                );
+                binding
+            });
+            let object = binding.create_read_expression(ctx);
+            let property = ctx.ast.identifier_name(SPAN, Atom::from(name));
+            return Expression::from(
+                ctx.ast.member_expression_static(SPAN, object, property, false),
+            );
+        }
+
+        let binding = self.imports.entry(name).or_insert_with(|| {
+            let binding = ctx.generate_uid_in_root_scope(name, SymbolFlags::Import);
+            self.ctx.module_imports.add_named_import(
+                Atom::from(VUE_SOURCE),
+                Atom::from(name),
+                binding.clone(),
+                false,
+            );
+            binding
+        });
+        binding.create_read_expression(ctx)
+    }
+}
+
+/// `v-model` -> `model`, `vModel_trim` -> `model` with modifiers `["trim"]`.
+fn parse_directive(name: &str) -> Option<(&str, Vec<&str>)> {
+    let rest = if let Some(rest) = name.strip_prefix("v-") {
+        rest
+    } else {
+        let rest = name.strip_prefix('v')?;
+        if !rest.starts_with(|c: char| c.is_ascii_uppercase()) {
+            return None;
+        }
+        rest
+    };
+    let mut parts = rest.split('_');
+    let directive = parts.next()?;
+    let directive = match directive {
+        "Model" | "model" => "model",
+        "Show" | "show" => "show",
+        "Slots" | "slots" => "slots",
+        "Html" | "html" => "html",
+        "Text" | "text" => "text",
+        directive => directive,
+    };
+    Some((directive, parts.collect()))
+}
+
+/// `v-model={x}`, `v-model={[x, "arg"]}`, `v-model={[x, ["trim"]]}` or `v-model={[x, "arg", ["trim"]]}`.
+fn parse_model_value<'a>(
+    value: Expression<'a>,
+    mut arg: Option<Atom<'a>>,
+    mut modifiers: Vec<&'a str>,
+) -> Option<(Expression<'a>, Option<Atom<'a>>, Vec<&'a str>)> {
+    let Expression::ArrayExpression(array) = value else {
+        return Some((value, arg, modifiers));
+    };
+    let mut elements = array.unbox().elements.into_iter();
+    let model = match elements.next()? {
+        element @ match_expression!(ArrayExpressionElement) => element.into_expression(),
+        _ => return None,
+    };
+    for element in elements {
+        match element {
+            ArrayExpressionElement::StringLiteral(s) if arg.is_none() => arg = Some(s.value),
+            ArrayExpressionElement::ArrayExpression(array) => {
+                for modifier in &array.elements {
+                    let ArrayExpressionElement::StringLiteral(s) = modifier else { return None };
+                    modifiers.push(s.value.as_str());
+                }
+            }
+            _ => return None,
+        }
+    }
+    Some((model, arg, modifiers))
+}
+
+/// Create a copy of the `v-model` value to assign to.
+fn create_model_target<'a>(
+    expr: &Expression<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Option<SimpleAssignmentTarget<'a>> {
+    if let Expression::Identifier(ident) = expr {
+        let symbol_id = ctx.scoping().get_reference(ident.reference_id()).symbol_id();
+        let ident =
+            ctx.create_ident_reference(ident.span, ident.name, symbol_id, ReferenceFlags::Write);
+        return Some(SimpleAssignmentTarget::AssignmentTargetIdentifier(ctx.alloc(ident)));
+    }
+    create_model_member_expression(expr, ctx).map(SimpleAssignmentTarget::from)
+}
+
+/// Clone `a.b` or `a[0]`, where the object and property are side effect free.
+fn create_model_member_expression<'a>(
+    expr: &Expression<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Option<MemberExpression<'a>> {
+    match expr {
+        Expression::StaticMemberExpression(member) => {
+            let object = clone_model_object(&member.object, ctx)?;
+            let property = ctx.ast.identifier_name(member.property.span, member.property.name);
+            Some(ctx.ast.member_expression_static(member.span, object, property, false))
+        }
+        Expression::ComputedMemberExpression(member) => {
+            let object = clone_model_object(&member.object, ctx)?;
+            let expression = clone_model_object(&member.expression, ctx)?;
+            Some(ctx.ast.member_expression_computed(member.span, object, expression, false))
+        }
+        _ => None,
+    }
+}
+
+fn clone_model_object<'a>(
+    expr: &Expression<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Option<Expression<'a>> {
+    match expr {
+        Expression::Identifier(ident) => {
+            let symbol_id = ctx.scoping().get_reference(ident.reference_id()).symbol_id();
+            Some(ctx.create_ident_expr(ident.span, ident.name, symbol_id, ReferenceFlags::Read))
+        }
+        Expression::ThisExpression(this) => Some(ctx.ast.expression_this(this.span)),
+        Expression::StringLiteral(s) => {
+            Some(ctx.ast.expression_string_literal(s.span, s.value, None))
+        }
+        Expression::NumericLiteral(n) => {
+            Some(ctx.ast.expression_numeric_literal(n.span, n.value, n.raw, n.base))
+        }
+        _ => create_model_member_expression(expr, ctx).map(Expression::from),
+    }
+}
+
+/// `$event => target = $event`
+fn create_update_handler<'a>(
+    target: SimpleAssignmentTarget<'a>,
+    ctx: &mut TraverseCtx<'a>,
+) -> Expression<'a> {
+    let scope_id = ctx.create_child_scope_of_current(ScopeFlags::Arrow | ScopeFlags::Function);
+    let event =
+        ctx.generate_binding(Atom::from("$event"), scope_id, SymbolFlags::FunctionScopedVariable);
+    let assignment = ctx.ast.expression_assignment(
+        SPAN,
+        AssignmentOperator::Assign,
+        AssignmentTarget::from(target),
+        event.create_read_expression(ctx),
+    );
+    let param = ctx.ast.formal_parameter(
+        SPAN,
+        ctx.ast.vec(),
+        event.create_binding_pattern(ctx),
+        None,
+        false,
+        false,
+    );
+    let params = ctx.ast.alloc_formal_parameters(
+        SPAN,
+        FormalParameterKind::ArrowFormalParameters,
+        ctx.ast.vec1(param),
+        NONE,
+    );
+    let body = ctx.ast.alloc_function_body(
+        SPAN,
+        ctx.ast.vec(),
+        ctx.ast.vec1(ctx.ast.statement_expression(SPAN, assignment)),
+    );
+    ctx.ast.expression_arrow_function_with_scope_id_and_pure(
+        SPAN, true, false, NONE, params, NONE, body, scope_id, false,
+    )
+}
+
+/// `[directive, value, arg, modifiers]`
+fn create_directive<'a>(
+    directive: Expression<'a>,
+    value: Expression<'a>,
+    arg: Option<Expression<'a>>,
+    modifiers: Option<Expression<'a>>,
+    ctx: &TraverseCtx<'a>,
+) -> ArrayExpressionElement<'a> {
+    let mut elements = ctx.ast.vec_with_capacity(4);
+    elements.push(ArrayExpressionElement::from(directive));
+    elements.push(ArrayExpressionElement::from(value));
+    if arg.is_some() || modifiers.is_some() {
+        let arg = arg.unwrap_or_else(|| ctx.ast.void_0(SPAN));
+        elements.push(ArrayExpressionElement::from(arg));
+    }
+    if let Some(modifiers) = modifiers {
+        elements.push(ArrayExpressionElement::from(modifiers));
+    }
+    ArrayExpressionElement::from(ctx.ast.expression_array(SPAN, elements))
+}
+
+/// `["trim", "number"]` -> `{ trim: true, number: true }`
+fn create_modifiers_object<'a>(
+    modifiers: &[&str],
+    ctx: &TraverseCtx<'a>,
+) -> Option<Expression<'a>> {
+    if modifiers.is_empty() {
+        return None;
+    }
+    let properties = modifiers.iter().map(|modifier| {
+        let key = ctx.ast.property_key_static_identifier(SPAN, ctx.ast.atom(modifier));
+        let value = ctx.ast.expression_boolean_literal(SPAN, true);
+        ctx.ast.object_property_kind_object_property(
+            SPAN,
+            PropertyKind::Init,
+            key,
+            value,
+            false,
+            false,
+            false,
+        )
+    });
+    Some(ctx.ast.expression_object(SPAN, ctx.ast.vec_from_iter(properties)))
+}
+
+/// `"name": value`
+fn create_property<'a>(
+    span: Span,
+    name: Atom<'a>,
+    value: Expression<'a>,
+    ctx: &TraverseCtx<'a>,
+) -> ObjectPropertyKind<'a> {
+    let key = PropertyKey::from(ctx.ast.expression_string_literal(SPAN, name, None));
+    ctx.ast.object_property_kind_object_property(
+        span,
+        PropertyKind::Init,
+        key,
+        value,
+        false,
+        false,
+        false,
+    )
+}
+
+/// `default: value`
+fn create_slot_property<'a>(
+    name: &'static str,
+    value: Expression<'a>,
+    ctx: &TraverseCtx<'a>,
+) -> ObjectPropertyKind<'a> {
+    let key = ctx.ast.property_key_static_identifier(SPAN, name);
+    ctx.ast.object_property_kind_object_property(
+        SPAN,
+        PropertyKind::Init,
+        key,
+        value,
+        false,
+        false,
+        false,
+    )
+}
+
+fn push_unique<'a>(names: &mut Vec<Atom<'a>>, name: Atom<'a>) {
+    if !names.contains(&name) {
+        names.push(name);
+    }
+}
+
+/// Literal attribute values never change between renders.
+fn is_static_value(value: Option<&JSXAttributeValue<'_>>) -> bool {
+    match value {
+        None | Some(JSXAttributeValue::StringLiteral(_)) => true,
+        Some(JSXAttributeValue::ExpressionContainer(container)) => matches!(
+            container.expression,
+            JSXExpression::StringLiteral(_)
+                | JSXExpression::NumericLiteral(_)
+                | JSXExpression::BooleanLiteral(_)
+                | JSXExpression::NullLiteral(_)
+                | JSXExpression::EmptyExpression(_)
+        ),
+        Some(_) => false,
+    }
+}
+
+/// Pick the `v-model` runtime directive for a native element.
+fn native_model_directive(tag: &str, attributes: &[JSXAttributeItem<'_>]) -> &'static str {
+    match tag {
+        "select" => "vModelSelect",
+        "input" => {
+            let type_value = attributes.iter().find_map(|attribute| match attribute {
+                JSXAttributeItem::Attribute(attr) if attr.is_identifier("type") => {
+                    Some(&attr.value)
+                }
+                _ => None,
+            });
+            match type_value {
+                None | Some(None) => "vModelText",
+                Some(Some(JSXAttributeValue::StringLiteral(s))) => match s.value.as_str() {
+                    "checkbox" => "vModelCheckbox",
+                    "radio" => "vModelRadio",
+                    _ => "vModelText",
+                },
+                Some(Some(_)) => "vModelDynamic",
+            }
+        }
+        _ => "vModelText",
+    }
+}
+
+fn is_native_tag(tag: &str) -> bool {
+    HTML_TAGS.contains(&tag) || SVG_TAGS.contains(&tag)
+}
+
+/// <https://github.com/vuejs/core/blob/main/packages/shared/src/domTagConfig.ts>
+const HTML_TAGS: &[&str] = &[
+    "html",
+    "body",
+    "base",
+    "head",
+    "link",
+    "meta",
+    "style",
+    "title",
+    "address",
+    "article",
+    "aside",
+    "footer",
+    "header",
+    "hgroup",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "nav",
+    "section",
+    "div",
+    "dd",
+    "dl",
+    "dt",
+    "figcaption",
+    "figure",
+    "picture",
+    "hr",
+    "img",
+    "li",
+    "main",
+    "ol",
+    "p",
+    "pre",
+    "ul",
+    "a",
+    "b",
+    "abbr",
+    "bdi",
+    "bdo",
+    "br",
+    "cite",
+    "code",
+    "data",
+    "dfn",
+    "em",
+    "i",
+    "kbd",
+    "mark",
+    "q",
+    "rp",
+    "rt",
+    "ruby",
+    "s",
+    "samp",
+    "small",
+    "span",
+    "strong",
+    "sub",
+    "sup",
+    "time",
+    "u",
+    "var",
+    "wbr",
+    "area",
+    "audio",
+    "map",
+    "track",
+    "video",
+    "embed",
+    "object",
+    "param",
+    "source",
+    "canvas",
+    "script",
+    "noscript",
+    "del",
+    "ins",
+    "caption",
+    "col",
+    "colgroup",
+    "table",
+    "thead",
+    "tbody",
+    "td",
+    "th",
+    "tr",
+    "button",
+    "datalist",
+    "fieldset",
+    "form",
+    "input",
+    "label",
+    "legend",
+    "meter",
+    "optgroup",
+    "option",
+    "output",
+    "progress",
+    "select",
+    "textarea",
+    "details",
+    "dialog",
+    "menu",
+    "summary",
+    "template",
+    "blockquote",
+    "iframe",
+    "tfoot",
+];
+
+const SVG_TAGS: &[&str] = &[
+    "svg",
+    "animate",
+    "animateMotion",
+    "animateTransform",
+    "circle",
+    "clipPath",
+    "color-profile",
+    "defs",
+    "desc",
+    "discard",
+    "ellipse",
+    "feBlend",
+    "feColorMatrix",
+    "feComponentTransfer",
+    "feComposite",
+    "feConvolveMatrix",
+    "feDiffuseLighting",
+    "feDisplacementMap",
+    "feDistantLight",
+    "feDropShadow",
+    "feFlood",
+    "feFuncA",
+    "feFuncB",
+    "feFuncG",
+    "feFuncR",
+    "feGaussianBlur",
+    "feImage",
+    "feMerge",
+    "feMergeNode",
+    "feMorphology",
+    "feOffset",
+    "fePointLight",
+    "feSpecularLighting",
+    "feSpotLight",
+    "feTile",
+    "feTurbulence",
+    "filter",
+    "foreignObject",
+    "g",
+    "hatch",
+    "hatchpath",
+    "image",
+    "line",
+    "linearGradient",
+    "marker",
+    "mask",
+    "mesh",
+    "meshgradient",
+    "meshpatch",
+    "meshrow",
+    "metadata",
+    "mpath",
+    "path",
+    "pattern",
+    "polygon",
+    "polyline",
+    "radialGradient",
+    "rect",
+    "set",
+    "solidcolor",
+    "stop",
+    "switch",
+    "symbol",
+    "text",
+    "textPath",
+    "tspan",
+    "unknown",
+    "use",
+    "view",
+];
@@ -32,6 +32,7 @@ mod es2020;
 mod es2021;
 mod es2022;
 mod jsx;
+mod plugins;
 mod proposals;
 mod regexp;
 mod typescript;
@@ -50,6 +51,7 @@ use es2020::ES2020;
 use es2021::ES2021;
 use es2022::ES2022;
 use jsx::Jsx;
+use plugins::Plugins;
 use proposals::ExplicitResourceManagement;
 use regexp::RegExp;
 use rustc_hash::FxHashMap;
@@ -57,7 +59,7 @@ use state::TransformState;
 use typescript::TypeScript;
 
 pub use crate::{
-    common::helper_loader::{Helper, HelperLoaderMode, HelperLoaderOptions},
+    common::helper_loader::{Helper, HelperLoaderMode, HelperLoaderOptions, HelperRegistry},
     compiler_assumptions::CompilerAssumptions,
     decorator::DecoratorOptions,
     es2015::{ArrowFunctionsOptions, ES2015Options},
@@ -68,13 +70,17 @@ pub use crate::{
     es2020::ES2020Options,
     es2021::ES2021Options,
     es2022::{ClassPropertiesOptions, ES2022Options},
-    jsx::{JsxOptions, JsxRuntime, ReactRefreshOptions},
+    jsx::{JsxOptions, JsxRuntime, ReactRefreshOptions, VueJsxOptions},
     options::{
         ESTarget, Engine, EngineTargets, EnvOptions, Module, TransformOptions,
         babel::{BabelEnvOptions, BabelOptions},
     },
+    plugins::{
+        AssetImportAction, AssetImportOptions, EmotionAutoLabel, EmotionOptions,
+        ModularImportOptions, ModularImportStyle, PluginsOptions,
+    },
     proposals::ProposalOptions,
-    typescript::{RewriteExtensionsMode, TypeScriptOptions},
+    typescript::{ImportsNotUsedAsValues, RewriteExtensionsMode, TypeScriptOptions},
 };
 
 #[non_exhaustive]
@@ -95,6 +101,7 @@ pub struct Transformer<'a> {
     jsx: JsxOptions,
     env: EnvOptions,
     proposals: ProposalOptions,
+    plugins: PluginsOptions,
 }
 
 impl<'a> Transformer<'a> {
@@ -108,6 +115,7 @@ impl<'a> Transformer<'a> {
             jsx: options.jsx.clone(),
             env: options.env,
             proposals: options.proposals,
+            plugins: options.plugins.clone(),
         }
     }
 
@@ -132,6 +140,7 @@ impl<'a> Transformer<'a> {
         }
 
         let mut transformer = TransformerImpl {
+            plugins: Plugins::new(&self.plugins, &self.jsx, &self.ctx),
             common: Common::new(&self.env, &self.ctx),
             decorator: Decorator::new(self.decorator, &self.ctx),
             explicit_resource_management: self
@@ -172,6 +181,7 @@ struct TransformerImpl<'a, 'ctx> {
     x0_typescript: Option<TypeScript<'a, 'ctx>>,
     decorator: Decorator<'a, 'ctx>,
     explicit_resource_management: Option<ExplicitResourceManagement<'a, 'ctx>>,
+    plugins: Plugins<'a, 'ctx>,
     x1_jsx: Jsx<'a, 'ctx>,
     x2_es2022: ES2022<'a, 'ctx>,
     x2_es2021: ES2021<'a, 'ctx>,
@@ -191,6 +201,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_program(program, ctx);
         }
+        self.plugins.enter_program(program, ctx);
         self.x1_jsx.enter_program(program, ctx);
         if let Some(explicit_resource_management) = self.explicit_resource_management.as_mut() {
             explicit_resource_management.enter_program(program, ctx);
@@ -255,6 +266,9 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         node: &mut IdentifierReference<'a>,
         ctx: &mut TraverseCtx<'a>,
     ) {
+        if let Some(typescript) = self.x0_typescript.as_mut() {
+            typescript.enter_identifier_reference(node, ctx);
+        }
         self.common.enter_identifier_reference(node, ctx);
     }
 
@@ -318,6 +332,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_expression(expr, ctx);
         }
+        self.plugins.enter_expression(expr, ctx);
         self.x2_es2022.enter_expression(expr, ctx);
         self.x2_es2021.enter_expression(expr, ctx);
         self.x2_es2020.enter_expression(expr, ctx);
@@ -439,6 +454,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_jsx_opening_element(elem, ctx);
         }
+        self.plugins.enter_jsx_opening_element(elem, ctx);
         self.x1_jsx.enter_jsx_opening_element(elem, ctx);
     }
 
@@ -649,6 +665,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_import_declaration(node, ctx);
         }
+        self.plugins.enter_import_declaration(node, ctx);
     }
 
     fn enter_export_all_declaration(
@@ -659,6 +676,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_export_all_declaration(node, ctx);
         }
+        self.plugins.enter_export_all_declaration(node, ctx);
     }
 
     fn enter_export_named_declaration(
@@ -669,6 +687,7 @@ impl<'a> Traverse<'a, TransformState<'a>> for TransformerImpl<'a, '_> {
         if let Some(typescript) = self.x0_typescript.as_mut() {
             typescript.enter_export_named_declaration(node, ctx);
         }
+        self.plugins.enter_export_named_declaration(node, ctx);
     }
 
     fn enter_ts_export_assignment(
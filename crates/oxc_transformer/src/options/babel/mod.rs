@@ -172,6 +172,7 @@ impl BabelOptions {
             || self.presets.jsx.is_some()
             || self.plugins.react_jsx.is_some()
             || self.plugins.react_jsx_dev.is_some()
+            || self.plugins.vue_jsx.is_some()
     }
 
     pub fn is_typescript(&self) -> bool {
@@ -1,8 +1,12 @@
 use serde::Deserialize;
 
 use crate::{
-    DecoratorOptions, TypeScriptOptions, es2015::ArrowFunctionsOptions,
-    es2018::ObjectRestSpreadOptions, es2022::ClassPropertiesOptions, jsx::JsxOptions,
+    DecoratorOptions, TypeScriptOptions,
+    es2015::ArrowFunctionsOptions,
+    es2018::ObjectRestSpreadOptions,
+    es2022::ClassPropertiesOptions,
+    jsx::{JsxOptions, VueJsxOptions},
+    plugins::{AssetImportOptions, EmotionOptions, ModularImportOptions},
 };
 
 use super::PluginPresetEntries;
@@ -37,6 +41,11 @@ pub struct BabelPlugins {
     pub react_jsx_dev: Option<JsxOptions>,
     pub react_jsx_self: bool,
     pub react_jsx_source: bool,
+    pub vue_jsx: Option<VueJsxOptions>,
+    pub emotion: Option<EmotionOptions>,
+    pub modular_imports: Option<Vec<ModularImportOptions>>,
+    pub asset_imports: Option<Vec<AssetImportOptions>>,
+    pub fully_specified: bool,
     pub react_display_name: bool,
     // modules
     pub modules_commonjs: bool,
@@ -120,6 +129,29 @@ impl TryFrom<PluginPresetEntries> for BabelPlugins {
                 "transform-react-display-name" => p.react_display_name = true,
                 "transform-react-jsx-self" => p.react_jsx_self = true,
                 "transform-react-jsx-source" => p.react_jsx_source = true,
+                "@vue/babel-plugin-jsx" => {
+                    p.vue_jsx =
+                        entry.value::<VueJsxOptions>().map_err(|err| p.errors.push(err)).ok();
+                }
+                "@emotion/babel-plugin" => {
+                    p.emotion =
+                        entry.value::<EmotionOptions>().map_err(|err| p.errors.push(err)).ok();
+                }
+                // `libraryName` is required, so there is no default value.
+                "import" => match entry.value::<Option<ModularImportOptions>>() {
+                    Ok(Some(options)) => p.modular_imports.get_or_insert_default().push(options),
+                    Ok(None) => p.errors.push("import: missing field `libraryName`".to_string()),
+                    Err(err) => p.errors.push(err),
+                },
+                // `test` and `action` are required, so there is no default value.
+                "asset-imports" => match entry.value::<Option<AssetImportOptions>>() {
+                    Ok(Some(options)) => p.asset_imports.get_or_insert_default().push(options),
+                    Ok(None) => {
+                        p.errors.push("asset-imports: missing field `test`".to_string());
+                    }
+                    Err(err) => p.errors.push(err),
+                },
+                "fully-specified" => p.fully_specified = true,
                 "transform-modules-commonjs" => p.modules_commonjs = true,
                 "transform-sticky-regex" => p.sticky_flag = true,
                 "transform-unicode-regex" => p.unicode_flag = true,
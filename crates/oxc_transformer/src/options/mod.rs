@@ -14,6 +14,7 @@ use crate::{
     es2021::ES2021Options,
     es2022::ES2022Options,
     jsx::JsxOptions,
+    plugins::PluginsOptions,
     proposals::ProposalOptions,
     regexp::RegExpOptions,
     typescript::TypeScriptOptions,
@@ -69,6 +70,9 @@ pub struct TransformOptions {
     /// Proposals
     pub proposals: ProposalOptions,
 
+    /// Plugins which are not part of a Babel preset, e.g. Emotion.
+    pub plugins: PluginsOptions,
+
     pub helper_loader: HelperLoaderOptions,
 }
 
@@ -90,6 +94,7 @@ impl TransformOptions {
             },
             env: EnvOptions::enable_all(/* include_unfinished_plugins */ false),
             proposals: ProposalOptions::default(),
+            plugins: PluginsOptions::default(),
             helper_loader: HelperLoaderOptions {
                 mode: HelperLoaderMode::Runtime,
                 ..Default::default()
@@ -178,6 +183,7 @@ impl TryFrom<&BabelOptions> for TransformOptions {
             jsx_options.display_name_plugin = options.plugins.react_display_name;
             jsx_options.jsx_self_plugin = options.plugins.react_jsx_self;
             jsx_options.jsx_source_plugin = options.plugins.react_jsx_source;
+            jsx_options.vue.clone_from(&options.plugins.vue_jsx);
             jsx_options
         };
 
@@ -279,6 +285,12 @@ impl TryFrom<&BabelOptions> for TransformOptions {
             proposals: ProposalOptions {
                 explicit_resource_management: options.plugins.explicit_resource_management,
             },
+            plugins: PluginsOptions {
+                emotion: options.plugins.emotion.clone(),
+                modular_imports: options.plugins.modular_imports.clone(),
+                asset_imports: options.plugins.asset_imports.clone(),
+                fully_specified: options.plugins.fully_specified,
+            },
             helper_loader,
         })
     }
@@ -0,0 +1,334 @@
+//! Asset Imports
+//!
+//! Rewrites the imports of assets, e.g. CSS modules or SVG files, as configured for their import
+//! sources, so the asset pipeline of a dev server can be expressed without a separate Babel pass.
+//!
+//! ## Example
+//!
+//! Input (with `[{ "test": "*.module.css", "action": "remove" }, { "test": "*.png", "action": "url" }, { "test": "*.svg?react", "action": "component", "exportName": "ReactComponent" }]`):
+//! ```js
+//! import styles from "./app.module.css";
+//! import logo from "./logo.png";
+//! import Icon from "./icon.svg?react";
+//! ```
+//!
+//! Output:
+//! ```js
+//! const styles = {};
+//! const logo = new URL("./logo.png", import.meta.url).href;
+//! import { ReactComponent as Icon } from "./icon.svg";
+//! ```
+//!
+//! ## Implementation
+//!
+//! Only the top level static imports are rewritten. Type-only imports and dynamic `import()`s are
+//! left untouched. The first configured pattern matching the import source is used.
+
+use cow_utils::CowUtils;
+
+use oxc_allocator::{TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_span::{Atom, SPAN};
+use oxc_syntax::{
+    identifier::is_identifier_name,
+    reference::ReferenceFlags,
+    symbol::{SymbolFlags, SymbolId},
+};
+use oxc_traverse::Traverse;
+
+use crate::{
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+};
+
+use super::{
+    diagnostics,
+    options::{AssetImportAction, AssetImportOptions},
+};
+
+pub struct AssetImports<'a, 'ctx> {
+    options: Vec<AssetImportOptions>,
+    ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a, 'ctx> AssetImports<'a, 'ctx> {
+    pub fn new(mut options: Vec<AssetImportOptions>, ctx: &'ctx TransformCtx<'a>) -> Self {
+        options.retain(|options| {
+            let valid = !options.test.is_empty();
+            if !valid {
+                ctx.error(diagnostics::asset_imports_empty_test());
+            }
+            valid
+        });
+        Self { options, ctx }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for AssetImports<'a, '_> {
+    fn enter_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        let has_asset_import = program.body.iter().any(
+            |stmt| matches!(stmt, Statement::ImportDeclaration(decl) if self.asset(decl).is_some()),
+        );
+        if !has_asset_import {
+            return;
+        }
+
+        let mut body = ctx.ast.vec_with_capacity(program.body.len());
+        for stmt in program.body.take_in(ctx.ast) {
+            let Statement::ImportDeclaration(mut decl) = stmt else {
+                body.push(stmt);
+                continue;
+            };
+            let Some(options) = self.asset(&decl) else {
+                body.push(Statement::ImportDeclaration(decl));
+                continue;
+            };
+            match options.action {
+                AssetImportAction::Remove => {
+                    body.extend(Self::transform_to_empty(&mut decl, ctx));
+                }
+                AssetImportAction::Url => {
+                    if let Some(stmt) = self.transform_to_url(&mut decl, ctx) {
+                        body.push(stmt);
+                    } else if decl.specifiers.as_ref().is_some_and(|s| !s.is_empty()) {
+                        body.push(Statement::ImportDeclaration(decl));
+                    }
+                }
+                AssetImportAction::Component => {
+                    Self::transform_to_component(options, &mut decl, ctx);
+                    body.push(Statement::ImportDeclaration(decl));
+                }
+            }
+        }
+        program.body = body;
+    }
+}
+
+impl<'a> AssetImports<'a, '_> {
+    /// Get the options of the asset imported by `decl`, if it's a value import of a configured pattern.
+    fn asset(&self, decl: &ImportDeclaration<'_>) -> Option<&AssetImportOptions> {
+        if decl.import_kind.is_type() {
+            return None;
+        }
+        let source = decl.source.value.as_str();
+        self.options.iter().find(|options| matches_pattern(&options.test, source))
+    }
+
+    /// `import styles, { a } from "./app.module.css"` -> `const styles = {}, a = void 0;`
+    ///
+    /// Default and namespace imports are bound to an empty object, named imports to `undefined`.
+    /// Side-effect imports and type imports are removed.
+    fn transform_to_empty(
+        decl: &mut ImportDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Statement<'a>> {
+        let specifiers = decl.specifiers.take()?;
+        if specifiers.is_empty() {
+            return None;
+        }
+        let declarators = ctx.ast.vec_from_iter(specifiers.into_iter().filter_map(|specifier| {
+            let (local, init) = match specifier {
+                // Types have no value to bind
+                ImportDeclarationSpecifier::ImportSpecifier(specifier)
+                    if specifier.import_kind.is_type() =>
+                {
+                    return None;
+                }
+                ImportDeclarationSpecifier::ImportSpecifier(specifier) => {
+                    (specifier.unbox().local, ctx.ast.void_0(SPAN))
+                }
+                ImportDeclarationSpecifier::ImportDefaultSpecifier(specifier) => {
+                    (specifier.unbox().local, ctx.ast.expression_object(SPAN, ctx.ast.vec()))
+                }
+                ImportDeclarationSpecifier::ImportNamespaceSpecifier(specifier) => {
+                    (specifier.unbox().local, ctx.ast.expression_object(SPAN, ctx.ast.vec()))
+                }
+            };
+            Some(Self::create_const_declarator(local, init, ctx))
+        }));
+        if declarators.is_empty() {
+            return None;
+        }
+        Some(Self::create_const_declaration(decl, declarators, ctx))
+    }
+
+    /// `import logo from "./logo.png"` -> `const logo = new URL("./logo.png", import.meta.url).href;`
+    ///
+    /// Side-effect imports are removed. Imports with named or namespace specifiers are kept, as
+    /// a URL has no members.
+    fn transform_to_url(
+        &self,
+        decl: &mut ImportDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Statement<'a>> {
+        let specifiers = decl.specifiers.as_mut()?;
+        if specifiers.is_empty() {
+            return None;
+        }
+        if !matches!(specifiers.as_slice(), [ImportDeclarationSpecifier::ImportDefaultSpecifier(_)])
+        {
+            self.ctx.error(diagnostics::asset_imports_url_without_default_import(decl.span));
+            return None;
+        }
+        let Some(ImportDeclarationSpecifier::ImportDefaultSpecifier(specifier)) = specifiers.pop()
+        else {
+            unreachable!()
+        };
+
+        let url_symbol_id = ctx.scoping().find_binding(ctx.current_scope_id(), "URL");
+        let callee =
+            ctx.create_ident_expr(SPAN, Atom::from("URL"), url_symbol_id, ReferenceFlags::Read);
+        let import_meta_url = Expression::from(ctx.ast.member_expression_static(
+            SPAN,
+            ctx.ast.expression_meta_property(
+                SPAN,
+                ctx.ast.identifier_name(SPAN, Atom::from("import")),
+                ctx.ast.identifier_name(SPAN, Atom::from("meta")),
+            ),
+            ctx.ast.identifier_name(SPAN, Atom::from("url")),
+            false,
+        ));
+        let arguments = ctx.ast.vec_from_array([
+            Argument::from(ctx.ast.expression_string_literal(SPAN, decl.source.value, None)),
+            Argument::from(import_meta_url),
+        ]);
+        let url = ctx.ast.expression_new(SPAN, callee, NONE, arguments);
+        let href = Expression::from(ctx.ast.member_expression_static(
+            SPAN,
+            url,
+            ctx.ast.identifier_name(SPAN, Atom::from("href")),
+            false,
+        ));
+
+        let declarator = Self::create_const_declarator(specifier.unbox().local, href, ctx);
+        Some(Self::create_const_declaration(decl, ctx.ast.vec1(declarator), ctx))
+    }
+
+    /// `import Icon from "./icon.svg?react"` -> `import { ReactComponent as Icon } from "./icon.svg"`
+    /// (with `exportName: "ReactComponent"`)
+    fn transform_to_component(
+        options: &AssetImportOptions,
+        decl: &mut ImportDeclaration<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) {
+        let source = decl.source.value.as_str();
+        let path = source.split_once('?').map_or(source, |(path, _)| path);
+        let new_source = options
+            .source
+            .as_deref()
+            .unwrap_or("{{path}}")
+            .cow_replace("{{path}}", path)
+            .cow_replace("{{source}}", source)
+            .into_owned();
+        decl.source = ctx.ast.string_literal(decl.source.span, ctx.ast.atom(&new_source), None);
+
+        let Some(export_name) = &options.export_name else { return };
+        let Some(specifiers) = &mut decl.specifiers else { return };
+        for specifier in specifiers.iter_mut() {
+            let ImportDeclarationSpecifier::ImportDefaultSpecifier(default) = specifier else {
+                continue;
+            };
+            let export_name = ctx.ast.atom(export_name);
+            let imported = if is_identifier_name(&export_name) {
+                ctx.ast.module_export_name_identifier_name(SPAN, export_name)
+            } else {
+                ctx.ast.module_export_name_string_literal(SPAN, export_name, None)
+            };
+            let local = default.local.clone();
+            *specifier =
+                ImportDeclarationSpecifier::ImportSpecifier(ctx.ast.alloc_import_specifier(
+                    default.span,
+                    imported,
+                    local,
+                    ImportOrExportKind::Value,
+                ));
+        }
+    }
+
+    /// `local = init`, turning the imported binding `local` into a `const` binding.
+    fn create_const_declarator(
+        local: BindingIdentifier<'a>,
+        init: Expression<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> VariableDeclarator<'a> {
+        let symbol_id: SymbolId = local.symbol_id();
+        let flags = ctx.scoping_mut().symbol_flags_mut(symbol_id);
+        flags.remove(SymbolFlags::Import);
+        flags.insert(SymbolFlags::BlockScopedVariable | SymbolFlags::ConstVariable);
+
+        let binding = ctx.ast.binding_pattern(
+            BindingPatternKind::BindingIdentifier(ctx.ast.alloc(local)),
+            NONE,
+            false,
+        );
+        ctx.ast.variable_declarator(
+            SPAN,
+            VariableDeclarationKind::Const,
+            binding,
+            Some(init),
+            false,
+        )
+    }
+
+    fn create_const_declaration(
+        decl: &ImportDeclaration<'a>,
+        declarators: ArenaVec<'a, VariableDeclarator<'a>>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        Statement::from(ctx.ast.declaration_variable(
+            decl.span,
+            VariableDeclarationKind::Const,
+            declarators,
+            false,
+        ))
+    }
+}
+
+/// Whether `source` matches `pattern`, where `*` matches any characters, including `/`,
+/// and the other characters match themselves.
+///
+/// `*.module.css` matches `./app.module.css`, `*.svg?react` matches `./icon.svg?react`.
+fn matches_pattern(pattern: &str, source: &str) -> bool {
+    let pattern = pattern.as_bytes();
+    let source = source.as_bytes();
+    let (mut p, mut s) = (0, 0);
+    // Position of the last `*` in the pattern, and of the source when it was reached
+    let mut backtrack = None;
+    while s < source.len() {
+        if p < pattern.len() && pattern[p] == b'*' {
+            backtrack = Some((p, s));
+            p += 1;
+        } else if p < pattern.len() && pattern[p] == source[s] {
+            p += 1;
+            s += 1;
+        } else if let Some((star, star_s)) = backtrack {
+            // Let the last `*` match one more character
+            p = star + 1;
+            s = star_s + 1;
+            backtrack = Some((star, star_s + 1));
+        } else {
+            return false;
+        }
+    }
+    pattern[p..].iter().all(|&b| b == b'*')
+}
+
+#[cfg(test)]
+mod test {
+    use super::matches_pattern;
+
+    #[test]
+    fn pattern() {
+        assert!(matches_pattern("*.module.css", "./app.module.css"));
+        assert!(matches_pattern("*.module.css", "../styles/app.module.css"));
+        assert!(!matches_pattern("*.module.css", "./app.css"));
+        assert!(!matches_pattern("*.module.css", "./app.module.css?inline"));
+        assert!(matches_pattern("*.svg?react", "./icon.svg?react"));
+        assert!(!matches_pattern("*.svg?react", "./icon.svg"));
+        assert!(matches_pattern("*.svg*", "./icon.svg?url"));
+        assert!(matches_pattern("@assets/*", "@assets/logo.png"));
+        assert!(matches_pattern("*", ""));
+        assert!(matches_pattern("./logo.png", "./logo.png"));
+        assert!(!matches_pattern("./logo.png", "./logo.png.js"));
+    }
+}
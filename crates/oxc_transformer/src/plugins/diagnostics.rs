@@ -0,0 +1,18 @@
+use oxc_diagnostics::OxcDiagnostic;
+use oxc_span::Span;
+
+pub fn modular_imports_missing_library_name() -> OxcDiagnostic {
+    OxcDiagnostic::warn("libraryName is required for modular imports.")
+        .with_help("Set the `libraryName` option.")
+}
+
+pub fn asset_imports_empty_test() -> OxcDiagnostic {
+    OxcDiagnostic::warn("test is required for asset imports.")
+        .with_help("Set the `test` option to a pattern such as `*.module.css`.")
+}
+
+pub fn asset_imports_url_without_default_import(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Only a default import can be rewritten to the URL of an asset.")
+        .with_label(span)
+        .with_help("Import the URL with `import url from \"...\"`.")
+}
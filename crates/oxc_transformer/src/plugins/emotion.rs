@@ -0,0 +1,545 @@
+//! Emotion
+//!
+//! Adds labels, source maps and stable class name targets to Emotion styles,
+//! and compiles the `css` prop when JSX is imported from `@emotion/react`.
+//!
+//! ## Example
+//!
+//! Input:
+//! ```js
+//! /** @jsxImportSource @emotion/react */
+//! import { css } from "@emotion/react";
+//! import styled from "@emotion/styled";
+//!
+//! const title = css({ color: "hotpink" });
+//! const Button = styled.button`color: red;`;
+//! const App = () => <div css={{ color: "blue" }} />;
+//! ```
+//!
+//! Output (with `autoLabel: "always"` and `sourceMap: false`):
+//! ```js
+//! import { jsx as _jsx } from "@emotion/react/jsx-runtime";
+//! import { css } from "@emotion/react";
+//! import styled from "@emotion/styled";
+//!
+//! const title = /*#__PURE__*/ css({ color: "hotpink" }, "label:title;");
+//! const Button = styled("button", { target: "e<hash>0", label: "Button" })("color: red;");
+//! const App = () => _jsx("div", { css: /*#__PURE__*/ css({ color: "blue" }, "label:App;") });
+//! ```
+//!
+//! ## Implementation
+//!
+//! Implementation based on [@emotion/babel-plugin](https://emotion.sh/docs/@emotion/babel-plugin).
+//!
+//! Differences from Babel:
+//! * Template literals are not minified. Quasis are passed through as string arguments.
+//! * The `target` hash is computed from the file name rather than from the path relative to the
+//!   nearest `package.json`.
+//!
+//! ## References:
+//!
+//! * Babel plugin implementation: <https://github.com/emotion-js/emotion/tree/main/packages/babel-plugin>
+
+use std::path::Path;
+
+use base64::prelude::{BASE64_STANDARD, Engine};
+use cow_utils::CowUtils;
+use rustc_hash::FxHashSet;
+
+use oxc_allocator::{TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_semantic::SymbolFlags;
+use oxc_span::{Atom, GetSpan, SPAN, Span};
+use oxc_syntax::symbol::SymbolId;
+use oxc_traverse::{Ancestor, BoundIdentifier, Traverse};
+
+use crate::{
+    context::{TransformCtx, TraverseCtx},
+    jsx::{JsxOptions, JsxRuntime},
+    state::TransformState,
+};
+
+use super::options::{EmotionAutoLabel, EmotionOptions};
+
+const EMOTION_REACT: &str = "@emotion/react";
+const EMOTION_CSS: &str = "@emotion/css";
+const EMOTION_STYLED: &str = "@emotion/styled";
+
+pub struct Emotion<'a, 'ctx> {
+    options: EmotionOptions,
+    ctx: &'ctx TransformCtx<'a>,
+
+    /// Add `label:...;` to styles.
+    auto_label: bool,
+    /// Add an inline source map to styles.
+    source_map: bool,
+    /// JSX is compiled with `@emotion/react` as the import source, so `css` props are compiled.
+    css_prop: bool,
+
+    /// Symbols of `css` and `keyframes` imported from Emotion.
+    css_symbols: FxHashSet<SymbolId>,
+    /// Symbols of the default export of `@emotion/styled`.
+    styled_symbols: FxHashSet<SymbolId>,
+    /// `css` imported from `@emotion/react`, either by the user or by the `css` prop transform.
+    css_binding: Option<BoundIdentifier<'a>>,
+    /// Number of styled components seen so far, used to make `target` unique within the file.
+    styled_count: usize,
+}
+
+impl<'a, 'ctx> Emotion<'a, 'ctx> {
+    pub fn new(options: EmotionOptions, jsx: &JsxOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
+        let auto_label = match options.auto_label {
+            EmotionAutoLabel::DevOnly => jsx.development,
+            EmotionAutoLabel::Always => true,
+            EmotionAutoLabel::Never => false,
+        };
+        let source_map = options.source_map && jsx.development;
+        let css_prop = jsx.jsx_plugin
+            && jsx.runtime == JsxRuntime::Automatic
+            && jsx.import_source.as_deref() == Some(EMOTION_REACT);
+        Self {
+            options,
+            ctx,
+            auto_label,
+            source_map,
+            css_prop,
+            css_symbols: FxHashSet::default(),
+            styled_symbols: FxHashSet::default(),
+            css_binding: None,
+            styled_count: 0,
+        }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for Emotion<'a, '_> {
+    fn enter_program(&mut self, program: &mut Program<'a>, _ctx: &mut TraverseCtx<'a>) {
+        for stmt in &program.body {
+            let Statement::ImportDeclaration(decl) = stmt else { continue };
+            if decl.import_kind.is_type() {
+                continue;
+            }
+            let Some(specifiers) = &decl.specifiers else { continue };
+            let source = decl.source.value.as_str();
+            for specifier in specifiers {
+                match specifier {
+                    ImportDeclarationSpecifier::ImportSpecifier(specifier)
+                        if matches!(source, EMOTION_REACT | EMOTION_CSS) =>
+                    {
+                        let imported = specifier.imported.name();
+                        if imported == "css" || imported == "keyframes" {
+                            self.css_symbols.insert(specifier.local.symbol_id());
+                        }
+                        if source == EMOTION_REACT && imported == "css" {
+                            self.css_binding =
+                                Some(BoundIdentifier::from_binding_ident(&specifier.local));
+                        }
+                    }
+                    ImportDeclarationSpecifier::ImportDefaultSpecifier(specifier)
+                        if source == EMOTION_STYLED =>
+                    {
+                        self.styled_symbols.insert(specifier.local.symbol_id());
+                    }
+                    _ => {}
+                }
+            }
+        }
+    }
+
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        match expr {
+            Expression::CallExpression(call) => {
+                if self.is_css_callee(&call.callee, ctx) {
+                    self.transform_css_call(call, ctx);
+                } else if let Some(tag) = self.styled_tag(&mut call.callee, ctx) {
+                    call.callee = tag;
+                }
+            }
+            Expression::TaggedTemplateExpression(tagged) => {
+                if self.is_css_callee(&tagged.tag, ctx) {
+                    let tagged = tagged.take_in(ctx.ast);
+                    let arguments = Self::template_to_arguments(tagged.quasi, ctx);
+                    let mut call =
+                        ctx.ast.call_expression(tagged.span, tagged.tag, NONE, arguments, false);
+                    self.transform_css_call(&mut call, ctx);
+                    *expr = Expression::CallExpression(ctx.ast.alloc(call));
+                } else if let Some(tag) = self.styled_tag(&mut tagged.tag, ctx) {
+                    let tagged = tagged.take_in(ctx.ast);
+                    let arguments = Self::template_to_arguments(tagged.quasi, ctx);
+                    *expr = ctx.ast.expression_call(tagged.span, tag, NONE, arguments, false);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn enter_jsx_opening_element(
+        &mut self,
+        elem: &mut JSXOpeningElement<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if !self.css_prop || !(self.auto_label || self.source_map) {
+            return;
+        }
+        // Named imports cannot be added to scripts
+        if self.ctx.source_type.is_script() {
+            return;
+        }
+
+        for attr in &mut elem.attributes {
+            let JSXAttributeItem::Attribute(attr) = attr else { continue };
+            if !attr.is_identifier("css") {
+                continue;
+            }
+            let Some(JSXAttributeValue::ExpressionContainer(container)) = &mut attr.value else {
+                continue;
+            };
+            let value = match &mut container.expression {
+                JSXExpression::ObjectExpression(_) | JSXExpression::ArrayExpression(_) => {
+                    container.expression.to_expression_mut()
+                }
+                _ => continue,
+            };
+
+            // `css={{ color: "red" }}` -> `css={css({ color: "red" })}`.
+            // Label and source map are added when the new `css` call is entered.
+            let css = self.css_binding(ctx);
+            let value = value.take_in(ctx.ast);
+            let span = value.span();
+            let arguments = ctx.ast.vec1(Argument::from(value));
+            container.expression =
+                JSXExpression::from(ctx.ast.expression_call(span, css, NONE, arguments, false));
+        }
+    }
+}
+
+impl<'a> Emotion<'a, '_> {
+    /// Check whether `callee` is a reference to `css` or `keyframes` imported from Emotion.
+    fn is_css_callee(&self, callee: &Expression<'a>, ctx: &TraverseCtx<'a>) -> bool {
+        Self::is_reference_to(callee, &self.css_symbols, ctx)
+    }
+
+    fn is_reference_to(
+        expr: &Expression<'a>,
+        symbols: &FxHashSet<SymbolId>,
+        ctx: &TraverseCtx<'a>,
+    ) -> bool {
+        let Expression::Identifier(ident) = expr else { return false };
+        ctx.scoping()
+            .get_reference(ident.reference_id())
+            .symbol_id()
+            .is_some_and(|symbol_id| symbols.contains(&symbol_id))
+    }
+
+    /// `css({ ... })` -> `/*#__PURE__*/ css({ ... }, "label:name;", "/*# sourceMappingURL=... */")`
+    fn transform_css_call(&self, call: &mut CallExpression<'a>, ctx: &TraverseCtx<'a>) {
+        call.pure = true;
+        if self.auto_label {
+            if let Some(label) = self.label(ctx) {
+                let label = ctx.ast.atom(&format!("label:{label};"));
+                call.arguments
+                    .push(Argument::from(ctx.ast.expression_string_literal(SPAN, label, None)));
+            }
+        }
+        if self.source_map {
+            let source_map = ctx.ast.atom(&self.source_map_comment(call.span));
+            call.arguments
+                .push(Argument::from(ctx.ast.expression_string_literal(SPAN, source_map, None)));
+        }
+    }
+
+    /// If `tag` is `styled.div` or `styled(Component)`, return the replacement callee
+    /// `styled("div", { target, label })` or `styled(Component, { target, label })`.
+    fn styled_tag(
+        &mut self,
+        tag: &mut Expression<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Option<Expression<'a>> {
+        let (styled, component, span) = match tag {
+            Expression::StaticMemberExpression(member)
+                if Self::is_reference_to(&member.object, &self.styled_symbols, ctx) =>
+            {
+                let component = ctx.ast.expression_string_literal(
+                    member.property.span,
+                    member.property.name,
+                    None,
+                );
+                (member.object.take_in(ctx.ast), component, member.span)
+            }
+            Expression::CallExpression(call)
+                if call.arguments.len() == 1
+                    && call.arguments[0].is_expression()
+                    && Self::is_reference_to(&call.callee, &self.styled_symbols, ctx) =>
+            {
+                let component = call.arguments.pop().unwrap().into_expression();
+                (call.callee.take_in(ctx.ast), component, call.span)
+            }
+            _ => return None,
+        };
+
+        let mut properties = ctx.ast.vec_with_capacity(2);
+        let target = ctx.ast.atom(&format!("e{}{}", self.file_hash(), self.styled_count));
+        self.styled_count += 1;
+        properties.push(Self::create_property("target", target, ctx));
+        if self.auto_label {
+            if let Some(label) = self.label(ctx) {
+                properties.push(Self::create_property("label", ctx.ast.atom(&label), ctx));
+            }
+        }
+        let options = ctx.ast.expression_object(SPAN, properties);
+
+        let mut arguments = ArenaVec::with_capacity_in(2, ctx.ast.allocator);
+        arguments.push(Argument::from(component));
+        arguments.push(Argument::from(options));
+        Some(ctx.ast.expression_call(span, styled, NONE, arguments, false))
+    }
+
+    fn create_property(
+        name: &'static str,
+        value: Atom<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> ObjectPropertyKind<'a> {
+        ctx.ast.object_property_kind_object_property(
+            SPAN,
+            PropertyKind::Init,
+            ctx.ast.property_key_static_identifier(SPAN, name),
+            ctx.ast.expression_string_literal(SPAN, value, None),
+            false,
+            false,
+            false,
+        )
+    }
+
+    /// `` `color: ${color};` `` -> `"color: ", color, ";"`
+    fn template_to_arguments(
+        quasi: TemplateLiteral<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> ArenaVec<'a, Argument<'a>> {
+        let mut arguments = ctx.ast.vec_with_capacity(quasi.quasis.len() + quasi.expressions.len());
+        let mut expressions = quasi.expressions.into_iter();
+        for element in quasi.quasis {
+            let value = element.value.cooked.unwrap_or(element.value.raw);
+            if !value.is_empty() {
+                arguments.push(Argument::from(ctx.ast.expression_string_literal(
+                    element.span,
+                    value,
+                    None,
+                )));
+            }
+            if let Some(expr) = expressions.next() {
+                arguments.push(Argument::from(expr));
+            }
+        }
+        arguments
+    }
+
+    /// Get `css` imported from `@emotion/react`, adding the import if it's not imported yet.
+    fn css_binding(&mut self, ctx: &mut TraverseCtx<'a>) -> Expression<'a> {
+        let binding = self.css_binding.get_or_insert_with(|| {
+            let binding = ctx.generate_uid_in_root_scope("css", SymbolFlags::Import);
+            self.ctx.module_imports.add_named_import(
+                Atom::from(EMOTION_REACT),
+                Atom::from("css"),
+                binding.clone(),
+                false,
+            );
+            binding
+        });
+        self.css_symbols.insert(binding.symbol_id);
+        binding.create_read_expression(ctx)
+    }
+
+    /// Format the label for the styles currently being visited, according to `labelFormat`.
+    ///
+    /// Returns `None` if `[local]` is required but no name can be inferred.
+    fn label(&self, ctx: &TraverseCtx<'a>) -> Option<String> {
+        let mut label = self.options.label_format.clone();
+        if label.contains("[local]") {
+            let local = Self::local_name(ctx)?;
+            label = label.cow_replace("[local]", &sanitize_label(local)).into_owned();
+        }
+        if label.contains("[filename]") {
+            let filename = sanitize_label(&self.ctx.filename);
+            label = label.cow_replace("[filename]", &filename).into_owned();
+        }
+        if label.contains("[dirname]") {
+            let dirname = self
+                .ctx
+                .source_path
+                .parent()
+                .and_then(Path::file_name)
+                .map(|name| name.to_string_lossy().to_string())
+                .unwrap_or_default();
+            let dirname = sanitize_label(&dirname);
+            label = label.cow_replace("[dirname]", &dirname).into_owned();
+        }
+        Some(label)
+    }
+
+    /// Find the name of the nearest variable, function, class or property enclosing the current node.
+    fn local_name<'b>(ctx: &'b TraverseCtx<'a>) -> Option<&'b str> {
+        for ancestor in ctx.ancestors() {
+            match ancestor {
+                // `const foo = css({})`
+                Ancestor::VariableDeclaratorInit(declarator) => {
+                    if let BindingPatternKind::BindingIdentifier(ident) = &declarator.id().kind {
+                        return Some(ident.name.as_str());
+                    }
+                }
+                // `{ foo: css({}) }`
+                Ancestor::ObjectPropertyValue(prop) => {
+                    if let PropertyKey::StaticIdentifier(ident) = prop.key() {
+                        return Some(ident.name.as_str());
+                    }
+                }
+                // `class A { foo = css({}) }`
+                Ancestor::PropertyDefinitionValue(prop) => {
+                    if let PropertyKey::StaticIdentifier(ident) = prop.key() {
+                        return Some(ident.name.as_str());
+                    }
+                }
+                // `function Foo() { return <div css={{}} /> }`
+                Ancestor::FunctionBody(func) => {
+                    if let Some(id) = func.id() {
+                        return Some(id.name.as_str());
+                    }
+                }
+                // `class Foo { render() { return <div css={{}} /> } }`
+                Ancestor::ClassBody(class) => {
+                    if let Some(id) = class.id() {
+                        return Some(id.name.as_str());
+                    }
+                }
+                _ => {}
+            }
+        }
+        None
+    }
+
+    /// Build `/*# sourceMappingURL=data:application/json;charset=utf-8;base64,... */`
+    /// pointing at the start of `span` in the original source.
+    fn source_map_comment(&self, span: Span) -> String {
+        let source_text = self.ctx.source_text;
+        let before = &source_text[..span.start as usize];
+        let line = before.matches('\n').count();
+        let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+        let column = before[line_start..].encode_utf16().count();
+
+        // Single mapping: generated `0:0` -> source `0`, `line:column`
+        let mut mappings = String::from("AA");
+        encode_vlq(&mut mappings, line);
+        encode_vlq(&mut mappings, column);
+
+        let file = self.file_name();
+        let source_map = serde_json::json!({
+            "version": 3,
+            "sources": [file],
+            "names": [],
+            "mappings": mappings,
+            "file": file,
+            "sourcesContent": [source_text],
+        });
+        format!(
+            "/*# sourceMappingURL=data:application/json;charset=utf-8;base64,{} */",
+            BASE64_STANDARD.encode(source_map.to_string())
+        )
+    }
+
+    fn file_name(&self) -> String {
+        self.ctx
+            .source_path
+            .file_name()
+            .map_or_else(|| String::from("unknown"), |name| name.to_string_lossy().to_string())
+    }
+
+    fn file_hash(&self) -> String {
+        murmur2(&self.file_name())
+    }
+}
+
+/// Replace characters which are not valid in a class name with `-`.
+fn sanitize_label(s: &str) -> String {
+    s.trim()
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '_' || c == '-' { c } else { '-' })
+        .collect()
+}
+
+/// Base64 VLQ encoding, as used in source map `mappings`.
+fn encode_vlq(out: &mut String, value: usize) {
+    const CHARS: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    // Values are never negative, so sign bit is always 0
+    let mut value = value << 1;
+    loop {
+        let mut digit = value & 0b1_1111;
+        value >>= 5;
+        if value > 0 {
+            digit |= 0b10_0000;
+        }
+        out.push(CHARS[digit] as char);
+        if value == 0 {
+            break;
+        }
+    }
+}
+
+/// Port of [@emotion/hash](https://github.com/emotion-js/emotion/blob/main/packages/hash/src/index.ts).
+///
+/// MurmurHash2 of the UTF-16 code units of `s` (truncated to bytes), formatted in base 36.
+fn murmur2(s: &str) -> String {
+    const M: u32 = 0x5bd1_e995;
+
+    let bytes = s.encode_utf16().map(|c| u32::from(c & 0xff)).collect::<Vec<_>>();
+    let mut h: u32 = 0;
+    let mut chunks = bytes.chunks_exact(4);
+    for chunk in &mut chunks {
+        let mut k = chunk[0] | (chunk[1] << 8) | (chunk[2] << 16) | (chunk[3] << 24);
+        k = k.wrapping_mul(M);
+        k ^= k >> 24;
+        h = k.wrapping_mul(M) ^ h.wrapping_mul(M);
+    }
+    let rest = chunks.remainder();
+    if !rest.is_empty() {
+        if rest.len() == 3 {
+            h ^= rest[2] << 16;
+        }
+        if rest.len() >= 2 {
+            h ^= rest[1] << 8;
+        }
+        h ^= rest[0];
+        h = h.wrapping_mul(M);
+    }
+    h ^= h >> 13;
+    h = h.wrapping_mul(M);
+    h ^= h >> 15;
+
+    let mut digits = Vec::new();
+    loop {
+        let digit = h % 36;
+        digits.push(char::from_digit(digit, 36).unwrap());
+        h /= 36;
+        if h == 0 {
+            break;
+        }
+    }
+    digits.iter().rev().collect()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{encode_vlq, murmur2};
+
+    #[test]
+    fn hash() {
+        assert_eq!(murmur2(""), "0");
+        assert_ne!(murmur2("input.js"), murmur2("input.jsx"));
+    }
+
+    #[test]
+    fn vlq() {
+        let mut out = String::new();
+        encode_vlq(&mut out, 0);
+        encode_vlq(&mut out, 16);
+        encode_vlq(&mut out, 1);
+        assert_eq!(out, "AgBC");
+    }
+}
@@ -0,0 +1,190 @@
+//! Fully Specified Imports
+//!
+//! Rewrites relative import sources into the fully specified paths that Node.js requires for
+//! ES modules, so that the output can be published without a post-processing step.
+//!
+//! ## Example
+//!
+//! Input (`src/index.ts`, with `src/utils.ts` and `src/components/index.tsx` on disk):
+//! ```js
+//! import { a } from "./utils";
+//! import { b } from "./utils.ts";
+//! export * from "./components";
+//! const c = await import("../package.json");
+//! ```
+//!
+//! Output:
+//! ```js
+//! import { a } from "./utils.js";
+//! import { b } from "./utils.js";
+//! export * from "./components/index.js";
+//! const c = await import("../package.json");
+//! ```
+//!
+//! ## Implementation
+//!
+//! Only sources starting with `./` or `../` are rewritten. Imports are left as ES modules, so this
+//! does nothing when `module` is `commonjs`.
+//!
+//! * `.ts`, `.tsx` and `.jsx` extensions become `.js`, `.mts` becomes `.mjs` and `.cts` becomes `.cjs`.
+//! * Other sources are looked up relative to the transformed file. Files are left untouched,
+//!   a directory becomes its `index` file, and a source file with a JavaScript or TypeScript
+//!   extension gets the extension of its output.
+//! * Sources which are not on disk get `.js`, or `index.js` when they end with `/`,
+//!   unless they already have an extension, e.g. `.js`, `.json` or `.css`.
+//!
+//! Type-only imports and exports are left untouched, as they are removed anyway.
+
+use std::path::Path;
+
+use oxc_ast::ast::*;
+use oxc_traverse::Traverse;
+
+use crate::{
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+};
+
+/// Extensions of source files, and the extensions of the files they are compiled to.
+const EXTENSIONS: &[(&str, &str)] = &[
+    ("ts", "js"),
+    ("tsx", "js"),
+    ("mts", "mjs"),
+    ("cts", "cjs"),
+    ("js", "js"),
+    ("jsx", "js"),
+    ("mjs", "mjs"),
+    ("cjs", "cjs"),
+];
+
+pub struct FullySpecified<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+}
+
+impl<'a, 'ctx> FullySpecified<'a, 'ctx> {
+    pub fn new(ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self { ctx }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for FullySpecified<'a, '_> {
+    fn enter_import_declaration(
+        &mut self,
+        node: &mut ImportDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if node.import_kind.is_type() {
+            return;
+        }
+        self.rewrite(&mut node.source, ctx);
+    }
+
+    fn enter_export_named_declaration(
+        &mut self,
+        node: &mut ExportNamedDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if node.export_kind.is_type() {
+            return;
+        }
+        if let Some(source) = node.source.as_mut() {
+            self.rewrite(source, ctx);
+        }
+    }
+
+    fn enter_export_all_declaration(
+        &mut self,
+        node: &mut ExportAllDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if node.export_kind.is_type() {
+            return;
+        }
+        self.rewrite(&mut node.source, ctx);
+    }
+
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        // `import("./foo")`
+        if let Expression::ImportExpression(import) = expr {
+            if let Expression::StringLiteral(source) = &mut import.source {
+                self.rewrite(source, ctx);
+            }
+        }
+    }
+}
+
+impl<'a> FullySpecified<'a, '_> {
+    fn rewrite(&self, source: &mut StringLiteral<'a>, ctx: &TraverseCtx<'a>) {
+        if self.ctx.module.is_commonjs() {
+            return;
+        }
+        let dir = self.ctx.source_path.parent().unwrap_or(Path::new(""));
+        if let Some(specified) = fully_specify(source.value.as_str(), dir) {
+            source.value = ctx.ast.atom(&specified);
+            source.raw = None;
+        }
+    }
+}
+
+/// The fully specified form of the relative `source` imported from a file in `dir`,
+/// or `None` if `source` is not relative or is already fully specified.
+fn fully_specify(source: &str, dir: &Path) -> Option<String> {
+    if !(source.starts_with("./") || source.starts_with("../") || source == "." || source == "..") {
+        return None;
+    }
+    // Keep the query and hash, e.g. `./worker?url`.
+    let (path, suffix) = source.split_at(source.find(['?', '#']).unwrap_or(source.len()));
+
+    if path.ends_with('/') || path == "." || path == ".." {
+        let path = path.trim_end_matches('/');
+        return Some(format!("{path}/{}{suffix}", resolve_index(&dir.join(path))));
+    }
+
+    let file_name = path.rsplit('/').next().unwrap_or(path);
+    if let Some((stem, extension)) = file_name.rsplit_once('.').filter(|(stem, _)| !stem.is_empty())
+    {
+        let output_extension = match extension {
+            "ts" | "tsx" | "jsx" => Some("js"),
+            "mts" => Some("mjs"),
+            "cts" => Some("cjs"),
+            _ => None,
+        };
+        if let Some(output_extension) = output_extension {
+            let dir_name = &path[..path.len() - file_name.len()];
+            return Some(format!("{dir_name}{stem}.{output_extension}{suffix}"));
+        }
+    }
+
+    let full_path = dir.join(path);
+    if full_path.is_file() {
+        return None;
+    }
+    if full_path.is_dir() {
+        return Some(format!("{path}/{}{suffix}", resolve_index(&full_path)));
+    }
+    if let Some(extension) = resolve_extension(&full_path) {
+        return Some(format!("{path}.{extension}{suffix}"));
+    }
+    // Not on disk: `./foo.config` or `./style.css` may already be complete.
+    if file_name.contains('.') {
+        return None;
+    }
+    Some(format!("{path}.js{suffix}"))
+}
+
+/// The output file name of the index file of the directory at `dir`.
+fn resolve_index(dir: &Path) -> String {
+    let extension = resolve_extension(&dir.join("index")).unwrap_or("js");
+    format!("index.{extension}")
+}
+
+/// The output extension of the source file at `path` with any of the [EXTENSIONS].
+fn resolve_extension(path: &Path) -> Option<&'static str> {
+    let path = path.as_os_str();
+    EXTENSIONS.iter().find_map(|(extension, output_extension)| {
+        let mut file = path.to_os_string();
+        file.push(".");
+        file.push(extension);
+        Path::new(&file).is_file().then_some(*output_extension)
+    })
+}
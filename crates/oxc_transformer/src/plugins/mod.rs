@@ -0,0 +1,112 @@
+use oxc_ast::ast::*;
+use oxc_traverse::Traverse;
+
+use crate::{
+    context::{TransformCtx, TraverseCtx},
+    jsx::JsxOptions,
+    state::TransformState,
+};
+
+mod asset_imports;
+mod diagnostics;
+mod emotion;
+mod fully_specified;
+mod modular_imports;
+mod options;
+
+use asset_imports::AssetImports;
+use emotion::Emotion;
+use fully_specified::FullySpecified;
+use modular_imports::ModularImports;
+pub use options::{
+    AssetImportAction, AssetImportOptions, EmotionAutoLabel, EmotionOptions, ModularImportOptions,
+    ModularImportStyle, PluginsOptions,
+};
+
+/// Plugins which are not part of a Babel preset.
+pub struct Plugins<'a, 'ctx> {
+    emotion: Option<Emotion<'a, 'ctx>>,
+    modular_imports: Option<ModularImports>,
+    asset_imports: Option<AssetImports<'a, 'ctx>>,
+    fully_specified: Option<FullySpecified<'a, 'ctx>>,
+}
+
+impl<'a, 'ctx> Plugins<'a, 'ctx> {
+    pub fn new(options: &PluginsOptions, jsx: &JsxOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self {
+            emotion: options.emotion.clone().map(|options| Emotion::new(options, jsx, ctx)),
+            modular_imports: options
+                .modular_imports
+                .clone()
+                .map(|options| ModularImports::new(options, ctx)),
+            asset_imports: options
+                .asset_imports
+                .clone()
+                .map(|options| AssetImports::new(options, ctx)),
+            fully_specified: options.fully_specified.then(|| FullySpecified::new(ctx)),
+        }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for Plugins<'a, '_> {
+    fn enter_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(modular_imports) = &mut self.modular_imports {
+            modular_imports.enter_program(program, ctx);
+        }
+        if let Some(asset_imports) = &mut self.asset_imports {
+            asset_imports.enter_program(program, ctx);
+        }
+        if let Some(emotion) = &mut self.emotion {
+            emotion.enter_program(program, ctx);
+        }
+    }
+
+    fn enter_expression(&mut self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(emotion) = &mut self.emotion {
+            emotion.enter_expression(expr, ctx);
+        }
+        if let Some(fully_specified) = &mut self.fully_specified {
+            fully_specified.enter_expression(expr, ctx);
+        }
+    }
+
+    fn enter_import_declaration(
+        &mut self,
+        node: &mut ImportDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if let Some(fully_specified) = &mut self.fully_specified {
+            fully_specified.enter_import_declaration(node, ctx);
+        }
+    }
+
+    fn enter_export_all_declaration(
+        &mut self,
+        node: &mut ExportAllDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if let Some(fully_specified) = &mut self.fully_specified {
+            fully_specified.enter_export_all_declaration(node, ctx);
+        }
+    }
+
+    fn enter_export_named_declaration(
+        &mut self,
+        node: &mut ExportNamedDeclaration<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if let Some(fully_specified) = &mut self.fully_specified {
+            fully_specified.enter_export_named_declaration(node, ctx);
+        }
+    }
+
+    fn enter_jsx_opening_element(
+        &mut self,
+        elem: &mut JSXOpeningElement<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if let Some(emotion) = &mut self.emotion {
+            emotion.enter_jsx_opening_element(elem, ctx);
+        }
+    }
+}
@@ -0,0 +1,201 @@
+//! Modular Imports
+//!
+//! Rewrites member imports of configured libraries into imports of the individual modules,
+//! so only the used modules are bundled even without tree shaking.
+//!
+//! ## Example
+//!
+//! Input (with `[{ "libraryName": "antd", "style": "css" }, { "libraryName": "lodash", "libraryDirectory": "", "camel2DashComponentName": false }]`):
+//! ```js
+//! import { Button, DatePicker as Picker } from "antd";
+//! import { debounce } from "lodash";
+//! ```
+//!
+//! Output:
+//! ```js
+//! import Button from "antd/lib/button";
+//! import "antd/lib/button/style/css";
+//! import Picker from "antd/lib/date-picker";
+//! import "antd/lib/date-picker/style/css";
+//! import debounce from "lodash/debounce";
+//! ```
+//!
+//! ## Implementation
+//!
+//! Implementation based on [babel-plugin-import](https://github.com/umijs/babel-plugin-import).
+//!
+//! Differences from Babel:
+//! * Every named import is rewritten, whether it is referenced or not.
+//! * Default and namespace imports of the library are left untouched.
+//! * `customName` is a template string with a `{{member}}` placeholder rather than a function.
+
+use cow_utils::CowUtils;
+
+use oxc_allocator::{TakeIn, Vec as ArenaVec};
+use oxc_ast::{NONE, ast::*};
+use oxc_span::{Atom, SPAN, Span};
+use oxc_traverse::Traverse;
+
+use crate::{
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+};
+
+use super::{
+    diagnostics,
+    options::{ModularImportOptions, ModularImportStyle},
+};
+
+pub struct ModularImports {
+    options: Vec<ModularImportOptions>,
+}
+
+impl ModularImports {
+    pub fn new(mut options: Vec<ModularImportOptions>, ctx: &TransformCtx<'_>) -> Self {
+        options.retain(|options| {
+            let valid = !options.library_name.is_empty();
+            if !valid {
+                ctx.error(diagnostics::modular_imports_missing_library_name());
+            }
+            valid
+        });
+        Self { options }
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for ModularImports {
+    fn enter_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        let has_library_import = program.body.iter().any(|stmt| {
+            matches!(stmt, Statement::ImportDeclaration(decl) if self.library(decl).is_some())
+        });
+        if !has_library_import {
+            return;
+        }
+
+        let mut body = ctx.ast.vec_with_capacity(program.body.len());
+        for stmt in program.body.take_in(ctx.ast) {
+            match stmt {
+                Statement::ImportDeclaration(mut decl) => {
+                    let keep = self.library(&decl).is_none_or(|library| {
+                        Self::transform_import(library, &mut decl, &mut body, ctx)
+                    });
+                    if keep {
+                        body.push(Statement::ImportDeclaration(decl));
+                    }
+                }
+                stmt => body.push(stmt),
+            }
+        }
+        program.body = body;
+    }
+}
+
+impl<'a> ModularImports {
+    /// Get options of the library imported by `decl`, if it's a value import of a configured library.
+    fn library(&self, decl: &ImportDeclaration<'_>) -> Option<&ModularImportOptions> {
+        if decl.import_kind.is_type() || decl.specifiers.is_none() {
+            return None;
+        }
+        self.options.iter().find(|options| options.library_name == decl.source.value.as_str())
+    }
+
+    /// Move named specifiers of `decl` into per-module imports pushed to `body`.
+    /// Returns `false` if no specifiers are left in `decl`, so it should be removed.
+    ///
+    /// `import { A, B } from "lib"` -> `import A from "lib/lib/a"; import B from "lib/lib/b";`
+    fn transform_import(
+        options: &ModularImportOptions,
+        decl: &mut ImportDeclaration<'a>,
+        body: &mut ArenaVec<'a, Statement<'a>>,
+        ctx: &TraverseCtx<'a>,
+    ) -> bool {
+        let Some(specifiers) = &mut decl.specifiers else { return true };
+        // `import {} from "lib"` is a side-effect import
+        if specifiers.is_empty() {
+            return true;
+        }
+        let mut kept = ctx.ast.vec_with_capacity(specifiers.len());
+        for specifier in specifiers.take_in(ctx.ast) {
+            let ImportDeclarationSpecifier::ImportSpecifier(specifier) = specifier else {
+                kept.push(specifier);
+                continue;
+            };
+            let imported = specifier.imported.name();
+            if specifier.import_kind.is_type() || imported == "default" {
+                kept.push(ImportDeclarationSpecifier::ImportSpecifier(specifier));
+                continue;
+            }
+
+            let path = ctx.ast.atom(&Self::module_path(options, &imported));
+            let specifier = specifier.unbox();
+            let new_specifier = if options.transform_to_default_import {
+                ImportDeclarationSpecifier::ImportDefaultSpecifier(
+                    ctx.ast.alloc_import_default_specifier(specifier.span, specifier.local),
+                )
+            } else {
+                ImportDeclarationSpecifier::ImportSpecifier(ctx.ast.alloc(specifier))
+            };
+            body.push(Self::create_import(decl.span, Some(ctx.ast.vec1(new_specifier)), path, ctx));
+
+            if let Some(style) = options.style {
+                let style_path = match style {
+                    ModularImportStyle::Source => format!("{path}/style"),
+                    ModularImportStyle::Css => format!("{path}/style/css"),
+                };
+                body.push(Self::create_import(SPAN, None, ctx.ast.atom(&style_path), ctx));
+            }
+        }
+        let keep = !kept.is_empty();
+        *specifiers = kept;
+        keep
+    }
+
+    /// `DatePicker` -> `antd/lib/date-picker`
+    fn module_path(options: &ModularImportOptions, imported: &str) -> String {
+        let member = if options.camel_to_dash_component_name {
+            camel_to_dash(imported)
+        } else {
+            imported.to_string()
+        };
+        if let Some(custom_name) = &options.custom_name {
+            return custom_name.cow_replace("{{member}}", &member).into_owned();
+        }
+        if options.library_directory.is_empty() {
+            format!("{}/{member}", options.library_name)
+        } else {
+            format!("{}/{}/{member}", options.library_name, options.library_directory)
+        }
+    }
+
+    fn create_import(
+        span: Span,
+        specifiers: Option<ArenaVec<'a, ImportDeclarationSpecifier<'a>>>,
+        source: Atom<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) -> Statement<'a> {
+        Statement::from(ctx.ast.module_declaration_import_declaration(
+            span,
+            specifiers,
+            ctx.ast.string_literal(SPAN, source, None),
+            None,
+            NONE,
+            ImportOrExportKind::Value,
+        ))
+    }
+}
+
+/// `DatePicker` -> `date-picker`
+fn camel_to_dash(name: &str) -> String {
+    let mut result = String::with_capacity(name.len() + 4);
+    for (i, c) in name.chars().enumerate() {
+        if c.is_ascii_uppercase() {
+            if i != 0 {
+                result.push('-');
+            }
+            result.push(c.to_ascii_lowercase());
+        } else {
+            result.push(c);
+        }
+    }
+    result
+}
@@ -0,0 +1,241 @@
+use std::fmt;
+
+use serde::{
+    Deserialize, Deserializer,
+    de::{self, Visitor},
+};
+
+/// Options for plugins which are not part of a Babel preset.
+#[derive(Debug, Default, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct PluginsOptions {
+    /// [Emotion](https://emotion.sh/docs/@emotion/babel-plugin)
+    pub emotion: Option<EmotionOptions>,
+
+    /// Rewrite member imports of libraries into per-module imports,
+    /// like [babel-plugin-import](https://github.com/umijs/babel-plugin-import).
+    pub modular_imports: Option<Vec<ModularImportOptions>>,
+
+    /// Rewrite imports of assets such as CSS modules or SVG files,
+    /// e.g. to drop them or to resolve them to URLs.
+    pub asset_imports: Option<Vec<AssetImportOptions>>,
+
+    /// Rewrite relative import sources into the fully specified paths Node.js requires for ES modules,
+    /// e.g. `./utils` -> `./utils.js`, `./utils.ts` -> `./utils.js`, `./components` -> `./components/index.js`.
+    ///
+    /// Imports are kept as ES modules, so this does nothing when `module` is `commonjs`.
+    pub fully_specified: bool,
+}
+
+/// Options for the Emotion transform.
+///
+/// Mirrors the options of [@emotion/babel-plugin](https://emotion.sh/docs/@emotion/babel-plugin#options).
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default, rename_all = "camelCase", deny_unknown_fields)]
+pub struct EmotionOptions {
+    /// When to add a `label` to styles, so class names contain the name of the variable or component.
+    ///
+    /// Defaults to `dev-only`.
+    pub auto_label: EmotionAutoLabel,
+
+    /// Format of the label. Supports the `[local]`, `[filename]` and `[dirname]` placeholders.
+    ///
+    /// Defaults to `[local]`.
+    pub label_format: String,
+
+    /// Add an inline source map pointing at the styles' location in development.
+    ///
+    /// Defaults to `true`.
+    pub source_map: bool,
+}
+
+impl Default for EmotionOptions {
+    fn default() -> Self {
+        Self {
+            auto_label: EmotionAutoLabel::default(),
+            label_format: String::from("[local]"),
+            source_map: true,
+        }
+    }
+}
+
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum EmotionAutoLabel {
+    /// Only add labels when JSX is compiled in development mode.
+    #[default]
+    DevOnly,
+    Always,
+    Never,
+}
+
+/// Options for rewriting member imports of a single library.
+///
+/// ```js
+/// import { Button } from "antd";
+/// // with `{ "libraryName": "antd", "style": true }` becomes
+/// import Button from "antd/lib/button";
+/// import "antd/lib/button/style";
+/// ```
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct ModularImportOptions {
+    /// Name of the library whose member imports are rewritten, e.g. `antd`.
+    ///
+    /// Required.
+    pub library_name: String,
+
+    /// Directory inside the library that contains one module per member.
+    ///
+    /// Defaults to `lib`.
+    #[serde(default = "default_library_directory")]
+    pub library_directory: String,
+
+    /// Convert member names from `CamelCase` to `dash-case` in module paths,
+    /// e.g. `DatePicker` -> `date-picker`.
+    ///
+    /// Defaults to `true`.
+    #[serde(rename = "camel2DashComponentName", default = "default_as_true")]
+    pub camel_to_dash_component_name: bool,
+
+    /// Import the default export of the per-module file.
+    /// When `false`, the member is imported by name instead.
+    ///
+    /// Defaults to `true`.
+    #[serde(default = "default_as_true")]
+    pub transform_to_default_import: bool,
+
+    /// Also import the member's styles.
+    ///
+    /// `true` imports `<module>/style`, `"css"` imports `<module>/style/css`.
+    ///
+    /// Defaults to `false`.
+    #[serde(default, deserialize_with = "deserialize_modular_import_style")]
+    pub style: Option<ModularImportStyle>,
+
+    /// Template for the module path, overriding `libraryDirectory`.
+    /// `{{member}}` is replaced by the (converted) member name, e.g. `lodash/{{member}}`.
+    #[serde(default)]
+    pub custom_name: Option<String>,
+}
+
+impl ModularImportOptions {
+    /// Options for `library_name`, with the other options set to their defaults.
+    pub fn new(library_name: impl Into<String>) -> Self {
+        Self {
+            library_name: library_name.into(),
+            library_directory: default_library_directory(),
+            camel_to_dash_component_name: true,
+            transform_to_default_import: true,
+            style: None,
+            custom_name: None,
+        }
+    }
+}
+
+fn default_library_directory() -> String {
+    String::from("lib")
+}
+
+fn default_as_true() -> bool {
+    true
+}
+
+/// Options for rewriting imports of assets matching a single pattern.
+///
+/// ```js
+/// import Icon from "./icon.svg?react";
+/// // with `{ "test": "*.svg?react", "action": "component", "exportName": "ReactComponent" }` becomes
+/// import { ReactComponent as Icon } from "./icon.svg";
+/// ```
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct AssetImportOptions {
+    /// Pattern the import source must match, e.g. `*.module.css` or `*.svg?react`.
+    /// `*` matches any characters, the other characters match themselves.
+    ///
+    /// Required.
+    pub test: String,
+
+    /// How the matching imports are rewritten.
+    pub action: AssetImportAction,
+
+    /// Template for the rewritten import source of the `component` action.
+    /// `{{path}}` is replaced by the source without its query, `{{source}}` by the whole source,
+    /// e.g. `{{path}}?component`.
+    ///
+    /// Defaults to `{{path}}`.
+    #[serde(default)]
+    pub source: Option<String>,
+
+    /// Named export the default import is bound to by the `component` action,
+    /// e.g. `ReactComponent`. When unset, the default import is kept.
+    #[serde(default)]
+    pub export_name: Option<String>,
+}
+
+impl AssetImportOptions {
+    /// Options applying `action` to the imports matching `test`.
+    pub fn new(test: impl Into<String>, action: AssetImportAction) -> Self {
+        Self { test: test.into(), action, source: None, export_name: None }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum AssetImportAction {
+    /// Remove the import, binding default and namespace imports to `{}`
+    /// and named imports to `undefined`.
+    Remove,
+    /// Bind the default import to the URL of the asset,
+    /// `new URL(source, import.meta.url).href`.
+    Url,
+    /// Import a component from the rewritten source, see `source` and `exportName`.
+    Component,
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum ModularImportStyle {
+    /// `import "<module>/style"`
+    Source,
+    /// `import "<module>/style/css"`
+    Css,
+}
+
+fn deserialize_modular_import_style<'de, D>(
+    deserializer: D,
+) -> Result<Option<ModularImportStyle>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    struct ModularImportStyleVisitor;
+
+    impl Visitor<'_> for ModularImportStyleVisitor {
+        type Value = Option<ModularImportStyle>;
+
+        fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+            formatter.write_str("true, false, or \"css\"")
+        }
+
+        fn visit_bool<E>(self, value: bool) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            Ok(value.then_some(ModularImportStyle::Source))
+        }
+
+        fn visit_str<E>(self, value: &str) -> Result<Self::Value, E>
+        where
+            E: de::Error,
+        {
+            match value {
+                "css" => Ok(Some(ModularImportStyle::Css)),
+                _ => Err(E::custom(format!(
+                    "Expected `style` to be a boolean or \"css\" but found: {value}"
+                ))),
+            }
+        }
+    }
+
+    deserializer.deserialize_any(ModularImportStyleVisitor)
+}
@@ -0,0 +1,450 @@
+//! Lower a RegExp pattern into an equivalent pattern which doesn't use unsupported features.
+//!
+//! * Unicode flag (`u`): Astral characters are split into surrogate pairs, and `.`, negated
+//!   character class escapes and character classes are expanded so they match a whole surrogate pair.
+//! * DotAll flag (`s`): `.` is replaced with `[\s\S]`.
+//! * Named capture groups: Names are removed, and named backreferences are replaced with indexed ones.
+//!   The caller is responsible for restoring `groups` at runtime with the `wrapRegExp` helper.
+//!
+//! Source text of any part of the pattern which doesn't need to change is copied as is.
+//!
+//! Anything else (lookbehind assertions, unicode property escapes, case-insensitive matching in
+//! `u` mode, the `v` flag, ...) can't be expressed without the feature, so `lower` returns `None`,
+//! and the caller falls back to `new RegExp(...)`.
+
+use std::fmt::Write;
+
+use oxc_ast::ast::RegExpFlags;
+use oxc_regular_expression::ast::{
+    Alternative, Character, CharacterClass, CharacterClassContents, CharacterClassEscape,
+    CharacterClassEscapeKind, Disjunction, LookAroundAssertionKind, Pattern, Term,
+};
+use oxc_span::{Atom, GetSpan, Span};
+
+/// Matches a surrogate pair, i.e. an astral character in `u` mode.
+const SURROGATE_PAIR: &str = r"[\uD800-\uDBFF][\uDC00-\uDFFF]";
+
+pub struct LoweredPattern<'a> {
+    pub text: String,
+    pub flags: RegExpFlags,
+    /// Names of capture groups which were removed, with their indices.
+    pub group_names: Vec<(Atom<'a>, u32)>,
+}
+
+struct Lowering<'a, 's> {
+    source: &'s str,
+    source_start: u32,
+    /// Remove `u` flag
+    unicode: bool,
+    /// Remove `s` flag
+    dot_all: bool,
+    /// Pattern has `s` flag
+    has_dot_all_flag: bool,
+    look_behind_assertions: bool,
+    unicode_property_escapes: bool,
+    named_capture_groups: bool,
+    group_names: Vec<(Atom<'a>, u32)>,
+    output: String,
+}
+
+/// Lower `pattern` (with source text `text`) so it doesn't contain any of `unsupported_flags`
+/// or the unsupported syntax.
+///
+/// Returns `None` if the pattern can't be lowered.
+pub fn lower<'a>(
+    pattern: &Pattern<'a>,
+    text: &str,
+    flags: RegExpFlags,
+    unsupported_flags: RegExpFlags,
+    look_behind_assertions: bool,
+    unicode_property_escapes: bool,
+    named_capture_groups: bool,
+) -> Option<LoweredPattern<'a>> {
+    let removed_flags = flags & unsupported_flags;
+    // `y` and `d` flags change matching behavior, and `v` flag has syntax we don't lower
+    if flags.contains(RegExpFlags::V) || !(RegExpFlags::U | RegExpFlags::S).contains(removed_flags)
+    {
+        return None;
+    }
+    let unicode = removed_flags.contains(RegExpFlags::U);
+    // Case folding is different in `u` mode (e.g. `/s/iu` matches `ſ`)
+    if unicode && flags.contains(RegExpFlags::I) {
+        return None;
+    }
+    if pattern.span.size() as usize != text.len() {
+        return None;
+    }
+
+    let mut lowering = Lowering {
+        source: text,
+        source_start: pattern.span.start,
+        unicode,
+        dot_all: removed_flags.contains(RegExpFlags::S),
+        has_dot_all_flag: flags.contains(RegExpFlags::S),
+        look_behind_assertions,
+        unicode_property_escapes,
+        named_capture_groups,
+        group_names: vec![],
+        output: String::with_capacity(text.len()),
+    };
+    if named_capture_groups {
+        let mut index = 0;
+        lowering.collect_group_names(&pattern.body, &mut index)?;
+    }
+    lowering.disjunction(&pattern.body)?;
+
+    Some(LoweredPattern {
+        text: lowering.output,
+        flags: flags - removed_flags,
+        group_names: lowering.group_names,
+    })
+}
+
+impl<'a, 's> Lowering<'a, 's> {
+    /// Collect names of capture groups, in order of their opening parentheses.
+    /// Returns `None` if a name is duplicated.
+    fn collect_group_names(
+        &mut self,
+        disjunction: &Disjunction<'a>,
+        index: &mut u32,
+    ) -> Option<()> {
+        for alternative in &disjunction.body {
+            for term in &alternative.body {
+                let mut term = term;
+                while let Term::Quantifier(quantifier) = term {
+                    term = &quantifier.body;
+                }
+                match term {
+                    Term::CapturingGroup(group) => {
+                        *index += 1;
+                        if let Some(name) = group.name {
+                            if self.group_names.iter().any(|(n, _)| *n == name) {
+                                return None;
+                            }
+                            self.group_names.push((name, *index));
+                        }
+                        self.collect_group_names(&group.body, index)?;
+                    }
+                    Term::IgnoreGroup(group) => self.collect_group_names(&group.body, index)?,
+                    Term::LookAroundAssertion(assertion) => {
+                        self.collect_group_names(&assertion.body, index)?;
+                    }
+                    _ => {}
+                }
+            }
+        }
+        Some(())
+    }
+
+    fn disjunction(&mut self, disjunction: &Disjunction<'a>) -> Option<()> {
+        for (i, alternative) in disjunction.body.iter().enumerate() {
+            if i > 0 {
+                self.output.push('|');
+            }
+            self.alternative(alternative)?;
+        }
+        Some(())
+    }
+
+    fn alternative(&mut self, alternative: &Alternative<'a>) -> Option<()> {
+        for (i, term) in alternative.body.iter().enumerate() {
+            self.term(term)?;
+            // `\k<a>0` -> `\1(?:)0`, not `\10`
+            if self.named_capture_groups
+                && matches!(term, Term::NamedReference(_))
+                && alternative.body.get(i + 1).is_some_and(|next| {
+                    matches!(next, Term::Character(_) | Term::Quantifier(_))
+                        && self.slice(next.span()).starts_with(|c: char| c.is_ascii_digit())
+                })
+            {
+                self.output.push_str("(?:)");
+            }
+        }
+        Some(())
+    }
+
+    fn term(&mut self, term: &Term<'a>) -> Option<()> {
+        match term {
+            Term::BoundaryAssertion(assertion) => self.copy(assertion.span),
+            Term::LookAroundAssertion(assertion) => {
+                let prefix = match assertion.kind {
+                    LookAroundAssertionKind::Lookahead => "(?=",
+                    LookAroundAssertionKind::NegativeLookahead => "(?!",
+                    LookAroundAssertionKind::Lookbehind
+                    | LookAroundAssertionKind::NegativeLookbehind
+                        if self.look_behind_assertions =>
+                    {
+                        return None;
+                    }
+                    LookAroundAssertionKind::Lookbehind => "(?<=",
+                    LookAroundAssertionKind::NegativeLookbehind => "(?<!",
+                };
+                self.output.push_str(prefix);
+                self.disjunction(&assertion.body)?;
+                self.output.push(')');
+            }
+            Term::Quantifier(quantifier) => {
+                let start = self.output.len();
+                self.term(&quantifier.body)?;
+                // `/😀+/u` -> `/(?:😀)+/`
+                if matches!(&quantifier.body, Term::Character(c) if self.unicode && c.value > 0xFFFF)
+                {
+                    self.output.insert_str(start, "(?:");
+                    self.output.push(')');
+                }
+                self.copy(Span::new(quantifier.body.span().end, quantifier.span.end));
+            }
+            Term::Character(character) => self.character(character)?,
+            Term::Dot(dot) => {
+                if self.unicode {
+                    self.output.push_str("(?:");
+                    self.output.push_str(SURROGATE_PAIR);
+                    self.output.push('|');
+                    self.output.push_str(if self.has_dot_all_flag {
+                        r"[\s\S]"
+                    } else {
+                        r"[^\n\r\u2028\u2029]"
+                    });
+                    self.output.push(')');
+                } else if self.dot_all {
+                    self.output.push_str(r"[\s\S]");
+                } else {
+                    self.copy(dot.span);
+                }
+            }
+            Term::CharacterClassEscape(escape) => {
+                // `/\S/u` -> `/(?:[\uD800-\uDBFF][\uDC00-\uDFFF]|\S)/`
+                if self.unicode && is_negative_escape(escape) {
+                    self.output.push_str("(?:");
+                    self.output.push_str(SURROGATE_PAIR);
+                    self.output.push('|');
+                    self.copy(escape.span);
+                    self.output.push(')');
+                } else {
+                    self.copy(escape.span);
+                }
+            }
+            Term::UnicodePropertyEscape(escape) => {
+                if self.unicode || self.unicode_property_escapes {
+                    return None;
+                }
+                self.copy(escape.span);
+            }
+            Term::CharacterClass(class) => self.character_class(class)?,
+            Term::CapturingGroup(group) => {
+                self.output.push('(');
+                if let Some(name) = group.name.filter(|_| !self.named_capture_groups) {
+                    self.output.push_str("?<");
+                    self.output.push_str(&name);
+                    self.output.push('>');
+                }
+                self.disjunction(&group.body)?;
+                self.output.push(')');
+            }
+            Term::IgnoreGroup(group) => {
+                if group.modifiers.is_some() {
+                    return None;
+                }
+                self.output.push_str("(?:");
+                self.disjunction(&group.body)?;
+                self.output.push(')');
+            }
+            Term::IndexedReference(reference) => self.copy(reference.span),
+            Term::NamedReference(reference) => {
+                if self.named_capture_groups {
+                    let (_, index) =
+                        self.group_names.iter().find(|(name, _)| *name == reference.name)?;
+                    self.output.push('\\');
+                    self.output.push_str(&index.to_string());
+                } else {
+                    self.copy(reference.span);
+                }
+            }
+        }
+        Some(())
+    }
+
+    fn character(&mut self, character: &Character) -> Option<()> {
+        if !self.unicode {
+            self.copy(character.span);
+            return Some(());
+        }
+        match character.value {
+            // A lone surrogate only matches a lone surrogate in `u` mode
+            0xD800..=0xDFFF => return None,
+            value @ 0x10000.. => {
+                let (lead, trail) = split_surrogate_pair(value);
+                push_escape(lead, &mut self.output);
+                push_escape(trail, &mut self.output);
+            }
+            value => {
+                // `\u{41}` is only valid in `u` mode
+                if self.slice(character.span).starts_with("\\u{") {
+                    push_escape(value, &mut self.output);
+                } else {
+                    self.copy(character.span);
+                }
+            }
+        }
+        Some(())
+    }
+
+    fn character_class(&mut self, class: &CharacterClass<'a>) -> Option<()> {
+        if !self.unicode {
+            if self.unicode_property_escapes
+                && class.body.iter().any(|content| {
+                    matches!(content, CharacterClassContents::UnicodePropertyEscape(_))
+                })
+            {
+                return None;
+            }
+            self.copy(class.span);
+            return Some(());
+        }
+
+        // Split contents into BMP characters, which stay in the class,
+        // and ranges of astral characters, which are matched as surrogate pairs
+        let mut bmp = String::new();
+        let mut astral = vec![];
+        for content in &class.body {
+            match content {
+                CharacterClassContents::CharacterClassRange(range) => {
+                    let (min, max) = (range.min.value, range.max.value);
+                    if min <= 0xDFFF && max >= 0xD800 {
+                        return None;
+                    }
+                    if max <= 0xFFFF {
+                        self.class_character(&range.min, &mut bmp);
+                        bmp.push('-');
+                        self.class_character(&range.max, &mut bmp);
+                    } else if min >= 0x10000 {
+                        astral.push((min, max));
+                    } else {
+                        self.class_character(&range.min, &mut bmp);
+                        bmp.push_str(r"-\uFFFF");
+                        astral.push((0x10000, max));
+                    }
+                }
+                CharacterClassContents::Character(character) => match character.value {
+                    0xD800..=0xDFFF => return None,
+                    value @ 0x10000.. => astral.push((value, value)),
+                    _ => self.class_character(character, &mut bmp),
+                },
+                // Negated escapes inside a class would also have to match surrogate pairs
+                CharacterClassContents::CharacterClassEscape(escape)
+                    if !is_negative_escape(escape) =>
+                {
+                    bmp.push_str(self.slice(escape.span));
+                }
+                _ => return None,
+            }
+        }
+
+        if class.negative {
+            if !astral.is_empty() {
+                return None;
+            }
+            // `/[^a]/u` -> `/(?:[\uD800-\uDBFF][\uDC00-\uDFFF]|[^a])/`
+            self.output.push_str("(?:");
+            self.output.push_str(SURROGATE_PAIR);
+            self.output.push_str("|[^");
+            self.output.push_str(&bmp);
+            self.output.push_str("])");
+        } else if astral.is_empty() {
+            self.output.push('[');
+            self.output.push_str(&bmp);
+            self.output.push(']');
+        } else {
+            // `/[a😀]/u` -> `/(?:[a]|😀)/`
+            self.output.push_str("(?:");
+            if !bmp.is_empty() {
+                self.output.push('[');
+                self.output.push_str(&bmp);
+                self.output.push_str("]|");
+            }
+            for (i, &(min, max)) in astral.iter().enumerate() {
+                if i > 0 {
+                    self.output.push('|');
+                }
+                push_astral_range(min, max, &mut self.output);
+            }
+            self.output.push(')');
+        }
+        Some(())
+    }
+
+    /// Push a BMP character inside a character class.
+    fn class_character(&self, character: &Character, output: &mut String) {
+        if self.slice(character.span).starts_with("\\u{") {
+            push_escape(character.value, output);
+        } else {
+            output.push_str(self.slice(character.span));
+        }
+    }
+
+    fn copy(&mut self, span: Span) {
+        self.output.push_str(self.slice(span));
+    }
+
+    fn slice(&self, span: Span) -> &'s str {
+        let start = (span.start - self.source_start) as usize;
+        let end = (span.end - self.source_start) as usize;
+        &self.source[start..end]
+    }
+}
+
+fn is_negative_escape(escape: &CharacterClassEscape) -> bool {
+    matches!(
+        escape.kind,
+        CharacterClassEscapeKind::NegativeD
+            | CharacterClassEscapeKind::NegativeS
+            | CharacterClassEscapeKind::NegativeW
+    )
+}
+
+fn split_surrogate_pair(code_point: u32) -> (u32, u32) {
+    let offset = code_point - 0x10000;
+    (0xD800 + (offset >> 10), 0xDC00 + (offset & 0x3FF))
+}
+
+/// Push `\uXXXX`.
+fn push_escape(code_unit: u32, output: &mut String) {
+    write!(output, "\\u{code_unit:04X}").unwrap();
+}
+
+/// Push `\uXXXX` if `min == max`, otherwise `[\uXXXX-\uYYYY]`.
+fn push_code_unit_range(min: u32, max: u32, output: &mut String) {
+    if min == max {
+        push_escape(min, output);
+    } else {
+        output.push('[');
+        push_escape(min, output);
+        output.push('-');
+        push_escape(max, output);
+        output.push(']');
+    }
+}
+
+/// Push alternatives matching surrogate pairs of all astral characters in `min..=max`.
+///
+/// `[\u{1F600}-\u{1F64F}]` -> `\uD83D[\uDE00-\uDE4F]`
+fn push_astral_range(min: u32, max: u32, output: &mut String) {
+    let (min_lead, min_trail) = split_surrogate_pair(min);
+    let (max_lead, max_trail) = split_surrogate_pair(max);
+    if min_lead == max_lead {
+        push_escape(min_lead, output);
+        push_code_unit_range(min_trail, max_trail, output);
+        return;
+    }
+
+    push_escape(min_lead, output);
+    push_code_unit_range(min_trail, 0xDFFF, output);
+    if max_lead - min_lead > 1 {
+        output.push('|');
+        push_code_unit_range(min_lead + 1, max_lead - 1, output);
+        push_code_unit_range(0xDC00, 0xDFFF, output);
+    }
+    output.push('|');
+    push_escape(max_lead, output);
+    push_code_unit_range(0xDC00, max_trail, output);
+}
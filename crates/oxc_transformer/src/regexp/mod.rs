@@ -1,10 +1,17 @@
 //! RegExp Transformer
 //!
 //! This module supports various RegExp plugins to handle unsupported RegExp literal features.
-//! When an unsupported feature is detected, these plugins convert the RegExp literal into
-//! a `new RegExp()` constructor call to avoid syntax errors.
 //!
-//! Note: You will need to include a polyfill for the `RegExp` constructor in your code to have the correct runtime behavior.
+//! When an unsupported feature is detected, the pattern is lowered to an equivalent pattern
+//! which doesn't use it, where possible (see [`lowering`]):
+//! * `u` flag: `/😀+/u` -> `/(?:\uD83D\uDE00)+/`
+//! * `s` flag: `/a.b/s` -> `/a[\s\S]b/`
+//! * Named capture groups: `/(?<a>x)\k<a>/` -> `_wrapRegExp(/(x)\1/, { a: 1 })`
+//!
+//! Otherwise, the RegExp literal is converted into a `new RegExp()` constructor call to avoid syntax errors.
+//!
+//! Note: You will need to include a polyfill for the `RegExp` constructor in your code to have the correct runtime behavior
+//! of RegExps which can't be lowered.
 //!
 //! ### ES2015
 //!
@@ -47,19 +54,23 @@
 use oxc_ast::{NONE, ast::*};
 use oxc_diagnostics::Result;
 use oxc_regular_expression::ast::{
-    CharacterClass, CharacterClassContents, LookAroundAssertionKind, Pattern, Term,
+    CharacterClass, CharacterClassContents, Disjunction, LookAroundAssertionKind, Pattern, Term,
 };
 use oxc_semantic::ReferenceFlags;
 use oxc_span::{Atom, SPAN};
 use oxc_traverse::Traverse;
 
 use crate::{
+    common::helper_loader::Helper,
     context::{TransformCtx, TraverseCtx},
     state::TransformState,
 };
 
+mod lowering;
 mod options;
 
+use lowering::LoweredPattern;
+
 pub use options::RegExpOptions;
 
 pub struct RegExp<'a, 'ctx> {
@@ -124,7 +135,8 @@ impl<'a> Traverse<'a, TransformState<'a>> for RegExp<'a, '_> {
 }
 
 impl<'a> RegExp<'a, '_> {
-    /// If `RegExpLiteral` contains unsupported syntax or flags, transform to `new RegExp(...)`.
+    /// If `RegExpLiteral` contains unsupported syntax or flags, lower it to an equivalent `RegExpLiteral`
+    /// which only uses supported syntax. If that's not possible, transform to `new RegExp(...)`.
     fn transform_regexp(&self, expr: &mut Expression<'a>, ctx: &mut TraverseCtx<'a>) {
         let Expression::RegExpLiteral(regexp) = expr else {
             unreachable!();
@@ -134,46 +146,69 @@ impl<'a> RegExp<'a, '_> {
         let pattern_text = regexp.regex.pattern.text;
         let flags = regexp.regex.flags;
         let has_unsupported_flags = flags.intersects(self.unsupported_flags);
-        if !has_unsupported_flags {
-            if !self.some_unsupported_patterns {
-                // This RegExp has no unsupported flags, and there are no patterns which may need transforming,
-                // so there's nothing to do
-                return;
-            }
+        if !has_unsupported_flags && !self.some_unsupported_patterns {
+            // This RegExp has no unsupported flags, and there are no patterns which may need transforming,
+            // so there's nothing to do
+            return;
+        }
 
-            let owned_pattern;
-            let pattern = if let Some(pattern) = &regexp.regex.pattern.pattern {
-                pattern
-            } else {
-                #[expect(clippy::cast_possible_truncation)]
-                let pattern_len = pattern_text.len() as u32;
-                let literal_span = regexp.span;
-                let pattern_span_start = literal_span.start + 1; // +1 to skip the opening `/`
-                let flags_span_start = pattern_span_start + pattern_len + 1; // +1 to skip the closing `/`
-                let flags_text =
-                    Span::new(flags_span_start, literal_span.end).source_text(self.ctx.source_text);
-                // Try to parse pattern
-                match try_parse_pattern(
-                    pattern_text.as_str(),
-                    pattern_span_start,
-                    flags_text,
-                    flags_span_start,
-                    ctx,
-                ) {
-                    Ok(pattern) => {
-                        owned_pattern = Some(pattern);
-                        owned_pattern.as_ref().unwrap()
-                    }
-                    Err(error) => {
-                        self.ctx.error(error);
-                        return;
-                    }
+        let owned_pattern;
+        let pattern = if let Some(pattern) = &regexp.regex.pattern.pattern {
+            Some(pattern.as_ref())
+        } else {
+            #[expect(clippy::cast_possible_truncation)]
+            let pattern_len = pattern_text.len() as u32;
+            let literal_span = regexp.span;
+            let pattern_span_start = literal_span.start + 1; // +1 to skip the opening `/`
+            let flags_span_start = pattern_span_start + pattern_len + 1; // +1 to skip the closing `/`
+            let flags_text =
+                Span::new(flags_span_start, literal_span.end).source_text(self.ctx.source_text);
+            // Try to parse pattern
+            match try_parse_pattern(
+                pattern_text.as_str(),
+                pattern_span_start,
+                flags_text,
+                flags_span_start,
+                ctx,
+            ) {
+                Ok(pattern) => {
+                    owned_pattern = pattern;
+                    Some(&owned_pattern)
+                }
+                // With unsupported flags, the RegExp is transformed to `new RegExp(...)` regardless
+                Err(_) if has_unsupported_flags => None,
+                Err(error) => {
+                    self.ctx.error(error);
+                    return;
                 }
-            };
+            }
+        };
 
-            if !self.has_unsupported_regular_expression_pattern(pattern) {
+        if let Some(pattern) = pattern {
+            if !has_unsupported_flags && !self.has_unsupported_regular_expression_pattern(pattern) {
                 return;
             }
+
+            if let Some(lowered) = lowering::lower(
+                pattern,
+                pattern_text.as_str(),
+                flags,
+                self.unsupported_flags,
+                self.look_behind_assertions,
+                self.unicode_property_escapes,
+                self.named_capture_groups,
+            ) {
+                if lowered.text == pattern_text.as_str()
+                    && lowered.flags == flags
+                    && lowered.group_names.is_empty()
+                {
+                    return;
+                }
+                if let Some(lowered_expr) = self.create_lowered_regexp(regexp.span, lowered, ctx) {
+                    *expr = lowered_expr;
+                    return;
+                }
+            }
         }
 
         let callee = {
@@ -193,11 +228,64 @@ impl<'a> RegExp<'a, '_> {
         *expr = ctx.ast.expression_new(regexp.span, callee, NONE, arguments);
     }
 
+    /// Create `RegExpLiteral` from lowered pattern.
+    /// If named capture groups were removed, wrap it in `wrapRegExp` helper to restore `groups`.
+    ///
+    /// `/(?<a>.)/s` -> `_wrapRegExp(/([\s\S])/, { a: 1 })`
+    ///
+    /// Returns `None` if lowered pattern fails to parse.
+    fn create_lowered_regexp(
+        &self,
+        span: Span,
+        lowered: LoweredPattern<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) -> Option<Expression<'a>> {
+        let text = ctx.ast.atom(&lowered.text);
+        let flags_text = ctx.ast.atom(lowered.flags.to_inline_string().as_str());
+        // Parse the lowered pattern, so it's not parsed again if this `RegExpLiteral` is visited again
+        let pattern = try_parse_pattern(text.as_str(), 0, flags_text.as_str(), 0, ctx).ok()?;
+        let regex = oxc_ast::ast::RegExp {
+            pattern: RegExpPattern { text, pattern: Some(ctx.ast.alloc(pattern)) },
+            flags: lowered.flags,
+        };
+
+        if lowered.group_names.is_empty() {
+            return Some(ctx.ast.expression_reg_exp_literal(span, regex, None));
+        }
+
+        let properties =
+            ctx.ast.vec_from_iter(lowered.group_names.into_iter().map(|(name, index)| {
+                ctx.ast.object_property_kind_object_property(
+                    SPAN,
+                    PropertyKind::Init,
+                    ctx.ast.property_key_static_identifier(SPAN, name),
+                    ctx.ast.expression_numeric_literal(
+                        SPAN,
+                        f64::from(index),
+                        None,
+                        NumberBase::Decimal,
+                    ),
+                    false,
+                    false,
+                    false,
+                )
+            }));
+        let arguments = ctx.ast.vec_from_array([
+            Argument::from(ctx.ast.expression_reg_exp_literal(SPAN, regex, None)),
+            Argument::from(ctx.ast.expression_object(SPAN, properties)),
+        ]);
+        Some(self.ctx.helper_call_expr(Helper::WrapRegExp, span, arguments, ctx))
+    }
+
     /// Check if the regular expression contains any unsupported syntax.
     ///
     /// Based on parsed regular expression pattern.
     fn has_unsupported_regular_expression_pattern(&self, pattern: &Pattern<'a>) -> bool {
-        pattern.body.body.iter().any(|alternative| {
+        self.disjunction_contains_unsupported(&pattern.body)
+    }
+
+    fn disjunction_contains_unsupported(&self, disjunction: &Disjunction) -> bool {
+        disjunction.body.iter().any(|alternative| {
             alternative.body.iter().any(|term| self.term_contains_unsupported(term))
         })
     }
@@ -206,19 +294,26 @@ impl<'a> RegExp<'a, '_> {
         // Loop because `Term::Quantifier` contains a nested `Term`
         loop {
             match term {
-                Term::CapturingGroup(_) => return self.named_capture_groups,
+                Term::CapturingGroup(group) => {
+                    return (self.named_capture_groups && group.name.is_some())
+                        || self.disjunction_contains_unsupported(&group.body);
+                }
+                Term::IgnoreGroup(group) => {
+                    return self.disjunction_contains_unsupported(&group.body);
+                }
                 Term::UnicodePropertyEscape(_) => return self.unicode_property_escapes,
                 Term::CharacterClass(character_class) => {
                     return self.unicode_property_escapes
                         && character_class_has_unicode_property_escape(character_class);
                 }
                 Term::LookAroundAssertion(assertion) => {
-                    return self.look_behind_assertions
+                    return (self.look_behind_assertions
                         && matches!(
                             assertion.kind,
                             LookAroundAssertionKind::Lookbehind
                                 | LookAroundAssertionKind::NegativeLookbehind
-                        );
+                        ))
+                        || self.disjunction_contains_unsupported(&assertion.body);
                 }
                 Term::Quantifier(quantifier) => term = &quantifier.body,
                 _ => return false,
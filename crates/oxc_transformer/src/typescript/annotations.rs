@@ -17,11 +17,15 @@ use crate::{
     state::TransformState,
 };
 
+use super::{diagnostics, options::ImportsNotUsedAsValues};
+
 pub struct TypeScriptAnnotations<'a, 'ctx> {
     ctx: &'ctx TransformCtx<'a>,
 
     // Options
     only_remove_type_imports: bool,
+    imports_not_used_as_values: ImportsNotUsedAsValues,
+    preserve_value_imports: bool,
 
     /// Assignments to be added to the constructor body
     assignments: Vec<Assignment<'a>>,
@@ -49,7 +53,9 @@ impl<'a, 'ctx> TypeScriptAnnotations<'a, 'ctx> {
 
         Self {
             ctx,
-            only_remove_type_imports: options.only_remove_type_imports,
+            only_remove_type_imports: options.only_remove_type_imports(),
+            imports_not_used_as_values: options.imports_not_used_as_values,
+            preserve_value_imports: options.preserve_value_imports,
             has_super_call: false,
             assignments: vec![],
             has_jsx_element: false,
@@ -122,12 +128,21 @@ impl<'a> Traverse<'a, TransformState<'a>> for TypeScriptAnnotations<'a, '_> {
                                     true
                                 } else {
                                     self.has_value_reference(id, ctx)
+                                        || self.preserve_value_imports
+                                            && Self::is_unreferenced(id, ctx)
                                 }
                             });
 
                             if specifiers.is_empty() {
                                 // `import { type A } from 'mod'`
-                                if self.only_remove_type_imports {
+                                if self.imports_not_used_as_values.is_error() {
+                                    self.ctx.error(diagnostics::import_never_used_as_value(
+                                        decl.span,
+                                    ));
+                                }
+                                if self.only_remove_type_imports
+                                    || !self.imports_not_used_as_values.is_remove()
+                                {
                                     // -> `import 'mod'`
                                     decl.specifiers = None;
                                     true
@@ -552,6 +567,11 @@ impl<'a> TypeScriptAnnotations<'a, '_> {
         self.is_jsx_imports(&id.name)
     }
 
+    /// Check if the given import binding is not referenced at all, neither as a value nor as a type.
+    fn is_unreferenced(id: &BindingIdentifier<'a>, ctx: &TraverseCtx<'a>) -> bool {
+        ctx.scoping().get_resolved_references(id.symbol_id()).next().is_none()
+    }
+
     fn can_retain_export_specifier(specifier: &ExportSpecifier<'a>, ctx: &TraverseCtx<'a>) -> bool {
         if specifier.export_kind.is_type() {
             return false;
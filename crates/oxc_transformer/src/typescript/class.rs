@@ -87,10 +87,11 @@ impl<'a> TypeScript<'a, '_> {
     /// we don't need extra transformation for static properties, the output is the same as instance properties
     /// transformation, and the greatest advantage is we don't need to care about `this` usage in static block.
     pub(super) fn transform_class_fields(&self, class: &mut Class<'a>, ctx: &mut TraverseCtx<'a>) {
-        let mut constructor = None;
         let mut property_assignments = Vec::new();
         let mut computed_key_assignments = Vec::new();
-        for element in &mut class.body.body {
+        // Indices of instance fields which are moved into the constructor
+        let mut moved_fields = Vec::new();
+        for (index, element) in class.body.body.iter_mut().enumerate() {
             match element {
                 // `set_public_class_fields: true` only needs to transform non-private class fields.
                 ClassElement::PropertyDefinition(prop) if !prop.key.is_private_identifier() => {
@@ -109,6 +110,11 @@ impl<'a> TypeScript<'a, '_> {
                             *element = Self::create_class_static_block(body, ctx);
                         } else {
                             property_assignments.push(assignment);
+                            // `class C { x = 1; }` -> `class C { constructor() { this.x = 1; } }`
+                            // Keep decorated fields, decorators still need to be applied to them.
+                            if prop.decorators.is_empty() {
+                                moved_fields.push(index);
+                            }
                         }
                     } else if self.remove_class_fields_without_initializer {
                         if let Some(key) = prop.key.as_expression_mut() {
@@ -125,16 +131,10 @@ impl<'a> TypeScript<'a, '_> {
                         }
                     }
                 }
-                ClassElement::MethodDefinition(method) => {
-                    if method.kind == MethodDefinitionKind::Constructor {
-                        constructor = Some(&mut method.value);
-                    } else {
-                        Self::convert_computed_key(
-                            &mut method.key,
-                            &mut computed_key_assignments,
-                            ctx,
-                        );
-                    }
+                ClassElement::MethodDefinition(method)
+                    if method.kind != MethodDefinitionKind::Constructor =>
+                {
+                    Self::convert_computed_key(&mut method.key, &mut computed_key_assignments, ctx);
                 }
                 ClassElement::AccessorProperty(accessor) => {
                     Self::convert_computed_key(
@@ -156,6 +156,23 @@ impl<'a> TypeScript<'a, '_> {
                 Self::create_class_static_block(ctx.ast.vec1(statement), ctx)
             });
 
+        if !moved_fields.is_empty() {
+            // `moved_fields` is in ascending order, so it can be walked along with the elements.
+            let mut moved_fields = moved_fields.into_iter().peekable();
+            let mut index = 0;
+            class.body.body.retain(|_| {
+                let moved = moved_fields.next_if_eq(&index).is_some();
+                index += 1;
+                !moved
+            });
+        }
+
+        let constructor = class.body.body.iter_mut().find_map(|element| match element {
+            ClassElement::MethodDefinition(method) if method.kind.is_constructor() => {
+                Some(&mut method.value)
+            }
+            _ => None,
+        });
         if let Some(constructor) = constructor {
             let params = &constructor.params.items;
 
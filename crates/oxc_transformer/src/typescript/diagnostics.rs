@@ -18,6 +18,61 @@ pub fn export_assignment_cannot_bed_used_in_esm(span: Span) -> OxcDiagnostic {
         .with_error_code("TS", "1203")
 }
 
+pub fn import_never_used_as_value(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("This import is never used as a value and must use 'import type' because 'importsNotUsedAsValues' is set to 'error'.")
+        .with_label(span)
+        .with_error_code("TS", "1371")
+}
+
+pub fn esm_syntax_in_commonjs_with_verbatim_module_syntax(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(
+        "ESM syntax is not allowed in a CommonJS module when 'verbatimModuleSyntax' is enabled.",
+    )
+    .with_label(span)
+    .with_error_code("TS", "1286")
+}
+
+pub fn export_modifier_in_commonjs_with_verbatim_module_syntax(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("A top-level 'export' modifier cannot be used on value declarations in a CommonJS module when 'verbatimModuleSyntax' is enabled.")
+        .with_label(span)
+        .with_error_code("TS", "1287")
+}
+
+pub fn global_script_file_in_isolated_modules(file_name: &str) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("'{file_name}' cannot be compiled under '--isolatedModules' because it is considered a global script file."))
+        .with_help("Add an import, export, or an empty 'export {}' statement to make it a module.")
+        .with_error_code("TS", "1208")
+}
+
+pub fn ambient_const_enum_in_isolated_modules(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn("Cannot access ambient const enums when 'isolatedModules' is enabled.")
+        .with_label(span)
+        .with_error_code("TS", "2748")
+}
+
+pub fn export_import_equals_type_in_isolated_modules(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(
+        "Cannot use 'export import' on a type or type-only namespace when 'isolatedModules' is enabled.",
+    )
+    .with_label(span)
+    .with_error_code("TS", "1269")
+}
+
+pub fn type_only_re_export_in_verbatim_module_syntax(name: &str, span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(format!("'{name}' resolves to a type-only declaration and must be re-exported using a type-only re-export when 'verbatimModuleSyntax' is enabled."))
+        .with_help(format!("Use `export type {{ {name} }}` instead."))
+        .with_label(span)
+        .with_error_code("TS", "1448")
+}
+
+pub fn type_re_export_in_isolated_modules(span: Span) -> OxcDiagnostic {
+    OxcDiagnostic::warn(
+        "Re-exporting a type when 'isolatedModules' is enabled requires using 'export type'.",
+    )
+    .with_label(span)
+    .with_error_code("TS", "1205")
+}
+
 pub fn ambient_module_nested(span: Span) -> OxcDiagnostic {
     OxcDiagnostic::warn("Ambient modules cannot be nested in other modules or namespaces.")
         .with_label(span)
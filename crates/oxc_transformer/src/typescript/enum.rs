@@ -222,8 +222,11 @@ impl<'a> TypeScriptEnum<'a> {
             let member_name = member.id.static_name();
 
             let init = if let Some(initializer) = &mut member.initializer {
-                let constant_value =
-                    self.computed_constant_value(initializer, &previous_enum_members);
+                let constant_value = self.computed_constant_value(
+                    initializer,
+                    param_binding.name,
+                    &previous_enum_members,
+                );
 
                 previous_enum_members.insert(member_name, constant_value.clone());
 
@@ -359,24 +362,34 @@ enum ConstantValue {
 impl<'a> TypeScriptEnum<'a> {
     /// Evaluate the expression to a constant value.
     /// Refer to [babel](https://github.com/babel/babel/blob/610897a9a96c5e344e77ca9665df7613d2f88358/packages/babel-plugin-transform-typescript/src/enum.ts#L241C1-L394C2)
+    ///
+    /// `enum_name` is the name of the enum being transformed, and `prev_members` are its members
+    /// declared before the member being evaluated.
     fn computed_constant_value(
         &self,
         expr: &Expression<'a>,
+        enum_name: Atom<'a>,
         prev_members: &PrevMembers<'a>,
     ) -> Option<ConstantValue> {
-        self.evaluate(expr, prev_members)
+        self.evaluate(expr, enum_name, prev_members)
     }
 
     fn evaluate_ref(
         &self,
         expr: &Expression<'a>,
+        enum_name: Atom<'a>,
         prev_members: &PrevMembers<'a>,
     ) -> Option<ConstantValue> {
         match expr {
             match_member_expression!(Expression) => {
                 let expr = expr.to_member_expression();
                 let Expression::Identifier(ident) = expr.object() else { return None };
-                let members = self.enums.get(&ident.name)?;
+                // `enum Foo { A = 1, B = Foo.A }`
+                let members = if ident.name == enum_name {
+                    prev_members
+                } else {
+                    self.enums.get(&ident.name)?
+                };
                 let property = expr.static_property_name()?;
                 members.get(property).cloned()?
             }
@@ -405,26 +418,40 @@ impl<'a> TypeScriptEnum<'a> {
     fn evaluate(
         &self,
         expr: &Expression<'a>,
+        enum_name: Atom<'a>,
         prev_members: &PrevMembers<'a>,
     ) -> Option<ConstantValue> {
         match expr {
             Expression::Identifier(_)
             | Expression::ComputedMemberExpression(_)
             | Expression::StaticMemberExpression(_)
-            | Expression::PrivateFieldExpression(_) => self.evaluate_ref(expr, prev_members),
-            Expression::BinaryExpression(expr) => self.eval_binary_expression(expr, prev_members),
-            Expression::UnaryExpression(expr) => self.eval_unary_expression(expr, prev_members),
+            | Expression::PrivateFieldExpression(_) => {
+                self.evaluate_ref(expr, enum_name, prev_members)
+            }
+            Expression::BinaryExpression(expr) => {
+                self.eval_binary_expression(expr, enum_name, prev_members)
+            }
+            Expression::UnaryExpression(expr) => {
+                self.eval_unary_expression(expr, enum_name, prev_members)
+            }
             Expression::NumericLiteral(lit) => Some(ConstantValue::Number(lit.value)),
             Expression::StringLiteral(lit) => Some(ConstantValue::String(lit.value.to_string())),
+            // `${A}-${B}`
             Expression::TemplateLiteral(lit) => {
                 let mut value = String::new();
-                for part in &lit.quasis {
-                    value.push_str(&part.value.raw);
+                for (i, quasi) in lit.quasis.iter().enumerate() {
+                    value.push_str(&quasi.value.cooked?);
+                    if let Some(expr) = lit.expressions.get(i) {
+                        match self.evaluate(expr, enum_name, prev_members)? {
+                            ConstantValue::Number(v) => value.push_str(&v.to_js_string()),
+                            ConstantValue::String(str) => value.push_str(&str),
+                        }
+                    }
                 }
                 Some(ConstantValue::String(value))
             }
             Expression::ParenthesizedExpression(expr) => {
-                self.evaluate(&expr.expression, prev_members)
+                self.evaluate(&expr.expression, enum_name, prev_members)
             }
             _ => None,
         }
@@ -434,10 +461,11 @@ impl<'a> TypeScriptEnum<'a> {
     fn eval_binary_expression(
         &self,
         expr: &BinaryExpression<'a>,
+        enum_name: Atom<'a>,
         prev_members: &PrevMembers<'a>,
     ) -> Option<ConstantValue> {
-        let left = self.evaluate(&expr.left, prev_members)?;
-        let right = self.evaluate(&expr.right, prev_members)?;
+        let left = self.evaluate(&expr.left, enum_name, prev_members)?;
+        let right = self.evaluate(&expr.right, enum_name, prev_members)?;
 
         if matches!(expr.operator, BinaryOperator::Addition)
             && (matches!(left, ConstantValue::String(_))
@@ -498,9 +526,10 @@ impl<'a> TypeScriptEnum<'a> {
     fn eval_unary_expression(
         &self,
         expr: &UnaryExpression<'a>,
+        enum_name: Atom<'a>,
         prev_members: &PrevMembers<'a>,
     ) -> Option<ConstantValue> {
-        let value = self.evaluate(&expr.argument, prev_members)?;
+        let value = self.evaluate(&expr.argument, enum_name, prev_members)?;
 
         let value = match value {
             ConstantValue::Number(value) => value,
@@ -0,0 +1,160 @@
+//! Isolated modules checks
+//!
+//! Reports the errors TypeScript reports under [`isolatedModules`](https://www.typescriptlang.org/tsconfig#isolatedModules),
+//! for constructs that can not be transpiled correctly without information from other files.
+//!
+//! Only checks that can be performed by looking at a single file are implemented.
+
+use std::borrow::Cow;
+
+use oxc_ast::ast::*;
+use oxc_traverse::Traverse;
+
+use crate::{
+    TypeScriptOptions,
+    context::{TransformCtx, TraverseCtx},
+    state::TransformState,
+};
+
+use super::diagnostics;
+
+pub struct TypeScriptIsolatedModules<'a, 'ctx> {
+    ctx: &'ctx TransformCtx<'a>,
+    verbatim_module_syntax: bool,
+}
+
+impl<'a, 'ctx> TypeScriptIsolatedModules<'a, 'ctx> {
+    /// `verbatimModuleSyntax` implies `isolatedModules`.
+    pub fn new(options: &TypeScriptOptions, ctx: &'ctx TransformCtx<'a>) -> Option<Self> {
+        (options.isolated_modules || options.verbatim_module_syntax)
+            .then_some(Self { ctx, verbatim_module_syntax: options.verbatim_module_syntax })
+    }
+}
+
+impl<'a> Traverse<'a, TransformState<'a>> for TypeScriptIsolatedModules<'a, '_> {
+    fn enter_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        self.check_global_script_file(program);
+
+        // Check before namespaces are transformed, so references to them are still resolved.
+        for stmt in &program.body {
+            let Statement::ExportNamedDeclaration(decl) = stmt else { continue };
+            match &decl.declaration {
+                Some(Declaration::TSImportEqualsDeclaration(import_equals)) => {
+                    self.check_export_import_equals(import_equals, ctx);
+                }
+                Some(_) => {}
+                None => self.check_type_only_re_export(decl, ctx),
+            }
+        }
+    }
+
+    fn enter_identifier_reference(
+        &mut self,
+        ident: &mut IdentifierReference<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        let scoping = ctx.scoping();
+        let reference = scoping.get_reference(ident.reference_id());
+        if reference.is_type() {
+            return;
+        }
+        let Some(symbol_id) = reference.symbol_id() else { return };
+        let flags = scoping.symbol_flags(symbol_id);
+        // `declare const enum E {}` has no runtime representation, and the members can't be inlined
+        // without type information.
+        if flags.is_const_enum() && flags.is_ambient() {
+            self.ctx.error(diagnostics::ambient_const_enum_in_isolated_modules(ident.span));
+        }
+    }
+}
+
+impl<'a> TypeScriptIsolatedModules<'a, '_> {
+    /// A file without any import or export is a global script, which can't be compiled in isolation
+    /// because its declarations may be referenced by other files.
+    fn check_global_script_file(&self, program: &Program<'a>) {
+        if self.ctx.source_type.is_typescript_definition()
+            || matches!(
+                self.ctx.source_path.extension().and_then(|ext| ext.to_str()),
+                Some("mts" | "cts")
+            )
+        {
+            return;
+        }
+
+        let is_module = program.body.iter().any(|stmt| match stmt {
+            match_module_declaration!(Statement) => true,
+            Statement::TSImportEqualsDeclaration(decl) => {
+                matches!(decl.module_reference, TSModuleReference::ExternalModuleReference(_))
+            }
+            _ => false,
+        });
+        if !is_module {
+            let file_name = self
+                .ctx
+                .source_path
+                .file_name()
+                .map_or(Cow::Borrowed("unknown"), |name| name.to_string_lossy());
+            self.ctx.error(diagnostics::global_script_file_in_isolated_modules(&file_name));
+        }
+    }
+
+    /// `export import A = NS.B` is emitted as a value export, so it must not refer to a type.
+    fn check_export_import_equals(
+        &self,
+        decl: &TSImportEqualsDeclaration<'a>,
+        ctx: &TraverseCtx<'a>,
+    ) {
+        if decl.import_kind.is_type() {
+            return;
+        }
+        let ident = match &decl.module_reference {
+            TSModuleReference::IdentifierReference(ident) => ident,
+            TSModuleReference::QualifiedName(name) => name.left.get_identifier_reference(),
+            TSModuleReference::ExternalModuleReference(_) => return,
+        };
+        let scoping = ctx.scoping();
+        // A reference to a type-only namespace is not resolved by semantic, so look up the binding by name.
+        let Some(symbol_id) = scoping
+            .get_reference(ident.reference_id())
+            .symbol_id()
+            .or_else(|| scoping.find_binding(ctx.current_scope_id(), &ident.name))
+        else {
+            return;
+        };
+        let flags = scoping.symbol_flags(symbol_id);
+        if flags.is_type_import() || !flags.is_value() && !flags.is_import() {
+            self.ctx.error(diagnostics::export_import_equals_type_in_isolated_modules(decl.span));
+        }
+    }
+
+    /// `import type { T } from "mod"; export { T };`
+    ///
+    /// The export can't be elided without knowing `T` is a type, so it has to be type-only as well.
+    /// `verbatimModuleSyntax` reports this with its own wording.
+    fn check_type_only_re_export(&self, decl: &ExportNamedDeclaration<'a>, ctx: &TraverseCtx<'a>) {
+        if decl.export_kind.is_type() || decl.source.is_some() {
+            return;
+        }
+        let scoping = ctx.scoping();
+        for specifier in &decl.specifiers {
+            if specifier.export_kind.is_type() {
+                continue;
+            }
+            let ModuleExportName::IdentifierReference(ident) = &specifier.local else { continue };
+            let Some(symbol_id) = scoping.get_reference(ident.reference_id()).symbol_id() else {
+                continue;
+            };
+            if scoping.symbol_flags(symbol_id).is_type_import() {
+                let error = if self.verbatim_module_syntax {
+                    diagnostics::type_only_re_export_in_verbatim_module_syntax(
+                        &ident.name,
+                        specifier.span,
+                    )
+                } else {
+                    diagnostics::type_re_export_in_isolated_modules(specifier.span)
+                };
+                self.ctx.error(error);
+            }
+        }
+    }
+}
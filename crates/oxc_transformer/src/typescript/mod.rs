@@ -11,6 +11,7 @@ mod annotations;
 mod class;
 mod diagnostics;
 mod r#enum;
+mod isolated_modules;
 mod module;
 mod namespace;
 mod options;
@@ -18,9 +19,10 @@ mod rewrite_extensions;
 
 use annotations::TypeScriptAnnotations;
 use r#enum::TypeScriptEnum;
+use isolated_modules::TypeScriptIsolatedModules;
 use module::TypeScriptModule;
 use namespace::TypeScriptNamespace;
-pub use options::{RewriteExtensionsMode, TypeScriptOptions};
+pub use options::{ImportsNotUsedAsValues, RewriteExtensionsMode, TypeScriptOptions};
 use rewrite_extensions::TypeScriptRewriteExtensions;
 
 /// [Preset TypeScript](https://babeljs.io/docs/babel-preset-typescript)
@@ -52,6 +54,7 @@ pub struct TypeScript<'a, 'ctx> {
     namespace: TypeScriptNamespace<'a, 'ctx>,
     module: TypeScriptModule<'a, 'ctx>,
     rewrite_extensions: Option<TypeScriptRewriteExtensions>,
+    isolated_modules: Option<TypeScriptIsolatedModules<'a, 'ctx>>,
     // Options
     remove_class_fields_without_initializer: bool,
 }
@@ -63,8 +66,9 @@ impl<'a, 'ctx> TypeScript<'a, 'ctx> {
             annotations: TypeScriptAnnotations::new(options, ctx),
             r#enum: TypeScriptEnum::new(),
             namespace: TypeScriptNamespace::new(options, ctx),
-            module: TypeScriptModule::new(options.only_remove_type_imports, ctx),
+            module: TypeScriptModule::new(options, ctx),
             rewrite_extensions: TypeScriptRewriteExtensions::new(options),
+            isolated_modules: TypeScriptIsolatedModules::new(options, ctx),
             remove_class_fields_without_initializer: !options.allow_declare_fields
                 || options.remove_class_fields_without_initializer,
         }
@@ -73,6 +77,9 @@ impl<'a, 'ctx> TypeScript<'a, 'ctx> {
 
 impl<'a> Traverse<'a, TransformState<'a>> for TypeScript<'a, '_> {
     fn enter_program(&mut self, program: &mut Program<'a>, ctx: &mut TraverseCtx<'a>) {
+        if let Some(isolated_modules) = &mut self.isolated_modules {
+            isolated_modules.enter_program(program, ctx);
+        }
         if self.ctx.source_type.is_typescript_definition() {
             // Output empty file for TS definitions
             program.directives.clear();
@@ -170,6 +177,16 @@ impl<'a> Traverse<'a, TransformState<'a>> for TypeScript<'a, '_> {
         self.annotations.exit_function(func, ctx);
     }
 
+    fn enter_identifier_reference(
+        &mut self,
+        ident: &mut IdentifierReference<'a>,
+        ctx: &mut TraverseCtx<'a>,
+    ) {
+        if let Some(isolated_modules) = &mut self.isolated_modules {
+            isolated_modules.enter_identifier_reference(ident, ctx);
+        }
+    }
+
     fn enter_jsx_opening_element(
         &mut self,
         elem: &mut JSXOpeningElement<'a>,
@@ -8,6 +8,7 @@ use oxc_traverse::Traverse;
 use super::diagnostics;
 
 use crate::{
+    TypeScriptOptions,
     context::{TransformCtx, TraverseCtx},
     state::TransformState,
 };
@@ -15,12 +16,18 @@ use crate::{
 pub struct TypeScriptModule<'a, 'ctx> {
     /// <https://babeljs.io/docs/babel-plugin-transform-typescript#onlyremovetypeimports>
     only_remove_type_imports: bool,
+    /// <https://www.typescriptlang.org/tsconfig#verbatimModuleSyntax>
+    verbatim_module_syntax: bool,
     ctx: &'ctx TransformCtx<'a>,
 }
 
 impl<'a, 'ctx> TypeScriptModule<'a, 'ctx> {
-    pub fn new(only_remove_type_imports: bool, ctx: &'ctx TransformCtx<'a>) -> Self {
-        Self { only_remove_type_imports, ctx }
+    pub fn new(options: &TypeScriptOptions, ctx: &'ctx TransformCtx<'a>) -> Self {
+        Self {
+            only_remove_type_imports: options.only_remove_type_imports(),
+            verbatim_module_syntax: options.verbatim_module_syntax,
+            ctx,
+        }
     }
 }
 
@@ -37,6 +44,9 @@ impl<'a> Traverse<'a, TransformState<'a>> for TypeScriptModule<'a, '_> {
     }
 
     fn enter_statement(&mut self, stmt: &mut Statement<'a>, ctx: &mut TraverseCtx<'a>) {
+        if self.verbatim_module_syntax && self.ctx.module.is_commonjs() {
+            self.check_esm_syntax_in_commonjs(stmt);
+        }
         if let Statement::TSExportAssignment(export_assignment) = stmt {
             *stmt = self.transform_ts_export_assignment(export_assignment, ctx);
         }
@@ -54,6 +64,37 @@ impl<'a> Traverse<'a, TransformState<'a>> for TypeScriptModule<'a, '_> {
 }
 
 impl<'a> TypeScriptModule<'a, '_> {
+    /// Under `verbatimModuleSyntax`, module syntax is emitted as written, so value imports and exports
+    /// can not be converted to CommonJS.
+    fn check_esm_syntax_in_commonjs(&self, stmt: &Statement<'a>) {
+        let error = match stmt {
+            Statement::ImportDeclaration(decl) if decl.import_kind.is_value() => {
+                diagnostics::esm_syntax_in_commonjs_with_verbatim_module_syntax(decl.span)
+            }
+            Statement::ExportNamedDeclaration(decl) if decl.export_kind.is_value() => {
+                match &decl.declaration {
+                    Some(declaration) if declaration.is_typescript_syntax() => return,
+                    Some(_) => {
+                        diagnostics::export_modifier_in_commonjs_with_verbatim_module_syntax(
+                            decl.span,
+                        )
+                    }
+                    None => {
+                        diagnostics::esm_syntax_in_commonjs_with_verbatim_module_syntax(decl.span)
+                    }
+                }
+            }
+            Statement::ExportDefaultDeclaration(decl) if !decl.is_typescript_syntax() => {
+                diagnostics::esm_syntax_in_commonjs_with_verbatim_module_syntax(decl.span)
+            }
+            Statement::ExportAllDeclaration(decl) if decl.export_kind.is_value() => {
+                diagnostics::esm_syntax_in_commonjs_with_verbatim_module_syntax(decl.span)
+            }
+            _ => return,
+        };
+        self.ctx.error(error);
+    }
+
     /// Transform `export = expression` to `module.exports = expression`.
     fn transform_ts_export_assignment(
         &self,
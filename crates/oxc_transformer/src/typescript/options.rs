@@ -36,6 +36,37 @@ pub struct TypeScriptOptions {
     /// This should only be used if you are using TypeScript >= 3.8.
     pub only_remove_type_imports: bool,
 
+    /// Mirrors TypeScript's [`verbatimModuleSyntax`](https://www.typescriptlang.org/tsconfig#verbatimModuleSyntax) option.
+    ///
+    /// Only imports and exports written with the `type` modifier are removed, everything else is
+    /// emitted as written, even if it is never referenced. Implies `only_remove_type_imports`.
+    ///
+    /// Defaults to `false`.
+    pub verbatim_module_syntax: bool,
+
+    /// Mirrors TypeScript's [`isolatedModules`](https://www.typescriptlang.org/tsconfig#isolatedModules) option.
+    ///
+    /// Reports the errors TypeScript reports for code that can not be correctly transpiled one file at a time,
+    /// such as accessing ambient const enums. Implied by `verbatim_module_syntax`.
+    ///
+    /// Defaults to `false`.
+    pub isolated_modules: bool,
+
+    /// Mirrors TypeScript's [`importsNotUsedAsValues`](https://www.typescriptlang.org/tsconfig#importsNotUsedAsValues) option.
+    ///
+    /// Controls what happens to an import declaration whose bindings are all elided.
+    ///
+    /// Defaults to [`ImportsNotUsedAsValues::Remove`].
+    pub imports_not_used_as_values: ImportsNotUsedAsValues,
+
+    /// Mirrors TypeScript's [`preserveValueImports`](https://www.typescriptlang.org/tsconfig#preserveValueImports) option.
+    ///
+    /// When enabled, imported bindings which are never referenced are kept. Bindings that are only
+    /// referenced in type positions are still removed.
+    ///
+    /// Defaults to `false`.
+    pub preserve_value_imports: bool,
+
     // Enables compilation of TypeScript namespaces.
     #[serde(default = "default_as_true")]
     pub allow_namespaces: bool,
@@ -104,6 +135,10 @@ impl Default for TypeScriptOptions {
             jsx_pragma: default_for_jsx_pragma(),
             jsx_pragma_frag: default_for_jsx_pragma_frag(),
             only_remove_type_imports: false,
+            verbatim_module_syntax: false,
+            isolated_modules: false,
+            imports_not_used_as_values: ImportsNotUsedAsValues::default(),
+            preserve_value_imports: false,
             allow_namespaces: default_as_true(),
             allow_declare_fields: default_as_true(),
             remove_class_fields_without_initializer: false,
@@ -113,6 +148,36 @@ impl Default for TypeScriptOptions {
     }
 }
 
+impl TypeScriptOptions {
+    /// Whether only imports and exports explicitly marked with `type` should be removed.
+    pub fn only_remove_type_imports(&self) -> bool {
+        self.only_remove_type_imports || self.verbatim_module_syntax
+    }
+}
+
+/// <https://www.typescriptlang.org/tsconfig#importsNotUsedAsValues>
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ImportsNotUsedAsValues {
+    /// Drop import declarations which only import types.
+    #[default]
+    Remove,
+    /// Keep import declarations whose bindings are all elided as side-effect imports (`import "mod"`).
+    Preserve,
+    /// Same as [`ImportsNotUsedAsValues::Preserve`], but also reports an error for every such declaration.
+    Error,
+}
+
+impl ImportsNotUsedAsValues {
+    pub fn is_remove(self) -> bool {
+        matches!(self, Self::Remove)
+    }
+
+    pub fn is_error(self) -> bool {
+        matches!(self, Self::Error)
+    }
+}
+
 #[derive(Debug, Clone, Copy, Default)]
 pub enum RewriteExtensionsMode {
     /// Rewrite `.ts`/`.mts`/`.cts` extensions in import/export declarations to `.js`/`.mjs`/`.cjs`.
@@ -0,0 +1 @@
+exports.c = 1;
@@ -0,0 +1,57 @@
+use std::path::Path;
+
+use oxc_allocator::Allocator;
+use oxc_codegen::Codegen;
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+use oxc_transformer::{Module, PluginsOptions, TransformOptions, Transformer};
+
+/// Transform `source_text` as if it was the `input.js` of the `fully_specified` fixtures.
+fn transform(source_text: &str, options: &TransformOptions) -> String {
+    let source_path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/integrations/fixtures/fully_specified/input.js");
+    let allocator = Allocator::default();
+    let mut program = Parser::new(&allocator, source_text, SourceType::mjs()).parse().program;
+    let scoping = SemanticBuilder::new().build(&program).semantic.into_scoping();
+    let ret = Transformer::new(&allocator, &source_path, options)
+        .build_with_scoping(scoping, &mut program);
+    assert!(ret.errors.is_empty(), "{:?}", ret.errors);
+    Codegen::new().build(&program).code
+}
+
+#[test]
+fn fully_specified() {
+    let options = TransformOptions {
+        plugins: PluginsOptions { fully_specified: true, ..PluginsOptions::default() },
+        ..TransformOptions::default()
+    };
+    let cases = [
+        ("import { a } from './utils';", "import { a } from \"./utils.js\";\n"),
+        ("import { b } from './esm';", "import { b } from \"./esm.mjs\";\n"),
+        ("import { c } from './lib';", "import { c } from \"./lib/index.cjs\";\n"),
+        ("export * from './components';", "export * from \"./components/index.js\";\n"),
+        ("export { e } from './plain';", "export { e } from \"./plain.js\";\n"),
+        ("export { e } from './plain.js';", "export { e } from \"./plain.js\";\n"),
+        ("import config from './vite.config';", "import config from \"./vite.config.js\";\n"),
+        ("import './missing';", "import \"./missing.js\";\n"),
+        ("import './missing.css';", "import \"./missing.css\";\n"),
+        ("import('./utils?worker');", "import(\"./utils.js?worker\");\n"),
+    ];
+    for (source_text, expected) in cases {
+        assert_eq!(transform(source_text, &options), expected, "{source_text}");
+    }
+}
+
+#[test]
+fn fully_specified_commonjs() {
+    let mut options = TransformOptions {
+        plugins: PluginsOptions { fully_specified: true, ..PluginsOptions::default() },
+        ..TransformOptions::default()
+    };
+    options.env.module = Module::CommonJS;
+    assert_eq!(
+        transform("import { a } from './utils';", &options),
+        "import { a } from \"./utils\";\n"
+    );
+}
@@ -0,0 +1,32 @@
+use oxc_transformer::{ESTarget, Helper, HelperLoaderOptions, HelperRegistry, TransformOptions};
+
+use crate::test;
+
+#[test]
+fn helper_registry() {
+    let registry = HelperRegistry::new("\0oxc-helpers");
+    let options = TransformOptions {
+        helper_loader: HelperLoaderOptions {
+            registry: Some(registry.clone()),
+            ..HelperLoaderOptions::default()
+        },
+        ..TransformOptions::from(ESTarget::ES2015)
+    };
+
+    let a = test("({ ...a }); ({ ...b });", &options).unwrap();
+    assert_eq!(
+        a,
+        "import { objectSpread2 as _objectSpread } from '\\0oxc-helpers';\n_objectSpread({}, a);\n_objectSpread({}, b);\n"
+    );
+    let b = test("async function f() {} ({ ...c });", &options).unwrap();
+    assert!(b.starts_with(
+        "import { asyncToGenerator as _asyncToGenerator, objectSpread2 as _objectSpread } from '\\0oxc-helpers';\n"
+    ), "{b}");
+
+    assert_eq!(registry.helpers(), [Helper::AsyncToGenerator, Helper::ObjectSpread2]);
+    assert_eq!(
+        registry.module_source(),
+        "export { default as asyncToGenerator } from \"@oxc-project/runtime/helpers/asyncToGenerator\";\n\
+         export { default as objectSpread2 } from \"@oxc-project/runtime/helpers/objectSpread2\";\n"
+    );
+}
@@ -1,10 +1,13 @@
 mod es_target;
+mod fully_specified;
+mod helper_registry;
 mod targets;
+mod tsc_emit;
 
 use std::path::Path;
 
 use oxc_allocator::Allocator;
-use oxc_codegen::{Codegen, CodegenOptions};
+use oxc_codegen::{Codegen, CodegenOptions, QuoteStyle};
 use oxc_diagnostics::OxcDiagnostic;
 use oxc_parser::Parser;
 use oxc_semantic::SemanticBuilder;
@@ -15,7 +18,10 @@ pub fn codegen(source_text: &str, source_type: SourceType) -> String {
     let allocator = Allocator::default();
     let ret = Parser::new(&allocator, source_text, source_type).parse();
     Codegen::new()
-        .with_options(CodegenOptions { single_quote: true, ..CodegenOptions::default() })
+        .with_options(CodegenOptions {
+            quote_style: QuoteStyle::Single,
+            ..CodegenOptions::default()
+        })
         .build(&ret.program)
         .code
 }
@@ -35,7 +41,10 @@ pub(crate) fn test(
         return Err(ret.errors);
     }
     let code = Codegen::new()
-        .with_options(CodegenOptions { single_quote: true, ..CodegenOptions::default() })
+        .with_options(CodegenOptions {
+            quote_style: QuoteStyle::Single,
+            ..CodegenOptions::default()
+        })
         .build(&program)
         .code;
     Ok(code)
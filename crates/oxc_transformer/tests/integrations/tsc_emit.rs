@@ -0,0 +1,113 @@
+//! Compare the TypeScript transform against the emit of `tsc --target esnext`.
+
+use std::path::Path;
+
+use oxc_allocator::Allocator;
+use oxc_codegen::Codegen;
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::SourceType;
+use oxc_transformer::{CompilerAssumptions, TransformOptions, Transformer, TypeScriptOptions};
+
+fn transform(source_text: &str, options: &TransformOptions) -> String {
+    let allocator = Allocator::default();
+    let mut program = Parser::new(&allocator, source_text, SourceType::ts()).parse().program;
+    let scoping = SemanticBuilder::new().build(&program).semantic.into_scoping();
+    let ret = Transformer::new(&allocator, Path::new("test.ts"), options)
+        .build_with_scoping(scoping, &mut program);
+    assert!(ret.errors.is_empty(), "{:?}", ret.errors);
+    Codegen::new().build(&program).code
+}
+
+fn print(source_text: &str) -> String {
+    let allocator = Allocator::default();
+    Codegen::new()
+        .build(&Parser::new(&allocator, source_text, SourceType::mjs()).parse().program)
+        .code
+}
+
+#[track_caller]
+fn test(source_text: &str, tsc_output: &str, options: &TransformOptions) {
+    assert_eq!(transform(source_text, options), print(tsc_output), "for source {source_text}");
+}
+
+/// `useDefineForClassFields: false`
+fn assign_class_fields() -> TransformOptions {
+    TransformOptions {
+        assumptions: CompilerAssumptions {
+            set_public_class_fields: true,
+            ..CompilerAssumptions::default()
+        },
+        typescript: TypeScriptOptions {
+            remove_class_fields_without_initializer: true,
+            ..TypeScriptOptions::default()
+        },
+        ..TransformOptions::default()
+    }
+}
+
+#[test]
+fn declare_fields() {
+    let source = "class A { declare a: string; b: number; c = 1; declare static d: string; }";
+    test(source, "class A { b; c = 1; }", &TransformOptions::default());
+    test(source, "class A { constructor() { this.c = 1; } }", &assign_class_fields());
+}
+
+#[test]
+fn parameter_properties() {
+    let source = "
+        class A extends B {
+            declare d: string;
+            field = this.x;
+            constructor(public x: number, private y: string) {
+                before();
+                super(x);
+                after();
+            }
+        }
+    ";
+    test(
+        source,
+        "
+        class A extends B {
+            constructor(x, y) {
+                before();
+                super(x);
+                this.x = x;
+                this.y = y;
+                this.field = this.x;
+                after();
+            }
+        }
+        ",
+        &assign_class_fields(),
+    );
+}
+
+#[test]
+fn string_enum_member_references() {
+    // tsc emits `(function (S) { ... })(S || (S = {}))`, so only the member assignments are compared.
+    let members = |code: &str| {
+        code.lines()
+            .filter(|line| line.contains("] = "))
+            .map(str::trim)
+            .collect::<Vec<_>>()
+            .join("\n")
+    };
+    let output = transform(
+        "enum S { A = 'a', B = A + 'b', C = `${B}c`, D = S.A }",
+        &TransformOptions::default(),
+    );
+    let tsc_output = print(
+        "
+        var S;
+        (function (S) {
+            S['A'] = 'a';
+            S['B'] = 'ab';
+            S['C'] = 'abc';
+            S['D'] = 'a';
+        })(S || (S = {}));
+        ",
+    );
+    assert_eq!(members(&output), members(&tsc_output));
+}
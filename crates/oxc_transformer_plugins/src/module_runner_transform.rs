@@ -852,7 +852,7 @@ mod test {
     use similar::TextDiff;
 
     use oxc_allocator::Allocator;
-    use oxc_codegen::{Codegen, CodegenOptions};
+    use oxc_codegen::{Codegen, CodegenOptions, QuoteStyle};
     use oxc_diagnostics::OxcDiagnostic;
     use oxc_parser::Parser;
     use oxc_semantic::SemanticBuilder;
@@ -890,7 +890,7 @@ mod test {
         let code = Codegen::new()
             .with_options(CodegenOptions {
                 comments: false,
-                single_quote: true,
+                quote_style: QuoteStyle::Single,
                 ..CodegenOptions::default()
             })
             .build(&program)
@@ -907,7 +907,7 @@ mod test {
         Codegen::new()
             .with_options(CodegenOptions {
                 comments: false,
-                single_quote: true,
+                quote_style: QuoteStyle::Single,
                 ..CodegenOptions::default()
             })
             .build(&ret.program)
@@ -3,7 +3,7 @@
 //! * <https://github.com/rollup/plugins/tree/pluginutils-v5.1.3/packages/inject/test>
 
 use oxc_allocator::Allocator;
-use oxc_codegen::{Codegen, CodegenOptions};
+use oxc_codegen::{Codegen, CodegenOptions, QuoteStyle};
 use oxc_parser::Parser;
 use oxc_semantic::SemanticBuilder;
 use oxc_span::SourceType;
@@ -20,7 +20,10 @@ fn test(source_text: &str, expected: &str, config: InjectGlobalVariablesConfig)
     let scoping = SemanticBuilder::new().build(&program).semantic.into_scoping();
     let _ = InjectGlobalVariables::new(&allocator, config).build(scoping, &mut program);
     let result = Codegen::new()
-        .with_options(CodegenOptions { single_quote: true, ..CodegenOptions::default() })
+        .with_options(CodegenOptions {
+            quote_style: QuoteStyle::Single,
+            ..CodegenOptions::default()
+        })
         .build(&program)
         .code;
     let expected = codegen(expected, source_type);
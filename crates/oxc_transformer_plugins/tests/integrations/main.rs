@@ -2,7 +2,7 @@ mod inject_global_variables;
 mod replace_global_defines;
 
 use oxc_allocator::Allocator;
-use oxc_codegen::{Codegen, CodegenOptions};
+use oxc_codegen::{Codegen, CodegenOptions, QuoteStyle};
 use oxc_parser::Parser;
 use oxc_span::SourceType;
 
@@ -10,7 +10,10 @@ pub fn codegen(source_text: &str, source_type: SourceType) -> String {
     let allocator = Allocator::default();
     let ret = Parser::new(&allocator, source_text, source_type).parse();
     Codegen::new()
-        .with_options(CodegenOptions { single_quote: true, ..CodegenOptions::default() })
+        .with_options(CodegenOptions {
+            quote_style: QuoteStyle::Single,
+            ..CodegenOptions::default()
+        })
         .build(&ret.program)
         .code
 }
@@ -1,5 +1,5 @@
 use oxc_allocator::Allocator;
-use oxc_codegen::{Codegen, CodegenOptions};
+use oxc_codegen::{Codegen, CodegenOptions, QuoteStyle};
 use oxc_minifier::{CompressOptions, Compressor};
 use oxc_parser::Parser;
 use oxc_semantic::SemanticBuilder;
@@ -21,7 +21,10 @@ pub fn test(source_text: &str, expected: &str, config: ReplaceGlobalDefinesConfi
     Compressor::new(&allocator, CompressOptions::default())
         .dead_code_elimination_with_scoping(scoping, &mut program);
     let result = Codegen::new()
-        .with_options(CodegenOptions { single_quote: true, ..CodegenOptions::default() })
+        .with_options(CodegenOptions {
+            quote_style: QuoteStyle::Single,
+            ..CodegenOptions::default()
+        })
         .build(&program)
         .code;
     let expected = codegen(expected, source_type);
@@ -245,6 +248,24 @@ fn replace_with_undefined() {
     test("Foo = 0", "Bar = 0", config);
 }
 
+#[test]
+fn dead_branch_elimination() {
+    let config =
+        ReplaceGlobalDefinesConfig::new(&[("process.env.NODE_ENV", "'production'")]).unwrap();
+    test(
+        "if (process.env.NODE_ENV !== 'production') { warn() } else { run() }",
+        "run()",
+        config.clone(),
+    );
+    test("process.env.NODE_ENV === 'production' ? a() : b()", "a()", config.clone());
+    test("process.env.NODE_ENV === 'development' && check()", "", config.clone());
+    test(
+        "import { devtools } from 'devtools'; if (process.env.NODE_ENV !== 'production') { devtools() }",
+        "import 'devtools'",
+        config,
+    );
+}
+
 #[cfg(not(miri))]
 #[test]
 fn test_sourcemap() {
@@ -281,7 +302,7 @@ log(__MEMBER__);
     let _ = ReplaceGlobalDefines::new(&allocator, config).build(scoping, &mut program);
     let result = Codegen::new()
         .with_options(CodegenOptions {
-            single_quote: true,
+            quote_style: QuoteStyle::Single,
             source_map_path: Some(std::path::Path::new(&"test.js.map").to_path_buf()),
             ..CodegenOptions::default()
         })
@@ -1,5 +1,31 @@
 /* auto-generated by NAPI-RS */
 /* eslint-disable */
+export interface Comment {
+  type: 'Line' | 'Block'
+  value: string
+  start: number
+  end: number
+}
+
+export interface ErrorLabel {
+  message?: string
+  start: number
+  end: number
+}
+
+export interface OxcError {
+  severity: Severity
+  message: string
+  labels: Array<ErrorLabel>
+  helpMessage?: string
+  codeframe?: string
+}
+
+export declare const enum Severity {
+  Error = 'Error',
+  Warning = 'Warning',
+  Advice = 'Advice'
+}
 export interface CodegenOptions {
   /**
    * Remove whitespace.
@@ -7,6 +33,13 @@ export interface CodegenOptions {
    * @default true
    */
   removeWhitespace?: boolean
+  /**
+   * Keep `@__PURE__` and `#__NO_SIDE_EFFECTS__` annotations,
+   * so that the output can be tree shaken by bundlers.
+   *
+   * @default false
+   */
+  pureAnnotations?: boolean
 }
 
 export interface CompressOptions {
@@ -31,12 +64,86 @@ export interface CompressOptions {
    * @default false
    */
   dropConsole?: boolean
+  /**
+   * Only discard calls to these `console` methods when `dropConsole` is enabled.
+   *
+   * @default []
+   */
+  dropConsoleMethods?: Array<string>
   /**
    * Remove `debugger;` statements.
    *
    * @default true
    */
   dropDebugger?: boolean
+  /**
+   * Discard calls to these functions, e.g. `["assert", "logger.debug"]`.
+   *
+   * A call whose result is used is replaced with `void 0`.
+   *
+   * @default []
+   */
+  pureFuncs?: Array<string>
+  /**
+   * Join consecutive simple statements with the comma operator.
+   *
+   * @default true
+   */
+  sequences?: boolean
+  /**
+   * Convert `if` statements into logical and conditional expressions.
+   *
+   * @default true
+   */
+  conditionals?: boolean
+  /**
+   * Optimize `if` statements followed by `return`, `throw`, `continue` and `break`.
+   *
+   * @default true
+   */
+  ifReturn?: boolean
+  /**
+   * Remove duplicated and unreachable `switch` branches.
+   *
+   * @default true
+   */
+  switches?: boolean
+  /**
+   * Optimize `for` and `do-while` loops,
+   * e.g. `do a(); while (true)` -> `for (;;) a()`.
+   *
+   * @default true
+   */
+  loops?: boolean
+  /**
+   * Evaluate calls to known global functions with constant arguments,
+   * e.g. `Math.pow(2, 8)` -> `256`.
+   *
+   * @default true
+   */
+  evaluateKnownGlobals?: boolean
+  /**
+   * Convert anonymous function expressions which don't use `this` or `arguments`
+   * into arrow functions, e.g. `a(function (b) { return b })` -> `a((b) => b)`.
+   *
+   * @default false
+   */
+  unsafeArrows?: boolean
+  /**
+   * Convert function expression properties into method shorthands,
+   * e.g. `({ a: function () {} })` -> `({ a() {} })`.
+   *
+   * @default false
+   */
+  unsafeMethods?: boolean
+  /**
+   * Replace global variables and their members with constant values,
+   * e.g. `{ DEBUG: false, "process.env.NODE_ENV": "production" }`.
+   *
+   * The value of a key prefixed with `@` is parsed as an expression,
+   * e.g. `{ "@alert": "console.log" }`.
+   */
+  globalDefs?: Record<string, boolean | number | string | null>
 }
 
 export interface CompressOptionsKeepNames {
@@ -56,6 +163,19 @@ export interface CompressOptionsKeepNames {
    * @default false
    */
   class: boolean
+  /** Only keep names of functions matching the regular expression. */
+  functionRegex?: string
+  /** Only keep names of classes matching the regular expression. */
+  classRegex?: string
+}
+
+export interface FunctionSize {
+  name?: string
+  start: number
+  end: number
+  originalSize: number
+  /** `0` if the function was removed or inlined. */
+  minifiedSize: number
 }
 
 export interface MangleOptions {
@@ -71,10 +191,69 @@ export interface MangleOptions {
    * @default false
    */
   keepNames?: boolean | MangleOptionsKeepNames
+  /**
+   * Pass `true` to mangle names visible in scopes where a direct `eval` or `with` is used.
+   *
+   * @default false
+   */
+  eval?: boolean
+  /**
+   * Names that are never mangled.
+   *
+   * @default []
+   */
+  reserved?: Array<string>
+  /**
+   * Mangle property names.
+   *
+   * @default false
+   */
+  properties?: boolean | ManglePropertiesOptions
+  /**
+   * Pass `true` to keep the names of private class members (`#foo`).
+   *
+   * @default false
+   */
+  keepPrivateNames?: boolean
+  /**
+   * Characters used by mangled names, most preferred first.
+   *
+   * Characters are ordered by their frequency in the source text by default.
+   */
+  alphabet?: string
   /** Debug mangled names. */
   debug?: boolean
 }
 
+export interface ManglePropertiesOptions {
+  /** Only mangle property names matching the regular expression. */
+  regex?: string
+  /**
+   * Property names that are never mangled.
+   *
+   * @default []
+   */
+  reserved?: Array<string>
+  /**
+   * Do not mangle quoted property names.
+   *
+   * @default false
+   */
+  keepQuoted?: boolean
+  /**
+   * Use readable mangled names (e.g. `_$foo$_`) for debugging.
+   *
+   * @default false
+   */
+  debug?: boolean
+  /**
+   * Mangled names from a previous build, keyed by the original name.
+   *
+   * Pass `propertyNameCache` of the previous result to keep mangled names consistent across builds.
+   */
+  nameCache?: Record<string, string>
+}
+
 export interface MangleOptionsKeepNames {
   /**
    * Preserve `name` property for functions.
@@ -88,6 +267,10 @@ export interface MangleOptionsKeepNames {
    * @default false
    */
   class: boolean
+  /** Only preserve `name` property for functions matching the regular expression. */
+  functionRegex?: string
+  /** Only preserve `name` property for classes matching the regular expression. */
+  classRegex?: string
 }
 
 /** Minify synchronously. */
@@ -98,11 +281,50 @@ export interface MinifyOptions {
   mangle?: boolean | MangleOptions
   codegen?: boolean | CodegenOptions
   sourcemap?: boolean
+  /**
+   * Return a breakdown of the minified size in `report`.
+   *
+   * @default false
+   */
+  report?: boolean
 }
 
 export interface MinifyResult {
   code: string
   map?: SourceMap
+  /** Mangled property names, when `mangle.properties` is set. */
+  propertyNameCache?: Record<string, string>
+  /** Breakdown of the minified size, when `report` is set. */
+  report?: SizeReport
+  /**
+   * Parse errors.
+   *
+   * The parser recovers from common syntax errors, so the code is still minified if there are
+   * errors in this list.
+   */
+  errors: Array<OxcError>
+}
+
+export interface PassSize {
+  name: string
+  size: number
+}
+
+/** Sizes are in bytes. */
+export interface SizeReport {
+  originalSize: number
+  minifiedSize: number
+  /** Size of the code after each pass, in the order the passes ran. */
+  passes: Array<PassSize>
+  /** Functions of the source text, in order of appearance. */
+  functions: Array<FunctionSize>
+  /** Top level declarations of the minified code, largest first. */
+  symbols: Array<SymbolSize>
+}
+
+export interface SymbolSize {
+  name: string
+  minifiedSize: number
 }
 export interface SourceMap {
   file?: string
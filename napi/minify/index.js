@@ -383,4 +383,5 @@ if (!nativeBinding) {
 }
 
 module.exports = nativeBinding
+module.exports.Severity = nativeBinding.Severity
 module.exports.minify = nativeBinding.minify
@@ -54,4 +54,5 @@ const {
   },
 })
 export default __napiModule.exports
+export const Severity = __napiModule.exports.Severity
 export const minify = __napiModule.exports.minify
@@ -85,4 +85,5 @@ const { instance: __napiInstance, module: __wasiModule, napiModule: __napiModule
   },
 })
 module.exports = __napiModule.exports
+module.exports.Severity = __napiModule.exports.Severity
 module.exports.minify = __napiModule.exports.minify
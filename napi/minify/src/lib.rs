@@ -17,10 +17,11 @@ use napi_derive::napi;
 use oxc_allocator::Allocator;
 use oxc_codegen::{Codegen, CodegenOptions};
 use oxc_minifier::Minifier;
+use oxc_napi::OxcError;
 use oxc_parser::Parser;
 use oxc_span::SourceType;
 
-use crate::options::{MinifyOptions, MinifyResult};
+use crate::options::{MinifyOptions, MinifyResult, SizeReport};
 
 /// Minify synchronously.
 #[napi]
@@ -40,9 +41,13 @@ pub fn minify(
 
     let source_type = SourceType::from_path(&filename).unwrap_or_default();
 
-    let mut program = Parser::new(&allocator, &source_text, source_type).parse().program;
+    let ret = Parser::new(&allocator, &source_text, source_type).parse();
+    let mut program = ret.program;
+    let errors = OxcError::from_diagnostics(&filename, &source_text, ret.errors);
 
-    let scoping = Minifier::new(minifier_options).build(&allocator, &mut program).scoping;
+    let minified = Minifier::new(minifier_options).build(&allocator, &mut program);
+    let property_name_cache = minified.property_name_cache.map(|cache| cache.into_iter().collect());
+    let report = minified.report.map(SizeReport::from);
 
     let mut codegen_options = match &options.codegen {
         // Need to remove all comments.
@@ -55,7 +60,14 @@ pub fn minify(
         codegen_options.source_map_path = Some(PathBuf::from(filename));
     }
 
-    let ret = Codegen::new().with_options(codegen_options).with_scoping(scoping).build(&program);
+    let ret =
+        Codegen::new().with_options(codegen_options).with_scoping(minified.scoping).build(&program);
 
-    Ok(MinifyResult { code: ret.code, map: ret.map.map(oxc_sourcemap::napi::SourceMap::from) })
+    Ok(MinifyResult {
+        code: ret.code,
+        map: ret.map.map(oxc_sourcemap::napi::SourceMap::from),
+        property_name_cache,
+        report,
+        errors,
+    })
 }
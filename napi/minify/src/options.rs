@@ -1,9 +1,10 @@
-use std::str::FromStr;
+use std::{collections::HashMap, str::FromStr};
 
-use napi::Either;
+use napi::{Either, bindgen_prelude::Either3};
 use napi_derive::napi;
 
 use oxc_minifier::TreeShakeOptions;
+use oxc_napi::OxcError;
 use oxc_sourcemap::napi::SourceMap;
 use oxc_syntax::es_target::ESTarget;
 
@@ -32,15 +33,95 @@ pub struct CompressOptions {
     /// @default false
     pub drop_console: Option<bool>,
 
+    /// Only discard calls to these `console` methods when `dropConsole` is enabled.
+    ///
+    /// @default []
+    pub drop_console_methods: Option<Vec<String>>,
+
     /// Remove `debugger;` statements.
     ///
     /// @default true
     pub drop_debugger: Option<bool>,
+
+    /// Discard calls to these functions, e.g. `["assert", "logger.debug"]`.
+    ///
+    /// A call whose result is used is replaced with `void 0`.
+    ///
+    /// @default []
+    pub pure_funcs: Option<Vec<String>>,
+
+    /// Join consecutive simple statements with the comma operator.
+    ///
+    /// @default true
+    pub sequences: Option<bool>,
+
+    /// Convert `if` statements into logical and conditional expressions.
+    ///
+    /// @default true
+    pub conditionals: Option<bool>,
+
+    /// Optimize `if` statements followed by `return`, `throw`, `continue` and `break`.
+    ///
+    /// @default true
+    pub if_return: Option<bool>,
+
+    /// Remove duplicated and unreachable `switch` branches.
+    ///
+    /// @default true
+    pub switches: Option<bool>,
+
+    /// Optimize `for` and `do-while` loops,
+    /// e.g. `do a(); while (true)` -> `for (;;) a()`.
+    ///
+    /// @default true
+    pub loops: Option<bool>,
+
+    /// Evaluate calls to known global functions with constant arguments,
+    /// e.g. `Math.pow(2, 8)` -> `256`.
+    ///
+    /// @default true
+    pub evaluate_known_globals: Option<bool>,
+
+    /// Convert anonymous function expressions which don't use `this` or `arguments`
+    /// into arrow functions, e.g. `a(function (b) { return b })` -> `a((b) => b)`.
+    ///
+    /// @default false
+    pub unsafe_arrows: Option<bool>,
+
+    /// Convert function expression properties into method shorthands,
+    /// e.g. `({ a: function () {} })` -> `({ a() {} })`.
+    ///
+    /// @default false
+    pub unsafe_methods: Option<bool>,
+
+    /// Replace global variables and their members with constant values,
+    /// e.g. `{ DEBUG: false, "process.env.NODE_ENV": "production" }`.
+    ///
+    /// The value of a key prefixed with `@` is parsed as an expression,
+    /// e.g. `{ "@alert": "console.log" }`.
+    #[napi(ts_type = "Record<string, boolean | number | string | null>")]
+    pub global_defs: Option<HashMap<String, Option<Either3<bool, f64, String>>>>,
 }
 
 impl Default for CompressOptions {
     fn default() -> Self {
-        Self { target: None, keep_names: None, drop_console: None, drop_debugger: Some(true) }
+        Self {
+            target: None,
+            keep_names: None,
+            drop_console: None,
+            drop_console_methods: None,
+            drop_debugger: Some(true),
+            pure_funcs: None,
+            sequences: Some(true),
+            conditionals: Some(true),
+            if_return: Some(true),
+            switches: Some(true),
+            loops: Some(true),
+            evaluate_known_globals: Some(true),
+            unsafe_arrows: Some(false),
+            unsafe_methods: Some(false),
+            global_defs: None,
+        }
     }
 }
 
@@ -56,8 +137,48 @@ impl TryFrom<&CompressOptions> for oxc_minifier::CompressOptions {
                 .transpose()?
                 .unwrap_or(default.target),
             drop_console: o.drop_console.unwrap_or(default.drop_console),
+            drop_console_methods: o
+                .drop_console_methods
+                .clone()
+                .unwrap_or(default.drop_console_methods),
             drop_debugger: o.drop_debugger.unwrap_or(default.drop_debugger),
-            keep_names: o.keep_names.as_ref().map(Into::into).unwrap_or_default(),
+            pure_funcs: o.pure_funcs.clone().unwrap_or(default.pure_funcs),
+            keep_names: o
+                .keep_names
+                .as_ref()
+                .map(TryInto::try_into)
+                .transpose()?
+                .unwrap_or_default(),
+            sequences: o.sequences.unwrap_or(default.sequences),
+            conditionals: o.conditionals.unwrap_or(default.conditionals),
+            if_return: o.if_return.unwrap_or(default.if_return),
+            switches: o.switches.unwrap_or(default.switches),
+            loops: o.loops.unwrap_or(default.loops),
+            evaluate_known_globals: o
+                .evaluate_known_globals
+                .unwrap_or(default.evaluate_known_globals),
+            unsafe_arrows: o.unsafe_arrows.unwrap_or(default.unsafe_arrows),
+            unsafe_methods: o.unsafe_methods.unwrap_or(default.unsafe_methods),
+            global_defs: o
+                .global_defs
+                .as_ref()
+                .map(|global_defs| {
+                    global_defs
+                        .iter()
+                        .map(|(key, value)| {
+                            let value = match value {
+                                None => oxc_minifier::GlobalDefValue::Null,
+                                Some(Either3::A(b)) => oxc_minifier::GlobalDefValue::Boolean(*b),
+                                Some(Either3::B(n)) => oxc_minifier::GlobalDefValue::Number(*n),
+                                Some(Either3::C(s)) => {
+                                    oxc_minifier::GlobalDefValue::String(s.clone())
+                                }
+                            };
+                            (key.clone(), value)
+                        })
+                        .collect()
+                })
+                .unwrap_or(default.global_defs),
             treeshake: TreeShakeOptions::default(),
         })
     }
@@ -78,11 +199,24 @@ pub struct CompressOptionsKeepNames {
     ///
     /// @default false
     pub class: bool,
+
+    /// Only keep names of functions matching the regular expression.
+    pub function_regex: Option<String>,
+
+    /// Only keep names of classes matching the regular expression.
+    pub class_regex: Option<String>,
 }
 
-impl From<&CompressOptionsKeepNames> for oxc_minifier::CompressOptionsKeepNames {
-    fn from(o: &CompressOptionsKeepNames) -> Self {
-        oxc_minifier::CompressOptionsKeepNames { function: o.function, class: o.class }
+impl TryFrom<&CompressOptionsKeepNames> for oxc_minifier::CompressOptionsKeepNames {
+    type Error = String;
+
+    fn try_from(o: &CompressOptionsKeepNames) -> Result<Self, Self::Error> {
+        Ok(oxc_minifier::CompressOptionsKeepNames {
+            function: o.function,
+            class: o.class,
+            function_regex: parse_regex(o.function_regex.as_deref())?,
+            class_regex: parse_regex(o.class_regex.as_deref())?,
+        })
     }
 }
 
@@ -99,23 +233,105 @@ pub struct MangleOptions {
     /// @default false
     pub keep_names: Option<Either<bool, MangleOptionsKeepNames>>,
 
+    /// Pass `true` to mangle names visible in scopes where a direct `eval` or `with` is used.
+    ///
+    /// @default false
+    pub eval: Option<bool>,
+
+    /// Names that are never mangled.
+    ///
+    /// @default []
+    pub reserved: Option<Vec<String>>,
+
+    /// Mangle property names.
+    ///
+    /// @default false
+    pub properties: Option<Either<bool, ManglePropertiesOptions>>,
+
+    /// Pass `true` to keep the names of private class members (`#foo`).
+    ///
+    /// @default false
+    pub keep_private_names: Option<bool>,
+
+    /// Characters used by mangled names, most preferred first.
+    ///
+    /// Characters are ordered by their frequency in the source text by default.
+    pub alphabet: Option<String>,
+
     /// Debug mangled names.
     pub debug: Option<bool>,
 }
 
-impl From<&MangleOptions> for oxc_minifier::MangleOptions {
-    fn from(o: &MangleOptions) -> Self {
+impl TryFrom<&MangleOptions> for oxc_minifier::MangleOptions {
+    type Error = String;
+
+    fn try_from(o: &MangleOptions) -> Result<Self, Self::Error> {
         let default = oxc_minifier::MangleOptions::default();
-        Self {
+        Ok(Self {
             top_level: o.toplevel.unwrap_or(default.top_level),
             keep_names: match &o.keep_names {
                 Some(Either::A(false)) => oxc_minifier::MangleOptionsKeepNames::all_false(),
                 Some(Either::A(true)) => oxc_minifier::MangleOptionsKeepNames::all_true(),
-                Some(Either::B(o)) => oxc_minifier::MangleOptionsKeepNames::from(o),
+                Some(Either::B(o)) => oxc_minifier::MangleOptionsKeepNames::try_from(o)?,
                 None => default.keep_names,
             },
+            eval: o.eval.unwrap_or(default.eval),
+            reserved: o.reserved.clone().unwrap_or(default.reserved),
+            properties: match &o.properties {
+                None | Some(Either::A(false)) => None,
+                Some(Either::A(true)) => Some(oxc_minifier::ManglePropertiesOptions::default()),
+                Some(Either::B(o)) => Some(oxc_minifier::ManglePropertiesOptions::try_from(o)?),
+            },
+            keep_private_names: o.keep_private_names.unwrap_or(default.keep_private_names),
+            alphabet: o.alphabet.as_deref().map(oxc_minifier::MangleAlphabet::new).transpose()?,
             debug: o.debug.unwrap_or(default.debug),
-        }
+        })
+    }
+}
+
+#[napi(object)]
+#[derive(Default)]
+pub struct ManglePropertiesOptions {
+    /// Only mangle property names matching the regular expression.
+    pub regex: Option<String>,
+
+    /// Property names that are never mangled.
+    ///
+    /// @default []
+    pub reserved: Option<Vec<String>>,
+
+    /// Do not mangle quoted property names.
+    ///
+    /// @default false
+    pub keep_quoted: Option<bool>,
+
+    /// Use readable mangled names (e.g. `_$foo$_`) for debugging.
+    ///
+    /// @default false
+    pub debug: Option<bool>,
+
+    /// Mangled names from a previous build, keyed by the original name.
+    ///
+    /// Pass `propertyNameCache` of the previous result to keep mangled names consistent across builds.
+    pub name_cache: Option<HashMap<String, String>>,
+}
+
+impl TryFrom<&ManglePropertiesOptions> for oxc_minifier::ManglePropertiesOptions {
+    type Error = String;
+
+    fn try_from(o: &ManglePropertiesOptions) -> Result<Self, Self::Error> {
+        let default = oxc_minifier::ManglePropertiesOptions::default();
+        Ok(Self {
+            regex: parse_regex(o.regex.as_deref())?,
+            reserved: o.reserved.clone().unwrap_or(default.reserved),
+            keep_quoted: o.keep_quoted.unwrap_or(default.keep_quoted),
+            debug: o.debug.unwrap_or(default.debug),
+            name_cache: o
+                .name_cache
+                .as_ref()
+                .map(|cache| cache.clone().into_iter().collect())
+                .unwrap_or_default(),
+        })
     }
 }
 
@@ -130,35 +346,62 @@ pub struct MangleOptionsKeepNames {
     ///
     /// @default false
     pub class: bool,
+
+    /// Only preserve `name` property for functions matching the regular expression.
+    pub function_regex: Option<String>,
+
+    /// Only preserve `name` property for classes matching the regular expression.
+    pub class_regex: Option<String>,
 }
 
-impl From<&MangleOptionsKeepNames> for oxc_minifier::MangleOptionsKeepNames {
-    fn from(o: &MangleOptionsKeepNames) -> Self {
-        oxc_minifier::MangleOptionsKeepNames { function: o.function, class: o.class }
+impl TryFrom<&MangleOptionsKeepNames> for oxc_minifier::MangleOptionsKeepNames {
+    type Error = String;
+
+    fn try_from(o: &MangleOptionsKeepNames) -> Result<Self, Self::Error> {
+        Ok(oxc_minifier::MangleOptionsKeepNames {
+            function: o.function,
+            class: o.class,
+            function_regex: parse_regex(o.function_regex.as_deref())?,
+            class_regex: parse_regex(o.class_regex.as_deref())?,
+        })
     }
 }
 
+fn parse_regex(pattern: Option<&str>) -> Result<Option<lazy_regex::Regex>, String> {
+    pattern.map(lazy_regex::Regex::new).transpose().map_err(|err| err.to_string())
+}
+
 #[napi(object)]
 pub struct CodegenOptions {
     /// Remove whitespace.
     ///
     /// @default true
     pub remove_whitespace: Option<bool>,
+
+    /// Keep `@__PURE__` and `#__NO_SIDE_EFFECTS__` annotations,
+    /// so that the output can be tree shaken by bundlers.
+    ///
+    /// @default false
+    pub pure_annotations: Option<bool>,
 }
 
 impl Default for CodegenOptions {
     fn default() -> Self {
-        Self { remove_whitespace: Some(true) }
+        Self { remove_whitespace: Some(true), pure_annotations: Some(false) }
     }
 }
 
 impl From<&CodegenOptions> for oxc_codegen::CodegenOptions {
     fn from(o: &CodegenOptions) -> Self {
-        if o.remove_whitespace.is_some_and(|b| b) {
+        let options = if o.remove_whitespace.is_some_and(|b| b) {
             oxc_codegen::CodegenOptions::minify()
         } else {
             // Need to remove all comments.
             oxc_codegen::CodegenOptions { minify: false, ..oxc_codegen::CodegenOptions::minify() }
+        };
+        oxc_codegen::CodegenOptions {
+            pure_annotations: o.pure_annotations.unwrap_or(options.pure_annotations),
+            ..options
         }
     }
 }
@@ -173,6 +416,11 @@ pub struct MinifyOptions {
     pub codegen: Option<Either<bool, CodegenOptions>>,
 
     pub sourcemap: Option<bool>,
+
+    /// Return a breakdown of the minified size in `report`.
+    ///
+    /// @default false
+    pub report: Option<bool>,
 }
 
 impl TryFrom<&MinifyOptions> for oxc_minifier::MinifierOptions {
@@ -187,9 +435,10 @@ impl TryFrom<&MinifyOptions> for oxc_minifier::MinifierOptions {
         let mangle = match &o.mangle {
             Some(Either::A(false)) => None,
             None | Some(Either::A(true)) => Some(oxc_minifier::MangleOptions::default()),
-            Some(Either::B(o)) => Some(oxc_minifier::MangleOptions::from(o)),
+            Some(Either::B(o)) => Some(oxc_minifier::MangleOptions::try_from(o)?),
         };
-        Ok(oxc_minifier::MinifierOptions { compress, mangle })
+        let report = o.report.unwrap_or(false);
+        Ok(oxc_minifier::MinifierOptions { compress, mangle, report })
     }
 }
 
@@ -198,4 +447,89 @@ pub struct MinifyResult {
     pub code: String,
 
     pub map: Option<SourceMap>,
+
+    /// Mangled property names, when `mangle.properties` is set.
+    pub property_name_cache: Option<HashMap<String, String>>,
+
+    /// Breakdown of the minified size, when `report` is set.
+    pub report: Option<SizeReport>,
+
+    /// Parse errors.
+    ///
+    /// The parser recovers from common syntax errors, so the code is still minified if there are
+    /// errors in this list.
+    pub errors: Vec<OxcError>,
+}
+
+/// Sizes are in bytes.
+#[napi(object)]
+pub struct SizeReport {
+    pub original_size: u32,
+
+    pub minified_size: u32,
+
+    /// Size of the code after each pass, in the order the passes ran.
+    pub passes: Vec<PassSize>,
+
+    /// Functions of the source text, in order of appearance.
+    pub functions: Vec<FunctionSize>,
+
+    /// Top level declarations of the minified code, largest first.
+    pub symbols: Vec<SymbolSize>,
+}
+
+#[napi(object)]
+pub struct PassSize {
+    pub name: String,
+    pub size: u32,
+}
+
+#[napi(object)]
+pub struct FunctionSize {
+    pub name: Option<String>,
+    pub start: u32,
+    pub end: u32,
+    pub original_size: u32,
+    /// `0` if the function was removed or inlined.
+    pub minified_size: u32,
+}
+
+#[napi(object)]
+pub struct SymbolSize {
+    pub name: String,
+    pub minified_size: u32,
+}
+
+#[expect(clippy::cast_possible_truncation)]
+impl From<oxc_minifier::SizeReport> for SizeReport {
+    fn from(report: oxc_minifier::SizeReport) -> Self {
+        Self {
+            original_size: report.original_size as u32,
+            minified_size: report.minified_size as u32,
+            passes: report
+                .passes
+                .into_iter()
+                .map(|pass| PassSize { name: pass.name.to_string(), size: pass.size as u32 })
+                .collect(),
+            functions: report
+                .functions
+                .into_iter()
+                .map(|function| FunctionSize {
+                    name: function.name,
+                    start: function.span.start,
+                    end: function.span.end,
+                    original_size: function.original_size as u32,
+                    minified_size: function.minified_size as u32,
+                })
+                .collect(),
+            symbols: report
+                .symbols
+                .into_iter()
+                .map(|symbol| SymbolSize {
+                    name: symbol.name,
+                    minified_size: symbol.minified_size as u32,
+                })
+                .collect(),
+        }
+    }
 }
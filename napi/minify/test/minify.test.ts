@@ -23,6 +23,7 @@ describe('simple', () => {
         ],
         'version': 3,
       },
+      'errors': [],
     });
   });
 
@@ -30,9 +31,24 @@ describe('simple', () => {
     const ret = minify('test.js', code, { compress: false, mangle: false, codegen: { removeWhitespace: false } });
     expect(ret).toStrictEqual({
       'code': 'function foo() {\n\tvar bar;\n\tbar(undefined);\n}\nfoo();\n',
+      'errors': [],
     });
   });
 
+  it('returns a size report', () => {
+    const ret = minify('test.js', code, { report: true });
+    expect(ret.report!.originalSize).toBe(code.length);
+    expect(ret.report!.minifiedSize).toBe(ret.code.length);
+    expect(ret.report!.functions.map((f) => f.name)).toStrictEqual(['foo']);
+    expect(minify('test.js', code).report).toBeUndefined();
+  });
+
+  it('returns parse errors', () => {
+    const ret = minify('test.js', 'foo(; bar()');
+    expect(ret.errors.length).toBe(1);
+    expect(ret.errors[0].severity).toBe('Error');
+  });
+
   it('defaults to esnext', () => {
     const code = 'try { foo } catch (e) {}';
     const ret = minify('test.js', code);
@@ -385,6 +385,8 @@ if (!nativeBinding) {
 module.exports = nativeBinding
 module.exports.Severity = nativeBinding.Severity
 module.exports.ParseResult = nativeBinding.ParseResult
+module.exports.SemanticResult = nativeBinding.SemanticResult
+module.exports.analyzeSync = nativeBinding.analyzeSync
 module.exports.ExportExportNameKind = nativeBinding.ExportExportNameKind
 module.exports.ExportImportNameKind = nativeBinding.ExportImportNameKind
 module.exports.ExportLocalNameKind = nativeBinding.ExportLocalNameKind
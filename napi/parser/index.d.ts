@@ -35,6 +35,31 @@ export declare class ParseResult {
   get errors(): Array<OxcError>
 }
 
+/**
+ * Scopes, symbols and references of a file.
+ *
+ * All offsets are UTF-16 offsets, like the offsets of the AST returned by `parseSync`.
+ */
+export declare class SemanticResult {
+  get symbols(): Array<SemanticSymbol>
+  get scopes(): Array<SemanticScope>
+  get errors(): Array<OxcError>
+  /** Find the symbol declared or referenced at `offset`. */
+  symbolAt(offset: number): SemanticSymbol | null
+  /** Find the innermost scope containing `offset`. */
+  scopeAt(offset: number): SemanticScope | null
+  /** List the references to the symbol with id `symbolId`. */
+  getReferences(symbolId: number): Array<SemanticReference>
+  /**
+   * List the references which are not resolved to a symbol declared in the file,
+   * e.g. references to globals.
+   */
+  getUnresolvedReferences(): Array<SemanticReference>
+}
+
+/** Parse and analyze the scopes, symbols and references of a file synchronously. */
+export declare function analyzeSync(filename: string, sourceText: string, options?: ParserOptions | undefined | null): SemanticResult
+
 export interface DynamicImport {
   start: number
   end: number
@@ -251,6 +276,45 @@ export declare function parseSyncRaw(filename: string, buffer: Uint8Array, sourc
 /** Returns `true` if raw transfer is supported on this platform. */
 export declare function rawTransferSupported(): boolean
 
+/** A reference to a symbol, or to a global if `symbolId` is not set. */
+export interface SemanticReference {
+  id: number
+  name: string
+  /** Id of the referenced symbol, not set for unresolved references. */
+  symbolId?: number
+  start: number
+  end: number
+  /** The reference reads the value of the symbol. */
+  isRead: boolean
+  /** The reference writes the value of the symbol. */
+  isWrite: boolean
+}
+
+/** A scope of the file. */
+export interface SemanticScope {
+  id: number
+  /** Id of the parent scope, not set for the scope of the program. */
+  parentId?: number
+  /** Start of the node creating the scope. */
+  start: number
+  /** End of the node creating the scope. */
+  end: number
+  /** Ids of the symbols declared in the scope. */
+  symbolIds: Array<number>
+}
+
+/** A symbol declared in the file. */
+export interface SemanticSymbol {
+  id: number
+  name: string
+  /** Id of the scope the symbol is declared in. */
+  scopeId: number
+  /** Start of the identifier of the declaration. */
+  start: number
+  /** End of the identifier of the declaration. */
+  end: number
+}
+
 export interface Span {
   start: number
   end: number
@@ -7,6 +7,8 @@ const rawTransferSupported = require('./raw-transfer/supported.js');
 const { parseSync: parseSyncBinding, parseAsync: parseAsyncBinding } = bindings;
 
 module.exports.ParseResult = bindings.ParseResult;
+module.exports.SemanticResult = bindings.SemanticResult;
+module.exports.analyzeSync = bindings.analyzeSync;
 module.exports.ExportExportNameKind = bindings.ExportExportNameKind;
 module.exports.ExportImportNameKind = bindings.ExportImportNameKind;
 module.exports.ExportLocalNameKind = bindings.ExportLocalNameKind;
@@ -56,6 +56,8 @@ const {
 export default __napiModule.exports
 export const Severity = __napiModule.exports.Severity
 export const ParseResult = __napiModule.exports.ParseResult
+export const SemanticResult = __napiModule.exports.SemanticResult
+export const analyzeSync = __napiModule.exports.analyzeSync
 export const ExportExportNameKind = __napiModule.exports.ExportExportNameKind
 export const ExportImportNameKind = __napiModule.exports.ExportImportNameKind
 export const ExportLocalNameKind = __napiModule.exports.ExportLocalNameKind
@@ -87,6 +87,8 @@ const { instance: __napiInstance, module: __wasiModule, napiModule: __napiModule
 module.exports = __napiModule.exports
 module.exports.Severity = __napiModule.exports.Severity
 module.exports.ParseResult = __napiModule.exports.ParseResult
+module.exports.SemanticResult = __napiModule.exports.SemanticResult
+module.exports.analyzeSync = __napiModule.exports.analyzeSync
 module.exports.ExportExportNameKind = __napiModule.exports.ExportExportNameKind
 module.exports.ExportImportNameKind = __napiModule.exports.ExportImportNameKind
 module.exports.ExportLocalNameKind = __napiModule.exports.ExportLocalNameKind
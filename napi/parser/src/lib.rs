@@ -24,10 +24,14 @@ use oxc_napi::{Comment, OxcError, convert_utf8_to_utf16, get_source_type};
 mod convert;
 mod raw_transfer;
 mod raw_transfer_types;
+mod semantic;
 mod types;
 pub use raw_transfer::{
     get_buffer_offset, parse_async_raw, parse_sync_raw, raw_transfer_supported,
 };
+pub use semantic::{
+    SemanticReference, SemanticResult, SemanticScope, SemanticSymbol, analyze_sync,
+};
 pub use types::{EcmaScriptModule, ParseResult, ParserOptions};
 
 mod generated {
@@ -0,0 +1,248 @@
+use napi_derive::napi;
+
+use oxc::{
+    allocator::Allocator,
+    ast_visit::utf8_to_utf16::Utf8ToUtf16,
+    semantic::{NodeId, Semantic, SemanticBuilder},
+    span::{GetSpan, Span},
+    syntax::reference::ReferenceId,
+};
+use oxc_index::Idx;
+use oxc_napi::{OxcError, get_source_type};
+
+use crate::{ParserOptions, parse};
+
+/// A symbol declared in the file.
+#[napi(object)]
+#[derive(Clone)]
+pub struct SemanticSymbol {
+    pub id: u32,
+    pub name: String,
+    /// Id of the scope the symbol is declared in.
+    pub scope_id: u32,
+    /// Start of the identifier of the declaration.
+    pub start: u32,
+    /// End of the identifier of the declaration.
+    pub end: u32,
+}
+
+/// A reference to a symbol, or to a global if `symbolId` is not set.
+#[napi(object)]
+#[derive(Clone)]
+pub struct SemanticReference {
+    pub id: u32,
+    pub name: String,
+    /// Id of the referenced symbol, not set for unresolved references.
+    pub symbol_id: Option<u32>,
+    pub start: u32,
+    pub end: u32,
+    /// The reference reads the value of the symbol.
+    pub is_read: bool,
+    /// The reference writes the value of the symbol.
+    pub is_write: bool,
+}
+
+/// A scope of the file.
+#[napi(object)]
+#[derive(Clone)]
+pub struct SemanticScope {
+    pub id: u32,
+    /// Id of the parent scope, not set for the scope of the program.
+    pub parent_id: Option<u32>,
+    /// Start of the node creating the scope.
+    pub start: u32,
+    /// End of the node creating the scope.
+    pub end: u32,
+    /// Ids of the symbols declared in the scope.
+    pub symbol_ids: Vec<u32>,
+}
+
+/// Scopes, symbols and references of a file.
+///
+/// All offsets are UTF-16 offsets, like the offsets of the AST returned by `parseSync`.
+#[napi]
+pub struct SemanticResult {
+    /// Symbols, indexed by id.
+    symbols: Vec<SemanticSymbol>,
+    /// Scopes, indexed by id. Parent scopes come before their children.
+    scopes: Vec<SemanticScope>,
+    references: Vec<SemanticReference>,
+    errors: Vec<OxcError>,
+}
+
+#[napi]
+impl SemanticResult {
+    #[napi(getter)]
+    pub fn symbols(&self) -> Vec<SemanticSymbol> {
+        self.symbols.clone()
+    }
+
+    #[napi(getter)]
+    pub fn scopes(&self) -> Vec<SemanticScope> {
+        self.scopes.clone()
+    }
+
+    #[napi(getter)]
+    pub fn errors(&mut self) -> Vec<OxcError> {
+        std::mem::take(&mut self.errors)
+    }
+
+    /// Find the symbol declared or referenced at `offset`.
+    #[napi]
+    pub fn symbol_at(&self, offset: u32) -> Option<SemanticSymbol> {
+        let contains = |start: u32, end: u32| start <= offset && offset < end;
+        let symbol_id = self
+            .symbols
+            .iter()
+            .find(|symbol| contains(symbol.start, symbol.end))
+            .map(|symbol| symbol.id)
+            .or_else(|| {
+                self.references
+                    .iter()
+                    .find(|reference| contains(reference.start, reference.end))
+                    .and_then(|reference| reference.symbol_id)
+            })?;
+        self.symbols.get(symbol_id as usize).cloned()
+    }
+
+    /// Find the innermost scope containing `offset`.
+    #[napi]
+    pub fn scope_at(&self, offset: u32) -> Option<SemanticScope> {
+        self.scopes
+            .iter()
+            .rev()
+            .find(|scope| scope.start <= offset && offset < scope.end)
+            .or_else(|| self.scopes.first())
+            .cloned()
+    }
+
+    /// List the references to the symbol with id `symbolId`.
+    #[napi]
+    pub fn get_references(&self, symbol_id: u32) -> Vec<SemanticReference> {
+        self.references
+            .iter()
+            .filter(|reference| reference.symbol_id == Some(symbol_id))
+            .cloned()
+            .collect()
+    }
+
+    /// List the references which are not resolved to a symbol declared in the file,
+    /// e.g. references to globals.
+    #[napi]
+    pub fn get_unresolved_references(&self) -> Vec<SemanticReference> {
+        self.references.iter().filter(|reference| reference.symbol_id.is_none()).cloned().collect()
+    }
+}
+
+/// Parse and analyze the scopes, symbols and references of a file synchronously.
+#[napi]
+pub fn analyze_sync(
+    filename: String,
+    source_text: String,
+    options: Option<ParserOptions>,
+) -> SemanticResult {
+    let options = options.unwrap_or_default();
+    let allocator = Allocator::default();
+    let source_type =
+        get_source_type(&filename, options.lang.as_deref(), options.source_type.as_deref());
+    let ret = parse(&allocator, source_type, &source_text, &options);
+
+    let show_semantic_errors = options.show_semantic_errors == Some(true);
+    let semantic_ret =
+        SemanticBuilder::new().with_check_syntax_error(show_semantic_errors).build(&ret.program);
+    let mut diagnostics = ret.errors;
+    if show_semantic_errors {
+        diagnostics.extend(semantic_ret.errors);
+    }
+    let mut errors = OxcError::from_diagnostics(&filename, &source_text, diagnostics);
+
+    let utf8_to_utf16 = Utf8ToUtf16::new(&source_text);
+    let mut converter = utf8_to_utf16.converter();
+    let mut convert = |mut span: Span| {
+        if let Some(converter) = converter.as_mut() {
+            converter.convert_span(&mut span);
+        }
+        span
+    };
+    if let Some(mut converter) = utf8_to_utf16.converter() {
+        for error in &mut errors {
+            for label in &mut error.labels {
+                converter.convert_offset(&mut label.start);
+                converter.convert_offset(&mut label.end);
+            }
+        }
+    }
+
+    let semantic = semantic_ret.semantic;
+    let scoping = semantic.scoping();
+
+    let symbols = scoping
+        .symbol_ids()
+        .map(|symbol_id| {
+            let span = convert(scoping.symbol_span(symbol_id));
+            SemanticSymbol {
+                id: to_u32(symbol_id),
+                name: scoping.symbol_name(symbol_id).to_string(),
+                scope_id: to_u32(scoping.symbol_scope_id(symbol_id)),
+                start: span.start,
+                end: span.end,
+            }
+        })
+        .collect();
+
+    // SemanticScope ids are assigned in the order of the AST, so parents come before their children.
+    let scopes = scoping
+        .scope_descendants_from_root()
+        .map(|scope_id| {
+            let span = convert(node_span(&semantic, scoping.get_node_id(scope_id)));
+            let mut symbol_ids = scoping.iter_bindings_in(scope_id).map(to_u32).collect::<Vec<_>>();
+            symbol_ids.sort_unstable();
+            SemanticScope {
+                id: to_u32(scope_id),
+                parent_id: scoping.scope_parent_id(scope_id).map(to_u32),
+                start: span.start,
+                end: span.end,
+                symbol_ids,
+            }
+        })
+        .collect();
+
+    let resolved = scoping.symbol_ids().flat_map(|symbol_id| {
+        let name = scoping.symbol_name(symbol_id);
+        scoping.get_resolved_reference_ids(symbol_id).iter().map(move |&id| (name, id))
+    });
+    let unresolved = scoping
+        .root_unresolved_references()
+        .iter()
+        .flat_map(|(&name, ids)| ids.iter().map(move |&id| (name, id)));
+    let mut reference_ids = resolved.chain(unresolved).collect::<Vec<(&str, ReferenceId)>>();
+    reference_ids.sort_unstable_by_key(|(_, id)| *id);
+    let references = reference_ids
+        .into_iter()
+        .map(|(name, reference_id)| {
+            let reference = scoping.get_reference(reference_id);
+            let span = convert(node_span(&semantic, reference.node_id()));
+            SemanticReference {
+                id: to_u32(reference_id),
+                name: name.to_string(),
+                symbol_id: reference.symbol_id().map(to_u32),
+                start: span.start,
+                end: span.end,
+                is_read: reference.is_read(),
+                is_write: reference.is_write(),
+            }
+        })
+        .collect();
+
+    SemanticResult { symbols, scopes, references, errors }
+}
+
+fn node_span(semantic: &Semantic, node_id: NodeId) -> Span {
+    semantic.nodes().get_node(node_id).span()
+}
+
+/// Ids of symbols, scopes and references are created from `u32`s.
+#[expect(clippy::cast_possible_truncation)]
+fn to_u32(id: impl Idx) -> u32 {
+    id.index() as u32
+}
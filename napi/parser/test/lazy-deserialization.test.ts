@@ -4,7 +4,7 @@
 
 import { describe, expect, it } from 'vitest';
 
-import { parseSync } from '../index.js';
+import { experimentalGetLazyVisitor, parseSync } from '../index.js';
 
 function parseSyncLazy(filename, code, options = null) {
   return parseSync(filename, code, { ...options, experimentalLazy: true });
@@ -49,6 +49,26 @@ it('returns same node objects and node arrays on each access', () => {
   expect(program.body[0].declarations[0].id).toBe(id);
 });
 
+describe('visit', () => {
+  it('visits nodes of the given types', () => {
+    const Visitor = experimentalGetLazyVisitor();
+    const visited = [];
+    const visitor = new Visitor({
+      ImportDeclaration(decl) {
+        visited.push(`import ${decl.source.value}`);
+      },
+      IdentifierReference(ident) {
+        visited.push(ident.name);
+      },
+      'CallExpression:exit'(call) {
+        visited.push(`exit ${call.callee.name}()`);
+      },
+    });
+    parseSyncLazy('test.js', "import a from 'a'; foo(b);").visit(visitor);
+    expect(visited).toStrictEqual(['import a', 'foo', 'b', 'exit foo()']);
+  });
+});
+
 describe('NodeArray', () => {
   describe('methods', () => {
     it('at', () => {
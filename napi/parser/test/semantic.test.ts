@@ -0,0 +1,58 @@
+import { describe, expect, it } from 'vitest';
+
+import { analyzeSync } from '../index.js';
+
+describe('semantic', () => {
+  const code = '"🤨"; let x = 1; function f(a) { return a + x + y; } x = 2;';
+
+  it('lists symbols and scopes', () => {
+    const ret = analyzeSync('test.js', code);
+    expect(ret.errors).toEqual([]);
+    expect(ret.symbols.map(({ name, start }) => ({ name, start }))).toEqual([
+      { name: 'x', start: 10 },
+      { name: 'f', start: 26 },
+      { name: 'a', start: 28 },
+    ]);
+    const [program, func] = ret.scopes;
+    expect(program.parentId).toBeUndefined();
+    expect(program.symbolIds).toEqual([0, 1]);
+    expect(func).toMatchObject({ parentId: program.id, start: 17, end: 52, symbolIds: [2] });
+  });
+
+  it('finds the symbol at an offset', () => {
+    const ret = analyzeSync('test.js', code);
+    // Declaration
+    expect(ret.symbolAt(10)?.name).toBe('x');
+    // Reference
+    expect(ret.symbolAt(44)?.name).toBe('x');
+    // Unresolved reference
+    expect(ret.symbolAt(48)).toBeNull();
+  });
+
+  it('finds the scope at an offset', () => {
+    const ret = analyzeSync('test.js', code);
+    expect(ret.scopeAt(35)?.symbolIds).toEqual([2]);
+    expect(ret.scopeAt(55)?.parentId).toBeUndefined();
+  });
+
+  it('lists references', () => {
+    const ret = analyzeSync('test.js', code);
+    const x = ret.symbolAt(10)!;
+    const references = ret.getReferences(x.id);
+    expect(references).toMatchObject([
+      { name: 'x', symbolId: x.id, start: 44, end: 45, isRead: true, isWrite: false },
+      { name: 'x', symbolId: x.id, start: 53, end: 54, isRead: false, isWrite: true },
+    ]);
+  });
+
+  it('lists unresolved references', () => {
+    const ret = analyzeSync('test.js', code);
+    expect(ret.getUnresolvedReferences()).toMatchObject([{ name: 'y', start: 48, end: 49 }]);
+  });
+
+  it('checks semantic', () => {
+    const code = 'let x; let x;';
+    expect(analyzeSync('test.js', code).errors.length).toBe(0);
+    expect(analyzeSync('test.js', code, { showSemanticErrors: true }).errors.length).toBe(1);
+  });
+});
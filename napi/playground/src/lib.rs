@@ -225,6 +225,7 @@ impl Oxc {
                 } else {
                     CompressOptions::default()
                 }),
+                report: false,
             };
             Minifier::new(options).build(&allocator, &mut program).scoping
         } else {
@@ -286,16 +287,18 @@ impl Oxc {
         if run_options.lint.unwrap_or_default() && self.diagnostics.is_empty() {
             let semantic_ret = SemanticBuilder::new().with_cfg(true).build(program);
             let semantic = Rc::new(semantic_ret.semantic);
-            let lint_config = if linter_options.config.is_some() {
-                let oxlintrc =
-                    Oxlintrc::from_string(&linter_options.config.as_ref().unwrap().to_string())
-                        .unwrap_or_default();
-                let config_builder =
-                    ConfigStoreBuilder::from_oxlintrc(false, oxlintrc).unwrap_or_default();
-                config_builder.build()
-            } else {
-                ConfigStoreBuilder::default().build()
+            // Report invalid configs, rather than silently linting with the default rules.
+            let config =
+                linter_options.config.as_deref().filter(|config| !config.trim().is_empty());
+            let config_builder = match config.map(Self::lint_config_builder) {
+                Some(Ok(config_builder)) => config_builder,
+                Some(Err(error)) => {
+                    self.diagnostics.push(error);
+                    return;
+                }
+                None => ConfigStoreBuilder::default(),
             };
+            let lint_config = config_builder.build();
             let linter_ret = Linter::new(
                 LintOptions::default(),
                 ConfigStore::new(lint_config, FxHashMap::default()),
@@ -305,6 +308,12 @@ impl Oxc {
         }
     }
 
+    fn lint_config_builder(config: &str) -> Result<ConfigStoreBuilder, OxcDiagnostic> {
+        let oxlintrc = Oxlintrc::from_string(config)?;
+        ConfigStoreBuilder::from_oxlintrc(false, oxlintrc)
+            .map_err(|err| OxcDiagnostic::error(format!("Invalid linter config: {err}")))
+    }
+
     fn run_formatter(
         &mut self,
         run_options: &OxcRunOptions,
@@ -48,6 +48,31 @@ export interface ArrowFunctionsOptions {
   spec?: boolean
 }
 
+export interface AssetImportOptions {
+  /**
+   * Pattern the import source must match, e.g. `*.module.css` or `*.svg?react`.
+   * `*` matches any characters.
+   */
+  test: string
+  /**
+   * How the matching imports are rewritten.
+   *
+   * - 'remove' - remove the import, binding default imports to `{}`
+   * - 'url' - bind the default import to `new URL(source, import.meta.url).href`
+   * - 'component' - import a component from the rewritten `source`
+   */
+  action: 'remove' | 'url' | 'component'
+  /**
+   * Template for the rewritten import source of the `component` action.
+   * `{{path}}` is replaced by the source without its query, `{{source}}` by the whole source.
+   *
+   * @default '{{path}}'
+   */
+  source?: string
+  /** Named export the default import is bound to by the `component` action, e.g. `ReactComponent`. */
+  exportName?: string
+}
+
 export interface CompilerAssumptions {
   ignoreFunctionLength?: boolean
   noDocumentAll?: boolean
@@ -95,6 +120,11 @@ export interface CompilerAssumptions {
    * to `true`.
    */
   setPublicClassFields?: boolean
+  /**
+   * When using object spread, assume that spread properties don't trigger getters on the target
+   * object and thus it's safe to assign them rather than defining them using `Object.defineProperty`.
+   */
+  setSpreadProperties?: boolean
 }
 
 export interface DecoratorOptions {
@@ -120,6 +150,27 @@ export interface DecoratorOptions {
   emitDecoratorMetadata?: boolean
 }
 
+export interface EmotionOptions {
+  /**
+   * When to add a `label` to styles.
+   *
+   * @default 'dev-only'
+   */
+  autoLabel?: 'dev-only' | 'always' | 'never'
+  /**
+   * Format of the label. Supports the `[local]`, `[filename]` and `[dirname]` placeholders.
+   *
+   * @default '[local]'
+   */
+  labelFormat?: string
+  /**
+   * Add an inline source map to styles in development.
+   *
+   * @default true
+   */
+  sourceMap?: boolean
+}
+
 export interface Es2015Options {
   /** Transform arrow functions into function expressions. */
   arrowFunction?: ArrowFunctionsOptions
@@ -156,6 +207,14 @@ export interface Helpers {
 /** TypeScript Isolated Declarations for Standalone DTS Emit */
 export declare function isolatedDeclaration(filename: string, sourceText: string, options?: IsolatedDeclarationsOptions | undefined | null): IsolatedDeclarationsResult
 
+/**
+ * TypeScript Isolated Declarations for Standalone DTS Emit, asynchronously.
+ *
+ * Runs on a thread of the libuv thread pool, so that declarations of several files can be
+ * generated in parallel.
+ */
+export declare function isolatedDeclarationAsync(filename: string, sourceText: string, options?: IsolatedDeclarationsOptions | undefined | null): Promise<IsolatedDeclarationsResult>
+
 export interface IsolatedDeclarationsOptions {
   /**
    * Do not emit declarations for code that has an @internal annotation in its JSDoc comment.
@@ -166,6 +225,12 @@ export interface IsolatedDeclarationsOptions {
    * See <https://www.typescriptlang.org/tsconfig/#stripInternal>
    */
   stripInternal?: boolean
+  /**
+   * Generate a declaration map (`.d.ts.map`), which maps the emitted declarations back to the
+   * original source.
+   *
+   * Default: `false`
+   */
   sourcemap?: boolean
 }
 
@@ -283,6 +348,40 @@ export interface JsxOptions {
  *
  * @deprecated Only works for Vite.
  */
+export interface ModularImportOptions {
+  /** Name of the library whose member imports are rewritten, e.g. `antd`. */
+  libraryName: string
+  /**
+   * Directory inside the library that contains one module per member.
+   *
+   * @default 'lib'
+   */
+  libraryDirectory?: string
+  /**
+   * Convert member names from `CamelCase` to `dash-case` in module paths.
+   *
+   * @default true
+   */
+  camel2DashComponentName?: boolean
+  /**
+   * Import the default export of the per-module file, instead of the member by name.
+   *
+   * @default true
+   */
+  transformToDefaultImport?: boolean
+  /**
+   * Also import the member's styles: `true` imports `<module>/style`, `'css'` imports `<module>/style/css`.
+   *
+   * @default false
+   */
+  style?: boolean | 'css'
+  /**
+   * Template for the module path, overriding `libraryDirectory`.
+   * `{{member}}` is replaced by the member name, e.g. `lodash/{{member}}`.
+   */
+  customName?: string
+}
+
 export declare function moduleRunnerTransform(filename: string, sourceText: string, options?: ModuleRunnerTransformOptions | undefined | null): ModuleRunnerTransformResult
 
 export interface ModuleRunnerTransformOptions {
@@ -323,6 +422,33 @@ export interface ModuleRunnerTransformResult {
   errors: Array<OxcError>
 }
 
+export interface PluginsOptions {
+  /**
+   * Emotion plugin.
+   *
+   * @see https://emotion.sh/docs/@emotion/babel-plugin
+   */
+  emotion?: EmotionOptions
+  /**
+   * Rewrite member imports of libraries into per-module imports.
+   *
+   * @see https://github.com/umijs/babel-plugin-import
+   */
+  modularImports?: Array<ModularImportOptions>
+  /**
+   * Rewrite imports of assets such as CSS modules or SVG files,
+   * e.g. to drop them or to resolve them to URLs.
+   */
+  assetImports?: Array<AssetImportOptions>
+  /**
+   * Rewrite relative import sources into fully specified paths, as required by Node.js for ES modules,
+   * e.g. `./utils` to `./utils.js` and `./components` to `./components/index.js`.
+   *
+   * @default false
+   */
+  fullySpecified?: boolean
+}
+
 export interface ReactRefreshOptions {
   /**
    * Specify the identifier of the refresh registration variable.
@@ -353,6 +479,18 @@ export interface ReactRefreshOptions {
  */
 export declare function transform(filename: string, sourceText: string, options?: TransformOptions | undefined | null): TransformResult
 
+/**
+ * Transpile a JavaScript or TypeScript into a target ECMAScript version, asynchronously.
+ *
+ * Transforms on a thread of the libuv thread pool, so that build tools can transform several
+ * files in parallel without blocking the event loop.
+ *
+ * Note: This function can be slower than `transform` due to the overhead of spawning a thread.
+ *
+ * @see {@link transform}
+ */
+export declare function transformAsync(filename: string, sourceText: string, options?: TransformOptions | undefined | null): Promise<TransformResult>
+
 /**
  * Options for transforming a JavaScript or TypeScript file.
  *
@@ -382,8 +520,13 @@ export interface TransformOptions {
   assumptions?: CompilerAssumptions
   /** Configure how TypeScript is transformed. */
   typescript?: TypeScriptOptions
-  /** Configure how TSX and JSX are transformed. */
-  jsx?: 'preserve' | JsxOptions
+  /**
+   * Configure how TSX and JSX are transformed.
+   *
+   * * `'preserve'` leaves JSX untouched
+   * * `'vue'` compiles JSX to Vue 3 render functions
+   */
+  jsx?: 'preserve' | 'vue' | JsxOptions
   /**
    * Sets the target environment for the generated JavaScript.
    *
@@ -407,6 +550,8 @@ export interface TransformOptions {
   inject?: Record<string, string | [string, string]>
   /** Decorator plugin */
   decorator?: DecoratorOptions
+  /** Third-party plugins to use. */
+  plugins?: PluginsOptions
 }
 
 export interface TransformResult {
@@ -465,6 +610,34 @@ export interface TypeScriptOptions {
   jsxPragma?: string
   jsxPragmaFrag?: string
   onlyRemoveTypeImports?: boolean
+  /**
+   * Only remove imports and exports written with the `type` modifier,
+   * same as TypeScript's `verbatimModuleSyntax`.
+   *
+   * @default false
+   */
+  verbatimModuleSyntax?: boolean
+  /**
+   * Report errors for code that can not be transpiled one file at a time,
+   * same as TypeScript's `isolatedModules`.
+   *
+   * @default false
+   */
+  isolatedModules?: boolean
+  /**
+   * What to do with import declarations whose bindings are all removed,
+   * same as TypeScript's `importsNotUsedAsValues`.
+   *
+   * @default 'remove'
+   */
+  importsNotUsedAsValues?: 'remove' | 'preserve' | 'error'
+  /**
+   * Keep imported bindings which are never referenced,
+   * same as TypeScript's `preserveValueImports`.
+   *
+   * @default false
+   */
+  preserveValueImports?: boolean
   allowNamespaces?: boolean
   /**
    * When enabled, type-only class fields are only removed if they are prefixed with the declare modifier:
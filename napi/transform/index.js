@@ -386,5 +386,7 @@ module.exports = nativeBinding
 module.exports.Severity = nativeBinding.Severity
 module.exports.HelperMode = nativeBinding.HelperMode
 module.exports.isolatedDeclaration = nativeBinding.isolatedDeclaration
+module.exports.isolatedDeclarationAsync = nativeBinding.isolatedDeclarationAsync
 module.exports.moduleRunnerTransform = nativeBinding.moduleRunnerTransform
 module.exports.transform = nativeBinding.transform
+module.exports.transformAsync = nativeBinding.transformAsync
@@ -1,5 +1,6 @@
 use std::path::Path;
 
+use napi::{Task, bindgen_prelude::AsyncTask};
 use napi_derive::napi;
 
 use oxc::{
@@ -30,6 +31,10 @@ pub struct IsolatedDeclarationsOptions {
     /// See <https://www.typescriptlang.org/tsconfig/#stripInternal>
     pub strip_internal: Option<bool>,
 
+    /// Generate a declaration map (`.d.ts.map`), which maps the emitted declarations back to the
+    /// original source.
+    ///
+    /// Default: `false`
     pub sourcemap: Option<bool>,
 }
 
@@ -47,12 +52,53 @@ pub fn isolated_declaration(
     source_text: String,
     options: Option<IsolatedDeclarationsOptions>,
 ) -> IsolatedDeclarationsResult {
-    let source_path = Path::new(&filename);
+    isolated_declaration_impl(&filename, &source_text, options)
+}
+
+pub struct IsolatedDeclarationTask {
+    filename: String,
+    source_text: String,
+    options: Option<IsolatedDeclarationsOptions>,
+}
+
+#[napi]
+impl Task for IsolatedDeclarationTask {
+    type JsValue = IsolatedDeclarationsResult;
+    type Output = IsolatedDeclarationsResult;
+
+    fn compute(&mut self) -> napi::Result<Self::Output> {
+        Ok(isolated_declaration_impl(&self.filename, &self.source_text, self.options))
+    }
+
+    fn resolve(&mut self, _: napi::Env, result: Self::Output) -> napi::Result<Self::JsValue> {
+        Ok(result)
+    }
+}
+
+/// TypeScript Isolated Declarations for Standalone DTS Emit, asynchronously.
+///
+/// Runs on a thread of the libuv thread pool, so that declarations of several files can be
+/// generated in parallel.
+#[napi]
+pub fn isolated_declaration_async(
+    filename: String,
+    source_text: String,
+    options: Option<IsolatedDeclarationsOptions>,
+) -> AsyncTask<IsolatedDeclarationTask> {
+    AsyncTask::new(IsolatedDeclarationTask { filename, source_text, options })
+}
+
+fn isolated_declaration_impl(
+    filename: &str,
+    source_text: &str,
+    options: Option<IsolatedDeclarationsOptions>,
+) -> IsolatedDeclarationsResult {
+    let source_path = Path::new(filename);
     let source_type = SourceType::from_path(source_path).unwrap_or_default().with_typescript(true);
     let allocator = Allocator::default();
     let options = options.unwrap_or_default();
 
-    let ret = Parser::new(&allocator, &source_text, source_type).parse();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
 
     let transformed_ret = IsolatedDeclarations::new(
         &allocator,
@@ -71,7 +117,7 @@ pub fn isolated_declaration(
         .build(&transformed_ret.program);
 
     let diagnostics = ret.errors.into_iter().chain(transformed_ret.errors).collect::<Vec<_>>();
-    let errors = OxcError::from_diagnostics(&filename, &source_text, diagnostics);
+    let errors = OxcError::from_diagnostics(filename, source_text, diagnostics);
 
     IsolatedDeclarationsResult {
         code: codegen_ret.code,
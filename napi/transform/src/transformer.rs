@@ -6,7 +6,7 @@ use std::{
     path::{Path, PathBuf},
 };
 
-use napi::Either;
+use napi::{Either, Task, bindgen_prelude::AsyncTask};
 use napi_derive::napi;
 use rustc_hash::FxHashMap;
 
@@ -19,7 +19,8 @@ use oxc::{
     semantic::{SemanticBuilder, SemanticBuilderReturn},
     span::SourceType,
     transformer::{
-        EnvOptions, HelperLoaderMode, HelperLoaderOptions, JsxRuntime, ProposalOptions,
+        AssetImportAction, EmotionAutoLabel, EnvOptions, HelperLoaderMode, HelperLoaderOptions,
+        ImportsNotUsedAsValues, JsxRuntime, ModularImportStyle, ProposalOptions,
         RewriteExtensionsMode,
     },
     transformer_plugins::{
@@ -114,7 +115,10 @@ pub struct TransformOptions {
     pub typescript: Option<TypeScriptOptions>,
 
     /// Configure how TSX and JSX are transformed.
-    #[napi(ts_type = "'preserve' | JsxOptions")]
+    ///
+    /// * `'preserve'` leaves JSX untouched
+    /// * `'vue'` compiles JSX to Vue 3 render functions
+    #[napi(ts_type = "'preserve' | 'vue' | JsxOptions")]
     pub jsx: Option<Either<String, JsxOptions>>,
 
     /// Sets the target environment for the generated JavaScript.
@@ -144,6 +148,9 @@ pub struct TransformOptions {
 
     /// Decorator plugin
     pub decorator: Option<DecoratorOptions>,
+
+    /// Third-party plugins to use.
+    pub plugins: Option<PluginsOptions>,
 }
 
 impl TryFrom<TransformOptions> for oxc::transformer::TransformOptions {
@@ -167,18 +174,20 @@ impl TryFrom<TransformOptions> for oxc::transformer::TransformOptions {
                 .map(oxc::transformer::DecoratorOptions::from)
                 .unwrap_or_default(),
             jsx: match options.jsx {
-                Some(Either::A(s)) => {
-                    if s == "preserve" {
-                        oxc::transformer::JsxOptions::disable()
-                    } else {
-                        return Err(format!("Invalid jsx option: `{s}`."));
-                    }
-                }
+                Some(Either::A(s)) => match s.as_str() {
+                    "preserve" => oxc::transformer::JsxOptions::disable(),
+                    "vue" => oxc::transformer::JsxOptions::vue(),
+                    _ => return Err(format!("Invalid jsx option: `{s}`.")),
+                },
                 Some(Either::B(options)) => oxc::transformer::JsxOptions::from(options),
                 None => oxc::transformer::JsxOptions::enable(),
             },
             env,
             proposals: ProposalOptions::default(),
+            plugins: options
+                .plugins
+                .map(oxc::transformer::PluginsOptions::from)
+                .unwrap_or_default(),
             helper_loader: options
                 .helpers
                 .map_or_else(HelperLoaderOptions::default, HelperLoaderOptions::from),
@@ -233,6 +242,9 @@ pub struct CompilerAssumptions {
     /// set both `set_public_class_fields` and [`crate::TypeScriptOptions::remove_class_fields_without_initializer`]
     /// to `true`.
     pub set_public_class_fields: Option<bool>,
+    /// When using object spread, assume that spread properties don't trigger getters on the target
+    /// object and thus it's safe to assign them rather than defining them using `Object.defineProperty`.
+    pub set_spread_properties: Option<bool>,
 }
 
 impl From<CompilerAssumptions> for oxc::transformer::CompilerAssumptions {
@@ -250,6 +262,7 @@ impl From<CompilerAssumptions> for oxc::transformer::CompilerAssumptions {
             set_public_class_fields: value
                 .set_public_class_fields
                 .unwrap_or(ops.set_public_class_fields),
+            set_spread_properties: value.set_spread_properties.unwrap_or(ops.set_spread_properties),
             ..ops
         }
     }
@@ -261,6 +274,27 @@ pub struct TypeScriptOptions {
     pub jsx_pragma: Option<String>,
     pub jsx_pragma_frag: Option<String>,
     pub only_remove_type_imports: Option<bool>,
+    /// Only remove imports and exports written with the `type` modifier,
+    /// same as TypeScript's `verbatimModuleSyntax`.
+    ///
+    /// @default false
+    pub verbatim_module_syntax: Option<bool>,
+    /// Report errors for code that can not be transpiled one file at a time,
+    /// same as TypeScript's `isolatedModules`.
+    ///
+    /// @default false
+    pub isolated_modules: Option<bool>,
+    /// What to do with import declarations whose bindings are all removed,
+    /// same as TypeScript's `importsNotUsedAsValues`.
+    ///
+    /// @default 'remove'
+    #[napi(ts_type = "'remove' | 'preserve' | 'error'")]
+    pub imports_not_used_as_values: Option<String>,
+    /// Keep imported bindings which are never referenced,
+    /// same as TypeScript's `preserveValueImports`.
+    ///
+    /// @default false
+    pub preserve_value_imports: Option<bool>,
     pub allow_namespaces: Option<bool>,
     /// When enabled, type-only class fields are only removed if they are prefixed with the declare modifier:
     ///
@@ -331,6 +365,18 @@ impl From<TypeScriptOptions> for oxc::transformer::TypeScriptOptions {
             only_remove_type_imports: options
                 .only_remove_type_imports
                 .unwrap_or(ops.only_remove_type_imports),
+            verbatim_module_syntax: options
+                .verbatim_module_syntax
+                .unwrap_or(ops.verbatim_module_syntax),
+            isolated_modules: options.isolated_modules.unwrap_or(ops.isolated_modules),
+            imports_not_used_as_values: match options.imports_not_used_as_values.as_deref() {
+                Some("preserve") => ImportsNotUsedAsValues::Preserve,
+                Some("error") => ImportsNotUsedAsValues::Error,
+                _ => ops.imports_not_used_as_values,
+            },
+            preserve_value_imports: options
+                .preserve_value_imports
+                .unwrap_or(ops.preserve_value_imports),
             allow_namespaces: options.allow_namespaces.unwrap_or(ops.allow_namespaces),
             allow_declare_fields: options.allow_declare_fields.unwrap_or(ops.allow_declare_fields),
             optimize_const_enums: false,
@@ -388,6 +434,173 @@ impl From<DecoratorOptions> for oxc::transformer::DecoratorOptions {
     }
 }
 
+#[napi(object)]
+#[derive(Default)]
+pub struct PluginsOptions {
+    /// Emotion plugin.
+    ///
+    /// @see https://emotion.sh/docs/@emotion/babel-plugin
+    pub emotion: Option<EmotionOptions>,
+
+    /// Rewrite member imports of libraries into per-module imports.
+    ///
+    /// @see https://github.com/umijs/babel-plugin-import
+    pub modular_imports: Option<Vec<ModularImportOptions>>,
+
+    /// Rewrite imports of assets such as CSS modules or SVG files,
+    /// e.g. to drop them or to resolve them to URLs.
+    pub asset_imports: Option<Vec<AssetImportOptions>>,
+
+    /// Rewrite relative import sources into fully specified paths, as required by Node.js for ES modules,
+    /// e.g. `./utils` to `./utils.js` and `./components` to `./components/index.js`.
+    ///
+    /// @default false
+    pub fully_specified: Option<bool>,
+}
+
+impl From<PluginsOptions> for oxc::transformer::PluginsOptions {
+    fn from(options: PluginsOptions) -> Self {
+        oxc::transformer::PluginsOptions {
+            emotion: options.emotion.map(oxc::transformer::EmotionOptions::from),
+            modular_imports: options.modular_imports.map(|options| {
+                options.into_iter().map(oxc::transformer::ModularImportOptions::from).collect()
+            }),
+            asset_imports: options.asset_imports.map(|options| {
+                options.into_iter().map(oxc::transformer::AssetImportOptions::from).collect()
+            }),
+            fully_specified: options.fully_specified.unwrap_or_default(),
+        }
+    }
+}
+
+#[napi(object)]
+pub struct ModularImportOptions {
+    /// Name of the library whose member imports are rewritten, e.g. `antd`.
+    pub library_name: String,
+
+    /// Directory inside the library that contains one module per member.
+    ///
+    /// @default 'lib'
+    pub library_directory: Option<String>,
+
+    /// Convert member names from `CamelCase` to `dash-case` in module paths.
+    ///
+    /// @default true
+    #[napi(js_name = "camel2DashComponentName")]
+    pub camel_to_dash_component_name: Option<bool>,
+
+    /// Import the default export of the per-module file, instead of the member by name.
+    ///
+    /// @default true
+    pub transform_to_default_import: Option<bool>,
+
+    /// Also import the member's styles: `true` imports `<module>/style`, `'css'` imports `<module>/style/css`.
+    ///
+    /// @default false
+    #[napi(ts_type = "boolean | 'css'")]
+    pub style: Option<Either<bool, String>>,
+
+    /// Template for the module path, overriding `libraryDirectory`.
+    /// `{{member}}` is replaced by the member name, e.g. `lodash/{{member}}`.
+    pub custom_name: Option<String>,
+}
+
+impl From<ModularImportOptions> for oxc::transformer::ModularImportOptions {
+    fn from(options: ModularImportOptions) -> Self {
+        let ops = oxc::transformer::ModularImportOptions::new(options.library_name);
+        oxc::transformer::ModularImportOptions {
+            library_directory: options.library_directory.unwrap_or(ops.library_directory),
+            camel_to_dash_component_name: options
+                .camel_to_dash_component_name
+                .unwrap_or(ops.camel_to_dash_component_name),
+            transform_to_default_import: options
+                .transform_to_default_import
+                .unwrap_or(ops.transform_to_default_import),
+            style: match options.style {
+                Some(Either::A(true)) => Some(ModularImportStyle::Source),
+                Some(Either::B(s)) if s == "css" => Some(ModularImportStyle::Css),
+                _ => None,
+            },
+            custom_name: options.custom_name,
+            ..ops
+        }
+    }
+}
+
+#[napi(object)]
+pub struct AssetImportOptions {
+    /// Pattern the import source must match, e.g. `*.module.css` or `*.svg?react`.
+    /// `*` matches any characters.
+    pub test: String,
+
+    /// How the matching imports are rewritten.
+    ///
+    /// - 'remove' - remove the import, binding default imports to `{}`
+    /// - 'url' - bind the default import to `new URL(source, import.meta.url).href`
+    /// - 'component' - import a component from the rewritten `source`
+    #[napi(ts_type = "'remove' | 'url' | 'component'")]
+    pub action: String,
+
+    /// Template for the rewritten import source of the `component` action.
+    /// `{{path}}` is replaced by the source without its query, `{{source}}` by the whole source.
+    ///
+    /// @default '{{path}}'
+    pub source: Option<String>,
+
+    /// Named export the default import is bound to by the `component` action, e.g. `ReactComponent`.
+    pub export_name: Option<String>,
+}
+
+impl From<AssetImportOptions> for oxc::transformer::AssetImportOptions {
+    fn from(options: AssetImportOptions) -> Self {
+        let action = match options.action.as_str() {
+            "url" => AssetImportAction::Url,
+            "component" => AssetImportAction::Component,
+            _ => AssetImportAction::Remove,
+        };
+        oxc::transformer::AssetImportOptions {
+            source: options.source,
+            export_name: options.export_name,
+            ..oxc::transformer::AssetImportOptions::new(options.test, action)
+        }
+    }
+}
+
+#[napi(object)]
+#[derive(Default)]
+pub struct EmotionOptions {
+    /// When to add a `label` to styles.
+    ///
+    /// @default 'dev-only'
+    #[napi(ts_type = "'dev-only' | 'always' | 'never'")]
+    pub auto_label: Option<String>,
+
+    /// Format of the label. Supports the `[local]`, `[filename]` and `[dirname]` placeholders.
+    ///
+    /// @default '[local]'
+    pub label_format: Option<String>,
+
+    /// Add an inline source map to styles in development.
+    ///
+    /// @default true
+    pub source_map: Option<bool>,
+}
+
+impl From<EmotionOptions> for oxc::transformer::EmotionOptions {
+    fn from(options: EmotionOptions) -> Self {
+        let ops = oxc::transformer::EmotionOptions::default();
+        oxc::transformer::EmotionOptions {
+            auto_label: match options.auto_label.as_deref() {
+                Some("always") => EmotionAutoLabel::Always,
+                Some("never") => EmotionAutoLabel::Never,
+                _ => EmotionAutoLabel::DevOnly,
+            },
+            label_format: options.label_format.unwrap_or(ops.label_format),
+            source_map: options.source_map.unwrap_or(ops.source_map),
+        }
+    }
+}
+
 /// Configure how TSX and JSX are transformed.
 ///
 /// @see {@link https://babeljs.io/docs/babel-plugin-transform-react-jsx#options}
@@ -757,10 +970,55 @@ pub fn transform(
     source_text: String,
     options: Option<TransformOptions>,
 ) -> TransformResult {
-    let source_path = Path::new(&filename);
+    transform_impl(&filename, &source_text, options)
+}
+
+pub struct TransformTask {
+    filename: String,
+    source_text: String,
+    options: Option<TransformOptions>,
+}
+
+#[napi]
+impl Task for TransformTask {
+    type JsValue = TransformResult;
+    type Output = TransformResult;
+
+    fn compute(&mut self) -> napi::Result<Self::Output> {
+        Ok(transform_impl(&self.filename, &self.source_text, self.options.take()))
+    }
+
+    fn resolve(&mut self, _: napi::Env, result: Self::Output) -> napi::Result<Self::JsValue> {
+        Ok(result)
+    }
+}
+
+/// Transpile a JavaScript or TypeScript into a target ECMAScript version, asynchronously.
+///
+/// Transforms on a thread of the libuv thread pool, so that build tools can transform several
+/// files in parallel without blocking the event loop.
+///
+/// Note: This function can be slower than `transform` due to the overhead of spawning a thread.
+///
+/// @see {@link transform}
+#[napi]
+pub fn transform_async(
+    filename: String,
+    source_text: String,
+    options: Option<TransformOptions>,
+) -> AsyncTask<TransformTask> {
+    AsyncTask::new(TransformTask { filename, source_text, options })
+}
+
+fn transform_impl(
+    filename: &str,
+    source_text: &str,
+    options: Option<TransformOptions>,
+) -> TransformResult {
+    let source_path = Path::new(filename);
 
     let source_type = get_source_type(
-        &filename,
+        filename,
         options.as_ref().and_then(|options| options.lang.as_deref()),
         options.as_ref().and_then(|options| options.source_type.as_deref()),
     );
@@ -769,13 +1027,13 @@ pub fn transform(
         Ok(compiler) => compiler,
         Err(errors) => {
             return TransformResult {
-                errors: OxcError::from_diagnostics(&filename, &source_text, errors),
+                errors: OxcError::from_diagnostics(filename, source_text, errors),
                 ..Default::default()
             };
         }
     };
 
-    compiler.compile(&source_text, source_type, source_path);
+    compiler.compile(source_text, source_type, source_path);
 
     TransformResult {
         code: compiler.printed,
@@ -783,7 +1041,7 @@ pub fn transform(
         declaration: compiler.declaration,
         declaration_map: compiler.declaration_map,
         helpers_used: compiler.helpers_used,
-        errors: OxcError::from_diagnostics(&filename, &source_text, compiler.errors),
+        errors: OxcError::from_diagnostics(filename, source_text, compiler.errors),
     }
 }
 
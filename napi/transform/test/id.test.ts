@@ -37,4 +37,15 @@ describe('isolated declaration', () => {
       errors: [],
     });
   });
+
+  it('matches output of isolatedDeclarationAsync', async () => {
+    const ret = await oxc.isolatedDeclarationAsync('test.ts', code, { sourcemap: true });
+    expect(ret).toStrictEqual(oxc.isolatedDeclaration('test.ts', code, { sourcemap: true }));
+  });
+
+  it('strips internal declarations', () => {
+    const code = 'export class A {\n  /** @internal */\n  foo = "bar";\n  baz = 1;\n}';
+    const ret = oxc.isolatedDeclaration('test.ts', code, { stripInternal: true });
+    expect(ret.code).toBe('export declare class A {\n\tbaz: number;\n}\n');
+  });
 });
@@ -1,7 +1,7 @@
 import { Worker } from 'node:worker_threads';
 import { describe, expect, it, test } from 'vitest';
 
-import { HelperMode, transform } from '../index';
+import { HelperMode, transform, transformAsync } from '../index';
 
 describe('simple', () => {
   const code = 'export class A<T> {}';
@@ -22,6 +22,11 @@ describe('simple', () => {
     });
   });
 
+  it('matches output of transformAsync', async () => {
+    const ret = await transformAsync('test.ts', code, { sourcemap: true });
+    expect(ret).toStrictEqual(transform('test.ts', code, { sourcemap: true }));
+  });
+
   it('uses the `lang` option', () => {
     const ret = transform('test.vue', code, { lang: 'ts' });
     expect(ret.code).toEqual('export class A {}\n');
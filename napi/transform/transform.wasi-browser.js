@@ -57,5 +57,7 @@ export default __napiModule.exports
 export const Severity = __napiModule.exports.Severity
 export const HelperMode = __napiModule.exports.HelperMode
 export const isolatedDeclaration = __napiModule.exports.isolatedDeclaration
+export const isolatedDeclarationAsync = __napiModule.exports.isolatedDeclarationAsync
 export const moduleRunnerTransform = __napiModule.exports.moduleRunnerTransform
 export const transform = __napiModule.exports.transform
+export const transformAsync = __napiModule.exports.transformAsync
@@ -88,5 +88,7 @@ module.exports = __napiModule.exports
 module.exports.Severity = __napiModule.exports.Severity
 module.exports.HelperMode = __napiModule.exports.HelperMode
 module.exports.isolatedDeclaration = __napiModule.exports.isolatedDeclaration
+module.exports.isolatedDeclarationAsync = __napiModule.exports.isolatedDeclarationAsync
 module.exports.moduleRunnerTransform = __napiModule.exports.moduleRunnerTransform
 module.exports.transform = __napiModule.exports.transform
+module.exports.transformAsync = __napiModule.exports.transformAsync
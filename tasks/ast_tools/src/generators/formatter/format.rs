@@ -49,6 +49,7 @@ impl Generator for FormatterFormatGenerator {
                 formatter::{
                     Buffer, Format, FormatResult, Formatter,
                     trivia::{FormatTrailingComments, format_leading_comments, format_trailing_comments},
+                    verbatim::write_unless_suppressed,
                 },
                 parentheses::NeedsParentheses,
                 generated::ast_nodes::{AstNode, SiblingNode},
@@ -95,6 +96,13 @@ fn implementation(type_def: &TypeDef, schema: &Schema) -> TokenStream {
         }
     };
 
+    // Nodes after a `prettier-ignore` comment are printed as they are in the source text.
+    let write = if leading_comments.is_empty() {
+        quote! { self.write(f) }
+    } else {
+        quote! { write_unless_suppressed(self, self.span, f) }
+    };
+
     let trailing_comments = if type_def.is_enum() {
         quote! {}
     } else if is_program {
@@ -141,7 +149,7 @@ fn implementation(type_def: &TypeDef, schema: &Schema) -> TokenStream {
         quote! {
             #leading_comments
             #needs_parentheses_before
-            let result = self.write(f);
+            let result = #write;
             #needs_parentheses_after
             #trailing_comments
             result
@@ -0,0 +1,2 @@
+type A<const T> = T;
+interface B<const T> {}
@@ -0,0 +1,32 @@
+function f<const T extends readonly unknown[]>(x: T): T {
+  return x;
+}
+class C<const T> {
+  m<const U>(u: U): U {
+    return u;
+  }
+}
+const g = <const T,>(x: T) => x;
+type F = new <const T>() => T;
+interface I {
+  m<const T>(): T;
+  <const U>(): U;
+}
+
+const a = { x: 1 } satisfies Record<string, number>;
+f([{} satisfies object]);
+const b = [1 satisfies number, (2 satisfies number) as number];
+const c = (a satisfies object) ? b satisfies number[] : null;
+const d = `${a.x satisfies number}`;
+const e = (() => 1) satisfies () => number;
+
+async function h(y: Iterable<Disposable>, z: AsyncIterable<AsyncDisposable>) {
+  for (using x of y) {}
+  for (await using x of y) {}
+  for await (await using x of z) {}
+}
+
+export { default as json } from "./data.json" with { type: "json" };
+export * as data from "./data.json" with { type: "json" };
+import type { K } from "./k" with { "resolution-mode": "import" };
+type M = typeof import("./m", { with: { "resolution-mode": "require" } });
@@ -6,17 +6,20 @@ use oxc::{
     CompilerInterface,
     allocator::Allocator,
     ast::{
-        AstKind, Comment,
-        ast::{Program, RegExpLiteral},
+        AstBuilder, AstKind, Comment,
+        ast::{
+            BindingProperty, Expression, ObjectProperty, Program, RegExpLiteral, TSLiteral,
+            TemplateLiteral,
+        },
     },
-    ast_visit::{Visit, walk},
-    codegen::{CodegenOptions, CodegenReturn},
+    ast_visit::{Visit, VisitMut, walk, walk_mut},
+    codegen::{Codegen, CodegenOptions, CodegenReturn},
     diagnostics::OxcDiagnostic,
     minifier::CompressOptions,
-    parser::{ParseOptions, ParserReturn},
+    parser::{ParseOptions, Parser, ParserReturn},
     regular_expression::{LiteralParser, Options},
     semantic::SemanticBuilderReturn,
-    span::{ContentEq, SourceType, Span},
+    span::{Atom, ContentEq, SourceType, Span},
     transformer::{TransformOptions, TransformerReturn},
 };
 use oxc_tasks_transform_checker::{check_semantic_after_transform, check_semantic_ids};
@@ -140,6 +143,11 @@ impl Driver {
     ) -> TestResult {
         self.run(source_text, source_type);
         let printed1 = self.printed.clone();
+        // The output must not depend on e.g. allocation addresses, so that it can be cached.
+        self.run(source_text, source_type);
+        if self.printed != printed1 {
+            return TestResult::Mismatch("Nondeterministic", printed1, self.printed.clone());
+        }
         self.run(&printed1, source_type);
         let printed2 = self.printed.clone();
         if printed1 == printed2 {
@@ -149,6 +157,39 @@ impl Driver {
         }
     }
 
+    /// Compare the ASTs of `source_text` and `other_source_text`, which must be the same program.
+    pub fn equivalence(
+        self,
+        case: &'static str,
+        source_text: &str,
+        other_source_text: &str,
+        source_type: SourceType,
+    ) -> TestResult {
+        let allocator = Allocator::default();
+        let parse = |source_text| {
+            let options = ParseOptions { preserve_parens: false, ..self.parse_options() };
+            let mut program = Parser::new(&allocator, source_text, source_type)
+                .with_options(options)
+                .parse()
+                .program;
+            NormalizeMinified { ast: AstBuilder::new(&allocator) }.visit_program(&mut program);
+            program
+        };
+        let expected = parse(source_text);
+        let actual = parse(other_source_text);
+        if expected.directives.content_eq(&actual.directives)
+            && expected.body.content_eq(&actual.body)
+        {
+            TestResult::Passed
+        } else {
+            TestResult::Mismatch(
+                case,
+                Codegen::new().build(&actual).code,
+                Codegen::new().build(&expected).code,
+            )
+        }
+    }
+
     pub fn run(&mut self, source_text: &str, source_type: SourceType) {
         let path = self.path.clone();
         self.compile(source_text, source_type, &path);
@@ -171,6 +212,52 @@ impl Driver {
     }
 }
 
+/// Remove the differences that printing without whitespace introduces: string literals printed as
+/// templates, and `{ a: a }` printed as `{ a }`.
+struct NormalizeMinified<'a> {
+    ast: AstBuilder<'a>,
+}
+
+impl<'a> VisitMut<'a> for NormalizeMinified<'a> {
+    fn visit_expression(&mut self, expr: &mut Expression<'a>) {
+        let string = match expr {
+            Expression::TemplateLiteral(lit) => string_value(lit).map(|value| (lit.span, value)),
+            _ => None,
+        };
+        if let Some((span, value)) = string {
+            *expr = self.ast.expression_string_literal(span, value, None);
+            return;
+        }
+        walk_mut::walk_expression(self, expr);
+    }
+
+    fn visit_object_property(&mut self, prop: &mut ObjectProperty<'a>) {
+        prop.shorthand = false;
+        walk_mut::walk_object_property(self, prop);
+    }
+
+    fn visit_binding_property(&mut self, prop: &mut BindingProperty<'a>) {
+        prop.shorthand = false;
+        walk_mut::walk_binding_property(self, prop);
+    }
+
+    fn visit_ts_literal(&mut self, lit: &mut TSLiteral<'a>) {
+        let string = match lit {
+            TSLiteral::TemplateLiteral(lit) => string_value(lit).map(|value| (lit.span, value)),
+            _ => None,
+        };
+        if let Some((span, value)) = string {
+            *lit = self.ast.ts_literal_string_literal(span, value, None);
+            return;
+        }
+        walk_mut::walk_ts_literal(self, lit);
+    }
+}
+
+fn string_value<'a>(lit: &TemplateLiteral<'a>) -> Option<Atom<'a>> {
+    if lit.is_no_substitution_template() { lit.quasi() } else { None }
+}
+
 struct CheckASTNodes<'a> {
     driver: &'a mut Driver,
     source_text: &'a str,
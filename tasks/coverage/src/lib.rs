@@ -10,11 +10,18 @@ mod test262;
 mod typescript;
 
 mod driver;
+mod results;
 mod tools;
 
-use std::{path::PathBuf, process::Command};
+use std::{
+    path::{Path, PathBuf},
+    process::Command,
+    sync::Mutex,
+    time::Duration,
+};
 
 use oxc_tasks_common::project_root;
+use rayon::ThreadPoolBuilder;
 use runtime::Test262RuntimeCase;
 use tools::estree::{AcornJsxSuite, EstreeJsxCase, EstreeTypescriptCase};
 
@@ -22,6 +29,7 @@ use crate::{
     babel::{BabelCase, BabelSuite},
     driver::Driver,
     misc::{MiscCase, MiscSuite},
+    results::CoverageResults,
     suite::Suite,
     test262::{Test262Case, Test262Suite},
     tools::{
@@ -54,14 +62,86 @@ fn snap_root() -> PathBuf {
 pub struct AppArgs {
     pub debug: bool,
     pub filter: Option<String>,
+    /// Run only the case at this path, e.g. `typescript/tests/cases/compiler/foo.ts`.
+    pub case: Option<PathBuf>,
     pub detail: bool,
     /// Print mismatch diff
-    pub diff: bool,
+    pub print_diff: bool,
+    /// Print the diagnostics and mismatch diffs of failing cases.
+    pub verbose: bool,
+    /// Write the results of the suites as JSON to this path.
+    pub json: Option<PathBuf>,
+    /// Compare the results with a JSON file written by `--json`, failing on regressions.
+    pub diff: Option<PathBuf>,
+    /// Results of the suites which have run, for `json` and `diff`.
+    pub results: Mutex<CoverageResults>,
+    /// Time taken by the suites which have run.
+    pub timings: Mutex<Vec<(String, Duration)>>,
 }
 
+/// Stack size of the threads running the cases.
+///
+/// Deeply nested cases overflow the default stack, especially in builds instrumented for code
+/// coverage, and a stack overflow aborts the whole run.
+const STACK_SIZE: usize = 256 * 1024 * 1024;
+
 impl AppArgs {
+    /// Build the global thread pool running the cases.
+    /// With `--debug`, a single thread runs the cases in order.
+    ///
+    /// # Panics
+    pub fn build_thread_pool(&self) {
+        let mut builder = ThreadPoolBuilder::new().stack_size(STACK_SIZE);
+        if self.debug {
+            builder = builder.num_threads(1);
+        }
+        builder.build_global().unwrap();
+    }
+
+    /// Only some cases run, with `--filter` or `--case`.
+    fn is_filtered(&self) -> bool {
+        self.filter.is_some() || self.case.is_some()
+    }
+
+    fn should_run_case(&self, path: &Path) -> bool {
+        self.filter.as_ref().is_none_or(|query| path.to_string_lossy().contains(query))
+            && self.case.as_ref().is_none_or(|case| path.ends_with(case))
+    }
+
     fn should_print_detail(&self) -> bool {
-        self.filter.is_some() || self.detail
+        self.is_filtered() || self.detail || self.verbose
+    }
+
+    fn should_print_diff(&self) -> bool {
+        self.print_diff || self.verbose
+    }
+
+    /// Write the results to the `json` path, and compare them with the `diff` baseline.
+    ///
+    /// Returns `false` if a case which passed in the baseline no longer passes.
+    ///
+    /// # Panics
+    pub fn report_results(&self) -> bool {
+        for (name, duration) in self.timings.lock().unwrap().iter() {
+            println!("{name:<24} {:>8.2}s", duration.as_secs_f64());
+        }
+        let results = self.results.lock().unwrap();
+        if let Some(path) = &self.json {
+            results.save(path).unwrap();
+        }
+        let Some(path) = &self.diff else { return true };
+        let baseline = CoverageResults::load(path).unwrap();
+        let regressions = results.regressions(&baseline);
+        for regression in &regressions {
+            println!(
+                "Regressed: {} tasks/coverage/{} ({:?})",
+                regression.suite, regression.path, regression.status
+            );
+        }
+        if regressions.is_empty() {
+            println!("No regressions compared to {}", path.display());
+        }
+        regressions.is_empty()
     }
 
     pub fn run_default(&self) {
@@ -141,5 +221,7 @@ impl AppArgs {
 #[test]
 #[cfg(any(coverage, coverage_nightly))]
 fn test() {
-    AppArgs::default().run_default()
+    let args = AppArgs::default();
+    args.build_thread_pool();
+    args.run_default();
 }
@@ -1,6 +1,5 @@
 use oxc_coverage::AppArgs;
 use pico_args::Arguments;
-use rayon::ThreadPoolBuilder;
 
 fn main() {
     let mut args = Arguments::from_env();
@@ -9,13 +8,16 @@ fn main() {
     let args = AppArgs {
         debug: args.contains("--debug"),
         filter: args.opt_value_from_str("--filter").unwrap(),
+        case: args.opt_value_from_str("--case").unwrap(),
         detail: args.contains("--detail"),
-        diff: args.contains("--diff"),
+        print_diff: args.contains("--print-diff"),
+        verbose: args.contains("--verbose"),
+        json: args.opt_value_from_str("--json").unwrap(),
+        diff: args.opt_value_from_str("--diff").unwrap(),
+        ..AppArgs::default()
     };
 
-    if args.debug {
-        ThreadPoolBuilder::new().num_threads(1).build_global().unwrap();
-    }
+    args.build_thread_pool();
 
     let task = command.as_deref().unwrap_or("default");
     match task {
@@ -34,4 +36,8 @@ fn main() {
         }
         _ => args.run_default(),
     }
+
+    if !args.report_results() {
+        std::process::exit(1);
+    }
 }
@@ -0,0 +1,103 @@
+//! Machine readable results of the suites, for `--json` and `--diff`.
+
+use std::{collections::BTreeMap, fs, path::Path};
+
+use oxc_tasks_common::normalize_path;
+use serde::{Deserialize, Serialize};
+
+use crate::suite::{Case, CoverageReport, TestResult};
+
+/// Results of the suites which have run, by suite name.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct CoverageResults {
+    suites: BTreeMap<String, SuiteResults>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SuiteResults {
+    parsed_positives: usize,
+    passed_positives: usize,
+    all_positives: usize,
+    passed_negatives: usize,
+    all_negatives: usize,
+    /// Status of each case, by path.
+    cases: BTreeMap<String, CaseStatus>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CaseStatus {
+    Passed,
+    ParseError,
+    IncorrectlyPassed,
+    Mismatch,
+    Error,
+    Panicked,
+}
+
+impl From<&TestResult> for CaseStatus {
+    fn from(result: &TestResult) -> Self {
+        match result {
+            TestResult::Passed | TestResult::CorrectError(..) => Self::Passed,
+            TestResult::ParseError(..) => Self::ParseError,
+            TestResult::IncorrectlyPassed => Self::IncorrectlyPassed,
+            TestResult::Mismatch(..) => Self::Mismatch,
+            TestResult::GenericError(..) => Self::Error,
+            TestResult::ToBeRun => Self::Panicked,
+        }
+    }
+}
+
+/// A case which passed in the baseline, and no longer passes.
+pub struct Regression<'a> {
+    pub suite: &'a str,
+    pub path: &'a str,
+    pub status: CaseStatus,
+}
+
+impl CoverageResults {
+    pub fn add<T: Case>(&mut self, name: &str, cases: &[T], report: &CoverageReport<T>) {
+        let cases = cases
+            .iter()
+            .map(|case| (normalize_path(case.path()), CaseStatus::from(case.test_result())))
+            .collect();
+        let results = SuiteResults {
+            parsed_positives: report.parsed_positives,
+            passed_positives: report.passed_positives,
+            all_positives: report.all_positives,
+            passed_negatives: report.passed_negatives,
+            all_negatives: report.all_negatives,
+            cases,
+        };
+        self.suites.insert(name.to_string(), results);
+    }
+
+    /// # Errors
+    pub fn save(&self, path: &Path) -> std::io::Result<()> {
+        fs::write(path, serde_json::to_string_pretty(self)?)
+    }
+
+    /// # Errors
+    pub fn load(path: &Path) -> std::io::Result<Self> {
+        Ok(serde_json::from_str(&fs::read_to_string(path)?)?)
+    }
+
+    /// Cases which passed in `baseline`, and did not pass in this run.
+    ///
+    /// Suites and cases which did not run, e.g. because of `--filter`, are not regressions.
+    pub fn regressions<'a>(&'a self, baseline: &Self) -> Vec<Regression<'a>> {
+        let mut regressions = vec![];
+        for (suite, results) in &self.suites {
+            let Some(baseline) = baseline.suites.get(suite) else { continue };
+            for (path, &status) in &results.cases {
+                if status != CaseStatus::Passed
+                    && baseline.cases.get(path) == Some(&CaseStatus::Passed)
+                {
+                    regressions.push(Regression { suite, path, status });
+                }
+            }
+        }
+        regressions
+    }
+}
@@ -5,7 +5,10 @@ use serde_json::json;
 use oxc::{
     allocator::Allocator,
     codegen::{Codegen, CodegenOptions},
-    minifier::{Minifier, MinifierOptions},
+    minifier::{
+        CompressOptions, CompressOptionsKeepNames, MangleOptions, MangleOptionsKeepNames, Minifier,
+        MinifierOptions,
+    },
     parser::Parser,
     semantic::SemanticBuilder,
     span::SourceType,
@@ -193,9 +196,19 @@ impl Test262RuntimeCase {
         }
 
         let symbol_table = if minify {
-            Minifier::new(MinifierOptions { mangle: None, ..MinifierOptions::default() })
-                .build(&allocator, &mut program)
-                .scoping
+            // Keep the names of functions and classes, which are checked by many tests.
+            let options = MinifierOptions {
+                mangle: Some(MangleOptions {
+                    keep_names: MangleOptionsKeepNames::all_true(),
+                    ..MangleOptions::default()
+                }),
+                compress: Some(CompressOptions {
+                    keep_names: CompressOptionsKeepNames::all_true(),
+                    ..CompressOptions::smallest()
+                }),
+                ..MinifierOptions::default()
+            };
+            Minifier::new(options).build(&allocator, &mut program).scoping
         } else {
             None
         };
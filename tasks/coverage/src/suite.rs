@@ -2,9 +2,10 @@ use std::{
     borrow::Cow,
     fs,
     io::{Read, Write, stdout},
-    panic::UnwindSafe,
+    panic::{AssertUnwindSafe, UnwindSafe, catch_unwind},
     path::{Path, PathBuf},
     process::{Command, Stdio},
+    time::Instant,
 };
 
 use console::Style;
@@ -24,6 +25,7 @@ use crate::{AppArgs, Driver, snap_root, workspace_root};
 
 #[derive(Debug, PartialEq, Eq)]
 pub enum TestResult {
+    /// Not run yet, or the case panicked.
     ToBeRun,
     Passed,
     IncorrectlyPassed,
@@ -36,35 +38,39 @@ pub enum TestResult {
 pub struct CoverageReport<'a, T> {
     failed_positives: Vec<&'a T>,
     failed_negatives: Vec<&'a T>,
-    parsed_positives: usize,
-    passed_positives: usize,
-    passed_negatives: usize,
-    all_positives: usize,
-    all_negatives: usize,
+    pub parsed_positives: usize,
+    pub passed_positives: usize,
+    pub passed_negatives: usize,
+    pub all_positives: usize,
+    pub all_negatives: usize,
 }
 
 /// A Test Suite is responsible for reading code from a repository
 pub trait Suite<T: Case> {
     fn run(&mut self, name: &str, args: &AppArgs) {
+        let start = Instant::now();
         self.read_test_cases(name, args);
 
-        if args.debug {
-            self.get_test_cases_mut().iter_mut().for_each(|case| {
+        // With `--debug`, the pool has a single thread, which runs the cases in order.
+        self.get_test_cases_mut().par_iter_mut().for_each(|case| {
+            if args.debug {
                 println!("{}", case.path().to_string_lossy());
-                case.run();
-            });
-        } else {
-            self.get_test_cases_mut().par_iter_mut().for_each(Case::run);
-        }
+            }
+            // A panicking case is reported as a failure, instead of ending the run.
+            let _ = catch_unwind(AssertUnwindSafe(|| case.run()));
+        });
 
+        args.timings.lock().unwrap().push((name.to_string(), start.elapsed()));
         self.run_coverage(name, args);
     }
 
     fn run_async(&mut self, args: &AppArgs) {
         use futures::{StreamExt, stream};
+        let start = Instant::now();
         self.read_test_cases("runtime", args);
         let cases = self.get_test_cases_mut().iter_mut().map(T::run_async);
         Runtime::new().unwrap().block_on(stream::iter(cases).buffer_unordered(100).count());
+        args.timings.lock().unwrap().push(("runtime".to_string(), start.elapsed()));
         self.run_coverage("runtime", args);
         let _ = oxc_tasks_common::agent().delete("http://localhost:32055").call();
     }
@@ -73,9 +79,12 @@ pub trait Suite<T: Case> {
         let report = self.coverage_report();
         let mut out = stdout();
         self.print_coverage(name, args, &report, &mut out).unwrap();
-        if args.filter.is_none() {
+        if !args.is_filtered() {
             self.snapshot_errors(name, &report).unwrap();
         }
+        if args.json.is_some() || args.diff.is_some() {
+            args.results.lock().unwrap().add(name, self.get_test_cases(), &report);
+        }
     }
 
     fn get_test_root(&self) -> &Path;
@@ -92,7 +101,6 @@ pub trait Suite<T: Case> {
         let cases_path = test_path.join(self.get_test_root());
 
         let get_paths = || {
-            let filter = args.filter.as_ref();
             WalkDir::new(&cases_path)
                 .into_iter()
                 .filter_map(Result::ok)
@@ -100,14 +108,14 @@ pub trait Suite<T: Case> {
                 .filter(|e| e.file_name() != ".DS_Store")
                 .map(|e| e.path().to_owned())
                 .filter(|path| !self.skip_test_path(path))
-                .filter(|path| filter.is_none_or(|query| path.to_string_lossy().contains(query)))
+                .filter(|path| args.should_run_case(path))
                 .collect::<Vec<_>>()
         };
 
         let mut paths = get_paths();
 
         // Initialize git submodule if it is empty and no filter is provided
-        if paths.is_empty() && args.filter.is_none() {
+        if paths.is_empty() && !args.is_filtered() {
             println!("-------------------------------------------------------");
             println!("git submodule is empty for {name}");
             println!("Running `just submodules` to clone the submodules");
@@ -149,7 +157,7 @@ pub trait Suite<T: Case> {
             .collect::<Vec<_>>();
 
         self.save_test_cases(cases);
-        if args.filter.is_none() {
+        if !args.is_filtered() {
             self.save_extra_test_cases();
         }
     }
@@ -298,18 +306,16 @@ pub trait Case: Sized + Sync + Send + UnwindSafe {
     }
 
     fn test_passed(&self) -> bool {
-        let result = self.test_result();
-        assert!(!matches!(result, TestResult::ToBeRun), "test should be run");
-        matches!(result, TestResult::Passed | TestResult::CorrectError(_, _))
+        matches!(self.test_result(), TestResult::Passed | TestResult::CorrectError(_, _))
     }
 
     fn test_parsed(&self) -> bool {
-        let result = self.test_result();
-        assert!(!matches!(result, TestResult::ToBeRun), "test should be run");
-        match result {
+        match self.test_result() {
             TestResult::ParseError(_, panicked) | TestResult::CorrectError(_, panicked) => {
                 !panicked
             }
+            // The case panicked.
+            TestResult::ToBeRun => false,
             _ => true,
         }
     }
@@ -384,7 +390,7 @@ pub trait Case: Sized + Sync + Send + UnwindSafe {
             }
             TestResult::Mismatch(case, ast_string, expected_ast_string) => {
                 writer.write_all(format!("{case}: {path}\n",).as_bytes())?;
-                if args.diff {
+                if args.should_print_diff() {
                     self.print_diff(writer, ast_string.as_str(), expected_ast_string.as_str())?;
                     println!("{case}: {path}");
                 }
@@ -396,7 +402,10 @@ pub trait Case: Sized + Sync + Send + UnwindSafe {
             TestResult::IncorrectlyPassed => {
                 writer.write_all(format!("Expect Syntax Error: {path}\n").as_bytes())?;
             }
-            TestResult::Passed | TestResult::ToBeRun | TestResult::CorrectError(..) => {}
+            TestResult::ToBeRun => {
+                writer.write_all(format!("Panicked: {path}\n").as_bytes())?;
+            }
+            TestResult::Passed | TestResult::CorrectError(..) => {}
         }
         writer.write_all(b"\n")?;
         Ok(())
@@ -11,7 +11,7 @@ use crate::{
     typescript::TypeScriptCase,
 };
 
-/// Idempotency test
+/// Idempotency and round-trip test
 fn get_result(source_text: &str, source_type: SourceType) -> TestResult {
     let result = Driver { codegen: true, ..Driver::default() }.idempotency(
         "Normal",
@@ -28,9 +28,33 @@ fn get_result(source_text: &str, source_type: SourceType) -> TestResult {
         return result;
     }
 
+    // Printing must not change the program, e.g. by missing parentheses or semicolons.
+    let result = get_equivalence_result("Normal semantics", source_text, source_type, false);
+    if result != TestResult::Passed {
+        return result;
+    }
+
+    // Removing whitespace must not change the program.
+    let result = get_equivalence_result("Minify semantics", source_text, source_type, true);
+    if result != TestResult::Passed {
+        return result;
+    }
+
     TestResult::Passed
 }
 
+/// Print `source_text`, and compare the AST of the printed code with the AST of `source_text`.
+fn get_equivalence_result(
+    case: &'static str,
+    source_text: &str,
+    source_type: SourceType,
+    remove_whitespace: bool,
+) -> TestResult {
+    let mut driver = Driver { codegen: true, remove_whitespace, ..Driver::default() };
+    driver.run(source_text, source_type);
+    Driver::default().equivalence(case, source_text, &driver.printed, source_type)
+}
+
 pub struct CodegenTest262Case {
     base: Test262Case,
 }
@@ -35,6 +35,8 @@ pub struct CompilerSettings {
     pub allow_unused_labels: bool,
     pub no_fallthrough_cases_in_switch: bool,
     pub experimental_decorators: Vec<bool>,
+    /// `None` when not set, the default depends on the target.
+    pub use_define_for_class_fields: Option<bool>,
 }
 
 impl CompilerSettings {
@@ -64,9 +66,38 @@ impl CompilerSettings {
                 .filter(|&v| v == "*")
                 .map(|_| vec![true, false])
                 .unwrap_or_default(),
+            use_define_for_class_fields: match options
+                .get("usedefineforclassfields")
+                .map(String::as_str)
+            {
+                Some("true") => Some(true),
+                Some("false") => Some(false),
+                _ => None,
+            },
         }
     }
 
+    /// The target closest to the latest ECMAScript version, which is the version oxc parses.
+    ///
+    /// Error baselines of other targets report syntax which is not available in these targets,
+    /// e.g. the RegExp `u` flag in ES5, or miss errors added in later versions, e.g. a `"use strict"`
+    /// directive in a function with non-simple parameters is only an error since ES2016.
+    pub fn latest_target(&self) -> Option<&str> {
+        if self.targets.iter().any(|target| target == "*") {
+            return Some("esnext");
+        }
+        self.targets
+            .iter()
+            .max_by_key(|target| match target.as_str() {
+                "es3" => 3,
+                "es5" => 5,
+                "es6" => 2015,
+                "esnext" => u32::MAX,
+                target => target.strip_prefix("es").and_then(|year| year.parse().ok()).unwrap_or(0),
+            })
+            .map(String::as_str)
+    }
+
     fn split_value_options(value: Option<&String>) -> Vec<String> {
         value
             .map(|value| value.split(',').map(|s| s.trim().to_lowercase()).collect())
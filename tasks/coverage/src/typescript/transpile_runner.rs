@@ -1,6 +1,9 @@
 //! <https://github.com/microsoft/TypeScript/blob/v5.6.3/src/testRunner/transpileRunner.ts>
 
-use std::path::{Path, PathBuf};
+use std::{
+    path::{Path, PathBuf},
+    str::FromStr,
+};
 
 use oxc::{
     allocator::Allocator,
@@ -8,7 +11,9 @@ use oxc::{
     diagnostics::OxcDiagnostic,
     isolated_declarations::{IsolatedDeclarations, IsolatedDeclarationsOptions},
     parser::Parser,
+    semantic::SemanticBuilder,
     span::SourceType,
+    transformer::{ESTarget, TransformOptions, Transformer},
 };
 
 use super::{
@@ -56,12 +61,21 @@ impl<T: Case> Suite<T> for TranspileRunner<T> {
     }
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum TranspileKind {
-    // Module,
+    Module,
     Declaration,
 }
 
+impl TranspileKind {
+    fn extension(self) -> &'static str {
+        match self {
+            Self::Module => "js",
+            Self::Declaration => "d.ts",
+        }
+    }
+}
+
 pub struct TypeScriptTranspileCase {
     base: TypeScriptCase,
 }
@@ -84,13 +98,16 @@ impl Case for TypeScriptTranspileCase {
     }
 
     fn skip_test_case(&self) -> bool {
-        !self.base.settings.declaration
+        !self.base.settings.declaration && self.transform_options().is_none()
     }
 
     fn run(&mut self) {
-        // if !self.settings.emit_declaration_only {
-        // self.run_kind(TranspileKind::Module);
-        // }
+        if self.transform_options().is_some() {
+            self.base.result = self.compare(TranspileKind::Module);
+            if self.base.result != TestResult::Passed {
+                return;
+            }
+        }
         if self.base.settings.declaration {
             self.base.result = self.compare(TranspileKind::Declaration);
         }
@@ -98,13 +115,42 @@ impl Case for TypeScriptTranspileCase {
 }
 
 impl TypeScriptTranspileCase {
+    /// Options for transforming to the JavaScript output of the latest target of the test case.
+    ///
+    /// `None` for targets the transformer can't lower to, where the output of `tsc` can't match,
+    /// and for declaration only test cases.
+    /// Test cases without a target use the default target of `tsc`, ES5.
+    fn transform_options(&self) -> Option<TransformOptions> {
+        let settings = &self.base.settings;
+        if settings.emit_declaration_only {
+            return None;
+        }
+        let target = ESTarget::from_str(settings.latest_target()?).ok()?;
+        if target < ESTarget::ES2015 {
+            return None;
+        }
+        let mut options = TransformOptions::from(target);
+        // `useDefineForClassFields` defaults to `true` for ES2022 and later.
+        let use_define_for_class_fields =
+            settings.use_define_for_class_fields.unwrap_or(target >= ESTarget::ES2022);
+        options.assumptions.set_public_class_fields = !use_define_for_class_fields;
+        options.typescript.remove_class_fields_without_initializer = !use_define_for_class_fields;
+        Some(options)
+    }
+
     fn compare(&self, kind: TranspileKind) -> TestResult {
-        // get expected text by reading its .d.ts file
+        // get expected text by reading its .js or .d.ts file
         let path = self.path().strip_prefix("typescript/tests/cases/transpile").unwrap();
-        let filename = change_extension(path.to_str().unwrap());
+        let filename = change_extension(path.to_str().unwrap(), kind.extension());
         let path =
             workspace_root().join(TESTS_ROOT).join("baselines/reference/transpile").join(filename);
-        let expected = BaselineFile::parse(&path);
+        let mut expected = BaselineFile::parse(&path);
+        if kind == TranspileKind::Module {
+            // `tsc` keeps comments, and formats differently, so compare the reprinted JavaScript.
+            for file in &mut expected.files {
+                file.oxc_printed = print_without_comments(&file.name, &file.original);
+            }
+        }
 
         let baseline = self.run_kind(kind);
 
@@ -141,7 +187,7 @@ impl TypeScriptTranspileCase {
         TestResult::Passed
     }
 
-    fn run_kind(&self, _kind: TranspileKind) -> BaselineFile {
+    fn run_kind(&self, kind: TranspileKind) -> BaselineFile {
         let mut files = vec![];
 
         for unit in &self.base.units {
@@ -155,9 +201,17 @@ impl TypeScriptTranspileCase {
         }
 
         for unit in &self.base.units {
-            let (source_text, errors) = transpile(self.path(), &unit.content);
+            let (source_text, errors) = match kind {
+                TranspileKind::Module => {
+                    let options = self.transform_options().unwrap();
+                    let (source_text, errors) = transform(self.path(), &unit.content, &options);
+                    let name = change_extension(&unit.name, kind.extension());
+                    (print_without_comments(&name, &source_text), errors)
+                }
+                TranspileKind::Declaration => transpile(self.path(), &unit.content),
+            };
             let baseline = Baseline {
-                name: change_extension(&unit.name),
+                name: change_extension(&unit.name, kind.extension()),
                 original: unit.content.clone(),
                 original_diagnostic: Vec::default(),
                 oxc_printed: source_text,
@@ -170,8 +224,35 @@ impl TypeScriptTranspileCase {
     }
 }
 
-fn change_extension(name: &str) -> String {
-    Path::new(name).with_extension("").with_extension("d.ts").to_str().unwrap().to_string()
+fn change_extension(name: &str, extension: &str) -> String {
+    Path::new(name).with_extension("").with_extension(extension).to_str().unwrap().to_string()
+}
+
+fn transform(
+    path: &Path,
+    source_text: &str,
+    options: &TransformOptions,
+) -> (String, Vec<OxcDiagnostic>) {
+    let allocator = Allocator::default();
+    let source_type = SourceType::from_path(path).unwrap();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    let mut program = ret.program;
+    let mut errors = ret.errors;
+    let scoping = SemanticBuilder::new().build(&program).semantic.into_scoping();
+    let ret = Transformer::new(&allocator, path, options).build_with_scoping(scoping, &mut program);
+    errors.extend(ret.errors);
+    let printed = Codegen::new().build(&program).code;
+    (printed, errors)
+}
+
+fn print_without_comments(name: &str, source_text: &str) -> String {
+    let allocator = Allocator::default();
+    let source_type = SourceType::from_path(Path::new(name)).unwrap_or_default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    Codegen::new()
+        .with_options(CodegenOptions { comments: false, ..CodegenOptions::default() })
+        .build(&ret.program)
+        .code
 }
 
 fn transpile(path: &Path, source_text: &str) -> (String, Vec<OxcDiagnostic>) {
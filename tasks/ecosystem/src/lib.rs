@@ -0,0 +1,210 @@
+#![expect(clippy::print_stdout)]
+
+use std::{
+    collections::BTreeMap,
+    fmt::Write as _,
+    fs, io,
+    panic::{AssertUnwindSafe, catch_unwind},
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use walkdir::WalkDir;
+
+use oxc_allocator::Allocator;
+use oxc_codegen::Codegen;
+use oxc_parser::Parser;
+use oxc_semantic::SemanticBuilder;
+use oxc_span::{SourceType, VALID_EXTENSIONS};
+use oxc_tasks_common::{normalize_path, project_root};
+use oxc_transformer::{TransformOptions, Transformer};
+
+/// A repository checked out at a pinned tag.
+struct Repo {
+    name: &'static str,
+    url: &'static str,
+    tag: &'static str,
+    /// Directory of the repository which is linted and transformed.
+    dir: &'static str,
+}
+
+const REPOS: &[Repo] = &[
+    Repo { name: "lodash", url: "https://github.com/lodash/lodash.git", tag: "4.17.21", dir: "." },
+    Repo {
+        name: "react",
+        url: "https://github.com/facebook/react.git",
+        tag: "v19.0.0",
+        dir: "packages",
+    },
+    Repo {
+        name: "typescript",
+        url: "https://github.com/microsoft/TypeScript.git",
+        tag: "v5.3.3",
+        dir: "src",
+    },
+    Repo {
+        name: "vite",
+        url: "https://github.com/vitejs/vite.git",
+        tag: "v6.0.0",
+        dir: "packages",
+    },
+    Repo { name: "vue", url: "https://github.com/vuejs/core.git", tag: "v3.5.13", dir: "packages" },
+];
+
+/// Clones the repositories of [REPOS], lints them with `oxlint` and transforms their files.
+///
+/// Repositories can be selected by passing their names as arguments.
+/// When all of them are checked, the results are saved to `tasks/ecosystem/ecosystem.snap`,
+/// so that changes of the diagnostics and new transform errors or panics show up in its diff.
+///
+/// `oxlint` is looked up in `target/release` before `PATH`, build it with `cargo oxlint`.
+///
+/// # Errors
+///
+/// * The snapshot can not be written
+pub fn run() -> Result<(), io::Error> {
+    let names = std::env::args().skip(1).collect::<Vec<_>>();
+    let repos =
+        REPOS.iter().filter(|repo| names.is_empty() || names.iter().any(|name| name == repo.name));
+
+    let mut out = String::new();
+    for repo in repos {
+        writeln!(out, "# {} {} ({})\n", repo.name, repo.tag, repo.dir).unwrap();
+        let Some(path) = checkout(repo) else {
+            out.push_str("Clone failed\n\n");
+            continue;
+        };
+        let root = path.join(repo.dir);
+        out.push_str(&transform(&root));
+        out.push('\n');
+        out.push_str(&lint(&root));
+        out.push('\n');
+    }
+
+    println!("{out}");
+
+    if names.is_empty() {
+        fs::write(project_root().join("tasks/ecosystem/ecosystem.snap"), out)?;
+    }
+    Ok(())
+}
+
+/// Clones `repo` to `target/ecosystem`, unless it is already cloned.
+fn checkout(repo: &Repo) -> Option<PathBuf> {
+    let path = project_root().join("target/ecosystem").join(format!("{}-{}", repo.name, repo.tag));
+    if path.exists() {
+        return Some(path);
+    }
+    println!("[{}] - Cloning [{}] to [{}]", repo.name, repo.url, path.display());
+    let status = Command::new("git")
+        .args(["clone", "--quiet", "--depth", "1", "--branch", repo.tag, repo.url])
+        .arg(&path)
+        .status()
+        .ok()?;
+    status.success().then_some(path)
+}
+
+/// Transforms and prints every file in `root`, and reports the files which fail.
+fn transform(root: &Path) -> String {
+    let mut files = 0;
+    let mut parse_errors = 0;
+    let mut failures = vec![];
+    for path in source_files(root) {
+        let Ok(source_text) = fs::read_to_string(&path) else { continue };
+        files += 1;
+        let source_type = SourceType::from_path(&path).unwrap();
+        let relative = normalize_path(path.strip_prefix(root).unwrap());
+        match catch_unwind(AssertUnwindSafe(|| transform_file(&path, &source_text, source_type))) {
+            Ok(TransformResult::Passed) => {}
+            Ok(TransformResult::ParseError) => parse_errors += 1,
+            Ok(TransformResult::Error(message)) => {
+                failures.push(format!("* {relative}: {message}"));
+            }
+            Err(_) => failures.push(format!("* {relative}: panicked")),
+        }
+    }
+
+    let mut out = format!(
+        "Transform: {files} files, {parse_errors} with parse errors, {} failed\n",
+        failures.len()
+    );
+    for failure in failures {
+        out.push_str(&failure);
+        out.push('\n');
+    }
+    out
+}
+
+enum TransformResult {
+    Passed,
+    /// The file can not be parsed, it is not transformed.
+    ParseError,
+    /// The first error reported by the transformer.
+    Error(String),
+}
+
+fn transform_file(path: &Path, source_text: &str, source_type: SourceType) -> TransformResult {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    if !ret.errors.is_empty() {
+        return TransformResult::ParseError;
+    }
+    let mut program = ret.program;
+    let scoping = SemanticBuilder::new().build(&program).semantic.into_scoping();
+    let ret = Transformer::new(&allocator, path, &TransformOptions::enable_all())
+        .build_with_scoping(scoping, &mut program);
+    if let Some(error) = ret.errors.first() {
+        return TransformResult::Error(error.message.to_string());
+    }
+    let _code = Codegen::new().build(&program).code;
+    TransformResult::Passed
+}
+
+/// JavaScript and TypeScript files in `root`, skipping `node_modules` and hidden directories.
+fn source_files(root: &Path) -> Vec<PathBuf> {
+    let mut files = WalkDir::new(root)
+        .into_iter()
+        .filter_entry(|entry| {
+            let name = entry.file_name().to_string_lossy();
+            entry.depth() == 0 || !(name.starts_with('.') || name == "node_modules")
+        })
+        .filter_map(Result::ok)
+        .filter(|entry| !entry.file_type().is_dir())
+        .map(walkdir::DirEntry::into_path)
+        .filter(|path| {
+            path.extension().is_some_and(|ext| VALID_EXTENSIONS.contains(&&*ext.to_string_lossy()))
+        })
+        .collect::<Vec<_>>();
+    files.sort_unstable();
+    files
+}
+
+/// Lints `root` with `oxlint` and reports the number of diagnostics of each rule.
+fn lint(root: &Path) -> String {
+    let local = project_root().join("target/release/oxlint");
+    let program = if local.exists() { local } else { PathBuf::from("oxlint") };
+    let output = match Command::new(program).arg("--format=unix").arg(root).output() {
+        Ok(output) => output,
+        Err(error) => return format!("Lint: oxlint can not be run: {error}\n"),
+    };
+    // `oxlint` exits with 1 when errors are reported, any other failure is a crash.
+    if !output.status.success() && output.status.code() != Some(1) {
+        return format!("Lint: oxlint crashed: {}\n", output.status);
+    }
+
+    // Lines are formatted as `path:line:column: message [Severity/rule]`.
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let mut rules = BTreeMap::<&str, usize>::new();
+    for line in stdout.lines() {
+        let Some(rest) = line.strip_suffix(']') else { continue };
+        let Some((_, label)) = rest.rsplit_once(" [") else { continue };
+        let rule = label.split_once('/').map_or("-", |(_, rule)| rule);
+        *rules.entry(rule).or_default() += 1;
+    }
+
+    let mut out = format!("Lint: {} diagnostics\n", rules.values().sum::<usize>());
+    for (rule, count) in rules {
+        writeln!(out, "* {rule}: {count}").unwrap();
+    }
+    out
+}
@@ -0,0 +1,7 @@
+use std::io;
+
+use oxc_ecosystem::run;
+
+fn main() -> Result<(), io::Error> {
+    run()
+}
@@ -0,0 +1,185 @@
+#![expect(clippy::print_stdout)]
+
+use std::{
+    fmt::Write as _,
+    fs::{self, File},
+    io::{self, Write},
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use flate2::{Compression, write::GzEncoder};
+use humansize::{DECIMAL, format_size};
+use oxc_allocator::Allocator;
+use oxc_codegen::{Codegen, CodegenOptions};
+use oxc_minifier::{Minifier, MinifierOptions};
+use oxc_parser::Parser;
+use oxc_span::SourceType;
+use oxc_tasks_common::{TestFile, TestFiles, project_root};
+
+/// Minifies the files of [TestFiles::minifier], or the files passed as arguments,
+/// and checks that the minified code behaves the same as the original code.
+///
+/// The results of [TestFiles::minifier] are saved to `tasks/minifier_diff/minifier_diff.snap`.
+///
+/// # Panics
+///
+/// * A file of [TestFiles::minifier] can not be downloaded
+/// * A file passed as argument does not have a JavaScript or TypeScript extension
+///
+/// # Errors
+///
+/// * A file passed as argument can not be read
+/// * The original and minified code or the snapshot can not be written
+pub fn run() -> Result<(), io::Error> {
+    let paths = std::env::args().skip(1).collect::<Vec<_>>();
+    let test_files;
+    let local_files;
+    let files: &[TestFile] = if paths.is_empty() {
+        test_files = TestFiles::minifier();
+        test_files.files()
+    } else {
+        local_files =
+            paths.iter().map(|path| read_file(Path::new(path))).collect::<Result<Vec<_>, _>>()?;
+        &local_files
+    };
+
+    let save_path = project_root().join("target/minifier_diff");
+    fs::create_dir_all(save_path.join("original"))?;
+    fs::create_dir_all(save_path.join("minified"))?;
+
+    let width = 10;
+    let mut out = String::new();
+    writeln!(
+        out,
+        "{:width$} | {:width$} | {:width$} | {:width$} | {:width$} | {:width$} | Fixture",
+        "Original", "Oxc", "ESBuild", "Terser", "Oxc gzip", "Behavior",
+    )
+    .unwrap();
+    let fixture_width = files.iter().map(|file| file.file_name.len()).max().unwrap_or_default();
+    out.push_str(&str::repeat("-", width * 6 + fixture_width + 18));
+    out.push('\n');
+
+    let mut failures = 0;
+    for file in files {
+        let original_path = save_path.join("original").join(&file.file_name);
+        let minified_path = save_path.join("minified").join(&file.file_name);
+        let minified = minify(file);
+        fs::write(&original_path, &file.source_text)?;
+        fs::write(&minified_path, &minified)?;
+
+        let behavior = compare(&original_path, &minified_path);
+        if behavior != Behavior::Same {
+            failures += 1;
+        }
+
+        writeln!(
+            out,
+            "{:width$} | {:width$} | {:width$} | {:width$} | {:width$} | {:width$} | {}",
+            format_size(file.source_text.len(), DECIMAL),
+            format_size(minified.len(), DECIMAL),
+            baseline_size("esbuild", &["--minify"], &original_path),
+            baseline_size("terser", &["--compress", "--mangle"], &original_path),
+            format_size(gzip_size(&minified), DECIMAL),
+            behavior.as_str(),
+            &file.file_name,
+        )
+        .unwrap();
+    }
+
+    println!("{out}");
+    if failures > 0 {
+        println!(
+            "{failures} file(s) behave differently after minification, see {}",
+            save_path.display()
+        );
+    }
+
+    if paths.is_empty() {
+        let mut snapshot =
+            File::create(project_root().join("tasks/minifier_diff/minifier_diff.snap"))?;
+        snapshot.write_all(out.as_bytes())?;
+        snapshot.flush()?;
+    }
+    Ok(())
+}
+
+fn read_file(path: &Path) -> Result<TestFile, io::Error> {
+    let file_name = path.file_name().unwrap().to_string_lossy().to_string();
+    let source_text = fs::read_to_string(path)?;
+    let source_type = SourceType::from_path(path).unwrap();
+    Ok(TestFile { url: path.to_string_lossy().to_string(), file_name, source_text, source_type })
+}
+
+fn minify(file: &TestFile) -> String {
+    let allocator = Allocator::default();
+    let ret = Parser::new(&allocator, &file.source_text, file.source_type).parse();
+    let mut program = ret.program;
+    let ret = Minifier::new(MinifierOptions::default()).build(&allocator, &mut program);
+    Codegen::new()
+        .with_options(CodegenOptions::minify())
+        .with_scoping(ret.scoping)
+        .build(&program)
+        .code
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Behavior {
+    /// Both versions export the same values, or throw the same error.
+    Same,
+    /// The minified code exports different values, or throws a different error.
+    Different,
+    /// Node could not be run.
+    Error,
+}
+
+impl Behavior {
+    fn as_str(&self) -> &'static str {
+        match self {
+            Self::Same => "same",
+            Self::Different => "different",
+            Self::Error => "error",
+        }
+    }
+}
+
+/// Runs both files with `runner.mjs` and compares what they export.
+///
+/// On difference, the outputs of the runner are saved next to the minified file.
+fn compare(original_path: &Path, minified_path: &Path) -> Behavior {
+    let (Some(original), Some(minified)) = (run_node(original_path), run_node(minified_path))
+    else {
+        return Behavior::Error;
+    };
+    if original == minified {
+        return Behavior::Same;
+    }
+    let _ = fs::write(original_path.with_extension("txt"), original);
+    let _ = fs::write(minified_path.with_extension("txt"), minified);
+    Behavior::Different
+}
+
+fn run_node(path: &Path) -> Option<String> {
+    let runner = project_root().join("tasks/minifier_diff/src/runner.mjs");
+    let output = Command::new("node").arg(runner).arg(path).output().ok()?;
+    output.status.success().then(|| String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Size of the code minified by `bin`, or `-` if `bin` is not installed.
+///
+/// `bin` is looked up in `node_modules/.bin` before `PATH`.
+fn baseline_size(bin: &str, args: &[&str], path: &Path) -> String {
+    let local: PathBuf = project_root().join("node_modules/.bin").join(bin);
+    let program = if local.exists() { local } else { PathBuf::from(bin) };
+    match Command::new(program).arg(path).args(args).output() {
+        Ok(output) if output.status.success() => format_size(output.stdout.len(), DECIMAL),
+        _ => "-".to_string(),
+    }
+}
+
+fn gzip_size(s: &str) -> usize {
+    let mut e = GzEncoder::new(Vec::new(), Compression::best());
+    e.write_all(s.as_bytes()).unwrap();
+    let s = e.finish().unwrap();
+    s.len()
+}
@@ -0,0 +1,108 @@
+// Runs a CommonJS or UMD script and prints a fingerprint of what it exports.
+//
+// The fingerprints of the original and the minified code are compared by `lib.rs`.
+// Function names and source text are not part of the fingerprint, since minification changes them.
+//
+// Usage: node runner.mjs <file>
+
+import fs from 'node:fs';
+import process from 'node:process';
+import vm from 'node:vm';
+
+const MAX_DEPTH = 4;
+const MAX_LENGTH = 100;
+
+const file = process.argv[2];
+const code = fs.readFileSync(file, 'utf8');
+
+const noop = () => {};
+const module = { exports: {} };
+const sandbox = {
+  module,
+  exports: module.exports,
+  require(name) {
+    throw new Error(`Cannot find module '${name}'`);
+  },
+  console: { log: noop, info: noop, warn: noop, error: noop, debug: noop, trace: noop },
+  process: { env: { NODE_ENV: 'development' } },
+  setTimeout: noop,
+  clearTimeout: noop,
+  setInterval: noop,
+  clearInterval: noop,
+  queueMicrotask: noop,
+};
+const context = vm.createContext(sandbox);
+sandbox.window = sandbox.self = sandbox.globalThis = context;
+const builtins = new Set(Object.keys(sandbox));
+
+const result = {};
+try {
+  new vm.Script(code, { filename: file }).runInContext(context, { timeout: 10_000 });
+} catch (error) {
+  result.error = errorName(error);
+}
+result.exports = fingerprint(module.exports, 0, new Set());
+result.globals = {};
+for (const key of Object.keys(sandbox).sort()) {
+  if (!builtins.has(key)) {
+    result.globals[key] = fingerprint(sandbox[key], 0, new Set());
+  }
+}
+
+process.stdout.write(JSON.stringify(result, null, 2));
+
+function errorName(error) {
+  return error?.constructor?.name ?? typeof error;
+}
+
+function fingerprint(value, depth, seen) {
+  switch (typeof value) {
+    case 'undefined':
+      return 'undefined';
+    case 'string':
+      return `string ${JSON.stringify(value.slice(0, MAX_LENGTH))}`;
+    case 'number':
+      return `number ${Object.is(value, -0) ? '-0' : String(value)}`;
+    case 'bigint':
+    case 'boolean':
+      return `${typeof value} ${String(value)}`;
+    case 'symbol':
+      return `symbol ${String(value.description)}`;
+  }
+  if (value === null) {
+    return 'null';
+  }
+  if (seen.has(value)) {
+    return 'circular';
+  }
+  if (depth >= MAX_DEPTH) {
+    return typeof value;
+  }
+  seen.add(value);
+  const result = typeof value === 'function'
+    ? {
+      type: `function ${value.length}`,
+      properties: properties(value, depth, seen),
+      prototype: value.prototype && typeof value.prototype === 'object'
+        ? Object.getOwnPropertyNames(value.prototype).sort()
+        : undefined,
+    }
+    : Array.isArray(value)
+    ? value.slice(0, MAX_LENGTH).map((item) => fingerprint(item, depth + 1, seen))
+    : properties(value, depth, seen);
+  seen.delete(value);
+  return result;
+}
+
+function properties(value, depth, seen) {
+  const result = {};
+  const keys = Object.keys(value).filter((key) => key !== 'prototype').sort();
+  for (const key of keys.slice(0, MAX_LENGTH)) {
+    const descriptor = Object.getOwnPropertyDescriptor(value, key);
+    // Don't run getters, they may have side effects.
+    result[key] = 'value' in descriptor
+      ? fingerprint(descriptor.value, depth + 1, seen)
+      : 'accessor';
+  }
+  return result;
+}
@@ -0,0 +1,160 @@
+//! A page showing the mapped ranges of the generated code next to the original sources.
+//!
+//! Ranges mapped to the same original position share a color, and hovering a range highlights the
+//! ranges it is mapped to. Reported mappings are outlined in red, with the problem as title.
+
+use std::fmt::Write;
+
+use rustc_hash::FxHashMap;
+
+use crate::{
+    Report, SourcemapChecker,
+    text::{Lines, byte_offset},
+};
+
+/// A range of a line, from `start` to the next range or the end of the line.
+struct Segment {
+    line: u32,
+    start: u32,
+    /// Index of the original position the range is mapped to.
+    group: usize,
+    problem: Option<String>,
+}
+
+pub fn render(checker: &SourcemapChecker, report: &Report) -> String {
+    let problems = report
+        .problems
+        .iter()
+        .map(|problem| (problem.index, problem.kind.to_string()))
+        .collect::<FxHashMap<_, _>>();
+
+    let mut groups = FxHashMap::default();
+    let mut output_segments = vec![];
+    let mut source_segments: Vec<Vec<Segment>> = checker.sources.iter().map(|_| vec![]).collect();
+    for (index, token) in checker.sourcemap.get_tokens().enumerate() {
+        let Some(source_id) = token.get_source_id() else {
+            // Unmapped ranges end the previous range.
+            output_segments.push(Segment {
+                line: token.get_dst_line(),
+                start: token.get_dst_col(),
+                group: usize::MAX,
+                problem: None,
+            });
+            continue;
+        };
+        let src = (source_id, token.get_src_line(), token.get_src_col());
+        let groups_len = groups.len();
+        let group = *groups.entry(src).or_insert(groups_len);
+        let problem = problems.get(&index).cloned();
+        // Each original position is highlighted once.
+        let is_new_group = group == groups_len;
+        if let Some(segments) = source_segments.get_mut(source_id as usize).filter(|_| is_new_group)
+        {
+            segments.push(Segment { line: src.1, start: src.2, group, problem: problem.clone() });
+        }
+        output_segments.push(Segment {
+            line: token.get_dst_line(),
+            start: token.get_dst_col(),
+            group,
+            problem,
+        });
+    }
+
+    let mut html = String::from(HEADER);
+    html.push_str("<div class=\"panes\"><section><h2>Generated</h2>");
+    render_text(&mut html, &checker.output, output_segments);
+    html.push_str("</section><section>");
+    for (id, segments) in source_segments.into_iter().enumerate() {
+        #[expect(clippy::cast_possible_truncation)]
+        let source = checker.sourcemap.get_source(id as u32).unwrap_or_default();
+        write!(html, "<h2>{}</h2>", escape(source)).unwrap();
+        render_text(&mut html, &checker.sources[id], segments);
+    }
+    html.push_str("</section></div>");
+    html.push_str(FOOTER);
+    html
+}
+
+fn render_text(html: &mut String, lines: &Lines, mut segments: Vec<Segment>) {
+    segments.sort_by_key(|segment| (segment.line, segment.start));
+    let mut segments = segments.into_iter().peekable();
+    html.push_str("<pre>");
+    for (line_index, line) in lines.iter().enumerate() {
+        let mut line_segments = vec![];
+        while let Some(segment) = segments.next_if(|segment| segment.line as usize == line_index) {
+            // Ranges which are not in the line are reported as invalid positions.
+            if let Some(start) = byte_offset(line, segment.start) {
+                line_segments.push((start, segment));
+            }
+        }
+        let mut end = line_segments.first().map_or(line.len(), |(start, _)| *start);
+        html.push_str(&escape(&line[..end]));
+        for (i, (start, segment)) in line_segments.iter().enumerate() {
+            end = line_segments.get(i + 1).map_or(line.len(), |(next, _)| *next);
+            let text = escape(&line[*start..end]);
+            if segment.group == usize::MAX {
+                html.push_str(&text);
+                continue;
+            }
+            let class = if segment.problem.is_some() { " problem" } else { "" };
+            let title = segment.problem.as_deref().map(escape).unwrap_or_default();
+            write!(
+                html,
+                "<span class=\"m c{}{class}\" data-m=\"{}\" title=\"{title}\">{text}</span>",
+                segment.group % 6,
+                segment.group,
+            )
+            .unwrap();
+        }
+        html.push('\n');
+    }
+    html.push_str("</pre>");
+}
+
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+const HEADER: &str = r#"<!DOCTYPE html>
+<html>
+<head>
+<meta charset="utf-8">
+<title>Source map</title>
+<style>
+body { font-family: sans-serif; margin: 0; }
+.panes { display: flex; }
+section { flex: 1; min-width: 0; overflow: auto; padding: 0 1em; }
+pre { font-size: 13px; }
+.m { cursor: pointer; }
+.c0 { background: #fde2e2; } .c1 { background: #e2f0fd; } .c2 { background: #e2fde6; }
+.c3 { background: #fdf7e2; } .c4 { background: #efe2fd; } .c5 { background: #e2fdfb; }
+.problem { outline: 2px solid #d00; }
+.active { background: #ffd24d; }
+</style>
+</head>
+<body>
+"#;
+
+const FOOTER: &str = r#"
+<script>
+document.addEventListener("mouseover", (event) => {
+  document.querySelectorAll(".active").forEach((el) => el.classList.remove("active"));
+  const m = event.target.dataset && event.target.dataset.m;
+  if (m !== undefined) {
+    document.querySelectorAll(`[data-m="${m}"]`).forEach((el) => el.classList.add("active"));
+  }
+});
+</script>
+</body>
+</html>
+"#;
@@ -0,0 +1,273 @@
+#![expect(clippy::print_stdout)]
+
+mod html;
+mod text;
+
+use std::{collections::BTreeMap, fmt, fs, path::Path};
+
+use oxc_sourcemap::{SourceMap, Token};
+use oxc_syntax::keyword::is_reserved_keyword;
+
+use crate::text::{Lines, identifier_at};
+
+/// Checks the source map `map` of the generated code `output`, prints a report, and writes a
+/// visualization of the mappings to `html`.
+///
+/// # Errors
+///
+/// * `output`, `map`, or an original source without content in the source map can not be read
+/// * `map` is not a valid source map
+/// * a mapping does not point at a plausible token
+pub fn run(output: &Path, map: &Path, html: Option<&Path>) -> Result<(), String> {
+    let read = |path: &Path| {
+        fs::read_to_string(path).map_err(|err| format!("Failed to read {}: {err}", path.display()))
+    };
+    let output_text = read(output)?;
+    let map_text = read(map)?;
+    let sourcemap = SourceMap::from_json_string(&map_text)
+        .map_err(|err| format!("Failed to parse {}: {err:?}", map.display()))?;
+
+    // Sources are relative to the source map, after the source root.
+    let source_dir =
+        map.parent().unwrap_or(Path::new("")).join(sourcemap.get_source_root().unwrap_or_default());
+    let sources = sourcemap
+        .get_sources()
+        .enumerate()
+        .map(|(id, source)| {
+            #[expect(clippy::cast_possible_truncation)]
+            let content = sourcemap.get_source_content(id as u32);
+            match content {
+                Some(content) => Ok(content.to_string()),
+                None => read(&source_dir.join(source)),
+            }
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let checker = SourcemapChecker::new(&output_text, &sourcemap, &sources);
+    let report = checker.check();
+    println!("{report}");
+
+    if let Some(html) = html {
+        fs::write(html, html::render(&checker, &report))
+            .map_err(|err| format!("Failed to write {}: {err}", html.display()))?;
+        println!("Visualization written to {}", html.display());
+    }
+
+    if report.problems.is_empty() {
+        Ok(())
+    } else {
+        Err(format!("{} mapping(s) don't point at a plausible token", report.problems.len()))
+    }
+}
+
+/// Checks the mappings of a source map against the generated code and the original sources.
+pub struct SourcemapChecker<'a> {
+    sourcemap: &'a SourceMap,
+    output: Lines<'a>,
+    sources: Vec<Lines<'a>>,
+}
+
+impl<'a> SourcemapChecker<'a> {
+    /// `sources` are the contents of the sources of `sourcemap`, in order.
+    pub fn new(output: &'a str, sourcemap: &'a SourceMap, sources: &'a [String]) -> Self {
+        Self {
+            sourcemap,
+            output: Lines::new(output),
+            sources: sources.iter().map(|source| Lines::new(source)).collect(),
+        }
+    }
+
+    pub fn check(&self) -> Report {
+        let mut report = Report::default();
+        for (index, token) in self.sourcemap.get_tokens().enumerate() {
+            let Some(source_id) = token.get_source_id() else { continue };
+            report.mappings += 1;
+            if let Err(kind) = self.check_token(token, source_id, &mut report.drift) {
+                report.problems.push(Problem {
+                    index,
+                    source: self.sourcemap.get_source(source_id).unwrap_or_default().to_string(),
+                    dst: (token.get_dst_line(), token.get_dst_col()),
+                    src: (token.get_src_line(), token.get_src_col()),
+                    kind,
+                });
+            }
+        }
+        report
+    }
+
+    fn check_token(
+        &self,
+        token: &Token,
+        source_id: u32,
+        drift: &mut BTreeMap<Drift, usize>,
+    ) -> Result<(), ProblemKind> {
+        let output = self
+            .output
+            .text_at(token.get_dst_line(), token.get_dst_col())
+            .ok_or(ProblemKind::InvalidOutputPosition)?;
+        let source_lines =
+            self.sources.get(source_id as usize).ok_or(ProblemKind::InvalidSourcePosition)?;
+        let source = source_lines
+            .text_at(token.get_src_line(), token.get_src_col())
+            .ok_or(ProblemKind::InvalidSourcePosition)?;
+        let source_identifier = identifier_at(source);
+
+        if let Some(name) = token.get_name_id().and_then(|id| self.sourcemap.get_name(id)) {
+            if source_identifier != Some(name) {
+                return Err(ProblemKind::NameMismatch {
+                    name: name.to_string(),
+                    found: source_identifier.map(ToString::to_string),
+                });
+            }
+            return Ok(());
+        }
+
+        // Mappings of identifiers which are not renamed point at the same identifier. Transformed
+        // code, e.g. `var` printed for `let` or `_jsx` for `<div>`, is not compared.
+        let (Some(output_identifier), Some(source_identifier)) =
+            (identifier_at(output), source_identifier)
+        else {
+            return Ok(());
+        };
+        if output_identifier == source_identifier
+            || is_reserved_keyword(output_identifier)
+            || is_reserved_keyword(source_identifier)
+        {
+            return Ok(());
+        }
+        let line = self.output.line(token.get_dst_line()).unwrap_or_default();
+        let offset = text::nearest_identifier(line, source_identifier, token.get_dst_col());
+        *drift.entry(offset.map_or(Drift::NotFound, Drift::Columns)).or_default() += 1;
+        Err(ProblemKind::IdentifierMismatch {
+            output: output_identifier.to_string(),
+            found: source_identifier.to_string(),
+            offset,
+        })
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Report {
+    /// Number of mappings with an original position.
+    pub mappings: usize,
+    pub problems: Vec<Problem>,
+    /// Number of mismatched identifiers by the distance from their generated position to the
+    /// original identifier.
+    pub drift: BTreeMap<Drift, usize>,
+}
+
+/// A mapping which does not point at a plausible token.
+#[derive(Debug)]
+pub struct Problem {
+    /// Index of the token in the source map.
+    pub index: usize,
+    pub source: String,
+    /// Generated line and column.
+    pub dst: (u32, u32),
+    /// Original line and column.
+    pub src: (u32, u32),
+    pub kind: ProblemKind,
+}
+
+#[derive(Debug)]
+pub enum ProblemKind {
+    InvalidOutputPosition,
+    InvalidSourcePosition,
+    /// The original identifier is not the name of the mapping.
+    NameMismatch {
+        name: String,
+        found: Option<String>,
+    },
+    /// An identifier which is not renamed is mapped to a different identifier.
+    IdentifierMismatch {
+        output: String,
+        found: String,
+        /// Columns from the generated position to the nearest `found` on the generated line.
+        offset: Option<i64>,
+    },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Drift {
+    Columns(i64),
+    /// The original identifier is not on the generated line.
+    NotFound,
+}
+
+impl fmt::Display for ProblemKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidOutputPosition => write!(f, "generated position is out of bounds"),
+            Self::InvalidSourcePosition => write!(f, "original position is out of bounds"),
+            Self::NameMismatch { name, found: Some(found) } => {
+                write!(f, "name `{name}` is mapped to `{found}`")
+            }
+            Self::NameMismatch { name, found: None } => {
+                write!(f, "name `{name}` is not mapped to an identifier")
+            }
+            Self::IdentifierMismatch { output, found, offset } => {
+                write!(f, "`{output}` is mapped to `{found}`")?;
+                match offset {
+                    Some(offset) => write!(f, " which is generated {offset:+} columns away"),
+                    None => write!(f, " which is not on the generated line"),
+                }
+            }
+        }
+    }
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Lines are printed 1-based, and columns 0-based as in the source map.
+        write!(
+            f,
+            "{}:{} -> {}:{}:{}: {}",
+            self.dst.0 + 1,
+            self.dst.1,
+            self.source,
+            self.src.0 + 1,
+            self.src.1,
+            self.kind
+        )
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let count = |matches: fn(&ProblemKind) -> bool| {
+            self.problems.iter().filter(|problem| matches(&problem.kind)).count()
+        };
+        writeln!(f, "Mappings:                 {}", self.mappings)?;
+        writeln!(
+            f,
+            "Invalid positions:        {}",
+            count(|kind| matches!(
+                kind,
+                ProblemKind::InvalidOutputPosition | ProblemKind::InvalidSourcePosition
+            ))
+        )?;
+        writeln!(
+            f,
+            "Name mismatches:          {}",
+            count(|kind| matches!(kind, ProblemKind::NameMismatch { .. }))
+        )?;
+        writeln!(
+            f,
+            "Identifier mismatches:    {}",
+            count(|kind| matches!(kind, ProblemKind::IdentifierMismatch { .. }))
+        )?;
+        if !self.drift.is_empty() {
+            writeln!(f, "Drift (columns: count):")?;
+            for (drift, count) in &self.drift {
+                match drift {
+                    Drift::Columns(columns) => writeln!(f, "  {columns:+}: {count}")?,
+                    Drift::NotFound => writeln!(f, "  not found: {count}")?,
+                }
+            }
+        }
+        for problem in &self.problems {
+            writeln!(f, "{problem}")?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,15 @@
+use std::path::PathBuf;
+
+use pico_args::Arguments;
+
+use oxc_sourcemap_checker::run;
+
+fn main() -> Result<(), String> {
+    let mut args = Arguments::from_env();
+    let html: Option<PathBuf> = args.opt_value_from_str("--html").map_err(|err| err.to_string())?;
+    let output: PathBuf = args.free_from_str().map_err(|_| USAGE.to_string())?;
+    let map: PathBuf = args.free_from_str().map_err(|_| USAGE.to_string())?;
+    run(&output, &map, html.as_deref())
+}
+
+const USAGE: &str = "Usage: oxc_sourcemap_checker <output> <map> [--html <path>]";
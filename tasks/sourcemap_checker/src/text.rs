@@ -0,0 +1,89 @@
+//! Lines and columns as counted by source maps: columns are UTF-16 code units.
+
+use oxc_syntax::identifier::{is_identifier_part, is_identifier_start};
+
+/// The lines of a text, split at the line terminators of JavaScript.
+pub struct Lines<'a> {
+    lines: Vec<&'a str>,
+}
+
+impl<'a> Lines<'a> {
+    pub fn new(text: &'a str) -> Self {
+        let mut lines = vec![];
+        let mut start = 0;
+        let mut chars = text.char_indices().peekable();
+        while let Some((index, c)) = chars.next() {
+            if !matches!(c, '\n' | '\r' | '\u{2028}' | '\u{2029}') {
+                continue;
+            }
+            lines.push(&text[start..index]);
+            start = index + c.len_utf8();
+            if c == '\r' && chars.peek().is_some_and(|&(_, next)| next == '\n') {
+                chars.next();
+                start += 1;
+            }
+        }
+        lines.push(&text[start..]);
+        Self { lines }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &'a str> + '_ {
+        self.lines.iter().copied()
+    }
+
+    pub fn line(&self, line: u32) -> Option<&'a str> {
+        self.lines.get(line as usize).copied()
+    }
+
+    /// The rest of the line from `line` and `col`, or `None` if the position is not in the text.
+    pub fn text_at(&self, line: u32, col: u32) -> Option<&'a str> {
+        let line = self.line(line)?;
+        Some(&line[byte_offset(line, col)?..])
+    }
+}
+
+/// The byte offset of the UTF-16 column `col` in `line`, or `None` if it is past the end of the
+/// line or within a character.
+pub fn byte_offset(line: &str, col: u32) -> Option<usize> {
+    let mut utf16_col = 0;
+    for (index, c) in line.char_indices() {
+        if utf16_col == col as usize {
+            return Some(index);
+        }
+        utf16_col += c.len_utf16();
+        if utf16_col > col as usize {
+            return None;
+        }
+    }
+    (utf16_col == col as usize).then_some(line.len())
+}
+
+/// The identifier at the start of `text`, without the `#` of a private name.
+pub fn identifier_at(text: &str) -> Option<&str> {
+    let text = text.strip_prefix('#').unwrap_or(text);
+    let mut chars = text.char_indices();
+    if !chars.next().is_some_and(|(_, c)| is_identifier_start(c)) {
+        return None;
+    }
+    let end = chars.find(|&(_, c)| !is_identifier_part(c)).map_or(text.len(), |(index, _)| index);
+    Some(&text[..end])
+}
+
+/// Columns from `col` to the nearest occurrence of the identifier `name` in `line`.
+pub fn nearest_identifier(line: &str, name: &str, col: u32) -> Option<i64> {
+    let mut utf16_col = 0i64;
+    let mut nearest: Option<i64> = None;
+    let mut previous = None;
+    for (index, c) in line.char_indices() {
+        let starts_identifier = !previous.is_some_and(is_identifier_part);
+        if starts_identifier && identifier_at(&line[index..]) == Some(name) {
+            let offset = utf16_col - i64::from(col);
+            if nearest.is_none_or(|nearest| offset.abs() < nearest.abs()) {
+                nearest = Some(offset);
+            }
+        }
+        utf16_col += i64::from(c.len_utf16() == 2) + 1;
+        previous = Some(c);
+    }
+    nearest
+}
@@ -59,6 +59,16 @@ pub const PLUGINS: &[&str] = &[
     "babel-plugin-proposal-explicit-resource-management",
     // RegExp tests ported from esbuild + a few additions
     "regexp",
+    // Vue JSX
+    "vue-jsx",
+    // Emotion
+    "emotion",
+    // babel-plugin-import
+    "modular-imports",
+    // Rewrite CSS modules, SVG and other asset imports
+    "asset-imports",
+    // Rewrite relative imports into fully specified paths for Node.js ES modules
+    "fully-specified",
     // Legacy decorators, tests almost ported from TypeScript
     "legacy-decorators",
 ];
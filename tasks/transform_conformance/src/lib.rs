@@ -190,6 +190,10 @@ impl TestRunner {
                 "Passed: {all_passed_count}/{total}\n\n# All Passed:\n{all_passed}\n\n\n{snapshot}"
             );
             self.snapshot.save(dest, &snapshot);
+        } else if total > 0 {
+            // The snapshot is not updated for a subset of the cases, report the results instead.
+            let name = dest.file_name().unwrap_or_default().to_string_lossy();
+            println!("{name}: Passed: {all_passed_count}/{total}\n\n{snapshot}");
         }
     }
 }
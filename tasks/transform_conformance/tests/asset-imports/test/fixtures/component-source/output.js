@@ -0,0 +1 @@
+import Icon from "@/icons/close.svg?component";
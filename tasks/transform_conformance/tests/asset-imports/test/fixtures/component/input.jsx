@@ -0,0 +1,5 @@
+import Icon from "./icon.svg?react";
+import Logo, { title } from "./logo.svg?react";
+import url from "./icon.svg";
+
+<Icon title={title} src={url}><Logo /></Icon>;
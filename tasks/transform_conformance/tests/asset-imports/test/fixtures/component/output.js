@@ -0,0 +1,5 @@
+import { ReactComponent as Icon } from "./icon.svg";
+import { ReactComponent as Logo, title } from "./logo.svg";
+import url from "./icon.svg";
+
+<Icon title={title} src={url}><Logo /></Icon>;
@@ -0,0 +1,10 @@
+import styles from "./app.module.css";
+import * as theme from "./theme.module.css";
+import { button, link as a } from "./button.module.css";
+import "./global.module.css";
+import "./global.css";
+
+styles.root;
+theme.dark;
+button;
+a;
@@ -0,0 +1,9 @@
+const styles = {};
+const theme = {};
+const button = void 0, a = void 0;
+import "./global.css";
+
+styles.root;
+theme.dark;
+button;
+a;
@@ -0,0 +1,7 @@
+import type Styles from "./app.module.css";
+import { type ClassNames } from "./button.module.css";
+import styles from "./app.module.css?inline";
+import Icon from "./icon.svg";
+
+export const props: Styles & ClassNames = styles;
+export default Icon;
@@ -0,0 +1,5 @@
+import styles from "./app.module.css?inline";
+import Icon from "./icon.svg";
+
+export const props = styles;
+export default Icon;
@@ -0,0 +1 @@
+import { width } from "./logo.png";
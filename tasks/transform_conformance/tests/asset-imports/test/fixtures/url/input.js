@@ -0,0 +1,5 @@
+import logo from "./logo.png";
+import "./background.png";
+import icon from "../assets/icon.svg?url";
+
+document.body.append(logo, icon);
@@ -0,0 +1,4 @@
+const logo = new URL("./logo.png", import.meta.url).href;
+const icon = new URL("../assets/icon.svg?url", import.meta.url).href;
+
+document.body.append(logo, icon);
@@ -0,0 +1,9 @@
+async function foo(a, b) {
+  await a;
+}
+const bar = async function (a, b) {
+  await b;
+};
+const baz = async (a, b) => {
+  await a;
+};
@@ -0,0 +1,20 @@
+function foo() {
+  return _foo.apply(this, arguments);
+}
+function _foo() {
+  _foo = babelHelpers.asyncToGenerator(function* (a, b) {
+    yield a;
+  });
+  return _foo.apply(this, arguments);
+}
+const bar = babelHelpers.asyncToGenerator(function* (a, b) {
+  yield b;
+});
+const baz = function() {
+  var _ref = babelHelpers.asyncToGenerator(function* (a, b) {
+    yield a;
+  });
+  return function baz() {
+    return _ref.apply(this, arguments);
+  };
+}();
@@ -0,0 +1,5 @@
+var foo = object.foo ?? "default";
+var bar = baz ?? "default";
+function f() {
+  return this ?? "default";
+}
@@ -0,0 +1,6 @@
+var _object$foo, _baz;
+var foo = (_object$foo = object.foo) != null ? _object$foo : "default";
+var bar = (_baz = baz) != null ? _baz : "default";
+function f() {
+  return this != null ? this : "default";
+}
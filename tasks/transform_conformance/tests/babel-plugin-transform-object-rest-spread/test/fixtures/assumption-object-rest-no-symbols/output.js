@@ -0,0 +1 @@
+const { a } = obj, rest = babelHelpers.objectWithoutPropertiesLoose(obj, ["a"]);
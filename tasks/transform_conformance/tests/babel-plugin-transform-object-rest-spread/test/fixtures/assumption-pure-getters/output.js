@@ -0,0 +1,3 @@
+z = babelHelpers.objectSpread2({ x }, y);
+z = babelHelpers.objectSpread2({ x }, y, { w }, v);
+z = babelHelpers.objectSpread2({}, x, { y });
@@ -0,0 +1,3 @@
+z = { x, ...y };
+z = { x, ...y, w, ...v };
+z = { ...x, y };
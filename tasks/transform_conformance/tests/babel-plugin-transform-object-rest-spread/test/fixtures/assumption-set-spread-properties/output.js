@@ -0,0 +1,3 @@
+z = babelHelpers.extends({ x }, y);
+z = babelHelpers.extends(babelHelpers.extends({ x }, y), {}, { w }, v);
+z = babelHelpers.extends(babelHelpers.extends({}, x), {}, { y });
@@ -0,0 +1,20 @@
+enum S {
+  A = "a",
+  B = A + "b",
+  C = `${B}c`,
+  D = S.A,
+  E = S["B"],
+  F = `${S.C}-${1 + 1}`,
+}
+
+enum Mixed {
+  A = 1 << 2,
+  B = A | 1,
+  C = `${B}`,
+  D = Mixed.B + 1,
+}
+
+enum Other {
+  A = S.E,
+  B = `${Mixed.C}`,
+}
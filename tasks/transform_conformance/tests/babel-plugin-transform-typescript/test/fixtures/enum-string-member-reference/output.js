@@ -0,0 +1,21 @@
+var S = /* @__PURE__ */ function(S) {
+  S["A"] = "a";
+  S["B"] = "ab";
+  S["C"] = "abc";
+  S["D"] = "a";
+  S["E"] = "ab";
+  S["F"] = "abc-2";
+  return S;
+}(S || {});
+var Mixed = /* @__PURE__ */ function(Mixed) {
+  Mixed[Mixed["A"] = 4] = "A";
+  Mixed[Mixed["B"] = 5] = "B";
+  Mixed["C"] = "5";
+  Mixed[Mixed["D"] = 6] = "D";
+  return Mixed;
+}(Mixed || {});
+var Other = /* @__PURE__ */ function(Other) {
+  Other["A"] = "ab";
+  Other["B"] = "5";
+  return Other;
+}(Other || {});
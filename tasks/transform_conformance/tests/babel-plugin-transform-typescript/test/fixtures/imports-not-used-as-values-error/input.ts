@@ -0,0 +1,3 @@
+import { A } from "a";
+
+const x: A = 0;
@@ -0,0 +1,7 @@
+import A from "a";
+import { B, type C } from "b";
+import D, { E } from "d";
+
+const x: A = 0;
+let y: B | C;
+console.log(E);
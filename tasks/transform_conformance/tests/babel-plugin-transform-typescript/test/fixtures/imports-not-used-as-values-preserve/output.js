@@ -0,0 +1,7 @@
+import "a";
+import "b";
+import { E } from "d";
+
+const x = 0;
+let y;
+console.log(E);
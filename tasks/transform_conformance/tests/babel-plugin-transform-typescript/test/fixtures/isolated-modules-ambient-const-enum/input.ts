@@ -0,0 +1,6 @@
+declare const enum Direction {
+  Up,
+  Down,
+}
+
+export const up = Direction.Up;
@@ -0,0 +1,5 @@
+namespace Types {
+  export interface Foo {}
+}
+
+export import Foo = Types.Foo;
@@ -0,0 +1,13 @@
+class A extends Base {
+  declare d: string;
+  field = this.x;
+  constructor(public x: number, @dec private readonly y: string) {
+    console.log("before");
+    super(x, y);
+    console.log("after");
+  }
+}
+class C {
+  field = this.x;
+  constructor(@dec public x: number) {}
+}
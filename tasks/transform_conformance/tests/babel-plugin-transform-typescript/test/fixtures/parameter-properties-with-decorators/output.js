@@ -0,0 +1,18 @@
+let A = class A extends Base {
+  constructor(x, y) {
+    console.log("before");
+    super(x, y);
+    this.x = x;
+    this.y = y;
+    this.field = this.x;
+    console.log("after");
+  }
+};
+A = babelHelpers.decorate([babelHelpers.decorateParam(1, dec)], A);
+let C = class C {
+  constructor(x) {
+    this.x = x;
+    this.field = this.x;
+  }
+};
+C = babelHelpers.decorate([babelHelpers.decorateParam(0, dec)], C);
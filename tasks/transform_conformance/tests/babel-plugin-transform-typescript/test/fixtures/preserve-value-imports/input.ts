@@ -0,0 +1,5 @@
+import A, { B, C, type D } from "a";
+import E from "e";
+
+let x: B;
+console.log(C);
@@ -0,0 +1,5 @@
+import A, { C } from "a";
+import E from "e";
+
+let x;
+console.log(C);
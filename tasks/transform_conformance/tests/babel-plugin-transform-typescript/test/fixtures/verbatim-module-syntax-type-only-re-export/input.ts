@@ -0,0 +1,3 @@
+import type { Foo } from "foo";
+
+export { Foo };
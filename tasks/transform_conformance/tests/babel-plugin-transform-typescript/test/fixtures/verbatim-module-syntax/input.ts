@@ -0,0 +1,7 @@
+import A from "a";
+import { B, type C } from "b";
+import { type D } from "d";
+import type E from "e";
+
+export { type C, B };
+let x: A;
@@ -0,0 +1,6 @@
+import A from "a";
+import { B } from "b";
+import "d";
+
+export { B };
+let x;
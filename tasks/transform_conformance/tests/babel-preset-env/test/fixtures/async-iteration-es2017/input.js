@@ -0,0 +1,19 @@
+async function* gen() {
+  await 1;
+  yield* other();
+  for await (const x of y) {
+    use(x);
+  }
+}
+
+const obj = {
+  async *method() {
+    yield this;
+  },
+};
+
+const fn = async () => {
+  for await (const [a, b] of y) {
+    use(a, b);
+  }
+};
@@ -0,0 +1,66 @@
+function gen() {
+  return _gen.apply(this, arguments);
+}
+function _gen() {
+  _gen = babelHelpers.wrapAsyncGenerator(function* () {
+    yield babelHelpers.awaitAsyncGenerator(1);
+    yield* babelHelpers.asyncGeneratorDelegate(babelHelpers.asyncIterator(other()));
+    var _iteratorAbruptCompletion = false;
+    var _didIteratorError = false;
+    var _iteratorError;
+    try {
+      for (var _iterator = babelHelpers.asyncIterator(y), _step; _iteratorAbruptCompletion = !(_step = yield babelHelpers.awaitAsyncGenerator(_iterator.next())).done; _iteratorAbruptCompletion = false) {
+        const x = _step.value;
+        {
+          use(x);
+        }
+      }
+    } catch (err) {
+      _didIteratorError = true;
+      _iteratorError = err;
+    } finally {
+      try {
+        if (_iteratorAbruptCompletion && _iterator.return != null) {
+          yield babelHelpers.awaitAsyncGenerator(_iterator.return());
+        }
+      } finally {
+        if (_didIteratorError) {
+          throw _iteratorError;
+        }
+      }
+    }
+  });
+  return _gen.apply(this, arguments);
+}
+const obj = { method() {
+  var _this = this;
+  return babelHelpers.wrapAsyncGenerator(function* () {
+    yield _this;
+  })();
+} };
+const fn = async () => {
+  var _iteratorAbruptCompletion2 = false;
+  var _didIteratorError2 = false;
+  var _iteratorError2;
+  try {
+    for (var _iterator2 = babelHelpers.asyncIterator(y), _step2; _iteratorAbruptCompletion2 = !(_step2 = await _iterator2.next()).done; _iteratorAbruptCompletion2 = false) {
+      const [a, b] = _step2.value;
+      {
+        use(a, b);
+      }
+    }
+  } catch (err) {
+    _didIteratorError2 = true;
+    _iteratorError2 = err;
+  } finally {
+    try {
+      if (_iteratorAbruptCompletion2 && _iterator2.return != null) {
+        await _iterator2.return();
+      }
+    } finally {
+      if (_didIteratorError2) {
+        throw _iteratorError2;
+      }
+    }
+  }
+};
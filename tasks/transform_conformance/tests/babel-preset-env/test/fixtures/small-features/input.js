@@ -0,0 +1,19 @@
+// ES2016
+a **= b ** 2;
+// ES2018
+const { x, ...rest } = { ...a, y: 1 };
+// ES2019
+try {
+  f();
+} catch {}
+// ES2021
+a ||= 1;
+a &&= 2;
+a ??= 3;
+const n = 1_000;
+// ES2022
+class C {
+  static {
+    init();
+  }
+}
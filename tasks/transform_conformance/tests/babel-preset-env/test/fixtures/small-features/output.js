@@ -0,0 +1,12 @@
+var _a, _a2;
+_a = a, a = Math.pow(_a, Math.pow(b, 2));
+const _a$y = babelHelpers.objectSpread2(babelHelpers.objectSpread2({}, a), {}, { y: 1 }), { x } = _a$y, rest = babelHelpers.objectWithoutProperties(_a$y, ["x"]);
+try {
+  f();
+} catch (_unused) {}
+a || (a = 1);
+a && (a = 2);
+(_a2 = a) !== null && _a2 !== void 0 ? _a2 : a = 3;
+const n = 1e3;
+class C {}
+init();
@@ -0,0 +1,19 @@
+import { css, keyframes } from "@emotion/react";
+import { css as emotionCss } from "@emotion/css";
+
+const title = css({ color: "hotpink" });
+const bounce = keyframes`
+  from { transform: scale(1); }
+  to { transform: scale(${1.5}); }
+`;
+const styles = {
+  container: emotionCss`display: flex;`,
+};
+function getStyles(color) {
+  return css({ color });
+}
+
+// Not from Emotion
+function other(css) {
+  return css({ color: "red" });
+}
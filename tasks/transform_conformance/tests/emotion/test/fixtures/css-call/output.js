@@ -0,0 +1,11 @@
+import { css, keyframes } from "@emotion/react";
+import { css as emotionCss } from "@emotion/css";
+const title = /* @__PURE__ */ css({ color: "hotpink" }, "label:title;");
+const bounce = /* @__PURE__ */ keyframes("\n  from { transform: scale(1); }\n  to { transform: scale(", 1.5, "); }\n", "label:bounce;");
+const styles = { container: /* @__PURE__ */ emotionCss("display: flex;", "label:container;") };
+function getStyles(color) {
+  return /* @__PURE__ */ css({ color }, "label:getStyles;");
+}
+function other(css) {
+  return css({ color: "red" });
+}
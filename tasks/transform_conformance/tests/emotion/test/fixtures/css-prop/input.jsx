@@ -0,0 +1,9 @@
+/** @jsxImportSource @emotion/react */
+const App = () => <div css={{ color: "hotpink" }}>Hello</div>;
+
+function List() {
+  return <ul css={[{ margin: 0 }, { padding: 0 }]} />;
+}
+
+// Dynamic values are left as is
+const Item = ({ style }) => <li css={style} />;
@@ -0,0 +1,10 @@
+import { css as _css } from "@emotion/react";
+import { jsx as _jsx } from "@emotion/react/jsx-runtime";
+const App = () => _jsx("div", {
+  css: /* @__PURE__ */ _css({ color: "hotpink" }, "label:App;"),
+  children: "Hello"
+});
+function List() {
+  return _jsx("ul", { css: /* @__PURE__ */ _css([{ margin: 0 }, { padding: 0 }], "label:List;") });
+}
+const Item = ({ style }) => _jsx("li", { css: style });
@@ -0,0 +1,2 @@
+import { css } from "@emotion/react";
+const title = /* @__PURE__ */ css({ color: "hotpink" }, "label:label-format--input--title;");
@@ -0,0 +1,2 @@
+import { css } from "@emotion/react";
+const title = /* @__PURE__ */ css({ color: "hotpink" }, "label:title;", "/*# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJmaWxlIjoiaW5wdXQuanN4IiwibWFwcGluZ3MiOiJBQUVjIiwibmFtZXMiOltdLCJzb3VyY2VzIjpbImlucHV0LmpzeCJdLCJzb3VyY2VzQ29udGVudCI6WyJpbXBvcnQgeyBjc3MgfSBmcm9tIFwiQGVtb3Rpb24vcmVhY3RcIjtcblxuY29uc3QgdGl0bGUgPSBjc3MoeyBjb2xvcjogXCJob3RwaW5rXCIgfSk7XG4iXSwidmVyc2lvbiI6M30= */");
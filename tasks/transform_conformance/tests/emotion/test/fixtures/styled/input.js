@@ -0,0 +1,8 @@
+import styled from "@emotion/styled";
+import Link from "./link";
+
+const Button = styled.button`
+  color: ${(props) => props.color};
+`;
+const StyledLink = styled(Link)({ textDecoration: "none" });
+const Title = styled.h1({ fontSize: 20 }, (props) => ({ color: props.color }));
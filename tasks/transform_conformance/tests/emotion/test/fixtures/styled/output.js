@@ -0,0 +1,14 @@
+import styled from "@emotion/styled";
+import Link from "./link";
+const Button = styled("button", {
+  target: "e6snblk0",
+  label: "Button"
+})("\n  color: ", (props) => props.color, ";\n");
+const StyledLink = styled(Link, {
+  target: "e6snblk1",
+  label: "StyledLink"
+})({ textDecoration: "none" });
+const Title = styled("h1", {
+  target: "e6snblk2",
+  label: "Title"
+})({ fontSize: 20 }, (props) => ({ color: props.color }));
@@ -0,0 +1,11 @@
+import { a } from "./utils";
+import { b } from "./utils.ts";
+import { c } from "./esm.mts";
+import { d } from "../lib/";
+import "./style.css";
+import "./data.json";
+import "lodash";
+export * from "./components/index.tsx";
+export { e } from ".";
+const f = import("./worker?url");
+const g = import(`./utils`);
@@ -0,0 +1,11 @@
+import { a } from "./utils.js";
+import { b } from "./utils.js";
+import { c } from "./esm.mjs";
+import { d } from "../lib/index.js";
+import "./style.css";
+import "./data.json";
+import "lodash";
+export * from "./components/index.js";
+export { e } from "./index.js";
+const f = import("./worker.js?url");
+const g = import(`./utils`);
@@ -0,0 +1,4 @@
+import { a } from "./utils";
+export type { V } from "./types";
+export * from "./components/index.tsx";
+export const b: number = a;
@@ -0,0 +1,3 @@
+import { a } from "./utils.js";
+export * from "./components/index.js";
+export const b = a;
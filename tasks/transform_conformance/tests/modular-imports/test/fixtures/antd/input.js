@@ -0,0 +1,4 @@
+import { Button, DatePicker as Picker } from "antd";
+
+Button;
+Picker;
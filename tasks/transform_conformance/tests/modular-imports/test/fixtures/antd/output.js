@@ -0,0 +1,6 @@
+import Button from "antd/lib/button";
+import "antd/lib/button/style/css";
+import Picker from "antd/lib/date-picker";
+import "antd/lib/date-picker/style/css";
+Button;
+Picker;
@@ -0,0 +1 @@
+import { AddIcon, ArrowBack } from "@icons/material";
@@ -0,0 +1,2 @@
+import AddIcon from "@icons/material/svg/add-icon.js";
+import ArrowBack from "@icons/material/svg/arrow-back.js";
@@ -0,0 +1,2 @@
+import { debounce, throttle } from "lodash";
+import { Button } from "@mui/material";
@@ -0,0 +1,3 @@
+import debounce from "lodash/debounce";
+import throttle from "lodash/throttle";
+import Button from "@mui/material/Button";
@@ -0,0 +1,5 @@
+import antd, { Button, default as Antd } from "antd";
+import * as all from "antd";
+import {} from "antd";
+import "antd";
+import { Modal } from "./antd";
@@ -0,0 +1,7 @@
+import Button from "antd/lib/button";
+import "antd/lib/button/style";
+import antd, { default as Antd } from "antd";
+import * as all from "antd";
+import {} from "antd";
+import "antd";
+import { Modal } from "./antd";
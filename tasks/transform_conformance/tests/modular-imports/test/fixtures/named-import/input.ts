@@ -0,0 +1,4 @@
+import { Button, type ButtonProps } from "antd";
+
+export const props: ButtonProps = {};
+export default Button;
@@ -0,0 +1,3 @@
+import { Button } from "antd/es/button";
+export const props = {};
+export default Button;
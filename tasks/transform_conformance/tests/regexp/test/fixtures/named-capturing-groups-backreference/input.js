@@ -0,0 +1,6 @@
+a1 = /(?<a>x)\k<a>/
+a2 = /(?<a>x)\k<a>0/
+a3 = /(?<year>\d{4})-(?<month>\d{2})(x)(?<day>\d{2})/g
+a4 = /(?:(?<a>x)|(?<b>y))(?=(?<c>z))/
+// Can't be lowered
+b1 = /(?<a>x)|(?<a>y)/
@@ -0,0 +1,13 @@
+a1 = babelHelpers.wrapRegExp(/(x)\1/, { a: 1 });
+a2 = babelHelpers.wrapRegExp(/(x)\1(?:)0/, { a: 1 });
+a3 = babelHelpers.wrapRegExp(/(\d{4})-(\d{2})(x)(\d{2})/g, {
+  year: 1,
+  month: 2,
+  day: 4
+});
+a4 = babelHelpers.wrapRegExp(/(?:(x)|(y))(?=(z))/, {
+  a: 1,
+  b: 2,
+  c: 3
+});
+b1 = new RegExp("(?<a>x)|(?<a>y)", "");
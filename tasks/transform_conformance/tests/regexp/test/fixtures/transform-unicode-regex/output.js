@@ -1 +1 @@
-x2 = new RegExp(".", "u");
+x2 = /(?:[\uD800-\uDBFF][\uDC00-\uDFFF]|[^\n\r\u2028\u2029])/;
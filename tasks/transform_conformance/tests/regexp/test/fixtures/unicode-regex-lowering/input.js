@@ -0,0 +1,15 @@
+a1 = /😀/u
+a2 = /😀+/u
+a3 = /\u{1F600}\u{41}/u
+a4 = /😀/u
+b1 = /a.b/su
+b2 = /\S\d/u
+c1 = /[a-z😀]/u
+c2 = /[\u{1F600}-\u{1F64F}]/u
+c3 = /[\u{10000}-\u{10FFFF}]/u
+c4 = /[^abc]/u
+d1 = /(?<year>\d{4})-\k<year>/u
+// Can't be lowered
+e1 = /😀/iu
+e2 = /[^😀]/u
+e3 = /[\S]/u
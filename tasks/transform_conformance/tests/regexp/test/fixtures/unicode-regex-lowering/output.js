@@ -0,0 +1,14 @@
+a1 = /\uD83D\uDE00/;
+a2 = /(?:\uD83D\uDE00)+/;
+a3 = /\uD83D\uDE00\u0041/;
+a4 = /\uD83D\uDE00/;
+b1 = /a(?:[\uD800-\uDBFF][\uDC00-\uDFFF]|[\s\S])b/s;
+b2 = /(?:[\uD800-\uDBFF][\uDC00-\uDFFF]|\S)\d/;
+c1 = /(?:[a-z]|\uD83D\uDE00)/;
+c2 = /(?:\uD83D[\uDE00-\uDE4F])/;
+c3 = /(?:\uD800[\uDC00-\uDFFF]|[\uD801-\uDBFE][\uDC00-\uDFFF]|\uDBFF[\uDC00-\uDFFF])/;
+c4 = /(?:[\uD800-\uDBFF][\uDC00-\uDFFF]|[^abc])/;
+d1 = /(?<year>\d{4})-\k<year>/;
+e1 = new RegExp("😀", "iu");
+e2 = new RegExp("[^😀]", "u");
+e3 = new RegExp("[\\S]", "u");
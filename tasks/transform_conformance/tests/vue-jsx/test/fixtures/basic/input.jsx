@@ -0,0 +1,10 @@
+import { Comp } from "./comp";
+
+<div class="a" id={id}>foo &amp; bar</div>;
+<Comp.Item />;
+<my-comp title="t" />;
+<>
+  <span>a</span>
+  {b}
+</>;
+<svg><path d={d} /></svg>;
@@ -0,0 +1,10 @@
+import { Comp } from "./comp";
+import { createTextVNode as _createTextVNode, createVNode as _createVNode, resolveComponent as _resolveComponent, Fragment as _Fragment } from "vue";
+_createVNode("div", {
+  "class": "a",
+  "id": id
+}, [_createTextVNode("foo & bar")]);
+_createVNode(Comp.Item, null, null);
+_createVNode(_resolveComponent("my-comp"), { "title": "t" }, null);
+_createVNode(_Fragment, null, [_createVNode("span", null, [_createTextVNode("a")]), b]);
+_createVNode("svg", null, [_createVNode("path", { "d": d }, null)]);
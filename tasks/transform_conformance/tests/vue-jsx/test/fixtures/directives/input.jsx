@@ -0,0 +1,6 @@
+let visible, html, text, value;
+<div v-show={visible} />;
+<div v-html={html} />;
+<div v-text={text} />;
+<div v-focus={value} />;
+<div v-tooltip:top_dark={value} />;
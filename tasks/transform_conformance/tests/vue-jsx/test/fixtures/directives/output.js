@@ -0,0 +1,12 @@
+import { vShow as _vShow, createVNode as _createVNode, withDirectives as _withDirectives, resolveDirective as _resolveDirective } from "vue";
+let visible, html, text, value;
+_withDirectives(_createVNode("div", null, null), [[_vShow, visible]]);
+_createVNode("div", { "innerHTML": html }, null);
+_createVNode("div", { "textContent": text }, null);
+_withDirectives(_createVNode("div", null, null), [[_resolveDirective("focus"), value]]);
+_withDirectives(_createVNode("div", null, null), [[
+  _resolveDirective("tooltip"),
+  value,
+  "top",
+  { dark: true }
+]]);
@@ -0,0 +1 @@
+<input v-model={a + b} />;
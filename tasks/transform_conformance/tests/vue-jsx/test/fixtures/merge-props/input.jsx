@@ -0,0 +1,3 @@
+let props, onClick;
+<div {...props} />;
+<div a="1" {...props} onClick={onClick} />;
@@ -0,0 +1,4 @@
+import { createVNode as _createVNode, mergeProps as _mergeProps } from "vue";
+let props, onClick;
+_createVNode("div", props, null);
+_createVNode("div", _mergeProps({ "a": "1" }, props, { "onClick": onClick }), null);
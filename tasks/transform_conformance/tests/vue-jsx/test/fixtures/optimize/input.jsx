@@ -0,0 +1,9 @@
+import { Comp } from "./comp";
+
+let cls, style, id, props, el, visible;
+<div class={cls} style={style} id={id} />;
+<div class="static" title={"static"} />;
+<div {...props} id={id} />;
+<div ref={el} />;
+<div v-show={visible} />;
+<Comp class={cls}>foo</Comp>;
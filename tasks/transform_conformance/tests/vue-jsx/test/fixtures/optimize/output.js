@@ -0,0 +1,19 @@
+import { Comp } from "./comp";
+import { createVNode as _createVNode, mergeProps as _mergeProps, vShow as _vShow, withDirectives as _withDirectives, createTextVNode as _createTextVNode } from "vue";
+let cls, style, id, props, el, visible;
+_createVNode("div", {
+  "class": cls,
+  "style": style,
+  "id": id
+}, null, 14, ["id"]);
+_createVNode("div", {
+  "class": "static",
+  "title": "static"
+}, null);
+_createVNode("div", _mergeProps(props, { "id": id }), null, 16);
+_createVNode("div", { "ref": el }, null, 512);
+_withDirectives(_createVNode("div", null, null, 512), [[_vShow, visible]]);
+_createVNode(Comp, { "class": cls }, {
+  default: () => [_createTextVNode("foo")],
+  _: 1
+}, 8, ["class"]);
@@ -0,0 +1,9 @@
+import { Comp } from "./comp";
+
+let slots;
+<Comp>foo</Comp>;
+<Comp>{() => <span />}</Comp>;
+<Comp>{{ default: () => "a", footer: () => "b" }}</Comp>;
+<Comp v-slots={slots} />;
+<Comp v-slots={slots}>foo</Comp>;
+<div>{...slots}</div>;
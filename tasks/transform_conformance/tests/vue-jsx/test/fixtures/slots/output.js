@@ -0,0 +1,15 @@
+import { Comp } from "./comp";
+import { createTextVNode as _createTextVNode, createVNode as _createVNode } from "vue";
+let slots;
+_createVNode(Comp, null, { default: () => [_createTextVNode("foo")] });
+_createVNode(Comp, null, { default: () => _createVNode("span", null, null) });
+_createVNode(Comp, null, {
+  default: () => "a",
+  footer: () => "b"
+});
+_createVNode(Comp, null, slots);
+_createVNode(Comp, null, {
+  default: () => [_createTextVNode("foo")],
+  ...slots
+});
+_createVNode("div", null, [...slots]);
@@ -0,0 +1,12 @@
+import { Comp } from "./comp";
+
+let text, checked, picked, selected, type, obj;
+<input v-model={text} />;
+<input type="checkbox" vModel={checked} />;
+<input type="radio" v-model={picked} />;
+<input type={type} v-model={text} />;
+<select v-model={selected} />;
+<textarea v-model={[text, ["trim"]]} />;
+<Comp v-model={text} />;
+<Comp v-model:title={obj.title} />;
+<Comp v-model={[obj.name, "name", ["lazy"]]} />;
@@ -0,0 +1,36 @@
+import { Comp } from "./comp";
+import { vModelText as _vModelText, createVNode as _createVNode, withDirectives as _withDirectives, vModelCheckbox as _vModelCheckbox, vModelRadio as _vModelRadio, vModelDynamic as _vModelDynamic, vModelSelect as _vModelSelect } from "vue";
+let text, checked, picked, selected, type, obj;
+_withDirectives(_createVNode("input", { "onUpdate:modelValue": ($event) => text = $event }, null), [[_vModelText, text]]);
+_withDirectives(_createVNode("input", {
+  "type": "checkbox",
+  "onUpdate:modelValue": ($event) => checked = $event
+}, null), [[_vModelCheckbox, checked]]);
+_withDirectives(_createVNode("input", {
+  "type": "radio",
+  "onUpdate:modelValue": ($event) => picked = $event
+}, null), [[_vModelRadio, picked]]);
+_withDirectives(_createVNode("input", {
+  "type": type,
+  "onUpdate:modelValue": ($event) => text = $event
+}, null), [[_vModelDynamic, text]]);
+_withDirectives(_createVNode("select", { "onUpdate:modelValue": ($event) => selected = $event }, null), [[_vModelSelect, selected]]);
+_withDirectives(_createVNode("textarea", { "onUpdate:modelValue": ($event) => text = $event }, null), [[
+  _vModelText,
+  text,
+  void 0,
+  { trim: true }
+]]);
+_createVNode(Comp, {
+  "modelValue": text,
+  "onUpdate:modelValue": ($event) => text = $event
+}, null);
+_createVNode(Comp, {
+  "title": obj.title,
+  "onUpdate:title": ($event) => obj.title = $event
+}, null);
+_createVNode(Comp, {
+  "name": obj.name,
+  "onUpdate:name": ($event) => obj.name = $event,
+  "nameModifiers": { lazy: true }
+}, null);